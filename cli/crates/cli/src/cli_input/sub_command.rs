@@ -44,6 +44,8 @@ pub enum SubCommand {
     Schema(SchemaCommand),
     /// Publish a subgraph to a federated graph
     Publish(PublishCommand),
+    /// Install the triggers publishing the changes of the Postgres tables to the subscriptions
+    SetupSubscriptions,
     /// Dump the registry as JSON.
     #[clap(hide = true)]
     DumpConfig,
@@ -68,6 +70,7 @@ impl SubCommand {
                 | Self::Build(_)
                 | Self::Unlink
                 | Self::DumpConfig
+                | Self::SetupSubscriptions
                 | Self::Introspect(IntrospectCommand {
                     dev: true,
                     url: None,
@@ -98,6 +101,7 @@ impl ArgumentNames for SubCommand {
             | SubCommand::Build(_)
             | SubCommand::Completions(_)
             | SubCommand::DumpConfig
+            | SubCommand::SetupSubscriptions
             | SubCommand::Logs(_) => None,
         }
     }
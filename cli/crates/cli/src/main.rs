@@ -21,6 +21,7 @@ mod prompts;
 mod publish;
 mod reset;
 mod schema;
+mod setup_subscriptions;
 mod start;
 mod subgraphs;
 mod unlink;
@@ -72,7 +73,11 @@ fn main() {
 fn try_main(args: Args) -> Result<(), CliError> {
     let filter = EnvFilter::builder().parse_lossy(args.log_filter());
 
-    tracing_subscriber::registry().with(fmt::layer()).with(filter).init();
+    // The log filter only applies to the output, exported spans are configured in the project.
+    tracing_subscriber::registry()
+        .with(telemetry::layer())
+        .with(fmt::layer().with_filter(filter))
+        .init();
     trace!("subcommand: {}", args.command);
 
     // do not display header if we're in a pipe
@@ -172,6 +177,7 @@ fn try_main(args: Args) -> Result<(), CliError> {
         }
         SubCommand::Introspect(cmd) => introspect::introspect(&cmd),
         SubCommand::DumpConfig => dump_config::dump_config(),
+        SubCommand::SetupSubscriptions => setup_subscriptions::setup_subscriptions(),
         SubCommand::Check(cmd) => check::check(cmd),
     }
 }
@@ -358,6 +358,17 @@ pub fn project_reset() {
     watercolor::output!(r#"If you have a running 'grafbase dev' instance in this project, it will need to be restarted for this change to take effect"#, @BrightBlue);
 }
 
+pub fn subscriptions_set_up(names: &[String]) {
+    if names.is_empty() {
+        watercolor::output!("No Postgres connector has subscriptions enabled", @BrightBlue);
+        return;
+    }
+
+    for name in names {
+        watercolor::output!("✨ Installed the subscription triggers of the Postgres connector '{name}'", @BrightBlue);
+    }
+}
+
 pub fn login(url: &str) {
     println!(
         "Please continue by opening the following URL:\n{}\n",
@@ -0,0 +1,7 @@
+use crate::{errors::CliError, output::report};
+
+pub fn setup_subscriptions() -> Result<(), CliError> {
+    let names = server::setup_subscriptions().map_err(CliError::ServerError)?;
+    report::subscriptions_set_up(&names);
+    Ok(())
+}
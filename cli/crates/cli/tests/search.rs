@@ -9,8 +9,9 @@ use std::time::Duration;
 use rstest::rstest;
 use serde_json::{json, Value};
 use utils::consts::{
-    SEARCH_CREATE_LIST, SEARCH_CREATE_OPTIONAL, SEARCH_CREATE_PERSON, SEARCH_CREATE_REQUIRED, SEARCH_METADATA_FIELDS,
-    SEARCH_PAGINATION, SEARCH_SCHEMA, SEARCH_SEARCH_LIST, SEARCH_SEARCH_OPTIONAL, SEARCH_SEARCH_PERSON,
+    SEARCH_CREATE_ARTICLE, SEARCH_CREATE_DOCUMENT, SEARCH_CREATE_LIST, SEARCH_CREATE_OPTIONAL, SEARCH_CREATE_PERSON,
+    SEARCH_CREATE_REQUIRED, SEARCH_FACETS, SEARCH_METADATA_FIELDS, SEARCH_PAGINATION, SEARCH_SCHEMA,
+    SEARCH_SEARCH_ARTICLE, SEARCH_SEARCH_DOCUMENT, SEARCH_SEARCH_LIST, SEARCH_SEARCH_OPTIONAL, SEARCH_SEARCH_PERSON,
     SEARCH_SEARCH_REQUIRED,
 };
 use utils::environment::Environment;
@@ -698,3 +699,220 @@ fn search_pagination_and_total_hits() {
         total_hits: 10
     );
 }
+
+#[cfg(not(feature = "dynamodb"))] // GB-3636
+#[test]
+fn search_facets_highlights_and_fuzziness() {
+    use backend::project::GraphType;
+
+    let mut env = Environment::init();
+    env.grafbase_init(GraphType::Single);
+    env.write_schema(SEARCH_SCHEMA);
+    env.grafbase_dev();
+    let client = env.create_client().with_api_key();
+    client.poll_endpoint(30, 300);
+
+    let create = |variables: Value| -> String {
+        let response = client.gql::<Value>(SEARCH_CREATE_OPTIONAL).variables(variables).send();
+        dot_get!(response, "data.fieldsCreate.fields.id")
+    };
+    let search = |variables: Value| -> Value {
+        let response = client.gql::<Value>(SEARCH_FACETS).variables(variables).send();
+        dot_get!(response, "data.fieldsSearch")
+    };
+    let facets = json!({ "text": { "limit": 2 }, "int": { "boundaries": [5] } });
+
+    let best_dog = create(json!({ "text": "Dogs are the best!", "int": 1 }));
+    create(json!({ "text": "Cats are the best!", "int": 5 }));
+    let ruling_dog = create(json!({ "text": "Dogs rule", "int": 10 }));
+
+    // Facets
+    let all = search(json!({ "facets": facets }));
+    assert_eq!(
+        dot_get!(all, "facets", Value),
+        json!({
+            "text": [
+                { "value": "Cats are the best!", "count": 1 },
+                { "value": "Dogs are the best!", "count": 1 }
+            ],
+            "int": [
+                { "gte": null, "lt": 5, "count": 1 },
+                { "gte": 5, "lt": null, "count": 2 }
+            ]
+        })
+    );
+    let dogs = search(json!({ "query": "dogs", "facets": facets }));
+    assert_eq!(
+        dot_get!(dogs, "facets.int", Value),
+        json!([
+            { "gte": null, "lt": 5, "count": 1 },
+            { "gte": 5, "lt": null, "count": 1 }
+        ])
+    );
+
+    // Highlights
+    let highlights = dot_get!(dogs, "edges", Vec<Value>)
+        .into_iter()
+        .map(|edge| (dot_get!(edge, "node.id", String), dot_get!(edge, "highlights", Value)))
+        .collect::<HashMap<_, _>>();
+    assert_eq!(
+        highlights,
+        HashMap::from([
+            (
+                best_dog.clone(),
+                json!([{ "field": "text", "snippet": "<b>Dogs</b> are the best!" }])
+            ),
+            (
+                ruling_dog.clone(),
+                json!([{ "field": "text", "snippet": "<b>Dogs</b> rule" }])
+            ),
+        ])
+    );
+
+    // Fuzziness, words shorter than 4 characters don't tolerate typos by default.
+    let total_hits = |variables: Value| dot_get!(search(variables), "searchInfo.totalHits", i64);
+    assert_eq!(total_hits(json!({ "query": "dgs" })), 0);
+    assert_eq!(total_hits(json!({ "query": "dgs", "fuzziness": 1 })), 2);
+    assert_eq!(total_hits(json!({ "query": "dogz" })), 2);
+    assert_eq!(total_hits(json!({ "query": "dogz", "fuzziness": 0 })), 0);
+}
+
+#[cfg(not(feature = "dynamodb"))] // GB-3636
+#[test]
+fn search_analyzers_and_order_by() {
+    use backend::project::GraphType;
+
+    let mut env = Environment::init();
+    env.grafbase_init(GraphType::Single);
+    env.write_schema(SEARCH_SCHEMA);
+    env.grafbase_dev();
+    let client = env.create_client().with_api_key();
+    client.poll_endpoint(30, 300);
+
+    let search = |variables: Value| -> Value {
+        let response = client.gql::<Value>(SEARCH_SEARCH_ARTICLE).variables(variables).send();
+        dot_get!(response, "data.articleSearch")
+    };
+    let slugs = |variables: Value| -> Vec<String> {
+        dot_get!(search(variables), "edges", Vec<Value>)
+            .into_iter()
+            .map(|edge| dot_get!(edge, "node.slug", String))
+            .collect()
+    };
+
+    for (title, slug, views) in [
+        ("Running shoes", "b-shoes", 10),
+        ("The runner's guide", "a-guide", 30),
+        ("Ran out of milk", "c-milk", 20),
+    ] {
+        client
+            .gql::<Value>(SEARCH_CREATE_ARTICLE)
+            .variables(json!({ "title": title, "slug": slug, "views": views }))
+            .send();
+    }
+
+    // English analyzer: words are stemmed and stop words ignored.
+    assert_eq!(slugs(json!({ "query": "runs", "first": 10 })), vec!["b-shoes"]);
+    assert_eq!(slugs(json!({ "query": "the", "first": 10 })), Vec::<String>::new());
+
+    // Keyword analyzer: only the exact value matches.
+    assert_eq!(
+        slugs(json!({ "query": "a-guide", "fields": ["slug"], "first": 10 })),
+        vec!["a-guide"]
+    );
+    assert_eq!(
+        slugs(json!({ "query": "guide", "fields": ["slug"], "first": 10 })),
+        Vec::<String>::new()
+    );
+
+    // Order by
+    assert_eq!(
+        slugs(json!({ "first": 10, "orderBy": { "views": "DESC" } })),
+        vec!["a-guide", "c-milk", "b-shoes"]
+    );
+    assert_eq!(
+        slugs(json!({ "first": 10, "orderBy": { "slug": "ASC" } })),
+        vec!["a-guide", "b-shoes", "c-milk"]
+    );
+    let first_page = search(json!({ "first": 2, "orderBy": { "views": "ASC" } }));
+    assert!(dot_get!(first_page, "pageInfo.hasNextPage", bool));
+    assert_eq!(
+        slugs(json!({
+            "first": 2,
+            "after": dot_get!(first_page, "pageInfo.endCursor", String),
+            "orderBy": { "views": "ASC" }
+        })),
+        vec!["a-guide"]
+    );
+}
+
+#[cfg(not(feature = "dynamodb"))] // GB-3636
+#[test]
+fn search_vectors() {
+    use backend::project::GraphType;
+
+    let mut env = Environment::init();
+    env.grafbase_init(GraphType::Single);
+    env.write_schema(SEARCH_SCHEMA);
+    env.grafbase_dev();
+    let client = env.create_client().with_api_key();
+    client.poll_endpoint(30, 300);
+
+    let titles = |variables: Value| -> Vec<String> {
+        let response = client.gql::<Value>(SEARCH_SEARCH_DOCUMENT).variables(variables).send();
+        dot_get!(response, "data.documentSearch.edges", Vec<Value>)
+            .into_iter()
+            .map(|edge| dot_get!(edge, "node.title", String))
+            .collect()
+    };
+
+    for (title, category, embedding) in [
+        ("Cats", "animals", json!([1.0, 0.0, 0.0])),
+        ("Kittens", "animals", json!([0.9, 0.1, 0.0])),
+        ("Dogs", "animals", json!([0.0, 1.0, 0.0])),
+        ("Cars", "vehicles", json!([0.8, 0.0, 0.6])),
+        ("Unknown", "vehicles", Value::Null),
+    ] {
+        client
+            .gql::<Value>(SEARCH_CREATE_DOCUMENT)
+            .variables(json!({ "title": title, "category": category, "embedding": embedding }))
+            .send();
+    }
+
+    // Nearest neighbours by cosine similarity, documents without any vector are ignored.
+    let cats = json!({ "embedding": { "vector": [1.0, 0.0, 0.0] } });
+    assert_eq!(
+        titles(json!({ "vector": cats, "first": 10 })),
+        vec!["Cats", "Kittens", "Cars", "Dogs"]
+    );
+    assert_eq!(
+        titles(json!({ "vector": { "embedding": { "vector": [1.0, 0.0, 0.0], "k": 2 } }, "first": 10 })),
+        vec!["Cats", "Kittens"]
+    );
+
+    // Combined with filters
+    assert_eq!(
+        titles(json!({ "vector": cats, "filter": { "category": { "eq": "vehicles" } }, "first": 10 })),
+        vec!["Cars"]
+    );
+
+    // Hybrid ranking
+    assert_eq!(
+        titles(json!({ "query": "dogs", "vector": cats, "first": 10 })),
+        vec!["Dogs", "Cats", "Kittens", "Cars"]
+    );
+    assert_eq!(
+        titles(json!({
+            "query": "dogs",
+            "vector": { "embedding": { "vector": [1.0, 0.0, 0.0], "weight": 1.0 } },
+            "first": 10
+        })),
+        vec!["Cats", "Kittens", "Cars", "Dogs"]
+    );
+
+    let response = client
+        .gql::<Value>(SEARCH_SEARCH_DOCUMENT)
+        .variables(json!({ "vector": { "embedding": { "vector": [1.0, 0.0] } }, "first": 10 }))
+        .send();
+    assert!(dot_get!(response, "errors.0.message", String).contains("Expected a vector with 3 dimensions, got 2"));
+}
@@ -65,6 +65,11 @@ pub const SEARCH_PAGINATION: &str = include_str!("../graphql/search/search-pagin
 pub const SEARCH_METADATA_FIELDS: &str = include_str!("../graphql/search/search-metadata-fields.graphql");
 pub const SEARCH_CREATE_PERSON: &str = include_str!("../graphql/search/create-person.graphql");
 pub const SEARCH_SEARCH_PERSON: &str = include_str!("../graphql/search/search-person.graphql");
+pub const SEARCH_FACETS: &str = include_str!("../graphql/search/search-facets.graphql");
+pub const SEARCH_CREATE_ARTICLE: &str = include_str!("../graphql/search/create-article.graphql");
+pub const SEARCH_SEARCH_ARTICLE: &str = include_str!("../graphql/search/search-article.graphql");
+pub const SEARCH_CREATE_DOCUMENT: &str = include_str!("../graphql/search/create-document.graphql");
+pub const SEARCH_SEARCH_DOCUMENT: &str = include_str!("../graphql/search/search-document.graphql");
 
 pub const ENVIRONMENT_SCHEMA: &str = include_str!("../graphql/environment/schema.graphql");
 
@@ -1,8 +1,8 @@
 use async_graphql::{EmptySubscription, Schema};
 use async_graphql_axum::GraphQL;
 use axum::{
-    extract::{Query, State},
-    http::{HeaderMap, StatusCode},
+    extract::{ConnectInfo, Query, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
     response::{Html, IntoResponse},
     routing::get,
     Json, Server,
@@ -10,8 +10,9 @@ use axum::{
 use common::environment::Environment;
 use handlebars::Handlebars;
 use parser_sdl::federation::FederatedGraphConfig;
+use runtime::rate_limiting::RateLimitStore;
 use serde_json::json;
-use std::time::Duration;
+use std::{net::SocketAddr, sync::Arc, time::Duration};
 use tokio::sync::{mpsc, oneshot};
 use tower_http::cors::CorsLayer;
 
@@ -38,7 +39,12 @@ struct ProxyState {
     request_sender: RequestSender,
 }
 
-pub(super) async fn run(port: u16, expose: bool, config: FederatedGraphConfig) -> Result<(), crate::Error> {
+pub(super) async fn run(
+    port: u16,
+    expose: bool,
+    config: FederatedGraphConfig,
+    rate_limit_store: Arc<dyn RateLimitStore>,
+) -> Result<(), crate::Error> {
     log::trace!("starting the federated dev server");
 
     let (graph_sender, graph_receiver) = mpsc::channel(16);
@@ -56,7 +62,7 @@ pub(super) async fn run(port: u16, expose: bool, config: FederatedGraphConfig) -
     let refresher = Refresher::new(refresh_bus);
     tokio::spawn(refresher.handler());
 
-    let router = Router::new(graph_receiver, request_receiver, config);
+    let router = Router::new(graph_receiver, request_receiver, config, rate_limit_store);
     tokio::spawn(router.handler());
 
     let ticker = Ticker::new(REFRESH_INTERVAL, compose_sender);
@@ -87,7 +93,7 @@ pub(super) async fn run(port: u16, expose: bool, config: FederatedGraphConfig) -
     let address = host.parse().expect("we just defined it above, it _must work_");
 
     Server::bind(&address)
-        .serve(app.into_make_service())
+        .serve(app.into_make_service_with_connect_info::<SocketAddr>())
         .await
         .map_err(|error| crate::Error::internal(error.to_string()))?;
 
@@ -127,23 +133,26 @@ async fn admin(
 async fn engine_get(
     Query(request): Query<engine::Request>,
     headers: HeaderMap,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
     State(ProxyState { request_sender, .. }): State<ProxyState>,
 ) -> impl IntoResponse {
-    handle_engine_request(request, request_sender, headers).await
+    handle_engine_request(request, request_sender, headers, peer).await
 }
 
 async fn engine_post(
     State(ProxyState { request_sender, .. }): State<ProxyState>,
     headers: HeaderMap,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
     Json(request): Json<engine::Request>,
 ) -> impl IntoResponse {
-    handle_engine_request(request, request_sender, headers).await
+    handle_engine_request(request, request_sender, headers, peer).await
 }
 
 async fn handle_engine_request(
     request: engine::Request,
     request_sender: RequestSender,
     headers: HeaderMap,
+    peer: SocketAddr,
 ) -> impl IntoResponse {
     let headers = headers
         .into_iter()
@@ -156,10 +165,23 @@ async fn handle_engine_request(
         .collect();
 
     let (response_sender, response_receiver) = oneshot::channel();
-    request_sender.send((request, headers, response_sender)).await.unwrap();
+    request_sender
+        .send((request, headers, Some(peer.ip()), response_sender))
+        .await
+        .unwrap();
 
     match response_receiver.await {
-        Ok(Ok(response)) => Json(response).into_response(),
+        Ok(Ok(response)) => {
+            let retry_after = response.metadata().retry_after;
+            let mut http_response = Json(response).into_response();
+            if let Some(retry_after) = retry_after {
+                http_response.headers_mut().insert(
+                    header::RETRY_AFTER,
+                    HeaderValue::from(runtime::rate_limiting::retry_after_secs(retry_after)),
+                );
+            }
+            http_response
+        }
         Ok(Err(error)) => Json(json!({
             "data": null,
             "errors": [
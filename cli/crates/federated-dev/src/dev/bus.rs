@@ -3,6 +3,8 @@ mod compose;
 mod message;
 mod refresh;
 
+use std::net::IpAddr;
+
 pub(crate) use admin::AdminBus;
 pub(crate) use compose::ComposeBus;
 use engine::RequestHeaders;
@@ -36,10 +38,10 @@ pub(crate) type ComposeSender = mpsc::Sender<ComposeMessage>;
 pub(crate) type ComposeReceiver = mpsc::Receiver<ComposeMessage>;
 
 /// Send half of channel for the server to send requests to the router actor
-pub(crate) type RequestSender = mpsc::Sender<(engine::Request, RequestHeaders, ResponseSender)>;
+pub(crate) type RequestSender = mpsc::Sender<(engine::Request, RequestHeaders, Option<IpAddr>, ResponseSender)>;
 
 /// Receive half of channel for the router actor to receive requests
-pub(crate) type RequestReceiver = mpsc::Receiver<(engine::Request, RequestHeaders, ResponseSender)>;
+pub(crate) type RequestReceiver = mpsc::Receiver<(engine::Request, RequestHeaders, Option<IpAddr>, ResponseSender)>;
 
 /// Send half of channel for the router actor to send responses
 pub(crate) type ResponseSender = oneshot::Sender<RouterResult<engine_v2::Response>>;
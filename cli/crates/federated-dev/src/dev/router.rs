@@ -1,12 +1,16 @@
-use std::sync::Arc;
+use std::{net::IpAddr, sync::Arc};
 
 use super::bus::{GraphReceiver, RequestReceiver, ResponseSender};
-use engine::RequestHeaders;
+use engine::{
+    persisted_queries::{PersistedQueries, PersistedQuery},
+    RequestHeaders,
+};
 use engine_v2::{Engine, EngineRuntime};
 use futures_concurrency::stream::Merge;
 use futures_util::{stream::BoxStream, StreamExt};
 use graphql_composition::FederatedGraph;
 use parser_sdl::federation::FederatedGraphConfig;
+use runtime::rate_limiting::RateLimitStore;
 use tokio_stream::wrappers::ReceiverStream;
 
 pub(crate) struct Router {
@@ -14,15 +18,22 @@ pub(crate) struct Router {
     request_bus: RequestReceiver,
     engine: Option<Arc<Engine>>,
     config: FederatedGraphConfig,
+    rate_limit_store: Arc<dyn RateLimitStore>,
 }
 
 impl Router {
-    pub fn new(graph_bus: GraphReceiver, request_bus: RequestReceiver, config: FederatedGraphConfig) -> Self {
+    pub fn new(
+        graph_bus: GraphReceiver,
+        request_bus: RequestReceiver,
+        config: FederatedGraphConfig,
+        rate_limit_store: Arc<dyn RateLimitStore>,
+    ) -> Self {
         Self {
             graph_bus,
             request_bus,
             engine: None,
             config,
+            rate_limit_store,
         }
     }
 
@@ -41,14 +52,21 @@ impl Router {
                 (RouterMessage::Graph(graph), _) => {
                     log::trace!("router got a new graph");
 
-                    self.engine = graph.map(|graph| new_engine(&self.config, graph));
+                    self.engine =
+                        graph.map(|graph| new_engine(&self.config, graph, Arc::clone(&self.rate_limit_store)));
                 }
-                (RouterMessage::Request(request, headers, response_sender), Some(engine)) => {
+                (RouterMessage::Request(request, headers, client_ip, response_sender), Some(engine)) => {
                     log::trace!("router got a new request with an existing engine");
 
-                    tokio::spawn(run_request(request, headers, response_sender, Arc::clone(engine)));
+                    tokio::spawn(run_request(
+                        request,
+                        headers,
+                        client_ip,
+                        response_sender,
+                        Arc::clone(engine),
+                    ));
                 }
-                (RouterMessage::Request(_, _, response_sender), None) => {
+                (RouterMessage::Request(_, _, _, response_sender), None) => {
                     log::trace!("router got a new request with a missing engine");
 
                     response_sender.send(Err(RouterError::NoSubgraphs)).ok();
@@ -58,13 +76,22 @@ impl Router {
     }
 }
 
-fn new_engine(config: &FederatedGraphConfig, graph: FederatedGraph) -> Arc<Engine> {
+fn new_engine(
+    config: &FederatedGraphConfig,
+    graph: FederatedGraph,
+    rate_limit_store: Arc<dyn RateLimitStore>,
+) -> Arc<Engine> {
     let config = engine_config_builder::build_config(config, graph);
 
     Arc::new(Engine::new(
         config.into_latest().into(),
         EngineRuntime {
             fetcher: runtime_local::NativeFetcher::runtime_fetcher(),
+            cache: None,
+            persisted_queries: Some(PersistedQueries::Automatic(Arc::new(runtime_local::InMemoryCache::<
+                PersistedQuery,
+            >::new()))),
+            rate_limit_store,
         },
     ))
 }
@@ -72,20 +99,25 @@ fn new_engine(config: &FederatedGraphConfig, graph: FederatedGraph) -> Arc<Engin
 async fn run_request(
     request: engine::Request,
     headers: RequestHeaders,
+    client_ip: Option<IpAddr>,
     response_sender: ResponseSender,
     engine: Arc<Engine>,
 ) {
-    response_sender.send(Ok(engine.execute(request, headers).await)).ok();
+    response_sender
+        .send(Ok(engine.execute(request, headers, client_ip).await))
+        .ok();
 }
 
 enum RouterMessage {
     Graph(Option<FederatedGraph>),
-    Request(engine::Request, RequestHeaders, ResponseSender),
+    Request(engine::Request, RequestHeaders, Option<IpAddr>, ResponseSender),
 }
 
 impl RouterMessage {
-    fn request((request, headers, sender): (engine::Request, RequestHeaders, ResponseSender)) -> Self {
-        RouterMessage::Request(request, headers, sender)
+    fn request(
+        (request, headers, client_ip, sender): (engine::Request, RequestHeaders, Option<IpAddr>, ResponseSender),
+    ) -> Self {
+        RouterMessage::Request(request, headers, client_ip, sender)
     }
 }
 
@@ -58,6 +58,8 @@ pub use self::{
 };
 
 use parser_sdl::federation::FederatedGraphConfig;
+use runtime::rate_limiting::RateLimitStore;
+use std::sync::Arc;
 use tokio::runtime::Builder;
 use url::Url;
 
@@ -71,7 +73,12 @@ pub fn add_subgraph(name: &str, url: &Url, dev_api_port: u16, headers: Vec<(&str
     runtime.block_on(subgraph::add(name, url, dev_api_port, headers))
 }
 
-/// Runs the federated dev system.
-pub async fn run(port: u16, expose: bool, config: FederatedGraphConfig) -> Result<(), Error> {
-    dev::run(port, expose, config).await
+/// Runs the federated dev system, the router counts the rate limited requests in `rate_limit_store`.
+pub async fn run(
+    port: u16,
+    expose: bool,
+    config: FederatedGraphConfig,
+    rate_limit_store: Arc<dyn RateLimitStore>,
+) -> Result<(), Error> {
+    dev::run(port, expose, config, rate_limit_store).await
 }
@@ -0,0 +1,127 @@
+use std::{collections::HashMap, sync::Arc};
+
+use engine::persisted_queries::{PersistedQueries, PersistedQuery, PersistedQueryStore, TrustedDocuments};
+use runtime::cache::{self, Cache, CacheStats, Entry, EntryState};
+use runtime_local::{InMemoryCache, InMemoryCacheCapacity, RedisCache};
+
+const CACHE_MAX_ENTRIES_ENV_VAR: &str = "GRAFBASE_CACHE_MAX_ENTRIES";
+const CACHE_MAX_BYTES_ENV_VAR: &str = "GRAFBASE_CACHE_MAX_BYTES";
+const CACHE_REDIS_URL_ENV_VAR: &str = "GRAFBASE_CACHE_REDIS_URL";
+const CACHE_REDIS_KEY_PREFIX_ENV_VAR: &str = "GRAFBASE_CACHE_REDIS_KEY_PREFIX";
+const TRUSTED_DOCUMENTS_MANIFEST_ENV_VAR: &str = "GRAFBASE_TRUSTED_DOCUMENTS_MANIFEST";
+/// Appended to the key prefix of the persisted queries, so purging the responses leaves them alone.
+const PERSISTED_QUERIES_KEY_PREFIX_SUFFIX: &str = "persisted-queries";
+
+/// Response cache of the gateway, kept in memory unless a Redis server is configured to share it
+/// between instances.
+pub enum GatewayCache {
+    InMemory(InMemoryCache<engine::Response>),
+    Redis(RedisCache<engine::Response>),
+}
+
+impl GatewayCache {
+    pub fn from_env_vars(env_vars: &HashMap<String, String>) -> Result<Self, crate::Error> {
+        if let Some(url) = env_vars.get(CACHE_REDIS_URL_ENV_VAR) {
+            let cache = RedisCache::new(url)
+                .map_err(|err| crate::Error::Internal(format!("Invalid value for {CACHE_REDIS_URL_ENV_VAR}: {err}")))?;
+            return Ok(GatewayCache::Redis(
+                match env_vars.get(CACHE_REDIS_KEY_PREFIX_ENV_VAR) {
+                    Some(key_prefix) => cache.with_key_prefix(key_prefix),
+                    None => cache,
+                },
+            ));
+        }
+        Ok(GatewayCache::InMemory(InMemoryCache::with_capacity(cache_capacity(
+            env_vars,
+        )?)))
+    }
+}
+
+/// Only the operations of the trusted documents manifest are allowed if one is provided, otherwise
+/// automatic persisted queries are stored in the same backend as the response cache.
+pub fn persisted_queries_from_env_vars(env_vars: &HashMap<String, String>) -> Result<PersistedQueries, crate::Error> {
+    if let Some(path) = env_vars.get(TRUSTED_DOCUMENTS_MANIFEST_ENV_VAR) {
+        let manifest = std::fs::read_to_string(path)
+            .map_err(|err| crate::Error::Internal(format!("Could not read trusted documents manifest: {err}")))?;
+        let documents = TrustedDocuments::from_manifest(&manifest)
+            .map_err(|err| crate::Error::Internal(format!("Invalid trusted documents manifest: {err}")))?;
+        return Ok(PersistedQueries::Trusted(documents));
+    }
+    let store: PersistedQueryStore = match env_vars.get(CACHE_REDIS_URL_ENV_VAR) {
+        Some(url) => {
+            let cache = RedisCache::<PersistedQuery>::new(url)
+                .map_err(|err| crate::Error::Internal(format!("Invalid value for {CACHE_REDIS_URL_ENV_VAR}: {err}")))?;
+            let key_prefix = format!(
+                "{}:{PERSISTED_QUERIES_KEY_PREFIX_SUFFIX}",
+                env_vars
+                    .get(CACHE_REDIS_KEY_PREFIX_ENV_VAR)
+                    .map_or(cache.key_prefix(), String::as_str)
+            );
+            Arc::new(cache.with_key_prefix(key_prefix))
+        }
+        None => Arc::new(InMemoryCache::<PersistedQuery>::new()),
+    };
+    Ok(PersistedQueries::Automatic(store))
+}
+
+fn cache_capacity(env_vars: &HashMap<String, String>) -> Result<InMemoryCacheCapacity, crate::Error> {
+    let parse = |name: &str, default: usize| {
+        env_vars.get(name).map_or(Ok(default), |value| {
+            value
+                .parse::<usize>()
+                .map_err(|_| crate::Error::Internal(format!("Invalid value for {name}: '{value}'")))
+        })
+    };
+    let default = InMemoryCacheCapacity::default();
+    Ok(InMemoryCacheCapacity {
+        max_entries: parse(CACHE_MAX_ENTRIES_ENV_VAR, default.max_entries)?,
+        max_bytes: parse(CACHE_MAX_BYTES_ENV_VAR, default.max_bytes)?,
+    })
+}
+
+#[async_trait::async_trait]
+impl Cache for GatewayCache {
+    type Value = engine::Response;
+
+    async fn get(&self, key: &str) -> cache::Result<Entry<Self::Value>> {
+        match self {
+            GatewayCache::InMemory(cache) => cache.get(key).await,
+            GatewayCache::Redis(cache) => cache.get(key).await,
+        }
+    }
+
+    async fn put(&self, key: &str, state: EntryState, value: Arc<Self::Value>, tags: Vec<String>) -> cache::Result<()> {
+        match self {
+            GatewayCache::InMemory(cache) => cache.put(key, state, value, tags).await,
+            GatewayCache::Redis(cache) => cache.put(key, state, value, tags).await,
+        }
+    }
+
+    async fn delete(&self, key: &str) -> cache::Result<()> {
+        match self {
+            GatewayCache::InMemory(cache) => cache.delete(key).await,
+            GatewayCache::Redis(cache) => cache.delete(key).await,
+        }
+    }
+
+    async fn purge_by_tags(&self, tags: Vec<String>) -> cache::Result<()> {
+        match self {
+            GatewayCache::InMemory(cache) => cache.purge_by_tags(tags).await,
+            GatewayCache::Redis(cache) => cache.purge_by_tags(tags).await,
+        }
+    }
+
+    async fn purge_by_hostname(&self, hostname: String) -> cache::Result<()> {
+        match self {
+            GatewayCache::InMemory(cache) => cache.purge_by_hostname(hostname).await,
+            GatewayCache::Redis(cache) => cache.purge_by_hostname(hostname).await,
+        }
+    }
+
+    async fn stats(&self) -> cache::Result<Option<CacheStats>> {
+        match self {
+            GatewayCache::InMemory(cache) => cache.stats().await,
+            GatewayCache::Redis(cache) => cache.stats().await,
+        }
+    }
+}
@@ -3,12 +3,18 @@ use std::{collections::HashMap, sync::Arc};
 use axum::response::IntoResponse;
 use common_types::auth::ExecutionAuth;
 use dynamodb::{DynamoDBBatchersData, DynamoDBContext};
-use engine::{registry::resolvers::graphql, RequestHeaders};
+use engine::{
+    registry::resolvers::{custom, graphql, join},
+    RequestHeaders,
+};
 use gateway_core::{RequestContext, StreamingFormat};
 use graphql_extensions::{authorization::AuthExtension, runtime_log::RuntimeLogExtension};
 use postgres_connector_types::transport::TcpTransport;
-use runtime::pg::PgTransportFactory;
-use runtime_local::{Bridge, LocalPgTransportFactory, LocalSearchEngine, UdfInvokerImpl};
+use runtime::{pg::PgTransportFactory, rate_limiting::RateLimiter};
+use runtime_local::{Bridge, LocalPgTransportFactory, LocalSearchEngine};
+use runtime_wasm::WasmResolvers;
+
+use crate::resolvers::ResolverInvoker;
 
 pub struct Executor {
     #[allow(dead_code)]
@@ -16,6 +22,8 @@ pub struct Executor {
     bridge: Bridge,
     registry: Arc<engine::Registry>,
     postgres: LocalPgTransportFactory,
+    rate_limiter: RateLimiter,
+    wasm_resolvers: Arc<WasmResolvers>,
 }
 
 impl Executor {
@@ -23,6 +31,8 @@ impl Executor {
         env_vars: HashMap<String, String>,
         bridge: Bridge,
         registry: Arc<engine::Registry>,
+        rate_limiter: RateLimiter,
+        wasm_resolvers: WasmResolvers,
     ) -> Result<Self, crate::Error> {
         let postgres = {
             let mut transports = HashMap::new();
@@ -41,6 +51,8 @@ impl Executor {
             bridge,
             registry,
             postgres,
+            rate_limiter,
+            wasm_resolvers: Arc::new(wasm_resolvers),
         })
     }
 
@@ -123,18 +135,21 @@ impl Executor {
             },
         );
 
-        let resolver_engine = UdfInvokerImpl::create_engine(self.bridge.clone());
+        let resolver_engine = ResolverInvoker::create_engine(self.wasm_resolvers.clone(), self.bridge.clone());
         let search_engine = LocalSearchEngine::new(self.bridge.clone());
 
         Ok(engine::Schema::build(engine::Registry::clone(&self.registry))
             .data(dynamodb_batchers_data)
             .data(graphql::QueryBatcher::new())
+            .data(join::JoinBatcher::new())
+            .data(custom::CustomResolverBatcher::new())
             .data(search_engine)
             .data(resolver_engine)
             .data(auth)
             .data(PgTransportFactory::new(Box::new(self.postgres.clone())))
             .data(RequestHeaders::from(&ctx.headers_as_map()))
             .data(runtime_ctx)
+            .data(self.rate_limiter.clone())
             .extension(RuntimeLogExtension::new(Box::new(
                 runtime_local::LogEventReceiverImpl::new(self.bridge.clone()),
             )))
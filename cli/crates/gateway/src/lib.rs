@@ -1,23 +1,27 @@
 use engine::registry::CachePartialRegistry;
 use gateway_core::{CacheConfig, CacheControl};
-use runtime_local::InMemoryCache;
+use runtime::rate_limiting::RateLimiter;
 use std::{collections::HashMap, ops::Deref, sync::Arc};
 
-use self::executor::Executor;
+use self::{cache::GatewayCache, executor::Executor};
 
 mod auth;
+mod cache;
 mod context;
 mod error;
 mod executor;
+mod rate_limiting;
+mod resolvers;
 mod response;
 mod serving;
 
 pub(crate) use context::Context;
 pub(crate) use error::Error;
+pub use rate_limiting::rate_limit_store_from_env_vars;
 pub(crate) use response::Response;
 pub use runtime_local::Bridge;
 
-pub type GatewayInner = gateway_core::Gateway<Executor, InMemoryCache<engine::Response>>;
+pub type GatewayInner = gateway_core::Gateway<Executor, GatewayCache>;
 
 #[derive(Clone)]
 pub struct Gateway {
@@ -29,6 +33,7 @@ impl Gateway {
         env_vars: HashMap<String, String>,
         bridge: Bridge,
         registry: Arc<engine::Registry>,
+        wasm_resolvers: runtime_wasm::WasmResolvers,
     ) -> Result<Self, crate::Error> {
         let cache_config = CacheConfig {
             global_enabled: true,
@@ -43,15 +48,29 @@ impl Gateway {
             bridge: bridge.clone(),
         });
 
-        let executor = Arc::new(Executor::new(env_vars, bridge, registry).await?);
+        let cache = GatewayCache::from_env_vars(&env_vars)?;
+        let persisted_queries = cache::persisted_queries_from_env_vars(&env_vars)?;
+        let rate_limiter = RateLimiter::new(
+            registry.rate_limits.clone(),
+            rate_limiting::rate_limit_store_from_env_vars(&env_vars)?,
+        );
+        let executor = Arc::new(
+            Executor::new(
+                env_vars,
+                bridge,
+                Arc::clone(&registry),
+                rate_limiter.clone(),
+                wasm_resolvers,
+            )
+            .await?,
+        );
 
         Ok(Gateway {
-            inner: Arc::new(gateway_core::Gateway::new(
-                executor,
-                Arc::new(InMemoryCache::<engine::Response>::new()),
-                cache_config,
-                authorizer,
-            )),
+            inner: Arc::new(
+                gateway_core::Gateway::new(executor, Arc::new(cache), cache_config, authorizer)
+                    .with_persisted_queries(persisted_queries)
+                    .with_rate_limiter(rate_limiter, registry),
+            ),
         })
     }
 
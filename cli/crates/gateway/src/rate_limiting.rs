@@ -0,0 +1,25 @@
+use std::{collections::HashMap, sync::Arc};
+
+use runtime::rate_limiting::{KvRateLimitStore, RateLimitStore};
+use runtime_local::{InMemoryRateLimitStore, RedisKv};
+
+const RATE_LIMIT_REDIS_URL_ENV_VAR: &str = "GRAFBASE_RATE_LIMIT_REDIS_URL";
+const RATE_LIMIT_REDIS_KEY_PREFIX_ENV_VAR: &str = "GRAFBASE_RATE_LIMIT_REDIS_KEY_PREFIX";
+
+/// Rate limit counters are shared between instances through a Redis server if one is configured,
+/// otherwise they're kept in memory and reset whenever the server restarts.
+pub fn rate_limit_store_from_env_vars(
+    env_vars: &HashMap<String, String>,
+) -> Result<Arc<dyn RateLimitStore>, crate::Error> {
+    let Some(url) = env_vars.get(RATE_LIMIT_REDIS_URL_ENV_VAR) else {
+        return Ok(Arc::new(InMemoryRateLimitStore::new()));
+    };
+    let kv = RedisKv::new(url)
+        .map_err(|err| crate::Error::Internal(format!("Invalid value for {RATE_LIMIT_REDIS_URL_ENV_VAR}: {err}")))?;
+    Ok(Arc::new(KvRateLimitStore::new(
+        match env_vars.get(RATE_LIMIT_REDIS_KEY_PREFIX_ENV_VAR) {
+            Some(key_prefix) => kv.with_key_prefix(key_prefix),
+            None => kv,
+        },
+    )))
+}
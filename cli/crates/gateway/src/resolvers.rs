@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use runtime::udf::{
+    CustomResolverError, CustomResolverRequestPayload, CustomResolverResponse, CustomResolversEngine, UdfInvoker,
+    UdfRequest,
+};
+use runtime_local::{Bridge, UdfInvokerImpl};
+use runtime_wasm::WasmResolvers;
+
+/// Runs the resolvers compiled to WebAssembly in-process, and the JavaScript ones in their
+/// workers through the bridge.
+pub(crate) struct ResolverInvoker {
+    wasm_resolvers: Arc<WasmResolvers>,
+    bridge: UdfInvokerImpl,
+}
+
+impl ResolverInvoker {
+    pub(crate) fn create_engine(wasm_resolvers: Arc<WasmResolvers>, bridge: Bridge) -> CustomResolversEngine {
+        CustomResolversEngine::new(Box::new(Self {
+            wasm_resolvers,
+            bridge: UdfInvokerImpl::new(bridge),
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl UdfInvoker<CustomResolverRequestPayload> for ResolverInvoker {
+    async fn invoke(
+        &self,
+        ray_id: &str,
+        request: UdfRequest<'_, CustomResolverRequestPayload>,
+    ) -> Result<CustomResolverResponse, CustomResolverError>
+    where
+        CustomResolverRequestPayload: 'async_trait,
+    {
+        if self.wasm_resolvers.contains(request.name) {
+            self.wasm_resolvers.invoke(ray_id, request).await
+        } else {
+            self.bridge.invoke(ray_id, request).await
+        }
+    }
+}
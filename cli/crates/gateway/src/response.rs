@@ -65,9 +65,13 @@ impl gateway_core::Response for Response {
     }
 
     fn engine(response: Arc<engine::Response>) -> Result<Self, Self::Error> {
+        let http_headers = response.http_headers.clone();
         let headers = [(header::CONTENT_TYPE, "application/json;charset=UTF-8")];
         let body = axum::Json(response.as_ref().to_graphql_response());
-        Ok((headers, body).into_response().into())
+        let mut response = (headers, body).into_response();
+        // Headers set while executing the operation, like the `Retry-After` of rate limited connectors.
+        response.headers_mut().extend(http_headers);
+        Ok(response.into())
     }
 
     fn admin(response: async_graphql::Response) -> Result<Self, Self::Error> {
@@ -1,7 +1,7 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, net::SocketAddr};
 
 use axum::{
-    extract::{Query, State},
+    extract::{ConnectInfo, Query, State},
     response::IntoResponse,
     routing::post,
     Router,
@@ -9,7 +9,10 @@ use axum::{
 use bytes::Bytes;
 use futures_util::future::{join_all, BoxFuture};
 use gateway_core::{
-    serving::{OPERATION_NAME_REQUEST_PARAMETER, QUERY_REQUEST_PARAMETER, VARIABLES_REQUEST_PARAMETER},
+    serving::{
+        EXTENSIONS_REQUEST_PARAMETER, OPERATION_NAME_REQUEST_PARAMETER, QUERY_REQUEST_PARAMETER,
+        VARIABLES_REQUEST_PARAMETER,
+    },
     StreamingFormat,
 };
 use http::{HeaderMap, StatusCode};
@@ -27,6 +30,7 @@ pub(super) fn router(gateway: Gateway) -> Router {
 
 async fn post_graphql(
     State(gateway): State<Gateway>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
     Query(params): Query<HashMap<String, String>>,
     body: Bytes,
@@ -37,7 +41,7 @@ async fn post_graphql(
         .and_then(|value| value.to_str().ok())
         .and_then(StreamingFormat::from_accept_header);
     let (sender, receiver) = mpsc::unbounded_channel();
-    let ctx = crate::Context::new(headers, &params, sender);
+    let ctx = crate::Context::new(headers, &params, Some(peer.ip()), sender);
     // FIXME: Pathfinder doesn't send the proper content-type, so axum complains about it.
     let request: engine::Request = match serde_json::from_slice(&body[..]) {
         Ok(req) => req,
@@ -52,6 +56,7 @@ async fn post_graphql(
 
 async fn get_graphql(
     State(gateway): State<Gateway>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
     Query(mut params): Query<HashMap<String, String>>,
 ) -> crate::Response {
@@ -60,13 +65,24 @@ async fn get_graphql(
         .and_then(|value| value.to_str().ok())
         .and_then(StreamingFormat::from_accept_header);
     let (sender, receiver) = mpsc::unbounded_channel();
-    let ctx = crate::Context::new(headers, &params, sender);
+    let ctx = crate::Context::new(headers, &params, Some(peer.ip()), sender);
 
-    let Some(query) = params.remove(QUERY_REQUEST_PARAMETER) else {
-        return Error::BadRequest("Missing 'query' parameter".into()).into();
+    // With persisted queries, only the hash is sent within the extensions.
+    let extensions: HashMap<String, engine::Value> = match params
+        .get(EXTENSIONS_REQUEST_PARAMETER)
+        .map(|value| serde_json::from_str(value))
+    {
+        Some(Ok(extensions)) => extensions,
+        Some(Err(err)) => return Error::BadRequest(format!("Invalid 'extensions' parameter: {err}")).into(),
+        None => HashMap::new(),
+    };
+    let query = match params.remove(QUERY_REQUEST_PARAMETER) {
+        Some(query) => query,
+        None if !extensions.is_empty() => String::new(),
+        None => return Error::BadRequest("Missing 'query' parameter".into()).into(),
     };
 
-    let request = engine::Request::new(query)
+    let mut request = engine::Request::new(query)
         .operation_name(params.remove(OPERATION_NAME_REQUEST_PARAMETER).unwrap_or_default())
         .variables(
             params
@@ -74,6 +90,7 @@ async fn get_graphql(
                 .and_then(|variables| serde_json::from_str(variables).ok())
                 .unwrap_or_default(),
         );
+    request.extensions = extensions;
 
     let response = gateway.execute(&ctx, request, streaming_format).await.into();
     tokio::spawn(wait(receiver));
@@ -0,0 +1,98 @@
+use std::collections::{HashMap, HashSet};
+
+use tantivy::collector::{Count, DocSetCollector};
+use tantivy::{DocAddress, Searcher};
+
+use super::query::{Query, Range};
+use super::{SearchResult, TantivyQueryBuilder};
+use runtime::search::{range_buckets, Facet, FacetBucket, FacetCounts};
+
+pub struct FacetCounter<'a> {
+    pub searcher: &'a Searcher,
+    pub query_builder: &'a TantivyQueryBuilder<'a>,
+    pub query: &'a Query,
+    /// Restricts the counts to those documents, for hits ranked outside of tantivy.
+    pub hits: Option<&'a HashSet<DocAddress>>,
+}
+
+impl<'a> FacetCounter<'a> {
+    pub fn count(&self, facet: Facet) -> SearchResult<FacetCounts> {
+        let buckets = match &facet {
+            Facet::Terms { field, limit } => self.count_terms(field, *limit)?,
+            Facet::Range { field, boundaries } => self.count_ranges(field, boundaries)?,
+        };
+        Ok(FacetCounts {
+            field: facet.field().to_string(),
+            buckets,
+        })
+    }
+
+    // cast_possible_truncation: Complains about u64 -> usize, which shouldn't matter for anything sensible.
+    #[allow(clippy::cast_possible_truncation)]
+    fn count_terms(&self, field: &str, limit: u64) -> SearchResult<Vec<FacetBucket>> {
+        let tantivy_field = self
+            .searcher
+            .schema()
+            .get_field(field)
+            .ok_or_else(|| format!("Unknown field: '{field}'"))?;
+        let query = self.query_builder.build(self.query.clone())?;
+
+        let mut counts: HashMap<String, u64> = HashMap::new();
+        for doc_address in self.searcher.search(&query, &DocSetCollector)? {
+            if self.hits.is_some_and(|hits| !hits.contains(&doc_address)) {
+                continue;
+            }
+            let doc = self.searcher.doc(doc_address)?;
+            let mut values = doc
+                .get_all(tantivy_field)
+                .filter_map(tantivy::schema::Value::as_text)
+                .collect::<Vec<_>>();
+            // A list can hold the same value multiple times, the document is only counted once.
+            values.sort_unstable();
+            values.dedup();
+            for value in values {
+                *counts.entry(value.to_string()).or_default() += 1;
+            }
+        }
+
+        let mut counts = counts.into_iter().collect::<Vec<_>>();
+        // Most frequent values first, ties are sorted alphabetically to keep a stable output.
+        counts
+            .sort_by(|(a_value, a_count), (b_value, b_count)| b_count.cmp(a_count).then_with(|| a_value.cmp(b_value)));
+        Ok(counts
+            .into_iter()
+            .take(limit as usize)
+            .map(|(value, count)| FacetBucket::Term { value, count })
+            .collect())
+    }
+
+    fn count_ranges(&self, field: &str, boundaries: &[runtime::search::ScalarValue]) -> SearchResult<Vec<FacetBucket>> {
+        range_buckets(boundaries)
+            .into_iter()
+            .map(|range| {
+                let query = self.query_builder.build(Query::Intersection(vec![
+                    self.query.clone(),
+                    Query::Range {
+                        field: field.to_string(),
+                        range: Range {
+                            start: range.start.clone(),
+                            end: range.end.clone(),
+                        },
+                    },
+                ]))?;
+                let count = match self.hits {
+                    Some(hits) => self
+                        .searcher
+                        .search(&query, &DocSetCollector)?
+                        .intersection(hits)
+                        .count(),
+                    None => self.searcher.search(&query, &Count)?,
+                };
+                Ok(FacetBucket::Range {
+                    range,
+                    count: count as u64,
+                })
+            })
+            .collect()
+    }
+}
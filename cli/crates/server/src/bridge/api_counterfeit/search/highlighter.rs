@@ -0,0 +1,56 @@
+use tantivy::{Document, Searcher, SnippetGenerator};
+
+use super::query::Query;
+use super::utils::tokenized_field_name;
+use super::{FieldType, Schema, SearchResult, TantivyQueryBuilder};
+use runtime::search::Highlight;
+
+pub struct Highlighter {
+    generators: Vec<(String, SnippetGenerator)>,
+}
+
+impl Highlighter {
+    pub fn new(
+        searcher: &Searcher,
+        query_builder: &TantivyQueryBuilder<'_>,
+        schema: &Schema,
+        query: Query,
+    ) -> SearchResult<Self> {
+        // Only the tokenized fields are highlighted, matching the text query.
+        let query = query_builder.build(query)?;
+        let mut generators = schema
+            .fields
+            .iter()
+            .filter(|(_, entry)| {
+                matches!(
+                    entry.ty,
+                    FieldType::String { .. } | FieldType::URL { .. } | FieldType::Email { .. }
+                )
+            })
+            .filter_map(|(name, _)| {
+                let field = searcher.schema().get_field(&tokenized_field_name(name))?;
+                Some(SnippetGenerator::create(searcher, &*query, field).map(|generator| (name.clone(), generator)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // Stable output
+        generators.sort_by(|(a, _), (b, _)| a.cmp(b));
+        Ok(Highlighter { generators })
+    }
+
+    pub fn highlight(&self, doc: &Document) -> Vec<Highlight> {
+        self.generators
+            .iter()
+            .filter_map(|(field, generator)| {
+                let snippet = generator.snippet_from_doc(doc);
+                if snippet.highlighted().is_empty() {
+                    None
+                } else {
+                    Some(Highlight {
+                        field: field.clone(),
+                        snippet: snippet.to_html(),
+                    })
+                }
+            })
+            .collect()
+    }
+}
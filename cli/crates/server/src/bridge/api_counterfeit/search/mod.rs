@@ -1,21 +1,30 @@
 #![allow(clippy::module_name_repetitions)]
 pub mod cursor;
+pub mod facets;
+pub mod highlighter;
 pub mod paginated_searcher;
 pub mod protocol;
 pub mod query;
 pub mod query_builder;
 mod tokenizer;
 pub mod utils;
+pub mod vector_index;
 
 pub use cursor::Cursor;
-pub use paginated_searcher::TopDocsPaginatedSearcher;
+pub use facets::FacetCounter;
+pub use highlighter::Highlighter;
+pub use paginated_searcher::{SortBy, TopDocsPaginatedSearcher};
 pub use protocol::{Hit, Info, PaginatedHits, Pagination, QueryRequest, QueryResponse};
-pub use query::Query;
+pub use query::{Query, VectorRanking};
 pub use query_builder::TantivyQueryBuilder;
 pub use runtime::search::{FieldType, Schema};
+use std::collections::HashMap;
+
+use tantivy::{collector::DocSetCollector, query::AllQuery, schema::Value};
 use tantivy::{directory::RamDirectory, Index};
-use tantivy::{store::Compressor, tokenizer::TokenizerManager, IndexSettings};
+use tantivy::{store::Compressor, IndexSettings};
 pub use utils::ID_FIELD;
+pub use vector_index::VectorIndex;
 
 #[derive(thiserror::Error, Debug, serde::Serialize, serde::Deserialize)]
 pub enum QueryError {
@@ -48,16 +57,11 @@ pub enum BadRequestError {
 
 pub type SearchResult<T> = Result<T, QueryError>;
 pub fn open_index(schema: &Schema) -> tantivy::Result<(Index, Vec<IndexedField>)> {
-    use tokenizer::{simple_normalized_tokenizer, TOKENIZER_NAME};
     use utils::{to_tantivy, tokenized_field_name};
 
     let index = Index::builder()
         .schema(to_tantivy(schema))
-        .tokenizers({
-            let tokenizers = TokenizerManager::default();
-            tokenizers.register(TOKENIZER_NAME, simple_normalized_tokenizer());
-            tokenizers
-        })
+        .tokenizers(tokenizer::tokenizers())
         .settings(IndexSettings {
             docstore_compression: Compressor::None,
             ..Default::default()
@@ -80,6 +84,48 @@ pub fn open_index(schema: &Schema) -> tantivy::Result<(Index, Vec<IndexedField>)
     Ok((index, fields))
 }
 
+/// Nearest neighbours indices of the vector fields, built from their stored values.
+pub fn open_vector_indices(index: &Index, schema: &Schema) -> tantivy::Result<HashMap<String, VectorIndex>> {
+    let tantivy_schema = index.schema();
+    let mut indices = schema
+        .fields
+        .iter()
+        .filter_map(|(name, entry)| match &entry.ty {
+            FieldType::Vector(options) => Some((
+                name.clone(),
+                (
+                    tantivy_schema.get_field(name)?,
+                    VectorIndex::new(options.dimensions as usize),
+                ),
+            )),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+    if indices.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let searcher = index.reader()?.searcher();
+    let mut doc_addresses = searcher
+        .search(&AllQuery, &DocSetCollector)?
+        .into_iter()
+        .collect::<Vec<_>>();
+    // Deterministic insertion order
+    doc_addresses.sort_unstable();
+    for doc_address in doc_addresses {
+        let doc = searcher.doc(doc_address)?;
+        for (field, vector_index) in indices.values_mut() {
+            if let Some(Value::Bytes(bytes)) = doc.get_first(*field) {
+                vector_index.insert(doc_address, &vector_index::from_bytes(bytes));
+            }
+        }
+    }
+    Ok(indices
+        .into_iter()
+        .map(|(name, (_, vector_index))| (name, vector_index))
+        .collect())
+}
+
 #[derive(Debug, Clone)]
 pub struct IndexedField {
     pub name: String,
@@ -1,4 +1,4 @@
-use std::cmp;
+use std::cmp::{self, Ordering};
 use std::fmt::Debug;
 
 use integer_encoding::{VarIntReader, VarIntWriter};
@@ -6,6 +6,7 @@ use tantivy::{self, collector::Count, collector::TopDocs, schema::Field, Documen
 use tantivy::{DocAddress, Searcher};
 
 use super::{BadRequestError, Cursor, Hit, Info, PaginatedHits, QueryError, SearchResult};
+use runtime::search::SortDirection;
 
 type DocId = Vec<u8>;
 
@@ -55,23 +56,29 @@ impl<Id: From<DocId>> From<Match> for Hit<Id> {
             id: Id::from(doc_id.clone()),
             score,
             cursor: Cursor::from(SearchCursor { offset, doc_id }),
+            highlights: Vec::new(),
         }
     }
 }
 
+pub struct SortBy {
+    pub field: Field,
+    pub direction: SortDirection,
+}
+
 pub struct TopDocsPaginatedSearcher {
     pub searcher: Searcher,
     pub query: Box<dyn tantivy::query::Query>,
     pub id_field: Field,
     pub pagination_limit: usize,
+    pub sort: Option<SortBy>,
+    /// Hits ranked outside of tantivy, by relevance, replacing the query.
+    pub ranked: Option<Vec<(f32, DocAddress)>>,
 }
 
 impl TopDocsPaginatedSearcher {
     pub fn search_forward<Id: From<DocId> + Debug>(&self, first: usize) -> SearchResult<PaginatedHits<Id>> {
-        let (total_hits, matches) = self.searcher.search(
-            &self.query,
-            &(Count, TopDocs::with_limit(cmp::min(self.pagination_limit, first))),
-        )?;
+        let (total_hits, matches) = self.top_docs(cmp::min(self.pagination_limit, first))?;
         matches
             .into_iter()
             .enumerate()
@@ -89,6 +96,7 @@ impl TopDocsPaginatedSearcher {
                         has_previous_page: false,
                         total_hits: total_hits as u64,
                     },
+                    facets: Vec::new(),
                 }
             })
     }
@@ -116,6 +124,7 @@ impl TopDocsPaginatedSearcher {
                         has_previous_page,
                         total_hits: total_hits as u64,
                     },
+                    facets: Vec::new(),
                 });
             }
             // increase by 50%
@@ -147,6 +156,7 @@ impl TopDocsPaginatedSearcher {
                         has_previous_page,
                         total_hits: total_hits as u64,
                     },
+                    facets: Vec::new(),
                 });
             }
             // Nothing left
@@ -158,6 +168,7 @@ impl TopDocsPaginatedSearcher {
                         has_next_page: false,
                         total_hits: total_hits as u64,
                     },
+                    facets: Vec::new(),
                 });
             }
             // increase by 50%
@@ -172,9 +183,7 @@ impl TopDocsPaginatedSearcher {
         forward: bool,
         limit: usize,
     ) -> SearchResult<(usize, Option<usize>, Vec<Hit<Id>>)> {
-        let (total_hits, matches) = self
-            .searcher
-            .search(&self.query, &(Count, TopDocs::with_limit(limit)))?;
+        let (total_hits, matches) = self.top_docs(limit)?;
         let matches = matches.into_iter().enumerate();
         let (cursor_offset, hits) = if forward {
             self.extract_hits_after_cursor(count, cursor, matches)?
@@ -184,6 +193,43 @@ impl TopDocsPaginatedSearcher {
         Ok((total_hits, cursor_offset, hits))
     }
 
+    /// Best matches and the total number of hits. If a sort is specified, all hits are loaded and
+    /// sorted by the stored value of the field, the relevance only breaking ties.
+    fn top_docs(&self, limit: usize) -> SearchResult<(usize, Vec<(f32, DocAddress)>)> {
+        let (total_hits, mut matches) = match (&self.ranked, &self.sort) {
+            (Some(ranked), _) => (ranked.len(), ranked.clone()),
+            (None, None) => {
+                return Ok(self
+                    .searcher
+                    .search(&self.query, &(Count, TopDocs::with_limit(limit)))?)
+            }
+            (None, Some(_)) => {
+                let total_hits = self.searcher.search(&self.query, &Count)?;
+                if total_hits == 0 {
+                    return Ok((0, Vec::new()));
+                }
+                (
+                    total_hits,
+                    self.searcher.search(&self.query, &TopDocs::with_limit(total_hits))?,
+                )
+            }
+        };
+        if let Some(sort) = &self.sort {
+            let mut values = matches
+                .into_iter()
+                .map(|(score, doc_address)| {
+                    let value = self.searcher.doc(doc_address)?.get_first(sort.field).cloned();
+                    Ok((value, (score, doc_address)))
+                })
+                .collect::<SearchResult<Vec<_>>>()?;
+            // Stable sort, keeping the relevance order for equal values.
+            values.sort_by(|(a, _), (b, _)| compare_sort_values(a.as_ref(), b.as_ref(), sort.direction));
+            matches = values.into_iter().map(|(_, top_doc)| top_doc).collect();
+        }
+        matches.truncate(limit);
+        Ok((total_hits, matches))
+    }
+
     fn extract_hits_after_cursor<Id: From<DocId> + Debug, I: IntoIterator<Item = (usize, (f32, DocAddress))>>(
         &self,
         count: usize,
@@ -219,3 +265,27 @@ fn get_document_id(doc: &Document, id_field: Field) -> SearchResult<DocId> {
         x => Err(format!("Unexpected data for 'id': {x:?}").into()),
     }
 }
+
+/// Documents without any value always come last.
+fn compare_sort_values(
+    a: Option<&tantivy::schema::Value>,
+    b: Option<&tantivy::schema::Value>,
+    direction: SortDirection,
+) -> Ordering {
+    use tantivy::schema::Value::{Date, Str, F64, I64};
+
+    let ordering = match (a, b) {
+        (None, None) => return Ordering::Equal,
+        (None, Some(_)) => return Ordering::Greater,
+        (Some(_), None) => return Ordering::Less,
+        (Some(Str(a)), Some(Str(b))) => a.cmp(b),
+        (Some(I64(a)), Some(I64(b))) => a.cmp(b),
+        (Some(F64(a)), Some(F64(b))) => a.total_cmp(b),
+        (Some(Date(a)), Some(Date(b))) => a.cmp(b),
+        _ => Ordering::Equal,
+    };
+    match direction {
+        SortDirection::Ascending => ordering,
+        SortDirection::Descending => ordering.reverse(),
+    }
+}
@@ -1,3 +1,4 @@
+use runtime::search::{Facet, FacetCounts, Highlight, Sort};
 use serde::{Deserialize, Serialize};
 use ulid::Ulid;
 
@@ -10,6 +11,12 @@ pub struct QueryRequest {
     #[serde(rename = "entity_type")]
     pub index: String,
     pub database: String,
+    #[serde(default)]
+    pub facets: Vec<Facet>,
+    #[serde(default)]
+    pub highlight: bool,
+    #[serde(default)]
+    pub sort: Option<Sort>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -27,21 +34,31 @@ pub enum Pagination {
 pub struct PaginatedHits<Id> {
     pub hits: Vec<Hit<Id>>,
     pub info: Info,
+    pub facets: Vec<FacetCounts>,
 }
 
 impl<T> PaginatedHits<T> {
     pub fn map_id<U, F: Fn(T) -> U>(self, f: F) -> PaginatedHits<U> {
-        let PaginatedHits { hits, info } = self;
+        let PaginatedHits { hits, info, facets } = self;
         PaginatedHits {
             hits: hits
                 .into_iter()
-                .map(|Hit { id, cursor, score }| Hit {
-                    id: f(id),
-                    cursor,
-                    score,
-                })
+                .map(
+                    |Hit {
+                         id,
+                         cursor,
+                         score,
+                         highlights,
+                     }| Hit {
+                        id: f(id),
+                        cursor,
+                        score,
+                        highlights,
+                    },
+                )
                 .collect(),
             info,
+            facets,
         }
     }
 }
@@ -58,4 +75,5 @@ pub struct Hit<Id> {
     pub id: Id,
     pub cursor: Cursor,
     pub score: f32,
+    pub highlights: Vec<Highlight>,
 }
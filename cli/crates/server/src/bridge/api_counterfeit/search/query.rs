@@ -9,13 +9,117 @@ pub enum Query {
     Intersection(Vec<Query>),
     Union(Vec<Query>),
     Not(Box<Query>),
-    Range { field: String, range: Range<ScalarValue> },
-    In { field: String, values: Vec<ScalarValue> },
-    Regex { field: String, pattern: String },
+    Range {
+        field: String,
+        range: Range<ScalarValue>,
+    },
+    In {
+        field: String,
+        values: Vec<ScalarValue>,
+    },
+    Regex {
+        field: String,
+        pattern: String,
+    },
     All,
     Empty,
-    IsNull { field: String },
-    Text { value: String, fields: Option<Vec<String>> },
+    IsNull {
+        field: String,
+    },
+    Text {
+        value: String,
+        fields: Option<Vec<String>>,
+        #[serde(default)]
+        fuzziness: Option<u8>,
+    },
+    Vector {
+        field: String,
+        vector: Vec<f32>,
+        k: u32,
+    },
+    Hybrid {
+        text: Box<Query>,
+        vector: Box<Query>,
+        vector_weight: f32,
+    },
+}
+
+/// Nearest neighbours part of a query, which isn't executed by tantivy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorRanking {
+    pub field: String,
+    pub vector: Vec<f32>,
+    pub k: u32,
+    /// Text query and weight of the vector similarity for hybrid queries.
+    pub hybrid: Option<(Query, f32)>,
+}
+
+impl Query {
+    /// Separates the vector query, if any, from the filter. Vector queries can only be used at the
+    /// top level or in a top level intersection.
+    pub fn split_vector_ranking(self) -> Result<(Query, Option<VectorRanking>), String> {
+        let (filter, ranking) = match self {
+            Query::Intersection(queries) => {
+                let mut ranking = None;
+                let mut filters = Vec::with_capacity(queries.len());
+                for query in queries {
+                    match query {
+                        query @ (Query::Vector { .. } | Query::Hybrid { .. }) if ranking.is_none() => {
+                            ranking = Some(VectorRanking::try_from(query)?);
+                        }
+                        query => filters.push(query),
+                    }
+                }
+                let filter = match filters.len() {
+                    0 => Query::All,
+                    1 => filters.pop().expect("Has one element"),
+                    _ => Query::Intersection(filters),
+                };
+                (filter, ranking)
+            }
+            query @ (Query::Vector { .. } | Query::Hybrid { .. }) => {
+                (Query::All, Some(VectorRanking::try_from(query)?))
+            }
+            query => (query, None),
+        };
+        if filter.contains_vector_query() {
+            return Err("Vector queries can only be combined with filters.".to_string());
+        }
+        Ok((filter, ranking))
+    }
+
+    fn contains_vector_query(&self) -> bool {
+        match self {
+            Query::Vector { .. } | Query::Hybrid { .. } => true,
+            Query::Intersection(queries) | Query::Union(queries) => queries.iter().any(Query::contains_vector_query),
+            Query::Not(query) => query.contains_vector_query(),
+            _ => false,
+        }
+    }
+}
+
+impl TryFrom<Query> for VectorRanking {
+    type Error = String;
+
+    fn try_from(query: Query) -> Result<Self, Self::Error> {
+        match query {
+            Query::Vector { field, vector, k } => Ok(VectorRanking {
+                field,
+                vector,
+                k,
+                hybrid: None,
+            }),
+            Query::Hybrid {
+                text,
+                vector,
+                vector_weight,
+            } if !text.contains_vector_query() => Ok(VectorRanking {
+                hybrid: Some((*text, vector_weight)),
+                ..VectorRanking::try_from(*vector)?
+            }),
+            query => Err(format!("Unexpected vector query: {query:?}")),
+        }
+    }
 }
 
 impl Not for Query {
@@ -25,6 +25,7 @@ pub struct TantivyQueryBuilder<'a> {
     index: &'a Index,
     schema: &'a Schema,
     typo_tolerance: TypoTolerance,
+    exact_terms: bool,
 }
 
 pub struct TypoTolerance {
@@ -59,6 +60,16 @@ impl<'a> TantivyQueryBuilder<'a> {
             index,
             schema,
             typo_tolerance: TypoTolerance::default(),
+            exact_terms: false,
+        }
+    }
+
+    /// Only matches the exact terms, ignoring any typo tolerance. Fuzzy queries don't expose
+    /// the terms they match which is needed to generate snippets.
+    pub(crate) fn exact_terms(self) -> Self {
+        Self {
+            exact_terms: true,
+            ..self
         }
     }
 
@@ -143,7 +154,15 @@ impl<'a> TantivyQueryBuilder<'a> {
             }
             Query::All => Box::new(AllQuery),
             Query::Empty => Box::new(EmptyQuery),
-            Query::Text { value, fields } => self.build_text_query(&value, fields)?,
+            Query::Text {
+                value,
+                fields,
+                fuzziness,
+            } => self.build_text_query(&value, fields, fuzziness)?,
+            // Ranked with the nearest neighbours index, see `Query::split_vector_ranking`.
+            query @ (Query::Vector { .. } | Query::Hybrid { .. }) => {
+                return Err(format!("Unexpected vector query: {query:?}").into());
+            }
             Query::IsNull { field } => {
                 if self.is_nullable_field(&field)? {
                     Box::new(BooleanQuery::new(vec![
@@ -164,7 +183,12 @@ impl<'a> TantivyQueryBuilder<'a> {
         })
     }
 
-    fn build_text_query(&self, text: &str, field_names: Option<Vec<String>>) -> SearchResult<Box<dyn TantivyQuery>> {
+    fn build_text_query(
+        &self,
+        text: &str,
+        field_names: Option<Vec<String>>,
+        fuzziness: Option<u8>,
+    ) -> SearchResult<Box<dyn TantivyQuery>> {
         let field_names = field_names.unwrap_or_else(|| {
             self.schema
                 .fields
@@ -207,23 +231,23 @@ impl<'a> TantivyQueryBuilder<'a> {
                             (0, _) => (),
                             (1, _) => {
                                 for (_, term) in terms_with_offset {
-                                    subqueries.push(self.build_term_query(term));
+                                    subqueries.push(self.build_term_query(term, fuzziness));
                                 }
                             }
                             (_, Text::Word(word)) => {
                                 // Adding full word query to boost any document with close/exact/phrase match.
                                 subqueries.push(Box::new(BoostQuery::new(
                                     Box::new(DisjunctionMaxQuery::new(vec![
-                                        self.build_term_query(Term::from_field_text(
-                                            self.get_field(&field_name)?,
-                                            word,
-                                        )),
+                                        self.build_term_query(
+                                            Term::from_field_text(self.get_field(&field_name)?, word),
+                                            fuzziness,
+                                        ),
                                         Box::new(PhraseQuery::new_with_offset(terms_with_offset.clone())),
                                     ])),
                                     2.0,
                                 )));
                                 for (_, term) in terms_with_offset {
-                                    subqueries.push(self.build_term_query(term));
+                                    subqueries.push(self.build_term_query(term, fuzziness));
                                 }
                             }
                             _ => subqueries.push(Box::new(PhraseQuery::new_with_offset(terms_with_offset))),
@@ -232,7 +256,7 @@ impl<'a> TantivyQueryBuilder<'a> {
                 }
                 Some(FieldType::PhoneNumber { .. }) => {
                     let term = Term::from_field_text(self.get_field(&field_name)?, text);
-                    subqueries.push(self.build_term_query(term));
+                    subqueries.push(self.build_term_query(term, fuzziness));
                 }
                 // Shouldn't happen unless gateway validation didn't do its job correctly
                 ty => {
@@ -244,13 +268,21 @@ impl<'a> TantivyQueryBuilder<'a> {
         Ok(Box::new(BooleanQuery::union(subqueries)))
     }
 
-    fn build_term_query(&self, term: Term) -> Box<dyn TantivyQuery> {
+    fn build_term_query(&self, term: Term, fuzziness: Option<u8>) -> Box<dyn TantivyQuery> {
         // TODO: This works well with languages using a latin alphabet as we're using the
         // AsciiFoldingFilter in our custom tokenizer. So all of those characters are mapped
         // to a single byte UTF8 byte (~ASCII). For other languages we'll just support more
         // typos than expected.
         let word_size = term.value_bytes().len();
-        let typos = self.typo_tolerance.supported_typos_for_word_size(word_size);
+        let typos = if self.exact_terms {
+            0
+        } else {
+            // Tantivy doesn't support more than 2 typos.
+            fuzziness.map_or_else(
+                || self.typo_tolerance.supported_typos_for_word_size(word_size),
+                |fuzziness| fuzziness.min(2),
+            )
+        };
         if typos > 0 {
             Box::new(FuzzyTermQuery::new(term, typos, true))
         } else {
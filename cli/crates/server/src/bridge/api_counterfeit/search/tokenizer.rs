@@ -1,8 +1,10 @@
 use std::mem;
 
+use runtime::search::Analyzer;
+use strum::IntoEnumIterator;
 use tantivy::tokenizer::{
-    AsciiFoldingFilter, BoxTokenStream, LowerCaser, RemoveLongFilter, SimpleTokenizer, TextAnalyzer, Token,
-    TokenFilter, TokenStream,
+    AsciiFoldingFilter, BoxTokenFilter, BoxTokenStream, Language, LowerCaser, RawTokenizer, RemoveLongFilter,
+    SimpleTokenizer, Stemmer, StopWordFilter, TextAnalyzer, Token, TokenFilter, TokenStream, TokenizerManager,
 };
 use unicode_normalization::UnicodeNormalization;
 
@@ -23,6 +25,52 @@ pub(super) fn simple_normalized_tokenizer() -> TextAnalyzer {
         .filter(AsciiFoldingFilter)
 }
 
+pub(super) fn tokenizer_name(analyzer: Analyzer) -> &'static str {
+    match analyzer {
+        Analyzer::Standard => TOKENIZER_NAME,
+        analyzer => analyzer.name(),
+    }
+}
+
+pub(super) fn tokenizers() -> TokenizerManager {
+    let tokenizers = TokenizerManager::default();
+    for analyzer in Analyzer::iter() {
+        let tokenizer = match analyzer {
+            Analyzer::Standard => simple_normalized_tokenizer(),
+            Analyzer::Keyword => TextAnalyzer::from(RawTokenizer),
+            Analyzer::Danish => language_tokenizer(Language::Danish),
+            Analyzer::Dutch => language_tokenizer(Language::Dutch),
+            Analyzer::English => language_tokenizer(Language::English),
+            Analyzer::Finnish => language_tokenizer(Language::Finnish),
+            Analyzer::French => language_tokenizer(Language::French),
+            Analyzer::German => language_tokenizer(Language::German),
+            Analyzer::Hungarian => language_tokenizer(Language::Hungarian),
+            Analyzer::Italian => language_tokenizer(Language::Italian),
+            Analyzer::Norwegian => language_tokenizer(Language::Norwegian),
+            Analyzer::Portuguese => language_tokenizer(Language::Portuguese),
+            Analyzer::Russian => language_tokenizer(Language::Russian),
+            Analyzer::Spanish => language_tokenizer(Language::Spanish),
+            Analyzer::Swedish => language_tokenizer(Language::Swedish),
+        };
+        tokenizers.register(tokenizer_name(analyzer), tokenizer);
+    }
+    tokenizers
+}
+
+fn language_tokenizer(language: Language) -> TextAnalyzer {
+    // Same as our default tokenizer, but stop words are removed and words stemmed before
+    // folding accents as the stemmers rely on them.
+    let mut filters: Vec<BoxTokenFilter> = vec![
+        RemoveLongFilter::limit(40).into(),
+        LowerCaser.into(),
+        UnicodeNormalizationFilter.into(),
+    ];
+    filters.extend(StopWordFilter::new(language).map(BoxTokenFilter::from));
+    filters.push(Stemmer::new(language).into());
+    filters.push(AsciiFoldingFilter.into());
+    TextAnalyzer::new(SimpleTokenizer, filters)
+}
+
 // Basically the same as AsciiFoldingFilter except we just normalize unicode.
 #[derive(Clone)]
 struct UnicodeNormalizationFilter;
@@ -1,7 +1,7 @@
 use tantivy::schema::{IndexRecordOption, Schema as TantivySchema, TextFieldIndexing, TextOptions};
 use tantivy::schema::{INDEXED, STORED, STRING};
 
-use super::tokenizer::TOKENIZER_NAME;
+use super::tokenizer::tokenizer_name;
 
 use super::{FieldType, Schema};
 
@@ -13,7 +13,9 @@ pub(crate) fn tokenized_field_name(name: &str) -> String {
 }
 
 pub(super) fn to_tantivy(schema: &Schema) -> TantivySchema {
-    use FieldType::{Boolean, Date, DateTime, Email, Float, IPAddress, Int, PhoneNumber, String, Timestamp, URL};
+    use FieldType::{
+        Boolean, Date, DateTime, Email, Float, IPAddress, Int, PhoneNumber, String, Timestamp, Vector, URL,
+    };
 
     let mut builder = TantivySchema::builder();
     builder.add_bytes_field(ID_FIELD, INDEXED | STORED);
@@ -23,25 +25,32 @@ pub(super) fn to_tantivy(schema: &Schema) -> TantivySchema {
                 // Storing the "raw" field directly avoiding any tokenization. This allows us
                 // to provide a sensible filter API. Otherwise filtering on a String "Hello
                 // world!" would end up filtering on the tokens ["hello", "world"].
-                builder.add_text_field(name, STRING);
+                // Stored to count terms facets and sort the hits.
+                builder.add_text_field(name, STRING | STORED);
                 builder.add_text_field(
                     &tokenized_field_name(name),
                     // equivalent to the standard TEXT with our tokenzier
-                    TextOptions::default().set_indexing_options(
-                        TextFieldIndexing::default()
-                            .set_tokenizer(TOKENIZER_NAME)
-                            .set_fieldnorms(true)
-                            .set_index_option(IndexRecordOption::WithFreqsAndPositions),
-                    ),
+                    TextOptions::default()
+                        .set_indexing_options(
+                            TextFieldIndexing::default()
+                                .set_tokenizer(tokenizer_name(entry.analyzer))
+                                .set_fieldnorms(true)
+                                .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+                        )
+                        // Stored to generate highlighted snippets.
+                        .set_stored(),
                 )
             }
             // There is little benefit to tokenize phone numbers currently.
-            PhoneNumber { .. } => builder.add_text_field(name, STRING),
-            Date { .. } | DateTime { .. } | Timestamp { .. } => builder.add_date_field(name, INDEXED),
-            Int { .. } => builder.add_i64_field(name, INDEXED),
-            Float { .. } => builder.add_f64_field(name, INDEXED),
+            PhoneNumber { .. } => builder.add_text_field(name, STRING | STORED),
+            // Stored to sort the hits.
+            Date { .. } | DateTime { .. } | Timestamp { .. } => builder.add_date_field(name, INDEXED | STORED),
+            Int { .. } => builder.add_i64_field(name, INDEXED | STORED),
+            Float { .. } => builder.add_f64_field(name, INDEXED | STORED),
             Boolean { .. } => builder.add_bool_field(name, INDEXED),
             IPAddress { .. } => builder.add_ip_addr_field(name, INDEXED),
+            // Only used to build the nearest neighbours index.
+            Vector { .. } => builder.add_bytes_field(name, STORED),
         };
     }
     builder.build()
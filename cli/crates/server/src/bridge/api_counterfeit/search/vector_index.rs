@@ -0,0 +1,393 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use tantivy::DocAddress;
+
+/// Maximum number of neighbours per node on the upper layers, twice as much on the bottom one.
+const MAX_NEIGHBOURS: usize = 16;
+const EF_CONSTRUCTION: usize = 100;
+/// Minimum number of candidates explored during a search, improving the recall for small `k`.
+const EF_SEARCH: usize = 64;
+
+/// Approximate nearest neighbours index (HNSW) over the vectors of a single field, comparing
+/// them by cosine similarity.
+///
+/// The search index is written once with a single commit, so document addresses are stable
+/// for its whole lifetime.
+#[derive(Debug)]
+pub struct VectorIndex {
+    dimensions: usize,
+    /// Normalized vectors, indexed by node.
+    vectors: Vec<Vec<f32>>,
+    docs: Vec<DocAddress>,
+    nodes: HashMap<DocAddress, u32>,
+    /// Neighbours of each node for every layer it belongs to, starting with the bottom one.
+    neighbours: Vec<Vec<Vec<u32>>>,
+    entry_point: Option<u32>,
+    /// Simple LCG, the index is built deterministically.
+    seed: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Candidate {
+    similarity: f32,
+    node: u32,
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.similarity
+            .total_cmp(&other.similarity)
+            .then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+impl VectorIndex {
+    pub fn new(dimensions: usize) -> Self {
+        Self {
+            dimensions,
+            vectors: Vec::new(),
+            docs: Vec::new(),
+            nodes: HashMap::new(),
+            neighbours: Vec::new(),
+            entry_point: None,
+            seed: 0x2545_f491_4f6c_dd1d,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.vectors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vectors.is_empty()
+    }
+
+    // cast_possible_truncation: Complains about usize -> u32, an index never gets that big.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn insert(&mut self, doc: DocAddress, vector: &[f32]) {
+        debug_assert_eq!(vector.len(), self.dimensions);
+        let node = self.vectors.len() as u32;
+        let level = self.random_level();
+        self.vectors.push(normalize(vector));
+        self.docs.push(doc);
+        self.nodes.insert(doc, node);
+        self.neighbours.push(vec![Vec::new(); level + 1]);
+
+        let Some(mut entry_point) = self.entry_point else {
+            self.entry_point = Some(node);
+            return;
+        };
+        let top_level = self.level(entry_point);
+        let query = self.vectors[node as usize].clone();
+        for layer in ((level + 1)..=top_level).rev() {
+            entry_point = self.greedy_search(&query, entry_point, layer);
+        }
+        let mut entry_points = vec![entry_point];
+        for layer in (0..=level.min(top_level)).rev() {
+            let candidates = self.search_layer(&query, &entry_points, EF_CONSTRUCTION, layer);
+            let max_neighbours = max_neighbours(layer);
+            let selected = candidates
+                .iter()
+                .take(max_neighbours)
+                .map(|candidate| candidate.node)
+                .collect::<Vec<_>>();
+            for &neighbour in &selected {
+                self.connect(neighbour, node, layer);
+            }
+            self.neighbours[node as usize][layer] = selected;
+            entry_points = candidates.into_iter().map(|candidate| candidate.node).collect();
+        }
+        if level > top_level {
+            self.entry_point = Some(node);
+        }
+    }
+
+    /// The `k` nearest documents with their cosine similarity, scaled from [-1, 1] to [0, 1]. If a filter is
+    /// specified, only its documents are considered.
+    pub fn search(&self, vector: &[f32], k: usize, filter: Option<&HashSet<DocAddress>>) -> Vec<(f32, DocAddress)> {
+        let query = normalize(vector);
+        let k = filter.map_or(k, |filter| k.min(filter.len()));
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut candidates = match filter {
+            // Exploring the graph would mostly visit filtered out documents.
+            Some(filter) if filter.len() <= EF_SEARCH.max(k) => self.exact_search(&query, filter),
+            _ => {
+                let candidates = self.approximate_search(&query, EF_SEARCH.max(k) * 2);
+                let candidates = candidates
+                    .into_iter()
+                    .filter(|candidate| match filter {
+                        Some(filter) => filter.contains(&self.docs[candidate.node as usize]),
+                        None => true,
+                    })
+                    .collect::<Vec<_>>();
+                match filter {
+                    // Too many matches were filtered out.
+                    Some(filter) if candidates.len() < k => self.exact_search(&query, filter),
+                    _ => candidates,
+                }
+            }
+        };
+        candidates.truncate(k);
+        candidates
+            .into_iter()
+            .map(|Candidate { similarity, node }| (similarity.mul_add(0.5, 0.5), self.docs[node as usize]))
+            .collect()
+    }
+
+    /// Similarity of a document, scaled like the search results, if it has a vector.
+    pub fn similarity(&self, vector: &[f32], doc: DocAddress) -> Option<f32> {
+        let node = *self.nodes.get(&doc)?;
+        Some(self.candidate(&normalize(vector), node).similarity.mul_add(0.5, 0.5))
+    }
+
+    fn approximate_search(&self, query: &[f32], ef: usize) -> Vec<Candidate> {
+        let Some(mut entry_point) = self.entry_point else {
+            return Vec::new();
+        };
+        for layer in (1..=self.level(entry_point)).rev() {
+            entry_point = self.greedy_search(query, entry_point, layer);
+        }
+        self.search_layer(query, &[entry_point], ef, 0)
+    }
+
+    fn exact_search(&self, query: &[f32], filter: &HashSet<DocAddress>) -> Vec<Candidate> {
+        let mut candidates = filter
+            .iter()
+            .filter_map(|doc| self.nodes.get(doc))
+            .map(|&node| self.candidate(query, node))
+            .collect::<Vec<_>>();
+        candidates.sort_unstable_by(|a, b| b.cmp(a));
+        candidates
+    }
+
+    fn greedy_search(&self, query: &[f32], entry_point: u32, layer: usize) -> u32 {
+        let mut best = self.candidate(query, entry_point);
+        loop {
+            let next = self.neighbours[best.node as usize][layer]
+                .iter()
+                .map(|&neighbour| self.candidate(query, neighbour))
+                .max()
+                .filter(|candidate| candidate.similarity > best.similarity);
+            match next {
+                Some(candidate) => best = candidate,
+                None => break best.node,
+            }
+        }
+    }
+
+    /// Best `ef` candidates of a layer, from the most to the least similar.
+    fn search_layer(&self, query: &[f32], entry_points: &[u32], ef: usize, layer: usize) -> Vec<Candidate> {
+        let mut visited = entry_points.iter().copied().collect::<HashSet<_>>();
+        let mut candidates = entry_points
+            .iter()
+            .map(|&node| self.candidate(query, node))
+            .collect::<BinaryHeap<_>>();
+        let mut results = candidates.iter().copied().map(Reverse).collect::<BinaryHeap<_>>();
+        while results.len() > ef {
+            results.pop();
+        }
+
+        while let Some(candidate) = candidates.pop() {
+            if results.len() >= ef && results.peek().is_some_and(|Reverse(worst)| candidate < *worst) {
+                break;
+            }
+            for &neighbour in &self.neighbours[candidate.node as usize][layer] {
+                if !visited.insert(neighbour) {
+                    continue;
+                }
+                let neighbour = self.candidate(query, neighbour);
+                if results.len() < ef || results.peek().is_some_and(|Reverse(worst)| neighbour > *worst) {
+                    candidates.push(neighbour);
+                    results.push(Reverse(neighbour));
+                    if results.len() > ef {
+                        results.pop();
+                    }
+                }
+            }
+        }
+
+        let mut results = results
+            .into_iter()
+            .map(|Reverse(candidate)| candidate)
+            .collect::<Vec<_>>();
+        results.sort_unstable_by(|a, b| b.cmp(a));
+        results
+    }
+
+    /// Adds a link from `node` to `neighbour`, keeping only the closest ones if there are too many.
+    fn connect(&mut self, node: u32, neighbour: u32, layer: usize) {
+        let mut neighbours = std::mem::take(&mut self.neighbours[node as usize][layer]);
+        neighbours.push(neighbour);
+        if neighbours.len() > max_neighbours(layer) {
+            let vector = &self.vectors[node as usize];
+            let mut candidates = neighbours
+                .into_iter()
+                .map(|neighbour| self.candidate(vector, neighbour))
+                .collect::<Vec<_>>();
+            candidates.sort_unstable_by(|a, b| b.cmp(a));
+            neighbours = candidates
+                .into_iter()
+                .take(max_neighbours(layer))
+                .map(|candidate| candidate.node)
+                .collect();
+        }
+        self.neighbours[node as usize][layer] = neighbours;
+    }
+
+    fn candidate(&self, query: &[f32], node: u32) -> Candidate {
+        Candidate {
+            similarity: dot(query, &self.vectors[node as usize]),
+            node,
+        }
+    }
+
+    fn level(&self, node: u32) -> usize {
+        self.neighbours[node as usize].len() - 1
+    }
+
+    /// Exponentially decaying level, with a probability of 1 / `MAX_NEIGHBOURS` to go up a layer.
+    // cast_precision_loss: Only the 53 most significant bits are used, as much as a f64 can hold.
+    // cast_possible_truncation, cast_sign_loss: The level is always a small positive number.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    fn random_level(&mut self) -> usize {
+        self.seed = self
+            .seed
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        let uniform = ((self.seed >> 11) as f64 + 1.0) / (1u64 << 53) as f64;
+        (-uniform.ln() / (MAX_NEIGHBOURS as f64).ln()).floor() as usize
+    }
+}
+
+fn max_neighbours(layer: usize) -> usize {
+    if layer == 0 {
+        MAX_NEIGHBOURS * 2
+    } else {
+        MAX_NEIGHBOURS
+    }
+}
+
+fn normalize(vector: &[f32]) -> Vec<f32> {
+    let norm = dot(vector, vector).sqrt();
+    if norm > 0.0 {
+        vector.iter().map(|value| value / norm).collect()
+    } else {
+        vector.to_vec()
+    }
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+pub(crate) fn to_bytes(vector: &[f32]) -> Vec<u8> {
+    vector.iter().flat_map(|value| value.to_le_bytes()).collect()
+}
+
+pub(crate) fn from_bytes(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // cast_precision_loss: Only the 24 most significant bits are used.
+    #[allow(clippy::cast_precision_loss)]
+    fn random_vectors(count: usize, dimensions: usize) -> Vec<Vec<f32>> {
+        let mut seed: u64 = 42;
+        (0..count)
+            .map(|_| {
+                (0..dimensions)
+                    .map(|_| {
+                        seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+                        (seed >> 40) as f32 / (1u64 << 24) as f32 - 0.5
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    // cast_possible_truncation: Complains about usize -> u32 for test document ids.
+    #[allow(clippy::cast_possible_truncation)]
+    fn doc(id: usize) -> DocAddress {
+        DocAddress::new(0, id as u32)
+    }
+
+    fn brute_force<'a>(
+        vectors: impl Iterator<Item = (usize, &'a Vec<f32>)>,
+        query: &[f32],
+        k: usize,
+    ) -> Vec<DocAddress> {
+        let query = normalize(query);
+        let mut similarities = vectors
+            .map(|(id, vector)| (dot(&query, &normalize(vector)), doc(id)))
+            .collect::<Vec<_>>();
+        similarities.sort_unstable_by(|(a, _), (b, _)| b.total_cmp(a));
+        similarities.into_iter().take(k).map(|(_, doc)| doc).collect()
+    }
+
+    // cast_precision_loss: Recall computation, small numbers only.
+    #[allow(clippy::cast_precision_loss)]
+    #[test]
+    fn recall_against_brute_force() {
+        let vectors = random_vectors(2000, 16);
+        let mut index = VectorIndex::new(16);
+        for (id, vector) in vectors.iter().enumerate() {
+            index.insert(doc(id), vector);
+        }
+        assert_eq!(index.len(), 2000);
+
+        let mut found = 0;
+        for query in random_vectors(20, 16) {
+            let expected = brute_force(vectors.iter().enumerate(), &query, 10);
+            let hits = index.search(&query, 10, None);
+            assert_eq!(hits.len(), 10);
+            assert!(hits.windows(2).all(|pair| pair[0].0 >= pair[1].0));
+            found += hits.iter().filter(|(_, doc)| expected.contains(doc)).count();
+        }
+        let recall = found as f32 / 200.0;
+        assert!(recall > 0.9, "recall: {recall}");
+    }
+
+    #[test]
+    fn filtered_search_is_exact() {
+        let vectors = random_vectors(500, 8);
+        let mut index = VectorIndex::new(8);
+        for (id, vector) in vectors.iter().enumerate() {
+            index.insert(doc(id), vector);
+        }
+
+        let filter = (0..500).step_by(10).map(doc).collect::<HashSet<_>>();
+        let query = &random_vectors(1, 8)[0];
+        let hits = index.search(query, 5, Some(&filter));
+        let expected = brute_force(vectors.iter().enumerate().step_by(10), query, 5);
+        assert_eq!(hits.into_iter().map(|(_, doc)| doc).collect::<Vec<_>>(), expected);
+
+        assert!(index.search(query, 5, Some(&HashSet::new())).is_empty());
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let vector = vec![0.5, -1.25, 3.0];
+        assert_eq!(from_bytes(&to_bytes(&vector)), vector);
+    }
+}
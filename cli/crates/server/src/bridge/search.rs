@@ -5,16 +5,20 @@ use futures_util::TryStreamExt;
 use serde_json::Value;
 use sqlx::SqlitePool;
 
-use tantivy::schema::Field;
-use tantivy::Document;
+use tantivy::collector::{Count, DocSetCollector, TopDocs};
+use tantivy::query::TermQuery;
+use tantivy::schema::{Field, IndexRecordOption};
+use tantivy::{DocAddress, Document, Searcher, Term};
 use ulid::Ulid;
 
+use std::collections::{HashMap, HashSet};
 use std::net::{IpAddr, Ipv6Addr};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
+use super::api_counterfeit::search::vector_index;
 use super::api_counterfeit::search::{
-    self, PaginatedHits, Pagination, Query, QueryError, QueryRequest, QueryResponse, TantivyQueryBuilder,
-    TopDocsPaginatedSearcher,
+    self, FacetCounter, Highlighter, PaginatedHits, Pagination, Query, QueryError, QueryRequest, QueryResponse, SortBy,
+    TantivyQueryBuilder, TopDocsPaginatedSearcher, VectorIndex, VectorRanking,
 };
 use super::errors::ApiError;
 use super::server::HandlerState;
@@ -24,26 +28,102 @@ const DATE_FORMAT: &str = "%Y-%m-%d";
 const DOCUMENT_FIELD_CREATED_AT: &str = "__created_at";
 const DOCUMENT_FIELD_UPDATED_AT: &str = "__updated_at";
 
+/// Built indices, only rebuilt when the schema or the records of their entity type change.
+#[derive(Default)]
+pub struct SearchIndexCache(Mutex<HashMap<String, CachedIndex>>);
+
+struct CachedIndex {
+    schema_digest: String,
+    records_version: RecordsVersion,
+    index: tantivy::Index,
+    vectors: Arc<VectorIndices>,
+}
+
+/// Nearest neighbours index of each vector field.
+type VectorIndices = HashMap<String, VectorIndex>;
+
+/// Number of records and last update, changing whenever a record is created, updated or deleted.
+type RecordsVersion = (i64, Option<String>);
+
+impl SearchIndexCache {
+    fn get(
+        &self,
+        entity_type: &str,
+        schema_digest: &str,
+        records_version: &RecordsVersion,
+    ) -> Option<(tantivy::Index, Arc<VectorIndices>)> {
+        self.0
+            .lock()
+            .unwrap()
+            .get(entity_type)
+            .filter(|cached| cached.schema_digest == schema_digest && cached.records_version == *records_version)
+            .map(|cached| (cached.index.clone(), cached.vectors.clone()))
+    }
+
+    fn insert(&self, entity_type: &str, cached: CachedIndex) {
+        self.0.lock().unwrap().insert(entity_type.to_string(), cached);
+    }
+}
+
 pub struct Index<'a> {
     inner: tantivy::Index,
     schema: &'a runtime::search::Schema,
     id_field: Field,
+    vectors: Arc<VectorIndices>,
 }
 
 impl<'a> Index<'a> {
     // needless_pass_by_value: Complains about pagination argument which has no other purpose anyway
     // cast_possible_truncation: Complains about u64 -> usize, which shouldn't matter for anything sensible.
     #[allow(clippy::needless_pass_by_value, clippy::cast_possible_truncation)]
-    pub fn search(&self, query: Query, pagination: Pagination) -> Result<PaginatedHits<Ulid>, QueryError> {
+    pub fn search(&self, request: QueryRequest) -> Result<PaginatedHits<Ulid>, QueryError> {
+        let QueryRequest {
+            query,
+            pagination,
+            facets,
+            highlight,
+            sort,
+            ..
+        } = request;
         trace!("Executing query: {query:?}");
-        let query = TantivyQueryBuilder::new(&self.inner, self.schema).build(query)?;
+        let query_builder = TantivyQueryBuilder::new(&self.inner, self.schema);
+        let tantivy_searcher = self.inner.reader()?.searcher();
+        // Vector queries are ranked with the nearest neighbours index, facets are counted on the
+        // ranked hits and highlights rely on the text query.
+        let (query, ranking) = query.split_vector_ranking()?;
+        let highlight_query = match ranking.as_ref().and_then(|ranking| ranking.hybrid.as_ref()) {
+            Some((text, _)) => Query::Intersection(vec![query.clone(), text.clone()]),
+            None => query.clone(),
+        };
+        let ranked = ranking
+            .map(|ranking| self.rank(&tantivy_searcher, &query_builder, &query, ranking))
+            .transpose()?;
+        let hits_set = ranked.as_ref().map(|ranked| {
+            ranked
+                .iter()
+                .map(|(_, doc_address)| *doc_address)
+                .collect::<HashSet<_>>()
+        });
         let searcher = TopDocsPaginatedSearcher {
-            searcher: self.inner.reader()?.searcher(),
-            query,
+            searcher: tantivy_searcher,
+            query: query_builder.build(query.clone())?,
             id_field: self.id_field,
             pagination_limit: 1000,
+            sort: sort
+                .map(|sort| {
+                    self.inner
+                        .schema()
+                        .get_field(&sort.field)
+                        .map(|field| SortBy {
+                            field,
+                            direction: sort.direction,
+                        })
+                        .ok_or_else(|| format!("Unknown field: '{}'", sort.field))
+                })
+                .transpose()?,
+            ranked,
         };
-        let hits: PaginatedHits<Vec<u8>> = match pagination {
+        let mut hits: PaginatedHits<Vec<u8>> = match pagination {
             Pagination::Forward { first, after: None } => searcher.search_forward(first as usize)?,
             Pagination::Forward {
                 first,
@@ -53,6 +133,32 @@ impl<'a> Index<'a> {
                 searcher.search_backward_before(last as usize, &before.try_into()?)?
             }
         };
+
+        if highlight {
+            let highlighter = Highlighter::new(
+                &searcher.searcher,
+                &TantivyQueryBuilder::new(&self.inner, self.schema).exact_terms(),
+                self.schema,
+                highlight_query,
+            )?;
+            for hit in &mut hits.hits {
+                if let Some(doc) = self.get_document(&searcher.searcher, &hit.id)? {
+                    hit.highlights = highlighter.highlight(&doc);
+                }
+            }
+        }
+
+        let facet_counter = FacetCounter {
+            searcher: &searcher.searcher,
+            query_builder: &query_builder,
+            query: &query,
+            hits: hits_set.as_ref(),
+        };
+        hits.facets = facets
+            .into_iter()
+            .map(|facet| facet_counter.count(facet))
+            .collect::<Result<_, _>>()?;
+
         Ok(hits.map_id(|id| {
             let mut id = String::from_utf8(id).unwrap();
             // removing the prefix 'post#<ulid>' from the id
@@ -61,8 +167,78 @@ impl<'a> Index<'a> {
         }))
     }
 
+    /// Nearest documents among those matching the filter. For hybrid queries, documents matching
+    /// the text are also included and all are ranked by the weighted sum of their normalized text
+    /// score and vector similarity.
+    // cast_possible_truncation: Complains about u32 -> usize, which shouldn't matter for anything sensible.
+    #[allow(clippy::cast_possible_truncation)]
+    fn rank(
+        &self,
+        searcher: &Searcher,
+        query_builder: &TantivyQueryBuilder<'_>,
+        filter: &Query,
+        VectorRanking {
+            field,
+            vector,
+            k,
+            hybrid,
+        }: VectorRanking,
+    ) -> Result<Vec<(f32, DocAddress)>, QueryError> {
+        let vector_index = self
+            .vectors
+            .get(&field)
+            .ok_or_else(|| format!("Unknown vector field: '{field}'"))?;
+        let k = k as usize;
+        let filter_docs = match filter {
+            Query::All => None,
+            filter => Some(searcher.search(&query_builder.build(filter.clone())?, &DocSetCollector)?),
+        };
+        let Some((text, vector_weight)) = hybrid else {
+            return Ok(vector_index.search(&vector, k, filter_docs.as_ref()));
+        };
+
+        let text_query = query_builder.build(Query::Intersection(vec![filter.clone(), text]))?;
+        let text_hits = match searcher.search(&text_query, &Count)? {
+            0 => Vec::new(),
+            total_hits => searcher.search(&text_query, &TopDocs::with_limit(total_hits))?,
+        };
+        let max_text_score = text_hits.iter().map(|(score, _)| *score).fold(0.0, f32::max);
+        let mut scores = HashMap::<DocAddress, f32>::new();
+        for (score, doc_address) in text_hits {
+            let text_score = if max_text_score > 0.0 {
+                score / max_text_score
+            } else {
+                0.0
+            };
+            let similarity = vector_index.similarity(&vector, doc_address).unwrap_or_default();
+            scores.insert(
+                doc_address,
+                (1.0 - vector_weight) * text_score + vector_weight * similarity,
+            );
+        }
+        for (similarity, doc_address) in vector_index.search(&vector, k, filter_docs.as_ref()) {
+            scores.entry(doc_address).or_insert(vector_weight * similarity);
+        }
+        let mut ranked = scores
+            .into_iter()
+            .map(|(doc_address, score)| (score, doc_address))
+            .collect::<Vec<_>>();
+        ranked.sort_unstable_by(|(a, a_doc), (b, b_doc)| b.total_cmp(a).then_with(|| a_doc.cmp(b_doc)));
+        ranked.truncate(k);
+        Ok(ranked)
+    }
+
+    fn get_document(&self, searcher: &Searcher, id: &[u8]) -> Result<Option<Document>, QueryError> {
+        let query = TermQuery::new(Term::from_field_bytes(self.id_field, id), IndexRecordOption::Basic);
+        let Some((_, doc_address)) = searcher.search(&query, &TopDocs::with_limit(1))?.into_iter().next() else {
+            return Ok(None);
+        };
+        Ok(Some(searcher.doc(doc_address)?))
+    }
+
     pub async fn build(
         pool: &SqlitePool,
+        cache: &SearchIndexCache,
         entity_type: &str,
         config: &'a runtime::search::Config,
     ) -> Result<Index<'a>, QueryError> {
@@ -75,49 +251,89 @@ impl<'a> Index<'a> {
             })?
             .schema;
 
-        trace!("Building index for {entity_type} and schema:\n{schema:?}");
-        let (index, fields) = search::open_index(schema)?;
-        let id_field = index.schema().get_field(search::ID_FIELD).unwrap();
-
-        let mut writer = index.writer_with_num_threads(1, 20_000_000)?;
-        // FIXME: GB-3636 Implement DynamoDB variant
-        let mut fut = sqlx::query_as(
+        let schema_digest = schema.digest();
+        let records_version: RecordsVersion = sqlx::query_as(
             r"
-        SELECT pk AS id, document
+        SELECT COUNT(*), MAX(updated_at)
         FROM records WHERE entity_type = $1 AND pk = sk
         ",
         )
         .bind(entity_type)
-        .fetch(pool);
-
-        let mut record_count: usize = 0;
-        while let Some::<RecordDocument>(record) = fut
-            .try_next()
-            .await
-            .map_err(|err| format!("Failed loading documents: {err:?}"))?
-        {
-            record_count += 1;
-            let mut doc = Document::default();
-            doc.add_bytes(id_field, record.id.as_bytes());
-            for field in &fields {
-                add_field(&mut doc, field, &record).map_err(|err| {
-                    error!("{:?} for record '{}' on field '{}'", err, &record.id, &field.name);
-                    QueryError::ServerError
-                })?;
-            }
-            writer.add_document(doc)?;
-        }
-        writer.commit()?;
-        trace!("Indexed {record_count} documents.");
+        .fetch_one(pool)
+        .await
+        .map_err(|err| format!("Failed loading documents: {err:?}"))?;
+
+        let (index, vectors) = if let Some(cached) = cache.get(entity_type, &schema_digest, &records_version) {
+            trace!("Re-using index for {entity_type}");
+            cached
+        } else {
+            let index = index_documents(pool, entity_type, schema).await?;
+            let vectors = Arc::new(search::open_vector_indices(&index, schema)?);
+            cache.insert(
+                entity_type,
+                CachedIndex {
+                    schema_digest,
+                    records_version,
+                    index: index.clone(),
+                    vectors: vectors.clone(),
+                },
+            );
+            (index, vectors)
+        };
+        let id_field = index.schema().get_field(search::ID_FIELD).unwrap();
 
         Ok(Index {
             inner: index,
             schema,
             id_field,
+            vectors,
         })
     }
 }
 
+async fn index_documents(
+    pool: &SqlitePool,
+    entity_type: &str,
+    schema: &runtime::search::Schema,
+) -> Result<tantivy::Index, QueryError> {
+    trace!("Building index for {entity_type} and schema:\n{schema:?}");
+    let (index, fields) = search::open_index(schema)?;
+    let id_field = index.schema().get_field(search::ID_FIELD).unwrap();
+
+    let mut writer = index.writer_with_num_threads(1, 20_000_000)?;
+    // FIXME: GB-3636 Implement DynamoDB variant
+    let mut fut = sqlx::query_as(
+        r"
+        SELECT pk AS id, document
+        FROM records WHERE entity_type = $1 AND pk = sk
+        ",
+    )
+    .bind(entity_type)
+    .fetch(pool);
+
+    let mut record_count: usize = 0;
+    while let Some::<RecordDocument>(record) = fut
+        .try_next()
+        .await
+        .map_err(|err| format!("Failed loading documents: {err:?}"))?
+    {
+        record_count += 1;
+        let mut doc = Document::default();
+        doc.add_bytes(id_field, record.id.as_bytes());
+        for field in &fields {
+            add_field(&mut doc, field, &record).map_err(|err| {
+                error!("{:?} for record '{}' on field '{}'", err, &record.id, &field.name);
+                QueryError::ServerError
+            })?;
+        }
+        writer.add_document(doc)?;
+    }
+    writer.commit()?;
+    trace!("Indexed {record_count} documents.");
+
+    Ok(index)
+}
+
 fn add_field(
     doc: &mut Document,
     search::IndexedField {
@@ -135,7 +351,7 @@ fn add_field(
     };
     if let Some(value) = document.get(document_field_name) {
         use runtime::search::FieldType::{
-            Boolean, Date, DateTime, Email, Float, IPAddress, Int, PhoneNumber, String, Timestamp, URL,
+            Boolean, Date, DateTime, Email, Float, IPAddress, Int, PhoneNumber, String, Timestamp, Vector, URL,
         };
         let field = *doc_key;
         match ty {
@@ -201,6 +417,24 @@ fn add_field(
                     doc.add_ip_addr(field, DynamoItemExt::to_ipaddr(value)?);
                 }
             }
+            Vector(options) => {
+                // cast_possible_truncation: Embeddings are stored as f32 like most vector databases.
+                #[allow(clippy::cast_possible_truncation)]
+                let vector = DynamoItemExt::flatten(value)
+                    .into_iter()
+                    .map(|value| DynamoItemExt::to_f64(value).map(|value| value as f32))
+                    .collect::<FieldResult<Vec<_>>>()?;
+                if vector.len() == options.dimensions as usize {
+                    doc.add_bytes(field, vector_index::to_bytes(&vector));
+                } else if !vector.is_empty() {
+                    // Not failing the whole search for a single invalid embedding.
+                    warn!(
+                        "Ignoring vector with {} dimensions instead of {} on field '{name}'",
+                        vector.len(),
+                        options.dimensions
+                    );
+                }
+            }
         };
     }
     Ok(())
@@ -313,11 +547,12 @@ pub async fn search_endpoint(
 ) -> Result<Json<QueryResponse>, ApiError> {
     let result = Index::build(
         &handler_state.pool,
+        &handler_state.search_indices,
         &request.index,
         &handler_state.registry.search_config,
     )
     .await
-    .and_then(|index| index.search(request.query, request.pagination));
+    .and_then(|index| index.search(request));
     Ok(Json(QueryResponse::V1(result)))
 }
 
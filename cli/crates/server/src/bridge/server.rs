@@ -3,7 +3,7 @@ use super::types::{Mutation, Operation, Record};
 use super::udf::UdfRuntime;
 use crate::bridge::errors::ApiError;
 use crate::bridge::log::log_event_endpoint;
-use crate::bridge::search::search_endpoint;
+use crate::bridge::search::{search_endpoint, SearchIndexCache};
 use crate::bridge::types::{Constraint, ConstraintKind, OperationKind};
 use crate::bridge::udf::invoke_udf_endpoint;
 use crate::errors::ServerError;
@@ -31,6 +31,7 @@ pub struct HandlerState {
     pub udf_runtime: UdfRuntime,
     pub tracing: bool,
     pub registry: Arc<engine::Registry>,
+    pub search_indices: SearchIndexCache,
 }
 
 async fn query_endpoint(
@@ -154,6 +155,7 @@ pub async fn build_router(
         udf_runtime,
         tracing,
         registry,
+        search_indices: SearchIndexCache::default(),
     });
 
     let router = Router::new()
@@ -10,6 +10,8 @@ pub async fn dump_config(cli_version: String) -> Result<String, ServerError> {
     let ParsingResponse {
         registry,
         detected_udfs: _,
+        wasm_resolvers: _,
+        telemetry: _,
         federated_graph_config: _,
     } = run_schema_parser(&env, None).await?;
 
@@ -190,6 +190,18 @@ pub enum ServerError {
 
     #[error("Error in gateway initialization: {0}")]
     GatewayError(String),
+
+    /// returned if the exporters of the `@telemetry` directive could not be started
+    #[error("could not start the telemetry export\nCaused by: {0}")]
+    Telemetry(#[from] telemetry::TelemetryError),
+
+    /// returned if the triggers behind the subscriptions of a Postgres connector could not be installed
+    #[error("could not install the subscription triggers of the Postgres connector '{0}'\nCaused by: {1}")]
+    SetupSubscriptions(String, postgres_connector_types::error::Error),
+
+    /// returned if a resolver compiled to WebAssembly could not be loaded
+    #[error(transparent)]
+    WasmResolver(#[from] runtime_wasm::WasmResolverError),
 }
 
 #[derive(Debug, Error)]
@@ -15,6 +15,8 @@ pub async fn introspect_local() -> Result<IntrospectLocalOutput, ServerError> {
     let ParsingResponse {
         registry,
         detected_udfs: _,
+        wasm_resolvers: _,
+        telemetry: _,
         federated_graph_config,
     } = run_schema_parser(&env, None).await?;
 
@@ -34,6 +34,7 @@ mod introspect_local;
 mod parser;
 mod proxy;
 mod servers;
+mod setup_subscriptions;
 mod udf_builder;
 
 pub mod errors;
@@ -42,3 +43,4 @@ pub mod types;
 pub use dump_config::dump_config;
 pub use introspect_local::{introspect_local, IntrospectLocalOutput};
 pub use servers::{export_embedded_files, start, ProductionServer};
+pub use setup_subscriptions::setup_subscriptions;
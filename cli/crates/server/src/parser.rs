@@ -3,7 +3,10 @@ use std::collections::{HashMap, HashSet};
 use common_types::UdfKind;
 use engine::registry::Registry;
 use itertools::Itertools;
-use parser_sdl::{GraphqlDirective, OpenApiDirective, ParseResult, PostgresDirective};
+use parser_sdl::{
+    GraphqlDirective, OpenApiDirective, ParseResult, PostgresDirective, TelemetryDirective, TelemetryExporter,
+    TelemetryProtocol,
+};
 use postgres_connector_types::transport::TcpTransport;
 
 use crate::errors::ServerError;
@@ -14,6 +17,7 @@ pub struct ParserResult {
     pub registry: Registry,
     pub required_udfs: HashSet<(UdfKind, String)>,
     pub federated_graph_config: Option<parser_sdl::federation::FederatedGraphConfig>,
+    pub telemetry: Option<telemetry::TelemetryConfig>,
 }
 
 /// Transform the input schema into a Registry
@@ -27,6 +31,7 @@ pub async fn parse_schema(schema: &str, environment: &HashMap<String, String>) -
         required_udfs,
         global_cache_rules,
         federated_graph_config,
+        telemetry,
         // FIXME: Revisit the `true` once we have settled on how to handle the migration story in the CLI.
     } = parser_sdl::parse(schema, environment, true, &connector_parsers)
         .await
@@ -41,9 +46,40 @@ pub async fn parse_schema(schema: &str, environment: &HashMap<String, String>) -
         registry,
         required_udfs,
         federated_graph_config,
+        telemetry: telemetry.map(telemetry_config),
     })
 }
 
+fn telemetry_config(directive: TelemetryDirective) -> telemetry::TelemetryConfig {
+    telemetry::TelemetryConfig {
+        service_name: directive.service_name().to_string(),
+        tracing: directive.tracing.map(|tracing| telemetry::TracingConfig {
+            sampling: tracing.sampling(),
+            exporter: exporter_config(tracing.exporter),
+        }),
+        metrics: directive.metrics.map(|metrics| telemetry::MetricsConfig {
+            interval: metrics.interval(),
+            exporter: exporter_config(metrics.exporter),
+        }),
+    }
+}
+
+fn exporter_config(exporter: TelemetryExporter) -> telemetry::ExporterConfig {
+    telemetry::ExporterConfig {
+        timeout: exporter.timeout(),
+        endpoint: exporter.endpoint.to_string(),
+        protocol: match exporter.protocol {
+            TelemetryProtocol::Grpc => telemetry::OtlpProtocol::Grpc,
+            TelemetryProtocol::Http => telemetry::OtlpProtocol::Http,
+        },
+        headers: exporter
+            .headers
+            .into_iter()
+            .map(|header| (header.name, header.value))
+            .collect(),
+    }
+}
+
 struct ConnectorParsers {
     http_client: reqwest::Client,
 }
@@ -99,8 +135,14 @@ impl parser_sdl::ConnectorParsers for ConnectorParsers {
             .await
             .map_err(|error| vec![error.to_string()])?;
 
-        parser_postgres::introspect(&transport, directive.name(), directive.namespace())
-            .await
-            .map_err(|error| vec![error.to_string()])
+        parser_postgres::introspect(
+            &transport,
+            directive.name(),
+            directive.namespace(),
+            directive.row_level_security().cloned(),
+            directive.subscriptions(),
+        )
+        .await
+        .map_err(|error| vec![error.to_string()])
     }
 }
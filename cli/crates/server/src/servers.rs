@@ -17,13 +17,14 @@ use common::types::UdfKind;
 use engine::registry::Registry;
 use flate2::read::GzDecoder;
 use futures_util::FutureExt;
+use runtime_wasm::{WasmResolverError, WasmResolvers};
 use sha2::Digest;
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::env;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::num::NonZeroUsize;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime};
@@ -42,8 +43,10 @@ pub struct ProductionServer {
     bridge_app: axum::Router,
     bridge_state: Box<dyn BridgeState>,
     environment_variables: HashMap<String, String>,
+    wasm_resolvers: WasmResolvers,
     message_sender: UnboundedSender<ServerMessage>,
     federated_graph_config: Option<parser_sdl::federation::FederatedGraphConfig>,
+    telemetry: Option<telemetry::TelemetryConfig>,
 }
 
 impl ProductionServer {
@@ -58,9 +61,12 @@ impl ProductionServer {
         let ParsingResponse {
             registry,
             detected_udfs,
+            wasm_resolvers,
             federated_graph_config,
+            telemetry,
         } = run_schema_parser(&environment_variables, None).await?;
         let registry = Arc::new(registry);
+        let wasm_resolvers = load_wasm_resolvers(&environment_variables, &wasm_resolvers)?;
 
         let (bridge_app, bridge_state) =
             bridge::build_router(message_sender.clone(), Arc::clone(&registry), tracing).await?;
@@ -98,12 +104,16 @@ impl ProductionServer {
             bridge_app,
             bridge_state: Box::new(bridge_state),
             environment_variables,
+            wasm_resolvers,
             message_sender,
             federated_graph_config,
+            telemetry,
         })
     }
 
     pub async fn serve(self, listen_address: IpAddr, port: u16) -> Result<(), ServerError> {
+        // Must be started before the gateway, whose metrics are bound to the exporter.
+        let _telemetry = self.telemetry.as_ref().map(telemetry::init).transpose()?;
         let is_federated = self.federated_graph_config.is_some();
 
         if let Some(config) = &self.federated_graph_config {
@@ -112,7 +122,9 @@ impl ProductionServer {
                 port,
                 is_federated,
             });
-            return federated_dev::run(port, true, config.clone())
+            let rate_limit_store = gateway::rate_limit_store_from_env_vars(&self.environment_variables)
+                .map_err(|error| ServerError::GatewayError(error.to_string()))?;
+            return federated_dev::run(port, true, config.clone(), rate_limit_store)
                 .await
                 .map_err(|error| ServerError::GatewayError(error.to_string()));
         }
@@ -124,13 +136,14 @@ impl ProductionServer {
             self.environment_variables,
             gateway::Bridge::new(bridge_port),
             self.registry,
+            self.wasm_resolvers,
         )
         .await
         .map_err(|error| ServerError::GatewayError(error.to_string()))?
         .into_router();
 
-        let gateway_server =
-            axum::Server::bind(&SocketAddr::new(listen_address, port)).serve(gateway_app.into_make_service());
+        let gateway_server = axum::Server::bind(&SocketAddr::new(listen_address, port))
+            .serve(gateway_app.into_make_service_with_connect_info::<SocketAddr>());
 
         let _ = self.message_sender.send(ServerMessage::Ready {
             listen_address,
@@ -263,7 +276,9 @@ async fn spawn_servers(
     let ParsingResponse {
         registry,
         mut detected_udfs,
+        wasm_resolvers,
         federated_graph_config,
+        telemetry,
     } = match run_schema_parser(&environment_variables, Some(event_bus)).await {
         Ok(parsing_response) => parsing_response,
         Err(error) => {
@@ -274,6 +289,8 @@ async fn spawn_servers(
         }
     };
 
+    // Replaced along with the servers whenever the schema changes.
+    let _telemetry = telemetry.as_ref().map(telemetry::init).transpose()?;
     let is_federated = federated_graph_config.is_some();
 
     if let Some(config) = federated_graph_config {
@@ -283,7 +300,9 @@ async fn spawn_servers(
             is_federated,
         });
 
-        return federated_dev::run(worker_port, false, config)
+        let rate_limit_store = gateway::rate_limit_store_from_env_vars(&environment_variables)
+            .map_err(|error| ServerError::GatewayError(error.to_string()))?;
+        return federated_dev::run(worker_port, false, config, rate_limit_store)
             .await
             .map_err(|error| ServerError::GatewayError(error.to_string()));
     }
@@ -325,6 +344,16 @@ async fn spawn_servers(
         }
     }
 
+    let wasm_resolvers = match load_wasm_resolvers(&environment_variables, &wasm_resolvers) {
+        Ok(wasm_resolvers) => wasm_resolvers,
+        Err(error) => {
+            let _: Result<_, _> = message_sender.send(ServerMessage::CompilationError(error.to_string()));
+            tokio::spawn(async move { error_server::start(worker_port, error.to_string(), bridge_event_bus).await })
+                .await??;
+            return Ok(());
+        }
+    };
+
     let (mut bridge_handle, bridge_port) = {
         let (listern, port) = get_listener_for_random_port().await?;
         let registry = Arc::clone(&registry);
@@ -337,13 +366,19 @@ async fn spawn_servers(
     };
 
     let gateway = {
-        let app = gateway::Gateway::new(environment_variables, gateway::Bridge::new(bridge_port), registry)
-            .await
-            .map_err(|error| ServerError::GatewayError(error.to_string()))?
-            .into_router();
+        let app = gateway::Gateway::new(
+            environment_variables,
+            gateway::Bridge::new(bridge_port),
+            registry,
+            wasm_resolvers,
+        )
+        .await
+        .map_err(|error| ServerError::GatewayError(error.to_string()))?
+        .into_router();
 
         // run it with hyper on localhost:3000
-        let server = axum::Server::bind(&"127.0.0.1:0".parse().unwrap()).serve(app.into_make_service());
+        let server = axum::Server::bind(&"127.0.0.1:0".parse().unwrap())
+            .serve(app.into_make_service_with_connect_info::<SocketAddr>());
         WORKER_PORT.store(server.local_addr().port(), Ordering::Relaxed);
         server
     };
@@ -437,10 +472,31 @@ pub struct DetectedUdf {
     pub fresh: bool,
 }
 
+fn load_wasm_resolvers(
+    environment_variables: &HashMap<String, String>,
+    wasm_resolvers: &[DetectedWasmResolver],
+) -> Result<WasmResolvers, WasmResolverError> {
+    let mut resolvers = WasmResolvers::new(environment_variables)?;
+    for DetectedWasmResolver { udf_name, path } in wasm_resolvers {
+        trace!("loading the WebAssembly resolver '{udf_name}' from {}", path.display());
+        resolvers.load(udf_name, path)?;
+    }
+    Ok(resolvers)
+}
+
+/// A resolver compiled to a WebAssembly component, found next to the JavaScript ones.
+#[derive(Debug, Clone)]
+pub struct DetectedWasmResolver {
+    pub udf_name: String,
+    pub path: PathBuf,
+}
+
 pub struct ParsingResponse {
     pub(crate) registry: Registry,
     pub(crate) detected_udfs: Vec<DetectedUdf>,
+    pub(crate) wasm_resolvers: Vec<DetectedWasmResolver>,
     pub(crate) federated_graph_config: Option<parser_sdl::federation::FederatedGraphConfig>,
+    pub(crate) telemetry: Option<telemetry::TelemetryConfig>,
 }
 
 // schema-parser is run via NodeJS due to it being built to run in a Wasm (via wasm-bindgen) environment
@@ -474,8 +530,23 @@ pub(crate) async fn run_schema_parser(
         registry,
         required_udfs,
         federated_graph_config,
+        telemetry,
     } = crate::parser::parse_schema(&schema, environment_variables).await?;
 
+    // Resolvers compiled to WebAssembly are run by the gateway itself, they don't need to be built.
+    let resolvers_source_path = project.udfs_source_path(UdfKind::Resolver);
+    let (wasm_resolvers, required_udfs): (Vec<_>, Vec<_>) =
+        required_udfs.into_iter().partition(|(udf_kind, udf_name)| {
+            *udf_kind == UdfKind::Resolver && resolvers_source_path.join(udf_name).with_extension("wasm").is_file()
+        });
+    let wasm_resolvers = wasm_resolvers
+        .into_iter()
+        .map(|(_, udf_name)| DetectedWasmResolver {
+            path: resolvers_source_path.join(&udf_name).with_extension("wasm"),
+            udf_name,
+        })
+        .collect();
+
     let offset = REGISTRY_PARSED_EPOCH_OFFSET_MILLIS.load(Ordering::Acquire);
     let registry_mtime = SystemTime::UNIX_EPOCH.checked_add(Duration::from_millis(offset));
     let detected_resolvers = futures_util::future::join_all(required_udfs.into_iter().map(|(udf_kind, udf_name)| {
@@ -516,7 +587,9 @@ pub(crate) async fn run_schema_parser(
     Ok(ParsingResponse {
         registry,
         detected_udfs: detected_resolvers,
+        wasm_resolvers,
         federated_graph_config,
+        telemetry,
     })
 }
 
@@ -0,0 +1,44 @@
+use postgres_connector_types::{
+    change_feed,
+    transport::{TcpTransport, Transport},
+};
+
+use crate::{
+    errors::ServerError,
+    servers::{run_schema_parser, ParsingResponse},
+};
+
+/// Installs the triggers publishing the changes of the tables to the subscriptions, for every
+/// Postgres connector with subscriptions enabled.
+#[tokio::main]
+pub async fn setup_subscriptions() -> Result<Vec<String>, ServerError> {
+    let env = crate::environment::variables().collect();
+
+    let ParsingResponse {
+        registry,
+        detected_udfs: _,
+        wasm_resolvers: _,
+        telemetry: _,
+        federated_graph_config: _,
+    } = run_schema_parser(&env, None).await?;
+
+    let mut names = Vec::new();
+
+    for (name, definition) in &registry.postgres_databases {
+        if !definition.subscriptions() {
+            continue;
+        }
+
+        let error = |error| ServerError::SetupSubscriptions(name.clone(), error);
+        let transport = TcpTransport::new(definition.connection_string()).await.map_err(error)?;
+
+        change_feed::install_triggers(&transport, definition)
+            .await
+            .map_err(error)?;
+
+        transport.close().await.map_err(error)?;
+        names.push(name.clone());
+    }
+
+    Ok(names)
+}
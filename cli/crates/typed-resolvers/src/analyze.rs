@@ -202,26 +202,34 @@ fn analyze_ast_input_field<'doc>(
         docs: field.description.as_ref().map(|d| d.node.as_str()),
         r#type: GraphqlType::resolve(&field.ty.node, schema)?,
         resolver_name: None, // no resolvers on input fields
+        resolver_batched: false,
         has_arguments: false,
     })
 }
 
 fn analyze_ast_field<'doc>(field: &'doc ast::FieldDefinition, schema: &AnalyzedSchema<'doc>) -> Option<Field<'doc>> {
-    let resolver_name = field
+    let resolver_directive = field
         .directives
         .iter()
-        .find(|directive| directive.node.name.node == "resolver")
-        .and_then(|directive| {
-            directive
-                .node
-                .arguments
-                .iter()
-                .find(|(name, _)| name.node == "name")
-                .and_then(|(_, value)| match &value.node {
-                    ConstValue::String(s) => Some(s.clone()),
-                    _ => None,
-                })
-        });
+        .find(|directive| directive.node.name.node == "resolver");
+    let resolver_name = resolver_directive.and_then(|directive| {
+        directive
+            .node
+            .arguments
+            .iter()
+            .find(|(name, _)| name.node == "name")
+            .and_then(|(_, value)| match &value.node {
+                ConstValue::String(s) => Some(s.clone()),
+                _ => None,
+            })
+    });
+    let resolver_batched = resolver_directive.is_some_and(|directive| {
+        directive
+            .node
+            .arguments
+            .iter()
+            .any(|(name, value)| name.node == "batch" && value.node == ConstValue::Boolean(true))
+    });
     let r#type = GraphqlType::resolve(&field.ty.node, schema)?;
 
     Some(Field {
@@ -229,6 +237,7 @@ fn analyze_ast_field<'doc>(field: &'doc ast::FieldDefinition, schema: &AnalyzedS
         docs: field.description.as_ref().map(|d| d.node.as_str()),
         r#type,
         resolver_name,
+        resolver_batched,
         has_arguments: !field.arguments.is_empty(),
     })
 }
@@ -297,6 +306,12 @@ pub(crate) struct Field<'doc> {
     /// ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
     /// ```
     pub(crate) resolver_name: Option<String>,
+
+    /// ```graphql,ignore
+    /// @resolver(name: "user/fullName", batch: true)
+    ///                                  ^^^^^^^^^^^
+    /// ```
+    pub(crate) resolver_batched: bool,
 }
 
 #[derive(Debug, Clone, Copy)]
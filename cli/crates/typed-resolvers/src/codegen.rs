@@ -140,6 +140,14 @@ fn write_resolver_type<O: fmt::Write>(schema: &AnalyzedSchema<'_>, out: &mut O)
     }
 
     out.write_str("\nimport { ResolverFn } from '@grafbase/sdk'\n\n")?;
+
+    if schema
+        .iter_fields()
+        .any(|(_, _, field)| field.resolver_name.is_some() && field.resolver_batched)
+    {
+        out.write_str(BATCHED_RESULT)?;
+    }
+
     out.write_str("export type Resolver = {\n")?;
 
     for (object_id, field_id, field) in fields {
@@ -159,15 +167,29 @@ fn write_resolver_type<O: fmt::Write>(schema: &AnalyzedSchema<'_>, out: &mut O)
 
         arguments.push_str(" }");
 
-        writeln!(
-            out,
-            "{INDENT}'{resolver_id}': ResolverFn<Schema['{parent_object_type_name}'], {arguments}, {rendered_field_type}>"
-        )?;
+        if field.resolver_batched {
+            // Invoked once with all the parents, returning a result for each of them in the same order.
+            writeln!(
+                out,
+                "{INDENT}'{resolver_id}': ResolverFn<Array<Schema['{parent_object_type_name}']>, {arguments}, Array<BatchedResult<{rendered_field_type}>>>"
+            )?;
+        } else {
+            writeln!(
+                out,
+                "{INDENT}'{resolver_id}': ResolverFn<Schema['{parent_object_type_name}'], {arguments}, {rendered_field_type}>"
+            )?;
+        }
     }
 
     writeln!(out, "}}\n")
 }
 
+const BATCHED_RESULT: &str = r#"export type BatchedResult<T> =
+  | { data: T }
+  | { error: { message: string; extensions?: Record<string, unknown> } }
+
+"#;
+
 const HEADER: &str = r#"// This is a generated file. It should not be edited manually.
 //
 // You can decide to commit this file or add it to your `.gitignore`.
@@ -0,0 +1,47 @@
+// This is a generated file. It should not be edited manually.
+//
+// You can decide to commit this file or add it to your `.gitignore`.
+//
+// By convention, this module is imported as `@grafbase/generated`. To make this syntax possible,
+// add a `paths` entry to your `tsconfig.json`.
+//
+//  "compilerOptions": {
+//    "paths": {
+//      "@grafbase/generated": ["./grafbase/generated"]
+//    }
+//  }
+
+export type Schema = {
+export type Schema = {
+  'Product': {
+    __typename?: 'Product';
+    id: string;
+    name: string;
+    price?: number | null;
+    reviews?: Array<Schema['Review']>;
+    description?: string | null;
+  };
+  'Review': {
+    __typename?: 'Review';
+    id: string;
+    body: string;
+  };
+  'Query': {
+    __typename?: 'Query';
+    products?: Array<Schema['Product']>;
+  };
+};
+
+import { ResolverFn } from '@grafbase/sdk'
+
+export type BatchedResult<T> =
+  | { data: T }
+  | { error: { message: string; extensions?: Record<string, unknown> } }
+
+export type Resolver = {
+  'Product.price': ResolverFn<Array<Schema['Product']>, { currency: string,  }, Array<BatchedResult<number | null>>>
+  'Product.reviews': ResolverFn<Array<Schema['Product']>, {  }, Array<BatchedResult<Array<Schema['Review']>>>>
+  'Product.description': ResolverFn<Schema['Product'], {  }, string | null>
+  'Query.products': ResolverFn<Schema['Query'], {  }, Array<Schema['Product']>>
+}
+
@@ -25,6 +25,9 @@ pub(super) fn collect_composed_directives<'a>(
         });
     }
 
+    // The shortest max age is the only one all subgraphs agree on.
+    let cache_max_age = sites.clone().filter_map(|site| site.cache_max_age()).min();
+
     for site in sites {
         tags.extend(site.tags().map(|t| t.id));
 
@@ -57,6 +60,13 @@ pub(super) fn collect_composed_directives<'a>(
         });
     }
 
+    if let Some(max_age) = cache_max_age {
+        composed_directives.push(federated::Directive {
+            name: ctx.insert_static_str("cacheControl"),
+            arguments: vec![(ctx.insert_static_str("maxAge"), federated::Value::Int(max_age))],
+        });
+    }
+
     composed_directives.extend(extra_directives);
     composed_directives
 }
@@ -88,6 +88,17 @@ pub(super) fn ingest_directives(
             }
         }
 
+        if directive_name == "cacheControl" || directive_name == "cache" {
+            let max_age = directive.node.get_argument("maxAge").and_then(|v| match &v.node {
+                ConstValue::Number(n) => n.as_i64(),
+                _ => None,
+            });
+
+            if let Some(max_age) = max_age {
+                subgraphs.insert_cache_max_age(directives, max_age);
+            }
+        }
+
         if directive_name == "deprecated" {
             let reason = directive.node.get_argument("reason").and_then(|v| match &v.node {
                 async_graphql_value::ConstValue::String(s) => Some(s.as_str()),
@@ -21,6 +21,8 @@ pub(super) struct Directives {
     site_id_counter: usize,
 
     deprecated: BTreeMap<DirectiveSiteId, Deprecated>,
+    /// From `@cacheControl(maxAge:)` or `@cache(maxAge:)`, in seconds.
+    cache_max_age: BTreeMap<DirectiveSiteId, i64>,
     r#override: BTreeMap<DirectiveSiteId, StringId>,
     provides: BTreeMap<DirectiveSiteId, Vec<Selection>>,
     requires: BTreeMap<DirectiveSiteId, Vec<Selection>>,
@@ -60,6 +62,10 @@ impl Subgraphs {
             .push((id, directive_name, arguments));
     }
 
+    pub(crate) fn insert_cache_max_age(&mut self, id: DirectiveSiteId, max_age: i64) {
+        self.directives.cache_max_age.insert(id, max_age);
+    }
+
     pub(crate) fn insert_deprecated(&mut self, id: DirectiveSiteId, reason: Option<&str>) {
         let reason = reason.map(|reason| self.strings.intern(reason));
         self.directives.deprecated.insert(id, Deprecated { reason });
@@ -112,6 +118,16 @@ impl Subgraphs {
 pub(crate) type DirectiveSiteWalker<'a> = Walker<'a, DirectiveSiteId>;
 
 impl<'a> DirectiveSiteWalker<'a> {
+    /// ```graphql,ignore
+    /// type Product @key(fields: "upc") @cacheControl(maxAge: 60) {
+    ///                                  ^^^^^^^^^^^^^^^^^^^^^^^^^
+    ///   upc: String!
+    /// }
+    /// ```
+    pub(crate) fn cache_max_age(self) -> Option<i64> {
+        self.subgraphs.directives.cache_max_age.get(&self.id).copied()
+    }
+
     pub(crate) fn deprecated(self) -> Option<DeprecatedWalker<'a>> {
         self.subgraphs
             .directives
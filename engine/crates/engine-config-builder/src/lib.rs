@@ -8,6 +8,7 @@ use engine_v2_config::{
 };
 use federated_graph::{FederatedGraph, FederatedGraphV1, SubgraphId};
 use parser_sdl::federation::{FederatedGraphConfig, SubgraphHeaderValue};
+use runtime::rate_limiting::{RateLimitKey, RateLimitRule};
 
 mod strings;
 
@@ -30,14 +31,55 @@ pub fn build_config(config: &FederatedGraphConfig, graph: FederatedGraph) -> Ver
     }
 
     VersionedConfig::V2(config::Config {
-        graph,
         default_headers,
         strings: context.strings.into_vec(),
         headers: context.headers,
         subgraph_configs,
+        operation_limits: build_operation_limits(config.operation_limits),
+        rate_limits: build_rate_limits(&config.rate_limits, &graph),
+        graph,
     })
 }
 
+fn build_operation_limits(limits: parser_sdl::federation::OperationLimits) -> config::OperationLimits {
+    config::OperationLimits {
+        depth: limits.depth,
+        aliases: limits.aliases,
+        root_fields: limits.root_fields,
+        complexity: limits.complexity,
+        tokens: limits.tokens,
+        parser_recursion: limits.parser_recursion,
+    }
+}
+
+/// Rules of unknown subgraphs are dropped, like their subgraph configs.
+fn build_rate_limits(rules: &[RateLimitRule], graph: &FederatedGraphV1) -> Vec<config::RateLimitRule> {
+    rules
+        .iter()
+        .filter_map(|rule| {
+            let subgraph = match &rule.source {
+                Some(name) => Some(graph.find_subgraph(name)?),
+                None => None,
+            };
+            Some(config::RateLimitRule {
+                name: rule.name.clone(),
+                key: match &rule.key {
+                    RateLimitKey::Global => config::RateLimitKey::Global,
+                    RateLimitKey::ClientIp => config::RateLimitKey::ClientIp,
+                    RateLimitKey::ClientIpHeader(name) => config::RateLimitKey::ClientIpHeader(name.clone()),
+                    RateLimitKey::Header(name) => config::RateLimitKey::Header(name.clone()),
+                    RateLimitKey::JwtClaim(name) => config::RateLimitKey::JwtClaim(name.clone()),
+                    RateLimitKey::OperationName => config::RateLimitKey::OperationName,
+                },
+                requests: rule.requests,
+                cost: rule.cost,
+                duration: rule.duration,
+                subgraph,
+            })
+        })
+        .collect()
+}
+
 #[derive(Default)]
 struct BuildContext<'a> {
     strings: strings::Strings<'a>,
@@ -38,6 +38,8 @@ impl VersionedConfig {
                 headers: Default::default(),
                 default_headers: Default::default(),
                 subgraph_configs: Default::default(),
+                operation_limits: Default::default(),
+                rate_limits: Default::default(),
             },
             VersionedConfig::V2(latest) => latest,
         }
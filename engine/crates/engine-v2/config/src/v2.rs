@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, time::Duration};
 
 use federated_graph::{FederatedGraphV1, SubgraphId};
 
@@ -14,6 +14,55 @@ pub struct Config {
 
     /// Additional configuration for our subgraphs
     pub subgraph_configs: BTreeMap<SubgraphId, SubgraphConfig>,
+
+    /// Limits enforced on every operation before it's executed
+    #[serde(default)]
+    pub operation_limits: OperationLimits,
+
+    /// Rate limits applied to the operations and subgraph requests
+    #[serde(default)]
+    pub rate_limits: Vec<RateLimitRule>,
+}
+
+/// Limits on incoming operations, nothing is limited by default
+#[derive(Clone, Copy, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct OperationLimits {
+    /// Maximum nesting of fields
+    pub depth: Option<u16>,
+    /// Maximum number of aliased fields
+    pub aliases: Option<u16>,
+    /// Maximum number of fields in the root selection set
+    pub root_fields: Option<u16>,
+    /// Maximum complexity, every field costs 1 unless it has a `@cost(weight: Int)` directive
+    pub complexity: Option<u16>,
+    /// Maximum number of lexical tokens in the query text
+    pub tokens: Option<u32>,
+    /// Maximum nesting of selection sets, lists and objects the parser will go through
+    pub parser_recursion: Option<u16>,
+}
+
+/// Limits the requests, and optionally the operation cost, within fixed windows of `duration`
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RateLimitRule {
+    pub name: String,
+    pub key: RateLimitKey,
+    pub requests: u64,
+    pub cost: Option<u64>,
+    pub duration: Duration,
+    /// Only the requests to this subgraph are limited if set, every operation otherwise
+    pub subgraph: Option<SubgraphId>,
+}
+
+/// What the requests of a rate limit are counted by
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub enum RateLimitKey {
+    Global,
+    ClientIp,
+    /// The client IP set in the header by a trusted proxy, or the peer address without it
+    ClientIpHeader(String),
+    Header(String),
+    JwtClaim(String),
+    OperationName,
 }
 
 /// Additional configuration for a particular subgraph
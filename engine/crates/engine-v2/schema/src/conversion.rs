@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 
 // All of that should be in federated_graph actually.
 use super::sources::*;
@@ -39,6 +39,8 @@ impl From<Config> for Schema {
                 ..Default::default()
             },
             default_headers: config.default_headers.into_iter().map(Into::into).collect(),
+            operation_limits: config.operation_limits,
+            rate_limits: config.rate_limits,
         };
 
         schema.strings.extend(config.strings);
@@ -51,11 +53,13 @@ impl From<Config> for Schema {
         for object in graph.objects {
             let object_id = ObjectId::from(schema.objects.len());
             let keys = object.resolvable_keys;
+            let composed_directives: Vec<Directive> = object.composed_directives.into_iter().map(Into::into).collect();
             schema.objects.push(Object {
                 name: object.name.into(),
                 description: None,
                 interfaces: object.implements_interfaces.into_iter().map(Into::into).collect(),
-                composed_directives: object.composed_directives.into_iter().map(Into::into).collect(),
+                cache_config: schema.cache_config(&composed_directives),
+                composed_directives,
             });
             for key in keys {
                 let resolver_id = ResolverId::from(schema.resolvers.len());
@@ -156,6 +160,9 @@ impl From<Config> for Schema {
                 }
             }
 
+            let composed_directives: Vec<Directive> = field.composed_directives.into_iter().map(Into::into).collect();
+            let cache_config = schema.cache_config(&composed_directives);
+            let cost = schema.cost(&composed_directives);
             let field = Field {
                 name: field.name.into(),
                 description: None,
@@ -183,7 +190,9 @@ impl From<Config> for Schema {
                         })
                         .collect()
                 },
-                composed_directives: field.composed_directives.into_iter().map(Into::into).collect(),
+                composed_directives,
+                cache_config,
+                cost,
                 is_deprecated: false,
                 deprecation_reason: None,
             };
@@ -257,17 +266,6 @@ impl From<federated_graph::Subgraph> for federation::Subgraph {
     }
 }
 
-impl From<federated_graph::Object> for Object {
-    fn from(object: federated_graph::Object) -> Self {
-        Object {
-            name: object.name.into(),
-            description: None,
-            interfaces: object.implements_interfaces.into_iter().map(Into::into).collect(),
-            composed_directives: object.composed_directives.into_iter().map(Into::into).collect(),
-        }
-    }
-}
-
 impl From<federated_graph::Directive> for Directive {
     fn from(directive: federated_graph::Directive) -> Self {
         Directive {
@@ -432,6 +430,36 @@ fn convert_headers(headers: Vec<config::latest::Header>, base_string_index: usiz
 }
 
 impl Schema {
+    fn cache_config(&self, directives: &[Directive]) -> Option<CacheConfig> {
+        directives
+            .iter()
+            .filter(|directive| self[directive.name] == "cacheControl")
+            .find_map(|directive| {
+                directive.arguments.iter().find_map(|(name, value)| match value {
+                    Value::Int(max_age) if self[*name] == "maxAge" => Some(CacheConfig {
+                        max_age: Duration::from_secs(u64::try_from(*max_age).unwrap_or_default()),
+                    }),
+                    _ => None,
+                })
+            })
+    }
+
+    fn cost(&self, directives: &[Directive]) -> Option<u16> {
+        // A field may be composed with different weights, the highest one is kept.
+        directives
+            .iter()
+            .filter(|directive| self[directive.name] == "cost")
+            .filter_map(|directive| {
+                directive.arguments.iter().find_map(|(name, value)| match value {
+                    Value::Int(weight) if self[*name] == "weight" => {
+                        Some(u16::try_from((*weight).max(0)).unwrap_or(u16::MAX))
+                    }
+                    _ => None,
+                })
+            })
+            .max()
+    }
+
     fn update_subgraph_config(&mut self, id: federated_graph::SubgraphId, config: config::latest::SubgraphConfig) {
         let subgraph = &mut self.data_sources.federation[id.into()];
         subgraph.headers = config.headers.into_iter().map(Into::into).collect()
@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{str::FromStr, time::Duration};
 
 mod conversion;
 mod field_set;
@@ -8,6 +8,7 @@ mod resolver;
 pub mod sources;
 mod walkers;
 
+pub use config::latest::{OperationLimits, RateLimitKey, RateLimitRule};
 pub use field_set::*;
 pub use ids::*;
 pub use names::Names;
@@ -50,6 +51,10 @@ pub struct Schema {
     headers: Vec<Header>,
 
     default_headers: Vec<HeaderId>,
+
+    pub operation_limits: OperationLimits,
+
+    pub rate_limits: Vec<RateLimitRule>,
 }
 
 #[derive(Default)]
@@ -139,7 +144,7 @@ impl Schema {
         self
     }
 
-    fn definition_name(&self, definition: Definition) -> &str {
+    pub fn definition_name(&self, definition: Definition) -> &str {
         let name = match definition {
             Definition::Scalar(s) => self[s].name,
             Definition::Object(o) => self[o].name,
@@ -172,6 +177,7 @@ pub struct Object {
     pub interfaces: Vec<InterfaceId>,
     /// All directives that made it through composition. Notably includes `@tag`.
     pub composed_directives: Vec<Directive>,
+    pub cache_config: Option<CacheConfig>,
 }
 
 #[derive(PartialOrd, Ord, PartialEq, Eq)]
@@ -198,6 +204,17 @@ pub struct Field {
 
     /// All directives that made it through composition. Notably includes `@tag`.
     pub composed_directives: Vec<Directive>,
+    pub cache_config: Option<CacheConfig>,
+    /// From the composed `@cost(weight: Int)` directive, used to compute the complexity of an
+    /// operation instead of the default cost of 1.
+    pub cost: Option<u16>,
+}
+
+/// From the composed `@cacheControl(maxAge: Int)` directive, subgraph responses may be cached
+/// for that long.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheConfig {
+    pub max_age: Duration,
 }
 
 #[derive(Debug)]
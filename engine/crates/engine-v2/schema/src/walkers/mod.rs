@@ -1,4 +1,4 @@
-use crate::{Names, Schema};
+use crate::{sources::federation::SubgraphWalker, Names, Schema, SubgraphId};
 
 mod definition;
 mod r#enum;
@@ -119,6 +119,10 @@ impl<'a> SchemaWalker<'a, ()> {
         self.names
     }
 
+    pub fn subgraph(&self, id: SubgraphId) -> SubgraphWalker<'a> {
+        self.walk(&self.schema.data_sources.federation[id])
+    }
+
     pub fn get(&self) -> &'a Schema {
         self.schema
     }
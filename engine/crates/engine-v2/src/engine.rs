@@ -1,12 +1,20 @@
-use std::sync::Arc;
+use std::{net::IpAddr, sync::Arc};
 
-use engine::RequestHeaders;
+use engine::{
+    persisted_queries::{PersistedQueries, PersistedQueryError},
+    RequestHeaders,
+};
+use runtime::rate_limiting::{
+    RateLimitKey, RateLimitRequest, RateLimitRule, RateLimitStore, RateLimited, RateLimiter, RATE_LIMITED_ERROR_CODE,
+};
 use schema::Schema;
+use telemetry::metrics::{GraphqlOperationMetrics, OperationAttributes};
+use tracing::{field::Empty, info_span, Instrument};
 
 use crate::{
     execution::{ExecutorCoordinator, Variables},
     request::{parse_operation, Operation},
-    response::{ExecutionMetadata, GraphqlError, Response},
+    response::{ExecutionMetadata, GraphqlError, OperationType, Response},
 };
 
 pub struct Engine {
@@ -14,37 +22,201 @@ pub struct Engine {
     // needs access to the schema strings
     pub(crate) schema: Arc<Schema>,
     pub(crate) runtime: EngineRuntime,
+    pub(crate) metrics: GraphqlOperationMetrics,
+    pub(crate) rate_limiter: RateLimiter,
 }
 
 pub struct EngineRuntime {
     pub fetcher: runtime::fetch::Fetcher,
+    /// Opt-in cache of subgraph responses, only used for the entities and root fields with a
+    /// `@cacheControl(maxAge:)`.
+    pub cache: Option<crate::EntityCache>,
+    /// Automatic persisted queries or trusted documents, if enabled.
+    pub persisted_queries: Option<PersistedQueries>,
+    /// Keeps the counters of the rate limits, if the graph defines any.
+    pub rate_limit_store: Arc<dyn RateLimitStore>,
 }
 
 impl Engine {
     pub fn new(schema: Schema, runtime: EngineRuntime) -> Self {
+        let rate_limiter = RateLimiter::new(rate_limit_rules(&schema), Arc::clone(&runtime.rate_limit_store));
         Self {
             schema: Arc::new(schema),
             runtime,
+            metrics: GraphqlOperationMetrics::build(),
+            rate_limiter,
         }
     }
 
-    pub async fn execute(&self, request: engine::Request, headers: RequestHeaders) -> Response {
+    /// The client IP is the address of the peer, if known, for the rate limits keyed by it.
+    pub async fn execute(
+        &self,
+        request: engine::Request,
+        headers: RequestHeaders,
+        client_ip: Option<IpAddr>,
+    ) -> Response {
+        let span = info_span!(
+            "execute_operation",
+            graphql.operation.name = request.operation_name.as_deref(),
+            graphql.operation.type = Empty,
+        );
+        telemetry::propagation::set_parent(&span, |name| headers.find(name));
+        let operation_name = request.operation_name.clone();
+        let start = web_time::Instant::now();
+
+        let response = self
+            .execute_operation(request, headers, client_ip)
+            .instrument(span.clone())
+            .await;
+
+        let ty = response.metadata().operation_type.map(|ty| match ty {
+            OperationType::Query => "query",
+            OperationType::Mutation => "mutation",
+            OperationType::Subscription => "subscription",
+        });
+        if let Some(ty) = ty {
+            span.record("graphql.operation.type", ty);
+        }
+        let errors = response.errors();
+        for error in &errors {
+            self.metrics.record_error(error.code());
+        }
+        self.metrics.record_operation(
+            OperationAttributes {
+                ty,
+                name: operation_name.as_deref(),
+                has_errors: !errors.is_empty(),
+            },
+            start.elapsed(),
+        );
+
+        response
+    }
+
+    async fn execute_operation(
+        &self,
+        mut request: engine::Request,
+        headers: RequestHeaders,
+        client_ip: Option<IpAddr>,
+    ) -> Response {
+        if let Some(persisted_queries) = &self.runtime.persisted_queries {
+            if let Err(err) = persisted_queries.resolve(&mut request).await {
+                return Response::from_error(err, ExecutionMetadata::default());
+            }
+        }
         let operation = match self.prepare(&request).await {
             Ok(operation) => operation,
             Err(error) => return Response::from_error(error, ExecutionMetadata::default()),
         };
+        if let Err(rejection) = self.check_rate_limits(&operation, &headers, client_ip).await {
+            let metadata = ExecutionMetadata {
+                retry_after: Some(rejection.retry_after),
+                ..ExecutionMetadata::build(&operation)
+            };
+            return Response::from_error(rejection, metadata);
+        }
         let variables = match Variables::from_request(&operation, self.schema.as_ref(), request.variables) {
             Ok(variables) => variables,
             Err(errors) => return Response::from_errors(errors, ExecutionMetadata::build(&operation)),
         };
-        let mut executor = ExecutorCoordinator::new(self, &operation, &variables, &headers);
+        let mut executor = ExecutorCoordinator::new(self, &operation, &variables, &headers, client_ip);
         executor.execute().await;
         executor.into_response()
     }
 
     async fn prepare(&self, request: &engine::Request) -> Result<Operation, GraphqlError> {
-        let unbound_operation = parse_operation(request)?;
+        let unbound_operation = parse_operation(request, &self.schema.operation_limits)?;
         let operation = Operation::bind(&self.schema, unbound_operation)?;
+        operation.enforce_limits(&self.schema)?;
         Ok(operation)
     }
+
+    async fn check_rate_limits(
+        &self,
+        operation: &Operation,
+        headers: &RequestHeaders,
+        client_ip: Option<IpAddr>,
+    ) -> Result<(), RateLimited> {
+        let cost = if self.rate_limiter.limits_cost() {
+            operation.complexity
+        } else {
+            0
+        };
+        let request = EngineRateLimitRequest {
+            client_ip,
+            headers,
+            operation_name: operation.name.as_deref(),
+        };
+        self.rate_limiter.check(None, &request, cost).await
+    }
+}
+
+/// Subgraph rules are identified by the name of their subgraph.
+fn rate_limit_rules(schema: &Schema) -> Vec<RateLimitRule> {
+    let walker = schema.walker();
+    schema
+        .rate_limits
+        .iter()
+        .map(|rule| RateLimitRule {
+            name: rule.name.clone(),
+            key: match &rule.key {
+                schema::RateLimitKey::Global => RateLimitKey::Global,
+                schema::RateLimitKey::ClientIp => RateLimitKey::ClientIp,
+                schema::RateLimitKey::ClientIpHeader(name) => RateLimitKey::ClientIpHeader(name.clone()),
+                schema::RateLimitKey::Header(name) => RateLimitKey::Header(name.clone()),
+                schema::RateLimitKey::JwtClaim(name) => RateLimitKey::JwtClaim(name.clone()),
+                schema::RateLimitKey::OperationName => RateLimitKey::OperationName,
+            },
+            requests: rule.requests,
+            cost: rule.cost,
+            duration: rule.duration,
+            source: rule.subgraph.map(|id| walker.subgraph(id.into()).name().to_string()),
+        })
+        .collect()
+}
+
+/// Federated graphs have no JWT authentication, rules keyed by a claim count all the requests
+/// together.
+pub(crate) struct EngineRateLimitRequest<'a> {
+    pub client_ip: Option<IpAddr>,
+    pub headers: &'a RequestHeaders,
+    pub operation_name: Option<&'a str>,
+}
+
+impl RateLimitRequest for EngineRateLimitRequest<'_> {
+    fn client_ip(&self) -> Option<IpAddr> {
+        self.client_ip
+    }
+
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.find(name)
+    }
+
+    fn jwt_claim(&self, _name: &str) -> Option<String> {
+        None
+    }
+
+    fn operation_name(&self) -> Option<&str> {
+        self.operation_name
+    }
+}
+
+impl From<RateLimited> for GraphqlError {
+    fn from(err: RateLimited) -> Self {
+        GraphqlError {
+            message: err.to_string(),
+            extensions: [("code".to_string(), serde_json::Value::from(RATE_LIMITED_ERROR_CODE))].into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<PersistedQueryError> for GraphqlError {
+    fn from(err: PersistedQueryError) -> Self {
+        GraphqlError {
+            message: err.to_string(),
+            extensions: [("code".to_string(), serde_json::Value::from(err.code()))].into(),
+            ..Default::default()
+        }
+    }
 }
@@ -1,8 +1,12 @@
+use std::net::IpAddr;
+
 use engine::RequestHeaders;
+use runtime::rate_limiting::RateLimited;
 use schema::SchemaWalker;
 
 use super::Variables;
 use crate::{
+    engine::EngineRateLimitRequest,
     plan::PlanOutput,
     request::{OperationWalker, PlanExt, PlanOperationWalker, VariablesWalker},
     response::{ExecutorOutput, ResponseBoundaryItem, ResponseObjectWriter},
@@ -16,6 +20,7 @@ pub(crate) struct ExecutionContext<'ctx> {
     pub walker: OperationWalker<'ctx>,
     pub(super) variables: &'ctx Variables<'ctx>,
     pub(super) request_headers: &'ctx RequestHeaders,
+    pub(super) client_ip: Option<IpAddr>,
 }
 
 impl<'ctx> ExecutionContext<'ctx> {
@@ -62,4 +67,14 @@ impl<'ctx> ExecutionContext<'ctx> {
     pub fn header(&self, name: &str) -> Option<&'ctx str> {
         self.request_headers.find(name)
     }
+
+    /// Counts a request to the subgraph against its rate limits.
+    pub async fn check_subgraph_rate_limits(&self, subgraph_name: &str) -> Result<(), RateLimited> {
+        let request = EngineRateLimitRequest {
+            client_ip: self.client_ip,
+            headers: self.request_headers,
+            operation_name: self.walker.name(),
+        };
+        self.engine.rate_limiter.check(Some(subgraph_name), &request, 0).await
+    }
 }
@@ -1,13 +1,16 @@
+use std::{net::IpAddr, time::Duration};
+
 use async_runtime::make_send_on_wasm;
 use engine::RequestHeaders;
 use futures_util::{future::BoxFuture, stream::FuturesUnordered, StreamExt};
+use tracing::{info_span, Instrument};
 
 use crate::{
     execution::{ExecutionContext, Variables},
     plan::{PlanBoundary, Planner},
     request::Operation,
     response::{ExecutionMetadata, ExecutorOutput, Response, ResponseBoundaryItem, ResponseBuilder, ResponsePath},
-    sources::{Executor, ExecutorResult, ResolverInput},
+    sources::{Executor, ExecutorError, ExecutorResult, ResolverInput},
     Engine,
 };
 
@@ -18,6 +21,8 @@ pub struct ExecutorCoordinator<'ctx> {
     response: ResponseBuilder,
     variables: &'ctx Variables<'ctx>,
     request_headers: &'ctx RequestHeaders,
+    client_ip: Option<IpAddr>,
+    retry_after: Option<Duration>,
 }
 
 impl<'ctx> ExecutorCoordinator<'ctx> {
@@ -26,6 +31,7 @@ impl<'ctx> ExecutorCoordinator<'ctx> {
         operation: &'ctx Operation,
         variables: &'ctx Variables<'ctx>,
         request_headers: &'ctx RequestHeaders,
+        client_ip: Option<IpAddr>,
     ) -> Self {
         Self {
             engine,
@@ -34,6 +40,8 @@ impl<'ctx> ExecutorCoordinator<'ctx> {
             response: ResponseBuilder::new(operation),
             variables,
             request_headers,
+            client_ip,
+            retry_after: None,
         }
     }
 
@@ -50,10 +58,7 @@ impl<'ctx> ExecutorCoordinator<'ctx> {
                     object_id: self.operation.root_object_id,
                 }];
 
-                let executors = self.generate_executors(vec![(plans, boundary)]);
-                for executor in executors {
-                    futures.push(Box::pin(make_send_on_wasm(executor.execute())));
-                }
+                futures.extend(self.generate_executors(vec![(plans, boundary)]));
             }
             Err(err) => {
                 self.response.push_error(err);
@@ -63,22 +68,23 @@ impl<'ctx> ExecutorCoordinator<'ctx> {
             match result {
                 Ok(output) => {
                     let boundaries = self.response.ingest(output);
-                    let executors = self.generate_executors(boundaries);
-                    for executor in executors {
-                        futures.push(Box::pin(make_send_on_wasm(executor.execute())));
-                    }
+                    futures.extend(self.generate_executors(boundaries));
                 }
                 Err(err) => {
+                    if let ExecutorError::RateLimited(rejection) = &err {
+                        self.retry_after = self.retry_after.max(Some(rejection.retry_after));
+                    }
                     self.response.push_error(err);
                 }
             }
         }
     }
 
+    /// Each plan is executed within its own span.
     fn generate_executors(
         &mut self,
         boundaries: Vec<(PlanBoundary, Vec<ResponseBoundaryItem>)>,
-    ) -> Vec<Executor<'ctx>> {
+    ) -> Vec<BoxFuture<'ctx, ExecutorResult<ExecutorOutput>>> {
         let mut executors = vec![];
         for (boundary, response_objects) in boundaries {
             match self.planner.generate_plans(boundary, &response_objects) {
@@ -90,6 +96,7 @@ impl<'ctx> ExecutorCoordinator<'ctx> {
                         // Ensuring that all walkers the executors has access to have a consistent
                         // `Names`.
                         let resolver = schema.walk(plan.resolver_id);
+                        let span = info_span!("execute_plan", plan.id = %plan.id, plan.resolver = %resolver.name());
                         let result = Executor::build(
                             resolver,
                             plan.output.entity_type,
@@ -99,6 +106,7 @@ impl<'ctx> ExecutorCoordinator<'ctx> {
                                     variables: self.variables,
                                     walker: self.operation.walker_with(schema, ()),
                                     request_headers: self.request_headers,
+                                    client_ip: self.client_ip,
                                 },
                                 boundary_objects_view: self.response.read(schema, plan.input),
                                 plan_id: plan.id,
@@ -107,7 +115,10 @@ impl<'ctx> ExecutorCoordinator<'ctx> {
                             },
                         );
                         match result {
-                            Ok(executor) => executors.push(executor),
+                            Ok(executor) => {
+                                let future = executor.execute().instrument(span);
+                                executors.push(Box::pin(make_send_on_wasm(future)) as BoxFuture<'ctx, _>);
+                            }
                             Err(err) => {
                                 self.response.push_error(err);
                             }
@@ -127,7 +138,10 @@ impl<'ctx> ExecutorCoordinator<'ctx> {
         self.response.build(
             self.engine.schema.clone(),
             self.operation.response_keys.clone(),
-            ExecutionMetadata::build(self.operation),
+            ExecutionMetadata {
+                retry_after: self.retry_after,
+                ..ExecutionMetadata::build(self.operation)
+            },
         )
     }
 }
@@ -9,5 +9,6 @@ mod utils;
 pub use engine::{Engine, EngineRuntime};
 pub use response::Response;
 pub use schema::Schema;
+pub use sources::{CachedEntity, EntityCache};
 
 pub use ::config::{latest as config, VersionedConfig};
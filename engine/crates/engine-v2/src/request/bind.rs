@@ -3,15 +3,17 @@ use std::{
     sync::Arc,
 };
 
+use engine::operation_cost;
 pub use engine_parser::types::OperationType;
 use engine_parser::Positioned;
 use schema::{Definition, FieldWalker, Schema};
 
 use super::{
-    selection_set::BoundField, variable::VariableDefinition, BoundAnyFieldDefinition, BoundAnyFieldDefinitionId,
-    BoundFieldArgument, BoundFieldDefinition, BoundFieldId, BoundFragmentDefinition, BoundFragmentDefinitionId,
-    BoundFragmentSpread, BoundInlineFragment, BoundSelection, BoundSelectionSet, BoundSelectionSetId,
-    BoundTypeNameFieldDefinition, Operation, Pos, ResponseKeys, SelectionSetType, TypeCondition, UnboundOperation,
+    limits::SchemaCosts, selection_set::BoundField, variable::VariableDefinition, BoundAnyFieldDefinition,
+    BoundAnyFieldDefinitionId, BoundFieldArgument, BoundFieldDefinition, BoundFieldId, BoundFragmentDefinition,
+    BoundFragmentDefinitionId, BoundFragmentSpread, BoundInlineFragment, BoundSelection, BoundSelectionSet,
+    BoundSelectionSetId, BoundTypeNameFieldDefinition, Operation, Pos, ResponseKeys, SelectionSetType, TypeCondition,
+    UnboundOperation,
 };
 use crate::response::GraphqlError;
 
@@ -110,6 +112,12 @@ pub fn bind(schema: &Schema, unbound: UnboundOperation) -> BindResult<Operation>
             .subscription
             .ok_or(BindError::NoSubscriptionDefined)?,
     };
+    let complexity = operation_cost::selection_set_cost(
+        &SchemaCosts(schema),
+        &schema[schema[root_object_id].name],
+        &unbound.definition.selection_set.node,
+        &|name| unbound.fragments.get(name).map(|fragment| &fragment.node),
+    );
     let mut binder = Binder {
         schema,
         operation_name: ErrorOperationName(unbound.name.clone()),
@@ -148,6 +156,7 @@ pub fn bind(schema: &Schema, unbound: UnboundOperation) -> BindResult<Operation>
         field_definitions: binder.field_definitions,
         fields: binder.fields,
         variable_definitions: binder.variable_definitions,
+        complexity,
     })
 }
 
@@ -0,0 +1,206 @@
+use engine::operation_cost::{CostSchema, FieldCost};
+use schema::{Definition, OperationLimits, Schema};
+
+use super::{BoundAnyFieldDefinition, BoundSelection, BoundSelectionSetId, Operation};
+use crate::response::GraphqlError;
+
+#[derive(thiserror::Error, Debug)]
+pub enum OperationLimitExceeded {
+    #[error("Query is nested too deep.")]
+    Depth,
+    #[error("Query contains too many aliases.")]
+    Aliases,
+    #[error("Query contains too many root fields.")]
+    RootFields,
+    #[error("Query is too complex.")]
+    Complexity,
+}
+
+impl From<OperationLimitExceeded> for GraphqlError {
+    fn from(err: OperationLimitExceeded) -> Self {
+        GraphqlError {
+            message: err.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Default)]
+struct Measures {
+    depth: usize,
+    aliases: usize,
+}
+
+impl Operation {
+    /// Fragments are measured as if they were inlined at every spread.
+    pub fn enforce_limits(&self, schema: &Schema) -> Result<(), OperationLimitExceeded> {
+        let limits = &schema.operation_limits;
+        if let Some(root_fields) = limits.root_fields {
+            if self.root_fields_count(self.root_selection_set_id) > usize::from(root_fields) {
+                return Err(OperationLimitExceeded::RootFields);
+            }
+        }
+        if limits.depth.is_none() && limits.aliases.is_none() && limits.complexity.is_none() {
+            return Ok(());
+        }
+
+        let mut measures = Measures::default();
+        self.measure(schema, self.root_selection_set_id, 1, &mut measures);
+        check(limits.depth, measures.depth, OperationLimitExceeded::Depth)?;
+        check(limits.aliases, measures.aliases, OperationLimitExceeded::Aliases)?;
+        check(
+            limits.complexity,
+            usize::try_from(self.complexity).unwrap_or(usize::MAX),
+            OperationLimitExceeded::Complexity,
+        )?;
+        Ok(())
+    }
+
+    fn root_fields_count(&self, id: BoundSelectionSetId) -> usize {
+        self[id]
+            .items
+            .iter()
+            .map(|selection| match selection {
+                BoundSelection::Field(_) => 1,
+                BoundSelection::FragmentSpread(spread) => self.root_fields_count(spread.selection_set_id),
+                BoundSelection::InlineFragment(fragment) => self.root_fields_count(fragment.selection_set_id),
+            })
+            .sum()
+    }
+
+    /// Records the depth and the aliases of the selection set.
+    fn measure(&self, schema: &Schema, id: BoundSelectionSetId, depth: usize, measures: &mut Measures) {
+        for selection in &self[id].items {
+            match selection {
+                BoundSelection::Field(field_id) => {
+                    measures.depth = measures.depth.max(depth);
+                    let field = &self[*field_id];
+                    let response_key = &self.response_keys[field.bound_response_key.into()];
+                    let name = match &self[field.definition_id] {
+                        BoundAnyFieldDefinition::TypeName(_) => "__typename",
+                        BoundAnyFieldDefinition::Field(definition) => schema[schema[definition.field_id].name].as_str(),
+                    };
+                    if response_key != name {
+                        measures.aliases += 1;
+                    }
+                    if let Some(id) = field.selection_set_id {
+                        self.measure(schema, id, depth + 1, measures);
+                    }
+                }
+                BoundSelection::FragmentSpread(spread) => {
+                    self.measure(schema, spread.selection_set_id, depth, measures);
+                }
+                BoundSelection::InlineFragment(fragment) => {
+                    self.measure(schema, fragment.selection_set_id, depth, measures);
+                }
+            }
+        }
+    }
+}
+
+/// Weights the fields by their composed `@cost`.
+pub(super) struct SchemaCosts<'a>(pub &'a Schema);
+
+impl CostSchema for SchemaCosts<'_> {
+    fn field_cost(&self, type_name: &str, field_name: &str) -> Option<FieldCost<'_>> {
+        let schema = self.0;
+        let field_id = match schema.definition_by_name(type_name)? {
+            Definition::Object(object_id) => schema.object_field_by_name(object_id, field_name),
+            Definition::Interface(interface_id) => schema.interface_field_by_name(interface_id, field_name),
+            _ => None,
+        }?;
+        let field = &schema[field_id];
+        Some(FieldCost {
+            weight: field.cost,
+            output_type: schema.definition_name(schema[field.type_id].inner),
+        })
+    }
+}
+
+fn check(limit: Option<u16>, value: usize, err: OperationLimitExceeded) -> Result<(), OperationLimitExceeded> {
+    match limit {
+        Some(limit) if value > usize::from(limit) => Err(err),
+        _ => Ok(()),
+    }
+}
+
+/// Cheap lexical pass over the query text, so that abusive queries are rejected before the
+/// parser has to go through them.
+pub(super) fn check_lexical_limits(query: &str, limits: &OperationLimits) -> Result<(), LexicalLimitExceeded> {
+    if limits.tokens.is_none() && limits.parser_recursion.is_none() {
+        return Ok(());
+    }
+    let max_tokens = limits.tokens.map(|tokens| tokens as usize).unwrap_or(usize::MAX);
+    let max_nesting = limits.parser_recursion.map(usize::from).unwrap_or(usize::MAX);
+
+    let bytes = query.as_bytes();
+    let mut i = 0;
+    let mut tokens = 0;
+    let mut nesting = 0usize;
+    while i < bytes.len() {
+        match bytes[i] {
+            // Commas are insignificant like whitespaces.
+            b' ' | b'\t' | b'\n' | b'\r' | b',' => {
+                i += 1;
+                continue;
+            }
+            b'#' => {
+                while i < bytes.len() && bytes[i] != b'\n' && bytes[i] != b'\r' {
+                    i += 1;
+                }
+                continue;
+            }
+            b'"' if bytes[i..].starts_with(b"\"\"\"") => {
+                i += 3;
+                while i < bytes.len() && !bytes[i..].starts_with(b"\"\"\"") {
+                    i += if bytes[i..].starts_with(b"\\\"\"\"") { 4 } else { 1 };
+                }
+                i += 3;
+            }
+            b'"' => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' && bytes[i] != b'\n' {
+                    i += if bytes[i] == b'\\' { 2 } else { 1 };
+                }
+                i += 1;
+            }
+            b'{' | b'[' | b'(' => {
+                nesting += 1;
+                if nesting > max_nesting {
+                    return Err(LexicalLimitExceeded::Recursion);
+                }
+                i += 1;
+            }
+            b'}' | b']' | b')' => {
+                nesting = nesting.saturating_sub(1);
+                i += 1;
+            }
+            b'.' if bytes[i..].starts_with(b"...") => i += 3,
+            b'-' | b'0'..=b'9' => {
+                i += 1;
+                while i < bytes.len() && matches!(bytes[i], b'0'..=b'9' | b'.' | b'e' | b'E' | b'+' | b'-') {
+                    i += 1;
+                }
+            }
+            b'_' | b'a'..=b'z' | b'A'..=b'Z' => {
+                while i < bytes.len() && matches!(bytes[i], b'_' | b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9') {
+                    i += 1;
+                }
+            }
+            _ => i += 1,
+        }
+        tokens += 1;
+        if tokens > max_tokens {
+            return Err(LexicalLimitExceeded::Tokens);
+        }
+    }
+    Ok(())
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum LexicalLimitExceeded {
+    #[error("Query contains too many tokens.")]
+    Tokens,
+    #[error("Query is nested too deep to be parsed.")]
+    Recursion,
+}
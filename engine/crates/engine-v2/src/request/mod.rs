@@ -1,6 +1,7 @@
 mod bind;
 mod flat;
 pub mod ids;
+mod limits;
 mod parse;
 mod path;
 mod selection_set;
@@ -13,6 +14,7 @@ pub use bind::BindResult;
 pub use engine_parser::{types::OperationType, Pos};
 pub use flat::*;
 pub use ids::*;
+pub use limits::{LexicalLimitExceeded, OperationLimitExceeded};
 pub use parse::{parse_operation, UnboundOperation};
 pub use path::QueryPath;
 use schema::{ObjectId, Schema, SchemaWalker};
@@ -36,6 +38,9 @@ pub struct Operation {
     pub fragment_definitions: Vec<BoundFragmentDefinition>,
     pub field_definitions: Vec<BoundAnyFieldDefinition>,
     pub variable_definitions: Vec<VariableDefinition>,
+    /// The cost counted by the rate limits and the complexity limit, see
+    /// [`engine::operation_cost`].
+    pub complexity: u64,
 }
 
 impl Operation {
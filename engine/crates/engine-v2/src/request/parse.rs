@@ -5,6 +5,9 @@ use engine_parser::{
     Positioned,
 };
 
+use schema::OperationLimits;
+
+use super::limits::{check_lexical_limits, LexicalLimitExceeded};
 use crate::response::GraphqlError;
 
 #[derive(thiserror::Error, Debug)]
@@ -15,6 +18,8 @@ pub enum ParseError {
     MissingOperationName,
     #[error(transparent)]
     ParserError(#[from] engine_parser::Error),
+    #[error(transparent)]
+    LexicalLimit(#[from] LexicalLimitExceeded),
 }
 
 pub type ParseResult<T> = Result<T, ParseError>;
@@ -42,7 +47,8 @@ pub struct UnboundOperation {
 }
 
 /// Returns a valid GraphQL operation from the query string before.
-pub fn parse_operation(request: &engine::Request) -> ParseResult<UnboundOperation> {
+pub fn parse_operation(request: &engine::Request, limits: &OperationLimits) -> ParseResult<UnboundOperation> {
+    check_lexical_limits(&request.query, limits)?;
     let document = engine_parser::parse_query(&request.query)?;
 
     let (operation_name, operation) = if let Some(operation_name) = &request.operation_name {
@@ -81,6 +81,10 @@ impl<'a, E> OperationWalker<'a, (), (), E> {
     pub fn names(&self) -> &'a dyn schema::Names {
         self.schema_walker.names()
     }
+
+    pub fn name(&self) -> Option<&'a str> {
+        self.operation.name.as_deref()
+    }
 }
 
 impl<'a, W, I, E> OperationWalker<'a, W, I, E> {
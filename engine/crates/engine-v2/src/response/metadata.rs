@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 pub use engine_parser::types::OperationType;
 
 use crate::request::Operation;
@@ -6,12 +8,16 @@ use crate::request::Operation;
 #[derive(Default)]
 pub struct ExecutionMetadata {
     pub operation_type: Option<OperationType>,
+    /// Set when the operation or one of its subgraph requests was rate limited, the longest wait
+    /// to send back as a `Retry-After` header.
+    pub retry_after: Option<Duration>,
 }
 
 impl ExecutionMetadata {
     pub fn build(operation: &Operation) -> Self {
         Self {
             operation_type: Some(operation.ty),
+            retry_after: None,
         }
     }
 }
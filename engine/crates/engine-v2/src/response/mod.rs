@@ -76,6 +76,12 @@ impl Response {
     }
 }
 
+impl Error<'_> {
+    pub fn code(&self) -> Option<&str> {
+        self.0.extensions.get("code").and_then(serde_json::Value::as_str)
+    }
+}
+
 impl std::fmt::Debug for Response {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Response").finish_non_exhaustive()
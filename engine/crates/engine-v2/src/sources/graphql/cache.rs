@@ -0,0 +1,132 @@
+//! Entities are cached one by one, so that only the missing representations are requested to the
+//! subgraph. Root fields are cached as a whole response. In both cases the max age is the
+//! smallest one among the entity type and fields selected by the plan, nothing is cached without
+//! one.
+
+use std::{sync::Arc, time::Duration};
+
+use engine_parser::types::OperationType;
+use runtime::cache::{Cache, Cacheable, Entry, EntryState};
+use sha2::{Digest, Sha256};
+use telemetry::metrics::{CacheReadStatus, GraphqlOperationMetrics};
+
+use crate::{
+    execution::ExecutionContext,
+    plan::PlanOutput,
+    request::{EntityType, PlanSelection, PlanSelectionSet},
+};
+
+pub type EntityCache = Arc<dyn Cache<Value = CachedEntity>>;
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+pub struct CachedEntity {
+    data: serde_json::Value,
+    max_age: Duration,
+}
+
+impl Cacheable for CachedEntity {
+    fn max_age(&self) -> Duration {
+        self.max_age
+    }
+
+    fn stale_while_revalidate(&self) -> Duration {
+        Duration::ZERO
+    }
+
+    fn cache_tags(&self) -> Vec<String> {
+        vec![]
+    }
+
+    fn should_purge_related(&self) -> bool {
+        false
+    }
+
+    fn should_cache(&self) -> bool {
+        true
+    }
+}
+
+pub(super) struct EntityCacheContext<'ctx> {
+    cache: &'ctx EntityCache,
+    metrics: &'ctx GraphqlOperationMetrics,
+    max_age: Duration,
+}
+
+impl<'ctx> EntityCacheContext<'ctx> {
+    /// Only queries are cached and only if at least one of the selected fields or the entity
+    /// itself has a max age.
+    pub fn build(ctx: ExecutionContext<'ctx>, plan_output: &PlanOutput) -> Option<Self> {
+        let cache = ctx.engine.runtime.cache.as_ref()?;
+        let operation = ctx.walk(plan_output);
+        if operation.ty() != OperationType::Query {
+            return None;
+        }
+        let mut max_age = match plan_output.entity_type {
+            EntityType::Object(object_id) => ctx.schema().walk(object_id).cache_config.map(|config| config.max_age),
+            EntityType::Interface(_) => None,
+        };
+        min_selection_set_max_age(operation.selection_set(), &mut max_age);
+        let max_age = max_age.filter(|max_age| !max_age.is_zero())?;
+        Some(EntityCacheContext {
+            cache,
+            metrics: &ctx.engine.metrics,
+            max_age,
+        })
+    }
+
+    /// Everything that may change the subgraph response must be part of the key. Hashed with
+    /// SHA-256, entities of different requests must not collide. Each value is prefixed with its
+    /// length, so that values can't run into each other.
+    pub fn key(&self, subgraph_name: &str, type_name: &str, parts: &[&str], headers: &[(&str, &str)]) -> String {
+        let mut hasher = Sha256::new();
+        let values = parts
+            .iter()
+            .copied()
+            .chain(headers.iter().flat_map(|(name, value)| [*name, *value]));
+        for value in values {
+            hasher.update((value.len() as u64).to_le_bytes());
+            hasher.update(value.as_bytes());
+        }
+        format!("{subgraph_name}.{type_name}.{}", hex::encode(hasher.finalize()))
+    }
+
+    /// Cache errors are treated as misses, the subgraph can still answer.
+    pub async fn get(&self, key: &str) -> Option<serde_json::Value> {
+        let (status, data) = match self.cache.get(key).await {
+            Ok(Entry::Hit(entity)) => (CacheReadStatus::Hit, Some(entity.data)),
+            Ok(Entry::Stale { .. }) => (CacheReadStatus::Stale, None),
+            Ok(Entry::Miss) | Err(_) => (CacheReadStatus::Miss, None),
+        };
+        self.metrics.record_cache_read(status);
+        data
+    }
+
+    pub async fn put(&self, key: &str, data: serde_json::Value) {
+        let entity = CachedEntity {
+            data,
+            max_age: self.max_age,
+        };
+        self.cache
+            .put(key, EntryState::Fresh, Arc::new(entity), vec![])
+            .await
+            .ok();
+    }
+}
+
+fn min_selection_set_max_age(selection_set: PlanSelectionSet<'_>, max_age: &mut Option<Duration>) {
+    for selection in selection_set {
+        let selection_set = match selection {
+            PlanSelection::Field(field) => {
+                if let Some(config) = field.cache_config {
+                    *max_age = Some(max_age.map_or(config.max_age, |max_age| max_age.min(config.max_age)));
+                }
+                field.selection_set()
+            }
+            PlanSelection::FragmentSpread(spread) => Some(spread.selection_set()),
+            PlanSelection::InlineFragment(fragment) => Some(fragment.selection_set()),
+        };
+        if let Some(selection_set) = selection_set {
+            min_selection_set_max_age(selection_set, max_age);
+        }
+    }
+}
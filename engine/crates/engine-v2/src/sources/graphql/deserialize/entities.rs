@@ -14,7 +14,7 @@ use crate::{
 
 pub(crate) struct EntitiesDataSeed<'a> {
     pub ctx: ExecutionContext<'a>,
-    pub response_boundary: &'a Vec<ResponseBoundaryItem>,
+    pub response_boundary: &'a [ResponseBoundaryItem],
     pub output: &'a mut ExecutorOutput,
     pub plan_output: &'a PlanOutput,
 }
@@ -63,7 +63,7 @@ impl<'de, 'a> Visitor<'de> for EntitiesDataSeed<'a> {
 
 struct EntitiesSeed<'a> {
     ctx: ExecutionContext<'a>,
-    response_boundary: &'a Vec<ResponseBoundaryItem>,
+    response_boundary: &'a [ResponseBoundaryItem],
     output: &'a mut ExecutorOutput,
     plan_output: &'a PlanOutput,
 }
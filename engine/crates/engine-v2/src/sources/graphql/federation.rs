@@ -1,6 +1,6 @@
-use runtime::fetch::FetchRequest;
-use schema::sources::federation::{EntityResolverWalker, SubgraphHeaderValueRef, SubgraphWalker};
-use serde::de::DeserializeSeed;
+use runtime::fetch::FetchResponse;
+use schema::sources::federation::{EntityResolverWalker, SubgraphWalker};
+use serde::{de::DeserializeSeed, Deserializer};
 
 use crate::{
     execution::ExecutionContext,
@@ -10,17 +10,29 @@ use crate::{
     sources::{Executor, ExecutorError, ExecutorResult, ResolverInput},
 };
 
-use super::{deserialize, query};
+use super::{cache::EntityCacheContext, deserialize, query, subgraph_headers};
 
 pub(crate) struct FederationEntityExecutor<'ctx> {
     ctx: ExecutionContext<'ctx>,
     subgraph: SubgraphWalker<'ctx>,
+    entity_type: EntityType,
     json_body: String,
+    /// If present, the query is sent only for the representations missing from the cache and
+    /// `json_body` is left empty.
+    cache: Option<CachedEntitiesQuery<'ctx>>,
     response_boundary: Vec<ResponseBoundaryItem>,
     plan_output: PlanOutput,
     output: ExecutorOutput,
 }
 
+struct CachedEntitiesQuery<'ctx> {
+    cache: EntityCacheContext<'ctx>,
+    query: String,
+    variables: serde_json::Map<String, serde_json::Value>,
+    representations_variable: String,
+    representations: Vec<serde_json::Value>,
+}
+
 impl<'ctx> FederationEntityExecutor<'ctx> {
     pub fn build<'input>(
         resolver: EntityResolverWalker<'ctx>,
@@ -46,11 +58,43 @@ impl<'ctx> FederationEntityExecutor<'ctx> {
         let response_boundary = boundary_objects_view.boundary();
         let query = query::FederationEntityQuery::build(ctx, plan_id, &plan_output, boundary_objects_view)
             .map_err(|err| ExecutorError::Internal(format!("Failed to build query: {err}")))?;
+        let serialization_error =
+            |err: serde_json::Error| ExecutorError::Internal(format!("Failed to serialize query: {err}"));
+        let (json_body, cache) = match EntityCacheContext::build(ctx, &plan_output) {
+            Some(cache) => {
+                let serde_json::Value::Object(variables) =
+                    serde_json::to_value(&query.variables.query_variables).map_err(serialization_error)?
+                else {
+                    return Err(ExecutorError::Internal("Query variables must be an object".to_string()));
+                };
+                let (representations_variable, representations) = query
+                    .variables
+                    .representations
+                    .into_iter()
+                    .next()
+                    .expect("There is always one representations variable");
+                let serde_json::Value::Array(representations) =
+                    serde_json::to_value(&representations).map_err(serialization_error)?
+                else {
+                    return Err(ExecutorError::Internal("Representations must be a list".to_string()));
+                };
+                let cache = CachedEntitiesQuery {
+                    cache,
+                    query: query.query,
+                    variables,
+                    representations_variable,
+                    representations,
+                };
+                (String::new(), Some(cache))
+            }
+            None => (serde_json::to_string(&query).map_err(serialization_error)?, None),
+        };
         Ok(Executor::FederationEntity(Self {
             ctx,
             subgraph,
-            json_body: serde_json::to_string(&query)
-                .map_err(|err| ExecutorError::Internal(format!("Failed to serialize query: {err}")))?,
+            entity_type,
+            json_body,
+            cache,
             response_boundary,
             plan_output,
             output,
@@ -58,32 +102,100 @@ impl<'ctx> FederationEntityExecutor<'ctx> {
     }
 
     pub async fn execute(mut self) -> ExecutorResult<ExecutorOutput> {
-        let bytes = self
+        let headers = subgraph_headers(self.ctx, self.subgraph);
+        let response_boundary = std::mem::take(&mut self.response_boundary);
+        let Some(CachedEntitiesQuery {
+            cache,
+            query,
+            mut variables,
+            representations_variable,
+            mut representations,
+        }) = self.cache.take()
+        else {
+            let json_body = std::mem::take(&mut self.json_body);
+            let bytes = self.fetch(json_body, headers).await?.bytes;
+            self.ingest(&mut serde_json::Deserializer::from_slice(&bytes), &response_boundary);
+            return Ok(self.output);
+        };
+
+        let type_name = self
             .ctx
-            .engine
-            .runtime
-            .fetcher
-            .post(FetchRequest {
-                url: self.subgraph.url(),
-                json_body: self.json_body,
-                headers: self
-                    .subgraph
-                    .headers()
-                    .filter_map(|header| {
-                        Some((
-                            header.name(),
-                            match header.value() {
-                                SubgraphHeaderValueRef::Forward(name) => self.ctx.header(name)?,
-                                SubgraphHeaderValueRef::Static(value) => value,
-                            },
-                        ))
-                    })
-                    .collect(),
-            })
-            .await?
-            .bytes;
+            .schema()
+            .walk(schema::Definition::from(self.entity_type))
+            .name();
+        let variables_key = serde_json::Value::Object(variables.clone()).to_string();
+        let mut missing_keys = vec![];
+        let mut missing_representations = vec![];
+        let mut missing_response_boundary = vec![];
+        for (representation, boundary_item) in representations.iter_mut().zip(&response_boundary) {
+            let key = cache.key(
+                self.subgraph.name(),
+                type_name,
+                &[&query, &variables_key, &representation.to_string()],
+                &headers,
+            );
+            match cache.get(&key).await {
+                Some(entity) => {
+                    let result = self
+                        .ctx
+                        .writer(&mut self.output, boundary_item, &self.plan_output)
+                        .deserialize(&entity);
+                    if let Err(err) = result {
+                        self.output.push_error(GraphqlError {
+                            message: format!("Cached entity error: {err}"),
+                            path: Some(boundary_item.response_path.clone()),
+                            ..Default::default()
+                        });
+                    }
+                }
+                None => {
+                    missing_keys.push(key);
+                    missing_representations.push(representation.take());
+                    missing_response_boundary.push(boundary_item.clone());
+                }
+            }
+        }
+        if missing_keys.is_empty() {
+            return Ok(self.output);
+        }
+
+        variables.insert(
+            representations_variable,
+            serde_json::Value::Array(missing_representations),
+        );
+        let json_body = serde_json::json!({ "query": query, "variables": variables }).to_string();
+        let bytes = self.fetch(json_body, headers).await?.bytes;
+        let Ok(response) = serde_json::from_slice::<serde_json::Value>(&bytes) else {
+            self.ingest(
+                &mut serde_json::Deserializer::from_slice(&bytes),
+                &missing_response_boundary,
+            );
+            return Ok(self.output);
+        };
+        if self.ingest(&response, &missing_response_boundary) {
+            if let Some(serde_json::Value::Array(entities)) = response.pointer("/data/_entities") {
+                for (key, entity) in missing_keys.iter().zip(entities) {
+                    if !entity.is_null() {
+                        cache.put(key, entity.clone()).await;
+                    }
+                }
+            }
+        }
+
+        Ok(self.output)
+    }
+
+    async fn fetch(&self, json_body: String, headers: Vec<(&'ctx str, &'ctx str)>) -> ExecutorResult<FetchResponse> {
+        super::fetch(self.ctx, self.subgraph, json_body, headers).await
+    }
+
+    /// Returns whether the response was ingested without any error.
+    fn ingest<'de, D>(&mut self, deserializer: D, response_boundary: &[ResponseBoundaryItem]) -> bool
+    where
+        D: Deserializer<'de, Error = serde_json::Error>,
+    {
         let err_path = Some(
-            self.response_boundary[0]
+            response_boundary[0]
                 .response_path
                 .child(self.ctx.walker.walk(self.plan_output.root_fields[0]).bound_response_key),
         );
@@ -93,12 +205,12 @@ impl<'ctx> FederationEntityExecutor<'ctx> {
             &mut upstream_errors,
             deserialize::EntitiesDataSeed {
                 ctx: self.ctx,
-                response_boundary: &self.response_boundary,
+                response_boundary,
                 output: &mut self.output,
                 plan_output: &self.plan_output,
             },
         )
-        .deserialize(&mut serde_json::Deserializer::from_slice(&bytes));
+        .deserialize(deserializer);
 
         if !upstream_errors.is_empty() {
             self.output.push_errors(upstream_errors);
@@ -113,6 +225,6 @@ impl<'ctx> FederationEntityExecutor<'ctx> {
             }
         }
 
-        Ok(self.output)
+        !self.output.has_errors()
     }
 }
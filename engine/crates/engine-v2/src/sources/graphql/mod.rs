@@ -1,6 +1,7 @@
-use runtime::fetch::FetchRequest;
+use runtime::fetch::{FetchRequest, FetchResponse};
 use schema::sources::federation::{RootFieldResolverWalker, SubgraphHeaderValueRef, SubgraphWalker};
-use serde::de::DeserializeSeed;
+use serde::{de::DeserializeSeed, Deserializer};
+use tracing::{info_span, Instrument};
 
 use super::{ExecutionContext, Executor, ExecutorError, ExecutorResult, ResolverInput};
 use crate::{
@@ -8,6 +9,7 @@ use crate::{
     response::{ExecutorOutput, GraphqlError, ResponseBoundaryItem},
 };
 
+pub mod cache;
 mod deserialize;
 pub mod federation;
 mod query;
@@ -16,6 +18,7 @@ pub(crate) struct GraphqlExecutor<'ctx> {
     ctx: ExecutionContext<'ctx>,
     subgraph: SubgraphWalker<'ctx>,
     json_body: String,
+    cache: Option<cache::EntityCacheContext<'ctx>>,
     boundary_item: ResponseBoundaryItem,
     plan_output: PlanOutput,
     output: ExecutorOutput,
@@ -40,6 +43,7 @@ impl<'ctx> GraphqlExecutor<'ctx> {
             subgraph,
             json_body: serde_json::to_string(&query)
                 .map_err(|err| ExecutorError::Internal(format!("Failed to serialize query: {err}")))?,
+            cache: cache::EntityCacheContext::build(ctx, &plan_output),
             boundary_item: roots.into_single_boundary_item(),
             plan_output,
             output,
@@ -47,30 +51,45 @@ impl<'ctx> GraphqlExecutor<'ctx> {
     }
 
     pub async fn execute(mut self) -> ExecutorResult<ExecutorOutput> {
-        let bytes = self
-            .ctx
-            .engine
-            .runtime
-            .fetcher
-            .post(FetchRequest {
-                url: self.subgraph.url(),
-                json_body: self.json_body,
-                headers: self
-                    .subgraph
-                    .headers()
-                    .filter_map(|header| {
-                        Some((
-                            header.name(),
-                            match header.value() {
-                                SubgraphHeaderValueRef::Forward(name) => self.ctx.header(name)?,
-                                SubgraphHeaderValueRef::Static(value) => value,
-                            },
-                        ))
-                    })
-                    .collect(),
-            })
-            .await?
-            .bytes;
+        let headers = subgraph_headers(self.ctx, self.subgraph);
+        let cache = self.cache.take().map(|cache| {
+            let type_name = self.ctx.walk(&self.plan_output).selection_set().ty().name();
+            let key = cache.key(self.subgraph.name(), type_name, &[&self.json_body], &headers);
+            (cache, key)
+        });
+        if let Some((cache, key)) = &cache {
+            if let Some(response) = cache.get(key).await {
+                self.ingest(&response);
+                return Ok(self.output);
+            }
+        }
+
+        let json_body = std::mem::take(&mut self.json_body);
+        let bytes = fetch(self.ctx, self.subgraph, json_body, headers).await?.bytes;
+
+        let cacheable_response = cache.and_then(|(cache, key)| {
+            let response = serde_json::from_slice::<serde_json::Value>(&bytes).ok()?;
+            Some((cache, key, response))
+        });
+        match cacheable_response {
+            Some((cache, key, response)) => {
+                if self.ingest(&response) {
+                    cache.put(&key, response).await;
+                }
+            }
+            None => {
+                self.ingest(&mut serde_json::Deserializer::from_slice(&bytes));
+            }
+        }
+
+        Ok(self.output)
+    }
+
+    /// Returns whether the response was ingested without any error.
+    fn ingest<'de, D>(&mut self, deserializer: D) -> bool
+    where
+        D: Deserializer<'de, Error = serde_json::Error>,
+    {
         let err_path = Some(
             self.boundary_item
                 .response_path
@@ -83,7 +102,7 @@ impl<'ctx> GraphqlExecutor<'ctx> {
             self.ctx
                 .writer(&mut self.output, &self.boundary_item, &self.plan_output),
         )
-        .deserialize(&mut serde_json::Deserializer::from_slice(&bytes));
+        .deserialize(deserializer);
 
         if !upstream_errors.is_empty() {
             self.output.push_errors(upstream_errors);
@@ -98,6 +117,53 @@ impl<'ctx> GraphqlExecutor<'ctx> {
             }
         }
 
-        Ok(self.output)
+        !self.output.has_errors()
     }
 }
+
+fn subgraph_headers<'ctx>(ctx: ExecutionContext<'ctx>, subgraph: SubgraphWalker<'ctx>) -> Vec<(&'ctx str, &'ctx str)> {
+    subgraph
+        .headers()
+        .filter_map(|header| {
+            Some((
+                header.name(),
+                match header.value() {
+                    SubgraphHeaderValueRef::Forward(name) => ctx.header(name)?,
+                    SubgraphHeaderValueRef::Static(value) => value,
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Sends the request within its own span, passing the trace context on to the subgraph. Trace
+/// headers are never part of the cache keys. Cached responses aren't counted by the rate limits.
+async fn fetch<'ctx>(
+    ctx: ExecutionContext<'ctx>,
+    subgraph: SubgraphWalker<'ctx>,
+    json_body: String,
+    headers: Vec<(&'ctx str, &'ctx str)>,
+) -> ExecutorResult<FetchResponse> {
+    ctx.check_subgraph_rate_limits(subgraph.name()).await?;
+    let span = info_span!(
+        "subgraph_request",
+        subgraph.name = subgraph.name(),
+        http.url = subgraph.url()
+    );
+    let trace_headers = span.in_scope(telemetry::propagation::current_headers);
+    let headers = headers
+        .into_iter()
+        .chain(trace_headers.iter().map(|(name, value)| (*name, value.as_str())))
+        .collect();
+    Ok(ctx
+        .engine
+        .runtime
+        .fetcher
+        .post(FetchRequest {
+            url: subgraph.url(),
+            json_body,
+            headers,
+        })
+        .instrument(span)
+        .await?)
+}
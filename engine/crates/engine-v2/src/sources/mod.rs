@@ -12,6 +12,7 @@ use crate::{
 mod graphql;
 mod introspection;
 
+pub use graphql::cache::{CachedEntity, EntityCache};
 use graphql::federation::FederationEntityExecutor;
 use graphql::GraphqlExecutor;
 use introspection::IntrospectionExecutionPlan;
@@ -103,12 +104,17 @@ pub enum ExecutorError {
     Write(#[from] crate::response::WriteError),
     #[error(transparent)]
     Fetch(#[from] runtime::fetch::FetchError),
+    #[error(transparent)]
+    RateLimited(#[from] runtime::rate_limiting::RateLimited),
 }
 
 pub type ExecutorResult<T> = Result<T, ExecutorError>;
 
 impl From<ExecutorError> for GraphqlError {
     fn from(err: ExecutorError) -> Self {
+        if let ExecutorError::RateLimited(rejection) = err {
+            return rejection.into();
+        }
         GraphqlError {
             message: err.to_string(),
             locations: vec![],
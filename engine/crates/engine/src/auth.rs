@@ -1,5 +1,8 @@
+mod claims;
+
 use std::collections::{BTreeSet, HashMap};
 
+pub use claims::{ClaimsContext, ClaimsExpression, ClaimsRule, Comparison, Operand};
 use common_types::auth::Operations;
 use secrecy::SecretString;
 use serde::{Deserialize, Serialize};
@@ -16,6 +19,9 @@ pub struct AuthConfig {
 
     pub allowed_owner_ops: Operations,
 
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub claims_rules: Vec<ClaimsRule>,
+
     pub provider: Option<AuthProvider>,
 }
 
@@ -90,6 +96,8 @@ impl Default for AuthConfig {
 
             allowed_owner_ops: Operations::empty(),
 
+            claims_rules: Vec::new(),
+
             provider: None,
         }
     }
@@ -109,4 +117,22 @@ impl AuthConfig {
     pub fn owner_based_ops(&self) -> Operations {
         self.allowed_owner_ops
     }
+
+    /// Operations granted by the claims rules which hold. Rules depending on a parent which
+    /// isn't known yet grant nothing, see `deferred_claims_ops`.
+    pub fn claims_based_ops(&self, ctx: &ClaimsContext<'_>) -> Operations {
+        self.claims_rules
+            .iter()
+            .filter(|rule| rule.expression.evaluate(ctx) == Some(true))
+            .fold(Operations::empty(), |ops, rule| ops.union(rule.operations))
+    }
+
+    /// Operations which may be granted once the parent object is known, by rules which don't
+    /// already fail on the claims and arguments alone.
+    pub fn deferred_claims_ops(&self, ctx: &ClaimsContext<'_>) -> Operations {
+        self.claims_rules
+            .iter()
+            .filter(|rule| rule.expression.evaluate(ctx).is_none())
+            .fold(Operations::empty(), |ops, rule| ops.union(rule.operations))
+    }
 }
@@ -0,0 +1,527 @@
+//! Claims rules grant operations when an expression comparing the token claims with constants,
+//! arguments or fields of the parent object holds, e.g. `tenant_id == $parent.tenantId`.
+//!
+//! The grammar is deliberately small:
+//!
+//! ```text
+//! expression := and ("||" and)*
+//! and        := term ("&&" term)*
+//! term       := "(" expression ")" | operand ("==" | "!=" | "in") operand
+//! operand    := claim | "$parent." path | "$args." path | string | number | boolean | null | list
+//! claim      := path
+//! path       := identifier ("." identifier)*
+//! ```
+
+use std::{collections::BTreeMap, fmt};
+
+use common_types::auth::Operations;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+const PARENT_VARIABLE: &str = "$parent";
+const ARGUMENTS_VARIABLE: &str = "$args";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClaimsRule {
+    pub expression: ClaimsExpression,
+    pub operations: Operations,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum ClaimsExpression {
+    And(Box<ClaimsExpression>, Box<ClaimsExpression>),
+    Or(Box<ClaimsExpression>, Box<ClaimsExpression>),
+    Compare(Operand, Comparison, Operand),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Equals,
+    NotEquals,
+    /// The left value is an item of the right list.
+    In,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Operand {
+    Claim(Vec<String>),
+    /// A field of the object the rule applies to, by its name in the schema.
+    Parent(Vec<String>),
+    Argument(Vec<String>),
+    Constant(Value),
+}
+
+/// The values an expression is evaluated with. The parent isn't known before the object is
+/// resolved, expressions referring to it can't be decided until then.
+#[derive(Debug, Clone, Copy)]
+pub struct ClaimsContext<'a> {
+    pub claims: &'a BTreeMap<String, Value>,
+    pub arguments: Option<&'a Value>,
+    pub parent: Option<&'a Value>,
+}
+
+impl ClaimsExpression {
+    /// Returns `None` if the result depends on the parent, and the parent isn't known.
+    pub fn evaluate(&self, ctx: &ClaimsContext<'_>) -> Option<bool> {
+        match self {
+            ClaimsExpression::And(left, right) => match (left.evaluate(ctx), right.evaluate(ctx)) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true)) => Some(true),
+                _ => None,
+            },
+            ClaimsExpression::Or(left, right) => match (left.evaluate(ctx), right.evaluate(ctx)) {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
+            },
+            ClaimsExpression::Compare(left, comparison, right) => {
+                if (left.is_parent() || right.is_parent()) && ctx.parent.is_none() {
+                    return None;
+                }
+                // A missing value never matches, a rule must not be granted because a claim is absent.
+                let (Some(left), Some(right)) = (left.resolve(ctx), right.resolve(ctx)) else {
+                    return Some(false);
+                };
+                Some(comparison.compare(left, right))
+            }
+        }
+    }
+
+    pub fn references_parent(&self) -> bool {
+        self.operands().any(Operand::is_parent)
+    }
+
+    pub fn references_arguments(&self) -> bool {
+        self.operands().any(|operand| matches!(operand, Operand::Argument(_)))
+    }
+
+    /// The fields of the parent object the expression refers to.
+    pub fn parent_fields(&self) -> impl Iterator<Item = &str> + '_ {
+        self.operands().filter_map(|operand| match operand {
+            Operand::Parent(path) => path.first().map(String::as_str),
+            _ => None,
+        })
+    }
+
+    fn operands(&self) -> Box<dyn Iterator<Item = &Operand> + '_> {
+        match self {
+            ClaimsExpression::And(left, right) | ClaimsExpression::Or(left, right) => {
+                Box::new(left.operands().chain(right.operands()))
+            }
+            ClaimsExpression::Compare(left, _, right) => Box::new([left, right].into_iter()),
+        }
+    }
+}
+
+impl Comparison {
+    pub fn compare(self, left: &Value, right: &Value) -> bool {
+        match self {
+            Comparison::Equals => values_equal(left, right),
+            Comparison::NotEquals => !values_equal(left, right),
+            Comparison::In => right
+                .as_array()
+                .is_some_and(|items| items.iter().any(|item| values_equal(left, item))),
+        }
+    }
+}
+
+impl Operand {
+    pub fn is_parent(&self) -> bool {
+        matches!(self, Operand::Parent(_))
+    }
+
+    /// The value of the operand, `None` if it's missing or if it's a parent field and the
+    /// parent isn't known.
+    pub fn resolve<'a>(&'a self, ctx: &ClaimsContext<'a>) -> Option<&'a Value> {
+        match self {
+            Operand::Claim(path) => {
+                let (first, rest) = path.split_first()?;
+                find_path(ctx.claims.get(first)?, rest)
+            }
+            Operand::Parent(path) => find_path(ctx.parent?, path),
+            Operand::Argument(path) => find_path(ctx.arguments?, path),
+            Operand::Constant(value) => Some(value),
+        }
+    }
+}
+
+fn find_path<'a>(value: &'a Value, path: &[String]) -> Option<&'a Value> {
+    path.iter()
+        .try_fold(value, |value, segment| value.as_object()?.get(segment))
+}
+
+/// IDs may be strings in the token and numbers in the data, scalars are compared by their text.
+fn values_equal(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::String(left), Value::Number(right)) | (Value::Number(right), Value::String(left)) => {
+            *left == right.to_string()
+        }
+        (Value::String(left), Value::Bool(right)) | (Value::Bool(right), Value::String(left)) => {
+            *left == right.to_string()
+        }
+        (left, right) => left == right,
+    }
+}
+
+impl TryFrom<String> for ClaimsExpression {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<ClaimsExpression> for String {
+    fn from(value: ClaimsExpression) -> Self {
+        value.to_string()
+    }
+}
+
+impl std::str::FromStr for ClaimsExpression {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            tokens: tokenize(s)?,
+            position: 0,
+        };
+        let expression = parser.expression()?;
+        match parser.next() {
+            None => Ok(expression),
+            Some(token) => Err(format!("unexpected {token} in claims expression")),
+        }
+    }
+}
+
+impl fmt::Display for ClaimsExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClaimsExpression::And(left, right) => {
+                for (index, side) in [left, right].into_iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(" && ")?;
+                    }
+                    match side.as_ref() {
+                        ClaimsExpression::Or(..) => write!(f, "({side})")?,
+                        _ => write!(f, "{side}")?,
+                    }
+                }
+                Ok(())
+            }
+            ClaimsExpression::Or(left, right) => write!(f, "{left} || {right}"),
+            ClaimsExpression::Compare(left, comparison, right) => write!(f, "{left} {comparison} {right}"),
+        }
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Comparison::Equals => "==",
+            Comparison::NotEquals => "!=",
+            Comparison::In => "in",
+        })
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Claim(path) => f.write_str(&path.join(".")),
+            Operand::Parent(path) => write!(f, "{PARENT_VARIABLE}.{}", path.join(".")),
+            Operand::Argument(path) => write!(f, "{ARGUMENTS_VARIABLE}.{}", path.join(".")),
+            Operand::Constant(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Path(Vec<String>),
+    Variable(String, Vec<String>),
+    Constant(Value),
+    Equals,
+    NotEquals,
+    In,
+    And,
+    Or,
+    OpenParenthesis,
+    CloseParenthesis,
+    OpenBracket,
+    CloseBracket,
+    Comma,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Path(path) => write!(f, "`{}`", path.join(".")),
+            Token::Variable(name, path) => write!(f, "`{name}.{}`", path.join(".")),
+            Token::Constant(value) => write!(f, "`{value}`"),
+            Token::Equals => f.write_str("`==`"),
+            Token::NotEquals => f.write_str("`!=`"),
+            Token::In => f.write_str("`in`"),
+            Token::And => f.write_str("`&&`"),
+            Token::Or => f.write_str("`||`"),
+            Token::OpenParenthesis => f.write_str("`(`"),
+            Token::CloseParenthesis => f.write_str("`)`"),
+            Token::OpenBracket => f.write_str("`[`"),
+            Token::CloseBracket => f.write_str("`]`"),
+            Token::Comma => f.write_str("`,`"),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '(' => Token::OpenParenthesis,
+            ')' => Token::CloseParenthesis,
+            '[' => Token::OpenBracket,
+            ']' => Token::CloseBracket,
+            ',' => Token::Comma,
+            '=' | '!' | '&' | '|' => {
+                let expected = if c == '!' { '=' } else { c };
+                if chars.next_if(|(_, next)| *next == expected).is_none() {
+                    return Err(format!("unexpected `{c}` in claims expression"));
+                }
+                match c {
+                    '=' => Token::Equals,
+                    '!' => Token::NotEquals,
+                    '&' => Token::And,
+                    _ => Token::Or,
+                }
+            }
+            '"' | '\'' => {
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, '\\')) => match chars.next() {
+                            Some((_, escaped)) => value.push(escaped),
+                            None => return Err("unterminated string in claims expression".to_string()),
+                        },
+                        Some((_, next)) if next == c => break,
+                        Some((_, next)) => value.push(next),
+                        None => return Err("unterminated string in claims expression".to_string()),
+                    }
+                }
+                Token::Constant(Value::String(value))
+            }
+            '-' | '0'..='9' => {
+                let mut end = start + c.len_utf8();
+                while let Some((index, next)) = chars.next_if(|(_, next)| next.is_ascii_digit() || *next == '.') {
+                    end = index + next.len_utf8();
+                }
+                let number = &input[start..end];
+                let number = serde_json::from_str::<serde_json::Number>(number)
+                    .map_err(|_| format!("invalid number `{number}` in claims expression"))?;
+                Token::Constant(Value::Number(number))
+            }
+            '$' | '_' | 'a'..='z' | 'A'..='Z' => {
+                let mut end = start + c.len_utf8();
+                while let Some((index, next)) =
+                    chars.next_if(|(_, next)| next.is_ascii_alphanumeric() || matches!(next, '_' | '.'))
+                {
+                    end = index + next.len_utf8();
+                }
+                let word = &input[start..end];
+                match word {
+                    "in" => Token::In,
+                    "true" => Token::Constant(Value::Bool(true)),
+                    "false" => Token::Constant(Value::Bool(false)),
+                    "null" => Token::Constant(Value::Null),
+                    _ => {
+                        let mut path: Vec<String> = word.split('.').map(str::to_string).collect();
+                        let variable = word.starts_with('$').then(|| path.remove(0));
+                        if let Some(ref variable) = variable {
+                            if variable != PARENT_VARIABLE && variable != ARGUMENTS_VARIABLE {
+                                return Err(format!(
+                                    "unknown variable `{variable}` in claims expression, expected {PARENT_VARIABLE} or {ARGUMENTS_VARIABLE}"
+                                ));
+                            }
+                        }
+                        if path.is_empty() || path.iter().any(String::is_empty) {
+                            return Err(format!("invalid path `{word}` in claims expression"));
+                        }
+                        match variable {
+                            Some(variable) => Token::Variable(variable, path),
+                            None => Token::Path(path),
+                        }
+                    }
+                }
+            }
+            c => return Err(format!("unexpected `{c}` in claims expression")),
+        };
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn next_if(&mut self, expected: &Token) -> bool {
+        let matches = self.tokens.get(self.position) == Some(expected);
+        if matches {
+            self.position += 1;
+        }
+        matches
+    }
+
+    fn expression(&mut self) -> Result<ClaimsExpression, String> {
+        let mut expression = self.and()?;
+        while self.next_if(&Token::Or) {
+            expression = ClaimsExpression::Or(Box::new(expression), Box::new(self.and()?));
+        }
+        Ok(expression)
+    }
+
+    fn and(&mut self) -> Result<ClaimsExpression, String> {
+        let mut expression = self.term()?;
+        while self.next_if(&Token::And) {
+            expression = ClaimsExpression::And(Box::new(expression), Box::new(self.term()?));
+        }
+        Ok(expression)
+    }
+
+    fn term(&mut self) -> Result<ClaimsExpression, String> {
+        if self.next_if(&Token::OpenParenthesis) {
+            let expression = self.expression()?;
+            if !self.next_if(&Token::CloseParenthesis) {
+                return Err("missing `)` in claims expression".to_string());
+            }
+            return Ok(expression);
+        }
+
+        let left = self.operand()?;
+        let comparison = match self.next() {
+            Some(Token::Equals) => Comparison::Equals,
+            Some(Token::NotEquals) => Comparison::NotEquals,
+            Some(Token::In) => Comparison::In,
+            Some(token) => return Err(format!("expected a comparison in claims expression, found {token}")),
+            None => return Err("expected a comparison at the end of the claims expression".to_string()),
+        };
+        let right = self.operand()?;
+        Ok(ClaimsExpression::Compare(left, comparison, right))
+    }
+
+    fn operand(&mut self) -> Result<Operand, String> {
+        match self.next() {
+            Some(Token::Path(path)) => Ok(Operand::Claim(path)),
+            Some(Token::Variable(name, path)) if name == PARENT_VARIABLE => Ok(Operand::Parent(path)),
+            Some(Token::Variable(_, path)) => Ok(Operand::Argument(path)),
+            Some(Token::Constant(value)) => Ok(Operand::Constant(value)),
+            Some(Token::OpenBracket) => {
+                let mut items = Vec::new();
+                while !self.next_if(&Token::CloseBracket) {
+                    match self.next() {
+                        Some(Token::Constant(value)) => items.push(value),
+                        _ => return Err("lists in claims expressions can only contain constants".to_string()),
+                    }
+                    if !self.next_if(&Token::Comma) && self.tokens.get(self.position) != Some(&Token::CloseBracket) {
+                        return Err("missing `]` in claims expression".to_string());
+                    }
+                }
+                Ok(Operand::Constant(Value::Array(items)))
+            }
+            Some(token) => Err(format!("expected a value in claims expression, found {token}")),
+            None => Err("expected a value at the end of the claims expression".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn claims() -> BTreeMap<String, Value> {
+        serde_json::from_value(json!({
+            "sub": "user_1",
+            "tenant_id": "42",
+            "org": { "role": "admin" },
+        }))
+        .unwrap()
+    }
+
+    fn evaluate(expression: &str, arguments: Option<Value>, parent: Option<Value>) -> Option<bool> {
+        let expression: ClaimsExpression = expression.parse().unwrap();
+        let claims = claims();
+        expression.evaluate(&ClaimsContext {
+            claims: &claims,
+            arguments: arguments.as_ref(),
+            parent: parent.as_ref(),
+        })
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let expression: ClaimsExpression = "(org.role == 'admin' || tenant_id in [1, \"2\"]) && $parent.ownerId != sub"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            expression.to_string(),
+            r#"(org.role == "admin" || tenant_id in [1,"2"]) && $parent.ownerId != sub"#
+        );
+        assert_eq!(expression.to_string().parse::<ClaimsExpression>().unwrap(), expression);
+        assert_eq!(expression.parent_fields().collect::<Vec<_>>(), vec!["ownerId"]);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |input: &str| input.parse::<ClaimsExpression>().unwrap_err();
+        assert_eq!(
+            error("tenant_id"),
+            "expected a comparison at the end of the claims expression"
+        );
+        assert_eq!(error("tenant_id = 1"), "unexpected `=` in claims expression");
+        assert_eq!(
+            error("$user.id == sub"),
+            "unknown variable `$user` in claims expression, expected $parent or $args"
+        );
+        assert_eq!(error("(sub == 'a'"), "missing `)` in claims expression");
+        assert_eq!(error("sub == 'a' sub"), "unexpected `sub` in claims expression");
+    }
+
+    #[test]
+    fn evaluation() {
+        assert_eq!(evaluate("org.role == 'admin'", None, None), Some(true));
+        assert_eq!(evaluate("tenant_id == 42", None, None), Some(true));
+        assert_eq!(evaluate("missing == null", None, None), Some(false));
+        assert_eq!(evaluate("missing != 'a'", None, None), Some(false));
+        assert_eq!(evaluate("sub in ['user_1', 'user_2']", None, None), Some(true));
+        assert_eq!(
+            evaluate("sub == $args.id", Some(json!({ "id": "user_1" })), None),
+            Some(true)
+        );
+        assert_eq!(evaluate("tenant_id == $parent.tenantId", None, None), None);
+        assert_eq!(
+            evaluate("org.role == 'admin' || tenant_id == $parent.tenantId", None, None),
+            Some(true)
+        );
+        assert_eq!(
+            evaluate("tenant_id == $parent.tenantId", None, Some(json!({ "tenantId": 42 }))),
+            Some(true)
+        );
+        assert_eq!(
+            evaluate("tenant_id == $parent.tenantId", None, Some(json!({ "tenantId": 7 }))),
+            Some(false)
+        );
+    }
+}
@@ -111,6 +111,10 @@ pub struct ResolveInfo<'a> {
 
     /// Arguments for the resolved value, if they exist, resolved.
     pub input_values: Vec<(Positioned<Name>, Option<Value>)>,
+
+    /// The value of the object the field belongs to, as returned by the parent resolver. Root
+    /// fields have none.
+    pub parent_value: Option<&'a serde_json::Value>,
 }
 
 type RequestFut<'a> = &'a mut (dyn Future<Output = Response> + Send + Unpin);
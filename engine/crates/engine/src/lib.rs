@@ -28,6 +28,9 @@ pub mod auth;
 pub mod context;
 pub mod extensions;
 pub mod http;
+pub mod operation_cost;
+pub mod persisted_queries;
+pub mod rate_limiting;
 
 pub mod resolver_utils;
 pub mod types;
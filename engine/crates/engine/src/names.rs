@@ -19,6 +19,17 @@ pub const INPUT_FIELD_FILTER_LIST_INCLUDES: &str = "includes";
 pub const INPUT_FIELD_FILTER_LIST_INCLUDES_NONE: &str = "includesNone";
 pub const INPUT_FIELD_FILTER_LIST_IS_EMPTY: &str = "isEmpty";
 
+pub const INPUT_FIELD_FACET_LIMIT: &str = "limit";
+pub const INPUT_FIELD_FACET_BOUNDARIES: &str = "boundaries";
+pub const INPUT_FIELD_VECTOR_VECTOR: &str = "vector";
+pub const INPUT_FIELD_VECTOR_K: &str = "k";
+pub const INPUT_FIELD_VECTOR_WEIGHT: &str = "weight";
+
+pub const OUTPUT_FIELD_FACET_BUCKET_VALUE: &str = "value";
+pub const OUTPUT_FIELD_FACET_BUCKET_GTE: &str = "gte";
+pub const OUTPUT_FIELD_FACET_BUCKET_LT: &str = "lt";
+pub const OUTPUT_FIELD_FACET_BUCKET_COUNT: &str = "count";
+
 pub const OUTPUT_FIELD_ID: &str = "id";
 pub const OUTPUT_FIELD_DELETED_ID: &str = "deletedId";
 pub const OUTPUT_FIELD_DELETED_IDS: &str = "deletedIds";
@@ -26,6 +37,13 @@ pub const OUTPUT_FIELD_DELETED_IDS: &str = "deletedIds";
 pub const OUTPUT_EDGE_CURSOR: &str = "#cursor";
 
 pub const MONGODB_OUTPUT_FIELD_ID: &str = "_id";
+pub const MONGODB_AGGREGATE_FIELD_COUNT: &str = "count";
+pub const MONGODB_AGGREGATE_FIELD_GROUP: &str = "group";
+pub const MONGODB_AGGREGATE_FIELD_SUM: &str = "sum";
+pub const MONGODB_AGGREGATE_FIELD_AVG: &str = "avg";
+pub const MONGODB_AGGREGATE_FIELD_MIN: &str = "min";
+pub const MONGODB_AGGREGATE_FIELD_MAX: &str = "max";
+pub const MONGODB_AGGREGATE_INPUT_GROUP_BY: &str = "groupBy";
 
 pub const DELETE_PAYLOAD_RETURN_TY_SUFFIX: &str = "DeletePayload";
 
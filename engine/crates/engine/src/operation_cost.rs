@@ -0,0 +1,170 @@
+//! The cost of an operation, counted by the cost limits of the rate limits and by the complexity
+//! limit. Every selected field costs its `@cost` weight, 1 without one, plus the cost of its own
+//! selection set. Fragments are counted at each of their spreads.
+
+use engine_parser::types::{FragmentDefinition, Selection, SelectionSet};
+
+use crate::Registry;
+
+/// How a schema weights its fields.
+pub trait CostSchema {
+    /// `None` if the schema doesn't know the type or the field, which then costs 1.
+    fn field_cost(&self, type_name: &str, field_name: &str) -> Option<FieldCost<'_>>;
+}
+
+pub struct FieldCost<'a> {
+    /// From `@cost(weight: Int!)`.
+    pub weight: Option<u16>,
+    /// The named type of the field, without its list and non-null wrappers.
+    pub output_type: &'a str,
+}
+
+/// The cost of the selection set of an object of type `type_name`, `fragment` looks up the
+/// fragments of the document by name.
+pub fn selection_set_cost<'a>(
+    schema: &dyn CostSchema,
+    type_name: &str,
+    selection_set: &'a SelectionSet,
+    fragment: &dyn Fn(&str) -> Option<&'a FragmentDefinition>,
+) -> u64 {
+    CostVisitor {
+        schema,
+        fragment,
+        spreads: Vec::new(),
+    }
+    .selection_set(type_name, selection_set)
+}
+
+struct CostVisitor<'a, 'v> {
+    schema: &'v dyn CostSchema,
+    fragment: &'v dyn Fn(&str) -> Option<&'a FragmentDefinition>,
+    spreads: Vec<&'a str>,
+}
+
+impl<'a> CostVisitor<'a, '_> {
+    fn selection_set(&mut self, type_name: &str, selection_set: &'a SelectionSet) -> u64 {
+        let mut cost = 0u64;
+        for selection in &selection_set.items {
+            let selection_cost = match &selection.node {
+                Selection::Field(field) => {
+                    let schema = self.schema;
+                    let field_cost = schema.field_cost(type_name, &field.node.name.node);
+                    let weight = field_cost
+                        .as_ref()
+                        .and_then(|field_cost| field_cost.weight)
+                        .map_or(1, u64::from);
+                    // Fields of unknown types are unknown too.
+                    let output_type = field_cost.map_or("", |field_cost| field_cost.output_type);
+                    weight.saturating_add(self.selection_set(output_type, &field.node.selection_set.node))
+                }
+                Selection::InlineFragment(fragment) => {
+                    let type_name = fragment
+                        .node
+                        .type_condition
+                        .as_ref()
+                        .map_or(type_name, |condition| condition.node.on.node.as_str());
+                    self.selection_set(type_name, &fragment.node.selection_set.node)
+                }
+                Selection::FragmentSpread(spread) => {
+                    let name = spread.node.fragment_name.node.as_str();
+                    // Cycles are rejected by the validation, they only need to terminate here.
+                    if self.spreads.contains(&name) {
+                        continue;
+                    }
+                    let Some(fragment) = (self.fragment)(name) else {
+                        continue;
+                    };
+                    self.spreads.push(name);
+                    let cost = self.selection_set(
+                        fragment.type_condition.node.on.node.as_str(),
+                        &fragment.selection_set.node,
+                    );
+                    self.spreads.pop();
+                    cost
+                }
+            };
+            cost = cost.saturating_add(selection_cost);
+        }
+        cost
+    }
+}
+
+impl CostSchema for Registry {
+    fn field_cost(&self, type_name: &str, field_name: &str) -> Option<FieldCost<'_>> {
+        let field = self.types.get(type_name)?.field_by_name(field_name)?;
+        Some(FieldCost {
+            weight: field.cost,
+            output_type: field.ty.base_type_name(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use engine_parser::types::DocumentOperations;
+
+    use super::{selection_set_cost, CostSchema, FieldCost};
+
+    /// Fields with their weight and output type, by type.
+    struct Schema(HashMap<&'static str, HashMap<&'static str, (Option<u16>, &'static str)>>);
+
+    impl CostSchema for Schema {
+        fn field_cost(&self, type_name: &str, field_name: &str) -> Option<FieldCost<'_>> {
+            let (weight, output_type) = self.0.get(type_name)?.get(field_name)?;
+            Some(FieldCost {
+                weight: *weight,
+                output_type,
+            })
+        }
+    }
+
+    fn cost(query: &str, operation_name: Option<&str>) -> u64 {
+        let schema = Schema(HashMap::from([
+            (
+                "Query",
+                HashMap::from([("products", (Some(10), "Product")), ("me", (None, "User"))]),
+            ),
+            (
+                "Product",
+                HashMap::from([
+                    ("id", (None, "ID")),
+                    ("price", (Some(5), "Int")),
+                    ("reviews", (None, "Review")),
+                ]),
+            ),
+            ("Review", HashMap::from([("body", (Some(0), "String"))])),
+        ]));
+        let document = engine_parser::parse_query(query).unwrap();
+        let operation = match (&document.operations, operation_name) {
+            (DocumentOperations::Single(operation), _) => operation,
+            (DocumentOperations::Multiple(operations), Some(name)) => &operations[name],
+            (DocumentOperations::Multiple(_), None) => unreachable!(),
+        };
+        selection_set_cost(&schema, "Query", &operation.node.selection_set.node, &|name| {
+            document.fragments.get(name).map(|fragment| &fragment.node)
+        })
+    }
+
+    #[test]
+    fn test_operation_cost() {
+        assert_eq!(cost("{ products { id name } }", None), 12);
+        assert_eq!(
+            cost(
+                r"
+                query A { products { ...ProductFields ... on Product { price } } }
+                query B { me { id } }
+                fragment ProductFields on Product { id reviews { ...ReviewFields } }
+                fragment ReviewFields on Review { body author { name } }
+                ",
+                Some("A"),
+            ),
+            // products 10, id 1, reviews 1, body 0, author 1, name 1 and price 5.
+            19
+        );
+        assert_eq!(cost("query B { me { id } }", Some("B")), 2);
+        assert_eq!(cost("{ products { ...A } } fragment A on Product { ...A }", None), 10);
+        assert_eq!(cost("{ __typename products { __typename } }", None), 12);
+    }
+}
@@ -0,0 +1,330 @@
+//! Persisted queries let clients send a hash instead of the full query text.
+//!
+//! Two modes are supported:
+//! - Automatic persisted queries (APQ), following Apollo's protocol: a client first sends only
+//!   `extensions.persistedQuery.sha256Hash` and, if the gateway doesn't know it yet, retries with
+//!   both the query and its hash so that it's stored for the next requests.
+//! - Trusted documents: only the documents of a manifest loaded at startup can be executed, either
+//!   by their hash or by their full text.
+
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use runtime::cache::{Cache, Cacheable, Entry, EntryState};
+use sha2::{Digest, Sha256};
+
+use crate::{ErrorExtensionValues, Request, ServerError};
+
+pub const PERSISTED_QUERY_EXTENSION: &str = "persistedQuery";
+
+/// Persisted queries never change for a given hash, they only expire to keep the store bounded.
+const PERSISTED_QUERY_MAX_AGE: Duration = Duration::from_secs(60 * 60 * 24 * 30);
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PersistedQuery {
+    query: String,
+}
+
+impl Cacheable for PersistedQuery {
+    fn max_age(&self) -> Duration {
+        PERSISTED_QUERY_MAX_AGE
+    }
+
+    fn stale_while_revalidate(&self) -> Duration {
+        Duration::ZERO
+    }
+
+    fn cache_tags(&self) -> Vec<String> {
+        vec![]
+    }
+
+    fn should_purge_related(&self) -> bool {
+        false
+    }
+
+    fn should_cache(&self) -> bool {
+        true
+    }
+}
+
+pub type PersistedQueryStore = Arc<dyn Cache<Value = PersistedQuery>>;
+
+pub enum PersistedQueries {
+    Automatic(PersistedQueryStore),
+    Trusted(TrustedDocuments),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PersistedQueryError {
+    // Apollo clients rely on this exact message to retry with the full query.
+    #[error("PersistedQueryNotFound")]
+    NotFound,
+    #[error("provided sha does not match query")]
+    HashMismatch,
+    #[error("Unsupported persisted query version: {0}")]
+    UnsupportedVersion(i64),
+    #[error("Invalid persisted query extension: {0}")]
+    InvalidExtension(String),
+    #[error("Only trusted documents can be executed")]
+    NotTrusted,
+}
+
+impl PersistedQueryError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            PersistedQueryError::NotFound => "PERSISTED_QUERY_NOT_FOUND",
+            PersistedQueryError::HashMismatch
+            | PersistedQueryError::UnsupportedVersion(_)
+            | PersistedQueryError::InvalidExtension(_) => "BAD_REQUEST",
+            PersistedQueryError::NotTrusted => "TRUSTED_DOCUMENT_ERROR",
+        }
+    }
+}
+
+impl From<PersistedQueryError> for ServerError {
+    fn from(error: PersistedQueryError) -> Self {
+        let mut extensions = ErrorExtensionValues::default();
+        extensions.set("code", error.code());
+        ServerError {
+            extensions: Some(extensions),
+            ..ServerError::new(error.to_string(), None)
+        }
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct PersistedQueryExtension {
+    version: i64,
+    #[serde(rename = "sha256Hash")]
+    sha256_hash: String,
+}
+
+impl PersistedQueries {
+    /// Fills in the query of the request from its hash, or stores it for later requests. Requests
+    /// without the persisted query extension are left untouched, unless only trusted documents
+    /// are allowed.
+    pub async fn resolve(&self, request: &mut Request) -> Result<(), PersistedQueryError> {
+        let extension = request
+            .extensions
+            .get(PERSISTED_QUERY_EXTENSION)
+            .map(|value| {
+                crate::from_value::<PersistedQueryExtension>(value.clone())
+                    .map_err(|err| PersistedQueryError::InvalidExtension(err.to_string()))
+            })
+            .transpose()?;
+        if let Some(PersistedQueryExtension { version, .. }) = extension {
+            if version != 1 {
+                return Err(PersistedQueryError::UnsupportedVersion(version));
+            }
+        }
+
+        match self {
+            PersistedQueries::Automatic(store) => {
+                let Some(PersistedQueryExtension { sha256_hash, .. }) = extension else {
+                    return Ok(());
+                };
+                let key = format!("apq.{}", sha256_hash.to_lowercase());
+                if request.query.is_empty() {
+                    // Store errors are treated like misses, the client will send the query.
+                    return match store.get(&key).await {
+                        Ok(
+                            Entry::Hit(persisted)
+                            | Entry::Stale {
+                                response: persisted, ..
+                            },
+                        ) => {
+                            request.query = persisted.query;
+                            Ok(())
+                        }
+                        Ok(Entry::Miss) | Err(_) => Err(PersistedQueryError::NotFound),
+                    };
+                }
+                if !sha256_hash.eq_ignore_ascii_case(&sha256_hex(&request.query)) {
+                    return Err(PersistedQueryError::HashMismatch);
+                }
+                let persisted = PersistedQuery {
+                    query: request.query.clone(),
+                };
+                store
+                    .put(&key, EntryState::Fresh, Arc::new(persisted), vec![])
+                    .await
+                    .ok();
+                Ok(())
+            }
+            PersistedQueries::Trusted(documents) => {
+                let hash = match extension {
+                    Some(PersistedQueryExtension { sha256_hash, .. }) => {
+                        if !request.query.is_empty() && !sha256_hash.eq_ignore_ascii_case(&sha256_hex(&request.query)) {
+                            return Err(PersistedQueryError::HashMismatch);
+                        }
+                        sha256_hash.to_lowercase()
+                    }
+                    None => sha256_hex(&request.query),
+                };
+                let document = documents.get(&hash).ok_or(PersistedQueryError::NotTrusted)?;
+                if request.query.is_empty() {
+                    request.query = document.to_string();
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Allowlist of operations, keyed by the lowercase hex SHA-256 hash of their document.
+#[derive(Debug, Default)]
+pub struct TrustedDocuments {
+    documents: HashMap<String, String>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum TrustedDocumentsManifest {
+    /// The format generated by `@apollo/generate-persisted-query-manifest`.
+    Apollo { operations: Vec<ApolloManifestOperation> },
+    /// A plain hash to document map, as generated by Relay for example.
+    Map(HashMap<String, String>),
+}
+
+#[derive(serde::Deserialize)]
+struct ApolloManifestOperation {
+    id: String,
+    body: String,
+}
+
+impl TrustedDocuments {
+    pub fn from_manifest(manifest: &str) -> Result<Self, serde_json::Error> {
+        let documents = match serde_json::from_str(manifest)? {
+            TrustedDocumentsManifest::Apollo { operations } => operations
+                .into_iter()
+                .map(|operation| (operation.id.to_lowercase(), operation.body))
+                .collect(),
+            TrustedDocumentsManifest::Map(documents) => documents
+                .into_iter()
+                .map(|(hash, document)| (hash.to_lowercase(), document))
+                .collect(),
+        };
+        Ok(TrustedDocuments { documents })
+    }
+
+    pub fn get(&self, hash: &str) -> Option<&str> {
+        self.documents.get(hash).map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+}
+
+fn sha256_hex(query: &str) -> String {
+    hex::encode(Sha256::digest(query.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use runtime::cache::Result;
+
+    use super::*;
+
+    const QUERY: &str = "query { me { id } }";
+
+    #[derive(Default)]
+    struct TestStore(Mutex<HashMap<String, PersistedQuery>>);
+
+    #[async_trait::async_trait]
+    impl Cache for TestStore {
+        type Value = PersistedQuery;
+
+        async fn get(&self, key: &str) -> Result<Entry<PersistedQuery>> {
+            Ok(self.0.lock().unwrap().get(key).cloned().map_or(Entry::Miss, Entry::Hit))
+        }
+
+        async fn put(&self, key: &str, _: EntryState, value: Arc<PersistedQuery>, _: Vec<String>) -> Result<()> {
+            self.0.lock().unwrap().insert(key.to_string(), value.as_ref().clone());
+            Ok(())
+        }
+
+        async fn delete(&self, key: &str) -> Result<()> {
+            self.0.lock().unwrap().remove(key);
+            Ok(())
+        }
+
+        async fn purge_by_tags(&self, _: Vec<String>) -> Result<()> {
+            Ok(())
+        }
+
+        async fn purge_by_hostname(&self, _: String) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn request(query: &str, hash: &str) -> Request {
+        let mut request = Request::new(query);
+        request.extensions.insert(
+            PERSISTED_QUERY_EXTENSION.to_string(),
+            crate::value!({ "version": 1, "sha256Hash": hash }),
+        );
+        request
+    }
+
+    #[tokio::test]
+    async fn automatic_persisted_queries() {
+        let apq = PersistedQueries::Automatic(Arc::new(TestStore::default()));
+        let hash = sha256_hex(QUERY);
+
+        let mut unknown = request("", &hash);
+        assert!(matches!(
+            apq.resolve(&mut unknown).await,
+            Err(PersistedQueryError::NotFound)
+        ));
+
+        let mut wrong_hash = request(QUERY, &sha256_hex("query { __typename }"));
+        assert!(matches!(
+            apq.resolve(&mut wrong_hash).await,
+            Err(PersistedQueryError::HashMismatch)
+        ));
+
+        let mut register = request(QUERY, &hash);
+        apq.resolve(&mut register).await.unwrap();
+
+        let mut known = request("", &hash);
+        apq.resolve(&mut known).await.unwrap();
+        assert_eq!(known.query, QUERY);
+    }
+
+    #[tokio::test]
+    async fn trusted_documents() {
+        let hash = sha256_hex(QUERY);
+        let manifest = serde_json::json!({
+            "format": "apollo-persisted-query-manifest",
+            "version": 1,
+            "operations": [{ "id": hash, "name": "Me", "type": "query", "body": QUERY }]
+        });
+        let trusted = PersistedQueries::Trusted(TrustedDocuments::from_manifest(&manifest.to_string()).unwrap());
+
+        let mut by_hash = request("", &hash);
+        trusted.resolve(&mut by_hash).await.unwrap();
+        assert_eq!(by_hash.query, QUERY);
+
+        let mut by_text = Request::new(QUERY);
+        trusted.resolve(&mut by_text).await.unwrap();
+
+        let mut untrusted = Request::new("query { __typename }");
+        assert!(matches!(
+            trusted.resolve(&mut untrusted).await,
+            Err(PersistedQueryError::NotTrusted)
+        ));
+    }
+
+    #[test]
+    fn relay_manifest() {
+        let manifest = serde_json::json!({ "ABC": QUERY });
+        let documents = TrustedDocuments::from_manifest(&manifest.to_string()).unwrap();
+        assert_eq!(documents.get("abc"), Some(QUERY));
+    }
+}
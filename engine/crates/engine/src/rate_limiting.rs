@@ -0,0 +1,79 @@
+//! Rate limits of the connectors, the operation-wide ones are checked by the gateway before the
+//! operation is executed.
+
+use std::net::IpAddr;
+
+use common_types::auth::ExecutionAuth;
+use runtime::rate_limiting::{RateLimitRequest, RateLimited, RateLimiter, RATE_LIMITED_ERROR_CODE};
+
+use crate::{Context, ContextExt, ContextField, Error, ErrorExtensionValues, RequestHeaders, ServerError};
+
+struct ConnectorRateLimitRequest<'a> {
+    client_ip: Option<IpAddr>,
+    headers: Option<&'a RequestHeaders>,
+    auth: Option<&'a ExecutionAuth>,
+    operation_name: Option<&'a str>,
+}
+
+impl RateLimitRequest for ConnectorRateLimitRequest<'_> {
+    fn client_ip(&self) -> Option<IpAddr> {
+        self.client_ip
+    }
+
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.and_then(|headers| headers.find(name))
+    }
+
+    fn jwt_claim(&self, name: &str) -> Option<String> {
+        match self.auth? {
+            ExecutionAuth::Token(token) => token.get_claim(name),
+            _ => None,
+        }
+    }
+
+    fn operation_name(&self) -> Option<&str> {
+        self.operation_name
+    }
+}
+
+/// Counts a request to the connector against its rate limits, if a [`RateLimiter`] was added to
+/// the request data. Rejections set the `Retry-After` header of the response.
+pub(crate) async fn check_connector_rate_limits(ctx: &ContextField<'_>, connector_name: &str) -> Result<(), Error> {
+    let Ok(rate_limiter) = ctx.data::<RateLimiter>() else {
+        return Ok(());
+    };
+    let request = ConnectorRateLimitRequest {
+        client_ip: ctx
+            .data::<runtime::Context>()
+            .ok()
+            .and_then(|runtime_ctx| runtime_ctx.client_ip()),
+        headers: ctx.data::<RequestHeaders>().ok(),
+        auth: ctx.data::<ExecutionAuth>().ok(),
+        operation_name: ctx.query_env().operation_name.as_deref(),
+    };
+    rate_limiter
+        .check(Some(connector_name), &request, 0)
+        .await
+        .map_err(|rejection| {
+            ctx.insert_http_header(::http::header::RETRY_AFTER, rejection.retry_after_secs());
+            Error {
+                extensions: Some(rate_limited_extensions()),
+                ..Error::new(rejection.to_string())
+            }
+        })
+}
+
+fn rate_limited_extensions() -> ErrorExtensionValues {
+    let mut extensions = ErrorExtensionValues::default();
+    extensions.set("code", RATE_LIMITED_ERROR_CODE);
+    extensions
+}
+
+impl From<RateLimited> for ServerError {
+    fn from(rejection: RateLimited) -> Self {
+        ServerError {
+            extensions: Some(rate_limited_extensions()),
+            ..ServerError::new(rejection.to_string(), None)
+        }
+    }
+}
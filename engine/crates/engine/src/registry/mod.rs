@@ -330,6 +330,9 @@ pub struct MetaField {
     pub r#override: Option<String>,
     pub tags: Vec<String>,
     pub inaccessible: bool,
+    /// From `@cost(weight: Int!)`, the cost of the field in the operations counted by the rate
+    /// limits instead of 1.
+    pub cost: Option<u16>,
 }
 
 impl MetaField {
@@ -1340,8 +1343,27 @@ pub struct MongoDBConfiguration {
     pub api_key: String,
     pub url: String,
     pub data_source: String,
+    /// Connects directly to the database instead of using the Atlas Data API. The Data API
+    /// fields are empty if this is set.
+    #[serde(default)]
+    pub connection_string: Option<String>,
     pub database: String,
     pub namespace: bool,
+    /// Relations between the collections, resolved with a `$lookup` stage. Keyed by the
+    /// model name and the name of the relation field.
+    #[serde(default)]
+    pub relations: BTreeMap<String, BTreeMap<String, MongoDBRelation>>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MongoDBRelation {
+    /// The collection of the related model.
+    pub collection: String,
+    /// The database field name in the model holding the relation field.
+    pub local_field: String,
+    /// The database field name in the related model.
+    pub foreign_field: String,
+    pub is_list: bool,
 }
 
 #[derive(
@@ -1421,6 +1443,8 @@ pub struct Registry {
     pub federation_entities: BTreeMap<String, FederationEntity>,
     #[serde(default)]
     pub enable_ai: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rate_limits: Vec<runtime::rate_limiting::RateLimitRule>,
     // FIXME: Make an enum.
     pub is_federated: bool,
 }
@@ -1446,6 +1470,7 @@ impl Default for Registry {
             enable_kv: false,
             federation_entities: Default::default(),
             enable_ai: false,
+            rate_limits: Vec::new(),
             is_federated: false,
         }
     }
@@ -1489,15 +1514,13 @@ impl Registry {
         match operation_type {
             OperationType::Query => self.query_root(),
             OperationType::Mutation => self.mutation_root(),
-            OperationType::Subscription => {
-                // We don't do subscriptions but may as well implement anyway.
-                self.concrete_type_by_name(
+            OperationType::Subscription => self
+                .concrete_type_by_name(
                     self.subscription_type
                         .as_deref()
                         .expect("we shouldnt get here if theres no subscription type"),
                 )
-                .expect("the registry to be valid")
-            }
+                .expect("the registry to be valid"),
         }
         .try_into()
         .expect("root type should always be a composite type")
@@ -1,9 +1,11 @@
 mod consts;
 mod cursor;
 mod input;
+mod lookup;
 mod normalize;
 mod operation;
 mod pagination;
+mod pipeline;
 mod projection;
 mod request;
 mod value;
@@ -19,8 +21,9 @@ use crate::{ContextExt, ContextField, Error};
 
 type JsonMap = serde_json::Map<String, serde_json::Value>;
 
-/// Resolver for the MongoDB Atlas Data API, which is a MongoDB endpoint using
-/// HTTP protocol for transfer.
+/// Resolver for MongoDB. The requests are sent either to the Atlas Data API, which
+/// is a MongoDB endpoint using HTTP protocol for transfer, or directly to the database
+/// with a connection string.
 ///
 /// # Internal documentation
 /// https://www.notion.so/grafbase/MongoDB-Connector-b4d134d2dd0f41ef88dd25cf19143be8
@@ -0,0 +1,73 @@
+use indexmap::IndexMap;
+use serde_json::json;
+
+use super::{
+    pipeline::{Lookup, Stage},
+    JsonMap,
+};
+use crate::{
+    registry::{MetaType, MongoDBConfiguration},
+    ContextExt, ContextField, SelectionField,
+};
+
+/// Creates the `$lookup` stages for the selected relation fields of the given type. The
+/// related documents of nested relations are joined with a pipeline inside the stage, so
+/// the whole selection is fetched with one request.
+pub(super) fn stages<'a>(
+    ctx: &ContextField<'_>,
+    config: &MongoDBConfiguration,
+    type_name: &str,
+    selection: impl IntoIterator<Item = SelectionField<'a>>,
+) -> Vec<Stage> {
+    let Some(relations) = config.relations.get(type_name) else {
+        return Vec::new();
+    };
+
+    let Some(fields) = ctx.get_type(type_name).and_then(MetaType::fields) else {
+        return Vec::new();
+    };
+
+    // The same relation can be selected multiple times with aliases, but it is joined only once.
+    let mut selected: IndexMap<&str, Vec<SelectionField<'a>>> = IndexMap::new();
+
+    for field in selection
+        .into_iter()
+        .filter(|field| relations.contains_key(field.name()))
+    {
+        selected.entry(field.name()).or_default().push(field);
+    }
+
+    let mut result = Vec::new();
+
+    for (field_name, selections) in selected {
+        let relation = &relations[field_name];
+
+        let target_type = fields
+            .get(field_name)
+            .map(|meta_field| meta_field.ty.base_type_name())
+            .unwrap_or_default();
+
+        let nested_selection: Vec<_> = selections.iter().flat_map(SelectionField::selection_set).collect();
+
+        result.push(Stage::Lookup(Lookup {
+            from: relation.collection.clone(),
+            local_field: relation.local_field.clone(),
+            foreign_field: relation.foreign_field.clone(),
+            pipeline: stages(ctx, config, target_type, nested_selection),
+            r#as: field_name.to_string(),
+        }));
+
+        // A to-one relation is joined as an array with at most one document.
+        if !relation.is_list {
+            let mut set = JsonMap::new();
+            set.insert(
+                field_name.to_string(),
+                json!({ "$arrayElemAt": [format!("${field_name}"), 0] }),
+            );
+
+            result.push(Stage::Set(set));
+        }
+    }
+
+    result
+}
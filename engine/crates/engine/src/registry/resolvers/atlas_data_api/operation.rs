@@ -11,6 +11,7 @@ pub enum OperationType {
     DeleteMany,
     UpdateOne,
     UpdateMany,
+    Aggregate,
 }
 
 impl AsRef<str> for OperationType {
@@ -24,6 +25,7 @@ impl AsRef<str> for OperationType {
             Self::DeleteMany => "deleteMany",
             Self::UpdateOne => "updateOne",
             Self::UpdateMany => "updateMany",
+            Self::Aggregate => "aggregate",
         }
     }
 }
@@ -0,0 +1,37 @@
+use indexmap::IndexMap;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::JsonMap;
+
+/// A stage in an aggregation pipeline.
+#[derive(Debug, Clone, Serialize)]
+pub(super) enum Stage {
+    #[serde(rename = "$match")]
+    Match(JsonMap),
+    #[serde(rename = "$sort")]
+    Sort(IndexMap<String, Value>),
+    #[serde(rename = "$limit")]
+    Limit(usize),
+    #[serde(rename = "$lookup")]
+    Lookup(Lookup),
+    #[serde(rename = "$set")]
+    Set(JsonMap),
+    #[serde(rename = "$group")]
+    Group(JsonMap),
+    #[serde(rename = "$project")]
+    Project(JsonMap),
+}
+
+/// Joins the matching documents of another collection into an array field.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Lookup {
+    pub(super) from: String,
+    pub(super) local_field: String,
+    pub(super) foreign_field: String,
+    /// Stages to run on the joined documents, such as lookups of nested relations.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(super) pipeline: Vec<Stage>,
+    pub(super) r#as: String,
+}
@@ -1,12 +1,12 @@
 mod query;
+mod transport;
 
-use http::{
-    header::{ACCEPT, CONTENT_TYPE, USER_AGENT},
-    StatusCode,
+use query::{
+    Aggregate, AtlasQuery, DeleteMany, DeleteOne, FindMany, FindOne, InsertMany, InsertOne, UpdateMany, UpdateOne,
 };
-use query::{AtlasQuery, DeleteMany, DeleteOne, FindMany, FindOne, InsertMany, InsertOne, UpdateMany, UpdateOne};
 use serde::Serialize;
 use serde_json::Value;
+use transport::Transport;
 
 use super::OperationType;
 use crate::{
@@ -14,15 +14,9 @@ use crate::{
         resolvers::{ResolvedValue, ResolverContext},
         MongoDBConfiguration,
     },
-    ContextExt, ContextField, Error,
+    ContextField, Error,
 };
 
-mod headers {
-    pub const API_KEY_HEADER_NAME: &str = "apiKey";
-    pub const APPLICATION_JSON_CONTENT_TYPE: &str = "application/json";
-    pub const APPLICATION_EJSON_CONTENT_TYPE: &str = "application/ejson";
-}
-
 pub(super) async fn execute(
     ctx: &ContextField<'_>,
     resolver_ctx: &ResolverContext<'_>,
@@ -31,14 +25,21 @@ pub(super) async fn execute(
     operation_type: OperationType,
 ) -> Result<ResolvedValue, Error> {
     let query: AtlasQuery = match operation_type {
-        OperationType::FindOne => FindOne::new(ctx, resolver_ctx)?.into(),
-        OperationType::FindMany => FindMany::new(ctx, resolver_ctx)?.into(),
+        OperationType::FindOne => FindOne::new(ctx, resolver_ctx, config)?.into(),
+        OperationType::FindMany => FindMany::new(ctx, resolver_ctx, config)?.into(),
         OperationType::InsertOne => InsertOne::new(ctx)?.into(),
         OperationType::DeleteOne => DeleteOne::new(ctx)?.into(),
         OperationType::DeleteMany => DeleteMany::new(ctx)?.into(),
         OperationType::InsertMany => InsertMany::new(ctx)?.into(),
         OperationType::UpdateOne => UpdateOne::new(ctx)?.into(),
         OperationType::UpdateMany => UpdateMany::new(ctx)?.into(),
+        OperationType::Aggregate => Aggregate::new(ctx, resolver_ctx)?.into(),
+    };
+
+    // Finds joining relations are executed as aggregation pipelines.
+    let operation_type = match query {
+        AtlasQuery::Aggregate(_) => OperationType::Aggregate,
+        _ => operation_type,
     };
 
     // In some cases, if our input is empty, we want to short-circuit here and
@@ -58,28 +59,7 @@ pub(super) async fn execute(
         query,
     };
 
-    let url = format!("{}/action/{}", config.url, operation_type);
-    let runtime_ctx = ctx.data::<runtime::Context>()?;
-    let ray_id = runtime_ctx.ray_id();
-    let fetch_log_endpoint_url = runtime_ctx.log.fetch_log_endpoint_url.as_deref();
-
-    let request_builder = reqwest::Client::new()
-        .post(url)
-        .header(CONTENT_TYPE, headers::APPLICATION_EJSON_CONTENT_TYPE)
-        .header(ACCEPT, headers::APPLICATION_JSON_CONTENT_TYPE)
-        .header(headers::API_KEY_HEADER_NAME, &config.api_key)
-        .header(USER_AGENT, "Grafbase")
-        .json(&request);
-
-    let value = super::super::logged_fetch::send_logged_request(ray_id, fetch_log_endpoint_url, request_builder)
-        .await
-        .map_err(map_err)?
-        .error_for_status()
-        .map_err(map_err)?
-        .json::<serde_json::Value>()
-        .await
-        .map_err(map_err)?
-        .take();
+    let value = Transport::new(config).execute(ctx, operation_type, &request).await?;
 
     request.convert_result(ctx, resolver_ctx, value)
 }
@@ -104,25 +84,10 @@ impl<'a> AtlasRequest<'a> {
         let result = match self.query {
             AtlasQuery::FindOne(ref query) => query.convert_result(&mut value),
             AtlasQuery::FindMany(ref query) => query.convert_result(ctx, resolver_ctx, &mut value)?,
+            AtlasQuery::Aggregate(ref query) => query.convert_result(ctx, resolver_ctx, &mut value)?,
             _ => ResolvedValue::new(value),
         };
 
         Ok(result)
     }
 }
-
-fn map_err(error: reqwest::Error) -> Error {
-    match error.status() {
-        Some(StatusCode::BAD_REQUEST) => Error::new(format!("the request was malformed: {error}")),
-        Some(StatusCode::NOT_FOUND) => Error::new(
-            "the request was sent to an endpoint that does not exist, please check the connector configuration",
-        ),
-        Some(StatusCode::UNAUTHORIZED) => {
-            Error::new("the request did not include an authorized and enabled Atlas Data API Key")
-        }
-        Some(StatusCode::INTERNAL_SERVER_ERROR) => {
-            Error::new("the Atlas Data API encountered an internal error and could not complete the request")
-        }
-        _ => Error::new(error.to_string()),
-    }
-}
@@ -1,3 +1,4 @@
+mod aggregate;
 mod delete_many;
 mod delete_one;
 mod find_many;
@@ -7,6 +8,7 @@ mod insert_one;
 mod update_many;
 mod update_one;
 
+pub(super) use aggregate::Aggregate;
 pub(super) use delete_many::DeleteMany;
 pub(super) use delete_one::DeleteOne;
 pub(super) use find_many::FindMany;
@@ -28,6 +30,7 @@ pub(super) enum AtlasQuery {
     DeleteMany(DeleteMany),
     UpdateOne(UpdateOne),
     UpdateMany(UpdateMany),
+    Aggregate(Aggregate),
 }
 
 impl AtlasQuery {
@@ -0,0 +1,187 @@
+use indexmap::IndexMap;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use super::{AtlasQuery, FindMany, FindOne};
+use crate::{
+    names::{
+        MONGODB_AGGREGATE_FIELD_AVG, MONGODB_AGGREGATE_FIELD_COUNT, MONGODB_AGGREGATE_FIELD_GROUP,
+        MONGODB_AGGREGATE_FIELD_MAX, MONGODB_AGGREGATE_FIELD_MIN, MONGODB_AGGREGATE_FIELD_SUM,
+        MONGODB_AGGREGATE_INPUT_GROUP_BY, MONGODB_OUTPUT_FIELD_ID,
+    },
+    registry::{
+        resolvers::{
+            atlas_data_api::{input, pipeline::Stage, JsonMap},
+            ResolvedValue, ResolverContext,
+        },
+        type_kinds::{OutputType, SelectionSetTarget},
+    },
+    Context, ContextField, Error, SelectionField,
+};
+
+const ACCUMULATORS: &[&str] = &[
+    MONGODB_AGGREGATE_FIELD_SUM,
+    MONGODB_AGGREGATE_FIELD_AVG,
+    MONGODB_AGGREGATE_FIELD_MIN,
+    MONGODB_AGGREGATE_FIELD_MAX,
+];
+
+#[derive(Debug, Clone)]
+enum Output {
+    /// The aggregated groups of an aggregate query.
+    Groups,
+    /// A find query for one document, joining relations.
+    FindOne,
+    /// A find query for a page of documents, joining relations.
+    FindMany(FindMany),
+}
+
+/// A query executed as an aggregation pipeline.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Aggregate {
+    pipeline: Vec<Stage>,
+    #[serde(skip)]
+    output: Output,
+}
+
+impl Aggregate {
+    pub fn new(ctx: &ContextField<'_>, resolver_ctx: &ResolverContext<'_>) -> Result<Self, Error> {
+        let selection_set: SelectionSetTarget<'_> = resolver_ctx.ty.try_into().unwrap();
+        let filter = input::filter(ctx)?;
+
+        let group_type = selection_set
+            .field(MONGODB_AGGREGATE_FIELD_GROUP)
+            .and_then(|field| ctx.registry().lookup(&field.ty).ok());
+
+        let group_by: Vec<String> = ctx
+            .input_by_name(MONGODB_AGGREGATE_INPUT_GROUP_BY)
+            .ok()
+            .unwrap_or_default();
+
+        let mut group_id = JsonMap::new();
+
+        for field_name in group_by {
+            let field = group_type
+                .as_ref()
+                .and_then(|group_type| group_type.field(&field_name))
+                .ok_or_else(|| Error::new(format!("Cannot group by the field {field_name}.")))?;
+
+            let database_name = field.target_field_name();
+            group_id.insert(database_name.to_string(), Value::String(format!("${database_name}")));
+        }
+
+        let mut group = JsonMap::new();
+        let mut projection = JsonMap::new();
+
+        if group_id.is_empty() {
+            group.insert(MONGODB_OUTPUT_FIELD_ID.to_string(), Value::Null);
+        } else {
+            group.insert(MONGODB_OUTPUT_FIELD_ID.to_string(), Value::Object(group_id));
+        }
+
+        group.insert(MONGODB_AGGREGATE_FIELD_COUNT.to_string(), json!({ "$sum": 1 }));
+
+        projection.insert(MONGODB_OUTPUT_FIELD_ID.to_string(), Value::from(0));
+        projection.insert(MONGODB_AGGREGATE_FIELD_COUNT.to_string(), Value::from(1));
+
+        projection.insert(
+            MONGODB_AGGREGATE_FIELD_GROUP.to_string(),
+            Value::String(format!("${MONGODB_OUTPUT_FIELD_ID}")),
+        );
+
+        for accumulator in ACCUMULATORS {
+            let accumulator_type = selection_set
+                .field(accumulator)
+                .and_then(|field| ctx.registry().lookup(&field.ty).ok());
+
+            let Some(fields) = accumulator_type.as_ref().and_then(OutputType::field_map) else {
+                continue;
+            };
+
+            let selection = ctx.look_ahead().field(accumulator).selection_fields();
+            let mut output = JsonMap::new();
+
+            for field in selection.iter().flat_map(SelectionField::selection_set) {
+                let Some(meta_field) = fields.get(field.name()) else {
+                    continue;
+                };
+
+                let database_name = meta_field.target_field_name();
+                let key = format!("{accumulator}_{database_name}");
+
+                group.insert(
+                    key.clone(),
+                    json!({ (format!("${accumulator}")): format!("${database_name}") }),
+                );
+                output.insert(database_name.to_string(), Value::String(format!("${key}")));
+            }
+
+            if !output.is_empty() {
+                projection.insert(accumulator.to_string(), Value::Object(output));
+            }
+        }
+
+        // The groups are returned in the order of their values.
+        let mut sort = IndexMap::new();
+        sort.insert(MONGODB_OUTPUT_FIELD_ID.to_string(), Value::from(1));
+
+        let pipeline = vec![
+            Stage::Match(filter),
+            Stage::Group(group),
+            Stage::Sort(sort),
+            Stage::Project(projection),
+        ];
+
+        Ok(Self {
+            pipeline,
+            output: Output::Groups,
+        })
+    }
+
+    pub(super) fn find_one(query: FindOne) -> Self {
+        Self {
+            pipeline: query.into_pipeline(),
+            output: Output::FindOne,
+        }
+    }
+
+    pub(super) fn find_many(query: FindMany) -> Self {
+        Self {
+            pipeline: query.pipeline(),
+            output: Output::FindMany(query),
+        }
+    }
+
+    pub fn convert_result(
+        &self,
+        ctx: &ContextField<'_>,
+        resolver_ctx: &ResolverContext<'_>,
+        result: &mut Value,
+    ) -> Result<ResolvedValue, Error> {
+        if let Output::FindMany(ref query) = self.output {
+            return query.convert_result(ctx, resolver_ctx, result);
+        }
+
+        let documents = result
+            .as_object_mut()
+            .and_then(|object| object.remove("documents"))
+            .unwrap_or_else(|| Value::Array(Vec::new()));
+
+        let value = match self.output {
+            Output::FindOne => match documents {
+                Value::Array(documents) => documents.into_iter().next().unwrap_or(Value::Null),
+                _ => Value::Null,
+            },
+            _ => documents,
+        };
+
+        Ok(ResolvedValue::new(value))
+    }
+}
+
+impl From<Aggregate> for AtlasQuery {
+    fn from(value: Aggregate) -> Self {
+        Self::Aggregate(value)
+    }
+}
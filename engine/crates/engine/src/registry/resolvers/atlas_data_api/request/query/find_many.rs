@@ -3,22 +3,24 @@ use runtime::search::GraphqlCursor;
 use serde::Serialize;
 use serde_json::Value;
 
-use super::AtlasQuery;
+use super::{Aggregate, AtlasQuery};
 use crate::{
     names::OUTPUT_EDGE_CURSOR,
     registry::{
         resolvers::{
             atlas_data_api::{
                 cursor::AtlasCursor,
-                input,
+                input, lookup,
                 pagination::{self, PaginationContext},
+                pipeline::Stage,
                 projection, JsonMap,
             },
             ResolvedValue, ResolverContext,
         },
         type_kinds::{OutputType, SelectionSetTarget},
+        MongoDBConfiguration,
     },
-    Context, ContextField, Error,
+    Context, ContextField, Error, SelectionField,
 };
 
 #[derive(Debug, Clone)]
@@ -35,11 +37,17 @@ pub struct FindMany {
     sort: Option<IndexMap<String, Value>>,
     limit: Option<usize>,
     #[serde(skip)]
+    lookups: Vec<Stage>,
+    #[serde(skip)]
     metadata: Metadata,
 }
 
 impl FindMany {
-    pub fn new(ctx: &ContextField<'_>, resolver_ctx: &ResolverContext<'_>) -> Result<Self, Error> {
+    pub fn new(
+        ctx: &ContextField<'_>,
+        resolver_ctx: &ResolverContext<'_>,
+        config: &MongoDBConfiguration,
+    ) -> Result<Self, Error> {
         match (input::first(ctx), input::last(ctx)) {
             (Some(_), Some(_)) => {
                 return Err(Error::new("first and last parameters can't be both defined"));
@@ -63,7 +71,16 @@ impl FindMany {
         let selection_field_types = selection_type.as_ref().and_then(OutputType::field_map).unwrap();
 
         let selection = ctx.look_ahead().field("edges").field("node").selection_fields();
-        let projection = projection::project(ctx, selection.into_iter(), selection_field_types)?;
+        let projection = projection::project(ctx, selection.clone().into_iter(), selection_field_types)?;
+
+        let lookups = match selection_type {
+            Some(ref selection_type) => {
+                let nested_selection = selection.iter().flat_map(SelectionField::selection_set);
+                lookup::stages(ctx, config, selection_type.name(), nested_selection)
+            }
+            None => Vec::new(),
+        };
+
         let filter = input::filter(ctx)?;
 
         let order_by = input::order_by(ctx);
@@ -78,6 +95,7 @@ impl FindMany {
             projection,
             sort,
             limit,
+            lookups,
             metadata,
         })
     }
@@ -135,6 +153,24 @@ impl FindMany {
         Ok(resolved_value)
     }
 
+    /// The query as an aggregation pipeline, joining the selected relations.
+    pub(super) fn pipeline(&self) -> Vec<Stage> {
+        let mut pipeline = vec![Stage::Match(self.filter.clone())];
+
+        if let Some(ref sort) = self.sort {
+            pipeline.push(Stage::Sort(sort.clone()));
+        }
+
+        if let Some(limit) = self.limit {
+            pipeline.push(Stage::Limit(limit));
+        }
+
+        pipeline.extend(self.lookups.iter().cloned());
+        pipeline.push(Stage::Project(self.projection.clone()));
+
+        pipeline
+    }
+
     fn order_by(&self) -> Option<&[JsonMap]> {
         self.metadata.order_by.as_deref()
     }
@@ -142,6 +178,11 @@ impl FindMany {
 
 impl From<FindMany> for AtlasQuery {
     fn from(value: FindMany) -> Self {
-        Self::FindMany(value)
+        // Relations can only be joined with the aggregate action.
+        if value.lookups.is_empty() {
+            Self::FindMany(value)
+        } else {
+            Self::Aggregate(Aggregate::find_many(value))
+        }
     }
 }
@@ -1,15 +1,16 @@
 use serde::Serialize;
 
-use super::AtlasQuery;
+use super::{Aggregate, AtlasQuery};
 use crate::{
     registry::{
         resolvers::{
-            atlas_data_api::{input, projection, JsonMap},
+            atlas_data_api::{input, lookup, pipeline::Stage, projection, JsonMap},
             ResolvedValue, ResolverContext,
         },
         type_kinds::SelectionSetTarget,
+        MongoDBConfiguration,
     },
-    ContextField, Error,
+    ContextField, Error, SelectionField,
 };
 
 #[derive(Debug, Clone, Serialize)]
@@ -17,18 +18,31 @@ use crate::{
 pub struct FindOne {
     filter: JsonMap,
     projection: JsonMap,
+    #[serde(skip)]
+    lookups: Vec<Stage>,
 }
 
 impl FindOne {
-    pub fn new(ctx: &ContextField<'_>, resolver_ctx: &ResolverContext<'_>) -> Result<Self, Error> {
+    pub fn new(
+        ctx: &ContextField<'_>,
+        resolver_ctx: &ResolverContext<'_>,
+        config: &MongoDBConfiguration,
+    ) -> Result<Self, Error> {
         let selection_set: SelectionSetTarget<'_> = resolver_ctx.ty.try_into().unwrap();
         let available_fields = selection_set.field_map().unwrap();
         let selection = ctx.look_ahead().selection_fields();
 
-        let projection = projection::project(ctx, selection.into_iter(), available_fields)?;
+        let projection = projection::project(ctx, selection.clone().into_iter(), available_fields)?;
         let filter = input::by(ctx)?;
 
-        Ok(Self { filter, projection })
+        let nested_selection = selection.iter().flat_map(SelectionField::selection_set);
+        let lookups = lookup::stages(ctx, config, resolver_ctx.ty.name(), nested_selection);
+
+        Ok(Self {
+            filter,
+            projection,
+            lookups,
+        })
     }
 
     pub fn convert_result(&self, result: &mut serde_json::Value) -> ResolvedValue {
@@ -39,10 +53,25 @@ impl FindOne {
 
         ResolvedValue::new(value)
     }
+
+    /// The query as an aggregation pipeline, joining the selected relations.
+    pub(super) fn into_pipeline(self) -> Vec<Stage> {
+        let mut pipeline = vec![Stage::Match(self.filter), Stage::Limit(1)];
+
+        pipeline.extend(self.lookups);
+        pipeline.push(Stage::Project(self.projection));
+
+        pipeline
+    }
 }
 
 impl From<FindOne> for AtlasQuery {
     fn from(value: FindOne) -> Self {
-        Self::FindOne(value)
+        // Relations can only be joined with the aggregate action.
+        if value.lookups.is_empty() {
+            Self::FindOne(value)
+        } else {
+            Self::Aggregate(Aggregate::find_one(value))
+        }
     }
 }
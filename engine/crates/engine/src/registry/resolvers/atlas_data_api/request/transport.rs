@@ -0,0 +1,47 @@
+mod data_api;
+#[cfg(not(target_arch = "wasm32"))]
+mod driver;
+
+use serde_json::Value;
+
+use super::AtlasRequest;
+use crate::{
+    registry::{resolvers::atlas_data_api::OperationType, MongoDBConfiguration},
+    ContextField, Error,
+};
+
+/// The way a request is sent to MongoDB. Both transports take the same request, and return
+/// the response in the format of the Atlas Data API, so the results are converted the same
+/// way no matter how the database is reached.
+pub(super) enum Transport<'a> {
+    /// Sends the request over HTTP to the Atlas Data API.
+    DataApi(&'a MongoDBConfiguration),
+    /// Connects directly to the database with a `mongodb://` connection string.
+    Driver(&'a str),
+}
+
+impl<'a> Transport<'a> {
+    pub(super) fn new(config: &'a MongoDBConfiguration) -> Self {
+        match config.connection_string {
+            Some(ref connection_string) => Self::Driver(connection_string),
+            None => Self::DataApi(config),
+        }
+    }
+
+    pub(super) async fn execute(
+        &self,
+        ctx: &ContextField<'_>,
+        operation_type: OperationType,
+        request: &AtlasRequest<'_>,
+    ) -> Result<Value, Error> {
+        match self {
+            Self::DataApi(config) => data_api::execute(ctx, config, operation_type, request).await,
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::Driver(connection_string) => driver::execute(connection_string, operation_type, request).await,
+            #[cfg(target_arch = "wasm32")]
+            Self::Driver(_) => Err(Error::new(
+                "connecting to MongoDB with a connection string is not supported in this environment, please use the Atlas Data API",
+            )),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use http::{
+    header::{ACCEPT, CONTENT_TYPE, USER_AGENT},
+    StatusCode,
+};
+use serde_json::Value;
+
+use super::super::AtlasRequest;
+use crate::{
+    registry::{
+        resolvers::{atlas_data_api::OperationType, logged_fetch::send_logged_request},
+        MongoDBConfiguration,
+    },
+    ContextExt, ContextField, Error,
+};
+
+mod headers {
+    pub const API_KEY_HEADER_NAME: &str = "apiKey";
+    pub const APPLICATION_JSON_CONTENT_TYPE: &str = "application/json";
+    pub const APPLICATION_EJSON_CONTENT_TYPE: &str = "application/ejson";
+}
+
+pub(super) async fn execute(
+    ctx: &ContextField<'_>,
+    config: &MongoDBConfiguration,
+    operation_type: OperationType,
+    request: &AtlasRequest<'_>,
+) -> Result<Value, Error> {
+    let url = format!("{}/action/{}", config.url, operation_type);
+    let runtime_ctx = ctx.data::<runtime::Context>()?;
+    let ray_id = runtime_ctx.ray_id();
+    let fetch_log_endpoint_url = runtime_ctx.log.fetch_log_endpoint_url.as_deref();
+
+    let request_builder = reqwest::Client::new()
+        .post(url)
+        .header(CONTENT_TYPE, headers::APPLICATION_EJSON_CONTENT_TYPE)
+        .header(ACCEPT, headers::APPLICATION_JSON_CONTENT_TYPE)
+        .header(headers::API_KEY_HEADER_NAME, &config.api_key)
+        .header(USER_AGENT, "Grafbase")
+        .json(request);
+
+    send_logged_request(ray_id, fetch_log_endpoint_url, request_builder)
+        .await
+        .map_err(map_err)?
+        .error_for_status()
+        .map_err(map_err)?
+        .json::<Value>()
+        .await
+        .map_err(map_err)
+}
+
+fn map_err(error: reqwest::Error) -> Error {
+    match error.status() {
+        Some(StatusCode::BAD_REQUEST) => Error::new(format!("the request was malformed: {error}")),
+        Some(StatusCode::NOT_FOUND) => Error::new(
+            "the request was sent to an endpoint that does not exist, please check the connector configuration",
+        ),
+        Some(StatusCode::UNAUTHORIZED) => {
+            Error::new("the request did not include an authorized and enabled Atlas Data API Key")
+        }
+        Some(StatusCode::INTERNAL_SERVER_ERROR) => {
+            Error::new("the Atlas Data API encountered an internal error and could not complete the request")
+        }
+        _ => Error::new(error.to_string()),
+    }
+}
@@ -0,0 +1,257 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use bson::{Bson, Document};
+use chrono::{DateTime, SecondsFormat, Utc};
+use futures_util::TryStreamExt;
+use mongodb::{
+    error::ErrorKind,
+    options::{FindOneOptions, FindOptions},
+    Client,
+};
+use once_cell::sync::Lazy;
+use serde_json::{json, Value};
+
+use super::super::AtlasRequest;
+use crate::{registry::resolvers::atlas_data_api::OperationType, Error};
+
+/// The extended JSON keys the engine uses to describe values JSON cannot represent.
+const EXTENDED_JSON_KEYS: &[&str] = &[
+    "$oid",
+    "$date",
+    "$binary",
+    "$timestamp",
+    "$numberLong",
+    "$numberDecimal",
+];
+
+/// The clients hold a connection pool, so one client is shared per connection string.
+static CLIENTS: Lazy<Mutex<HashMap<String, Client>>> = Lazy::new(Default::default);
+
+pub(super) async fn execute(
+    connection_string: &str,
+    operation_type: OperationType,
+    request: &AtlasRequest<'_>,
+) -> Result<Value, Error> {
+    let client = client(connection_string).await?;
+
+    let collection = client
+        .database(request.database)
+        .collection::<Document>(request.collection);
+
+    // The request is serialized straight to BSON to keep the order of the sort keys.
+    let mut body = match bson::to_bson(&request.query).map(from_extended_json) {
+        Ok(Bson::Document(document)) => document,
+        Ok(_) => return Err(Error::new("the request must be a document")),
+        Err(error) => return Err(Error::new(format!("the request was malformed: {error}"))),
+    };
+
+    let filter = take_document(&mut body, "filter");
+
+    let value = match operation_type {
+        OperationType::FindOne => {
+            let options = FindOneOptions::builder()
+                .projection(take_document(&mut body, "projection"))
+                .build();
+
+            let document = collection.find_one(filter, options).await.map_err(map_err)?;
+
+            json!({ "document": document.map(Bson::Document).map(to_json) })
+        }
+        OperationType::FindMany => {
+            let limit = match body.get("limit") {
+                Some(Bson::Int64(limit)) => Some(*limit),
+                Some(Bson::Int32(limit)) => Some(i64::from(*limit)),
+                _ => None,
+            };
+
+            let options = FindOptions::builder()
+                .projection(take_document(&mut body, "projection"))
+                .sort(take_document(&mut body, "sort"))
+                .limit(limit)
+                .build();
+
+            let documents: Vec<Document> = collection
+                .find(filter, options)
+                .await
+                .map_err(map_err)?
+                .try_collect()
+                .await
+                .map_err(map_err)?;
+
+            json!({ "documents": documents.into_iter().map(Bson::Document).map(to_json).collect::<Vec<_>>() })
+        }
+        OperationType::InsertOne => {
+            let document = take_document(&mut body, "document").unwrap_or_default();
+            let result = collection.insert_one(document, None).await.map_err(map_err)?;
+
+            json!({ "insertedId": to_json(result.inserted_id) })
+        }
+        OperationType::InsertMany => {
+            let documents = match body.remove("documents") {
+                Some(Bson::Array(documents)) => documents
+                    .into_iter()
+                    .filter_map(|document| match document {
+                        Bson::Document(document) => Some(document),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            };
+
+            let result = collection.insert_many(documents, None).await.map_err(map_err)?;
+
+            let mut inserted_ids: Vec<_> = result.inserted_ids.into_iter().collect();
+            inserted_ids.sort_by_key(|(index, _)| *index);
+
+            let inserted_ids: Vec<_> = inserted_ids.into_iter().map(|(_, id)| to_json(id)).collect();
+
+            json!({ "insertedIds": inserted_ids })
+        }
+        OperationType::UpdateOne | OperationType::UpdateMany => {
+            let filter = filter.unwrap_or_default();
+            let update = take_document(&mut body, "update").unwrap_or_default();
+
+            let result = match operation_type {
+                OperationType::UpdateOne => collection.update_one(filter, update, None).await,
+                _ => collection.update_many(filter, update, None).await,
+            }
+            .map_err(map_err)?;
+
+            json!({
+                "matchedCount": result.matched_count,
+                "modifiedCount": result.modified_count,
+            })
+        }
+        OperationType::DeleteOne | OperationType::DeleteMany => {
+            let filter = filter.unwrap_or_default();
+
+            let result = match operation_type {
+                OperationType::DeleteOne => collection.delete_one(filter, None).await,
+                _ => collection.delete_many(filter, None).await,
+            }
+            .map_err(map_err)?;
+
+            json!({ "deletedCount": result.deleted_count })
+        }
+        OperationType::Aggregate => {
+            let pipeline = match body.remove("pipeline") {
+                Some(Bson::Array(stages)) => stages
+                    .into_iter()
+                    .filter_map(|stage| match stage {
+                        Bson::Document(stage) => Some(stage),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            };
+
+            let documents: Vec<Document> = collection
+                .aggregate(pipeline, None)
+                .await
+                .map_err(map_err)?
+                .try_collect()
+                .await
+                .map_err(map_err)?;
+
+            json!({ "documents": documents.into_iter().map(Bson::Document).map(to_json).collect::<Vec<_>>() })
+        }
+    };
+
+    Ok(value)
+}
+
+async fn client(connection_string: &str) -> Result<Client, Error> {
+    let cached = CLIENTS.lock().unwrap().get(connection_string).cloned();
+
+    if let Some(client) = cached {
+        return Ok(client);
+    }
+
+    let client = Client::with_uri_str(connection_string).await.map_err(map_err)?;
+
+    let client = CLIENTS
+        .lock()
+        .unwrap()
+        .entry(connection_string.to_string())
+        .or_insert(client)
+        .clone();
+
+    Ok(client)
+}
+
+fn take_document(body: &mut Document, key: &str) -> Option<Document> {
+    match body.remove(key) {
+        Some(Bson::Document(document)) => Some(document),
+        _ => None,
+    }
+}
+
+/// The engine writes special values, such as object ids and dates, in the extended JSON
+/// format the Data API understands. Here they are converted into the matching BSON values.
+fn from_extended_json(value: Bson) -> Bson {
+    match value {
+        Bson::Document(document) => {
+            let is_extended_json = document.len() == 1
+                && document
+                    .keys()
+                    .next()
+                    .is_some_and(|key| EXTENDED_JSON_KEYS.contains(&key.as_str()));
+
+            if is_extended_json {
+                let extended_json = Bson::Document(document.clone()).into_relaxed_extjson();
+
+                if let Ok(value) = Bson::try_from(extended_json) {
+                    return value;
+                }
+            }
+
+            document
+                .into_iter()
+                .map(|(key, value)| (key, from_extended_json(value)))
+                .collect::<Document>()
+                .into()
+        }
+        Bson::Array(values) => Bson::Array(values.into_iter().map(from_extended_json).collect()),
+        value => value,
+    }
+}
+
+/// Converts a BSON value into the JSON format returned by the Data API.
+fn to_json(value: Bson) -> Value {
+    match value {
+        Bson::Document(document) => {
+            Value::Object(document.into_iter().map(|(key, value)| (key, to_json(value))).collect())
+        }
+        Bson::Array(values) => Value::Array(values.into_iter().map(to_json).collect()),
+        Bson::ObjectId(id) => Value::String(id.to_hex()),
+        Bson::DateTime(date_time) => DateTime::<Utc>::from_timestamp_millis(date_time.timestamp_millis())
+            .map(|date_time| Value::String(date_time.to_rfc3339_opts(SecondsFormat::Millis, true)))
+            .unwrap_or(Value::Null),
+        Bson::Timestamp(timestamp) => json!({ "T": timestamp.time, "I": timestamp.increment }),
+        Bson::Decimal128(decimal) => Value::String(decimal.to_string()),
+        Bson::Binary(binary) => Value::String(STANDARD.encode(binary.bytes)),
+        Bson::Int32(value) => Value::from(value),
+        Bson::Int64(value) => Value::from(value),
+        Bson::Double(value) => Value::from(value),
+        Bson::String(value) | Bson::Symbol(value) => Value::String(value),
+        Bson::Boolean(value) => Value::Bool(value),
+        Bson::Null | Bson::Undefined => Value::Null,
+        value => value.into_relaxed_extjson(),
+    }
+}
+
+fn map_err(error: mongodb::error::Error) -> Error {
+    match *error.kind {
+        ErrorKind::Authentication { .. } => {
+            Error::new("the connection to MongoDB could not be authenticated, please check the connection string")
+        }
+        ErrorKind::InvalidArgument { ref message, .. } => {
+            Error::new(format!("the connection string is invalid: {message}"))
+        }
+        ErrorKind::ServerSelection { .. } => Error::new(
+            "could not connect to the MongoDB server, please check the connection string and that the server is running",
+        ),
+        _ => Error::new(error.to_string()),
+    }
+}
@@ -0,0 +1,118 @@
+//! Groups the parents resolving the same field of a query, all the items of a list, into batches so
+//! that the field is resolved once for all of them. The loader can't resolve anything itself as it
+//! has no access to the request context, the first parent to get back its batch resolves it.
+
+use std::{collections::HashMap, marker::PhantomData, sync::Arc};
+
+use async_lock::OnceCell;
+use dataloader::{DataLoader, Loader, NoCache};
+use engine_parser::Pos;
+use engine_value::ConstValue;
+
+use crate::{ContextField, Error};
+
+/// Batches the parents within a request, `T` being what the field resolves to for a whole batch.
+pub struct Batcher<T> {
+    loader: DataLoader<BatchLoader<T>, NoCache>,
+}
+
+impl<T: Send + Sync + 'static> Batcher<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            loader: DataLoader::new(BatchLoader(PhantomData), async_runtime::spawn),
+        }
+    }
+
+    /// Registers `parent` for the field of `ctx`, returning the batch it ended up in.
+    pub(super) async fn load(&self, ctx: &ContextField<'_>, parent: ConstValue) -> Result<Arc<Batch<T>>, Error> {
+        let key = BatchKey {
+            batch: BatchId {
+                parent_type: ctx.parent_type.name().to_string(),
+                field_name: ctx.field.name.clone(),
+                pos: ctx.item.pos,
+            },
+            parent,
+        };
+        Ok(self
+            .loader
+            .load_one(key.clone())
+            .await?
+            .unwrap_or_else(|| Arc::new(Batch::new(vec![key.parent]))))
+    }
+}
+
+impl<T: Send + Sync + 'static> Default for Batcher<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub(super) struct Batch<T> {
+    /// Sorted, so that the batch doesn't depend on the order the parents registered in.
+    pub(super) parents: Vec<ConstValue>,
+    /// Position of each parent within `parents`.
+    positions: HashMap<ConstValue, usize>,
+    pub(super) result: OnceCell<Result<T, Error>>,
+}
+
+impl<T> Batch<T> {
+    pub(super) fn new(parents: Vec<ConstValue>) -> Self {
+        Batch {
+            positions: parents
+                .iter()
+                .enumerate()
+                .map(|(position, parent)| (parent.clone(), position))
+                .collect(),
+            parents,
+            result: OnceCell::new(),
+        }
+    }
+
+    pub(super) fn position(&self, parent: &ConstValue) -> Option<usize> {
+        self.positions.get(parent).copied()
+    }
+}
+
+/// Identifies the parents sharing a batch: all the items of a list resolving the same field of
+/// the query.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct BatchId {
+    parent_type: String,
+    field_name: String,
+    pos: Pos,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct BatchKey {
+    batch: BatchId,
+    parent: ConstValue,
+}
+
+struct BatchLoader<T>(PhantomData<fn() -> T>);
+
+#[async_trait::async_trait]
+impl<T: Send + Sync + 'static> Loader<BatchKey> for BatchLoader<T> {
+    type Value = Arc<Batch<T>>;
+    type Error = Error;
+
+    async fn load(&self, keys: &[BatchKey]) -> Result<HashMap<BatchKey, Self::Value>, Self::Error> {
+        let mut parents: HashMap<&BatchId, Vec<ConstValue>> = HashMap::new();
+        for key in keys {
+            parents.entry(&key.batch).or_default().push(key.parent.clone());
+        }
+
+        let batches = parents
+            .into_iter()
+            .map(|(id, mut parents)| {
+                parents.sort_by_cached_key(ToString::to_string);
+                (id, Arc::new(Batch::new(parents)))
+            })
+            .collect::<HashMap<_, _>>();
+
+        Ok(keys
+            .iter()
+            .map(|key| (key.clone(), Arc::clone(&batches[&key.batch])))
+            .collect())
+    }
+}
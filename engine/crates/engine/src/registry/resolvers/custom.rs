@@ -1,19 +1,24 @@
-use std::hash::Hash;
+use std::{collections::BTreeMap, hash::Hash};
 
 use common_types::UdfKind;
 use dynamodb::attribute_to_value;
 use dynomite::AttributeValue;
+use engine_value::ConstValue;
 use runtime::udf::{
-    CustomResolverError, CustomResolverRequestPayload, CustomResolverResponse, CustomResolversEngine, UdfRequest,
-    UdfRequestContext, UdfRequestContextRequest,
+    CustomResolverBatchItem, CustomResolverError, CustomResolverRequestPayload, CustomResolverResponse,
+    CustomResolversEngine, UdfRequest, UdfRequestContext, UdfRequestContextRequest,
 };
 
-use super::ResolvedValue;
+use super::{batch::Batcher, ResolvedValue};
 use crate::{ContextExt, ContextField, Error, ErrorExtensionValues};
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, Hash, PartialEq, Eq)]
 pub struct CustomResolver {
     pub resolver_name: String,
+    /// If set, the resolver is invoked once for all the parents of a list, receiving the list of
+    /// parents and returning a [`CustomResolverBatchItem`] for each of them.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub batched: bool,
 }
 
 impl CustomResolver {
@@ -42,6 +47,31 @@ impl CustomResolver {
 
         // -- End of hack
 
+        if self.batched {
+            return match ctx.data_opt::<CustomResolverBatcher>() {
+                Some(batcher) => self.resolve_batched(ctx, batcher, parent.data_resolved().clone()).await,
+                None => self.resolve_alone(ctx, parent.data_resolved().clone()).await,
+            };
+        }
+
+        let info = serde_json::json!({
+            "fieldName": ctx.item.name.node.as_str(),
+            "path": ctx.response_path(),
+            "variableValues": &ctx.query_env.variables,
+        });
+        match self.invoke(ctx, parent.data_resolved().clone(), info).await? {
+            CustomResolverResponse::Success(value) => Ok(ResolvedValue::new(value)),
+            CustomResolverResponse::GraphQLError { message, extensions } => Err(graphql_error(message, extensions)),
+            CustomResolverResponse::Error(_err) => Err(CustomResolverError::InvocationError.into()),
+        }
+    }
+
+    async fn invoke(
+        &self,
+        ctx: &ContextField<'_>,
+        parent: serde_json::Value,
+        info: serde_json::Value,
+    ) -> Result<CustomResolverResponse, Error> {
         let runtime_ctx = ctx.data::<runtime::Context>()?;
         let custom_resolvers_engine = ctx.data::<CustomResolversEngine>()?;
         let arguments = ctx
@@ -58,41 +88,118 @@ impl CustomResolver {
                 request_id: ray_id,
                 payload: CustomResolverRequestPayload {
                     arguments,
-                    parent: Some(parent.data_resolved().clone()),
+                    parent: Some(parent),
                     context: UdfRequestContext {
                         request: UdfRequestContextRequest {
                             headers: serde_json::to_value(runtime_ctx.headers_as_map()).expect("must be valid"),
                         },
                     },
-                    info: Some(serde_json::json!({
-                        "fieldName": ctx.item.name.node.as_str(),
-                        "path": ctx.response_path(),
-                        "variableValues": &ctx.query_env.variables,
-                    })),
+                    info: Some(info),
                 },
                 udf_kind: UdfKind::Resolver,
             },
         );
 
-        match future.await? {
-            CustomResolverResponse::Success(value) => Ok(ResolvedValue::new(value)),
-            CustomResolverResponse::GraphQLError { message, extensions } => {
-                let mut error = Error::new(message);
-                error.extensions = extensions.map(|extensions| {
-                    ErrorExtensionValues(
-                        extensions
-                            .into_iter()
-                            .filter_map(|(key, value)| Some((key, crate::Value::from_json(value).ok()?)))
-                            .collect(),
-                    )
+        Ok(future.await?)
+    }
+
+    /// Every parent registers itself in the `CustomResolverBatcher`, the first one to get back the
+    /// batch invokes the resolver for all of them.
+    async fn resolve_batched(
+        &self,
+        ctx: &ContextField<'_>,
+        batcher: &CustomResolverBatcher,
+        parent: serde_json::Value,
+    ) -> Result<ResolvedValue, Error> {
+        let parent =
+            ConstValue::from_json(parent).map_err(|_| Error::new("Internal error converting intermediate values"))?;
+        let batch = batcher.load(ctx, parent.clone()).await?;
+
+        let results = batch
+            .result
+            .get_or_init(|| async {
+                let parents = batch
+                    .parents
+                    .iter()
+                    .cloned()
+                    .map(ConstValue::into_json)
+                    .collect::<serde_json::Result<Vec<_>>>()?;
+                // The path differs between the parents, only the common fields are given.
+                let info = serde_json::json!({
+                    "fieldName": ctx.item.name.node.as_str(),
+                    "variableValues": &ctx.query_env.variables,
                 });
-                Err(error)
-            }
+                let response = self.invoke(ctx, serde_json::Value::Array(parents), info).await?;
+                self.batch_results(response, batch.parents.len())
+            })
+            .await
+            .as_ref()
+            .map_err(Clone::clone)?;
+
+        match batch.position(&parent).and_then(|position| results.get(position)) {
+            Some(Ok(value)) => Ok(ResolvedValue::new(value.clone())),
+            Some(Err(error)) => Err(error.clone()),
+            None => Ok(ResolvedValue::null()),
+        }
+    }
+
+    /// Without a `CustomResolverBatcher` in the request data, the parent is a batch of its own:
+    /// the resolver still expects a list of parents and returns a list of results.
+    async fn resolve_alone(&self, ctx: &ContextField<'_>, parent: serde_json::Value) -> Result<ResolvedValue, Error> {
+        let info = serde_json::json!({
+            "fieldName": ctx.item.name.node.as_str(),
+            "variableValues": &ctx.query_env.variables,
+        });
+        let response = self.invoke(ctx, serde_json::Value::Array(vec![parent]), info).await?;
+        match self.batch_results(response, 1)?.pop() {
+            Some(result) => result.map(ResolvedValue::new),
+            None => Ok(ResolvedValue::null()),
+        }
+    }
+
+    fn batch_results(
+        &self,
+        response: CustomResolverResponse,
+        parents_count: usize,
+    ) -> Result<Vec<Result<serde_json::Value, Error>>, Error> {
+        let resolver_name = &self.resolver_name;
+        match response {
+            CustomResolverResponse::Success(serde_json::Value::Array(items)) if items.len() == parents_count => Ok(items
+                .into_iter()
+                .map(|item| match serde_json::from_value(item) {
+                    Ok(CustomResolverBatchItem::Data(value)) => Ok(value),
+                    Ok(CustomResolverBatchItem::Error { message, extensions }) => Err(graphql_error(message, extensions)),
+                    Err(_) => Err(Error::new(format!(
+                        "The batched resolver '{resolver_name}' must return either `data` or an `error` for each parent"
+                    ))),
+                })
+                .collect()),
+            CustomResolverResponse::Success(_) => Err(Error::new(format!(
+                "The batched resolver '{resolver_name}' must return a list with a result for each of the {parents_count} parents"
+            ))),
+            CustomResolverResponse::GraphQLError { message, extensions } => Err(graphql_error(message, extensions)),
             CustomResolverResponse::Error(_err) => Err(CustomResolverError::InvocationError.into()),
         }
     }
 }
 
+fn graphql_error(message: String, extensions: Option<BTreeMap<String, serde_json::Value>>) -> Error {
+    let mut error = Error::new(message);
+    error.extensions = extensions.map(|extensions| {
+        ErrorExtensionValues(
+            extensions
+                .into_iter()
+                .filter_map(|(key, value)| Some((key, crate::Value::from_json(value).ok()?)))
+                .collect(),
+        )
+    });
+    error
+}
+
+/// Batches the invocations of batched custom resolvers within a request, so that a resolver on
+/// the items of a list is invoked once for all of them.
+pub type CustomResolverBatcher = Batcher<Vec<Result<serde_json::Value, Error>>>;
+
 /// Magic function to convert the dynamodb format to the format we want to have on the
 /// resolver.
 fn dynamodb_to_json(model_data: serde_json::Value) -> serde_json::Value {
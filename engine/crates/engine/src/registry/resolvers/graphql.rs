@@ -116,6 +116,36 @@ pub struct Resolver {
     /// This should point to the actual query endpoint, not a publicly available playground or any
     /// other destination.
     pub url: Url,
+
+    /// How the connector transforms changed the upstream schema, so that queries can still be
+    /// sent with the upstream names.
+    #[serde(default, skip_serializing_if = "UpstreamNames::is_empty")]
+    pub upstream_names: UpstreamNames,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, Hash, PartialEq, Eq)]
+pub struct UpstreamNames {
+    /// The name renamed types had before, by their current name. Those may still carry the type
+    /// prefix, which is stripped like for any other type.
+    pub types: BTreeMap<String, String>,
+
+    /// Upstream fields a hoisted field was nested in, by `Type.field`.
+    pub hoisted_fields: BTreeMap<String, Vec<String>>,
+}
+
+impl UpstreamNames {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty() && self.hoisted_fields.is_empty()
+    }
+
+    /// The current name of renamed types, by the name they had before.
+    fn renamed_types(&self) -> HashMap<&str, &str> {
+        self.types
+            .iter()
+            .map(|(name, previous_name)| (previous_name.as_str(), name.as_str()))
+            .collect()
+    }
 }
 
 impl Resolver {
@@ -126,6 +156,7 @@ impl Resolver {
             url,
             namespace,
             type_prefix,
+            upstream_names: UpstreamNames::default(),
         }
     }
 
@@ -149,6 +180,7 @@ impl Resolver {
             type_prefix: namespace.clone(),
             namespace,
             url: Url::parse(url.as_ref()).expect("valid url"),
+            upstream_names: UpstreamNames::default(),
         }
     }
 }
@@ -367,9 +399,16 @@ impl Resolver {
             self.namespace.as_ref().map(inflector::Inflector::to_pascal_case),
         );
 
+        // The field is serialized with its upstream name, unless it has an alias.
         let wrapping_field = match &target {
             Target::SelectionSet(_) => None,
-            Target::Field(field, _) => Some(field.name.node.to_string()),
+            Target::Field(field, schema_field) => Some(
+                field
+                    .alias
+                    .as_ref()
+                    .map_or(schema_field.target_field_name(), |alias| alias.node.as_str())
+                    .to_string(),
+            ),
         };
 
         Box::pin(make_send_on_wasm(async move {
@@ -379,7 +418,8 @@ impl Resolver {
                 variable_definitions,
                 &mut query,
                 registry,
-            );
+            )
+            .with_upstream_names(&self.upstream_names);
 
             match operation {
                 OperationType::Query => serializer.query(target, current_type)?,
@@ -387,10 +427,7 @@ impl Resolver {
                 OperationType::Subscription => return Err(Error::UnsupportedOperation("subscription")),
             };
 
-            let variables = variables
-                .into_iter()
-                .filter(|(name, _)| serializer.variable_references().any(|reference| reference == name))
-                .collect();
+            let variables = serializer.upstream_variables(variables);
 
             let query_data = QueryData {
                 query: Query { query, variables },
@@ -427,8 +464,9 @@ impl Resolver {
 
             errors.into_iter().for_each(error_handler);
 
-            if let Some(prefix) = prefix {
-                prefix_result_typename(&mut data, &prefix);
+            let renamed_types = self.upstream_names.renamed_types();
+            if prefix.is_some() || !renamed_types.is_empty() {
+                prefix_result_typename(&mut data, prefix.as_deref(), &renamed_types);
             }
 
             let mut resolved_value = ResolvedValue::new(match wrapping_field {
@@ -474,18 +512,28 @@ pub enum Error {
 
 /// Before the resolver returns the JSON to the caller, it needs to iterate the JSON, find any
 /// `__typename` field, and change the value of that field to contain the prefix defined by the
-/// directive that triggered this resolver.
+/// directive that triggered this resolver, and the name given by the connector transforms to
+/// renamed types.
 ///
 /// Without doing so, the caller wouldn't be able to match the typesnames, resulting in invalid
 /// data.
-fn prefix_result_typename(value: &mut serde_json::Value, prefix: &str) {
+fn prefix_result_typename(value: &mut serde_json::Value, prefix: Option<&str>, renamed_types: &HashMap<&str, &str>) {
     use serde_json::Value::{Array, Object, String};
 
     match value {
-        Array(v) => v.iter_mut().for_each(|v| prefix_result_typename(v, prefix)),
+        Array(v) => v
+            .iter_mut()
+            .for_each(|v| prefix_result_typename(v, prefix, renamed_types)),
         Object(v) => v.iter_mut().for_each(|(k, v)| match v {
-            String(s) if k == "__typename" => *s = format!("{prefix} {s}").to_pascal_case(),
-            _ => prefix_result_typename(v, prefix),
+            String(s) if k == "__typename" => {
+                if let Some(prefix) = prefix {
+                    *s = format!("{prefix} {s}").to_pascal_case();
+                }
+                if let Some(name) = renamed_types.get(s.as_str()) {
+                    *s = (*name).to_string();
+                }
+            }
+            _ => prefix_result_typename(v, prefix, renamed_types),
         }),
         _ => {}
     }
@@ -887,7 +935,8 @@ mod tests {
                 id_or_name: IdOrName::LegacyId { id: 1 },
                 url: "https://example.com".parse().unwrap(),
                 namespace: Some("prefix".into()),
-                type_prefix: None
+                type_prefix: None,
+                upstream_names: UpstreamNames::default()
             }
         );
 
@@ -908,7 +957,8 @@ mod tests {
                 },
                 url: "https://example.com".parse().unwrap(),
                 namespace: Some("prefix".into()),
-                type_prefix: None
+                type_prefix: None,
+                upstream_names: UpstreamNames::default()
             }
         );
     }
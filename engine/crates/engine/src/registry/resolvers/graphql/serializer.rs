@@ -1,5 +1,5 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     fmt::{self, Write},
     ops::Deref,
 };
@@ -11,11 +11,11 @@ use engine_parser::{
     },
     Positioned,
 };
-use engine_value::{Name, Value};
+use engine_value::{ConstValue, Name, Value, Variables};
 use type_names::WrappingType;
 
-use super::Target;
-use crate::registry::{type_kinds::SelectionSetTarget, type_names, MetaField, MetaTypeName, Registry};
+use super::{Target, UpstreamNames};
+use crate::registry::{type_kinds::SelectionSetTarget, type_names, MetaField, MetaType, MetaTypeName, Registry};
 
 /// Serialize a list of [`Selection`]s into a GraphQL query string.
 ///
@@ -26,6 +26,10 @@ pub struct Serializer<'a, 'b> {
     /// The prefix string to strip from any global type, before serializing the query.
     prefix: Option<&'a str>,
 
+    /// Renamed types and hoisted fields of the connector, to serialize their upstream
+    /// counterpart instead.
+    upstream_names: Option<&'a UpstreamNames>,
+
     /// Buffer used to write operation string to.
     buf: &'a mut String,
 
@@ -64,6 +68,7 @@ impl<'a, 'b> Serializer<'a, 'b> {
     ) -> Self {
         Serializer {
             prefix,
+            upstream_names: None,
             buf,
             fragment_definitions,
             fragment_spreads: HashSet::new(),
@@ -74,6 +79,14 @@ impl<'a, 'b> Serializer<'a, 'b> {
         }
     }
 
+    #[must_use]
+    pub fn with_upstream_names(self, upstream_names: &'a UpstreamNames) -> Self {
+        Serializer {
+            upstream_names: Some(upstream_names),
+            ..self
+        }
+    }
+
     /// Get an iterator over variable references the serializer has serialized.
     ///
     /// This list will be empty, until [`Serializer::query()`] or [`Serializer::mutation()`] is
@@ -95,8 +108,9 @@ impl<'a: 'b, 'b: 'a, 'c: 'a> Serializer<'a, 'b> {
                 self.serialize_selections(selections, current_type)?;
             }
             Target::Field(field, metafield) => {
+                let registry = self.registry;
                 self.open_object()?;
-                self.serialize_field(&field, Some(&metafield))?;
+                self.serialize_field(&field, Some(&metafield), Some(registry.query_type.as_str()))?;
                 self.close_object()?;
             }
         }
@@ -117,8 +131,9 @@ impl<'a: 'b, 'b: 'a, 'c: 'a> Serializer<'a, 'b> {
                 self.serialize_selections(selections, current_type)?;
             }
             Target::Field(field, schema_field) => {
+                let registry = self.registry;
                 self.open_object()?;
-                self.serialize_field(&field, Some(&schema_field))?;
+                self.serialize_field(&field, Some(&schema_field), registry.mutation_type.as_deref())?;
                 self.close_object()?;
             }
         }
@@ -145,14 +160,23 @@ impl<'a: 'b, 'b: 'a, 'c: 'a> Serializer<'a, 'b> {
                     })
                     .transpose()?;
 
-                self.serialize_field(&field, schema_field)
+                self.serialize_field(
+                    &field,
+                    schema_field,
+                    current_type.map(|current_type| current_type.name()),
+                )
             }
             FragmentSpread(Positioned { node, .. }) => self.serialize_fragment_spread(&node),
             InlineFragment(Positioned { node, .. }) => self.serialize_inline_fragment(&node, current_type),
         }
     }
 
-    fn serialize_field(&mut self, field: &Field, schema_field: Option<&MetaField>) -> Result<(), Error> {
+    fn serialize_field(
+        &mut self,
+        field: &Field,
+        schema_field: Option<&MetaField>,
+        parent_type: Option<&str>,
+    ) -> Result<(), Error> {
         if let Some(schema_field) = schema_field {
             if schema_field.resolver.is_custom() || schema_field.resolver.is_join() {
                 // Skip fields that have resolvers or are joins - they won't exist in the downstream
@@ -171,11 +195,11 @@ impl<'a: 'b, 'b: 'a, 'c: 'a> Serializer<'a, 'b> {
             self.write_str(": ")?;
         }
 
-        // Field name
-        self.write_str(field.name.as_str())?;
+        // Field name, renamed fields keep their upstream name.
+        self.write_str(schema_field.map_or(field.name.as_str(), MetaField::target_field_name))?;
 
         // Arguments
-        self.serialize_arguments(&field.arguments)?;
+        self.serialize_arguments(&field.arguments, schema_field)?;
 
         // Directives
         {
@@ -183,6 +207,24 @@ impl<'a: 'b, 'b: 'a, 'c: 'a> Serializer<'a, 'b> {
             self.serialize_directives(directives)?;
         }
 
+        // A hoisted field is nested within its upstream parents.
+        let hoisted_path = parent_type
+            .zip(schema_field)
+            .zip(self.upstream_names)
+            .and_then(|((parent_type, schema_field), upstream_names)| {
+                upstream_names
+                    .hoisted_fields
+                    .get(&format!("{parent_type}.{}", schema_field.name))
+            })
+            .cloned()
+            .unwrap_or_default();
+
+        for upstream_field in &hoisted_path {
+            self.open_object()?;
+            self.indent()?;
+            self.write_str(upstream_field)?;
+        }
+
         // Selection Sets
         if !field.selection_set.items.is_empty() {
             let selections = field.selection_set.deref().items.iter().map(|v| v.node.clone());
@@ -193,13 +235,24 @@ impl<'a: 'b, 'b: 'a, 'c: 'a> Serializer<'a, 'b> {
             self.serialize_selections(selections, field_type)?;
         }
 
+        for _ in &hoisted_path {
+            if !self.buf.ends_with('\n') {
+                self.write_str("\n")?;
+            }
+            self.close_object()?;
+        }
+
         self.write_str("\n")
     }
 
     /// Arguments
     ///
     /// <https://graphql.org/learn/queries/#arguments>
-    fn serialize_arguments(&mut self, arguments: &[(Positioned<Name>, Positioned<Value>)]) -> Result<(), Error> {
+    fn serialize_arguments(
+        &mut self,
+        arguments: &[(Positioned<Name>, Positioned<Value>)],
+        schema_field: Option<&MetaField>,
+    ) -> Result<(), Error> {
         if arguments.is_empty() {
             return Ok(());
         }
@@ -216,6 +269,11 @@ impl<'a: 'b, 'b: 'a, 'c: 'a> Serializer<'a, 'b> {
             // variable values are needed to execute the document.
             self.variable_references.extend(value.variables_used().cloned());
 
+            let value = match schema_field.and_then(|schema_field| schema_field.args.get(name.as_str())) {
+                Some(argument) => self.upstream_value(argument.ty.as_str(), value),
+                None => value,
+            };
+
             self.write_str(name)?;
             self.write_str(": ")?;
             self.write_str(value.to_string())?;
@@ -259,7 +317,7 @@ impl<'a: 'b, 'b: 'a, 'c: 'a> Serializer<'a, 'b> {
 
             self.write_str(" @")?;
             self.write_str(directive.name.as_str())?;
-            self.serialize_arguments(&directive.arguments)?;
+            self.serialize_arguments(&directive.arguments, None)?;
         }
 
         Ok(())
@@ -357,7 +415,7 @@ impl<'a: 'b, 'b: 'a, 'c: 'a> Serializer<'a, 'b> {
                 );
             }
 
-            self.write_str(self.remove_prefix_from_type(condition.on.as_str()))?;
+            self.write_str(self.upstream_type_name(condition.on.as_str()))?;
         }
 
         // So the new type is _either_ the TypeCondition or whatever the current type is, so we need to pass that in.
@@ -397,7 +455,7 @@ impl<'a: 'b, 'b: 'a, 'c: 'a> Serializer<'a, 'b> {
                 } = variable_definition;
 
                 let var_type = var_type.to_string();
-                let var_type = self.remove_prefix_from_type(&var_type);
+                let var_type = self.upstream_type_name(&var_type);
 
                 write!(declaration, "${name}: {var_type}")?;
 
@@ -470,9 +528,10 @@ impl<'a: 'b, 'b: 'a, 'c: 'a> Serializer<'a, 'b> {
         self.writeln_str("}\n")
     }
 
-    fn remove_prefix_from_type(&self, ty: &str) -> String {
+    fn upstream_type_name(&self, ty: &str) -> String {
         // We remove the `prefix` from condition types, as these are local to Grafbase, and
-        // should not be sent to the upstream server.
+        // should not be sent to the upstream server. Renamed types get back their previous name
+        // first.
         let wrappers = WrappingType::all_for(ty);
         let mut out = String::with_capacity(ty.len());
         for wrapper in &wrappers {
@@ -482,6 +541,10 @@ impl<'a: 'b, 'b: 'a, 'c: 'a> Serializer<'a, 'b> {
         }
 
         let stripped_type = MetaTypeName::concrete_typename(ty);
+        let stripped_type = self
+            .upstream_names
+            .and_then(|upstream_names| upstream_names.types.get(stripped_type))
+            .map_or(stripped_type, String::as_str);
         let stripped_type = stripped_type
             .strip_prefix(self.prefix.unwrap_or_default())
             .unwrap_or(stripped_type)
@@ -498,6 +561,57 @@ impl<'a: 'b, 'b: 'a, 'c: 'a> Serializer<'a, 'b> {
     }
 }
 
+impl Serializer<'_, '_> {
+    /// The values of the variables used by the serialized query, with the input fields renamed by
+    /// the connector transforms given back their upstream name.
+    #[must_use]
+    pub fn upstream_variables(&self, variables: Variables) -> BTreeMap<Name, ConstValue> {
+        variables
+            .into_iter()
+            .filter(|(name, _)| self.variable_references.contains(name))
+            .map(|(name, value)| {
+                let value = match self.variable_definitions.get(&name) {
+                    Some(definition) => self
+                        .upstream_value(&definition.var_type.to_string(), value.into_value())
+                        .into_const()
+                        .unwrap_or_default(),
+                    None => value,
+                };
+                (name, value)
+            })
+            .collect()
+    }
+
+    fn upstream_value(&self, ty: &str, value: Value) -> Value {
+        match (MetaTypeName::create(ty), value) {
+            (MetaTypeName::NonNull(ty), value) => self.upstream_value(ty, value),
+            (MetaTypeName::List(ty), Value::List(values)) => {
+                Value::List(values.into_iter().map(|value| self.upstream_value(ty, value)).collect())
+            }
+            // A single value is accepted for a list.
+            (MetaTypeName::List(ty), value) => self.upstream_value(ty, value),
+            (MetaTypeName::Named(ty), Value::Object(fields)) => {
+                let Some(MetaType::InputObject(input_object)) = self.registry.types.get(ty) else {
+                    return Value::Object(fields);
+                };
+                Value::Object(
+                    fields
+                        .into_iter()
+                        .map(|(name, value)| match input_object.input_fields.get(name.as_str()) {
+                            Some(input_field) => (
+                                input_field.rename.as_deref().map_or(name, Name::new),
+                                self.upstream_value(input_field.ty.as_str(), value),
+                            ),
+                            None => (name, value),
+                        })
+                        .collect(),
+                )
+            }
+            (MetaTypeName::Named(_), value) => value,
+        }
+    }
+}
+
 #[derive(Debug, thiserror::Error, Clone, PartialEq)]
 pub enum Error {
     #[error(transparent)]
@@ -537,11 +651,10 @@ fn should_forward_directive(name: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use engine_parser::Pos;
-    use engine_value::ConstValue;
     use rstest::rstest;
 
     use super::*;
-    use crate::registry::{MetaField, ObjectType, UnionType};
+    use crate::registry::{InputObjectType, MetaField, MetaInputValue, ObjectType, UnionType};
 
     macro_rules! set_snapshot_suffix {
         ($($expr:expr),*) => {
@@ -648,7 +761,37 @@ mod tests {
         insta::assert_snapshot!(serialize(input));
     }
 
+    #[test]
+    fn upstream_names() {
+        let input = r#"
+        query {
+          renamedFoo {
+            bar
+          }
+          hoisted {
+            quux
+          }
+          search(filter: { fullName: "Jim" }) {
+            baz
+          }
+        }"#;
+
+        let upstream_names = UpstreamNames {
+            types: BTreeMap::new(),
+            hoisted_fields: BTreeMap::from([(
+                "Query.hoisted".to_string(),
+                vec!["page".to_string(), "node".to_string()],
+            )]),
+        };
+
+        insta::assert_snapshot!(serialize_with_upstream_names(input, &upstream_names));
+    }
+
     fn serialize(input: &str) -> String {
+        serialize_with_upstream_names(input, &UpstreamNames::default())
+    }
+
+    fn serialize_with_upstream_names(input: &str, upstream_names: &UpstreamNames) -> String {
         let mut buf = String::new();
         let (selections, fragment_definitions) = input_to_selections(input);
         let fragments = fragment_definitions.iter().collect();
@@ -663,7 +806,8 @@ mod tests {
         let variables = HashMap::from([(&name, &variable_definition)]);
         let registry = fake_registry();
 
-        let mut serializer = Serializer::new(Some("Github"), fragments, variables, &mut buf, &registry);
+        let mut serializer = Serializer::new(Some("Github"), fragments, variables, &mut buf, &registry)
+            .with_upstream_names(upstream_names);
 
         if input.trim_start().starts_with("query") {
             let query_ty = registry.lookup_by_str("Query").unwrap().try_into().unwrap();
@@ -747,6 +891,28 @@ mod tests {
         query_fields.insert("foo".into(), MetaField::new("foo", "Foo"));
         query_fields.insert("bar".into(), MetaField::new("bar", "String"));
         query_fields.insert("qux".into(), MetaField::new("qux", "Qux"));
+        query_fields.insert(
+            "renamedFoo".into(),
+            MetaField {
+                mapped_name: Some("foo".into()),
+                ..MetaField::new("renamedFoo", "Foo")
+            },
+        );
+        query_fields.insert("hoisted".into(), MetaField::new("hoisted", "Qux"));
+        query_fields.insert(
+            "search".into(),
+            MetaField {
+                args: [("filter".to_string(), MetaInputValue::new("filter", "SearchFilter"))]
+                    .into_iter()
+                    .collect(),
+                ..MetaField::new("search", "Foo")
+            },
+        );
+
+        registry.insert_type(InputObjectType::new(
+            "SearchFilter".into(),
+            [MetaInputValue::new("fullName", "String").with_rename(Some("name".into()))],
+        ));
 
         registry
     }
@@ -1,16 +1,34 @@
-use std::collections::BTreeMap;
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
 
-use engine_parser::{types::Field, Positioned};
+use engine_parser::{
+    types::{Field, Selection, SelectionSet},
+    Pos, Positioned,
+};
 use engine_value::{argument_set::ArgumentSet, ConstValue, Name, Value};
 
-use super::{ResolvedValue, ResolverContext};
-use crate::{Context, ContextField, Error};
+use super::{
+    batch::{Batch, Batcher},
+    ResolvedValue, ResolverContext,
+};
+use crate::{
+    registry::{type_kinds::SelectionSetTarget, MetaField},
+    resolver_utils::InputResolveMode,
+    Context, ContextExt, ContextField, Error,
+};
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, PartialEq, Eq)]
 #[serde_with::minify_field_names(serialize = "minified", deserialize = "minified")]
 pub struct JoinResolver {
     pub field_name: String,
     pub arguments: ArgumentSet,
+    /// If present, the joined field is resolved once for all the parents: the field of the parent
+    /// is replaced by the list of all the parent values and every item returned is given back
+    /// to the parent whose value matches its `batch_key` field.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batch_key: Option<String>,
 }
 
 // ArgumentSet can't be hashed so we've got a manual impl here that goes via JSON.
@@ -19,6 +37,7 @@ impl core::hash::Hash for JoinResolver {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.field_name.hash(state);
         serde_json::to_string(&self.arguments).unwrap_or_default().hash(state);
+        self.batch_key.hash(state);
     }
 }
 
@@ -27,8 +46,15 @@ impl JoinResolver {
         JoinResolver {
             field_name,
             arguments: ArgumentSet::new(arguments),
+            batch_key: None,
         }
     }
+
+    #[must_use]
+    pub fn with_batch_key(mut self, batch_key: Option<String>) -> Self {
+        self.batch_key = batch_key;
+        self
+    }
 }
 
 impl JoinResolver {
@@ -50,7 +76,14 @@ impl JoinResolver {
             ))
         })?;
 
-        let fake_query_field = self.field_for_join(ctx.item, last_resolver_value)?;
+        if let Some(batch_key) = &self.batch_key {
+            return self
+                .resolve_batched(ctx, batch_key, meta_field, root_type, last_resolver_value)
+                .await;
+        }
+
+        let arguments = self.resolve_arguments(ctx, &last_resolver_value, None)?;
+        let fake_query_field = self.field_for_join(ctx.item, arguments, None);
         let join_context = ctx.to_join_context(&fake_query_field, meta_field, root_type);
         let resolver_context = ResolverContext::new(&join_context);
 
@@ -59,34 +92,134 @@ impl JoinResolver {
             .resolve(&join_context, &resolver_context, None)
             .await
     }
+
+    /// Every parent registers its value in the `JoinBatcher`, the first one to get back the batch
+    /// resolves the joined field for all of them.
+    async fn resolve_batched(
+        &self,
+        ctx: &ContextField<'_>,
+        batch_key: &str,
+        meta_field: &MetaField,
+        root_type: SelectionSetTarget<'_>,
+        last_resolver_value: ResolvedValue,
+    ) -> Result<ResolvedValue, Error> {
+        let Some(parent_value) = self.parent_value(ctx, &last_resolver_value) else {
+            return Ok(ResolvedValue::null().with_early_return());
+        };
+        let item_key = join_key(&parent_value);
+
+        let parent_value = ConstValue::from_json(parent_value)
+            .map_err(|_| Error::new("Internal error converting intermediate values"))?;
+        let batch = match ctx.data_opt::<JoinBatcher>() {
+            Some(batcher) => batcher.load(ctx, parent_value).await?,
+            None => Arc::new(Batch::new(vec![parent_value])),
+        };
+
+        let items = batch
+            .result
+            .get_or_init(|| async {
+                let arguments = self.resolve_arguments(ctx, &last_resolver_value, Some(&batch.parents))?;
+                let fake_query_field = self.field_for_join(ctx.item, arguments, Some(batch_key));
+                let join_context = ctx.to_join_context(&fake_query_field, meta_field, root_type);
+                let resolver_context = ResolverContext::new(&join_context);
+
+                let resolved = meta_field
+                    .resolver
+                    .resolve(&join_context, &resolver_context, None)
+                    .await?;
+
+                Ok::<_, Error>(
+                    resolved
+                        .data_resolved()
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|item| Some((join_key(item.get(batch_key)?), item.clone())))
+                        .collect::<HashMap<_, _>>(),
+                )
+            })
+            .await
+            .as_ref()
+            .map_err(Clone::clone)?;
+
+        Ok(match items.get(&item_key) {
+            Some(item) => ResolvedValue::new(item.clone()),
+            None => ResolvedValue::null().with_early_return(),
+        })
+    }
+
+    /// The value of the parent field a batched join refers to, `None` if it's null.
+    fn parent_value(&self, ctx: &ContextField<'_>, last_resolver_value: &ResolvedValue) -> Option<serde_json::Value> {
+        let variable = self
+            .arguments
+            .clone()
+            .into_iter()
+            .flat_map(|(_, value)| value.variables_used().cloned().collect::<Vec<_>>())
+            .find(|variable| !ctx.field.args.contains_key(variable.as_str()))?;
+
+        last_resolver_value
+            .data_resolved()
+            .get(variable.as_str())
+            .filter(|value| !value.is_null())
+            .cloned()
+    }
 }
 
 impl JoinResolver {
     fn field_for_join(
         &self,
         actual_field: &Positioned<Field>,
-        last_resolver_value: ResolvedValue,
-    ) -> Result<Positioned<Field>, Error> {
-        let arguments = self.resolve_arguments(last_resolver_value)?;
-
+        arguments: Vec<(String, ConstValue)>,
+        batch_key: Option<&str>,
+    ) -> Positioned<Field> {
         let Positioned { pos, node: field } = actual_field;
 
-        Ok(Positioned::new(
+        let mut selection_set = field.selection_set.clone();
+        if let Some(batch_key) = batch_key {
+            // We need the key of every item to give it back to the right parent.
+            let is_selected = selection_set.node.items.iter().any(|selection| {
+                matches!(
+                    &selection.node,
+                    Selection::Field(field) if field.node.alias.is_none() && field.node.name.node == batch_key
+                )
+            });
+            if !is_selected {
+                selection_set
+                    .node
+                    .items
+                    .push(Positioned::new(Selection::Field(key_field(batch_key, *pos)), *pos));
+            }
+        }
+
+        Positioned::new(
             Field {
                 alias: None,
                 name: Positioned::new(Name::new(&self.field_name), *pos),
                 arguments: arguments
                     .into_iter()
-                    .map(|(name, value)| (Positioned::new(Name::new(name), *pos), Positioned::new(value, *pos)))
+                    .map(|(name, value)| {
+                        (
+                            Positioned::new(Name::new(name), *pos),
+                            Positioned::new(value.into_value(), *pos),
+                        )
+                    })
                     .collect(),
                 directives: field.directives.clone(),
-                selection_set: field.selection_set.clone(),
+                selection_set,
             },
             *pos,
-        ))
+        )
     }
 
-    fn resolve_arguments(&self, last_resolver_value: ResolvedValue) -> Result<Vec<(String, Value)>, Error> {
+    /// Variables refer either to arguments of the current field, which are forwarded as is, or
+    /// to fields of the parent object. For batched joins the parent field is replaced by the
+    /// values of all the parents.
+    fn resolve_arguments(
+        &self,
+        ctx: &ContextField<'_>,
+        last_resolver_value: &ResolvedValue,
+        batch_values: Option<&[ConstValue]>,
+    ) -> Result<Vec<(String, ConstValue)>, Error> {
         let serde_json::Value::Object(parent_object) = last_resolver_value.data_resolved() else {
             // This might be an error but I'm going to defer reporting to the child resolver for now.
             // Saves us some work here.  Can revisit if it doesn't work very well (which is very possible)
@@ -97,28 +230,59 @@ impl JoinResolver {
 
         for (name, value) in self.arguments.clone() {
             // Any variables this value refers to are actually fields on the last_resolver_value
-            // so we need to resolve with into_const_with then convert back to a value.
+            // or arguments of the current field so we need to resolve with into_const_with.
             arguments.insert(
                 name.to_string(),
-                value
-                    .into_const_with(|variable_name| {
-                        let value = parent_object.get(variable_name.as_str()).cloned().ok_or_else(|| {
-                            Error::new(format!(
-                                "Internal error: couldn't find {variable_name} in parent_resolver_value"
-                            ))
-                        })?;
-
-                        ConstValue::from_json(value)
-                            .map_err(|_| Error::new("Internal error converting intermediate values"))
-                    })?
-                    .into_value(),
+                value.into_const_with(|variable_name| {
+                    if ctx.field.args.contains_key(variable_name.as_str()) {
+                        return Ok::<_, Error>(
+                            ctx.param_value_dynamic(variable_name.as_str(), InputResolveMode::Default)?
+                                .unwrap_or_default(),
+                        );
+                    }
+
+                    if let Some(values) = batch_values {
+                        return Ok(ConstValue::List(values.to_vec()));
+                    }
+
+                    let value = parent_object.get(variable_name.as_str()).cloned().ok_or_else(|| {
+                        Error::new(format!(
+                            "Internal error: couldn't find {variable_name} in parent_resolver_value"
+                        ))
+                    })?;
+
+                    ConstValue::from_json(value)
+                        .map_err(|_| Error::new("Internal error converting intermediate values"))
+                })?,
             );
         }
 
-        // At some point we'll probably want to think about forwarding arguments from the current field
-        // to the joined field.  But we can handle that when the need comes up, this PR is already too
-        // big
-
         Ok(arguments.into_iter().collect())
     }
 }
+
+fn key_field(name: &str, pos: Pos) -> Positioned<Field> {
+    Positioned::new(
+        Field {
+            alias: None,
+            name: Positioned::new(Name::new(name), pos),
+            arguments: vec![],
+            directives: vec![],
+            selection_set: Positioned::new(SelectionSet::default(), pos),
+        },
+        pos,
+    )
+}
+
+/// Keys are compared as strings as IDs may be returned either as strings or numbers.
+fn join_key(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// Batches the resolution of joined fields within a request, so that a join on a list of parents
+/// makes a single call to the joined field. The items returned by the joined field are kept by
+/// their key.
+pub type JoinBatcher = Batcher<HashMap<String, serde_json::Value>>;
@@ -1,4 +1,6 @@
+use reqwest::header::{HeaderName, HeaderValue};
 use runtime::log::LogEvent;
+use tracing::{info_span, Instrument};
 
 pub async fn send_logged_request(
     request_id: &str,
@@ -8,12 +10,20 @@ pub async fn send_logged_request(
     let start_time = web_time::Instant::now();
 
     let (client, request) = request_builder.build_split();
-    let request = request?;
+    let mut request = request?;
 
     let url = request.url().to_string();
     let method = request.method().to_string();
 
-    let mut response = client.execute(request).await?;
+    // The connector continues the trace of the operation.
+    let span = info_span!("http_request", http.method = %method, http.url = %url);
+    for (name, value) in span.in_scope(telemetry::propagation::current_headers) {
+        if let Ok(value) = HeaderValue::from_str(&value) {
+            request.headers_mut().insert(HeaderName::from_static(name), value);
+        }
+    }
+
+    let mut response = client.execute(request).instrument(span).await?;
 
     if let Some(fetch_log_endpoint_url) = fetch_log_endpoint_url {
         let status_code = response.status().as_u16();
@@ -28,9 +28,10 @@ use self::{
 };
 pub use self::{introspection::IntrospectionResolver, resolved_value::ResolvedValue};
 use super::{type_kinds::OutputType, Constraint, MetaField};
-use crate::{Context, ContextExt, ContextField, Error, RequestHeaders};
+use crate::{rate_limiting::check_connector_rate_limits, Context, ContextExt, ContextField, Error, RequestHeaders};
 
 pub mod atlas_data_api;
+mod batch;
 pub mod custom;
 pub mod dynamo_mutation;
 pub mod dynamo_querying;
@@ -177,12 +178,14 @@ impl Resolver {
                 Ok(current)
             }
             Resolver::Http(resolver) => {
+                check_connector_rate_limits(ctx, &resolver.api_name).await?;
                 resolver
                     .resolve(ctx, resolver_ctx, last_resolver_value)
                     .instrument(info_span!("http_resolver", api_name = resolver.api_name))
                     .await
             }
             Resolver::Graphql(resolver) => {
+                check_connector_rate_limits(ctx, &resolver.name()).await?;
                 let runtime_ctx = ctx.data::<runtime::Context>()?;
                 let ray_id = runtime_ctx.ray_id();
                 let fetch_log_endpoint_url = runtime_ctx.log.fetch_log_endpoint_url.as_deref();
@@ -265,7 +268,7 @@ impl Resolver {
                 .await
                 .map_err(Into::into),
             Resolver::PostgresResolver(resolver) => resolver
-                .resolve(ctx, resolver_ctx)
+                .resolve(ctx, resolver_ctx, last_resolver_value)
                 .instrument(info_span!(
                     "postgres_resolver",
                     operation = resolver.operation.as_ref(),
@@ -1,11 +1,18 @@
 mod context;
 mod request;
 
-use std::{future::Future, pin::Pin};
+use std::{future::Future, pin::Pin, sync::Arc};
 
 use async_runtime::make_send_on_wasm;
+use common_types::auth::{ExecutionAuth, Operations};
 pub use context::CollectionArgs;
 use context::PostgresContext;
+use futures_util::{pin_mut, stream::BoxStream, StreamExt};
+use postgres_connector_types::{
+    change_feed::ChangeKind,
+    database_definition::DatabaseDefinition,
+    transport::{SessionTransport, Transport},
+};
 use runtime::pg::PgTransportFactory;
 
 use super::{ResolvedValue, ResolverContext};
@@ -22,6 +29,8 @@ pub enum Operation {
     CreateMany,
     UpdateOne,
     UpdateMany,
+    CallFunction,
+    Subscribe(ChangeKind),
 }
 
 impl AsRef<str> for Operation {
@@ -35,6 +44,8 @@ impl AsRef<str> for Operation {
             Self::CreateMany => "createMany",
             Self::UpdateOne => "updateOne",
             Self::UpdateMany => "updateMany",
+            Self::CallFunction => "callFunction",
+            Self::Subscribe(_) => "subscribe",
         }
     }
 }
@@ -54,12 +65,36 @@ impl PostgresResolver {
         }
     }
 
+    /// The operations whose access rules depending on the accessed rows are checked by filtering
+    /// the rows in the database, see `PostgresContext::access_filter`.
+    pub fn row_filtered_operations(&self) -> Operations {
+        match self.operation {
+            Operation::FindOne | Operation::FindMany => Operations::READ,
+            Operation::UpdateOne | Operation::UpdateMany => Operations::UPDATE,
+            Operation::DeleteOne | Operation::DeleteMany => Operations::DELETE,
+            Operation::CreateOne | Operation::CreateMany | Operation::CallFunction | Operation::Subscribe(_) => {
+                Operations::empty()
+            }
+        }
+    }
+
+    /// The name of the connector, its database definition is registered with this name.
+    pub fn directive_name(&self) -> &str {
+        &self.directive_name
+    }
+
     pub fn resolve<'a>(
         &'a self,
         ctx: &'a ContextField<'_>,
         resolver_ctx: &'a ResolverContext<'_>,
+        last_resolver_value: Option<ResolvedValue>,
     ) -> Pin<Box<dyn Future<Output = Result<ResolvedValue, Error>> + Send + 'a>> {
         Box::pin(make_send_on_wasm(async move {
+            // The changed rows come from the stream, and pass through the root field.
+            if let Operation::Subscribe(_) = self.operation {
+                return Ok(last_resolver_value.unwrap_or_default());
+            }
+
             let pg_transport_factory = ctx.data::<PgTransportFactory>()?;
 
             let database_definition = ctx
@@ -70,9 +105,65 @@ impl PostgresResolver {
                 )))?;
 
             let transport = pg_transport_factory.try_get(&self.directive_name).await?;
+            let transport = with_session(ctx, database_definition, transport)?;
             let context = PostgresContext::new(ctx, resolver_ctx, database_definition, transport).await?;
 
             request::execute(context, self.operation).await
         }))
     }
+
+    /// Streams the changed rows of the table for a subscription field.
+    pub fn subscribe<'a>(&'a self, ctx: &'a ContextField<'a>) -> BoxStream<'a, Result<ResolvedValue, Error>> {
+        let Operation::Subscribe(kind) = self.operation else {
+            let error = Error::new(format!("{} is not a subscription", self.operation.as_ref()));
+            return Box::pin(futures_util::stream::once(async move { Err(error) }));
+        };
+
+        Box::pin(async_stream::try_stream! {
+            let pg_transport_factory = ctx.data::<PgTransportFactory>()?;
+
+            let database_definition = ctx
+                .get_postgres_definition(&self.directive_name)
+                .ok_or(Error::new(format!(
+                    "pg directive ({}) must exist",
+                    &self.directive_name
+                )))?;
+
+            let transport = pg_transport_factory.try_get(&self.directive_name).await?;
+            let session_transport = with_session(ctx, database_definition, transport.clone())?;
+
+            let resolver_ctx = ResolverContext::new(ctx);
+            let context = PostgresContext::new(ctx, &resolver_ctx, database_definition, session_transport).await?;
+
+            let rows = request::subscribe(&context, transport.as_ref(), kind);
+
+            pin_mut!(rows);
+
+            while let Some(row) = rows.next().await {
+                yield row?;
+            }
+        })
+    }
+}
+
+/// If row-level security is enabled, runs the queries in a session built from the token claims.
+/// Requests with an API key skip the session, and run as the user of the connection string.
+fn with_session(
+    ctx: &ContextField<'_>,
+    database_definition: &DatabaseDefinition,
+    transport: Arc<dyn Transport>,
+) -> Result<Arc<dyn Transport>, Error> {
+    let Some(row_level_security) = database_definition.row_level_security() else {
+        return Ok(transport);
+    };
+
+    let claims = match ctx.data::<ExecutionAuth>().ok() {
+        Some(ExecutionAuth::ApiKey) => return Ok(transport),
+        Some(ExecutionAuth::Token(token)) => Some(token.token_claims()),
+        Some(ExecutionAuth::Public { .. }) | None => None,
+    };
+
+    let session = row_level_security.session(claims)?;
+
+    Ok(Arc::new(SessionTransport::new(transport, session)))
 }
@@ -1,17 +1,21 @@
+mod access;
 mod create_input;
+mod database_value;
 mod filter;
 pub mod selection;
 mod update_input;
 
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 
+use common_types::auth::{ExecutionAuth, Operations};
 pub(super) use create_input::{CreateInputItem, CreateInputIterator};
 pub(super) use filter::FilterIterator;
+use grafbase_sql_ast::ast::{ConditionTree, Expression};
 use postgres_connector_types::{
-    database_definition::{DatabaseDefinition, TableWalker},
+    database_definition::{DatabaseDefinition, FunctionArgumentWalker, FunctionWalker, TableWalker},
     transport::Transport,
 };
-pub use selection::CollectionArgs;
+pub use selection::{CollectionArgs, TextSearchRank};
 pub(super) use selection::{SelectionIterator, TableSelection};
 use serde_json::{Map, Value};
 pub(super) use update_input::{UpdateInputItem, UpdateInputIterator};
@@ -19,7 +23,7 @@ pub(super) use update_input::{UpdateInputItem, UpdateInputIterator};
 use self::filter::{ByFilterIterator, ComplexFilterIterator};
 use crate::{
     registry::{resolvers::ResolverContext, type_kinds::SelectionSetTarget, Registry},
-    Context, ContextExt, ContextField, Error, SelectionField, ServerResult,
+    ClaimsContext, Context, ContextExt, ContextField, Error, SelectionField, ServerResult,
 };
 
 /// The API to access the request parameters, such as filters and selection, and map that together with
@@ -57,6 +61,37 @@ impl<'a> PostgresContext<'a> {
             .expect("could not find table for client type")
     }
 
+    /// The function called by this request.
+    pub fn function(&self) -> Result<FunctionWalker<'a>, Error> {
+        self.database_definition
+            .find_function_for_client_field(&self.context.field.name)
+            .ok_or_else(|| {
+                Error::new(format!(
+                    "could not find a function for field {}",
+                    self.context.field.name
+                ))
+            })
+    }
+
+    /// The values for the arguments of the called function, in the order of the function definition.
+    /// Arguments not defined in the request are `None`.
+    pub fn function_input(
+        &self,
+        function: FunctionWalker<'a>,
+    ) -> ServerResult<Vec<(FunctionArgumentWalker<'a>, Option<Value>)>> {
+        let mut input = Vec::with_capacity(function.arguments().len());
+
+        for argument in function.arguments() {
+            let value: Option<Value> = self.context.input_by_name(argument.client_name().to_string())?;
+
+            let value = value.map(|value| database_value::to_database_value(argument.database_type(), value));
+
+            input.push((argument, value));
+        }
+
+        Ok(input)
+    }
+
     /// The first field of the query, e.g. the query.
     pub fn root_field(&self) -> SelectionField<'a> {
         self.context
@@ -151,6 +186,85 @@ impl<'a> PostgresContext<'a> {
         Ok(FilterIterator::Complex(iterator))
     }
 
+    /// Restricts the accessed rows to the ones the claims rules depending on `$parent` grant the
+    /// operation for. `None` if the operation is already granted without looking at the rows.
+    /// If a rule can't be translated to SQL, reads are not filtered and the returned fields are
+    /// checked one by one, but updates and deletes are rejected.
+    pub fn access_filter(&self) -> Result<Option<ConditionTree<'a>>, Error> {
+        let Some(auth) = self.context.field.auth.as_ref() else {
+            return Ok(None);
+        };
+
+        let Some(operation) = self.context.field.required_operation else {
+            return Ok(None);
+        };
+
+        let no_claims = BTreeMap::new();
+        let (claims, granted_ops) = match self.context.data::<ExecutionAuth>().ok() {
+            None | Some(ExecutionAuth::ApiKey) => return Ok(None),
+            Some(ExecutionAuth::Token(token)) => (
+                token.token_claims(),
+                auth.private_public_and_group_based_ops(token.groups_from_token()),
+            ),
+            Some(ExecutionAuth::Public { .. }) => (&no_claims, auth.allowed_public_ops),
+        };
+
+        let arguments = self
+            .context
+            .item
+            .node
+            .arguments
+            .iter()
+            .filter_map(|(name, value)| {
+                let value = self.context.resolve_input_value(value.clone()).ok()?.into_json().ok()?;
+                Some((name.node.to_string(), value))
+            })
+            .collect();
+        let arguments = Value::Object(arguments);
+
+        let ctx = ClaimsContext {
+            claims,
+            arguments: Some(&arguments),
+            parent: None,
+        };
+
+        if granted_ops.union(auth.claims_based_ops(&ctx)).contains(operation) {
+            return Ok(None);
+        }
+
+        let mut conditions = Vec::new();
+
+        for rule in &auth.claims_rules {
+            if !rule.operations.contains(operation) || rule.expression.evaluate(&ctx).is_some() {
+                continue;
+            }
+
+            let condition = access::claims_condition(&rule.expression, &ctx, self.database_definition, self.table());
+
+            match condition {
+                Some(condition) => conditions.push(Expression::from(condition)),
+                None if operation.intersects(Operations::UPDATE | Operations::DELETE) => {
+                    return Err(Error::new(format!(
+                        "Unauthorized to {} {}, the access rules cannot be checked by the database",
+                        if operation.contains(Operations::DELETE) {
+                            "delete"
+                        } else {
+                            "update"
+                        },
+                        self.table().client_name()
+                    )));
+                }
+                None => return Ok(None),
+            }
+        }
+
+        if conditions.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(ConditionTree::Or(conditions)))
+    }
+
     /// An iterator for create input value definition.
     pub fn create_input(&'a self) -> ServerResult<CreateInputIterator<'a>> {
         let input_map: Map<String, Value> = self.context.input_by_name("input")?;
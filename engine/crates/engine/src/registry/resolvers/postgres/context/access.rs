@@ -0,0 +1,95 @@
+use grafbase_sql_ast::ast::{raw, Column, Comparable, ConditionTree, Expression, Row};
+use postgres_connector_types::database_definition::{DatabaseDefinition, TableColumnWalker, TableWalker};
+use serde_json::Value;
+
+use crate::{ClaimsContext, ClaimsExpression, Comparison, Operand};
+
+/// Translates a claims expression using `$parent` into a condition on the rows of the table.
+/// Returns `None` if the expression can't be expressed in SQL, e.g. if it compares a nested
+/// field of the parent.
+pub(super) fn claims_condition<'a>(
+    expression: &ClaimsExpression,
+    ctx: &ClaimsContext<'_>,
+    database_definition: &'a DatabaseDefinition,
+    table: TableWalker<'a>,
+) -> Option<ConditionTree<'a>> {
+    let condition = match expression {
+        ClaimsExpression::And(left, right) => ConditionTree::And(vec![
+            Expression::from(claims_condition(left, ctx, database_definition, table)?),
+            Expression::from(claims_condition(right, ctx, database_definition, table)?),
+        ]),
+        ClaimsExpression::Or(left, right) => ConditionTree::Or(vec![
+            Expression::from(claims_condition(left, ctx, database_definition, table)?),
+            Expression::from(claims_condition(right, ctx, database_definition, table)?),
+        ]),
+        ClaimsExpression::Compare(left, comparison, right) => {
+            let column = |operand: &Operand| match operand {
+                Operand::Parent(path) => match path.as_slice() {
+                    [field] => database_definition.find_column_for_client_field(field, table.id()),
+                    _ => None,
+                },
+                _ => None,
+            };
+
+            match (left.is_parent(), right.is_parent(), comparison) {
+                (false, false, _) => {
+                    let matches = match (left.resolve(ctx), right.resolve(ctx)) {
+                        (Some(left), Some(right)) => comparison.compare(left, right),
+                        _ => false,
+                    };
+
+                    ConditionTree::single(raw(if matches { "true" } else { "false" }))
+                }
+                (true, true, Comparison::Equals | Comparison::NotEquals) => {
+                    let left = column_ref(column(left)?);
+                    let right = column_ref(column(right)?);
+
+                    match comparison {
+                        Comparison::Equals => ConditionTree::single(left.equals(right)),
+                        _ => ConditionTree::single(left.not_equals(right)),
+                    }
+                }
+                (true, false, _) => compare_column(column(left)?, *comparison, right.resolve(ctx)),
+                (false, true, Comparison::Equals | Comparison::NotEquals) => {
+                    compare_column(column(right)?, *comparison, left.resolve(ctx))
+                }
+                // Checking if a value is in a list column would need the array operators.
+                (_, true, Comparison::In) => return None,
+            }
+        }
+    };
+
+    Some(condition)
+}
+
+fn column_ref<'a>(column: TableColumnWalker<'a>) -> Column<'a> {
+    Column::from((column.table().database_name(), column.database_name()))
+}
+
+/// Compares a column to a value the same way the rule is evaluated outside of the database:
+/// a missing value never matches, and a null column is different from any value.
+fn compare_column<'a>(
+    column: TableColumnWalker<'a>,
+    comparison: Comparison,
+    value: Option<&Value>,
+) -> ConditionTree<'a> {
+    let Some(value) = value else {
+        return ConditionTree::single(raw("false"));
+    };
+
+    let column = column_ref(column);
+
+    match (comparison, value) {
+        (Comparison::Equals, Value::Null) => ConditionTree::single(column.is_null()),
+        (Comparison::Equals, value) => ConditionTree::single(column.equals(value.clone())),
+        (Comparison::NotEquals, Value::Null) => ConditionTree::single(column.is_not_null()),
+        (Comparison::NotEquals, value) => ConditionTree::Or(vec![
+            column.clone().not_equals(value.clone()).into(),
+            column.is_null().into(),
+        ]),
+        (Comparison::In, Value::Array(values)) => {
+            ConditionTree::single(column.in_selection(Row::from(Value::Array(values.clone()))))
+        }
+        (Comparison::In, _) => ConditionTree::single(raw("false")),
+    }
+}
@@ -1,10 +1,9 @@
 use std::collections::VecDeque;
 
-use postgres_connector_types::database_definition::{
-    DatabaseDefinition, DatabaseType, EnumWalker, TableColumnWalker, TableWalker,
-};
+use postgres_connector_types::database_definition::{DatabaseDefinition, TableColumnWalker, TableWalker};
 use serde_json::Value;
 
+use super::database_value::to_database_value;
 use crate::registry::type_kinds::InputType;
 
 pub enum CreateInputItem<'a> {
@@ -47,28 +46,8 @@ impl<'a> Iterator for CreateInputIterator<'a> {
             .find_column_for_client_field(&field, self.table.id())
             .expect("column for client field not found");
 
-        let value = match (value, column.database_type()) {
-            (Value::String(value), DatabaseType::Enum(r#enum)) => rename_enum_variant(r#enum, &value),
-            (Value::Array(values), DatabaseType::Enum(r#enum)) => {
-                let values = values
-                    .into_iter()
-                    .map(|value| rename_enum_variant(r#enum, value.as_str().expect("must be a string")))
-                    .collect();
-
-                Value::Array(values)
-            }
-            (value, _) => value,
-        };
+        let value = to_database_value(column.database_type(), value);
 
         Some(CreateInputItem::Column(column, value))
     }
 }
-
-fn rename_enum_variant(r#enum: EnumWalker<'_>, variant: &str) -> Value {
-    let variant = r#enum
-        .rename_variant(variant)
-        .expect("invalid enum variant")
-        .to_string();
-
-    Value::String(variant)
-}
@@ -0,0 +1,76 @@
+use postgres_connector_types::{
+    database_definition::{CompositeTypeWalker, DatabaseType, EnumWalker},
+    range,
+};
+use serde_json::{Map, Value};
+
+/// Converts a value from the GraphQL input to the value we send to the database. Enum variants are
+/// renamed to the database variants, and composite and range objects are rendered in the PostgreSQL
+/// text format.
+pub(super) fn to_database_value(database_type: DatabaseType<'_>, value: Value) -> Value {
+    match (database_type, value) {
+        (_, Value::Array(values)) => {
+            let values = values
+                .into_iter()
+                .map(|value| to_database_value(database_type, value))
+                .collect();
+
+            Value::Array(values)
+        }
+        (DatabaseType::Enum(r#enum), Value::String(variant)) => rename_enum_variant(r#enum, &variant),
+        (DatabaseType::Composite(composite_type), Value::Object(object)) => {
+            Value::String(record_literal(composite_type, object))
+        }
+        (DatabaseType::Scalar(_), Value::Object(object)) if database_type.is_range() => {
+            Value::String(range::to_literal(&object))
+        }
+        (_, value) => value,
+    }
+}
+
+fn rename_enum_variant(r#enum: EnumWalker<'_>, variant: &str) -> Value {
+    let variant = r#enum
+        .rename_variant(variant)
+        .expect("invalid enum variant")
+        .to_string();
+
+    Value::String(variant)
+}
+
+/// Renders a composite value in the record format, e.g. `("1 Main St",Springfield,)`. The attributes
+/// are in the order of the type definition, and a missing attribute is null.
+fn record_literal(composite_type: CompositeTypeWalker<'_>, mut object: Map<String, Value>) -> String {
+    let attributes = composite_type.attributes().map(|attribute| {
+        let value = object.remove(attribute.client_name()).unwrap_or_default();
+
+        match to_database_value(attribute.database_type(), value) {
+            Value::Null => String::new(),
+            value => quote(&text_value(value)),
+        }
+    });
+
+    format!("({})", attributes.collect::<Vec<_>>().join(","))
+}
+
+/// The text representation of a value nested in a record or an array literal.
+fn text_value(value: Value) -> String {
+    match value {
+        Value::String(value) => value,
+        Value::Array(values) => {
+            let elements = values
+                .into_iter()
+                .map(|value| match value {
+                    Value::Null => String::from("NULL"),
+                    value => quote(&text_value(value)),
+                })
+                .collect::<Vec<_>>();
+
+            format!("{{{}}}", elements.join(","))
+        }
+        value => value.to_string(),
+    }
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
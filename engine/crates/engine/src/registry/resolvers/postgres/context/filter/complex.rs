@@ -1,7 +1,7 @@
 use std::collections::VecDeque;
 
-use grafbase_sql_ast::ast::{Column, Comparable, ConditionTree, Expression, Row, Select};
-use postgres_connector_types::database_definition::TableColumnWalker;
+use grafbase_sql_ast::ast::{raw, Column, Comparable, ConditionTree, Expression, Row, Select};
+use postgres_connector_types::{database_definition::TableColumnWalker, range};
 use serde_json::{Map, Value};
 
 use crate::registry::{resolvers::postgres::context::PostgresContext, type_kinds::InputType};
@@ -132,6 +132,14 @@ fn generate_conditions(operations: Map<String, Value>, column: TableColumnWalker
     for (key, value) in operations {
         let table_column = (column.table().database_name(), column.database_name());
 
+        // ranges are compared with range values in the text format
+        let value = match (key.as_str(), value) {
+            ("eq" | "ne" | "overlaps", Value::Object(object)) if column.database_type().is_range() => {
+                Value::String(range::to_literal(&object))
+            }
+            (_, value) => value,
+        };
+
         let compare = match key.as_str() {
             "eq" => match value {
                 Value::Null => table_column.is_null(),
@@ -147,6 +155,21 @@ fn generate_conditions(operations: Map<String, Value>, column: TableColumnWalker
             "lte" => table_column.less_than_or_equals(value),
             "in" => table_column.in_selection(Row::from(value)),
             "nin" => table_column.not_in_selection(Row::from(value)),
+            "like" => table_column.compare_raw("LIKE", value),
+            "ilike" => table_column.compare_raw("ILIKE", value),
+            "regex" => table_column.compare_raw("~", value),
+            "iregex" => table_column.compare_raw("~*", value),
+            // JSON values are sent as text, a string must be quoted to be a valid JSON document.
+            "contains" if column.database_type().is_jsonb() && !column.is_array() => {
+                table_column.compare_raw("@>", Value::String(value.to_string()))
+            }
+            "path" => table_column.compare_raw("@?", value),
+            "matches" => table_column.compare_raw("@@", websearch_to_tsquery(value)),
+            // sent as a range of the single value, an untyped parameter being inferred as a range
+            "contains" if column.database_type().is_range() => {
+                table_column.compare_raw("@>", Value::String(range::to_single_value_literal(&value)))
+            }
+            "overlaps" if column.database_type().is_range() => table_column.compare_raw("&&", value),
             "contains" => table_column.array_contains(value),
             "contained" => table_column.array_contained(value),
             "overlaps" => table_column.array_overlaps(value),
@@ -169,3 +192,23 @@ fn generate_conditions(operations: Map<String, Value>, column: TableColumnWalker
 
     ConditionTree::And(compares)
 }
+
+/// A `websearch_to_tsquery` call, parsing the query into a `tsquery` for the `@@` operator. The SQL
+/// AST has no builder for the function, so only its name is rendered as-is, in front of the
+/// parameterized arguments.
+fn websearch_to_tsquery(value: Value) -> Expression<'static> {
+    Expression::from(raw("websearch_to_tsquery").compare_raw("", text_search_query(value)))
+}
+
+/// The arguments for `websearch_to_tsquery`, the text search configuration being optional.
+fn text_search_query(value: Value) -> Row<'static> {
+    let mut arguments = Vec::with_capacity(2);
+
+    if let Some(language) = value.get("language").filter(|language| !language.is_null()) {
+        arguments.push(language.clone());
+    }
+
+    arguments.push(value.get("query").cloned().unwrap_or(Value::Null));
+
+    Row::from(Value::Array(arguments))
+}
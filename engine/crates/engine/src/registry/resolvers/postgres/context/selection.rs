@@ -1,6 +1,6 @@
 pub mod collection_args;
 
-pub use collection_args::CollectionArgs;
+pub use collection_args::{CollectionArgs, TextSearchRank};
 use postgres_connector_types::database_definition::{RelationWalker, TableColumnWalker, TableWalker};
 
 use super::PostgresContext;
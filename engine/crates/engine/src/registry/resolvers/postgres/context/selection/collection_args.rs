@@ -35,12 +35,45 @@ impl CollectionOrdering {
     }
 }
 
+/// Ordering by the rank of a full-text search match against a `tsvector` column. The rank is
+/// computed in a result set the query selects from, and named with the alias.
+#[derive(Debug, Clone)]
+pub struct TextSearchRank {
+    column: String,
+    alias: String,
+    query: String,
+    language: Option<String>,
+}
+
+impl TextSearchRank {
+    /// The database name of the `tsvector` column.
+    pub(crate) fn column(&self) -> &str {
+        &self.column
+    }
+
+    /// The name of the computed rank column.
+    pub(crate) fn alias(&self) -> &str {
+        &self.alias
+    }
+
+    /// The search query, in web search syntax.
+    pub(crate) fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// The text search configuration to parse the query with.
+    pub(crate) fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+}
+
 /// Argument defining a relay-style GraphQL collection.
 #[derive(Debug, Clone)]
 pub struct CollectionArgs {
     first: Option<u64>,
     last: Option<u64>,
     order_by: CollectionOrdering,
+    text_search_ranks: Vec<TextSearchRank>,
     extra_columns: Vec<Column<'static>>,
     before: Option<SQLCursor>,
     after: Option<SQLCursor>,
@@ -134,11 +167,43 @@ impl CollectionArgs {
         // extra columns we have to select (based on ordering)
         let mut extra_columns = Vec::new();
 
+        // ranks of full-text search matches we order with
+        let mut text_search_ranks = Vec::new();
+
         for value in order_by_argument {
             let Some((field, value)) = value.as_object().and_then(IndexMap::first) else {
                 continue;
             };
-            let Some(direction) = value.as_str() else { continue };
+
+            let column = database_definition
+                .find_column_for_client_field(field, table.id())
+                .expect("ordering with non-existing column");
+
+            let (column_name, direction) = match value {
+                // `{ document: { query: "...", language: "...", direction: ... } }`, best matches first by default.
+                Value::Object(rank) => {
+                    let Some(query) = rank.get("query").and_then(Value::as_str) else {
+                        continue;
+                    };
+
+                    let alias = format!("__rank_{}", column.database_name());
+
+                    text_search_ranks.push(TextSearchRank {
+                        column: column.database_name().to_string(),
+                        alias: alias.clone(),
+                        query: query.to_string(),
+                        language: rank.get("language").and_then(Value::as_str).map(ToString::to_string),
+                    });
+
+                    let direction = rank.get("direction").and_then(Value::as_str).unwrap_or("DESC");
+
+                    (alias, direction)
+                }
+                value => {
+                    let Some(direction) = value.as_str() else { continue };
+                    (column.database_name().to_string(), direction)
+                }
+            };
 
             // For `last` to work, we must reverse the order of the inner query.
             let inner_direction = match direction {
@@ -155,21 +220,16 @@ impl CollectionArgs {
                 _ => inner_direction,
             };
 
-            let column = database_definition
-                .find_column_for_client_field(field, table.id())
-                .expect("ordering with non-existing column");
-
-            let sql_column = Column::from((table.database_name().to_string(), column.database_name().to_string()));
+            let sql_column = Column::from((table.database_name().to_string(), column_name.clone()));
 
             // We must name our order columns for them to be visible in the order by statement of the
             // outer queries.
-            let alias = format!("{}_{}", table.database_name(), column.database_name());
+            let alias = format!("{}_{}", table.database_name(), column_name);
             extra_columns.push(sql_column.clone().alias(alias.clone()));
 
-            order_by.inner.push((
-                (table.database_name().to_string(), column.database_name().to_string()),
-                Some(inner_direction),
-            ));
+            order_by
+                .inner
+                .push(((table.database_name().to_string(), column_name), Some(inner_direction)));
 
             order_by.outer.push((alias, Some(outer_direction)));
         }
@@ -178,6 +238,7 @@ impl CollectionArgs {
             first,
             last,
             order_by,
+            text_search_ranks,
             extra_columns,
             before,
             after,
@@ -240,6 +301,12 @@ impl CollectionArgs {
         &self.order_by
     }
 
+    /// Full-text search ranks the collection is ordered with. The ranks must be computed in
+    /// the result set the collection is selected from.
+    pub(crate) fn text_search_ranks(&self) -> &[TextSearchRank] {
+        &self.text_search_ranks
+    }
+
     /// A set of extra columns needing to select in the collecting query. Needed to handle the ordering of the outer
     /// layers.
     pub(crate) fn extra_columns(&self) -> impl ExactSizeIterator<Item = Column<'static>> + '_ {
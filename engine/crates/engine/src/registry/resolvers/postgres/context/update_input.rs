@@ -4,6 +4,7 @@ use grafbase_sql_ast::ast::{Column, Expression, SqlOp};
 use postgres_connector_types::database_definition::{DatabaseDefinition, TableColumnWalker, TableWalker};
 use serde_json::Value;
 
+use super::database_value::to_database_value;
 use crate::registry::type_kinds::InputType;
 
 pub enum UpdateInputItem<'a> {
@@ -55,7 +56,7 @@ impl<'a> Iterator for UpdateInputIterator<'a> {
 
         // the type is oneOf, so we always have at most one operation in the object
         let expression = match value.into_iter().next() {
-            Some((key, value)) if key == "set" => Expression::from(value),
+            Some((key, value)) if key == "set" => Expression::from(to_database_value(column.database_type(), value)),
             Some((key, value)) if key == "increment" => Expression::from(sql_column) + Expression::from(value),
             Some((key, value)) if key == "decrement" || key == "deleteKey" => {
                 Expression::from(sql_column) - Expression::from(value)
@@ -66,7 +67,7 @@ impl<'a> Iterator for UpdateInputIterator<'a> {
                 let value = if column.database_type().is_jsonb() {
                     Value::String(serde_json::to_string(&value).unwrap())
                 } else {
-                    value
+                    to_database_value(column.database_type(), value)
                 };
 
                 let op = SqlOp::Append(Expression::from(sql_column), Expression::from(value));
@@ -76,7 +77,7 @@ impl<'a> Iterator for UpdateInputIterator<'a> {
                 let value = if column.database_type().is_jsonb() {
                     Value::String(serde_json::to_string(&value).unwrap())
                 } else {
-                    value
+                    to_database_value(column.database_type(), value)
                 };
 
                 let op = SqlOp::Append(Expression::from(value), Expression::from(sql_column));
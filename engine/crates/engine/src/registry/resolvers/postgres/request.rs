@@ -1,3 +1,4 @@
+mod call_function;
 mod create_many;
 mod create_one;
 mod delete_many;
@@ -6,10 +7,12 @@ mod find_many;
 mod find_one;
 mod log;
 mod query;
+mod subscribe;
 mod update_many;
 mod update_one;
 
 use serde_json::Value;
+pub(super) use subscribe::execute as subscribe;
 
 use super::{context::PostgresContext, Operation};
 use crate::{registry::resolvers::ResolvedValue, Error};
@@ -29,5 +32,7 @@ pub(super) async fn execute(ctx: PostgresContext<'_>, operation: Operation) -> R
         Operation::CreateMany => create_many::execute(ctx).await,
         Operation::UpdateOne => update_one::execute(ctx).await,
         Operation::UpdateMany => update_many::execute(ctx).await,
+        Operation::CallFunction => call_function::execute(ctx).await,
+        Operation::Subscribe(_) => Err(Error::new("subscriptions are resolved from a stream of changes")),
     }
 }
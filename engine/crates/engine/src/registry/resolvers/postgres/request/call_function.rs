@@ -0,0 +1,26 @@
+use postgres_connector_types::{database_definition::FunctionReturnTypeWalker, transport::TransportExt};
+use serde_json::Value;
+
+use super::{find_many, find_one, log, query::FunctionCall};
+use crate::{
+    registry::resolvers::{postgres::context::PostgresContext, ResolvedValue},
+    Error,
+};
+
+pub(super) async fn execute(ctx: PostgresContext<'_>) -> Result<ResolvedValue, Error> {
+    let function = ctx.function()?;
+    let call = FunctionCall::new(function, ctx.function_input(function)?);
+
+    match function.return_type() {
+        FunctionReturnTypeWalker::Table(_) if function.returns_set() => find_many::execute_from(ctx, Some(call)).await,
+        FunctionReturnTypeWalker::Table(_) => find_one::execute_from(ctx, Some(call)).await,
+        FunctionReturnTypeWalker::Scalar(_) | FunctionReturnTypeWalker::Enum(_) => {
+            let (sql, params) = call.scalar_query()?;
+            let operation = ctx.transport().collect_query(&sql, params);
+            let rows = log::query(&ctx, &sql, operation).await?;
+            let value = rows.into_iter().next().map(|row| row.root).unwrap_or(Value::Null);
+
+            Ok(ResolvedValue::new(value))
+        }
+    }
+}
@@ -7,7 +7,7 @@ use serde_json::Value;
 
 use super::{
     log,
-    query::{self, SelectBuilder},
+    query::{self, FunctionCall, RankedSource, SelectBuilder},
 };
 use crate::{
     registry::resolvers::{
@@ -19,9 +19,35 @@ use crate::{
 };
 
 pub(crate) async fn execute(ctx: PostgresContext<'_>) -> Result<ResolvedValue, Error> {
-    let mut builder = SelectBuilder::new(ctx.table(), ctx.collection_selection(), "root");
+    execute_from(ctx, None).await
+}
 
+/// Fetches a page of rows from the table, or from the rows returned by a function call.
+pub(super) async fn execute_from(
+    ctx: PostgresContext<'_>,
+    call: Option<FunctionCall<'_>>,
+) -> Result<ResolvedValue, Error> {
+    let mut builder = SelectBuilder::new(ctx.table(), ctx.collection_selection(), "root");
     let args = CollectionArgs::new(ctx.database_definition(), ctx.table(), &ctx.root_field())?;
+
+    // Ordering by full-text search rank, the ranks are computed in a result set we select from.
+    let mut ranked = match args.text_search_ranks() {
+        [] => None,
+        ranks => Some(RankedSource::new(ctx.table(), ranks.to_vec())),
+    };
+
+    if let Some(ref call) = call {
+        builder.set_source(call.function().result_set_name());
+
+        if let Some(ref mut ranked) = ranked {
+            ranked.set_source(call.function().result_set_name());
+        }
+    }
+
+    if let Some(ref ranked) = ranked {
+        builder.set_source(ranked.name());
+    }
+
     let mut selection_data = SelectionData::default();
 
     if let Some(first) = args.first() {
@@ -52,7 +78,29 @@ pub(crate) async fn execute(ctx: PostgresContext<'_>) -> Result<ResolvedValue, E
         builder.set_filter(filter);
     }
 
-    let (sql, params) = renderer::Postgres::build(query::select::build(builder)?);
+    if let Some(filter) = ctx.access_filter()? {
+        builder.set_access_filter(filter);
+    }
+
+    let (sql, mut params) = renderer::Postgres::build(query::select::build(builder)?);
+    let mut expressions = Vec::new();
+
+    if let Some(call) = call {
+        let (expression, call_params) = call.common_table_expression(params.len())?;
+
+        params.extend(call_params);
+        expressions.push(expression);
+    }
+
+    if let Some(ranked) = ranked {
+        let (expression, rank_params) = ranked.common_table_expression(params.len());
+
+        params.extend(rank_params);
+        expressions.push(expression);
+    }
+
+    let sql = query::with_common_table_expressions(&expressions, sql);
+
     let operation = ctx.transport().collect_query(&sql, params);
     let rows = log::query(&ctx, &sql, operation).await?;
 
@@ -7,7 +7,7 @@ use crate::{
     registry::resolvers::{
         postgres::{
             context::PostgresContext,
-            request::query::{self, SelectBuilder},
+            request::query::{self, FunctionCall, SelectBuilder},
         },
         ResolvedValue,
     },
@@ -15,13 +15,36 @@ use crate::{
 };
 
 pub(super) async fn execute(ctx: PostgresContext<'_>) -> Result<ResolvedValue, Error> {
+    execute_from(ctx, None).await
+}
+
+/// Fetches a single row from the table, or the row returned by a function call.
+pub(super) async fn execute_from(
+    ctx: PostgresContext<'_>,
+    call: Option<FunctionCall<'_>>,
+) -> Result<ResolvedValue, Error> {
     let mut builder = SelectBuilder::new(ctx.table(), ctx.selection(), "root");
 
-    if let Ok(filter) = ctx.by_filter() {
-        builder.set_filter(filter);
+    match call {
+        Some(ref call) => builder.set_source(call.function().result_set_name()),
+        None => {
+            if let Ok(filter) = ctx.by_filter() {
+                builder.set_filter(filter);
+            }
+        }
+    }
+
+    if let Some(filter) = ctx.access_filter()? {
+        builder.set_access_filter(filter);
     }
 
     let (sql, params) = renderer::Postgres::build(query::select::build(builder)?);
+
+    let (sql, params) = match call {
+        Some(call) => call.with_query(sql, params)?,
+        None => (sql, params),
+    };
+
     let operation = ctx.transport().collect_query(&sql, params);
     let rows = log::query(&ctx, &sql, operation).await?;
     let row = rows.into_iter().next().map(|row| row.root).unwrap_or(Value::Null);
@@ -1,7 +1,26 @@
 mod builder;
+mod change;
 pub mod delete;
+mod function;
 pub mod insert;
 pub mod select;
+mod text_search;
 pub mod update;
 
 pub use builder::SelectBuilder;
+pub use change::ChangedRow;
+pub use function::FunctionCall;
+pub use text_search::RankedSource;
+
+/// Prepends common table expressions rendered by hand to a query rendered from the SQL AST.
+pub fn with_common_table_expressions(expressions: &[String], sql: String) -> String {
+    if expressions.is_empty() {
+        return sql;
+    }
+
+    format!("WITH {} {sql}", expressions.join(", "))
+}
+
+fn quote(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
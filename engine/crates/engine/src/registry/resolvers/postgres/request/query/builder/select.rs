@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use grafbase_sql_ast::ast::ConditionTree;
 use postgres_connector_types::database_definition::{RelationWalker, TableWalker};
 
 use crate::registry::resolvers::postgres::context::{CollectionArgs, FilterIterator, SelectionIterator};
@@ -10,9 +11,11 @@ pub struct SelectBuilder<'a> {
     table: TableWalker<'a>,
     selection: SelectionIterator<'a>,
     filter: Option<FilterIterator<'a>>,
+    access_filter: Option<ConditionTree<'a>>,
     collection_args: Option<CollectionArgs>,
     field_name: Cow<'static, str>,
     relation: Option<RelationWalker<'a>>,
+    source: Option<String>,
 }
 
 impl<'a> SelectBuilder<'a> {
@@ -27,9 +30,11 @@ impl<'a> SelectBuilder<'a> {
             table,
             selection,
             filter: None,
+            access_filter: None,
             collection_args: None,
             field_name: field_name.into(),
             relation: None,
+            source: None,
         }
     }
 
@@ -38,6 +43,11 @@ impl<'a> SelectBuilder<'a> {
         self.filter = Some(filter);
     }
 
+    /// Restricts the statement to the rows the user has access to.
+    pub fn set_access_filter(&mut self, filter: ConditionTree<'a>) {
+        self.access_filter = Some(filter);
+    }
+
     /// If defining collection arguments to the query, it sets the
     /// result to be an array of rows, and allows defining the relay
     /// arguments with first/last/before/after and orderBy.
@@ -50,6 +60,12 @@ impl<'a> SelectBuilder<'a> {
         self.relation = Some(relation);
     }
 
+    /// Selects the rows from a named result set, such as a common table expression, instead
+    /// of the table. The result set must have the same columns as the table.
+    pub fn set_source(&mut self, source: impl Into<String>) {
+        self.source = Some(source.into());
+    }
+
     /// The name of the table we're selecting from.
     pub fn table(&self) -> TableWalker<'a> {
         self.table
@@ -76,6 +92,16 @@ impl<'a> SelectBuilder<'a> {
         self.filter.clone()
     }
 
+    /// The access condition for this select, on top of the filter.
+    pub fn access_filter(&self) -> Option<ConditionTree<'a>> {
+        self.access_filter.clone()
+    }
+
+    /// The name of the result set to select from, if not selecting directly from the table.
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
     /// If selecting for a join, this should have the definition of the relation we're
     /// currently on.
     pub fn relation(&self) -> Option<RelationWalker<'a>> {
@@ -0,0 +1,56 @@
+use postgres_connector_types::{
+    change_feed::{ChangeEvent, ChangeKind},
+    database_definition::TableWalker,
+};
+use serde_json::Value;
+
+use super::quote;
+
+/// The row of a change event as a result set with the columns of the table. Rendered by hand,
+/// the SQL AST has no way to express calls to `json_populate_record`.
+pub struct ChangedRow<'a> {
+    table: TableWalker<'a>,
+    event: &'a ChangeEvent,
+}
+
+impl<'a> ChangedRow<'a> {
+    pub fn new(table: TableWalker<'a>, event: &'a ChangeEvent) -> Self {
+        Self { table, event }
+    }
+
+    /// The name of the result set, for the query to select from.
+    pub fn name(&self) -> String {
+        format!("{}_changed", self.table.database_name())
+    }
+
+    /// The changed row as a common table expression, numbering the parameter after the given
+    /// offset. Created and updated rows are loaded from the table by their key, deleted rows are
+    /// gone and come from the event.
+    pub fn common_table_expression(&self, offset: usize) -> (String, Vec<Value>) {
+        let table = format!("{}.{}", quote(self.table.schema()), quote(self.table.database_name()));
+        let record = format!("json_populate_record(NULL::{table}, ${}::json)", offset + 1);
+
+        let query = match self.event.kind() {
+            ChangeKind::Deleted => format!("SELECT * FROM {record}"),
+            ChangeKind::Created | ChangeKind::Updated => {
+                let key = self
+                    .table
+                    .implicit_ordering_key()
+                    .expect("tables in the client have at least one unique constraint")
+                    .columns()
+                    .map(|column| quote(column.table_column().database_name()))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!(
+                    "SELECT {alias}.* FROM {table} AS {alias} JOIN {record} AS \"changed\" USING ({key})",
+                    alias = quote(self.table.database_name()),
+                )
+            }
+        };
+
+        let expression = format!("{} AS ({query})", quote(&self.name()));
+
+        (expression, vec![Value::String(self.event.row().to_string())])
+    }
+}
@@ -5,6 +5,7 @@ use crate::registry::resolvers::postgres::context::{FilterIterator, PostgresCont
 pub fn build<'a>(ctx: &'a PostgresContext<'a>, filter: FilterIterator<'a>) -> Result<Delete<'a>, crate::Error> {
     let sql_table = Table::from((ctx.table().schema(), ctx.table().database_name())).alias(ctx.table().database_name());
     let mut query = Delete::from_table(sql_table);
+    let filter = filter.chain(ctx.access_filter()?);
     query.so_that(filter.fold(ConditionTree::NoCondition, ConditionTree::and));
 
     if let Some(selection) = ctx.returning_selection() {
@@ -0,0 +1,108 @@
+use postgres_connector_types::database_definition::{FunctionArgumentWalker, FunctionWalker};
+use serde_json::Value;
+
+use super::quote;
+
+/// A call to a database function. The call is rendered by hand, the SQL AST has no way
+/// to express calls to user-defined functions.
+pub struct FunctionCall<'a> {
+    function: FunctionWalker<'a>,
+    arguments: Vec<(FunctionArgumentWalker<'a>, Option<Value>)>,
+}
+
+impl<'a> FunctionCall<'a> {
+    /// Calls the function with the given arguments. An argument with `None` as the value is
+    /// left out from the call, if the argument has a default value, otherwise it's set to `NULL`.
+    pub fn new(
+        function: FunctionWalker<'a>,
+        arguments: impl IntoIterator<Item = (FunctionArgumentWalker<'a>, Option<Value>)>,
+    ) -> Self {
+        let arguments = arguments
+            .into_iter()
+            .map(|(argument, value)| match value {
+                None if !argument.has_default() => (argument, Some(Value::Null)),
+                value => (argument, value),
+            })
+            .collect();
+
+        Self { function, arguments }
+    }
+
+    /// The called function.
+    pub fn function(&self) -> FunctionWalker<'a> {
+        self.function
+    }
+
+    /// Prepends the call as a common table expression to a query rendered from the SQL AST. The query must
+    /// select from the function's result set name instead of the table.
+    pub fn with_query(&self, sql: String, mut params: Vec<Value>) -> Result<(String, Vec<Value>), crate::Error> {
+        let (expression, call_params) = self.common_table_expression(params.len())?;
+        params.extend(call_params);
+
+        Ok((super::with_common_table_expressions(&[expression], sql), params))
+    }
+
+    /// The call as a common table expression named with the function's result set name, numbering the
+    /// parameters starting after the given offset.
+    pub fn common_table_expression(&self, offset: usize) -> Result<(String, Vec<Value>), crate::Error> {
+        let (call, params) = self.render(offset)?;
+        let result_set_name = quote(&self.function.result_set_name());
+
+        Ok((format!("{result_set_name} AS (SELECT * FROM {call})"), params))
+    }
+
+    /// A query selecting the value returned from the function as JSON into the `root` column.
+    /// Functions returning a set of values are aggregated into a JSON array.
+    pub fn scalar_query(&self) -> Result<(String, Vec<Value>), crate::Error> {
+        let (call, params) = self.render(0)?;
+
+        let sql = if self.function.returns_set() {
+            format!("SELECT COALESCE(json_agg(result.value), '[]') AS root FROM {call} AS result(value)")
+        } else {
+            format!("SELECT to_json({call}) AS root")
+        };
+
+        Ok((sql, params))
+    }
+
+    /// Renders the call, numbering the parameters starting after the given offset.
+    ///
+    /// Arguments are given in positional notation until the first left out argument. The arguments after
+    /// that must be given in named notation.
+    fn render(&self, offset: usize) -> Result<(String, Vec<Value>), crate::Error> {
+        let mut rendered_arguments = Vec::with_capacity(self.arguments.len());
+        let mut params = Vec::with_capacity(self.arguments.len());
+        let mut named_notation = false;
+
+        for (argument, value) in &self.arguments {
+            let Some(value) = value else {
+                named_notation = true;
+                continue;
+            };
+
+            params.push(value.clone());
+            let placeholder = format!("${}", offset + params.len());
+
+            match argument.database_name() {
+                Some(name) if named_notation => rendered_arguments.push(format!("{} => {placeholder}", quote(name))),
+                None if named_notation => {
+                    return Err(crate::Error::new(format!(
+                        "argument {} of function {} has no name and must be given, if any of the arguments before it are left out",
+                        argument.client_name(),
+                        self.function.database_name(),
+                    )))
+                }
+                _ => rendered_arguments.push(placeholder),
+            }
+        }
+
+        let call = format!(
+            "{}.{}({})",
+            quote(self.function.schema()),
+            quote(self.function.database_name()),
+            rendered_arguments.join(", ")
+        );
+
+        Ok((call, params))
+    }
+}
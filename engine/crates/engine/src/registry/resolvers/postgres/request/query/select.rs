@@ -12,8 +12,11 @@ use crate::registry::resolvers::postgres::context::TableSelection;
 /// [example query](https://gist.github.com/pimeys/a7535acb0922fa432562539f5d8123c3)
 pub fn build(builder: SelectBuilder<'_>) -> Result<Select<'_>, crate::Error> {
     // The innermost query of the select. All filters, ordering, limits etc. are defined here.
-    let sql_table =
-        Table::from((builder.table().schema(), builder.table().database_name())).alias(builder.table().database_name());
+    let sql_table = match builder.source() {
+        Some(source) => Table::from(source.to_string()),
+        None => Table::from((builder.table().schema(), builder.table().database_name())),
+    }
+    .alias(builder.table().database_name());
 
     let mut inner_nested = Select::from_table(sql_table);
 
@@ -23,7 +26,18 @@ pub fn build(builder: SelectBuilder<'_>) -> Result<Select<'_>, crate::Error> {
         }
     }
 
+    if let Some(filter) = builder.access_filter() {
+        inner_nested.and_where(filter);
+    }
+
     if let Some(args) = builder.collection_args() {
+        // The ranks are computed in a result set we select from, which is only defined for the root query.
+        if builder.relation().is_some() && !args.text_search_ranks().is_empty() {
+            return Err(crate::Error::new(
+                "ordering by text search rank is only supported on root collections",
+            ));
+        }
+
         for ordering in args.order_by().inner() {
             inner_nested.order_by(ordering.clone());
         }
@@ -97,6 +111,14 @@ pub fn build(builder: SelectBuilder<'_>) -> Result<Select<'_>, crate::Error> {
         }
     }
 
+    // Like the ordering columns missing from the selection, the ranks must be part of the row: the
+    // cursors of the edges are built from the values of the row they're ordered with.
+    if let Some(args) = builder.collection_args() {
+        for rank in args.text_search_ranks() {
+            collecting_select.column((builder.table().client_name(), rank.alias().to_string()));
+        }
+    }
+
     let mut json_select = Select::from_table(Table::from(collecting_select).alias(builder.table().database_name()));
     json_select.value(row_to_json(builder.table().database_name(), false).alias(builder.field_name().to_string()));
 
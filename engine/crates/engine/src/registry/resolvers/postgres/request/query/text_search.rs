@@ -0,0 +1,71 @@
+use postgres_connector_types::database_definition::TableWalker;
+use serde_json::Value;
+
+use super::quote;
+use crate::registry::resolvers::postgres::context::TextSearchRank;
+
+/// The rows of a table with the ranks of full-text search matches as extra columns. Rendered
+/// by hand, the SQL AST has no way to express calls to the ranking functions.
+pub struct RankedSource<'a> {
+    table: TableWalker<'a>,
+    ranks: Vec<TextSearchRank>,
+    source: Option<String>,
+}
+
+impl<'a> RankedSource<'a> {
+    /// Ranks the rows of the table with the given ranks.
+    pub fn new(table: TableWalker<'a>, ranks: Vec<TextSearchRank>) -> Self {
+        Self {
+            table,
+            ranks,
+            source: None,
+        }
+    }
+
+    /// Ranks the rows of a named result set, such as a function call, instead of the table.
+    /// The result set must have the same columns as the table.
+    pub fn set_source(&mut self, source: impl Into<String>) {
+        self.source = Some(source.into());
+    }
+
+    /// The name of the ranked result set, for the query to select from.
+    pub fn name(&self) -> String {
+        format!("{}_ranked", self.table.database_name())
+    }
+
+    /// The ranked rows as a common table expression, numbering the parameters starting after
+    /// the given offset.
+    pub fn common_table_expression(&self, offset: usize) -> (String, Vec<Value>) {
+        let mut params = Vec::with_capacity(self.ranks.len() * 2);
+        let mut columns = vec![String::from("*")];
+
+        for rank in &self.ranks {
+            let mut arguments = Vec::with_capacity(2);
+
+            for value in rank.language().into_iter().chain(Some(rank.query())) {
+                params.push(Value::String(value.to_string()));
+                arguments.push(format!("${}", offset + params.len()));
+            }
+
+            columns.push(format!(
+                "ts_rank({}, websearch_to_tsquery({})) AS {}",
+                quote(rank.column()),
+                arguments.join(", "),
+                quote(rank.alias()),
+            ));
+        }
+
+        let source = match self.source {
+            Some(ref source) => quote(source),
+            None => format!("{}.{}", quote(self.table.schema()), quote(self.table.database_name())),
+        };
+
+        let expression = format!(
+            "{} AS (SELECT {} FROM {source})",
+            quote(&self.name()),
+            columns.join(", ")
+        );
+
+        (expression, params)
+    }
+}
@@ -6,6 +6,7 @@ use crate::registry::resolvers::postgres::context::{FilterIterator, PostgresCont
 
 pub fn build<'a>(ctx: &'a PostgresContext<'a>, filter: FilterIterator<'a>) -> Result<Query<'a>, crate::Error> {
     let mut update = Update::table(ctx.table().database_name());
+    let filter = filter.chain(ctx.access_filter()?);
     update.so_that(filter.fold(ConditionTree::NoCondition, ConditionTree::and));
 
     for item in ctx.update_input()? {
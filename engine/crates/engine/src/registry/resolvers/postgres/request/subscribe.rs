@@ -0,0 +1,93 @@
+use futures_util::{pin_mut, stream::BoxStream, StreamExt};
+use grafbase_sql_ast::renderer::{self, Renderer};
+use postgres_connector_types::{
+    change_feed::{self, ChangeEvent, ChangeKind},
+    database_definition::TableWalker,
+    transport::{Transport, TransportExt},
+};
+use serde_json::Value;
+
+use super::{
+    log,
+    query::{self, ChangedRow, SelectBuilder},
+};
+use crate::{
+    registry::resolvers::{postgres::context::PostgresContext, ResolvedValue},
+    Error,
+};
+
+/// Streams the changed rows of the given kind matching the filter. The listener uses the given
+/// transport, the rows are fetched in the session of the request. Fails if the trigger publishing
+/// the changes of the table is not installed.
+pub(crate) fn execute<'a>(
+    ctx: &'a PostgresContext<'a>,
+    transport: &'a dyn Transport,
+    kind: ChangeKind,
+) -> BoxStream<'a, Result<ResolvedValue, Error>> {
+    Box::pin(async_stream::try_stream! {
+        let table = ctx.table();
+
+        async_runtime::make_send_on_wasm(ensure_trigger(transport, table)).await?;
+
+        let channel = change_feed::channel(table);
+        let notifications = transport.listen(&channel);
+
+        pin_mut!(notifications);
+
+        while let Some(payload) = notifications.next().await {
+            let payload = payload.map_err(|error| Error::new(error.to_string()))?;
+            let event = ChangeEvent::parse(&payload).map_err(|error| Error::new(error.to_string()))?;
+
+            if event.kind() != kind {
+                continue;
+            }
+
+            let row = async_runtime::make_send_on_wasm(fetch(ctx, &event)).await?;
+
+            // the changed row does not match the filter
+            if !row.is_null() {
+                yield ResolvedValue::new(row);
+            }
+        }
+    })
+}
+
+async fn ensure_trigger(transport: &dyn Transport, table: TableWalker<'_>) -> Result<(), Error> {
+    let exists: Vec<Value> = transport
+        .collect_query(&change_feed::trigger_exists_query(table), Vec::new())
+        .await
+        .map_err(|error| Error::new(error.to_string()))?;
+
+    if exists.is_empty() {
+        return Err(Error::new(format!(
+            "the changes of the table {} are not published, install the triggers with `grafbase setup-subscriptions`",
+            table.database_name()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Selects the changed row with the filter and selection of the request.
+async fn fetch(ctx: &PostgresContext<'_>, event: &ChangeEvent) -> Result<Value, Error> {
+    let changed = ChangedRow::new(ctx.table(), event);
+    let mut builder = SelectBuilder::new(ctx.table(), ctx.selection(), "root");
+
+    builder.set_source(changed.name());
+
+    if let Ok(filter) = ctx.filter() {
+        builder.set_filter(filter);
+    }
+
+    let (sql, mut params) = renderer::Postgres::build(query::select::build(builder)?);
+    let (expression, changed_params) = changed.common_table_expression(params.len());
+
+    params.extend(changed_params);
+
+    let sql = query::with_common_table_expressions(&[expression], sql);
+
+    let operation = ctx.transport().collect_query(&sql, params);
+    let rows = log::query(ctx, &sql, operation).await?;
+
+    Ok(rows.into_iter().next().map(|row| row.root).unwrap_or(Value::Null))
+}
@@ -1,3 +1,6 @@
+use std::ops::Bound;
+
+use chrono::SecondsFormat;
 use runtime::search::{self, GraphqlCursor};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
@@ -7,7 +10,10 @@ use super::{
     ResolvedValue, ResolverContext,
 };
 use crate::{
-    names::OUTPUT_EDGE_CURSOR,
+    names::{
+        OUTPUT_EDGE_CURSOR, OUTPUT_FIELD_FACET_BUCKET_COUNT, OUTPUT_FIELD_FACET_BUCKET_GTE,
+        OUTPUT_FIELD_FACET_BUCKET_LT, OUTPUT_FIELD_FACET_BUCKET_VALUE,
+    },
     registry::{variables::VariableResolveDefinition, ModelName},
     Context, ContextExt, ContextField, Error,
 };
@@ -17,6 +23,8 @@ mod search_parser;
 pub const SEARCH_RESOLVER_EDGES: &str = "edges";
 pub const SEARCH_RESOLVER_EDGE_SCORE: &str = "#score";
 pub const SEARCH_RESOLVER_TOTAL_HITS: &str = "totalHits";
+pub const SEARCH_RESOLVER_EDGE_HIGHLIGHTS: &str = "#highlights";
+pub const SEARCH_RESOLVER_FACETS: &str = "facets";
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
 pub enum QueryResolver {
@@ -30,6 +38,10 @@ pub enum QueryResolver {
         last: VariableResolveDefinition,
         after: VariableResolveDefinition,
         before: VariableResolveDefinition,
+        fuzziness: VariableResolveDefinition,
+        facets: VariableResolveDefinition,
+        order_by: VariableResolveDefinition,
+        vector: VariableResolveDefinition,
     },
 }
 
@@ -51,6 +63,10 @@ impl QueryResolver {
                 last,
                 after,
                 before,
+                fuzziness,
+                facets,
+                order_by,
+                vector,
             } => {
                 let schema = &ctx
                     .registry()
@@ -66,6 +82,16 @@ impl QueryResolver {
                 let last = last.expect_opt_int(ctx, last_val, Some(PAGINATION_LIMIT))?;
                 let before: Option<GraphqlCursor> = before.resolve(ctx, last_val)?;
                 let after: Option<GraphqlCursor> = after.resolve(ctx, last_val)?;
+                let facets = match facets.resolve::<Value>(ctx, last_val)? {
+                    Value::Null => Vec::new(),
+                    value => search_parser::parse_facets(schema, value)?,
+                };
+                let sort = match order_by.resolve::<Value>(ctx, last_val)? {
+                    Value::Null => None,
+                    value => search_parser::parse_sort(schema, value)?,
+                };
+                // Snippets are only generated if requested as they require re-reading each hit.
+                let highlight = ctx.look_ahead().field("edges").field("highlights").exists();
 
                 let response = search_engine
                     .query(
@@ -78,9 +104,17 @@ impl QueryResolver {
                                     Value::Null => None,
                                     value => Some(search_parser::parse_filter(schema, value)?),
                                 },
+                                fuzziness: search_parser::parse_fuzziness(fuzziness.resolve(ctx, last_val)?)?,
+                                vector: match vector.resolve::<Value>(ctx, last_val)? {
+                                    Value::Null => None,
+                                    value => search_parser::parse_vector(schema, value)?,
+                                },
                             },
                             pagination: search_parser::parse_pagination(first, before, last, after)?,
                             index: entity_type.clone(),
+                            facets,
+                            highlight,
+                            sort,
                         },
                     )
                     .await?;
@@ -114,6 +148,10 @@ impl QueryResolver {
                                         serde_json::to_value(hit.score)?,
                                     );
                                     fields.insert(OUTPUT_EDGE_CURSOR.to_string(), serde_json::to_value(hit.cursor)?);
+                                    fields.insert(
+                                        SEARCH_RESOLVER_EDGE_HIGHLIGHTS.to_string(),
+                                        serde_json::to_value(hit.highlights)?,
+                                    );
                                     Ok(Value::Object(fields))
                                 }
                                 _ => Err(Error::new("Unexpected data from DynamoDB")),
@@ -123,12 +161,57 @@ impl QueryResolver {
                     }?
                 };
 
+                let facets: serde_json::Map<String, Value> = response
+                    .facets
+                    .into_iter()
+                    .map(|facet| (facet.field, facet.buckets.into_iter().map(bucket_to_value).collect()))
+                    .collect();
+
                 Ok(ResolvedValue::new(json!({
                     SEARCH_RESOLVER_EDGES: edges,
-                    SEARCH_RESOLVER_TOTAL_HITS: response.info.total_hits
+                    SEARCH_RESOLVER_TOTAL_HITS: response.info.total_hits,
+                    SEARCH_RESOLVER_FACETS: facets
                 }))
                 .with_pagination(resolved_pagination))
             }
         }
     }
 }
+
+fn bucket_to_value(bucket: search::FacetBucket) -> Value {
+    match bucket {
+        search::FacetBucket::Term { value, count } => json!({
+            OUTPUT_FIELD_FACET_BUCKET_VALUE: value,
+            OUTPUT_FIELD_FACET_BUCKET_COUNT: count
+        }),
+        search::FacetBucket::Range { range, count } => {
+            let bound_to_value = |bound: Bound<search::ScalarValue>| match bound {
+                Bound::Included(value) | Bound::Excluded(value) => scalar_to_value(value),
+                Bound::Unbounded => Value::Null,
+            };
+            json!({
+                OUTPUT_FIELD_FACET_BUCKET_GTE: bound_to_value(range.start),
+                OUTPUT_FIELD_FACET_BUCKET_LT: bound_to_value(range.end),
+                OUTPUT_FIELD_FACET_BUCKET_COUNT: count
+            })
+        }
+    }
+}
+
+/// Formats the value like the matching GraphQL scalar would.
+fn scalar_to_value(value: search::ScalarValue) -> Value {
+    use search::ScalarValue;
+    match value {
+        ScalarValue::URL(value)
+        | ScalarValue::Email(value)
+        | ScalarValue::PhoneNumber(value)
+        | ScalarValue::String(value) => Value::String(value),
+        ScalarValue::Int(value) => Value::from(value),
+        ScalarValue::Float(value) => Value::from(value),
+        ScalarValue::Boolean(value) => Value::Bool(value),
+        ScalarValue::Date(date) => Value::String(date.format("%Y-%m-%d").to_string()),
+        ScalarValue::DateTime(datetime) => Value::String(datetime.to_rfc3339_opts(SecondsFormat::Millis, true)),
+        ScalarValue::Timestamp(timestamp) => Value::from(timestamp.timestamp_millis()),
+        ScalarValue::IPAddress(ip) => Value::String(ip.to_string()),
+    }
+}
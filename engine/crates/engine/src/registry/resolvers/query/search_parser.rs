@@ -4,16 +4,31 @@ use thiserror::Error;
 
 use crate::{
     names::{
-        INPUT_FIELD_FILTER_ALL, INPUT_FIELD_FILTER_ANY, INPUT_FIELD_FILTER_EQ, INPUT_FIELD_FILTER_GT,
-        INPUT_FIELD_FILTER_GTE, INPUT_FIELD_FILTER_IN, INPUT_FIELD_FILTER_IS_NULL, INPUT_FIELD_FILTER_LIST_INCLUDES,
-        INPUT_FIELD_FILTER_LIST_INCLUDES_NONE, INPUT_FIELD_FILTER_LIST_IS_EMPTY, INPUT_FIELD_FILTER_LT,
-        INPUT_FIELD_FILTER_LTE, INPUT_FIELD_FILTER_NEQ, INPUT_FIELD_FILTER_NONE, INPUT_FIELD_FILTER_NOT,
-        INPUT_FIELD_FILTER_NOT_IN, INPUT_FIELD_FILTER_REGEX,
+        INPUT_FIELD_FACET_BOUNDARIES, INPUT_FIELD_FACET_LIMIT, INPUT_FIELD_FILTER_ALL, INPUT_FIELD_FILTER_ANY,
+        INPUT_FIELD_FILTER_EQ, INPUT_FIELD_FILTER_GT, INPUT_FIELD_FILTER_GTE, INPUT_FIELD_FILTER_IN,
+        INPUT_FIELD_FILTER_IS_NULL, INPUT_FIELD_FILTER_LIST_INCLUDES, INPUT_FIELD_FILTER_LIST_INCLUDES_NONE,
+        INPUT_FIELD_FILTER_LIST_IS_EMPTY, INPUT_FIELD_FILTER_LT, INPUT_FIELD_FILTER_LTE, INPUT_FIELD_FILTER_NEQ,
+        INPUT_FIELD_FILTER_NONE, INPUT_FIELD_FILTER_NOT, INPUT_FIELD_FILTER_NOT_IN, INPUT_FIELD_FILTER_REGEX,
+        INPUT_FIELD_VECTOR_K, INPUT_FIELD_VECTOR_VECTOR, INPUT_FIELD_VECTOR_WEIGHT,
+    },
+    registry::{
+        enums::OrderByDirection,
+        scalars::{DateScalar, DateTimeScalar, IPAddressScalar, TimestampScalar},
     },
-    registry::scalars::{DateScalar, DateTimeScalar, IPAddressScalar, TimestampScalar},
     Error,
 };
 
+/// Default number of values returned by a terms facet.
+const DEFAULT_TERMS_FACET_LIMIT: u64 = 10;
+/// Default number of nearest neighbours returned by a vector search.
+const DEFAULT_VECTOR_K: u32 = 10;
+/// Same limit as the pagination.
+const MAX_VECTOR_K: u32 = 1000;
+/// Text score and vector similarity count equally by default in hybrid searches.
+const DEFAULT_VECTOR_WEIGHT: f32 = 0.5;
+/// Edit distances above 2 match almost anything and are not supported by the search engine.
+const MAX_FUZZINESS: u8 = 2;
+
 #[derive(Debug, Error)]
 pub enum InvalidPagination {
     #[error("The '{0}' and '{0}' parameters are not supported together.")]
@@ -52,6 +67,150 @@ pub fn parse_pagination(
     }
 }
 
+pub fn parse_fuzziness(fuzziness: Option<i64>) -> Result<Option<u8>, Error> {
+    fuzziness
+        .map(|fuzziness| {
+            u8::try_from(fuzziness)
+                .ok()
+                .filter(|fuzziness| *fuzziness <= MAX_FUZZINESS)
+                .ok_or_else(|| Error::new(format!("The fuzziness must be between 0 and {MAX_FUZZINESS}.")))
+        })
+        .transpose()
+}
+
+pub fn parse_facets(schema: &search::Schema, object: Value) -> Result<Vec<search::Facet>, Error> {
+    let Value::Object(facets) = object else {
+        return Err(Error::new("Expected an object of facets"));
+    };
+
+    facets
+        .into_iter()
+        .filter(|(_, value)| !value.is_null())
+        .map(|(name, value)| {
+            let field = schema
+                .fields
+                .get(&name)
+                .ok_or_else(|| Error::new(format!("Field '{name}': Unknown field")))?;
+            parse_facet(name.clone(), field, value).map_err(|err| Error::new(format!("Field '{name}': {err:?}")))
+        })
+        .collect()
+}
+
+fn parse_facet(field_name: String, field: &search::FieldEntry, value: Value) -> Result<search::Facet, Error> {
+    use search::FieldType::*;
+    let Value::Object(mut options) = value else {
+        return Err(Error::new("Expected an object of facet options"));
+    };
+
+    Ok(match field.ty {
+        URL { .. } | Email { .. } | PhoneNumber { .. } | String { .. } => search::Facet::Terms {
+            field: field_name,
+            limit: match options.remove(INPUT_FIELD_FACET_LIMIT) {
+                None | Some(Value::Null) => DEFAULT_TERMS_FACET_LIMIT,
+                Some(limit) => serde_json::from_value(limit)?,
+            },
+        },
+        Int { .. } | Float { .. } | Date { .. } | DateTime { .. } | Timestamp { .. } => search::Facet::Range {
+            field: field_name,
+            boundaries: match options.remove(INPUT_FIELD_FACET_BOUNDARIES) {
+                Some(Value::Array(boundaries)) => boundaries
+                    .into_iter()
+                    .map(|value| parse_scalar(field, value))
+                    .collect::<Result<_, _>>()?,
+                _ => return Err(Error::new("Expected a list of boundaries")),
+            },
+        },
+        Boolean { .. } | IPAddress { .. } | Vector { .. } => {
+            return Err(Error::new("Facets are not supported for this type"))
+        }
+    })
+}
+
+pub fn parse_sort(schema: &search::Schema, object: Value) -> Result<Option<search::Sort>, Error> {
+    let Value::Object(sort) = object else {
+        return Err(Error::new("Expected an object for the order"));
+    };
+
+    let mut sort = sort.into_iter().filter(|(_, value)| !value.is_null());
+    let Some((name, direction)) = sort.next() else {
+        return Ok(None);
+    };
+    if sort.next().is_some() {
+        return Err(Error::new("Results can only be ordered by a single field"));
+    }
+    if !schema.fields.get(&name).is_some_and(|field| field.sortable) {
+        return Err(Error::new(format!(
+            "Field '{name}': Results cannot be ordered by this field"
+        )));
+    }
+    Ok(Some(search::Sort {
+        field: name,
+        direction: match serde_json::from_value(direction)? {
+            OrderByDirection::ASC => search::SortDirection::Ascending,
+            OrderByDirection::DESC => search::SortDirection::Descending,
+        },
+    }))
+}
+
+pub fn parse_vector(schema: &search::Schema, object: Value) -> Result<Option<search::VectorSearch>, Error> {
+    let Value::Object(vector) = object else {
+        return Err(Error::new("Expected an object for the vector search"));
+    };
+
+    let mut vector = vector.into_iter().filter(|(_, value)| !value.is_null());
+    let Some((name, value)) = vector.next() else {
+        return Ok(None);
+    };
+    if vector.next().is_some() {
+        return Err(Error::new("Only a single field can be used for a vector search"));
+    }
+    let Some(search::FieldType::Vector(options)) = schema.fields.get(&name).map(|field| &field.ty) else {
+        return Err(Error::new(format!("Field '{name}': Not a vector field")));
+    };
+    parse_vector_options(name.clone(), options, value)
+        .map(Some)
+        .map_err(|err| Error::new(format!("Field '{name}': {err:?}")))
+}
+
+fn parse_vector_options(
+    field: String,
+    options: &search::VectorOptions,
+    value: Value,
+) -> Result<search::VectorSearch, Error> {
+    let Value::Object(mut value) = value else {
+        return Err(Error::new("Expected an object of vector search options"));
+    };
+
+    let vector: Vec<f32> = serde_json::from_value(value.remove(INPUT_FIELD_VECTOR_VECTOR).unwrap_or_default())?;
+    if vector.len() != options.dimensions as usize {
+        return Err(Error::new(format!(
+            "Expected a vector with {} dimensions, got {}",
+            options.dimensions,
+            vector.len()
+        )));
+    }
+    let k = match value.remove(INPUT_FIELD_VECTOR_K) {
+        None | Some(Value::Null) => DEFAULT_VECTOR_K,
+        Some(k) => serde_json::from_value(k)?,
+    };
+    if k == 0 || k > MAX_VECTOR_K {
+        return Err(Error::new(format!("k must be between 1 and {MAX_VECTOR_K}")));
+    }
+    let weight = match value.remove(INPUT_FIELD_VECTOR_WEIGHT) {
+        None | Some(Value::Null) => DEFAULT_VECTOR_WEIGHT,
+        Some(weight) => serde_json::from_value(weight)?,
+    };
+    if !(0.0..=1.0).contains(&weight) {
+        return Err(Error::new("The weight must be between 0 and 1"));
+    }
+    Ok(search::VectorSearch {
+        field,
+        vector,
+        k,
+        weight,
+    })
+}
+
 pub fn parse_filter(schema: &search::Schema, object: Value) -> Result<search::Filter, Error> {
     match object {
         Value::Object(filters) => Ok(search::Filter::All(
@@ -205,6 +364,7 @@ fn parse_scalar(field: &search::FieldEntry, value: Value) -> Result<search::Scal
         Float { .. } => ScalarValue::Float(serde_json::from_value(value)?),
         Boolean { .. } => ScalarValue::Boolean(serde_json::from_value(value)?),
         IPAddress { .. } => ScalarValue::IPAddress(IPAddressScalar::parse_value(value)?),
+        Vector { .. } => return Err(Error::new("Vectors cannot be used in filters")),
     })
 }
 
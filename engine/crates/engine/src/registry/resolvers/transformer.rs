@@ -6,7 +6,11 @@ use dynamodb::attribute_to_value;
 use dynomite::AttributeValue;
 use grafbase_sql_ast::ast::Order;
 use indexmap::IndexMap;
-use postgres_connector_types::{cursor::SQLCursor, database_definition::TableId};
+use postgres_connector_types::{
+    cursor::SQLCursor,
+    database_definition::{ScalarType, TableId},
+    range,
+};
 use runtime::search::GraphqlCursor;
 use serde_json::Value;
 
@@ -102,6 +106,10 @@ pub enum Transformer {
         directive_name: String,
         table_id: TableId,
     },
+    /// Converts a Postgres range from the text format into a range object.
+    PostgresRange {
+        scalar: ScalarType,
+    },
 }
 
 impl From<Transformer> for Resolver {
@@ -436,6 +444,14 @@ impl Transformer {
                 let mut new_value = ResolvedValue::new(cursor);
                 new_value.selection_data = Some(selection_data.clone());
 
+                Ok(new_value)
+            }
+            Transformer::PostgresRange { scalar } => {
+                let resolved_value =
+                    last_resolver_value.ok_or_else(|| Error::new("Internal error resolving postgres range"))?;
+
+                let new_value = ResolvedValue::new(resolve_range_value(*scalar, resolved_value.take())?);
+
                 Ok(new_value)
             }
         }
@@ -491,3 +507,16 @@ fn enum_type_mismatch_error(received: &str) -> Error {
         "Received an unexpected type from the remote API.  Expected a string but received {received}"
     ))
 }
+
+/// Converts Postgres ranges in the text format, or arrays of them, into range objects.
+fn resolve_range_value(scalar: ScalarType, value: Value) -> Result<Value, Error> {
+    match value {
+        Value::String(value) => range::to_object(scalar, &value).map_err(|error| Error::new(error.to_string())),
+        Value::Array(values) => values
+            .into_iter()
+            .map(|value| resolve_range_value(scalar, value))
+            .collect::<Result<_, _>>()
+            .map(Value::Array),
+        value => Ok(value),
+    }
+}
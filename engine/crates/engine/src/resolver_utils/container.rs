@@ -339,6 +339,8 @@ impl<'a> FieldExecutionSet<'a> {
                 let alias = ctx_field.item.node.alias.clone().map(|x| x.node);
                 let extensions = &ctx.query_env.extensions;
 
+                // Kept for the extensions, authorization rules may depend on the parent object.
+                let parent_value = parent_resolver_value.clone();
                 let resolve_fut = resolve_field(&ctx_field, parent_resolver_value);
 
                 if extensions.is_empty() && field.node.directives.is_empty() {
@@ -389,6 +391,7 @@ impl<'a> FieldExecutionSet<'a> {
                     required_operation: meta_field.and_then(|f| f.required_operation),
                     auth: meta_field.and_then(|f| f.auth.as_ref()),
                     input_values: args_values,
+                    parent_value: parent_value.as_ref().map(ResolvedValue::data_resolved),
                 };
 
                 if field.node.directives.is_empty() {
@@ -594,6 +597,7 @@ impl<'a> Fields<'a> {
                                     required_operation: meta_field.and_then(|f| f.required_operation),
                                     auth: meta_field.and_then(|f| f.auth.as_ref()),
                                     input_values: args_values,
+                                    parent_value: None,
                                 };
 
                                 let resolve_fut = async {
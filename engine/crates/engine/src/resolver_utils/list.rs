@@ -167,6 +167,7 @@ fn apply_extensions<'a>(
             required_operation: meta_field.and_then(|f| f.required_operation),
             auth: meta_field.and_then(|f| f.auth.as_ref()),
             input_values: args_values,
+            parent_value: None,
         };
 
         let resolve_fut = async move { Ok(Some(resolve_fut.await?)) };
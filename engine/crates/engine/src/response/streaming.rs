@@ -7,8 +7,7 @@ use crate::{GraphQlResponse, QueryPath, Response, ServerError};
 /// they can received.  The first payload will always be an `InitialResponse` - followed by
 /// zero or more `Incremental` payloads (if there were any deferred workloads in the request).
 ///
-/// At some point we might add support for subscriptions in which case a user will probably
-/// see multiple Response entries.
+/// Subscriptions send an `InitialResponse` for every event.
 #[derive(Debug)]
 pub enum StreamingPayload {
     InitialResponse(InitialResponse),
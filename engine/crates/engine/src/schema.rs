@@ -1,8 +1,8 @@
 use std::{any::Any, ops::Deref, sync::Arc};
 
 use dynamodb::CurrentDateTime;
-use futures_util::stream::{self, Stream, StreamExt};
-use graph_entities::CompactValue;
+use futures_util::stream::{Stream, StreamExt};
+use graph_entities::{CompactValue, ResponseNodeId};
 use indexmap::map::IndexMap;
 
 use crate::{
@@ -18,11 +18,12 @@ use crate::{
     registry::{MetaDirective, MetaInputValue, Registry},
     resolver_utils::{self, resolve_root_container, resolve_root_container_serial},
     response::{IncrementalPayload, StreamingPayload},
-    subscription::collect_subscription_streams,
+    subscription,
     types::QueryRoot,
     validation::{check_rules, ValidationMode},
     BatchRequest, BatchResponse, CacheControl, ContextExt, ContextSelectionSet, LegacyInputType, LegacyOutputType,
-    ObjectType, QueryEnv, QueryEnvBuilder, QueryPath, Request, Response, ServerError, SubscriptionType, Variables, ID,
+    ObjectType, QueryEnv, QueryEnvBuilder, QueryPath, Request, Response, ServerError, ServerResult, SubscriptionType,
+    Variables, ID,
 };
 
 /// Schema builder
@@ -526,29 +527,7 @@ impl Schema {
             )),
         };
 
-        let operation_name =
-            env.operation_name
-                .as_deref()
-                .or_else(|| match env.operation.selection_set.node.items.as_slice() {
-                    [Positioned {
-                        node: Selection::Field(field),
-                        ..
-                    }] => Some(field.node.name.node.as_str()),
-                    _ => None,
-                });
-
-        let mut resp = match res {
-            Ok(value) => {
-                let response = &mut *ctx.response().await;
-                response.set_root_unchecked(value);
-                Response::new(std::mem::take(response), operation_name, &env.operation)
-            }
-            Err(err) => Response::from_errors(vec![err], operation_name, &env.operation),
-        }
-        .http_headers(std::mem::take(&mut *env.response_http_headers.lock().unwrap()));
-
-        resp.errors.extend(std::mem::take(&mut *env.errors.lock().unwrap()));
-        resp
+        build_response(&ctx, &env, res).await
     }
 
     /// Execute a GraphQL query.
@@ -646,15 +625,26 @@ impl Schema {
                     schema.registry().root_type(env.operation.node.ty),
                 );
 
-                let mut streams = Vec::new();
-                if let Err(err) = collect_subscription_streams(&ctx, &crate::EmptySubscription, &mut streams) {
-                    // This hasNext: false is probably not correct, but we dont' support subscriptios atm so whatever
-                    yield Response::from_errors_with_type(vec![err], OperationType::Subscription).into_streaming_payload(false);
-                }
+                let mut values = match subscription::create_field_stream(&ctx) {
+                    Ok(values) => values,
+                    Err(err) => {
+                        yield Response::from_errors_with_type(vec![err], OperationType::Subscription).into_streaming_payload(false);
+                        return;
+                    }
+                };
 
-                let mut stream = stream::select_all(streams);
-                while let Some(resp) = stream.next().await {
-                    yield resp.into_streaming_payload(false);
+                // Every value is resolved with the selection of the subscription, as if it was the
+                // value of a query.
+                while let Some(value) = values.next().await {
+                    let response = match value {
+                        Ok(value) => {
+                            let res = resolver_utils::resolve_deferred_container(&ctx, Some(value)).await;
+                            build_response(&ctx, &env, res).await
+                        }
+                        Err(err) => Response::from_errors_with_type(vec![err], OperationType::Subscription),
+                    };
+
+                    yield response.into_streaming_payload(true);
                 }
             }
         })
@@ -673,6 +663,34 @@ impl Schema {
     }
 }
 
+/// Builds the response of an operation from its resolved root, taking the response data, the
+/// errors and the HTTP headers collected during the execution.
+async fn build_response(ctx: &ContextSelectionSet<'_>, env: &QueryEnv, res: ServerResult<ResponseNodeId>) -> Response {
+    let operation_name =
+        env.operation_name
+            .as_deref()
+            .or_else(|| match env.operation.selection_set.node.items.as_slice() {
+                [Positioned {
+                    node: Selection::Field(field),
+                    ..
+                }] => Some(field.node.name.node.as_str()),
+                _ => None,
+            });
+
+    let mut resp = match res {
+        Ok(value) => {
+            let response = &mut *ctx.response().await;
+            response.set_root_unchecked(value);
+            Response::new(std::mem::take(response), operation_name, &env.operation)
+        }
+        Err(err) => Response::from_errors(vec![err], operation_name, &env.operation),
+    }
+    .http_headers(std::mem::take(&mut *env.response_http_headers.lock().unwrap()));
+
+    resp.errors.extend(std::mem::take(&mut *env.errors.lock().unwrap()));
+    resp
+}
+
 async fn process_deferred_workload(
     workload: deferred::DeferredWorkload,
     schema: &Schema,
@@ -1,12 +1,15 @@
 use std::{borrow::Cow, pin::Pin};
 
-use engine_parser::types::OperationType;
+use engine_parser::Positioned;
 use futures_util::stream::{Stream, StreamExt};
 
 use crate::{
-    parser::types::{Selection, TypeCondition},
+    parser::types::{Field, Selection, SelectionSet, TypeCondition},
     registry,
-    registry::Registry,
+    registry::{
+        resolvers::{ResolvedValue, Resolver},
+        Registry,
+    },
     ContextField, ContextSelectionSet, Response, ServerError, ServerResult,
 };
 
@@ -36,70 +39,71 @@ pub trait SubscriptionType: Send + Sync {
     ) -> Option<Pin<Box<dyn Stream<Item = Response> + Send + 'a>>>;
 }
 
-type BoxFieldStream<'a> = Pin<Box<dyn Stream<Item = Response> + 'a + Send>>;
+type BoxValueStream<'a> = Pin<Box<dyn Stream<Item = ServerResult<ResolvedValue>> + Send + 'a>>;
 
-pub(crate) fn collect_subscription_streams<'a, T: SubscriptionType + 'static>(
-    ctx: &ContextSelectionSet<'a>,
-    root: &'a T,
-    streams: &mut Vec<BoxFieldStream<'a>>,
-) -> ServerResult<()> {
-    for selection in &ctx.item.node.items {
+/// Creates the stream of a subscription from the resolver of its root field. Every value is
+/// resolved with the selection of the root field into its own response. Subscriptions select
+/// exactly one root field, possibly within fragments.
+pub(crate) fn create_field_stream<'a>(ctx: &'a ContextSelectionSet<'a>) -> ServerResult<BoxValueStream<'a>> {
+    let mut fields = Vec::new();
+    collect_root_fields(ctx, &ctx.item.node, &mut fields);
+    let field = match fields.as_slice() {
+        [field] => *field,
+        _ => {
+            return Err(ServerError::new(
+                "A subscription must select exactly one field.",
+                Some(ctx.item.pos),
+            ))
+        }
+    };
+
+    Ok(Box::pin(async_stream::stream! {
+        let ctx = ctx.with_field(field);
+        let field_name = ctx.item.node.response_key().node.clone();
+
+        let mut stream = match ctx.field.resolver {
+            Resolver::PostgresResolver(ref resolver) => resolver.subscribe(&ctx),
+            _ => {
+                let err = ServerError::new(format!(r#"Cannot subscribe to field "{field_name}"."#), Some(ctx.item.pos))
+                    .with_path(vec![field_name.as_str().into()]);
+
+                yield Err(err);
+                return;
+            }
+        };
+
+        while let Some(value) = stream.next().await {
+            yield value.map_err(|err| err.into_server_error(ctx.item.pos));
+        }
+    }))
+}
+
+/// The fields of the root selection set, with the fragments applying to the root type expanded.
+/// Fields skipped with `@skip` or `@include` were already removed from the document.
+fn collect_root_fields<'a>(
+    ctx: &'a ContextSelectionSet<'a>,
+    selection_set: &'a SelectionSet,
+    fields: &mut Vec<&'a Positioned<Field>>,
+) {
+    for selection in &selection_set.items {
         match &selection.node {
-            Selection::Field(field) => streams.push(Box::pin({
-                let ctx = ctx.clone();
-                async_stream::stream! {
-                    let ctx = ctx.with_field(field);
-                    let field_name = ctx.item.node.response_key().node.clone();
-                    let stream = root.create_field_stream(&ctx);
-                    if let Some(mut stream) = stream {
-                        while let Some(resp) = stream.next().await {
-                            yield resp;
-                        }
-                    } else {
-                        let err = ServerError::new(format!(r#"Cannot query field "{}" on type "{}"."#, field_name, T::type_name()), Some(ctx.item.pos))
-                            .with_path(vec![field_name.as_str().into()]);
-                        yield Response::from_errors_with_type(vec![err], OperationType::Subscription);
-                    }
-                }
-            })),
+            Selection::Field(field) => fields.push(field),
             Selection::FragmentSpread(fragment_spread) => {
-                if let Some(fragment) = ctx
-                    .query_env
-                    .fragments
-                    .get(&fragment_spread.node.fragment_name.node)
-                {
-                    collect_subscription_streams(
-                        &ctx.with_selection_set(&fragment.node.selection_set, ctx.ty),
-                        root,
-                        streams,
-                    )?;
+                if let Some(fragment) = ctx.query_env.fragments.get(&fragment_spread.node.fragment_name.node) {
+                    collect_root_fields(ctx, &fragment.node.selection_set.node, fields);
                 }
             }
             Selection::InlineFragment(inline_fragment) => {
-                if let Some(TypeCondition { on: name }) = inline_fragment
-                    .node
-                    .type_condition
-                    .as_ref()
-                    .map(|v| &v.node)
-                {
-                    if name.node.as_str() == T::type_name() {
-                        collect_subscription_streams(
-                            &ctx.with_selection_set(&inline_fragment.node.selection_set, ctx.ty),
-                            root,
-                            streams,
-                        )?;
-                    }
-                } else {
-                    collect_subscription_streams(
-                        &ctx.with_selection_set(&inline_fragment.node.selection_set, ctx.ty),
-                        root,
-                        streams,
-                    )?;
+                let applies = match inline_fragment.node.type_condition.as_ref().map(|v| &v.node) {
+                    Some(TypeCondition { on: name }) => name.node.as_str() == ctx.ty.name(),
+                    None => true,
+                };
+                if applies {
+                    collect_root_fields(ctx, &inline_fragment.node.selection_set.node, fields);
                 }
             }
         }
     }
-    Ok(())
 }
 
 impl<T: SubscriptionType> SubscriptionType for &T {
@@ -0,0 +1,39 @@
+use engine_value::Value;
+
+use super::DynValidate;
+use crate::{registry::MetaInputValue, validation::visitor::VisitorContext, Pos};
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ItemsValidator {
+    min: Option<usize>,
+    max: Option<usize>,
+}
+
+impl ItemsValidator {
+    pub fn new(min: Option<usize>, max: Option<usize>) -> Self {
+        ItemsValidator { min, max }
+    }
+}
+
+impl DynValidate<&Value> for ItemsValidator {
+    fn validate(&self, ctx: &mut VisitorContext<'_>, meta: &MetaInputValue, pos: Pos, value: &Value) {
+        let Value::List(items) = value else {
+            return;
+        };
+        let count = items.len();
+
+        if let Some(min) = self.min.filter(|min| count < *min) {
+            let name = super::value_name(ctx, meta);
+            ctx.report_error(
+                vec![pos],
+                format!("Invalid value for argument \"{name}\", {count} items are too few, must be at least {min}"),
+            );
+        } else if let Some(max) = self.max.filter(|max| count > *max) {
+            let name = super::value_name(ctx, meta);
+            ctx.report_error(
+                vec![pos],
+                format!("Invalid value for argument \"{name}\", {count} items are too many, must be at most {max}"),
+            );
+        }
+    }
+}
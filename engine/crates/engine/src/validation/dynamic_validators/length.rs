@@ -51,7 +51,7 @@ impl DynValidate<&Value> for LengthValidator {
             Value::String(string) => string.chars().count(),
             _ => return,
         };
-        let name = meta.name.as_str();
+        let name = super::value_name(ctx, meta);
         match check_bounds(count, self.min, self.max) {
             InBounds => (),
             TooLong => ctx.report_error(
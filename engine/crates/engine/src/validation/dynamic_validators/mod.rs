@@ -2,32 +2,73 @@ use engine_value::Value;
 
 use crate::{registry::MetaInputValue, validation::visitor::VisitorContext, Pos};
 
+mod items;
 mod length;
+mod multiple_of;
+mod pattern;
+mod range;
 
+use items::ItemsValidator;
 use length::LengthValidator;
+use multiple_of::MultipleOfValidator;
+use pattern::PatternValidator;
+use range::RangeValidator;
 
 pub(crate) trait DynValidate<T> {
     fn validate(&self, _ctx: &mut VisitorContext<'_>, meta: &MetaInputValue, pos: Pos, other: T);
 }
 
+/// How the invalid value is named in the errors: its path in the arguments if known.
+fn value_name(ctx: &VisitorContext<'_>, meta: &MetaInputValue) -> String {
+    ctx.input_path().unwrap_or_else(|| meta.name.clone())
+}
+
 // Wrap Validators up in an enum to avoid having to box the context data
 #[derive(Clone, derivative::Derivative, serde::Serialize, serde::Deserialize)]
 pub enum DynValidator {
     Length(LengthValidator),
+    Range(RangeValidator),
+    MultipleOf(MultipleOfValidator),
+    Pattern(PatternValidator),
+    Items(ItemsValidator),
 }
 
 impl DynValidator {
     pub fn length(min: Option<usize>, max: Option<usize>) -> Self {
         Self::Length(LengthValidator::new(min, max))
     }
+
+    pub fn range(min: Option<f64>, max: Option<f64>) -> Self {
+        Self::Range(RangeValidator::new(min, max))
+    }
+
+    pub fn multiple_of(value: f64) -> Self {
+        Self::MultipleOf(MultipleOfValidator::new(value))
+    }
+
+    pub fn pattern(regex: impl Into<String>) -> Self {
+        Self::Pattern(PatternValidator::new(regex))
+    }
+
+    pub fn items(min: Option<usize>, max: Option<usize>) -> Self {
+        Self::Items(ItemsValidator::new(min, max))
+    }
+
+    /// Validators defined on a list which apply to each of its items.
+    pub(crate) fn validates_items(&self) -> bool {
+        matches!(self, Self::Range(_) | Self::MultipleOf(_) | Self::Pattern(_))
+    }
 }
 
 impl DynValidator {
     fn inner(&self) -> &dyn DynValidate<&Value> {
         use DynValidator::*;
-        #[allow(clippy::single_match)]
         match self {
             Length(v) => v,
+            Range(v) => v,
+            MultipleOf(v) => v,
+            Pattern(v) => v,
+            Items(v) => v,
         }
     }
 }
@@ -37,3 +78,98 @@ impl DynValidate<&Value> for DynValidator {
         self.inner().validate(ctx, meta, pos, value);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::diverging_sub_expression)]
+
+    use engine_value::{Name, Value};
+
+    use super::DynValidator;
+    use crate::{
+        parser::parse_query,
+        registry::{InputObjectType, MetaInputValue, MetaTypeName},
+        validation::{
+            visitor::{test::visit_input_value, VisitorContext},
+            VisitorNil,
+        },
+        EmptyMutation, EmptySubscription, Object, Pos, Schema, Variables,
+    };
+
+    struct Query;
+
+    #[Object(internal)]
+    #[allow(unreachable_code)]
+    impl Query {
+        async fn value(&self) -> i32 {
+            todo!()
+        }
+    }
+
+    fn validation_errors(value: &Value, variables: Option<&Variables>) -> Vec<String> {
+        let mut registry = Schema::create_registry_static::<Query, EmptyMutation, EmptySubscription>();
+        let input_fields = [
+            MetaInputValue {
+                validators: Some(vec![
+                    DynValidator::range(Some(0.0), Some(100.0)),
+                    DynValidator::multiple_of(0.5),
+                ]),
+                ..MetaInputValue::new("price", "Float")
+            },
+            MetaInputValue {
+                validators: Some(vec![
+                    DynValidator::pattern("^[a-z]+$"),
+                    DynValidator::items(None, Some(2)),
+                ]),
+                ..MetaInputValue::new("tags", "[String!]")
+            },
+        ];
+        registry.types.insert(
+            "ProductInput".to_string(),
+            InputObjectType::new("ProductInput".to_string(), input_fields).into(),
+        );
+
+        let doc = parse_query("{ value }").unwrap();
+        let meta = MetaInputValue::new("input", "ProductInput!");
+        let mut ctx = VisitorContext::new(&registry, &doc, variables);
+
+        ctx.with_input_path("input".to_string(), |ctx| {
+            visit_input_value(
+                &mut VisitorNil,
+                ctx,
+                Pos::default(),
+                Some(MetaTypeName::create("ProductInput!")),
+                value,
+                Some(&meta),
+            );
+        });
+
+        ctx.errors.into_iter().map(|error| error.message).collect()
+    }
+
+    #[test]
+    fn test_input_validators() {
+        let value = Value::from_json(serde_json::json!({ "price": 10.5, "tags": ["a", "b"] })).unwrap();
+        assert!(validation_errors(&value, None).is_empty());
+
+        let value = Value::from_json(serde_json::json!({ "price": 100.5, "tags": ["a", "B", "c"] })).unwrap();
+        assert_eq!(
+            validation_errors(&value, None),
+            [
+                "Invalid value for argument \"input.price\", 100.5 is too large, must be at most 100",
+                "Invalid value for argument \"input.tags.1\", must match the pattern \"^[a-z]+$\"",
+                "Invalid value for argument \"input.tags\", 3 items are too many, must be at most 2",
+            ]
+        );
+
+        let variables = Variables::from_json(serde_json::json!({ "product": { "price": 1.2, "tags": ["a", "1"] } }));
+        let value = Value::Variable(Name::new("product"));
+        assert_eq!(
+            validation_errors(&value, Some(&variables)),
+            [
+                "Invalid value for argument \"input.price\", 1.2 must be a multiple of 0.5",
+                "Invalid value for argument \"input.tags.1\", must match the pattern \"^[a-z]+$\"",
+            ]
+        );
+    }
+}
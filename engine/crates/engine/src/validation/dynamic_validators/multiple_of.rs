@@ -0,0 +1,39 @@
+use engine_value::Value;
+
+use super::DynValidate;
+use crate::{registry::MetaInputValue, validation::visitor::VisitorContext, Pos};
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MultipleOfValidator {
+    value: f64,
+}
+
+impl MultipleOfValidator {
+    pub fn new(value: f64) -> Self {
+        MultipleOfValidator { value }
+    }
+}
+
+impl DynValidate<&Value> for MultipleOfValidator {
+    fn validate(&self, ctx: &mut VisitorContext<'_>, meta: &MetaInputValue, pos: Pos, value: &Value) {
+        let Some(number) = (match value {
+            Value::Number(number) => number.as_f64(),
+            _ => None,
+        }) else {
+            return;
+        };
+
+        // Comparing with the closest multiple, decimal steps such as 0.01 aren't exact in binary.
+        let quotient = number / self.value;
+        if (quotient - quotient.round()).abs() > 1e-9 {
+            let name = super::value_name(ctx, meta);
+            ctx.report_error(
+                vec![pos],
+                format!(
+                    "Invalid value for argument \"{name}\", {number} must be a multiple of {}",
+                    self.value
+                ),
+            );
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use std::sync::OnceLock;
+
+use engine_value::Value;
+use regex::Regex;
+
+use super::DynValidate;
+use crate::{registry::MetaInputValue, validation::visitor::VisitorContext, Pos};
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PatternValidator {
+    regex: String,
+    /// Compiled on first use, the pattern is checked when parsing the schema.
+    #[serde(skip)]
+    compiled: OnceLock<Option<Regex>>,
+}
+
+impl PatternValidator {
+    pub fn new(regex: impl Into<String>) -> Self {
+        PatternValidator {
+            regex: regex.into(),
+            compiled: OnceLock::new(),
+        }
+    }
+}
+
+impl DynValidate<&Value> for PatternValidator {
+    fn validate(&self, ctx: &mut VisitorContext<'_>, meta: &MetaInputValue, pos: Pos, value: &Value) {
+        let Value::String(string) = value else {
+            return;
+        };
+
+        let Some(regex) = self.compiled.get_or_init(|| Regex::new(&self.regex).ok()) else {
+            return;
+        };
+
+        if !regex.is_match(string) {
+            let name = super::value_name(ctx, meta);
+            ctx.report_error(
+                vec![pos],
+                format!(
+                    "Invalid value for argument \"{name}\", must match the pattern \"{}\"",
+                    self.regex
+                ),
+            );
+        }
+    }
+}
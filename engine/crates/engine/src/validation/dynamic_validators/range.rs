@@ -0,0 +1,41 @@
+use engine_value::Value;
+
+use super::DynValidate;
+use crate::{registry::MetaInputValue, validation::visitor::VisitorContext, Pos};
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RangeValidator {
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+impl RangeValidator {
+    pub fn new(min: Option<f64>, max: Option<f64>) -> Self {
+        RangeValidator { min, max }
+    }
+}
+
+impl DynValidate<&Value> for RangeValidator {
+    fn validate(&self, ctx: &mut VisitorContext<'_>, meta: &MetaInputValue, pos: Pos, value: &Value) {
+        let Some(number) = (match value {
+            Value::Number(number) => number.as_f64(),
+            _ => None,
+        }) else {
+            return;
+        };
+
+        if let Some(min) = self.min.filter(|min| number < *min) {
+            let name = super::value_name(ctx, meta);
+            ctx.report_error(
+                vec![pos],
+                format!("Invalid value for argument \"{name}\", {number} is too small, must be at least {min}"),
+            );
+        } else if let Some(max) = self.max.filter(|max| number > *max) {
+            let name = super::value_name(ctx, meta);
+            ctx.report_error(
+                vec![pos],
+                format!("Invalid value for argument \"{name}\", {number} is too large, must be at most {max}"),
+            );
+        }
+    }
+}
@@ -3,7 +3,7 @@ use std::{
     fmt::{self, Display, Formatter},
 };
 
-use engine_value::Value;
+use engine_value::{ConstValue, Value};
 
 use super::dynamic_validators::DynValidate;
 use crate::{
@@ -22,6 +22,7 @@ pub struct VisitorContext<'a> {
     pub(crate) errors: Vec<RuleError>,
     type_stack: Vec<Option<&'a registry::MetaType>>,
     input_type: Vec<Option<MetaTypeName<'a>>>,
+    input_path: Vec<String>,
     fragments: &'a HashMap<Name, Positioned<FragmentDefinition>>,
 }
 
@@ -37,6 +38,7 @@ impl<'a> VisitorContext<'a> {
             errors: Default::default(),
             type_stack: Default::default(),
             input_type: Default::default(),
+            input_path: Default::default(),
             fragments: &doc.fragments,
         }
     }
@@ -69,6 +71,17 @@ impl<'a> VisitorContext<'a> {
         self.input_type.pop();
     }
 
+    pub(crate) fn with_input_path<F: FnMut(&mut VisitorContext<'a>)>(&mut self, segment: String, mut f: F) {
+        self.input_path.push(segment);
+        f(self);
+        self.input_path.pop();
+    }
+
+    /// The path to the input value being visited, e.g. `input.tags.0`.
+    pub(crate) fn input_path(&self) -> Option<String> {
+        (!self.input_path.is_empty()).then(|| self.input_path.join("."))
+    }
+
     pub(crate) fn parent_type(&self) -> Option<&'a registry::MetaType> {
         if self.type_stack.len() >= 2 {
             self.type_stack.get(self.type_stack.len() - 2).copied().flatten()
@@ -571,7 +584,9 @@ fn visit_field<'a, V: Visitor<'a>>(v: &mut V, ctx: &mut VisitorContext<'a>, fiel
         let expected_ty = meta_input_value.map(|input_ty| MetaTypeName::create(input_ty.ty.as_str()));
 
         ctx.with_input_type(expected_ty, |ctx| {
-            visit_input_value(v, ctx, field.pos, expected_ty, &value.node, meta_input_value);
+            ctx.with_input_path(name.node.to_string(), |ctx| {
+                visit_input_value(v, ctx, field.pos, expected_ty, &value.node, meta_input_value);
+            });
         });
         v.exit_argument(ctx, name, value);
     }
@@ -596,31 +611,18 @@ fn visit_input_value<'a, V: Visitor<'a>>(
             if let Some(expected_ty) = expected_ty {
                 let elem_ty = expected_ty.unwrap_non_null();
                 if let MetaTypeName::List(expected_ty) = elem_ty {
-                    let (name, description) = ctx
-                        .registry
-                        .types
-                        .get(MetaTypeName::concrete_typename(expected_ty))
-                        .map(|meta_type| (meta_type.name().to_string(), meta_type.description().map(String::from)))
-                        .unwrap_or_default();
-                    let inner_meta = meta.map(|meta| MetaInputValue {
-                        name,
-                        description,
-                        ty: expected_ty.into(),
-                        default_value: None,
-                        visible: meta.visible,
-                        validators: None,
-                        is_secret: meta.is_secret,
-                        rename: None,
-                    });
-                    for value in values {
-                        visit_input_value(
-                            v,
-                            ctx,
-                            pos,
-                            Some(MetaTypeName::create(expected_ty)),
-                            value,
-                            inner_meta.as_ref(),
-                        );
+                    let inner_meta = meta.map(|meta| list_item_meta(ctx, meta, expected_ty));
+                    for (index, value) in values.iter().enumerate() {
+                        ctx.with_input_path(index.to_string(), |ctx| {
+                            visit_input_value(
+                                v,
+                                ctx,
+                                pos,
+                                Some(MetaTypeName::create(expected_ty)),
+                                value,
+                                inner_meta.as_ref(),
+                            );
+                        });
                     }
                 }
             }
@@ -634,14 +636,16 @@ fn visit_input_value<'a, V: Visitor<'a>>(
                     {
                         for (item_key, item_value) in values {
                             if let Some(input_value) = input_object.input_fields.get(item_key.as_str()) {
-                                visit_input_value(
-                                    v,
-                                    ctx,
-                                    pos,
-                                    Some(MetaTypeName::create(input_value.ty.as_str())),
-                                    item_value,
-                                    Some(input_value),
-                                );
+                                ctx.with_input_path(item_key.to_string(), |ctx| {
+                                    visit_input_value(
+                                        v,
+                                        ctx,
+                                        pos,
+                                        Some(MetaTypeName::create(input_value.ty.as_str())),
+                                        item_value,
+                                        Some(input_value),
+                                    );
+                                });
                             }
                         }
                     }
@@ -651,15 +655,116 @@ fn visit_input_value<'a, V: Visitor<'a>>(
         _ => {}
     }
 
-    if let Some(meta) = meta {
-        if let Some(validators) = meta.validators.as_ref() {
-            for validator in validators {
-                validator.validate(ctx, meta, pos, value);
+    // The validators also apply to the values provided through variables.
+    let variable_value = match value {
+        Value::Variable(name) => ctx
+            .variables
+            .and_then(|variables| variables.get(name))
+            .cloned()
+            .map(ConstValue::into_value),
+        _ => None,
+    };
+
+    match variable_value {
+        Some(value) => validate_input_value(ctx, pos, expected_ty, &value, meta),
+        None => run_validators(ctx, pos, value, meta),
+    }
+
+    v.exit_input_value(ctx, pos, &expected_ty, value, meta);
+}
+
+/// The definition of the items of a list input value. Validators of the items are
+/// defined on the list.
+fn list_item_meta(ctx: &VisitorContext<'_>, meta: &MetaInputValue, item_ty: &str) -> MetaInputValue {
+    let (name, description) = ctx
+        .registry
+        .types
+        .get(MetaTypeName::concrete_typename(item_ty))
+        .map(|meta_type| (meta_type.name().to_string(), meta_type.description().map(String::from)))
+        .unwrap_or_default();
+
+    let validators = meta.validators.as_ref().and_then(|validators| {
+        let validators: Vec<_> = validators
+            .iter()
+            .filter(|validator| validator.validates_items())
+            .cloned()
+            .collect();
+        (!validators.is_empty()).then_some(validators)
+    });
+
+    MetaInputValue {
+        name,
+        description,
+        ty: item_ty.into(),
+        default_value: None,
+        visible: meta.visible,
+        validators,
+        is_secret: meta.is_secret,
+        rename: None,
+    }
+}
+
+/// Runs the validators of the input value and of its nested input values, for values which are
+/// not visited, such as the values of variables.
+fn validate_input_value<'a>(
+    ctx: &mut VisitorContext<'a>,
+    pos: Pos,
+    expected_ty: Option<MetaTypeName<'a>>,
+    value: &Value,
+    meta: Option<&MetaInputValue>,
+) {
+    match (value, expected_ty.map(|ty| ty.unwrap_non_null())) {
+        (Value::List(values), Some(MetaTypeName::List(item_ty))) => {
+            let inner_meta = meta.map(|meta| list_item_meta(ctx, meta, item_ty));
+            for (index, value) in values.iter().enumerate() {
+                ctx.with_input_path(index.to_string(), |ctx| {
+                    validate_input_value(
+                        ctx,
+                        pos,
+                        Some(MetaTypeName::create(item_ty)),
+                        value,
+                        inner_meta.as_ref(),
+                    );
+                });
+            }
+        }
+        (Value::Object(values), Some(MetaTypeName::Named(expected_ty))) => {
+            if let Some(MetaType::InputObject(input_object)) =
+                ctx.registry.types.get(MetaTypeName::concrete_typename(expected_ty))
+            {
+                for (item_key, item_value) in values {
+                    if let Some(input_value) = input_object.input_fields.get(item_key.as_str()) {
+                        ctx.with_input_path(item_key.to_string(), |ctx| {
+                            validate_input_value(
+                                ctx,
+                                pos,
+                                Some(MetaTypeName::create(input_value.ty.as_str())),
+                                item_value,
+                                Some(input_value),
+                            );
+                        });
+                    }
+                }
             }
         }
+        _ => {}
+    }
+
+    run_validators(ctx, pos, value, meta);
+}
+
+fn run_validators(ctx: &mut VisitorContext<'_>, pos: Pos, value: &Value, meta: Option<&MetaInputValue>) {
+    let Some(meta) = meta else {
+        return;
     };
 
-    v.exit_input_value(ctx, pos, &expected_ty, value, meta);
+    for validator in meta.validators.iter().flatten() {
+        // Validators of the items run on each item.
+        if validator.validates_items() && matches!(value, Value::List(_)) {
+            continue;
+        }
+        validator.validate(ctx, meta, pos, value);
+    }
 }
 
 fn visit_variable_definitions<'a, V: Visitor<'a>>(
@@ -688,7 +793,9 @@ fn visit_directives<'a, V: Visitor<'a>>(
             let meta_input_value = schema_directive.and_then(|schema_directive| schema_directive.args.get(&*name.node));
             let expected_ty = meta_input_value.map(|input_ty| MetaTypeName::create(input_ty.ty.as_str()));
             ctx.with_input_type(expected_ty, |ctx| {
-                visit_input_value(v, ctx, d.pos, expected_ty, &value.node, meta_input_value);
+                ctx.with_input_path(name.node.to_string(), |ctx| {
+                    visit_input_value(v, ctx, d.pos, expected_ty, &value.node, meta_input_value);
+                });
             });
             v.exit_argument(ctx, name, value);
         }
@@ -91,7 +91,10 @@ impl<'a> State<'a> {
     fn insert_value(&mut self, node: &async_graphql_value::ConstValue) -> Value {
         match node {
             async_graphql_value::ConstValue::Null => Value::String(self.insert_string("null")),
-            async_graphql_value::ConstValue::Number(number) => Value::String(self.insert_string(&number.to_string())),
+            async_graphql_value::ConstValue::Number(number) => match number.as_i64() {
+                Some(int) => Value::Int(int),
+                None => Value::Float(self.insert_string(&number.to_string())),
+            },
             async_graphql_value::ConstValue::String(s) => Value::String(self.insert_string(s)),
             async_graphql_value::ConstValue::Boolean(b) => Value::Boolean(*b),
             async_graphql_value::ConstValue::Enum(enm) => Value::EnumValue(self.insert_string(enm)),
@@ -3,4 +3,6 @@
 pub enum AdminError {
     #[error("Error purging cache - {0}")]
     CachePurgeError(String),
+    #[error("Error retrieving cache statistics - {0}")]
+    CacheStatsError(String),
 }
@@ -1,3 +1,5 @@
 mod mutation;
+mod query;
 
 pub use mutation::CachePurgeMutation;
+pub use query::CacheStatsQuery;
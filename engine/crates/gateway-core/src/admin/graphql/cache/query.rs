@@ -0,0 +1,49 @@
+use async_graphql::Context;
+use async_runtime::make_send_on_wasm;
+
+use super::super::super::{error::AdminError, AdminContext};
+
+mod output {
+    #[derive(Debug, async_graphql::SimpleObject)]
+    pub struct CacheStats {
+        /// Lookups returning a fresh or stale response.
+        pub hits: u64,
+        pub misses: u64,
+        /// Entries removed to keep the cache within its capacity.
+        pub evictions: u64,
+        pub entries: u64,
+        /// Approximate size of the cached responses.
+        pub size_bytes: u64,
+    }
+
+    impl From<runtime::cache::CacheStats> for CacheStats {
+        fn from(stats: runtime::cache::CacheStats) -> Self {
+            Self {
+                hits: stats.hits,
+                misses: stats.misses,
+                evictions: stats.evictions,
+                entries: stats.entries,
+                size_bytes: stats.size_bytes,
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CacheStatsQuery;
+
+#[async_graphql::Object]
+impl CacheStatsQuery {
+    /// Usage of the cache since the gateway started, null if the cache doesn't keep track of it.
+    pub async fn cache_stats(&self, ctx: &Context<'_>) -> Result<Option<output::CacheStats>, AdminError> {
+        let ctx = ctx
+            .data::<AdminContext>()
+            .map_err(|_| AdminError::CacheStatsError("Missing context".to_string()))?;
+
+        let stats = make_send_on_wasm(ctx.cache.stats())
+            .await
+            .map_err(|e| AdminError::CacheStatsError(e.to_string()))?;
+
+        Ok(stats.map(Into::into))
+    }
+}
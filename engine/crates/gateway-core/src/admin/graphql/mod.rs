@@ -1,7 +1,7 @@
 mod cache;
 
 #[derive(Debug, async_graphql::MergedObject, Default)]
-pub struct Query;
+pub struct Query(cache::CacheStatsQuery);
 
 #[derive(Debug, async_graphql::MergedObject, Default)]
 pub struct Mutation(cache::CachePurgeMutation);
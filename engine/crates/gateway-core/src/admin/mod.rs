@@ -22,13 +22,17 @@ pub async fn handle_graphql_request(
     cache_config: &CacheConfig,
     request: async_graphql::Request,
 ) -> async_graphql::Response {
-    let schema = Schema::build(graphql::Query, graphql::Mutation::default(), EmptySubscription)
-        .data(AdminContext {
-            cache: Arc::clone(cache) as Arc<dyn Cache<Value = engine::Response> + 'static>,
-            ray_id: ctx.ray_id().to_string(),
-            host_name: cache_config.host_name.clone(),
-        })
-        .finish();
+    let schema = Schema::build(
+        graphql::Query::default(),
+        graphql::Mutation::default(),
+        EmptySubscription,
+    )
+    .data(AdminContext {
+        cache: Arc::clone(cache) as Arc<dyn Cache<Value = engine::Response> + 'static>,
+        ray_id: ctx.ray_id().to_string(),
+        host_name: cache_config.host_name.clone(),
+    })
+    .finish();
 
     schema
         .execute(request)
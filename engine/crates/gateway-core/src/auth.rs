@@ -1,7 +1,7 @@
 use std::{collections::BTreeMap, sync::Arc};
 
 use common_types::{auth::ExecutionAuth, UdfKind};
-use engine::{AuthConfig, AuthProvider, AuthorizerProvider};
+use engine::{AuthConfig, AuthProvider, AuthorizerProvider, ClaimsContext};
 use futures_util::TryFutureExt;
 use jwt_verifier::{VerificationError, VerifiedToken};
 use runtime::{
@@ -52,7 +52,13 @@ pub trait Authorizer: Send + Sync {
 
 pub fn build_token_based_auth(verified_token: VerifiedToken, auth_config: &AuthConfig) -> ExecutionAuth {
     // Get the global level group and owner based operations that are allowed.
-    let private_public_and_group_ops = auth_config.private_public_and_group_based_ops(&verified_token.groups);
+    let private_public_and_group_ops = auth_config
+        .private_public_and_group_based_ops(&verified_token.groups)
+        .union(auth_config.claims_based_ops(&ClaimsContext {
+            claims: &verified_token.token_claims,
+            arguments: None,
+            parent: None,
+        }));
     let allowed_owner_ops = auth_config.owner_based_ops();
 
     // It's fine for ops and groups to be empty as there might
@@ -75,7 +81,13 @@ pub fn build_token_based_auth(verified_token: VerifiedToken, auth_config: &AuthC
 
 pub fn build_public_auth(auth_config: &AuthConfig) -> ExecutionAuth {
     ExecutionAuth::Public {
-        global_ops: auth_config.allowed_public_ops,
+        global_ops: auth_config
+            .allowed_public_ops
+            .union(auth_config.claims_based_ops(&ClaimsContext {
+                claims: &BTreeMap::new(),
+                arguments: None,
+                parent: None,
+            })),
     }
 }
 
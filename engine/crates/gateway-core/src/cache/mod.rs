@@ -97,6 +97,30 @@ impl<T: Cacheable> From<CacheResponse<Arc<T>>> for ExecutionResponse<Arc<T>> {
     }
 }
 
+impl<T> ExecutionResponse<T> {
+    pub(crate) fn response(&self) -> &T {
+        match self {
+            ExecutionResponse::Stale { response, .. }
+            | ExecutionResponse::Cached(response)
+            | ExecutionResponse::Origin { response, .. } => response,
+        }
+    }
+
+    pub(crate) fn cache_read_status(&self) -> Option<telemetry::metrics::CacheReadStatus> {
+        use telemetry::metrics::CacheReadStatus as Status;
+        match self {
+            ExecutionResponse::Cached(_) => Some(Status::Hit),
+            ExecutionResponse::Stale { .. } => Some(Status::Stale),
+            ExecutionResponse::Origin { cache_read, .. } => cache_read.as_ref().map(|status| match status {
+                CacheReadStatus::Hit => Status::Hit,
+                CacheReadStatus::Bypass => Status::Bypass,
+                CacheReadStatus::Miss { .. } => Status::Miss,
+                CacheReadStatus::Stale { .. } => Status::Stale,
+            }),
+        }
+    }
+}
+
 #[derive(Clone, Default)]
 pub struct CacheControl {
     /// The no-cache request directive asks caches to validate the response with the origin server before reuse.
@@ -1,15 +1,17 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
-use engine::parser::types::OperationType;
+use engine::{parser::types::OperationType, persisted_queries::PersistedQueries};
 use futures_util::FutureExt;
-use runtime::cache::Cache;
 pub use runtime::context::RequestContext;
+use runtime::{cache::Cache, rate_limiting::RateLimiter};
+use telemetry::metrics::{GraphqlOperationMetrics, OperationAttributes};
 use tracing::{info_span, Instrument};
 
 mod admin;
 mod auth;
 mod cache;
 mod executor;
+mod rate_limiting;
 mod response;
 pub mod serving;
 mod streaming;
@@ -37,6 +39,10 @@ pub struct Gateway<Executor: self::Executor, Cache> {
     cache: Arc<Cache>,
     cache_config: CacheConfig,
     authorizer: Box<dyn Authorizer<Context = Executor::Context>>,
+    persisted_queries: Option<PersistedQueries>,
+    /// With the registry weighting the fields of the operations.
+    rate_limiter: Option<(RateLimiter, Arc<engine::Registry>)>,
+    metrics: GraphqlOperationMetrics,
 }
 
 impl<Executor, Cache> Gateway<Executor, Cache>
@@ -58,9 +64,27 @@ where
             cache,
             cache_config,
             authorizer,
+            persisted_queries: None,
+            rate_limiter: None,
+            metrics: GraphqlOperationMetrics::build(),
         }
     }
 
+    #[must_use]
+    pub fn with_persisted_queries(mut self, persisted_queries: PersistedQueries) -> Self {
+        self.persisted_queries = Some(persisted_queries);
+        self
+    }
+
+    /// Applies the operation-wide rules of the rate limiter, the executor is expected to apply
+    /// the connector ones. The cost of the operations is weighted by the `@cost` of the fields of
+    /// the registry.
+    #[must_use]
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter, registry: Arc<engine::Registry>) -> Self {
+        self.rate_limiter = Some((rate_limiter, registry));
+        self
+    }
+
     pub async fn admin_execute(
         &self,
         ctx: &Arc<Executor::Context>,
@@ -90,6 +114,34 @@ where
         request: engine::Request,
         streaming_format: Option<StreamingFormat>,
     ) -> Result<Executor::Response, Executor::Error> {
+        let span = info_span!("execute_operation");
+        telemetry::propagation::set_parent(&span, |name| {
+            ctx.headers().get(name).and_then(|value| value.to_str().ok())
+        });
+        self.execute_operation(ctx, request, streaming_format)
+            .instrument(span)
+            .await
+    }
+
+    async fn execute_operation(
+        &self,
+        ctx: &Arc<Executor::Context>,
+        mut request: engine::Request,
+        streaming_format: Option<StreamingFormat>,
+    ) -> Result<Executor::Response, Executor::Error> {
+        if let Some(persisted_queries) = &self.persisted_queries {
+            if let Err(err) = persisted_queries
+                .resolve(&mut request)
+                .instrument(info_span!("resolve_persisted_query"))
+                .await
+            {
+                return Executor::Response::engine(Arc::new(engine::Response::from_errors_with_type(
+                    vec![err.into()],
+                    OperationType::Query,
+                )));
+            }
+        }
+
         let Ok(auth) = self
             .authorizer
             .authorize_request(ctx, &request)
@@ -103,29 +155,85 @@ where
             )));
         };
 
+        if let Some((rate_limiter, registry)) = &self.rate_limiter {
+            let cost = if rate_limiter.limits_cost() {
+                rate_limiting::operation_cost(registry, &request)
+            } else {
+                0
+            };
+            let rate_limit_request = rate_limiting::GatewayRateLimitRequest {
+                client_ip: ctx.client_ip(),
+                headers: ctx.headers(),
+                auth: &auth,
+                operation_name: request.operation_name.as_deref(),
+            };
+            if let Err(rejection) = rate_limiter
+                .check(None, &rate_limit_request, cost)
+                .instrument(info_span!("check_rate_limits"))
+                .await
+            {
+                return rate_limiting::rejection_response(rejection);
+            }
+        }
+
         if let Some(streaming_format) = streaming_format {
             Arc::clone(&self.executor)
                 .execute_stream(Arc::clone(ctx), auth, request, streaming_format)
                 .instrument(info_span!("execute_stream"))
                 .await
         } else {
-            cache::process_execution_response(
+            let start = web_time::Instant::now();
+            let response = cache::execute_with_cache(
+                &self.cache,
+                &self.cache_config,
                 ctx.as_ref(),
-                cache::execute_with_cache(
-                    &self.cache,
-                    &self.cache_config,
-                    ctx.as_ref(),
-                    request,
-                    auth,
-                    |request, auth| {
-                        Arc::clone(&self.executor)
-                            .execute(Arc::clone(ctx), auth, request)
-                            .instrument(info_span!("execute"))
-                            .map(move |res| res.map(Arc::new))
-                    },
-                )
-                .await,
+                request,
+                auth,
+                |request, auth| {
+                    Arc::clone(&self.executor)
+                        .execute(Arc::clone(ctx), auth, request)
+                        .instrument(info_span!("execute"))
+                        .map(move |res| res.map(Arc::new))
+                },
             )
+            .await;
+            if let Ok(response) = &response {
+                self.record_metrics(response, start.elapsed());
+            }
+            cache::process_execution_response(ctx.as_ref(), response)
         }
     }
+
+    fn record_metrics(&self, response: &cache::ExecutionResponse<Arc<engine::Response>>, duration: Duration) {
+        if let Some(status) = response.cache_read_status() {
+            self.metrics.record_cache_read(status);
+        }
+
+        let response = response.response();
+        for error in &response.errors {
+            let code = error
+                .extensions
+                .as_ref()
+                .and_then(|extensions| extensions.0.get("code"))
+                .and_then(|code| match code {
+                    engine_value::ConstValue::String(code) => Some(code.as_str()),
+                    _ => None,
+                });
+            self.metrics.record_error(code);
+        }
+
+        let operation = response.graphql_operation.as_ref();
+        self.metrics.record_operation(
+            OperationAttributes {
+                ty: operation.map(|operation| match operation.r#type {
+                    common_types::OperationType::Query { .. } => "query",
+                    common_types::OperationType::Mutation => "mutation",
+                    common_types::OperationType::Subscription => "subscription",
+                }),
+                name: operation.and_then(|operation| operation.name.as_deref()),
+                has_errors: !response.errors.is_empty(),
+            },
+            duration,
+        );
+    }
 }
@@ -0,0 +1,73 @@
+use std::{net::IpAddr, sync::Arc};
+
+use common_types::auth::ExecutionAuth;
+use engine::{operation_cost, parser::types::OperationType};
+use runtime::rate_limiting::{RateLimitRequest, RateLimited};
+
+pub(crate) struct GatewayRateLimitRequest<'a> {
+    pub client_ip: Option<IpAddr>,
+    pub headers: &'a http::HeaderMap,
+    pub auth: &'a ExecutionAuth,
+    pub operation_name: Option<&'a str>,
+}
+
+impl RateLimitRequest for GatewayRateLimitRequest<'_> {
+    fn client_ip(&self) -> Option<IpAddr> {
+        self.client_ip
+    }
+
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+
+    fn jwt_claim(&self, name: &str) -> Option<String> {
+        match self.auth {
+            ExecutionAuth::Token(token) => token.get_claim(name),
+            _ => None,
+        }
+    }
+
+    fn operation_name(&self) -> Option<&str> {
+        self.operation_name
+    }
+}
+
+/// The cost of the operation weighted by the registry, see [`engine::operation_cost`]. Queries
+/// that can't be parsed cost nothing, the engine rejects them anyway.
+pub(crate) fn operation_cost(registry: &engine::Registry, request: &engine::Request) -> u64 {
+    let Ok(document) = engine::parser::parse_query(&request.query) else {
+        return 0;
+    };
+    let operation = document
+        .operations
+        .iter()
+        .find(|(name, _)| match request.operation_name.as_deref() {
+            Some(expected) => name.is_some_and(|name| name.as_str() == expected),
+            None => true,
+        });
+    let Some((_, operation)) = operation else {
+        return 0;
+    };
+    let root_type = match operation.node.ty {
+        OperationType::Query => Some(registry.query_type.as_str()),
+        OperationType::Mutation => registry.mutation_type.as_deref(),
+        OperationType::Subscription => registry.subscription_type.as_deref(),
+    };
+    operation_cost::selection_set_cost(
+        registry,
+        root_type.unwrap_or_default(),
+        &operation.node.selection_set.node,
+        &|name| document.fragments.get(name).map(|fragment| &fragment.node),
+    )
+}
+
+/// The response of a rejected operation, with its `Retry-After` header.
+pub(crate) fn rejection_response<Response: crate::Response>(
+    rejection: RateLimited,
+) -> Result<Response, Response::Error> {
+    let mut headers = http::HeaderMap::new();
+    headers.insert(http::header::RETRY_AFTER, rejection.retry_after_secs().into());
+    let response =
+        engine::Response::from_errors_with_type(vec![rejection.into()], engine::parser::types::OperationType::Query);
+    Response::engine(Arc::new(response)).map(|response| response.with_additional_headers(headers))
+}
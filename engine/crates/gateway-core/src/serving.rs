@@ -2,6 +2,7 @@
 pub const QUERY_REQUEST_PARAMETER: &str = "query";
 pub const OPERATION_NAME_REQUEST_PARAMETER: &str = "operationName";
 pub const VARIABLES_REQUEST_PARAMETER: &str = "variables";
+pub const EXTENSIONS_REQUEST_PARAMETER: &str = "extensions";
 
 // Both auth headers were retrieved in both headers & query params.
 pub const X_API_KEY_HEADER: &str = "x-api-key";
@@ -2,14 +2,14 @@
 //! The Auth is going to be injected inside engine instead of just living as an
 //! Extension as it's adding complexity without much gain.
 //! ----------------------------------------------------------------------------
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 
 use common_types::auth::{ExecutionAuth, Operations};
 use engine::{
     extensions::{Extension, ExtensionContext, ExtensionFactory, NextResolve, ResolveInfo},
     graph_entities::ResponseNodeId,
-    registry::{relations::MetaRelation, ModelName, NamedType, Registry, TypeReference},
-    AuthConfig, ServerError, ServerResult,
+    registry::{relations::MetaRelation, resolvers::Resolver, ModelName, NamedType, Registry, TypeReference},
+    AuthConfig, ClaimsContext, ServerError, ServerResult,
 };
 use engine_value::ConstValue;
 use log::{trace, warn};
@@ -67,16 +67,51 @@ impl Extension for AuthExtension {
         let execution_auth = ctx
             .data::<ExecutionAuth>()
             .expect("auth must be injected into the context");
+        let no_claims = BTreeMap::new();
+        let claims_scope = ClaimsScope::new(
+            match execution_auth {
+                ExecutionAuth::Token(token) => token.token_claims(),
+                ExecutionAuth::ApiKey | ExecutionAuth::Public { .. } => &no_claims,
+            },
+            &info,
+            &ctx.schema_env.registry,
+        );
         let auth_fn = |auth: Option<&AuthConfig>, default_ops: Operations| {
             auth.map(|auth| match execution_auth {
                 ExecutionAuth::ApiKey => common_types::auth::API_KEY_OPS,
-                ExecutionAuth::Token(token) => auth.private_public_and_group_based_ops(token.groups_from_token()),
-                ExecutionAuth::Public { .. } => auth.allowed_public_ops,
+                ExecutionAuth::Token(token) => auth
+                    .private_public_and_group_based_ops(token.groups_from_token())
+                    .union(claims_scope.ops(auth)),
+                ExecutionAuth::Public { .. } => auth.allowed_public_ops.union(claims_scope.ops(auth)),
             })
             .unwrap_or(default_ops)
         };
         // Get the allowed operation from the parsed schema.
         let model_allowed_ops = auth_fn(info.auth, execution_auth.global_ops()); // Fall back to global auth if model auth is not configured
+
+        // Claims rules depending on the objects accessed by a root field can't be decided yet. Reads
+        // are checked on the fields of the returned objects, Postgres resolvers filter the rows they
+        // update or delete and reject the operation if a rule can't be checked by the database.
+        let model_allowed_ops = match info.auth {
+            Some(auth) if !matches!(execution_auth, ExecutionAuth::ApiKey) => {
+                let deferred_ops = claims_scope.deferred_ops(auth);
+                let resolver = ctx
+                    .schema_env
+                    .registry
+                    .types
+                    .get(info.parent_type)
+                    .and_then(|ty| ty.field_by_name(info.name))
+                    .map(|field| &field.resolver);
+                let checked_ops = match resolver {
+                    Some(Resolver::PostgresResolver(resolver)) => {
+                        Operations::READ.union(resolver.row_filtered_operations())
+                    }
+                    _ => Operations::READ,
+                };
+                model_allowed_ops.union(deferred_ops.intersection(checked_ops))
+            }
+            _ => model_allowed_ops,
+        };
         trace!(
             self.trace_id,
             "Resolving {parent_type}.{name}, auth: {auth:?} allowed ops as {model_allowed_ops:?}, required {required_op:?}",
@@ -176,6 +211,82 @@ impl Extension for AuthExtension {
     }
 }
 
+/// The values claims rules are evaluated with for the field being resolved.
+struct ClaimsScope<'a> {
+    claims: &'a BTreeMap<String, serde_json::Value>,
+    arguments: serde_json::Value,
+    /// The object the field belongs to, by the names of its fields in the schema. For creations
+    /// the created objects, from the input.
+    parents: Vec<serde_json::Value>,
+}
+
+impl<'a> ClaimsScope<'a> {
+    fn new(claims: &'a BTreeMap<String, serde_json::Value>, info: &ResolveInfo<'_>, registry: &Registry) -> Self {
+        let arguments: serde_json::Map<_, _> = info
+            .input_values
+            .iter()
+            .filter_map(|(name, value)| Some((name.node.to_string(), value.clone()?.into_json().ok()?)))
+            .collect();
+
+        let references_parent = info
+            .auth
+            .is_some_and(|auth| auth.claims_rules.iter().any(|rule| rule.expression.references_parent()));
+        let parents = match (info.parent_value, info.required_operation) {
+            _ if !references_parent => Vec::new(),
+            (_, Some(Operations::CREATE)) => match arguments.get(INPUT_ARG) {
+                Some(serde_json::Value::Array(inputs)) => inputs.clone(),
+                Some(input @ serde_json::Value::Object(_)) => vec![input.clone()],
+                _ => Vec::new(),
+            },
+            (Some(parent), _) => {
+                // Resolvers may keep the fields under their upstream names.
+                let fields = registry.types.get(info.parent_type).and_then(|ty| ty.fields());
+                let parent = fields
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|(name, field)| Some((name.clone(), parent.get(field.target_field_name())?.clone())))
+                    .collect();
+                vec![serde_json::Value::Object(parent)]
+            }
+            (None, _) => Vec::new(),
+        };
+
+        ClaimsScope {
+            claims,
+            arguments: serde_json::Value::Object(arguments),
+            parents,
+        }
+    }
+
+    fn context<'b>(&'b self, parent: Option<&'b serde_json::Value>) -> ClaimsContext<'b> {
+        ClaimsContext {
+            claims: self.claims,
+            arguments: Some(&self.arguments),
+            parent,
+        }
+    }
+
+    /// With several parents, an operation is granted only if it's granted for all of them.
+    fn ops(&self, auth: &AuthConfig) -> Operations {
+        if self.parents.is_empty() {
+            return auth.claims_based_ops(&self.context(None));
+        }
+        self.parents
+            .iter()
+            .map(|parent| auth.claims_based_ops(&self.context(Some(parent))))
+            .reduce(Operations::intersection)
+            .unwrap_or_default()
+    }
+
+    fn deferred_ops(&self, auth: &AuthConfig) -> Operations {
+        if self.parents.is_empty() {
+            auth.deferred_claims_ops(&self.context(None))
+        } else {
+            Operations::empty()
+        }
+    }
+}
+
 struct CheckInputOptions<'a, F: Fn(Option<&AuthConfig>, Operations) -> Operations> {
     input: &'a ConstValue,
     type_name: NamedType<'a>,
@@ -1,6 +1,9 @@
 use std::{collections::HashMap, sync::Arc};
 
-use engine::{registry::resolvers::graphql::QueryBatcher, Schema};
+use engine::{
+    registry::resolvers::{custom::CustomResolverBatcher, graphql::QueryBatcher, join::JoinBatcher},
+    Schema,
+};
 use futures::future::BoxFuture;
 use parser_sdl::{ConnectorParsers, GraphqlDirective, OpenApiDirective, ParseResult, PostgresDirective, Registry};
 use postgres_connector_types::transport::TcpTransport;
@@ -92,6 +95,8 @@ impl EngineBuilder {
 
         let mut schema_builder = Schema::build(registry)
             .data(QueryBatcher::new())
+            .data(JoinBatcher::new())
+            .data(CustomResolverBatcher::new())
             .data(runtime::Context::new(
                 &Arc::new(RequestContext {
                     ray_id: String::new(),
@@ -165,8 +170,14 @@ impl ConnectorParsers for EngineBuilder {
             .await
             .map_err(|error| vec![error.to_string()])?;
 
-        parser_postgres::introspect(&transport, directive.name(), directive.namespace())
-            .await
-            .map_err(|error| vec![error.to_string()])
+        parser_postgres::introspect(
+            &transport,
+            directive.name(),
+            directive.namespace(),
+            directive.row_level_security().cloned(),
+            directive.subscriptions(),
+        )
+        .await
+        .map_err(|error| vec![error.to_string()])
     }
 }
@@ -3,6 +3,7 @@ mod dynamo;
 
 use std::{collections::HashMap, future::IntoFuture, sync::Arc};
 
+use common_types::auth::ExecutionAuth;
 use engine::{Request, RequestHeaders, Response, Schema, StreamingPayload, Variables};
 use futures::{future::BoxFuture, Stream, StreamExt};
 use serde::Deserialize;
@@ -28,6 +29,7 @@ impl Engine {
         ExecutionRequest {
             graphql: operation.into(),
             headers: HashMap::new(),
+            auth: None,
             schema: &self.inner.schema,
         }
     }
@@ -45,6 +47,7 @@ impl Engine {
 pub struct ExecutionRequest<'a> {
     graphql: GraphQlRequest,
     headers: HashMap<String, String>,
+    auth: Option<ExecutionAuth>,
     schema: &'a Schema,
 }
 
@@ -55,6 +58,12 @@ impl ExecutionRequest<'_> {
         self
     }
 
+    /// Sets the authorization of the request, as if it had been verified by the gateway
+    pub fn auth(mut self, auth: ExecutionAuth) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+
     pub fn variables(mut self, variables: impl serde::Serialize) -> Self {
         self.graphql.variables = Some(Variables::from_json(
             serde_json::to_value(variables).expect("variables to be serializable"),
@@ -70,11 +79,15 @@ impl<'a> IntoFuture for ExecutionRequest<'a> {
 
     fn into_future(self) -> Self::IntoFuture {
         Box::pin(async move {
-            let request = self
+            let mut request = self
                 .graphql
                 .into_engine_request()
                 .data(RequestHeaders::from(&self.headers));
 
+            if let Some(auth) = self.auth {
+                request = request.data(auth);
+            }
+
             self.schema.execute(request).await
         })
     }
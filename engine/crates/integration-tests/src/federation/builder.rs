@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
 use async_graphql_parser::types::ServiceDocument;
 use engine_v2::Engine;
@@ -12,6 +12,7 @@ use super::TestFederationEngine;
 pub struct FederationEngineBuilder {
     schemas: Vec<(String, String, ServiceDocument)>,
     config_sdl: Option<String>,
+    entity_cache: bool,
 }
 
 pub trait EngineV2Ext {
@@ -19,6 +20,7 @@ pub trait EngineV2Ext {
         FederationEngineBuilder {
             schemas: vec![],
             config_sdl: None,
+            entity_cache: false,
         }
     }
 }
@@ -37,6 +39,11 @@ impl FederationEngineBuilder {
         self
     }
 
+    pub fn with_entity_cache(mut self) -> Self {
+        self.entity_cache = true;
+        self
+    }
+
     pub async fn with_schema(mut self, name: &str, schema: &impl SchemaSource) -> Self {
         self.schemas.push((
             name.to_string(),
@@ -73,6 +80,12 @@ impl FederationEngineBuilder {
                 config.into(),
                 engine_v2::EngineRuntime {
                     fetcher: runtime_local::NativeFetcher::runtime_fetcher(),
+                    cache: self.entity_cache.then(|| {
+                        Arc::new(runtime_local::InMemoryCache::<engine_v2::CachedEntity>::new())
+                            as engine_v2::EntityCache
+                    }),
+                    persisted_queries: None,
+                    rate_limit_store: Arc::new(runtime_local::InMemoryRateLimitStore::new()),
                 },
             ),
         }
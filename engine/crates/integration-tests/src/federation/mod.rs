@@ -54,7 +54,8 @@ impl<'a> IntoFuture for ExecutionRequest<'a> {
         let request = self.graphql.into_engine_request();
 
         Box::pin(async move {
-            GraphqlResponse(serde_json::to_value(self.engine.execute(request, (&self.headers).into()).await).unwrap())
+            let response = self.engine.execute(request, (&self.headers).into(), None).await;
+            GraphqlResponse(serde_json::to_value(response).unwrap())
         })
     }
 }
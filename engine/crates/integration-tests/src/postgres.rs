@@ -1,11 +1,15 @@
 use std::{collections::HashMap, future::Future, panic::AssertUnwindSafe, sync::Arc};
 
 use async_once_cell::OnceCell;
-use engine::Response;
-use futures::FutureExt;
+use common_types::auth::ExecutionAuth;
+use engine::{Response, StreamingPayload};
+use futures::{FutureExt, Stream, StreamExt};
 use graphql_parser::parse_schema;
 use indoc::formatdoc;
-use postgres_connector_types::transport::{TcpTransport, Transport, TransportExt};
+use postgres_connector_types::{
+    change_feed,
+    transport::{TcpTransport, Transport, TransportExt},
+};
 use serde::de::DeserializeOwned;
 
 use crate::{Engine, EngineBuilder};
@@ -40,6 +44,47 @@ where
     inner_query_postgres(test_api, &database, test)
 }
 
+/// Runs the test with row-level security enabled. The argument is the value of the
+/// `rowLevelSecurity` argument of the `@postgres` directive.
+#[track_caller]
+pub fn query_postgres_with_row_level_security<F, U>(row_level_security: &str, test: F) -> String
+where
+    F: FnOnce(TestApi) -> U,
+    U: Future<Output = Response>,
+{
+    let database = super::random_name();
+    let test_api = || async { TestApi::new_with_row_level_security(&database, row_level_security).await };
+
+    inner_query_postgres(test_api, &database, test)
+}
+
+/// Runs the test with subscriptions enabled. The engine connects to the database directly,
+/// listening to notifications doesn't work through a transaction pooler.
+#[track_caller]
+pub fn query_postgres_with_subscriptions<F, U>(test: F) -> String
+where
+    F: FnOnce(TestApi) -> U,
+    U: Future<Output = Response>,
+{
+    let database = super::random_name();
+    let test_api = || async { TestApi::new_with_subscriptions(&database).await };
+
+    inner_query_postgres(test_api, &database, test)
+}
+
+/// Runs the test with the SDL appended to the schema, e.g. to extend the introspected types.
+#[track_caller]
+pub fn query_postgres_with_schema_extension<F, U>(extension: &str, test: F) -> String
+where
+    F: FnOnce(TestApi) -> U,
+    U: Future<Output = Response>,
+{
+    let database = super::random_name();
+    let test_api = || async { TestApi::new_with_schema_extension(&database, extension).await };
+
+    inner_query_postgres(test_api, &database, test)
+}
+
 #[track_caller]
 pub fn introspect_postgres<F, U>(schema_init: F) -> String
 where
@@ -174,6 +219,64 @@ impl TestApi {
         Self::new_inner(schema, connection_string).await
     }
 
+    async fn new_with_row_level_security(database: &str, row_level_security: &str) -> Self {
+        let mut url = url::Url::parse(POOL_CONNECTION_STRING).unwrap();
+        url.set_path(&format!("/{database}"));
+
+        let connection_string = url.to_string();
+
+        let schema = formatdoc! {r#"
+            extend schema
+              @postgres(
+                name: "test",
+                url: "{connection_string}",
+                namespace: false,
+                rowLevelSecurity: {row_level_security}
+              )
+        "#};
+
+        Self::new_inner(schema, connection_string).await
+    }
+
+    async fn new_with_subscriptions(database: &str) -> Self {
+        let mut url = url::Url::parse(ADMIN_CONNECTION_STRING).unwrap();
+        url.set_path(&format!("/{database}"));
+
+        let connection_string = url.to_string();
+
+        let schema = formatdoc! {r#"
+            extend schema
+              @postgres(
+                name: "test",
+                url: "{connection_string}",
+                namespace: false,
+                subscriptions: true
+              )
+        "#};
+
+        Self::new_inner(schema, connection_string).await
+    }
+
+    async fn new_with_schema_extension(database: &str, extension: &str) -> Self {
+        let mut url = url::Url::parse(POOL_CONNECTION_STRING).unwrap();
+        url.set_path(&format!("/{database}"));
+
+        let connection_string = url.to_string();
+
+        let schema = formatdoc! {r#"
+            extend schema
+              @postgres(
+                name: "test",
+                url: "{connection_string}",
+                namespace: false
+              )
+
+            {extension}
+        "#};
+
+        Self::new_inner(schema, connection_string).await
+    }
+
     async fn new_inner(schema: String, connection_string: String) -> Self {
         let engine = OnceCell::new();
         let connection = TcpTransport::new(&connection_string).await.unwrap();
@@ -208,6 +311,50 @@ impl TestApi {
         .await
     }
 
+    pub async fn execute_with_auth(&self, operation: impl AsRef<str>, auth: ExecutionAuth) -> Response {
+        Box::pin(
+            self.inner
+                .engine
+                .get_or_init(async { Engine::new(self.inner.schema.clone()).await }),
+        )
+        .await
+        .execute(operation.as_ref())
+        .auth(auth)
+        .await
+    }
+
+    /// Installs the triggers publishing the changes of the tables, as `grafbase setup-subscriptions`
+    /// does. Run after creating the tables.
+    pub async fn setup_subscriptions(&self) {
+        let registry = parser_postgres::introspect(&self.inner.connection, "test", false, None, true)
+            .await
+            .unwrap();
+
+        change_feed::install_triggers(&self.inner.connection, &registry.postgres_databases["test"])
+            .await
+            .unwrap();
+    }
+
+    /// Starts a subscription. The subscription is set up when the stream is first polled.
+    pub async fn subscribe(&self, operation: impl AsRef<str>) -> impl Stream<Item = Response> + '_ {
+        let engine = Box::pin(
+            self.inner
+                .engine
+                .get_or_init(async { Engine::new(self.inner.schema.clone()).await }),
+        )
+        .await;
+
+        engine
+            .execute_stream(operation.as_ref())
+            .into_stream()
+            .filter_map(|payload| async move {
+                match payload {
+                    StreamingPayload::InitialResponse(initial) => Some(initial.response),
+                    StreamingPayload::Incremental(_) => None,
+                }
+            })
+    }
+
     pub async fn execute_as<T>(&self, operation: impl AsRef<str>) -> T
     where
         T: DeserializeOwned + Send,
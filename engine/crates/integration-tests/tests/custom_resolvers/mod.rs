@@ -1,3 +1,8 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
 use integration_tests::{runtime, udfs::RustUdfs, EngineBuilder, ResponseExt};
 use runtime::udf::{CustomResolverRequestPayload, CustomResolverResponse};
 use serde_json::json;
@@ -148,3 +153,85 @@ fn custom_resolver_context() {
         );
     });
 }
+
+#[test]
+fn batched_custom_resolver() {
+    runtime().block_on(async {
+        let schema = r#"
+            type Query {
+                list: [Object]! @resolver(name: "list")
+            }
+
+            type Object {
+                item: Int @resolver(name: "item", batch: true)
+            }
+        "#;
+        let invocations = Arc::new(AtomicUsize::new(0));
+        let engine = EngineBuilder::new(schema)
+            .with_custom_resolvers(
+                RustUdfs::new()
+                    .resolver(
+                        "list",
+                        CustomResolverResponse::Success(json!([{"id": 1}, {"id": 2}, {"id": 3}])),
+                    )
+                    .resolver("item", {
+                        let invocations = Arc::clone(&invocations);
+                        move |payload: CustomResolverRequestPayload| {
+                            invocations.fetch_add(1, Ordering::SeqCst);
+                            let results = payload
+                                .parent
+                                .unwrap()
+                                .as_array()
+                                .unwrap()
+                                .iter()
+                                .map(|parent| match parent["id"].as_i64().unwrap() {
+                                    2 => json!({ "error": { "message": "Not found" } }),
+                                    id => json!({ "data": id * 10 }),
+                                })
+                                .collect();
+                            Ok(CustomResolverResponse::Success(serde_json::Value::Array(results)))
+                        }
+                    }),
+            )
+            .build()
+            .await;
+
+        insta::assert_json_snapshot!(
+            engine.execute("query { list { item }}",).await.into_value(),
+            @r###"
+        {
+          "data": {
+            "list": [
+              {
+                "item": 10
+              },
+              {
+                "item": null
+              },
+              {
+                "item": 30
+              }
+            ]
+          },
+          "errors": [
+            {
+              "message": "Not found",
+              "locations": [
+                {
+                  "line": 1,
+                  "column": 16
+                }
+              ],
+              "path": [
+                "list",
+                1,
+                "item"
+              ]
+            }
+          ]
+        }
+        "###
+        );
+        assert_eq!(invocations.load(Ordering::SeqCst), 1);
+    });
+}
@@ -1,5 +1,7 @@
 //! Tests of the join directive
 
+use std::sync::{Arc, Mutex};
+
 use integration_tests::{
     mocks::graphql::FakeGithubSchema, runtime, udfs::RustUdfs, EngineBuilder, MockGraphQlServer, ResponseExt,
 };
@@ -115,3 +117,145 @@ fn join_on_connector_type() {
         );
     });
 }
+
+#[test]
+fn batched_join() {
+    runtime().block_on(async {
+        let schema = r#"
+            extend type Query {
+                posts: [Post!]! @resolver(name: "posts")
+                usersByIds(ids: [ID!]!): [User!]! @resolver(name: "usersByIds")
+            }
+
+            type Post {
+                id: ID!
+                authorId: ID!
+                author: User @join(select: "usersByIds(ids: $authorId)", batchKey: "id")
+            }
+
+            type User {
+                id: ID!
+                name: String!
+            }
+        "#;
+
+        let calls = Arc::new(Mutex::new(vec![]));
+
+        let engine = EngineBuilder::new(schema)
+            .with_custom_resolvers(
+                RustUdfs::new()
+                    .resolver(
+                        "posts",
+                        CustomResolverResponse::Success(json!([
+                            {"id": "1", "authorId": "2"},
+                            {"id": "2", "authorId": "1"},
+                            {"id": "3", "authorId": "2"},
+                            {"id": "4", "authorId": "3"}
+                        ])),
+                    )
+                    .resolver("usersByIds", {
+                        let calls = Arc::clone(&calls);
+                        move |input: CustomResolverRequestPayload| {
+                            calls.lock().unwrap().push(input.arguments["ids"].clone());
+                            Ok(CustomResolverResponse::Success(json!([
+                                {"id": "1", "name": "Alice"},
+                                {"id": "2", "name": "Bob"}
+                            ])))
+                        }
+                    }),
+            )
+            .build()
+            .await;
+
+        insta::assert_json_snapshot!(
+            engine
+                .execute("{ posts { id author { name } } }")
+                .await
+                .into_data::<Value>(),
+                @r###"
+        {
+          "posts": [
+            {
+              "id": "1",
+              "author": {
+                "name": "Bob"
+              }
+            },
+            {
+              "id": "2",
+              "author": {
+                "name": "Alice"
+              }
+            },
+            {
+              "id": "3",
+              "author": {
+                "name": "Bob"
+              }
+            },
+            {
+              "id": "4",
+              "author": null
+            }
+          ]
+        }
+        "###
+        );
+
+        assert_eq!(*calls.lock().unwrap(), vec![json!(["1", "2", "3"])]);
+    });
+}
+
+#[test]
+fn join_forwarding_arguments() {
+    runtime().block_on(async {
+        let schema = r#"
+            extend type Query {
+                greetPerson(name: String, greeting: String): String! @resolver(name: "greetPerson")
+                user: User! @resolver(name: "user")
+            }
+
+            type User {
+                id: ID!
+                name: String!
+                greeting(greeting: String): String! @join(select: "greetPerson(name: $name, greeting: $greeting)")
+            }
+        "#;
+
+        let engine = EngineBuilder::new(schema)
+            .with_custom_resolvers(
+                RustUdfs::new()
+                    .resolver(
+                        "user",
+                        CustomResolverResponse::Success(json!({"id": "123", "name": "Bob"})),
+                    )
+                    .resolver("greetPerson", |input: CustomResolverRequestPayload| {
+                        Ok(CustomResolverResponse::Success(
+                            format!(
+                                "{} {}",
+                                input.arguments["greeting"].as_str().unwrap_or("Hello"),
+                                input.arguments["name"].as_str().unwrap(),
+                            )
+                            .into(),
+                        ))
+                    }),
+            )
+            .build()
+            .await;
+
+        insta::assert_json_snapshot!(
+            engine
+                .execute(r#"{ user { default: greeting formal: greeting(greeting: "Good day") } }"#)
+                .await
+                .into_data::<Value>(),
+                @r###"
+        {
+          "user": {
+            "default": "Hello Bob",
+            "formal": "Good day Bob"
+          }
+        }
+        "###
+        );
+    });
+}
@@ -0,0 +1,151 @@
+//! Tests of the subgraph response cache, enabled with `@cacheControl(maxAge:)`.
+
+use std::sync::{Arc, Mutex};
+
+use engine_v2::Engine;
+use integration_tests::{
+    federation::{EngineV2Ext, SchemaSource},
+    mocks::graphql::{FakeFederationAccountsSchema, FakeFederationProductsSchema, FakeFederationReviewsSchema, Schema},
+    runtime, MockGraphQlServer,
+};
+
+struct CachedTopProducts<'a>(&'a MockGraphQlServer);
+
+#[async_trait::async_trait]
+impl SchemaSource for CachedTopProducts<'_> {
+    async fn sdl(&self) -> String {
+        self.0.sdl().await.replace(
+            "topProducts: [Product!]!",
+            "topProducts: [Product!]! @cacheControl(maxAge: 60)",
+        )
+    }
+
+    fn url(&self) -> String {
+        self.0.url()
+    }
+}
+
+struct CachedProductNames<'a>(&'a MockGraphQlServer);
+
+#[async_trait::async_trait]
+impl SchemaSource for CachedProductNames<'_> {
+    async fn sdl(&self) -> String {
+        self.0
+            .sdl()
+            .await
+            .replace("name: String!", "name: String! @cacheControl(maxAge: 60)")
+    }
+
+    fn url(&self) -> String {
+        self.0.url()
+    }
+}
+
+/// Keeps the variables of every request, to see which representations the subgraph received.
+struct RecordedVariables<S> {
+    schema: S,
+    variables: Arc<Mutex<Vec<serde_json::Value>>>,
+}
+
+#[async_trait::async_trait]
+impl<S: Schema> Schema for RecordedVariables<S> {
+    async fn execute(
+        &self,
+        headers: Vec<(String, String)>,
+        request: async_graphql::Request,
+    ) -> async_graphql::Response {
+        let variables = serde_json::to_value(&request.variables).unwrap();
+        self.variables.lock().unwrap().push(variables);
+        self.schema.execute(headers, request).await
+    }
+
+    fn sdl(&self) -> String {
+        self.schema.sdl()
+    }
+}
+
+#[test]
+fn root_field_is_served_from_cache() {
+    let (first, second) = runtime().block_on(async move {
+        let products = MockGraphQlServer::new(FakeFederationProductsSchema).await;
+
+        let engine = Engine::build()
+            .with_schema("products", &CachedTopProducts(&products))
+            .await
+            .with_entity_cache()
+            .finish()
+            .await;
+
+        let query = "query { topProducts { upc name } }";
+        let first = engine.execute(query).await;
+        // The subgraph isn't reachable anymore, only the cache can answer.
+        drop(products);
+        let second = engine.execute(query).await;
+        (first, second)
+    });
+
+    assert_eq!(first.into_data(), second.into_data());
+}
+
+#[test]
+fn partial_hit_fetches_only_missing_entities() {
+    let (response, variables) = runtime().block_on(async move {
+        let variables = Arc::new(Mutex::new(Vec::new()));
+        let accounts = MockGraphQlServer::new(FakeFederationAccountsSchema).await;
+        let products = MockGraphQlServer::new(RecordedVariables {
+            schema: FakeFederationProductsSchema,
+            variables: Arc::clone(&variables),
+        })
+        .await;
+        let reviews = MockGraphQlServer::new(FakeFederationReviewsSchema).await;
+
+        let engine = Engine::build()
+            .with_schema("accounts", &accounts)
+            .await
+            .with_schema("products", &CachedProductNames(&products))
+            .await
+            .with_schema("reviews", &reviews)
+            .await
+            .with_entity_cache()
+            .finish()
+            .await;
+
+        // Caches the names of the products reviewed by me: top-1 and top-2.
+        engine.execute("query { me { reviews { product { name } } } }").await;
+        variables.lock().unwrap().clear();
+
+        // The reviewed products are top-1, top-2, top-3 and top-5.
+        let response = engine
+            .execute("query { topProducts { reviews { product { name } } } }")
+            .await;
+
+        let variables = std::mem::take(&mut *variables.lock().unwrap());
+        (response, variables)
+    });
+
+    let names = response.into_data()["topProducts"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|product| product["reviews"].as_array().unwrap())
+        .map(|review| review["product"]["name"].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["Trilby", "Fedora", "Boater", "Pink Jeans"]);
+
+    // The upcs of the representations sent in each request: none for the root field, then only
+    // the products missing from the cache.
+    let requested = variables
+        .iter()
+        .map(|variables| {
+            variables
+                .as_object()
+                .unwrap()
+                .values()
+                .filter_map(serde_json::Value::as_array)
+                .flatten()
+                .map(|representation| representation["upc"].as_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(requested, vec![vec![], vec!["top-3", "top-5"]]);
+}
@@ -1,3 +1,5 @@
 mod basic;
+mod entity_cache;
 mod introspection;
+mod operation_limits;
 mod subgraphs;
@@ -0,0 +1,128 @@
+//! Tests of the `@operationLimits` directive.
+
+use engine_v2::Engine;
+use integration_tests::{
+    federation::{EngineV2Ext, GraphqlResponse},
+    mocks::graphql::FakeGithubSchema,
+    runtime, MockGraphQlServer,
+};
+
+fn execute(limits: &str, query: &str) -> GraphqlResponse {
+    let limits = format!("extend schema @operationLimits({limits})");
+    let query = query.to_string();
+    runtime().block_on(async move {
+        let github_mock = MockGraphQlServer::new(FakeGithubSchema).await;
+
+        let engine = Engine::build()
+            .with_schema("github", &github_mock)
+            .await
+            .with_supergraph_config(limits)
+            .finish()
+            .await;
+
+        engine.execute(query).await
+    })
+}
+
+#[test]
+fn depth() {
+    let query = "query { allBotPullRequests { author { ... on User { name } } } }";
+
+    let response = execute("depth: 2", query);
+    insta::assert_json_snapshot!(response, @r###"
+    {
+      "data": null,
+      "errors": [
+        {
+          "message": "Query is nested too deep."
+        }
+      ]
+    }
+    "###);
+
+    let response = execute("depth: 3", query);
+    assert!(response.errors().is_empty());
+}
+
+#[test]
+fn aliases() {
+    let response = execute("aliases: 1", "query { a: serverVersion b: serverVersion }");
+    insta::assert_json_snapshot!(response, @r###"
+    {
+      "data": null,
+      "errors": [
+        {
+          "message": "Query contains too many aliases."
+        }
+      ]
+    }
+    "###);
+}
+
+#[test]
+fn root_fields() {
+    let response = execute("rootFields: 1", "query { serverVersion ... { __typename } }");
+    insta::assert_json_snapshot!(response, @r###"
+    {
+      "data": null,
+      "errors": [
+        {
+          "message": "Query contains too many root fields."
+        }
+      ]
+    }
+    "###);
+}
+
+#[test]
+fn complexity() {
+    let query = "query { allBotPullRequests { title checks } }";
+
+    let response = execute("complexity: 2", query);
+    insta::assert_json_snapshot!(response, @r###"
+    {
+      "data": null,
+      "errors": [
+        {
+          "message": "Query is too complex."
+        }
+      ]
+    }
+    "###);
+
+    let response = execute("complexity: 3", query);
+    assert!(response.errors().is_empty());
+}
+
+#[test]
+fn tokens() {
+    let response = execute("tokens: 3", "query { serverVersion }");
+    insta::assert_json_snapshot!(response, @r###"
+    {
+      "data": null,
+      "errors": [
+        {
+          "message": "Query contains too many tokens."
+        }
+      ]
+    }
+    "###);
+}
+
+#[test]
+fn parser_recursion() {
+    let response = execute(
+        "parserRecursion: 2",
+        "query { allBotPullRequests { author { __typename } } }",
+    );
+    insta::assert_json_snapshot!(response, @r###"
+    {
+      "data": null,
+      "errors": [
+        {
+          "message": "Query is nested too deep to be parsed."
+        }
+      ]
+    }
+    "###);
+}
@@ -1,6 +1,7 @@
 use cynic::QueryBuilder;
 use cynic_introspection::IntrospectionQuery;
 use integration_tests::{mocks::graphql::FakeGithubSchema, runtime, EngineBuilder, MockGraphQlServer, ResponseExt};
+use serde_json::json;
 
 #[test]
 fn graphql_test_with_transforms() {
@@ -58,6 +59,70 @@ fn graphql_test_with_transforms() {
     });
 }
 
+#[test]
+fn graphql_test_with_renames() {
+    runtime().block_on(async {
+        let graphql_mock = MockGraphQlServer::new(FakeGithubSchema).await;
+
+        let engine = EngineBuilder::new(schema_with_renames(graphql_mock.port()))
+            .build()
+            .await;
+
+        let response = engine
+            .execute(
+                r#"
+                query {
+                    version
+                    pullRequestOrIssue(id: "1") {
+                        __typename
+                        name
+                        ... on Change {
+                            checks
+                        }
+                    }
+                }
+                "#,
+            )
+            .await
+            .into_value();
+
+        assert_eq!(
+            response,
+            json!({
+                "data": {
+                    "version": "1",
+                    "pullRequestOrIssue": {
+                        "__typename": "Change",
+                        "name": "Creating the thing",
+                        "checks": ["Success!"]
+                    }
+                }
+            })
+        );
+    });
+}
+
+fn schema_with_renames(port: u16) -> String {
+    format!(
+        r#"
+          extend schema
+          @graphql(
+            name: "test",
+            namespace: false,
+            url: "http://127.0.0.1:{port}",
+            schema: "http://127.0.0.1:{port}/spec.json",
+            transforms: {{
+              rename: [
+                {{ from: "PullRequest", to: "Change" }},
+                {{ from: "{{Change,Issue,PullRequestOrIssue}}.title", to: "name" }},
+                {{ from: "Query.serverVersion", to: "version" }}
+              ]
+            }}
+          )
+        "#
+    )
+}
+
 fn schema(port: u16) -> String {
     format!(
         r#"
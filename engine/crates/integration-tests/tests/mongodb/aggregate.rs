@@ -0,0 +1,188 @@
+use expect_test::expect;
+use indoc::indoc;
+use integration_tests::with_mongodb;
+use serde_json::json;
+
+#[test]
+fn count_and_sum() {
+    let schema = indoc! {r#"
+        type Product @model(connector: "test", collection: "products") {
+          category: String!
+          price: Int!
+        }
+    "#};
+
+    let response = with_mongodb(schema, |api| async move {
+        let documents = json!([
+            { "category": "tools", "price": 10 },
+            { "category": "tools", "price": 20 },
+            { "category": "toys", "price": 5 },
+        ]);
+
+        api.insert_many("products", documents).await;
+
+        let query = indoc! {r"
+            query {
+              productAggregate {
+                count
+                sum { price }
+              }
+            }
+        "};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "productAggregate": [
+              {
+                "count": 3,
+                "sum": {
+                  "price": 35
+                }
+              }
+            ]
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn filtered() {
+    let schema = indoc! {r#"
+        type Product @model(connector: "test", collection: "products") {
+          category: String!
+          price: Int!
+        }
+    "#};
+
+    let response = with_mongodb(schema, |api| async move {
+        let documents = json!([
+            { "category": "tools", "price": 10 },
+            { "category": "tools", "price": 20 },
+            { "category": "toys", "price": 5 },
+        ]);
+
+        api.insert_many("products", documents).await;
+
+        let query = indoc! {r#"
+            query {
+              productAggregate(filter: { category: { eq: "tools" } }) {
+                count
+                min { price }
+              }
+            }
+        "#};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "productAggregate": [
+              {
+                "count": 2,
+                "min": {
+                  "price": 10
+                }
+              }
+            ]
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn grouped() {
+    let schema = indoc! {r#"
+        type Product @model(connector: "test", collection: "products") {
+          category: String! @map(name: "cat")
+          price: Int!
+        }
+    "#};
+
+    let response = with_mongodb(schema, |api| async move {
+        let documents = json!([
+            { "cat": "tools", "price": 10 },
+            { "cat": "tools", "price": 20 },
+            { "cat": "toys", "price": 5 },
+        ]);
+
+        api.insert_many("products", documents).await;
+
+        let query = indoc! {r"
+            query {
+              productAggregate(groupBy: [category]) {
+                count
+                group { category }
+                max { price }
+              }
+            }
+        "};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "productAggregate": [
+              {
+                "count": 2,
+                "group": {
+                  "category": "tools"
+                },
+                "max": {
+                  "price": 20
+                }
+              },
+              {
+                "count": 1,
+                "group": {
+                  "category": "toys"
+                },
+                "max": {
+                  "price": 5
+                }
+              }
+            ]
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn empty_collection() {
+    let schema = indoc! {r#"
+        type Product @model(connector: "test", collection: "products") {
+          price: Int!
+        }
+    "#};
+
+    let response = with_mongodb(schema, |api| async move {
+        let query = indoc! {r"
+            query {
+              productAggregate {
+                count
+              }
+            }
+        "};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "productAggregate": []
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
@@ -0,0 +1,215 @@
+use expect_test::expect;
+use indoc::{formatdoc, indoc};
+use integration_tests::{with_mongodb_driver, GetPath};
+use serde_json::json;
+
+#[test]
+fn find_one_special_types() {
+    let schema = indoc! {r#"
+        type User @model(connector: "test", collection: "users") {
+          birthday: DateTime!
+          registered: Timestamp!
+          dec: Decimal!
+        }
+    "#};
+
+    let response = with_mongodb_driver(schema, |api| async move {
+        let document = json!({
+            "birthday": {
+                "$date": "2022-01-12T02:33:23.067+00:00",
+            },
+            "registered": {
+                "$timestamp": { "t": 1_565_545_684, "i": 1 },
+            },
+            "dec": {
+                "$numberDecimal": "1.2345"
+            }
+        });
+
+        let id = api.insert_one("users", document).await.inserted_id;
+
+        let query = formatdoc! {r#"
+            query {{
+              user(by: {{ id: "{id}" }}) {{
+                birthday
+                registered
+                dec
+              }}
+            }}
+        "#};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "user": {
+              "birthday": "2022-01-12T02:33:23.067Z",
+              "registered": 1565545684,
+              "dec": "1.2345"
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn find_many_filter_and_sort() {
+    let schema = indoc! {r#"
+        type User @model(connector: "test", collection: "users") {
+          age: Int!
+        }
+    "#};
+
+    let response = with_mongodb_driver(schema, |api| async move {
+        let documents = json!([
+            { "age": 39 },
+            { "age": 40 },
+            { "age": 38 },
+        ]);
+
+        api.insert_many("users", documents).await;
+
+        let query = indoc! {r"
+            query {
+              userCollection(
+                first: 10,
+                filter: { age: { gt: 38 } },
+                orderBy: [{ age: DESC }]
+              ) {
+                edges { node { age } }
+              }
+            }
+        "};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userCollection": {
+              "edges": [
+                {
+                  "node": {
+                    "age": 40
+                  }
+                },
+                {
+                  "node": {
+                    "age": 39
+                  }
+                }
+              ]
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn create_update_and_delete() {
+    let schema = indoc! {r#"
+        type User @model(connector: "test", collection: "users") {
+          name: String!
+        }
+    "#};
+
+    let response = with_mongodb_driver(schema, |api| async move {
+        let mutation = indoc! {r#"
+            mutation {
+              userCreate(input: { name: "Bob" }) { insertedId }
+            }
+        "#};
+
+        let result = api.execute(mutation).await;
+        let id = result.get_string("userCreate.insertedId");
+
+        let mutation = formatdoc! {r#"
+            mutation {{
+              userUpdate(
+                by: {{ id: "{id}" }},
+                input: {{ name: {{ set: "Derp" }} }}
+              ) {{
+                matchedCount
+                modifiedCount
+              }}
+            }}
+        "#};
+
+        let result = api.execute(mutation).await;
+        let expected = expect![[r#"{"data":{"userUpdate":{"matchedCount":1,"modifiedCount":1}}}"#]];
+
+        expected.assert_eq(&result.as_json_string());
+
+        let mutation = formatdoc! {r#"
+            mutation {{
+              userDelete(by: {{ id: "{id}" }}) {{
+                deletedCount
+              }}
+            }}
+        "#};
+
+        api.execute(mutation).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userDelete": {
+              "deletedCount": 1
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn aggregate() {
+    let schema = indoc! {r#"
+        type Order @model(connector: "test", collection: "orders") {
+          amount: Int!
+        }
+    "#};
+
+    let response = with_mongodb_driver(schema, |api| async move {
+        let documents = json!([
+            { "amount": 1 },
+            { "amount": 2 },
+            { "amount": 3 },
+        ]);
+
+        api.insert_many("orders", documents).await;
+
+        let query = indoc! {r"
+            query {
+              orderAggregate {
+                count
+                sum { amount }
+              }
+            }
+        "};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "orderAggregate": [
+              {
+                "count": 3,
+                "sum": {
+                  "amount": 6
+                }
+              }
+            ]
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
@@ -748,3 +748,115 @@ fn complex_array() {
 
     expected.assert_eq(&response);
 }
+
+#[test]
+fn lookup_to_one() {
+    let schema = indoc! {r#"
+        type User @model(connector: "test", collection: "users") {
+          name: String!
+        }
+
+        type Post @model(connector: "test", collection: "posts") {
+          title: String!
+          authorId: ID!
+          author: User @lookup(localField: "authorId")
+        }
+    "#};
+
+    let response = with_mongodb(schema, |api| async move {
+        let user_id = api.insert_one("users", json!({ "name": "Bob" })).await.inserted_id;
+
+        let document = json!({
+            "title": "Hello",
+            "authorId": { "$oid": user_id },
+        });
+
+        let post_id = api.insert_one("posts", document).await.inserted_id;
+
+        let query = formatdoc! {r#"
+            query {{
+              post(by: {{ id: "{post_id}" }}) {{
+                title
+                author {{ name }}
+              }}
+            }}
+        "#};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "post": {
+              "title": "Hello",
+              "author": {
+                "name": "Bob"
+              }
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn lookup_nested() {
+    let schema = indoc! {r#"
+        type User @model(connector: "test", collection: "users") {
+          name: String!
+          posts: [Post] @lookup(foreignField: "authorId")
+        }
+
+        type Post @model(connector: "test", collection: "posts") {
+          title: String!
+          authorId: ID!
+          author: User @lookup(localField: "authorId")
+        }
+    "#};
+
+    let response = with_mongodb(schema, |api| async move {
+        let user_id = api.insert_one("users", json!({ "name": "Bob" })).await.inserted_id;
+
+        let documents = json!([
+            { "title": "Hello", "authorId": { "$oid": user_id } },
+            { "title": "World", "authorId": { "$oid": user_id } },
+        ]);
+
+        let post_ids = api.insert_many("posts", documents).await.inserted_ids;
+
+        let query = formatdoc! {r#"
+            query {{
+              post(by: {{ id: "{}" }}) {{
+                author {{
+                  name
+                  posts {{ title }}
+                }}
+              }}
+            }}
+        "#, post_ids[0]};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "post": {
+              "author": {
+                "name": "Bob",
+                "posts": [
+                  {
+                    "title": "Hello"
+                  },
+                  {
+                    "title": "World"
+                  }
+                ]
+              }
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
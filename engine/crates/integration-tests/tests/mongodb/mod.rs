@@ -1,7 +1,9 @@
+mod aggregate;
 mod create_many;
 mod create_one;
 mod delete_many;
 mod delete_one;
+mod driver;
 mod find_many;
 mod find_one;
 mod update_many;
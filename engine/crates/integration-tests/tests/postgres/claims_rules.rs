@@ -0,0 +1,129 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use common_types::auth::{ExecutionAuth, Operations};
+use expect_test::expect;
+use indoc::indoc;
+use integration_tests::postgres::{query_postgres_with_schema_extension, TestApi};
+use serde_json::json;
+
+// The tenant rule compares a claim with a column, and is added to the WHERE clause of the
+// statements. A value can't be looked up from an array column, so the reader rule can't.
+const ACCESS_RULES: &str = indoc! {r#"
+    extend type Message @auth(rules: [
+      { allow: private, operations: [read] },
+      { allow: claims, expr: "tenant_id == $parent.tenantId", operations: [update] },
+      { allow: claims, expr: "sub in $parent.readerIds", operations: [delete] }
+    ])
+"#};
+
+#[derive(Debug, PartialEq, serde::Deserialize)]
+struct Message {
+    id: i32,
+    body: String,
+}
+
+fn token(claims: serde_json::Value) -> ExecutionAuth {
+    let claims: BTreeMap<String, serde_json::Value> = serde_json::from_value(claims).unwrap();
+    ExecutionAuth::new_from_token(Operations::all(), BTreeSet::new(), None, claims)
+}
+
+async fn init(api: &TestApi) {
+    let schema = indoc! {r#"
+        CREATE TABLE "Message" (
+            id INT PRIMARY KEY,
+            tenant_id INT NOT NULL,
+            reader_ids TEXT[] NOT NULL,
+            body TEXT NOT NULL
+        )
+    "#};
+
+    api.execute_sql(schema).await;
+
+    let insert = indoc! {r#"
+        INSERT INTO "Message" (id, tenant_id, reader_ids, body) VALUES
+            (1, 1, '{musti}', 'hello'),
+            (2, 1, '{naukio}', 'world'),
+            (3, 2, '{musti}', 'secret')
+    "#};
+
+    api.execute_sql(insert).await;
+}
+
+async fn messages(api: &TestApi) -> Vec<Message> {
+    api.query_sql(r#"SELECT id, body FROM "Message" ORDER BY id"#).await
+}
+
+#[test]
+fn update_filtered_by_rule() {
+    let response = query_postgres_with_schema_extension(ACCESS_RULES, |api| async move {
+        init(&api).await;
+
+        let mutation = indoc! {r#"
+            mutation {
+              messageUpdateMany(filter: { id: { gt: 1 } }, input: { body: { set: "edited" } }) { rowCount }
+            }
+        "#};
+
+        let response = api
+            .execute_with_auth(mutation, token(json!({ "sub": "musti", "tenant_id": 1 })))
+            .await;
+
+        let expected = vec![
+            Message {
+                id: 1,
+                body: String::from("hello"),
+            },
+            Message {
+                id: 2,
+                body: String::from("edited"),
+            },
+            Message {
+                id: 3,
+                body: String::from("secret"),
+            },
+        ];
+
+        assert_eq!(expected, messages(&api).await);
+
+        response
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "messageUpdateMany": {
+              "rowCount": 1
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn delete_with_rule_not_checked_by_database() {
+    let response = query_postgres_with_schema_extension(ACCESS_RULES, |api| async move {
+        init(&api).await;
+
+        let mutation = indoc! {r"
+            mutation {
+              messageDeleteMany(filter: { id: { lt: 3 } }) { rowCount }
+            }
+        "};
+
+        let response = api
+            .execute_with_auth(mutation, token(json!({ "sub": "musti", "tenant_id": 1 })))
+            .await;
+
+        assert_eq!(3, api.row_count("Message").await);
+
+        response
+    });
+
+    let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+
+    assert_eq!(
+        json!("Unauthorized to delete Message, the access rules cannot be checked by the database"),
+        response["errors"][0]["message"]
+    );
+}
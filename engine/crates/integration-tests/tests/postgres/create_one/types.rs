@@ -2660,3 +2660,308 @@ fn enum_array() {
 
     expected.assert_eq(&response);
 }
+
+#[test]
+fn composite() {
+    let response = query_postgres(|api| async move {
+        let r#type = indoc! {r"
+            CREATE TYPE address AS (street TEXT, zip INT);
+        "};
+
+        api.execute_sql(r#type).await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE "User" (
+                id SERIAL PRIMARY KEY,
+                val address NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let mutation = indoc! {r#"
+            mutation {
+              userCreate(input: { val: { street: "Main \"Street\"", zip: 12345 } }) {
+                returning { val { street zip } }
+              }
+            }
+        "#};
+
+        let result = api.execute(mutation).await;
+
+        assert_eq!(1, api.row_count("User").await);
+
+        result
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userCreate": {
+              "returning": {
+                "val": {
+                  "street": "Main \"Street\"",
+                  "zip": 12345
+                }
+              }
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn int4range() {
+    let response = query_postgres(|api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "User" (
+                id SERIAL PRIMARY KEY,
+                val INT4RANGE NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let mutation = indoc! {r"
+            mutation {
+              userCreate(input: { val: { start: 1, end: 10, endInclusive: true } }) {
+                returning { val { start end startInclusive endInclusive } }
+              }
+            }
+        "};
+
+        let result = api.execute(mutation).await;
+
+        assert_eq!(1, api.row_count("User").await);
+
+        result
+    });
+
+    // integer ranges are normalized to an exclusive upper bound
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userCreate": {
+              "returning": {
+                "val": {
+                  "start": 1,
+                  "end": 11,
+                  "startInclusive": true,
+                  "endInclusive": false
+                }
+              }
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn ltree() {
+    let response = query_postgres(|api| async move {
+        api.execute_sql("CREATE EXTENSION IF NOT EXISTS ltree").await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE "User" (
+                id SERIAL PRIMARY KEY,
+                val LTREE NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let mutation = indoc! {r#"
+            mutation {
+              userCreate(input: { val: "Top.Science.Astronomy" }) {
+                returning { val }
+              }
+            }
+        "#};
+
+        let result = api.execute(mutation).await;
+
+        assert_eq!(1, api.row_count("User").await);
+
+        result
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userCreate": {
+              "returning": {
+                "val": "Top.Science.Astronomy"
+              }
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn ltree_array() {
+    let response = query_postgres(|api| async move {
+        api.execute_sql("CREATE EXTENSION IF NOT EXISTS ltree").await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE "User" (
+                id SERIAL PRIMARY KEY,
+                val LTREE[] NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let mutation = indoc! {r#"
+            mutation {
+              userCreate(input: { val: ["Top.Science", "Top.Hobbies.Amateurs_Astronomy"] }) {
+                returning { val }
+              }
+            }
+        "#};
+
+        let result = api.execute(mutation).await;
+
+        assert_eq!(1, api.row_count("User").await);
+
+        result
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userCreate": {
+              "returning": {
+                "val": [
+                  "Top.Science",
+                  "Top.Hobbies.Amateurs_Astronomy"
+                ]
+              }
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn geometry() {
+    let response = query_postgres(|api| async move {
+        api.execute_sql("CREATE EXTENSION IF NOT EXISTS postgis").await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE "User" (
+                id SERIAL PRIMARY KEY,
+                val GEOMETRY NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let mutation = indoc! {r#"
+            mutation {
+              userCreate(input: { val: { type: "Point", coordinates: [24.94, 60.17] } }) {
+                returning { val }
+              }
+            }
+        "#};
+
+        let result = api.execute(mutation).await;
+
+        assert_eq!(1, api.row_count("User").await);
+
+        result
+    });
+
+    // geometries are written and read as GeoJSON
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userCreate": {
+              "returning": {
+                "val": {
+                  "type": "Point",
+                  "coordinates": [
+                    24.94,
+                    60.17
+                  ]
+                }
+              }
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn geometry_array() {
+    let response = query_postgres(|api| async move {
+        api.execute_sql("CREATE EXTENSION IF NOT EXISTS postgis").await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE "User" (
+                id SERIAL PRIMARY KEY,
+                val GEOMETRY[] NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let mutation = indoc! {r#"
+            mutation {
+              userCreate(input: {
+                val: [
+                  { type: "Point", coordinates: [24.94, 60.17] },
+                  { type: "LineString", coordinates: [[0, 0], [1, 1]] }
+                ]
+              }) {
+                returning { val }
+              }
+            }
+        "#};
+
+        let result = api.execute(mutation).await;
+
+        assert_eq!(1, api.row_count("User").await);
+
+        result
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userCreate": {
+              "returning": {
+                "val": [
+                  {
+                    "type": "Point",
+                    "coordinates": [
+                      24.94,
+                      60.17
+                    ]
+                  },
+                  {
+                    "type": "LineString",
+                    "coordinates": [
+                      [
+                        0,
+                        0
+                      ],
+                      [
+                        1,
+                        1
+                      ]
+                    ]
+                  }
+                ]
+              }
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
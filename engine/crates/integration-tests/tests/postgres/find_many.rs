@@ -1541,3 +1541,449 @@ fn one_to_many_relation_filter_parent_side() {
 
     expected.assert_eq(&response);
 }
+
+#[test]
+fn string_like() {
+    let response = query_postgres(|api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "User" (
+                id INT PRIMARY KEY,
+                name VARCHAR(255) NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "User" (id, name) VALUES (1, 'Musti'), (2, 'Naukio'), (3, 'musti')
+        "#};
+
+        api.execute_sql(insert).await;
+
+        let query = indoc! {r#"
+            query {
+              userCollection(first: 10, filter: { name: { like: "Mus%" } }) {
+                edges { node { id name } }
+              }
+            }
+        "#};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userCollection": {
+              "edges": [
+                {
+                  "node": {
+                    "id": 1,
+                    "name": "Musti"
+                  }
+                }
+              ]
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn string_ilike() {
+    let response = query_postgres(|api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "User" (
+                id INT PRIMARY KEY,
+                name VARCHAR(255) NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "User" (id, name) VALUES (1, 'Musti'), (2, 'Naukio'), (3, 'musti')
+        "#};
+
+        api.execute_sql(insert).await;
+
+        let query = indoc! {r#"
+            query {
+              userCollection(first: 10, filter: { name: { ilike: "mus%" } }) {
+                edges { node { id name } }
+              }
+            }
+        "#};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userCollection": {
+              "edges": [
+                {
+                  "node": {
+                    "id": 1,
+                    "name": "Musti"
+                  }
+                },
+                {
+                  "node": {
+                    "id": 3,
+                    "name": "musti"
+                  }
+                }
+              ]
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn string_regex() {
+    let response = query_postgres(|api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "User" (
+                id INT PRIMARY KEY,
+                name VARCHAR(255) NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "User" (id, name) VALUES (1, 'Musti'), (2, 'Naukio'), (3, 'musti')
+        "#};
+
+        api.execute_sql(insert).await;
+
+        let query = indoc! {r#"
+            query {
+              userCollection(first: 10, filter: { name: { regex: "^[a-z]+$" } }) {
+                edges { node { id name } }
+              }
+            }
+        "#};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userCollection": {
+              "edges": [
+                {
+                  "node": {
+                    "id": 3,
+                    "name": "musti"
+                  }
+                }
+              ]
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn jsonb_contains() {
+    let response = query_postgres(|api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "User" (
+                id INT PRIMARY KEY,
+                data JSONB NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "User" (id, data) VALUES (1, '{"name": "Musti", "tags": ["cat"]}'), (2, '{"name": "Naukio", "tags": ["dog"]}')
+        "#};
+
+        api.execute_sql(insert).await;
+
+        let query = indoc! {r#"
+            query {
+              userCollection(first: 10, filter: { data: { contains: { tags: ["cat"] } } }) {
+                edges { node { id } }
+              }
+            }
+        "#};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userCollection": {
+              "edges": [
+                {
+                  "node": {
+                    "id": 1
+                  }
+                }
+              ]
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn jsonb_path() {
+    let response = query_postgres(|api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "User" (
+                id INT PRIMARY KEY,
+                data JSONB NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "User" (id, data) VALUES (1, '{"age": 11}'), (2, '{"age": 3}')
+        "#};
+
+        api.execute_sql(insert).await;
+
+        let query = indoc! {r#"
+            query {
+              userCollection(first: 10, filter: { data: { path: "$.age ? (@ > 10)" } }) {
+                edges { node { id } }
+              }
+            }
+        "#};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userCollection": {
+              "edges": [
+                {
+                  "node": {
+                    "id": 1
+                  }
+                }
+              ]
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn text_search_matches() {
+    let response = query_postgres(|api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "Article" (
+                id INT PRIMARY KEY,
+                document TSVECTOR NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "Article" (id, document) VALUES
+                (1, to_tsvector('english', 'Cats are sleeping on the sofa')),
+                (2, to_tsvector('english', 'Dogs are running in the park'))
+        "#};
+
+        api.execute_sql(insert).await;
+
+        let query = indoc! {r#"
+            query {
+              articleCollection(first: 10, filter: { document: { matches: { query: "sleeping cat", language: "english" } } }) {
+                edges { node { id } }
+              }
+            }
+        "#};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "articleCollection": {
+              "edges": [
+                {
+                  "node": {
+                    "id": 1
+                  }
+                }
+              ]
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn text_search_rank_order() {
+    let response = query_postgres(|api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "Article" (
+                id INT PRIMARY KEY,
+                document TSVECTOR NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "Article" (id, document) VALUES
+                (1, to_tsvector('english', 'A cat')),
+                (2, to_tsvector('english', 'A cat and another cat and a third cat')),
+                (3, to_tsvector('english', 'A dog'))
+        "#};
+
+        api.execute_sql(insert).await;
+
+        let query = indoc! {r#"
+            query {
+              articleCollection(
+                first: 10,
+                filter: { document: { matches: { query: "cat", language: "english" } } },
+                orderBy: [{ document: { query: "cat", language: "english" } }]
+              ) {
+                edges { node { id } }
+              }
+            }
+        "#};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "articleCollection": {
+              "edges": [
+                {
+                  "node": {
+                    "id": 2
+                  }
+                },
+                {
+                  "node": {
+                    "id": 1
+                  }
+                }
+              ]
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn range_overlaps() {
+    let response = query_postgres(|api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "Booking" (
+                id INT PRIMARY KEY,
+                during INT4RANGE NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "Booking" (id, during) VALUES (1, '[1,5)'), (2, '[5,10)')
+        "#};
+
+        api.execute_sql(insert).await;
+
+        let query = indoc! {r"
+            query {
+              bookingCollection(first: 10, filter: { during: { overlaps: { start: 3, end: 5 } } }) {
+                edges { node { id } }
+              }
+            }
+        "};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "bookingCollection": {
+              "edges": [
+                {
+                  "node": {
+                    "id": 1
+                  }
+                }
+              ]
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn range_contains() {
+    let response = query_postgres(|api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "Booking" (
+                id INT PRIMARY KEY,
+                during DATERANGE NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "Booking" (id, during) VALUES (1, '[2020-01-01,2020-02-01)'), (2, '[2020-02-01,2020-03-01)')
+        "#};
+
+        api.execute_sql(insert).await;
+
+        let query = indoc! {r#"
+            query {
+              bookingCollection(first: 10, filter: { during: { contains: "2020-02-15" } }) {
+                edges { node { id } }
+              }
+            }
+        "#};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "bookingCollection": {
+              "edges": [
+                {
+                  "node": {
+                    "id": 2
+                  }
+                }
+              ]
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
@@ -2598,3 +2598,272 @@ fn enum_array() {
 
     expected.assert_eq(&response);
 }
+
+#[test]
+fn interval() {
+    let response = query_postgres(|api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "A" (
+                id INT PRIMARY KEY,
+                val INTERVAL NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "A" (id, val) VALUES (1, '1 day 2 hours')
+        "#};
+
+        api.execute_sql(insert).await;
+
+        let query = indoc! {r"
+            query {
+              a(by: { id: 1 }) { id val }
+            }
+        "};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "a": {
+              "id": 1,
+              "val": "1 day 02:00:00"
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn citext() {
+    let response = query_postgres(|api| async move {
+        api.execute_sql("CREATE EXTENSION IF NOT EXISTS citext").await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE "A" (
+                id INT PRIMARY KEY,
+                val CITEXT NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "A" (id, val) VALUES (1, 'Musti')
+        "#};
+
+        api.execute_sql(insert).await;
+
+        let query = indoc! {r"
+            query {
+              a(by: { id: 1 }) { id val }
+            }
+        "};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "a": {
+              "id": 1,
+              "val": "Musti"
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn domain() {
+    let response = query_postgres(|api| async move {
+        let domain = indoc! {r"
+            CREATE DOMAIN positive_int AS INT CHECK (VALUE > 0);
+        "};
+
+        api.execute_sql(domain).await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE "A" (
+                id INT PRIMARY KEY,
+                val positive_int NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "A" (id, val) VALUES (1, 5)
+        "#};
+
+        api.execute_sql(insert).await;
+
+        let query = indoc! {r"
+            query {
+              a(by: { id: 1 }) { id val }
+            }
+        "};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "a": {
+              "id": 1,
+              "val": 5
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn composite() {
+    let response = query_postgres(|api| async move {
+        let r#type = indoc! {r"
+            CREATE TYPE address AS (street TEXT, zip INT);
+        "};
+
+        api.execute_sql(r#type).await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE "A" (
+                id INT PRIMARY KEY,
+                val address NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "A" (id, val) VALUES (1, ROW('Main Street', 12345))
+        "#};
+
+        api.execute_sql(insert).await;
+
+        let query = indoc! {r"
+            query {
+              a(by: { id: 1 }) { id val { street zip } }
+            }
+        "};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "a": {
+              "id": 1,
+              "val": {
+                "street": "Main Street",
+                "zip": 12345
+              }
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn int4range() {
+    let response = query_postgres(|api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "A" (
+                id INT PRIMARY KEY,
+                val INT4RANGE NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "A" (id, val) VALUES (1, '[1,10)')
+        "#};
+
+        api.execute_sql(insert).await;
+
+        let query = indoc! {r"
+            query {
+              a(by: { id: 1 }) { id val { start end startInclusive endInclusive isEmpty } }
+            }
+        "};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "a": {
+              "id": 1,
+              "val": {
+                "start": 1,
+                "end": 10,
+                "startInclusive": true,
+                "endInclusive": false,
+                "isEmpty": false
+              }
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn daterange_unbounded() {
+    let response = query_postgres(|api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "A" (
+                id INT PRIMARY KEY,
+                val DATERANGE NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "A" (id, val) VALUES (1, '[2020-01-01,)')
+        "#};
+
+        api.execute_sql(insert).await;
+
+        let query = indoc! {r"
+            query {
+              a(by: { id: 1 }) { id val { start end startInclusive endInclusive isEmpty } }
+            }
+        "};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "a": {
+              "id": 1,
+              "val": {
+                "start": "2020-01-01",
+                "end": null,
+                "startInclusive": true,
+                "endInclusive": false,
+                "isEmpty": false
+              }
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
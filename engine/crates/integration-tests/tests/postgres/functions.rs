@@ -0,0 +1,207 @@
+use expect_test::expect;
+use indoc::indoc;
+use integration_tests::postgres::query_postgres;
+
+#[test]
+fn stable_scalar_function() {
+    let response = query_postgres(|api| async move {
+        let function = indoc! {r#"
+            CREATE FUNCTION add_numbers(a INT, b INT) RETURNS INT AS $$
+                SELECT a + b
+            $$ LANGUAGE SQL IMMUTABLE
+        "#};
+
+        api.execute_sql(function).await;
+
+        let query = indoc! {r"
+            query {
+              addNumbers(a: 1, b: 2)
+            }
+        "};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "addNumbers": 3
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn scalar_set_function() {
+    let response = query_postgres(|api| async move {
+        let function = indoc! {r#"
+            CREATE FUNCTION numbers(up_to INT) RETURNS SETOF INT AS $$
+                SELECT generate_series(1, up_to)
+            $$ LANGUAGE SQL STABLE
+        "#};
+
+        api.execute_sql(function).await;
+
+        let query = indoc! {r"
+            query {
+              numbers(upTo: 3)
+            }
+        "};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "numbers": [
+              1,
+              2,
+              3
+            ]
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn default_arguments() {
+    let response = query_postgres(|api| async move {
+        let function = indoc! {r#"
+            CREATE FUNCTION greet(name TEXT, greeting TEXT DEFAULT 'Hello', punctuation TEXT DEFAULT '!') RETURNS TEXT AS $$
+                SELECT greeting || ', ' || name || punctuation
+            $$ LANGUAGE SQL IMMUTABLE
+        "#};
+
+        api.execute_sql(function).await;
+
+        let query = indoc! {r#"
+            query {
+              greet(name: "Musti", punctuation: ".")
+            }
+        "#};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "greet": "Hello, Musti."
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn table_set_function_with_filter_and_pagination() {
+    let response = query_postgres(|api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "User" (
+                id INT PRIMARY KEY,
+                name VARCHAR(255) NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let function = indoc! {r#"
+            CREATE FUNCTION users_above(min_id INT) RETURNS SETOF "User" AS $$
+                SELECT * FROM "User" WHERE id > min_id
+            $$ LANGUAGE SQL STABLE
+        "#};
+
+        api.execute_sql(function).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "User" (id, name) VALUES (1, 'Musti'), (2, 'Naukio'), (3, 'Pertti'), (4, 'Kalle')
+        "#};
+
+        api.execute_sql(insert).await;
+
+        let query = indoc! {r#"
+            query {
+              usersAbove(minId: 1, filter: { name: { ne: "Pertti" } }, first: 10, orderBy: [{ id: DESC }]) {
+                edges { node { id name } }
+              }
+            }
+        "#};
+
+        api.execute(query).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "usersAbove": {
+              "edges": [
+                {
+                  "node": {
+                    "id": 4,
+                    "name": "Kalle"
+                  }
+                },
+                {
+                  "node": {
+                    "id": 2,
+                    "name": "Naukio"
+                  }
+                }
+              ]
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn volatile_function_as_mutation() {
+    let response = query_postgres(|api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "User" (
+                id INT PRIMARY KEY,
+                name VARCHAR(255) NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let function = indoc! {r#"
+            CREATE FUNCTION rename_user(user_id INT, new_name TEXT) RETURNS "User" AS $$
+                UPDATE "User" SET name = new_name WHERE id = user_id RETURNING *
+            $$ LANGUAGE SQL VOLATILE
+        "#};
+
+        api.execute_sql(function).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "User" (id, name) VALUES (1, 'Musti')
+        "#};
+
+        api.execute_sql(insert).await;
+
+        let mutation = indoc! {r#"
+            mutation {
+              renameUser(userId: 1, newName: "Naukio") { id name }
+            }
+        "#};
+
+        api.execute(mutation).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "renameUser": {
+              "id": 1,
+              "name": "Naukio"
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
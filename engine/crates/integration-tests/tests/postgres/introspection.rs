@@ -1206,6 +1206,18 @@ fn table_with_serial_primary_key_string_unique() {
           """
             The value is not in the given array of values
           """ nin: [String]
+          """
+            The value matches the given LIKE pattern, where % matches any sequence of characters and _ any single character
+          """ like: String
+          """
+            The value matches the given LIKE pattern, ignoring case
+          """ ilike: String
+          """
+            The value matches the given POSIX regular expression
+          """ regex: String
+          """
+            The value matches the given POSIX regular expression, ignoring case
+          """ iregex: String
           not: StringSearchFilterInput
         }
 
@@ -1454,6 +1466,18 @@ fn table_with_composite_primary_key() {
           """
             The value is not in the given array of values
           """ nin: [String]
+          """
+            The value matches the given LIKE pattern, where % matches any sequence of characters and _ any single character
+          """ like: String
+          """
+            The value matches the given LIKE pattern, ignoring case
+          """ ilike: String
+          """
+            The value matches the given POSIX regular expression
+          """ regex: String
+          """
+            The value matches the given POSIX regular expression, ignoring case
+          """ iregex: String
           not: StringSearchFilterInput
         }
 
@@ -2725,6 +2749,12 @@ fn table_with_jsonb_column() {
           """
             The value is not in the given array of values
           """ nin: [JSON]
+          """
+            The value contains the given JSON value at the top level.
+          """ contains: JSON
+          """
+            The given SQL/JSON path expression returns at least one item for the value.
+          """ path: String
           not: JsonSearchFilterInput
         }
 
@@ -2991,37 +3021,6 @@ fn table_with_json_column() {
         """
         scalar JSON
 
-        """
-          Search filter input for JSON type.
-        """
-        input JsonSearchFilterInput {
-          """
-            The value is exactly the one given
-          """ eq: JSON
-          """
-            The value is not the one given
-          """ ne: JSON
-          """
-            The value is greater than the one given
-          """ gt: JSON
-          """
-            The value is less than the one given
-          """ lt: JSON
-          """
-            The value is greater than, or equal to the one given
-          """ gte: JSON
-          """
-            The value is less than, or equal to the one given
-          """ lte: JSON
-          """
-            The value is in the given array of values
-          """ in: [JSON]
-          """
-            The value is not in the given array of values
-          """ nin: [JSON]
-          not: JsonSearchFilterInput
-        }
-
         type Mutation {
           """
             Delete a unique User by a field or combination of fields
@@ -3081,6 +3080,37 @@ fn table_with_json_column() {
           """ set: SimpleJSON
         }
 
+        """
+          Search filter input for JSON type.
+        """
+        input SimpleJsonSearchFilterInput {
+          """
+            The value is exactly the one given
+          """ eq: JSON
+          """
+            The value is not the one given
+          """ ne: JSON
+          """
+            The value is greater than the one given
+          """ gt: JSON
+          """
+            The value is less than the one given
+          """ lt: JSON
+          """
+            The value is greater than, or equal to the one given
+          """ gte: JSON
+          """
+            The value is less than, or equal to the one given
+          """ lte: JSON
+          """
+            The value is in the given array of values
+          """ in: [JSON]
+          """
+            The value is not in the given array of values
+          """ nin: [JSON]
+          not: SimpleJsonSearchFilterInput
+        }
+
         type User {
           id: Int!
           name: JSON!
@@ -3092,7 +3122,7 @@ fn table_with_json_column() {
 
         input UserCollection {
           id: IntSearchFilterInput
-          name: JsonSearchFilterInput
+          name: SimpleJsonSearchFilterInput
           """
             All of the filters must match
           """ ALL: [UserCollection]
@@ -3165,7 +3195,7 @@ fn table_with_json_column() {
 
         input UserMutationCollection {
           id: IntSearchFilterInput
-          name: JsonSearchFilterInput
+          name: SimpleJsonSearchFilterInput
           """
             All of the filters must match
           """ ALL: [UserMutationCollection]
@@ -3564,6 +3594,18 @@ fn two_tables_with_single_column_foreign_key() {
           """
             The value is not in the given array of values
           """ nin: [String]
+          """
+            The value matches the given LIKE pattern, where % matches any sequence of characters and _ any single character
+          """ like: String
+          """
+            The value matches the given LIKE pattern, ignoring case
+          """ ilike: String
+          """
+            The value matches the given POSIX regular expression
+          """ regex: String
+          """
+            The value matches the given POSIX regular expression, ignoring case
+          """ iregex: String
           not: StringSearchFilterInput
         }
 
@@ -3813,6 +3855,18 @@ fn foreign_key_to_a_table_without_a_key_should_not_create_a_relation() {
           """
             The value is not in the given array of values
           """ nin: [String]
+          """
+            The value matches the given LIKE pattern, where % matches any sequence of characters and _ any single character
+          """ like: String
+          """
+            The value matches the given LIKE pattern, ignoring case
+          """ ilike: String
+          """
+            The value matches the given POSIX regular expression
+          """ regex: String
+          """
+            The value matches the given POSIX regular expression, ignoring case
+          """ iregex: String
           not: PgStringSearchFilterInput
         }
 
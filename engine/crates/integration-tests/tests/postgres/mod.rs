@@ -1,9 +1,13 @@
+mod claims_rules;
 mod create_many;
 mod create_one;
 mod delete_many;
 mod delete_one;
 mod find_many;
 mod find_one;
+mod functions;
 mod introspection;
+mod row_level_security;
+mod subscriptions;
 mod update_many;
 mod update_one;
@@ -0,0 +1,311 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use common_types::auth::{ExecutionAuth, Operations};
+use expect_test::expect;
+use indoc::{formatdoc, indoc};
+use integration_tests::postgres::{query_postgres, query_postgres_with_row_level_security, TestApi};
+use serde_json::json;
+
+const ROW_LEVEL_SECURITY: &str = indoc! {r#"
+    {
+      roleClaim: "role",
+      defaultRole: "rls_anonymous",
+      claimsSetting: "request.jwt.claims",
+      settings: [{ name: "app.tenant_id", claim: "tenant.id" }]
+    }
+"#};
+
+fn token(claims: serde_json::Value) -> ExecutionAuth {
+    let claims: BTreeMap<String, serde_json::Value> = serde_json::from_value(claims).unwrap();
+    ExecutionAuth::new_from_token(Operations::all(), BTreeSet::new(), None, claims)
+}
+
+async fn init(api: &TestApi) {
+    // roles are shared by all the databases in the cluster
+    for role in ["rls_user", "rls_anonymous"] {
+        let create_role = formatdoc! {r#"
+            DO $$
+            BEGIN
+                CREATE ROLE {role};
+            EXCEPTION WHEN duplicate_object THEN NULL;
+            END $$
+        "#};
+
+        api.execute_sql(&create_role).await;
+    }
+
+    let schema = indoc! {r#"
+        CREATE TABLE "Message" (
+            id INT PRIMARY KEY,
+            tenant_id INT NOT NULL,
+            body TEXT NOT NULL
+        )
+    "#};
+
+    api.execute_sql(schema).await;
+
+    api.execute_sql(r#"ALTER TABLE "Message" ENABLE ROW LEVEL SECURITY"#)
+        .await;
+    api.execute_sql(r#"GRANT SELECT ON "Message" TO rls_user, rls_anonymous"#)
+        .await;
+
+    let tenant_policy = indoc! {r#"
+        CREATE POLICY tenant_messages ON "Message" TO rls_user
+            USING (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::int)
+    "#};
+
+    api.execute_sql(tenant_policy).await;
+
+    let subject_policy = indoc! {r#"
+        CREATE POLICY own_messages ON "Message" TO rls_user
+            USING (body LIKE '%' || (current_setting('request.jwt.claims', true)::jsonb ->> 'sub') || '%')
+    "#};
+
+    api.execute_sql(subject_policy).await;
+
+    let insert = indoc! {r#"
+        INSERT INTO "Message" (id, tenant_id, body) VALUES
+            (1, 1, 'hello'),
+            (2, 1, 'world'),
+            (3, 2, 'secret'),
+            (4, 2, 'for musti')
+    "#};
+
+    api.execute_sql(insert).await;
+}
+
+const QUERY: &str = indoc! {r"
+    query {
+      messageCollection(first: 10, orderBy: [{ id: ASC }]) { edges { node { id body } } }
+    }
+"};
+
+#[test]
+fn claims_are_passed_to_policies() {
+    let response = query_postgres_with_row_level_security(ROW_LEVEL_SECURITY, |api| async move {
+        init(&api).await;
+
+        let auth = token(json!({ "sub": "musti", "role": "rls_user", "tenant": { "id": 1 } }));
+        api.execute_with_auth(QUERY, auth).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "messageCollection": {
+              "edges": [
+                {
+                  "node": {
+                    "id": 1,
+                    "body": "hello"
+                  }
+                },
+                {
+                  "node": {
+                    "id": 2,
+                    "body": "world"
+                  }
+                },
+                {
+                  "node": {
+                    "id": 4,
+                    "body": "for musti"
+                  }
+                }
+              ]
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn requests_without_a_token_use_the_default_role() {
+    let response = query_postgres_with_row_level_security(ROW_LEVEL_SECURITY, |api| async move {
+        init(&api).await;
+
+        api.execute_with_auth(
+            QUERY,
+            ExecutionAuth::Public {
+                global_ops: Operations::all(),
+            },
+        )
+        .await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "messageCollection": {
+              "edges": []
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn api_key_requests_skip_the_session() {
+    let response = query_postgres_with_row_level_security(ROW_LEVEL_SECURITY, |api| async move {
+        init(&api).await;
+
+        api.execute_with_auth(QUERY, ExecutionAuth::ApiKey).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "messageCollection": {
+              "edges": [
+                {
+                  "node": {
+                    "id": 1,
+                    "body": "hello"
+                  }
+                },
+                {
+                  "node": {
+                    "id": 2,
+                    "body": "world"
+                  }
+                },
+                {
+                  "node": {
+                    "id": 3,
+                    "body": "secret"
+                  }
+                },
+                {
+                  "node": {
+                    "id": 4,
+                    "body": "for musti"
+                  }
+                }
+              ]
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn disabled_by_default() {
+    let response = query_postgres(|api| async move {
+        init(&api).await;
+
+        let auth = token(json!({ "sub": "musti", "role": "rls_user", "tenant": { "id": 1 } }));
+        api.execute_with_auth(QUERY, auth).await
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "messageCollection": {
+              "edges": [
+                {
+                  "node": {
+                    "id": 1,
+                    "body": "hello"
+                  }
+                },
+                {
+                  "node": {
+                    "id": 2,
+                    "body": "world"
+                  }
+                },
+                {
+                  "node": {
+                    "id": 3,
+                    "body": "secret"
+                  }
+                },
+                {
+                  "node": {
+                    "id": 4,
+                    "body": "for musti"
+                  }
+                }
+              ]
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn concurrent_sessions_use_their_own_connection() {
+    let response = query_postgres_with_row_level_security(ROW_LEVEL_SECURITY, |api| async move {
+        init(&api).await;
+
+        let query = indoc! {r"
+            query {
+              first: messageCollection(first: 10, orderBy: [{ id: ASC }]) { edges { node { id } } }
+              second: messageCollection(first: 10, orderBy: [{ id: DESC }]) { edges { node { id } } }
+            }
+        "};
+
+        let first_tenant = token(json!({ "sub": "nobody", "role": "rls_user", "tenant": { "id": 1 } }));
+        let second_tenant = token(json!({ "sub": "musti", "role": "rls_user", "tenant": { "id": 2 } }));
+
+        let (first, second) = futures::join!(
+            api.execute_with_auth(query, first_tenant),
+            api.execute_with_auth(query, second_tenant)
+        );
+
+        let ids = |response: &serde_json::Value, field: &str| -> Vec<i64> {
+            response["data"][field]["edges"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|edge| edge["node"]["id"].as_i64().unwrap())
+                .collect()
+        };
+
+        let first_response = serde_json::to_value(first.to_graphql_response()).unwrap();
+        assert_eq!(ids(&first_response, "first"), vec![1, 2]);
+        assert_eq!(ids(&first_response, "second"), vec![2, 1]);
+
+        second
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "first": {
+              "edges": [
+                {
+                  "node": {
+                    "id": 3
+                  }
+                },
+                {
+                  "node": {
+                    "id": 4
+                  }
+                }
+              ]
+            },
+            "second": {
+              "edges": [
+                {
+                  "node": {
+                    "id": 4
+                  }
+                },
+                {
+                  "node": {
+                    "id": 3
+                  }
+                }
+              ]
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
@@ -0,0 +1,212 @@
+use std::time::Duration;
+
+use expect_test::expect;
+use futures::StreamExt;
+use indoc::indoc;
+use integration_tests::postgres::{query_postgres_with_subscriptions, TestApi};
+
+async fn init(api: &TestApi) {
+    let schema = indoc! {r#"
+        CREATE TABLE "User" (
+            id INT PRIMARY KEY,
+            name VARCHAR(255) NOT NULL
+        )
+    "#};
+
+    api.execute_sql(schema).await;
+    api.setup_subscriptions().await;
+}
+
+// the subscription starts listening when the stream is first polled
+async fn after_listening(api: &TestApi, statements: &[&str]) {
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    for statement in statements {
+        api.execute_sql(statement).await;
+    }
+}
+
+#[test]
+fn created() {
+    let response = query_postgres_with_subscriptions(|api| async move {
+        init(&api).await;
+
+        let subscription = indoc! {r#"
+            subscription {
+              userCreated(filter: { name: { eq: "Naukio" } }) { id name }
+            }
+        "#};
+
+        let mut stream = Box::pin(api.subscribe(subscription).await);
+
+        let statements = [
+            r#"INSERT INTO "User" (id, name) VALUES (1, 'Musti')"#,
+            r#"INSERT INTO "User" (id, name) VALUES (2, 'Naukio')"#,
+        ];
+
+        let (response, _) = futures::join!(stream.next(), after_listening(&api, &statements));
+
+        response.unwrap()
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userCreated": {
+              "id": 2,
+              "name": "Naukio"
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn updated() {
+    let response = query_postgres_with_subscriptions(|api| async move {
+        init(&api).await;
+
+        api.execute_sql(r#"INSERT INTO "User" (id, name) VALUES (1, 'Musti')"#)
+            .await;
+
+        let subscription = indoc! {r"
+            subscription {
+              userUpdated { id name }
+            }
+        "};
+
+        let mut stream = Box::pin(api.subscribe(subscription).await);
+
+        let statements = [
+            r#"INSERT INTO "User" (id, name) VALUES (2, 'Naukio')"#,
+            r#"UPDATE "User" SET name = 'Pertti' WHERE id = 1"#,
+        ];
+
+        let (response, _) = futures::join!(stream.next(), after_listening(&api, &statements));
+
+        response.unwrap()
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userUpdated": {
+              "id": 1,
+              "name": "Pertti"
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn deleted() {
+    let response = query_postgres_with_subscriptions(|api| async move {
+        init(&api).await;
+
+        api.execute_sql(r#"INSERT INTO "User" (id, name) VALUES (1, 'Musti'), (2, 'Naukio')"#)
+            .await;
+
+        let subscription = indoc! {r"
+            subscription {
+              userDeleted(filter: { id: { eq: 2 } }) { id name }
+            }
+        "};
+
+        let mut stream = Box::pin(api.subscribe(subscription).await);
+
+        let statements = [
+            r#"DELETE FROM "User" WHERE id = 1"#,
+            r#"DELETE FROM "User" WHERE id = 2"#,
+        ];
+
+        let (response, _) = futures::join!(stream.next(), after_listening(&api, &statements));
+
+        response.unwrap()
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userDeleted": {
+              "id": 2,
+              "name": "Naukio"
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn within_fragments() {
+    let response = query_postgres_with_subscriptions(|api| async move {
+        init(&api).await;
+
+        let subscription = indoc! {r"
+            subscription {
+              ... on Subscription {
+                ...Created
+              }
+              userDeleted @skip(if: true) { id }
+            }
+
+            fragment Created on Subscription {
+              userCreated { id name }
+            }
+        "};
+
+        let mut stream = Box::pin(api.subscribe(subscription).await);
+
+        let statements = [r#"INSERT INTO "User" (id, name) VALUES (1, 'Musti')"#];
+
+        let (response, _) = futures::join!(stream.next(), after_listening(&api, &statements));
+
+        response.unwrap()
+    });
+
+    let expected = expect![[r#"
+        {
+          "data": {
+            "userCreated": {
+              "id": 1,
+              "name": "Musti"
+            }
+          }
+        }"#]];
+
+    expected.assert_eq(&response);
+}
+
+#[test]
+fn without_triggers() {
+    let response = query_postgres_with_subscriptions(|api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "User" (
+                id INT PRIMARY KEY,
+                name VARCHAR(255) NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let subscription = indoc! {r"
+            subscription {
+              userCreated { id name }
+            }
+        "};
+
+        let mut stream = Box::pin(api.subscribe(subscription).await);
+
+        stream.next().await.unwrap()
+    });
+
+    let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+
+    assert_eq!(
+        response["errors"][0]["message"],
+        "the changes of the table User are not published, install the triggers with `grafbase setup-subscriptions`"
+    );
+}
@@ -1,13 +1,19 @@
 mod columns;
+mod composite_types;
 mod enums;
 mod foreign_keys;
+mod function_arguments;
+mod functions;
 mod schemas;
 mod tables;
 mod unique_constraints;
 
 use std::sync::OnceLock;
 
-use postgres_connector_types::{database_definition::DatabaseDefinition, transport::Transport};
+use postgres_connector_types::{
+    database_definition::{ColumnType, DatabaseDefinition, ScalarType},
+    transport::Transport,
+};
 use serde_json::Value;
 
 /// A list of schemas to filter out automatically on every introspection.
@@ -24,10 +30,13 @@ where
     // order matters
     schemas::introspect(transport, &mut database_definition).await?;
     enums::introspect(transport, &mut database_definition).await?;
+    composite_types::introspect(transport, &mut database_definition).await?;
     tables::introspect(transport, &mut database_definition).await?;
     columns::introspect(transport, &mut database_definition).await?;
     foreign_keys::introspect(transport, &mut database_definition).await?;
     unique_constraints::introspect(transport, &mut database_definition).await?;
+    functions::introspect(transport, &mut database_definition).await?;
+    function_arguments::introspect(transport, &mut database_definition).await?;
 
     database_definition.finalize();
 
@@ -48,3 +57,37 @@ pub(super) fn blocked_schemas() -> Value {
 
     Value::Array(result)
 }
+
+/// Maps a database type to an enum or a composite type, if introspected, and otherwise to a scalar type.
+pub(super) fn column_type(
+    database_definition: &DatabaseDefinition,
+    type_id: u32,
+    type_name: &str,
+    type_schema: &str,
+) -> ColumnType {
+    // If the type is an array, it's named `_type` in the database. We don't need that info in the type
+    // name, we store enums and composite types without an underscore in our interner.
+    let base_type_name = type_name.trim_start_matches('_');
+    let schema_id = database_definition.get_schema_id(type_schema);
+
+    let enum_id = schema_id.and_then(|schema_id| database_definition.get_enum_id(schema_id, base_type_name));
+
+    if let Some(enum_id) = enum_id {
+        return ColumnType::Enum(enum_id);
+    }
+
+    let composite_type_id =
+        schema_id.and_then(|schema_id| database_definition.get_composite_type_id(schema_id, base_type_name));
+
+    if let Some(composite_type_id) = composite_type_id {
+        return ColumnType::Composite(composite_type_id);
+    }
+
+    match ScalarType::from(type_id) {
+        // Types defined in extensions get a different oid in every database.
+        ScalarType::Other(_) => {
+            ColumnType::Scalar(ScalarType::from_extension_type(type_name).unwrap_or(ScalarType::Other(type_id)))
+        }
+        scalar => ColumnType::Scalar(scalar),
+    }
+}
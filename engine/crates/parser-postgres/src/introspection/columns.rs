@@ -1,5 +1,5 @@
 use postgres_connector_types::{
-    database_definition::{ColumnType, DatabaseDefinition, ScalarType, TableColumn},
+    database_definition::{DatabaseDefinition, TableColumn},
     transport::{Transport, TransportExt},
 };
 use serde::Deserialize;
@@ -35,19 +35,7 @@ where
             continue;
         };
 
-        // If the type is an array, it's named `_type` in the database. We don't need that info in the type
-        // name, we store enums without an underscore in our interner.
-        let type_name = row.type_name.trim_start_matches('_');
-
-        let enum_id = database_definition
-            .get_schema_id(&row.type_schema)
-            .and_then(|enum_schema_id| database_definition.get_enum_id(enum_schema_id, type_name));
-
-        let database_type = match enum_id {
-            Some(enum_id) => ColumnType::Enum(enum_id),
-            None => ColumnType::Scalar(ScalarType::from(row.type_id)),
-        };
-
+        let database_type = super::column_type(database_definition, row.type_id, &row.type_name, &row.type_schema);
         let mut column = TableColumn::new(table_id, row.column_name, database_type);
 
         column.set_nullable(row.is_nullable);
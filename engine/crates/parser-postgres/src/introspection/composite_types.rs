@@ -0,0 +1,64 @@
+use postgres_connector_types::{
+    database_definition::{CompositeType, CompositeTypeAttribute, DatabaseDefinition},
+    transport::{Transport, TransportExt},
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct Row {
+    schema: String,
+    type_name: String,
+    attribute_name: String,
+    attribute_type_id: u32,
+    attribute_type_name: String,
+    attribute_type_schema: String,
+    is_array: bool,
+}
+
+pub(super) async fn introspect<T>(transport: &T, database_definition: &mut DatabaseDefinition) -> crate::Result<()>
+where
+    T: Transport + Sync,
+{
+    let query = include_str!("queries/composite_types.sql");
+
+    let result = transport
+        .collect_query::<Row>(query, vec![super::blocked_schemas()])
+        .await?;
+
+    // An attribute can be of another composite type, so all types must exist before the attributes.
+    for row in &result {
+        let Some(schema_id) = database_definition.get_schema_id(&row.schema) else {
+            continue;
+        };
+
+        if database_definition
+            .get_composite_type_id(schema_id, &row.type_name)
+            .is_none()
+        {
+            database_definition.push_composite_type(CompositeType::new(schema_id, row.type_name.clone()));
+        }
+    }
+
+    for row in result {
+        let Some(composite_type_id) = database_definition
+            .get_schema_id(&row.schema)
+            .and_then(|schema_id| database_definition.get_composite_type_id(schema_id, &row.type_name))
+        else {
+            continue;
+        };
+
+        let database_type = super::column_type(
+            database_definition,
+            row.attribute_type_id,
+            &row.attribute_type_name,
+            &row.attribute_type_schema,
+        );
+
+        let mut attribute = CompositeTypeAttribute::new(composite_type_id, row.attribute_name, database_type);
+        attribute.set_is_array(row.is_array);
+
+        database_definition.push_composite_type_attribute(attribute);
+    }
+
+    Ok(())
+}
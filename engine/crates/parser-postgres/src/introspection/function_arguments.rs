@@ -0,0 +1,50 @@
+use postgres_connector_types::{
+    database_definition::{DatabaseDefinition, FunctionArgument},
+    transport::{Transport, TransportExt},
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct Row {
+    schema: String,
+    function_name: String,
+    argument_name: String,
+    position: usize,
+    type_id: u32,
+    type_name: String,
+    type_schema: String,
+    is_array: bool,
+    has_default: bool,
+}
+
+pub(super) async fn introspect<T>(transport: &T, database_definition: &mut DatabaseDefinition) -> crate::Result<()>
+where
+    T: Transport + Sync,
+{
+    let query = include_str!("queries/function_arguments.sql");
+
+    let result = transport
+        .collect_query::<Row>(query, vec![super::blocked_schemas()])
+        .await?;
+
+    for row in result {
+        let Some(schema_id) = database_definition.get_schema_id(&row.schema) else {
+            continue;
+        };
+
+        // overloaded functions and functions from extensions are not introspected
+        let Some(function_id) = database_definition.get_function_id(schema_id, &row.function_name) else {
+            continue;
+        };
+
+        let database_type = super::column_type(database_definition, row.type_id, &row.type_name, &row.type_schema);
+        let mut argument = FunctionArgument::new(function_id, row.argument_name, row.position, database_type);
+
+        argument.set_is_array(row.is_array);
+        argument.set_has_default(row.has_default);
+
+        database_definition.push_function_argument(argument);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use postgres_connector_types::{
+    database_definition::{ColumnType, DatabaseDefinition, Function, FunctionReturnType, Volatility},
+    transport::{Transport, TransportExt},
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct Row {
+    schema: String,
+    function_name: String,
+    return_type_id: u32,
+    return_type_name: String,
+    return_type_schema: String,
+    return_table_name: Option<String>,
+    returns_array: bool,
+    returns_set: bool,
+    volatility: String,
+}
+
+pub(super) async fn introspect<T>(transport: &T, database_definition: &mut DatabaseDefinition) -> crate::Result<()>
+where
+    T: Transport + Sync,
+{
+    let query = include_str!("queries/functions.sql");
+
+    let result = transport
+        .collect_query::<Row>(query, vec![super::blocked_schemas()])
+        .await?;
+
+    for row in result {
+        let Some(schema_id) = database_definition.get_schema_id(&row.schema) else {
+            continue;
+        };
+
+        let return_type = match row.return_table_name {
+            Some(ref table_name) => {
+                let Some(table_id) = database_definition
+                    .get_schema_id(&row.return_type_schema)
+                    .and_then(|table_schema_id| database_definition.get_table_id(table_schema_id, table_name))
+                else {
+                    continue;
+                };
+
+                FunctionReturnType::Table(table_id)
+            }
+            None => match super::column_type(
+                database_definition,
+                row.return_type_id,
+                &row.return_type_name,
+                &row.return_type_schema,
+            ) {
+                ColumnType::Scalar(scalar) => FunctionReturnType::Scalar(scalar),
+                ColumnType::Enum(enum_id) => FunctionReturnType::Enum(enum_id),
+                // functions returning composite values are not supported yet
+                ColumnType::Composite(_) => continue,
+            },
+        };
+
+        let mut function = Function::new(schema_id, row.function_name, return_type);
+
+        function.set_returns_set(row.returns_set);
+        function.set_returns_array(row.returns_array);
+        function.set_volatility(Volatility::from(row.volatility.as_str()));
+
+        database_definition.push_function(function);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+SELECT pg_namespace.nspname             AS schema,
+       pg_type.typname                  AS type_name,
+       pg_attribute.attname             AS attribute_name,
+       CAST(attribute_type.oid AS int4) AS attribute_type_id,
+       attribute_type.typname           AS attribute_type_name,
+       attribute_namespace.nspname      AS attribute_type_schema,
+       attribute_type.typcategory = 'A' AS is_array
+
+FROM pg_type
+         JOIN pg_namespace ON pg_namespace.oid = pg_type.typnamespace
+
+         -- only types created with CREATE TYPE, tables have a composite type for their rows too
+         JOIN pg_class ON pg_class.oid = pg_type.typrelid AND pg_class.relkind = 'c'
+
+         JOIN pg_attribute ON pg_attribute.attrelid = pg_class.oid
+         JOIN pg_type declared_type ON declared_type.oid = pg_attribute.atttypid
+
+         -- domains are mapped to their base type
+         JOIN pg_type attribute_type
+              ON attribute_type.oid = COALESCE(NULLIF(declared_type.typbasetype, 0), declared_type.oid)
+         JOIN pg_namespace attribute_namespace ON attribute_namespace.oid = attribute_type.typnamespace
+
+WHERE pg_namespace.nspname <> ALL ( $1 )
+  AND pg_type.typtype = 'c'
+  AND pg_attribute.attnum > 0
+  AND NOT pg_attribute.attisdropped
+ORDER BY pg_namespace.nspname, pg_type.typname, pg_attribute.attnum;
@@ -0,0 +1,25 @@
+SELECT pg_namespace.nspname                                               AS schema,
+       pg_proc.proname                                                    AS function_name,
+       COALESCE(pg_proc.proargnames[arguments.position], '')              AS argument_name,
+       CAST(arguments.position AS int4)                                   AS position,
+       CAST(pg_type.oid AS int4)                                          AS type_id,
+       pg_type.typname                                                    AS type_name,
+       type_namespace.nspname                                             AS type_schema,
+       pg_type.typcategory = 'A'                                          AS is_array,
+       arguments.position > pg_proc.pronargs - pg_proc.pronargdefaults    AS has_default
+
+FROM pg_proc
+         JOIN pg_namespace ON pg_namespace.oid = pg_proc.pronamespace
+
+         -- one row per argument, numbered from one
+         CROSS JOIN LATERAL unnest(CAST(pg_proc.proargtypes AS oid[])) WITH ORDINALITY AS arguments(type_id, position)
+
+         JOIN pg_type declared_type ON declared_type.oid = arguments.type_id
+
+         -- domains are mapped to their base type
+         JOIN pg_type ON pg_type.oid = COALESCE(NULLIF(declared_type.typbasetype, 0), declared_type.oid)
+         JOIN pg_namespace type_namespace ON type_namespace.oid = pg_type.typnamespace
+
+WHERE pg_namespace.nspname <> ALL ( $1 )
+  AND pg_proc.prokind = 'f'
+ORDER BY schema, function_name, arguments.position;
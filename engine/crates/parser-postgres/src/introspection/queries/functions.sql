@@ -0,0 +1,39 @@
+SELECT pg_namespace.nspname               AS schema,
+       pg_proc.proname                    AS function_name,
+       CAST(return_type.oid AS int4)      AS return_type_id,
+       return_type.typname                AS return_type_name,
+       return_type_namespace.nspname      AS return_type_schema,
+       return_table.relname               AS return_table_name,
+       return_type.typcategory = 'A'      AS returns_array,
+       pg_proc.proretset                  AS returns_set,
+       CAST(pg_proc.provolatile AS text)  AS volatility
+
+FROM pg_proc
+         JOIN pg_namespace ON pg_namespace.oid = pg_proc.pronamespace
+         JOIN pg_type declared_return_type ON declared_return_type.oid = pg_proc.prorettype
+
+         -- domains are mapped to their base type
+         JOIN pg_type return_type
+              ON return_type.oid = COALESCE(NULLIF(declared_return_type.typbasetype, 0), declared_return_type.oid)
+         JOIN pg_namespace return_type_namespace ON return_type_namespace.oid = return_type.typnamespace
+
+         -- set if the function returns rows of a table
+         LEFT JOIN pg_class return_table
+                   ON return_table.oid = return_type.typrelid
+                       AND return_table.relkind = 'r'
+
+WHERE pg_namespace.nspname <> ALL ( $1 )
+  AND pg_proc.prokind = 'f' -- f = normal function, no aggregates, window functions or procedures
+  AND pg_proc.proargmodes IS NULL -- only IN arguments, no OUT, INOUT, TABLE or VARIADIC
+  -- functions installed by extensions are not part of the user's API
+  AND NOT EXISTS (SELECT 1
+                  FROM pg_depend
+                  WHERE pg_depend.objid = pg_proc.oid
+                    AND pg_depend.deptype = 'e')
+  -- overloaded functions cannot be represented as GraphQL fields
+  AND NOT EXISTS (SELECT 1
+                  FROM pg_proc overload
+                  WHERE overload.pronamespace = pg_proc.pronamespace
+                    AND overload.proname = pg_proc.proname
+                    AND overload.oid <> pg_proc.oid)
+ORDER BY schema, function_name;
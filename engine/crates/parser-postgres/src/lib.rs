@@ -1,15 +1,30 @@
 use parser_sdl::Registry;
-use postgres_connector_types::{transport::Transport, Result};
+use postgres_connector_types::{row_level_security::RowLevelSecurity, transport::Transport, Result};
 
 mod introspection;
 mod registry;
 
-pub async fn introspect<T>(transport: &T, name: &str, namespaced: bool) -> Result<Registry>
+pub async fn introspect<T>(
+    transport: &T,
+    name: &str,
+    namespaced: bool,
+    row_level_security: Option<RowLevelSecurity>,
+    subscriptions: bool,
+) -> Result<Registry>
 where
     T: Transport + Sync,
 {
-    let database_definition = introspection::introspect(transport).await?;
-    let registry = registry::generate(database_definition, name, namespaced);
+    let mut database_definition = introspection::introspect(transport).await?;
+
+    if let Some(row_level_security) = row_level_security {
+        database_definition.set_row_level_security(row_level_security);
+    }
+
+    if subscriptions {
+        database_definition.enable_subscriptions();
+    }
+
+    let registry = registry::generate(database_definition, name, namespaced, subscriptions);
 
     Ok(registry)
 }
@@ -8,9 +8,14 @@ use postgres_connector_types::database_definition::DatabaseDefinition;
 
 use self::context::OutputContext;
 
-pub(super) fn generate(database_definition: DatabaseDefinition, name: &str, namespaced: bool) -> Registry {
-    let input_ctx = InputContext::new(database_definition, name, namespaced);
-    let mut output_ctx = OutputContext::new(namespaced.then_some(name));
+pub(super) fn generate(
+    database_definition: DatabaseDefinition,
+    name: &str,
+    namespaced: bool,
+    subscriptions: bool,
+) -> Registry {
+    let input_ctx = InputContext::new(database_definition, name, namespaced, subscriptions);
+    let mut output_ctx = OutputContext::new(namespaced.then_some(name), subscriptions);
 
     types::generate(&input_ctx, &mut output_ctx);
     queries::generate(&input_ctx, &mut output_ctx);
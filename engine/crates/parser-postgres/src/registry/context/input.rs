@@ -6,14 +6,21 @@ pub struct InputContext<'a> {
     namespace: Option<&'a str>,
     database_definition: DatabaseDefinition,
     directive_name: &'a str,
+    subscriptions: bool,
 }
 
 impl<'a> InputContext<'a> {
-    pub(crate) fn new(database_definition: DatabaseDefinition, name: &'a str, namespaced: bool) -> Self {
+    pub(crate) fn new(
+        database_definition: DatabaseDefinition,
+        name: &'a str,
+        namespaced: bool,
+        subscriptions: bool,
+    ) -> Self {
         Self {
             namespace: namespaced.then_some(name),
             database_definition,
             directive_name: name,
+            subscriptions,
         }
     }
 
@@ -25,6 +32,10 @@ impl<'a> InputContext<'a> {
         self.directive_name
     }
 
+    pub(crate) fn subscriptions(&self) -> bool {
+        self.subscriptions
+    }
+
     pub(crate) fn type_name(&self, name: &str) -> String {
         let base_name = name.to_string();
         self.namespaced(base_name)
@@ -105,6 +116,14 @@ impl<'a> InputContext<'a> {
         MetaNames::collection_by_str(type_name).to_camel_case()
     }
 
+    // subscriptions are not namespaced under a field, the root field starts the stream
+    pub(crate) fn subscription_name(&self, type_name: &str, event: &str) -> String {
+        match self.namespace {
+            Some(namespace) => format!("{namespace}_{type_name}_{event}").to_camel_case(),
+            None => format!("{type_name}_{event}").to_camel_case(),
+        }
+    }
+
     pub(crate) fn database_definition(&self) -> &DatabaseDefinition {
         &self.database_definition
     }
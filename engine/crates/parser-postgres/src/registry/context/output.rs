@@ -4,7 +4,7 @@ use engine::registry::{EnumType, InputObjectType, MetaField, ObjectType};
 use inflector::Inflector;
 use parser_sdl::Registry;
 use postgres_connector_types::database_definition::{
-    DatabaseDefinition, EnumId, RelationId, TableColumnId, TableId, UniqueConstraintId,
+    DatabaseDefinition, EnumId, FunctionId, RelationId, TableColumnId, TableId, UniqueConstraintId,
 };
 
 pub use self::builders::{EnumBuilder, InputTypeBuilder, ObjectTypeBuilder};
@@ -13,16 +13,18 @@ pub use self::builders::{EnumBuilder, InputTypeBuilder, ObjectTypeBuilder};
 pub struct OutputContext {
     query_type_name: String,
     mutation_type_name: String,
+    subscription_type_name: Option<String>,
     registry: Registry,
     type_mapping: Vec<(String, TableId)>,
     unique_constraint_mapping: Vec<(String, UniqueConstraintId)>,
     field_mapping: Vec<(String, TableColumnId)>,
     enum_mapping: Vec<(String, EnumId)>,
     relation_mapping: Vec<(String, RelationId)>,
+    function_mapping: Vec<(String, FunctionId)>,
 }
 
 impl OutputContext {
-    pub fn new(namespace: Option<&str>) -> Self {
+    pub fn new(namespace: Option<&str>, subscriptions: bool) -> Self {
         let query_type_name = namespace
             .map(|namespace| format!("{namespace}_Query").to_pascal_case())
             .unwrap_or_else(|| String::from("Query"));
@@ -47,6 +49,18 @@ impl OutputContext {
 
         registry.mutation_type = Some(String::from("Mutation"));
 
+        let subscription_type_name = subscriptions.then(|| String::from("Subscription"));
+
+        if let Some(ref subscription_type_name) = subscription_type_name {
+            registry.create_type(
+                |_| ObjectType::new(subscription_type_name, []).into(),
+                subscription_type_name,
+                subscription_type_name,
+            );
+
+            registry.subscription_type = Some(subscription_type_name.clone());
+        }
+
         if let Some(namespace) = namespace {
             let mut query_type = ObjectType::new("Query", []);
 
@@ -70,12 +84,14 @@ impl OutputContext {
         Self {
             query_type_name,
             mutation_type_name,
+            subscription_type_name,
             registry,
             type_mapping: Vec::new(),
             unique_constraint_mapping: Vec::new(),
             field_mapping: Vec::new(),
             enum_mapping: Vec::new(),
             relation_mapping: Vec::new(),
+            function_mapping: Vec::new(),
         }
     }
 
@@ -166,6 +182,27 @@ impl OutputContext {
         fields.insert(mutation.name.to_string(), mutation);
     }
 
+    pub fn push_subscription(&mut self, subscription: MetaField) {
+        let fields = self
+            .subscription_type_name
+            .as_ref()
+            .and_then(|name| self.registry.types.get_mut(name))
+            .and_then(|r#type| r#type.fields_mut())
+            .expect("Subscription type not registered.");
+
+        fields.insert(subscription.name.to_string(), subscription);
+    }
+
+    pub fn push_function_query(&mut self, query: MetaField, function_id: FunctionId) {
+        self.function_mapping.push((query.name.clone(), function_id));
+        self.push_query(query);
+    }
+
+    pub fn push_function_mutation(&mut self, mutation: MetaField, function_id: FunctionId) {
+        self.function_mapping.push((mutation.name.clone(), function_id));
+        self.push_mutation(mutation);
+    }
+
     /// Merge the database definition to a registry.
     pub fn finalize(mut self, mut database_definition: DatabaseDefinition, name: &str) -> Registry {
         for (type_name, table_id) in self.type_mapping {
@@ -191,6 +228,10 @@ impl OutputContext {
             database_definition.push_client_field_unique_constraint_mapping(&field_name, table_id, constraint_id);
         }
 
+        for (field_name, function_id) in self.function_mapping {
+            database_definition.push_client_function_mapping(&field_name, function_id);
+        }
+
         self.registry
             .postgres_databases
             .insert(name.to_string(), database_definition);
@@ -4,10 +4,14 @@ mod delete_many;
 mod delete_one;
 mod find_many;
 mod find_one;
+mod function;
 mod input;
+mod subscription;
 mod update_many;
 mod update_one;
 
+use std::collections::HashMap;
+
 use super::context::{InputContext, OutputContext};
 
 pub(super) fn generate(input_ctx: &InputContext<'_>, output_ctx: &mut OutputContext) {
@@ -16,6 +20,8 @@ pub(super) fn generate(input_ctx: &InputContext<'_>, output_ctx: &mut OutputCont
         .tables()
         .filter(|table| table.allowed_in_client());
 
+    let mut filter_types = HashMap::new();
+
     for table in tables {
         let filter_oneof_type = input::oneof::register(input_ctx, table, output_ctx);
         let create_input_type = input::create::register(input_ctx, table, output_ctx);
@@ -31,5 +37,20 @@ pub(super) fn generate(input_ctx: &InputContext<'_>, output_ctx: &mut OutputCont
         create_many::register(input_ctx, table, &create_input_type, output_ctx);
         update_one::register(input_ctx, table, &filter_oneof_type, &update_input_type, output_ctx);
         update_many::register(input_ctx, table, &simple_filter, &update_input_type, output_ctx);
+
+        if input_ctx.subscriptions() {
+            subscription::register(input_ctx, table, &complex_filter, output_ctx);
+        }
+
+        filter_types.insert(table.id(), complex_filter);
+    }
+
+    let functions = input_ctx
+        .database_definition()
+        .functions()
+        .filter(|function| function.allowed_in_client());
+
+    for function in functions {
+        function::register(input_ctx, function, &filter_types, output_ctx);
     }
 }
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use common_types::auth::Operations;
+use engine::{
+    indexmap::IndexMap,
+    registry::{
+        resolvers::{
+            postgres::{Operation, PostgresResolver},
+            transformer::Transformer,
+            Resolver,
+        },
+        MetaField, MetaInputValue,
+    },
+};
+use postgres_connector_types::database_definition::{FunctionReturnTypeWalker, FunctionWalker, TableId};
+
+use crate::registry::context::{InputContext, OutputContext};
+
+/// Registers a query for a stable or immutable function, and a mutation for a volatile function.
+///
+/// A function returning a set of table rows returns the connection type of the table, and can be
+/// filtered, ordered and paginated like a collection query.
+pub(crate) fn register(
+    input_ctx: &InputContext<'_>,
+    function: FunctionWalker<'_>,
+    filter_types: &HashMap<TableId, String>,
+    output_ctx: &mut OutputContext,
+) {
+    let resolver = Resolver::PostgresResolver(PostgresResolver::new(
+        Operation::CallFunction,
+        input_ctx.directive_name(),
+    ));
+
+    let mut field = match function.return_type() {
+        FunctionReturnTypeWalker::Table(table) if function.returns_set() => {
+            let Some(filter_type) = filter_types.get(&table.id()) else {
+                return;
+            };
+
+            let type_name = table.client_name();
+            let mut field = MetaField::new(function.client_name(), input_ctx.connection_type_name(type_name));
+            let order_by_type = input_ctx.orderby_input_type_name(type_name);
+
+            field.args = IndexMap::from([
+                (
+                    "filter".to_string(),
+                    MetaInputValue::new("filter", filter_type.as_str()),
+                ),
+                ("first".to_string(), MetaInputValue::new("first", "Int")),
+                ("last".to_string(), MetaInputValue::new("last", "Int")),
+                ("before".to_string(), MetaInputValue::new("before", "String")),
+                ("after".to_string(), MetaInputValue::new("after", "String")),
+                (
+                    "orderBy".to_string(),
+                    MetaInputValue::new("orderBy", format!("[{order_by_type}]")),
+                ),
+            ]);
+
+            field.resolver = resolver.and_then(Transformer::PostgresPageInfo);
+            field
+        }
+        FunctionReturnTypeWalker::Table(table) => {
+            let mut field = MetaField::new(function.client_name(), input_ctx.type_name(table.client_name()));
+            field.resolver = resolver;
+            field
+        }
+        FunctionReturnTypeWalker::Enum(_) => {
+            let client_type = function
+                .graphql_type(input_ctx.namespace())
+                .expect("unsupported types are filtered out at this point");
+
+            let mut field = MetaField::new(function.client_name(), client_type.as_ref());
+            field.resolver = resolver.and_then(Transformer::RemoteEnum);
+            field
+        }
+        FunctionReturnTypeWalker::Scalar(scalar) => {
+            let client_type = function
+                .graphql_type(input_ctx.namespace())
+                .expect("unsupported types are filtered out at this point");
+
+            let mut field = MetaField::new(function.client_name(), client_type.as_ref());
+
+            field.resolver = if scalar.range_element_type().is_some() {
+                resolver.and_then(Transformer::PostgresRange { scalar })
+            } else {
+                resolver
+            };
+
+            field
+        }
+    };
+
+    for argument in function.arguments() {
+        let client_type = argument
+            .graphql_input_type(input_ctx.namespace())
+            .expect("unsupported types are filtered out at this point");
+
+        let client_type = if argument.has_default() {
+            client_type.to_string()
+        } else {
+            format!("{client_type}!")
+        };
+
+        let input = MetaInputValue::new(argument.client_name(), client_type);
+        field.args.insert(argument.client_name().to_string(), input);
+    }
+
+    field.description = Some(format!(
+        "Calls the function {}.{}",
+        function.schema(),
+        function.database_name()
+    ));
+
+    if function.is_mutation() {
+        field.required_operation = Some(Operations::WRITE);
+        output_ctx.push_function_mutation(field, function.id());
+    } else {
+        field.required_operation = Some(if function.returns_set() {
+            Operations::LIST
+        } else {
+            Operations::GET
+        });
+
+        output_ctx.push_function_query(field, function.id());
+    }
+}
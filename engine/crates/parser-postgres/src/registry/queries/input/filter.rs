@@ -73,12 +73,22 @@ fn add_relation(input_ctx: &InputContext<'_>, relation: RelationWalker<'_>, buil
 }
 
 fn add_column(input_ctx: &InputContext<'_>, column: TableColumnWalker<'_>, builder: &mut InputTypeBuilder) {
+    // composite values are compared as a whole, which is not useful for filtering
+    if column.database_type().is_composite() {
+        return;
+    }
+
     let scalar = column
         .graphql_base_type(None)
         .expect("unsupported types are filtered out at this point");
 
-    let type_name = if column.is_array() {
+    let type_name = if column.database_type().is_tsvector() {
+        input_ctx.filter_type_name("TsVector")
+    } else if column.is_array() {
         input_ctx.filter_type_name(&format!("{scalar}Array"))
+    } else if column.database_type().is_json() {
+        // containment and path operations are only defined for JSONB
+        input_ctx.filter_type_name("SimpleJSON")
     } else {
         input_ctx.filter_type_name(&scalar)
     };
@@ -0,0 +1,49 @@
+use common_types::auth::Operations;
+use engine::registry::{
+    resolvers::{
+        postgres::{Operation, PostgresResolver},
+        Resolver,
+    },
+    MetaField, MetaInputValue,
+};
+use postgres_connector_types::{change_feed::ChangeKind, database_definition::TableWalker};
+
+use crate::registry::context::{InputContext, OutputContext};
+
+pub(crate) fn register(
+    input_ctx: &InputContext<'_>,
+    table: TableWalker<'_>,
+    filter_type: &str,
+    output_ctx: &mut OutputContext,
+) {
+    let type_name = input_ctx.type_name(table.client_name());
+
+    let kinds = [
+        (ChangeKind::Created, "created"),
+        (ChangeKind::Updated, "updated"),
+        (ChangeKind::Deleted, "deleted"),
+    ];
+
+    for (kind, event) in kinds {
+        let field_name = input_ctx.subscription_name(table.client_name(), event);
+        let filter_description = format!("Streams only the {event} rows matching the filter");
+
+        let mut field = MetaField::new(field_name, type_name.as_str());
+        field.description = Some(format!("Streams every {type_name} {event}"));
+
+        field.args = [(
+            "filter".to_string(),
+            MetaInputValue::new("filter", filter_type).with_description(filter_description),
+        )]
+        .into();
+
+        field.resolver = Resolver::PostgresResolver(PostgresResolver::new(
+            Operation::Subscribe(kind),
+            input_ctx.directive_name(),
+        ));
+
+        field.required_operation = Some(Operations::LIST);
+
+        output_ctx.push_subscription(field);
+    }
+}
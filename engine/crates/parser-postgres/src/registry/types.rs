@@ -1,9 +1,18 @@
+mod composite_type;
 mod order_direction;
 mod page_info;
+mod range;
 mod scalar;
 mod table;
+mod text_search;
 
-use engine::registry::MetaEnumValue;
+use std::collections::BTreeSet;
+
+use engine::registry::{
+    resolvers::{transformer::Transformer, Resolver},
+    MetaEnumValue,
+};
+use postgres_connector_types::database_definition::{DatabaseType, FunctionReturnTypeWalker};
 
 use self::scalar::{create_array_update_type, create_filter_types, create_scalar_update_type, TypeKind};
 
@@ -19,6 +28,7 @@ pub(super) fn generate(input_ctx: &InputContext<'_>, output_ctx: &mut OutputCont
     scalar::register(input_ctx, output_ctx);
 
     let direction_type = order_direction::register(input_ctx, output_ctx);
+    text_search::register(input_ctx, &direction_type, output_ctx);
 
     for table in tables {
         table::generate(input_ctx, table, &direction_type, output_ctx);
@@ -40,4 +50,75 @@ pub(super) fn generate(input_ctx: &InputContext<'_>, output_ctx: &mut OutputCont
         create_scalar_update_type(input_ctx, TypeKind::Enum(r#enum.client_name()), output_ctx);
         create_array_update_type(input_ctx, TypeKind::Enum(r#enum.client_name()), output_ctx);
     }
+
+    let composite_types = input_ctx
+        .database_definition()
+        .composite_types()
+        .filter(|composite_type| composite_type.allowed_in_client());
+
+    for composite_type in composite_types {
+        composite_type::generate(input_ctx, composite_type, output_ctx);
+    }
+
+    for element in used_range_elements(input_ctx) {
+        range::register(input_ctx, element, output_ctx);
+    }
+}
+
+/// Selects the value of a column or a composite attribute from the row, converting it to the
+/// GraphQL representation if needed.
+fn column_resolver(database_name: &str, database_type: DatabaseType<'_>) -> Resolver {
+    let resolver = Resolver::Transformer(Transformer::Select {
+        key: database_name.to_string(),
+    });
+
+    match database_type {
+        DatabaseType::Enum(_) => resolver.and_then(Transformer::RemoteEnum),
+        DatabaseType::Scalar(scalar) if database_type.is_range() => {
+            resolver.and_then(Transformer::PostgresRange { scalar })
+        }
+        _ => resolver,
+    }
+}
+
+/// The element scalars of the range types used in the API. Range types are registered only
+/// if something uses them.
+fn used_range_elements(input_ctx: &InputContext<'_>) -> BTreeSet<&'static str> {
+    let database_definition = input_ctx.database_definition();
+
+    let columns = database_definition
+        .tables()
+        .filter(|table| table.allowed_in_client())
+        .flat_map(|table| table.columns())
+        .map(|column| column.database_type());
+
+    let attributes = database_definition
+        .composite_types()
+        .filter(|composite_type| composite_type.allowed_in_client())
+        .flat_map(|composite_type| composite_type.attributes())
+        .map(|attribute| attribute.database_type());
+
+    let functions = database_definition
+        .functions()
+        .filter(|function| function.allowed_in_client())
+        .flat_map(|function| {
+            let return_type = match function.return_type() {
+                FunctionReturnTypeWalker::Scalar(scalar) => Some(DatabaseType::Scalar(scalar)),
+                _ => None,
+            };
+
+            function
+                .arguments()
+                .map(|argument| argument.database_type())
+                .chain(return_type)
+        });
+
+    columns
+        .chain(attributes)
+        .chain(functions)
+        .filter_map(|database_type| match database_type {
+            DatabaseType::Scalar(scalar) => scalar.range_element_type(),
+            _ => None,
+        })
+        .collect()
 }
@@ -0,0 +1,50 @@
+use engine::registry::{InputObjectType, MetaField, MetaInputValue, ObjectType};
+use postgres_connector_types::database_definition::CompositeTypeWalker;
+
+use super::scalar::{create_array_update_type, create_scalar_update_type, TypeKind};
+use crate::registry::context::{InputContext, OutputContext};
+
+/// Registers an object type and an input object type for a composite type. Composite values
+/// are always read and written as a whole.
+pub(super) fn generate(
+    input_ctx: &InputContext<'_>,
+    composite_type: CompositeTypeWalker<'_>,
+    output_ctx: &mut OutputContext,
+) {
+    let type_name = input_ctx.type_name(composite_type.client_name());
+    let input_type_name = input_ctx.type_name(&format!("{}Input", composite_type.client_name()));
+
+    let fields = composite_type.attributes().map(|attribute| {
+        let client_type = attribute
+            .graphql_type(input_ctx.namespace())
+            .expect("unsupported types are filtered out at this point");
+
+        let mut field = MetaField::new(attribute.client_name(), client_type.as_ref());
+        field.mapped_name = Some(attribute.database_name().to_string());
+        field.resolver = super::column_resolver(attribute.database_name(), attribute.database_type());
+
+        field
+    });
+
+    let description = format!(
+        "The composite type {}.{}",
+        composite_type.schema(),
+        composite_type.database_name()
+    );
+
+    output_ctx.create_object_type(ObjectType::new(type_name, fields).with_description(Some(description.clone())));
+
+    let fields = composite_type.attributes().map(|attribute| {
+        let client_type = attribute
+            .graphql_input_type(input_ctx.namespace())
+            .expect("unsupported types are filtered out at this point");
+
+        MetaInputValue::new(attribute.client_name(), client_type.as_ref())
+    });
+
+    let input_type = InputObjectType::new(input_type_name, fields).with_description(Some(description));
+    output_ctx.create_input_type(input_type);
+
+    create_scalar_update_type(input_ctx, TypeKind::Object(composite_type.client_name()), output_ctx);
+    create_array_update_type(input_ctx, TypeKind::Object(composite_type.client_name()), output_ctx);
+}
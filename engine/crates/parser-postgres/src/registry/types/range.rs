@@ -0,0 +1,120 @@
+use engine::registry::{
+    resolvers::{transformer::Transformer, Resolver},
+    InputObjectType, MetaField, MetaInputValue, ObjectType,
+};
+use parser_sdl::{
+    INPUT_FIELD_OP_CONTAINS, INPUT_FIELD_OP_EQ, INPUT_FIELD_OP_NE, INPUT_FIELD_OP_NOT, INPUT_FIELD_OP_OVERLAPS,
+};
+
+use super::scalar::{create_scalar_update_type, TypeKind};
+use crate::registry::context::{InputContext, OutputContext};
+
+/// Registers the output, input, filter and update types for ranges of the given element scalar,
+/// such as `DateTimeRange` for `tstzrange`.
+pub(super) fn register(input_ctx: &InputContext<'_>, element: &str, output_ctx: &mut OutputContext) {
+    let range_name = format!("{element}Range");
+    let type_name = input_ctx.type_name(&range_name);
+    let input_type_name = input_ctx.type_name(&format!("{range_name}Input"));
+
+    let bounds = [
+        ("start", element, "The lower bound of the range, null if unbounded."),
+        ("end", element, "The upper bound of the range, null if unbounded."),
+        (
+            "startInclusive",
+            "Boolean!",
+            "True, if the lower bound is part of the range.",
+        ),
+        (
+            "endInclusive",
+            "Boolean!",
+            "True, if the upper bound is part of the range.",
+        ),
+        ("isEmpty", "Boolean!", "True, if the range contains no values."),
+    ];
+
+    let fields = bounds.into_iter().map(|(name, r#type, description)| {
+        let mut field = MetaField::new(name, r#type);
+        field.description = Some(String::from(description));
+        field.resolver = Resolver::Transformer(Transformer::Select { key: name.to_string() });
+
+        field
+    });
+
+    let description = format!("A range of {element} values.");
+    output_ctx.create_object_type(ObjectType::new(type_name.clone(), fields).with_description(Some(description)));
+
+    let bounds = [
+        (
+            "start",
+            element,
+            "The lower bound of the range, unbounded if not defined.",
+        ),
+        (
+            "end",
+            element,
+            "The upper bound of the range, unbounded if not defined.",
+        ),
+        (
+            "startInclusive",
+            "Boolean",
+            "True, if the lower bound is part of the range. Defaults to true.",
+        ),
+        (
+            "endInclusive",
+            "Boolean",
+            "True, if the upper bound is part of the range. Defaults to false.",
+        ),
+    ];
+
+    let fields = bounds.into_iter().map(|(name, r#type, description)| {
+        let mut input = MetaInputValue::new(name, r#type);
+        input.description = Some(String::from(description));
+
+        input
+    });
+
+    let description = format!("Input for a range of {element} values.");
+    let input_type = InputObjectType::new(input_type_name.clone(), fields).with_description(Some(description));
+
+    output_ctx.create_input_type(input_type);
+
+    let filter_type_name = input_ctx.filter_type_name(&range_name);
+    let mut fields = Vec::with_capacity(5);
+
+    fields.push({
+        let mut input = MetaInputValue::new(INPUT_FIELD_OP_EQ, input_type_name.as_str());
+        input.description = Some(String::from("The value is exactly the one given"));
+
+        input
+    });
+
+    fields.push({
+        let mut input = MetaInputValue::new(INPUT_FIELD_OP_NE, input_type_name.as_str());
+        input.description = Some(String::from("The value is not the one given"));
+
+        input
+    });
+
+    fields.push({
+        let mut input = MetaInputValue::new(INPUT_FIELD_OP_OVERLAPS, input_type_name.as_str());
+        input.description = Some(String::from("The range has values in common with the given range"));
+
+        input
+    });
+
+    fields.push({
+        let mut input = MetaInputValue::new(INPUT_FIELD_OP_CONTAINS, element);
+        input.description = Some(String::from("The range contains the given value"));
+
+        input
+    });
+
+    fields.push(MetaInputValue::new(INPUT_FIELD_OP_NOT, filter_type_name.as_str()));
+
+    let description = format!("Search filter input for {type_name} type.");
+    let input_type = InputObjectType::new(filter_type_name, fields).with_description(Some(description));
+
+    output_ctx.create_input_type(input_type);
+
+    create_scalar_update_type(input_ctx, TypeKind::Object(&range_name), output_ctx);
+}
@@ -7,8 +7,9 @@ use parser_sdl::{
     INPUT_FIELD_COLLECTION_OP_DELETE_KEY, INPUT_FIELD_COLLECTION_OP_PREPEND, INPUT_FIELD_NUM_OP_DECREMENT,
     INPUT_FIELD_NUM_OP_DIVIDE, INPUT_FIELD_NUM_OP_INCREMENT, INPUT_FIELD_NUM_OP_MULTIPLY, INPUT_FIELD_NUM_OP_SET,
     INPUT_FIELD_OP_CONTAINED, INPUT_FIELD_OP_CONTAINS, INPUT_FIELD_OP_EQ, INPUT_FIELD_OP_GT, INPUT_FIELD_OP_GTE,
-    INPUT_FIELD_OP_IN, INPUT_FIELD_OP_LT, INPUT_FIELD_OP_LTE, INPUT_FIELD_OP_NE, INPUT_FIELD_OP_NIN,
-    INPUT_FIELD_OP_NOT, INPUT_FIELD_OP_OVERLAPS,
+    INPUT_FIELD_OP_ILIKE, INPUT_FIELD_OP_IN, INPUT_FIELD_OP_IREGEX, INPUT_FIELD_OP_LIKE, INPUT_FIELD_OP_LT,
+    INPUT_FIELD_OP_LTE, INPUT_FIELD_OP_NE, INPUT_FIELD_OP_NIN, INPUT_FIELD_OP_NOT, INPUT_FIELD_OP_OVERLAPS,
+    INPUT_FIELD_OP_PATH, INPUT_FIELD_OP_REGEX,
 };
 
 use crate::registry::context::{InputContext, OutputContext};
@@ -38,6 +39,22 @@ static SCALARS: &[&str] = &[
 
 static NUMERIC_SCALARS: &[&str] = &["BigInt", "Float", "Decimal", "Int"];
 
+static STRING_FILTERS: &[(&str, &str)] = &[
+    (
+        INPUT_FIELD_OP_LIKE,
+        "The value matches the given LIKE pattern, where % matches any sequence of characters and _ any single character",
+    ),
+    (
+        INPUT_FIELD_OP_ILIKE,
+        "The value matches the given LIKE pattern, ignoring case",
+    ),
+    (INPUT_FIELD_OP_REGEX, "The value matches the given POSIX regular expression"),
+    (
+        INPUT_FIELD_OP_IREGEX,
+        "The value matches the given POSIX regular expression, ignoring case",
+    ),
+];
+
 static SCALAR_FILTERS: &[(&str, &str, &str)] = &[
     (INPUT_FIELD_OP_EQ, "=", "The value is exactly the one given"),
     (INPUT_FIELD_OP_NE, "<>", "The value is not the one given"),
@@ -59,12 +76,15 @@ static SCALAR_FILTERS: &[(&str, &str, &str)] = &[
 pub(super) enum TypeKind<'a> {
     Scalar(&'a str),
     Enum(&'a str),
+    /// A composite or a range type, with a separate input object type.
+    Object(&'a str),
 }
 
 impl<'a> TypeKind<'a> {
     fn prefixed(&'a self, input_ctx: &InputContext<'_>) -> Cow<'a, str> {
         match (self, input_ctx.namespace()) {
             (Self::Enum(r#type), Some(namespace)) => Cow::Owned(format!("{namespace}_{type}").to_pascal_case()),
+            (Self::Object(r#type), _) => Cow::Owned(input_ctx.type_name(&format!("{type}Input"))),
             _ => Cow::Borrowed(self.as_ref()),
         }
     }
@@ -73,7 +93,7 @@ impl<'a> TypeKind<'a> {
 impl<'a> AsRef<str> for TypeKind<'a> {
     fn as_ref(&self) -> &str {
         match self {
-            TypeKind::Enum(s) | TypeKind::Scalar(s) => s,
+            TypeKind::Enum(s) | TypeKind::Scalar(s) | TypeKind::Object(s) => s,
         }
     }
 }
@@ -240,7 +260,16 @@ pub(super) fn create_scalar_update_type(
 pub(super) fn create_filter_types(input_ctx: &InputContext<'_>, scalar: TypeKind<'_>, output_ctx: &mut OutputContext) {
     let type_name = input_ctx.filter_type_name(scalar.as_ref());
     let mut fields = Vec::with_capacity(SCALAR_FILTERS.len() + 2);
-    let scalar = scalar.prefixed(input_ctx);
+    let is_string = matches!(scalar, TypeKind::Scalar("String"));
+    let is_jsonb = matches!(scalar, TypeKind::Scalar("JSON"));
+    let is_simple_json = matches!(scalar, TypeKind::Scalar("SimpleJSON"));
+
+    // the virtual type filters JSON columns, which have no JSONB operations
+    let scalar = if is_simple_json {
+        Cow::Borrowed("JSON")
+    } else {
+        scalar.prefixed(input_ctx)
+    };
 
     for (filter, mapped_name, description) in SCALAR_FILTERS {
         let mut input = MetaInputValue::new(*filter, scalar.as_ref());
@@ -264,6 +293,36 @@ pub(super) fn create_filter_types(input_ctx: &InputContext<'_>, scalar: TypeKind
         input
     });
 
+    if is_string {
+        for (filter, description) in STRING_FILTERS {
+            let mut input = MetaInputValue::new(*filter, "String");
+            input.description = Some(String::from(*description));
+
+            fields.push(input);
+        }
+    }
+
+    if is_jsonb {
+        fields.push({
+            let mut input = MetaInputValue::new(INPUT_FIELD_OP_CONTAINS, scalar.as_ref());
+            input.description = Some(String::from(
+                "The value contains the given JSON value at the top level.",
+            ));
+
+            input
+        });
+
+        fields.push({
+            let mut input = MetaInputValue::new(INPUT_FIELD_OP_PATH, "String");
+
+            input.description = Some(String::from(
+                "The given SQL/JSON path expression returns at least one item for the value.",
+            ));
+
+            input
+        });
+    }
+
     fields.push(MetaInputValue::new(INPUT_FIELD_OP_NOT, type_name.as_str()));
 
     let description = format!("Search filter input for {scalar} type.");
@@ -271,6 +330,11 @@ pub(super) fn create_filter_types(input_ctx: &InputContext<'_>, scalar: TypeKind
 
     output_ctx.create_input_type(input_type);
 
+    // arrays of JSON values use the JSON array filter
+    if is_simple_json {
+        return;
+    }
+
     let type_name = input_ctx.filter_type_name(&format!("{scalar}Array"));
     let mut fields = Vec::with_capacity(SCALAR_FILTERS.len() + 2);
 
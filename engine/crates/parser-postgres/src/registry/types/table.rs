@@ -2,7 +2,7 @@ use engine::registry::{
     resolvers::{transformer::Transformer, Resolver},
     Constraint, InputObjectType, MetaField, MetaInputValue, ObjectType,
 };
-use postgres_connector_types::database_definition::{DatabaseType, RelationWalker, TableColumnWalker, TableWalker};
+use postgres_connector_types::database_definition::{RelationWalker, TableColumnWalker, TableWalker};
 
 use crate::registry::context::{InputContext, ObjectTypeBuilder, OutputContext};
 
@@ -110,18 +110,7 @@ fn add_column(input_ctx: &InputContext<'_>, column: TableColumnWalker<'_>, build
     let mut field = MetaField::new(column.client_name(), client_type.as_ref());
     field.mapped_name = Some(column.database_name().to_string());
 
-    field.resolver = Resolver::Transformer(Transformer::Select {
-        key: column.database_name().to_string(),
-    });
-
-    let extra_transformer = match column.database_type() {
-        DatabaseType::Enum(_) => Some(Transformer::RemoteEnum),
-        _ => None,
-    };
-
-    if let Some(transformer) = extra_transformer {
-        field.resolver = field.resolver.and_then(transformer);
-    }
+    field.resolver = super::column_resolver(column.database_name(), column.database_type());
 
     builder.push_scalar_field(field, column.id());
 }
@@ -233,9 +222,21 @@ fn register_orderby_input(
 ) {
     let type_name = input_ctx.orderby_input_type_name(table.client_name());
 
-    let input_fields = table
-        .columns()
-        .map(|column| MetaInputValue::new(column.client_name().to_string(), direction_type));
+    let rank_type = super::text_search::rank_input_type_name(input_ctx);
+
+    // composite values have no ordering we could expose
+    let columns = table.columns().filter(|column| !column.database_type().is_composite());
+
+    let input_fields = columns.map(|column| {
+        // Full-text search documents are ordered by how well they match a query.
+        let r#type = if column.database_type().is_tsvector() {
+            rank_type.as_str()
+        } else {
+            direction_type
+        };
+
+        MetaInputValue::new(column.client_name().to_string(), r#type)
+    });
 
     let input_object = InputObjectType::new(type_name.to_string(), input_fields).with_oneof(true);
 
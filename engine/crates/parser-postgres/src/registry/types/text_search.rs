@@ -0,0 +1,83 @@
+use engine::registry::{InputObjectType, MetaInputValue};
+use parser_sdl::{INPUT_FIELD_OP_MATCHES, INPUT_FIELD_OP_NOT};
+
+use crate::registry::context::{InputContext, OutputContext};
+
+const QUERY_INPUT: &str = "TextSearchQueryInput";
+const RANK_INPUT: &str = "TextSearchRankInput";
+
+/// The name of the input type ordering a collection by the rank of a full-text search match.
+pub(super) fn rank_input_type_name(input_ctx: &InputContext<'_>) -> String {
+    input_ctx.type_name(RANK_INPUT)
+}
+
+/// Registers the input types for full-text search over `tsvector` columns.
+pub(super) fn register(input_ctx: &InputContext<'_>, direction_type: &str, output_ctx: &mut OutputContext) {
+    let query_type_name = input_ctx.type_name(QUERY_INPUT);
+
+    let input_type = InputObjectType::new(query_type_name.clone(), query_fields())
+        .with_description(Some(String::from("A full-text search query.")));
+
+    output_ctx.create_input_type(input_type);
+
+    let type_name = input_ctx.filter_type_name("TsVector");
+    let mut fields = Vec::with_capacity(2);
+
+    fields.push({
+        let mut input = MetaInputValue::new(INPUT_FIELD_OP_MATCHES, query_type_name.as_str());
+        input.description = Some(String::from("The document matches the given search query"));
+
+        input
+    });
+
+    fields.push(MetaInputValue::new(INPUT_FIELD_OP_NOT, type_name.as_str()));
+
+    let input_type = InputObjectType::new(type_name, fields).with_description(Some(String::from(
+        "Search filter input for full-text search documents.",
+    )));
+
+    output_ctx.create_input_type(input_type);
+
+    let mut fields = query_fields();
+
+    fields.push({
+        let mut input = MetaInputValue::new("direction", direction_type);
+        input.description = Some(String::from(
+            "The direction of the ordering, by default the best match first.",
+        ));
+
+        input
+    });
+
+    let input_type = InputObjectType::new(rank_input_type_name(input_ctx), fields).with_description(Some(
+        String::from("Orders the collection by how well the document matches the given search query."),
+    ));
+
+    output_ctx.create_input_type(input_type);
+}
+
+fn query_fields() -> Vec<MetaInputValue> {
+    let mut fields = Vec::with_capacity(3);
+
+    fields.push({
+        let mut input = MetaInputValue::new("query", "String!");
+
+        input.description = Some(String::from(
+            "The search query in web search syntax: quoted phrases, OR and - for exclusion are supported.",
+        ));
+
+        input
+    });
+
+    fields.push({
+        let mut input = MetaInputValue::new("language", "String");
+
+        input.description = Some(String::from(
+            "The text search configuration used to parse the query, such as english. Defaults to the database setting.",
+        ));
+
+        input
+    });
+
+    fields
+}
@@ -70,6 +70,11 @@ pub(crate) fn merge_registry(ctx: &mut VisitorContext<'_>, mut src_registry: Reg
             .extend(type_fields(src_registry.types.remove(mutation_type).unwrap()));
     }
 
+    if let Some(subscription_type) = &src_registry.subscription_type {
+        ctx.subscriptions
+            .extend(type_fields(src_registry.types.remove(subscription_type).unwrap()));
+    }
+
     // The parser relies on `ctx.types` in a few places, which contains parsed SDL
     // TypeDefinitions (rather than the processed MetaTypes that our connectors give
     // us).  We hackishly fake these TypeDefinitions here to work around that
@@ -82,9 +87,21 @@ pub(crate) fn merge_registry(ctx: &mut VisitorContext<'_>, mut src_registry: Reg
 
     let mut main_registry = ctx.registry.borrow_mut();
 
-    // I am sort of making the assumption that connectors won't generate names that
-    // clash with each other here.  This should be the case with the type prefixes
-    // in openapi but might need revisited with other connectors.
+    // Connectors shouldn't generate names that clash with each other thanks to the type
+    // prefixes, unless types were renamed to the same name on purpose to merge them. That only
+    // works if they've got the same shape.
+    let conflicting_types = src_registry
+        .types
+        .values()
+        .filter(|ty| {
+            main_registry
+                .types
+                .get(ty.name())
+                .is_some_and(|existing| !have_same_shape(existing, ty))
+        })
+        .map(|ty| ty.name().to_string())
+        .collect::<Vec<_>>();
+
     main_registry.types.extend(src_registry.types);
 
     main_registry.implements.extend(src_registry.implements);
@@ -97,6 +114,42 @@ pub(crate) fn merge_registry(ctx: &mut VisitorContext<'_>, mut src_registry: Reg
     // There are other fields on a Registry, but I think these are the only
     // ones likely to be touched by connectors for now.  We can look to update
     // this later as we add more connectors.
+
+    drop(main_registry);
+    for name in conflicting_types {
+        ctx.report_error(
+            vec![position],
+            format!("The type {name} already exists with a different shape and can't be merged"),
+        );
+    }
+}
+
+/// Merged types must have the same fields, read from the same upstream fields.
+fn have_same_shape(left: &MetaType, right: &MetaType) -> bool {
+    match (left, right) {
+        (MetaType::Object(_) | MetaType::Interface(_), MetaType::Object(_) | MetaType::Interface(_)) => {
+            let (Some(left), Some(right)) = (left.fields(), right.fields()) else {
+                return false;
+            };
+            left.len() == right.len()
+                && left.values().all(|field| {
+                    right.get(&field.name).is_some_and(|other| {
+                        field.ty == other.ty && field.target_field_name() == other.target_field_name()
+                    })
+                })
+        }
+        (MetaType::InputObject(left), MetaType::InputObject(right)) => {
+            left.input_fields.len() == right.input_fields.len()
+                && left.input_fields.values().all(|field| {
+                    right
+                        .input_fields
+                        .get(&field.name)
+                        .is_some_and(|other| field.ty == other.ty && field.rename == other.rename)
+                })
+        }
+        // Scalars and enums are shared freely between connectors.
+        _ => true,
+    }
 }
 
 #[allow(clippy::panic)]
@@ -1,6 +1,7 @@
 use std::collections::BTreeMap;
 
 use engine::registry::ConnectorHeaderValue;
+use runtime::rate_limiting::RateLimitRule;
 
 /// Configuration for a federated graph
 #[derive(Clone, Debug, Default)]
@@ -8,6 +9,21 @@ pub struct FederatedGraphConfig {
     pub subgraphs: BTreeMap<String, SubgraphConfig>,
 
     pub default_headers: Vec<(String, SubgraphHeaderValue)>,
+
+    pub operation_limits: OperationLimits,
+
+    pub rate_limits: Vec<RateLimitRule>,
+}
+
+/// Limits applied to every operation before it's executed, none by default
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OperationLimits {
+    pub depth: Option<u16>,
+    pub aliases: Option<u16>,
+    pub root_fields: Option<u16>,
+    pub complexity: Option<u16>,
+    pub tokens: Option<u32>,
+    pub parser_recursion: Option<u16>,
 }
 
 /// Configuration for a subgraph of the current federated graph
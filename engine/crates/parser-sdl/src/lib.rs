@@ -23,6 +23,7 @@ use rules::{
     check_type_validity::CheckTypeValidity,
     check_types_underscore::CheckBeginsWithDoubleUnderscore,
     connector_transforms::run_transforms,
+    cost_directive::CostDirective,
     default_directive::DefaultDirective,
     default_directive_types::DefaultDirectiveTypes,
     deprecated_directive::DeprecatedDirective,
@@ -42,17 +43,21 @@ use rules::{
     length_directive::LengthDirective,
     map_directive::MapDirective,
     model_directive::ModelDirective,
-    mongodb_directive::{MongoDBModelDirective, MongoDBTypeDirective},
+    mongodb_directive::{MongoDBLookupDirective, MongoDBModelDirective, MongoDBTypeDirective},
     one_of_directive::OneOfDirective,
     openapi_directive::OpenApiVisitor,
+    operation_limits_directive::{OperationLimitsDirective, OperationLimitsDirectiveVisitor},
     postgres_directive::PostgresVisitor,
+    rate_limit_directive::{RateLimitDirective, RateLimitDirectiveVisitor},
     relations::{relations_rules, RelationEngine},
     requires_directive::RequiresDirective,
     resolver_directive::ResolverDirective,
     search_directive::SearchDirective,
     subgraph_directive::{SubgraphDirective, SubgraphDirectiveVisitor},
+    telemetry_directive::TelemetryDirectiveVisitor,
     unique_directive::UniqueDirective,
     unique_fields::UniqueObjectFields,
+    validation_directives::ValidationDirectives,
     visitor::{visit, RuleError, Visitor, VisitorContext},
 };
 
@@ -71,6 +76,9 @@ pub use rules::{
     mongodb_directive::MongoDBDirective,
     openapi_directive::{OpenApiDirective, OpenApiQueryNamingStrategy, OpenApiTransforms},
     postgres_directive::PostgresDirective,
+    telemetry_directive::{
+        TelemetryDirective, TelemetryExporter, TelemetryHeader, TelemetryMetrics, TelemetryProtocol, TelemetryTracing,
+    },
 };
 use validations::post_parsing_validations;
 
@@ -130,6 +138,7 @@ pub struct ParseResult<'a> {
     pub required_udfs: HashSet<(UdfKind, String)>,
     pub global_cache_rules: GlobalCacheRules<'a>,
     pub federated_graph_config: Option<federation::FederatedGraphConfig>,
+    pub telemetry: Option<TelemetryDirective>,
 }
 
 fn parse_schema(schema: &str) -> engine::parser::Result<ServiceDocument> {
@@ -138,6 +147,7 @@ fn parse_schema(schema: &str) -> engine::parser::Result<ServiceDocument> {
         .with::<DefaultDirective>()
         .with::<MapDirective>()
         .with::<LengthDirective>()
+        .with::<ValidationDirectives>()
         .with::<ModelDirective>()
         .with::<OneOfDirective>()
         .with::<RelationEngine>()
@@ -149,6 +159,7 @@ fn parse_schema(schema: &str) -> engine::parser::Result<ServiceDocument> {
         .with::<GraphqlDirective>()
         .with::<CacheDirective>()
         .with::<MongoDBDirective>()
+        .with::<MongoDBLookupDirective>()
         .with::<PostgresDirective>()
         .with::<ExperimentalDirective>()
         .with::<FederationDirective>()
@@ -164,7 +175,11 @@ fn parse_schema(schema: &str) -> engine::parser::Result<ServiceDocument> {
         .with::<TagDirective>()
         .with::<ExtendFieldDirective>()
         .with::<SubgraphDirective>()
-        .with::<AllSubgraphsDirective>();
+        .with::<AllSubgraphsDirective>()
+        .with::<OperationLimitsDirective>()
+        .with::<RateLimitDirective>()
+        .with::<CostDirective>()
+        .with::<TelemetryDirective>();
 
     let schema = format!(
         "{}\n{}\n{}\n{}",
@@ -242,7 +257,8 @@ async fn parse_connectors<'a>(
         .with(OpenApiVisitor)
         .with(GraphqlVisitor)
         .with(MongoDBVisitor)
-        .with(PostgresVisitor);
+        .with(PostgresVisitor)
+        .with(RateLimitDirectiveVisitor);
 
     visit(&mut connector_rules, ctx, schema);
 
@@ -256,7 +272,8 @@ async fn parse_connectors<'a>(
         match connector_parsers.fetch_and_parse_openapi(directive).await {
             Ok(mut registry) => {
                 if let Some(transforms) = &transforms {
-                    run_transforms(&mut registry, transforms);
+                    run_transforms(&mut registry, transforms)
+                        .map_err(|error| Error::ConnectorErrors(directive_name, vec![error], position))?;
                 }
                 connector_parsers::merge_registry(ctx, registry, position);
             }
@@ -270,7 +287,8 @@ async fn parse_connectors<'a>(
         match connector_parsers.fetch_and_parse_graphql(directive).await {
             Ok(mut registry) => {
                 if let Some(transforms) = &transforms {
-                    run_transforms(&mut registry, transforms);
+                    run_transforms(&mut registry, transforms)
+                        .map_err(|error| Error::ConnectorErrors(directive_name, vec![error], position))?;
                 }
                 connector_parsers::merge_registry(ctx, registry, position);
             }
@@ -355,13 +373,16 @@ fn parse_types<'a>(schema: &'a ServiceDocument, ctx: &mut VisitorContext<'a>) {
         .with(MongoDBTypeDirective)
         .with(MongoDBModelDirective)
         .with(LengthDirective)
+        .with(ValidationDirectives)
         .with(UniqueObjectFields)
         .with(CheckAllDirectivesAreKnown::default())
         .with(ExperimentalDirectiveVisitor)
         .with(FederationDirectiveVisitor) // This will likely need moved.  Here'll do for now though
         .with(ExtendFieldVisitor)
         .with(SubgraphDirectiveVisitor)
-        .with(AllSubgraphsDirectiveVisitor);
+        .with(AllSubgraphsDirectiveVisitor)
+        .with(OperationLimitsDirectiveVisitor)
+        .with(TelemetryDirectiveVisitor);
 
     visit(&mut rules, ctx, schema);
 }
@@ -410,7 +410,7 @@ fn register_input(
                         })
                         .to_string()
                         .into(),
-                        validators: super::get_length_validator(&field.node).map(|val| vec![val]),
+                        validators: super::get_validators(&field.node.directives, &field.node.ty.node),
                         visible: None,
                         default_value: (if mutation_kind.is_update() {
                             None
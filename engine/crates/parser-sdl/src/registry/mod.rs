@@ -8,13 +8,15 @@ use case::CaseExt;
 use engine::{
     registry::{self, enums::EngineEnum, relations::MetaRelation, MetaEnumValue, MetaInputValue, NamedType, Registry},
     validation::dynamic_validators::DynValidator,
+    Positioned,
 };
-use engine_parser::types::{FieldDefinition, ObjectType, TypeDefinition};
+use engine_parser::types::{ConstDirective, ObjectType, Type, TypeDefinition};
 
 use crate::{
     registry::names::MetaNames,
     rules::{
         length_directive::{LENGTH_DIRECTIVE, MAX_ARGUMENT, MIN_ARGUMENT},
+        validation_directives::ValidationDirectives,
         visitor::VisitorContext,
     },
     utils::{to_base_type_str, to_input_type},
@@ -99,10 +101,24 @@ pub fn add_input_type_non_primitive(ctx: &mut VisitorContext<'_>, object: &Objec
     input_type
 }
 
-pub fn get_length_validator(field: &FieldDefinition) -> Option<DynValidator> {
+/// The validators defined by the directives of a field, an argument or an input field. Invalid
+/// directives are reported by their rules.
+pub fn get_validators(directives: &[Positioned<ConstDirective>], ty: &Type) -> Option<Vec<DynValidator>> {
+    let validators: Vec<_> = get_length_validator(directives)
+        .into_iter()
+        .chain(
+            directives
+                .iter()
+                .filter_map(|directive| ValidationDirectives::validator(directive, ty).ok().flatten()),
+        )
+        .collect();
+
+    (!validators.is_empty()).then_some(validators)
+}
+
+fn get_length_validator(directives: &[Positioned<ConstDirective>]) -> Option<DynValidator> {
     use tuple::Map;
-    field
-        .directives
+    directives
         .iter()
         .find(|directive| directive.node.name.node == LENGTH_DIRECTIVE)
         .map(|directive| {
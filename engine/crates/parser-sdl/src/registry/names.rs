@@ -21,10 +21,20 @@ pub const PAGINATION_FIELD_PAGE_INFO: &str = "pageInfo";
 pub const PAGINATION_FIELD_EDGE_NODE: &str = "node";
 pub const PAGINATION_FIELD_EDGE_CURSOR: &str = "cursor";
 pub const PAGINATION_FIELD_EDGE_SEARCH_SCORE: &str = "score";
+pub const PAGINATION_FIELD_EDGE_SEARCH_HIGHLIGHTS: &str = "highlights";
+pub const PAGINATION_FIELD_SEARCH_FACETS: &str = "facets";
 
 pub const SEARCH_INFO_TYPE: &str = "SearchInfo";
 pub const SEARCH_INFO_FIELD_TOTAL_HITS: &str = "totalHits";
 
+pub const SEARCH_HIGHLIGHT_TYPE: &str = "SearchHighlight";
+pub const SEARCH_HIGHLIGHT_FIELD_FIELD: &str = "field";
+pub const SEARCH_HIGHLIGHT_FIELD_SNIPPET: &str = "snippet";
+
+pub const SEARCH_VECTOR_INPUT: &str = "SearchVectorInput";
+pub const SEARCH_TERMS_FACET_INPUT: &str = "SearchTermsFacetInput";
+pub const SEARCH_TERMS_FACET_BUCKET_TYPE: &str = "SearchTermsFacetBucket";
+
 pub const PAGE_INFO_TYPE: &str = "PageInfo";
 pub const PAGE_INFO_FIELD_HAS_PREVIOUS_PAGE: &str = "hasPreviousPage";
 pub const PAGE_INFO_FIELD_HAS_NEXT_PAGE: &str = "hasNextPage";
@@ -36,6 +46,9 @@ pub const INPUT_ARG_INPUT: &str = "input";
 pub const INPUT_ARG_FILTER: &str = "filter";
 pub const INPUT_ARG_QUERY: &str = "query";
 pub const INPUT_ARG_FIELDS: &str = "fields";
+pub const INPUT_ARG_FUZZINESS: &str = "fuzziness";
+pub const INPUT_ARG_FACETS: &str = "facets";
+pub const INPUT_ARG_VECTOR: &str = "vector";
 
 pub const INPUT_FIELD_RELATION_CREATE: &str = "create";
 pub const INPUT_FIELD_RELATION_LINK: &str = "link";
@@ -64,6 +77,12 @@ pub const INPUT_FIELD_OP_NOT: &str = "not";
 pub const INPUT_FIELD_OP_CONTAINS: &str = "contains";
 pub const INPUT_FIELD_OP_CONTAINED: &str = "contained";
 pub const INPUT_FIELD_OP_OVERLAPS: &str = "overlaps";
+pub const INPUT_FIELD_OP_LIKE: &str = "like";
+pub const INPUT_FIELD_OP_ILIKE: &str = "ilike";
+pub const INPUT_FIELD_OP_REGEX: &str = "regex";
+pub const INPUT_FIELD_OP_IREGEX: &str = "iregex";
+pub const INPUT_FIELD_OP_PATH: &str = "path";
+pub const INPUT_FIELD_OP_MATCHES: &str = "matches";
 
 pub const ORDER_BY_DIRECTION: &str = "OrderByDirection";
 pub const ORDER_BY_ASC: &str = "ASC";
@@ -137,6 +156,30 @@ impl MetaNames {
         format!("{}SearchEdge", Self::model(model_type_definition))
     }
 
+    pub fn search_facets_input(model_type_definition: &TypeDefinition) -> String {
+        format!("{}SearchFacetsInput", Self::model(model_type_definition))
+    }
+
+    pub fn search_order_by_input(model_type_definition: &TypeDefinition) -> String {
+        format!("{}SearchOrderByInput", Self::model(model_type_definition))
+    }
+
+    pub fn search_vector_input(model_type_definition: &TypeDefinition) -> String {
+        format!("{}SearchVectorInput", Self::model(model_type_definition))
+    }
+
+    pub fn search_facets_type(model_type_definition: &TypeDefinition) -> String {
+        format!("{}SearchFacets", Self::model(model_type_definition))
+    }
+
+    pub fn search_range_facet_input(scalar: &str) -> String {
+        format!("{scalar}SearchRangeFacetInput")
+    }
+
+    pub fn search_range_facet_bucket_type(scalar: &str) -> String {
+        format!("{scalar}SearchRangeFacetBucket")
+    }
+
     //
     // COLLECTION
     //
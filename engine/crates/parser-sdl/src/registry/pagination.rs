@@ -239,6 +239,7 @@ pub fn add_query_paginated_collection(
         shareable: false,
         inaccessible: false,
         tags: vec![],
+        cost: None,
     });
 }
 
@@ -2,11 +2,19 @@ use std::collections::HashMap;
 
 use common_types::auth::Operations;
 use engine::{
-    names::{INPUT_FIELD_FILTER_REGEX, OUTPUT_EDGE_CURSOR},
+    names::{
+        INPUT_FIELD_FACET_BOUNDARIES, INPUT_FIELD_FACET_LIMIT, INPUT_FIELD_FILTER_REGEX, INPUT_FIELD_VECTOR_K,
+        INPUT_FIELD_VECTOR_VECTOR, INPUT_FIELD_VECTOR_WEIGHT, OUTPUT_EDGE_CURSOR, OUTPUT_FIELD_FACET_BUCKET_COUNT,
+        OUTPUT_FIELD_FACET_BUCKET_GTE, OUTPUT_FIELD_FACET_BUCKET_LT, OUTPUT_FIELD_FACET_BUCKET_VALUE,
+    },
     registry::{
         self,
+        enums::OrderByDirection,
         resolvers::{
-            query::{QueryResolver, SEARCH_RESOLVER_EDGES, SEARCH_RESOLVER_EDGE_SCORE, SEARCH_RESOLVER_TOTAL_HITS},
+            query::{
+                QueryResolver, SEARCH_RESOLVER_EDGES, SEARCH_RESOLVER_EDGE_HIGHLIGHTS, SEARCH_RESOLVER_EDGE_SCORE,
+                SEARCH_RESOLVER_FACETS, SEARCH_RESOLVER_TOTAL_HITS,
+            },
             transformer::Transformer,
             Resolver,
         },
@@ -23,26 +31,50 @@ use crate::{
     registry::{
         generate_pagination_args,
         names::{
-            MetaNames, INPUT_ARG_FIELDS, INPUT_ARG_FILTER, INPUT_ARG_QUERY, INPUT_FIELD_FILTER_ALL,
-            INPUT_FIELD_FILTER_ANY, INPUT_FIELD_FILTER_EQ, INPUT_FIELD_FILTER_GT, INPUT_FIELD_FILTER_GTE,
-            INPUT_FIELD_FILTER_IN, INPUT_FIELD_FILTER_IS_NULL, INPUT_FIELD_FILTER_LIST_INCLUDES,
-            INPUT_FIELD_FILTER_LIST_INCLUDES_NONE, INPUT_FIELD_FILTER_LIST_IS_EMPTY, INPUT_FIELD_FILTER_LT,
-            INPUT_FIELD_FILTER_LTE, INPUT_FIELD_FILTER_NEQ, INPUT_FIELD_FILTER_NONE, INPUT_FIELD_FILTER_NOT,
-            INPUT_FIELD_FILTER_NOT_IN, PAGINATION_FIELD_EDGES, PAGINATION_FIELD_EDGE_CURSOR,
-            PAGINATION_FIELD_EDGE_NODE, PAGINATION_FIELD_EDGE_SEARCH_SCORE, PAGINATION_FIELD_PAGE_INFO,
-            PAGINATION_FIELD_SEARCH_INFO, PAGINATION_INPUT_ARG_AFTER, PAGINATION_INPUT_ARG_BEFORE,
-            PAGINATION_INPUT_ARG_FIRST, PAGINATION_INPUT_ARG_LAST, SEARCH_INFO_FIELD_TOTAL_HITS, SEARCH_INFO_TYPE,
+            MetaNames, INPUT_ARG_FACETS, INPUT_ARG_FIELDS, INPUT_ARG_FILTER, INPUT_ARG_FUZZINESS, INPUT_ARG_QUERY,
+            INPUT_ARG_VECTOR, INPUT_FIELD_FILTER_ALL, INPUT_FIELD_FILTER_ANY, INPUT_FIELD_FILTER_EQ,
+            INPUT_FIELD_FILTER_GT, INPUT_FIELD_FILTER_GTE, INPUT_FIELD_FILTER_IN, INPUT_FIELD_FILTER_IS_NULL,
+            INPUT_FIELD_FILTER_LIST_INCLUDES, INPUT_FIELD_FILTER_LIST_INCLUDES_NONE, INPUT_FIELD_FILTER_LIST_IS_EMPTY,
+            INPUT_FIELD_FILTER_LT, INPUT_FIELD_FILTER_LTE, INPUT_FIELD_FILTER_NEQ, INPUT_FIELD_FILTER_NONE,
+            INPUT_FIELD_FILTER_NOT, INPUT_FIELD_FILTER_NOT_IN, PAGINATION_FIELD_EDGES, PAGINATION_FIELD_EDGE_CURSOR,
+            PAGINATION_FIELD_EDGE_NODE, PAGINATION_FIELD_EDGE_SEARCH_HIGHLIGHTS, PAGINATION_FIELD_EDGE_SEARCH_SCORE,
+            PAGINATION_FIELD_PAGE_INFO, PAGINATION_FIELD_SEARCH_FACETS, PAGINATION_FIELD_SEARCH_INFO,
+            PAGINATION_INPUT_ARG_AFTER, PAGINATION_INPUT_ARG_BEFORE, PAGINATION_INPUT_ARG_FIRST,
+            PAGINATION_INPUT_ARG_LAST, PAGINATION_INPUT_ARG_ORDER_BY, SEARCH_HIGHLIGHT_FIELD_FIELD,
+            SEARCH_HIGHLIGHT_FIELD_SNIPPET, SEARCH_HIGHLIGHT_TYPE, SEARCH_INFO_FIELD_TOTAL_HITS, SEARCH_INFO_TYPE,
+            SEARCH_TERMS_FACET_BUCKET_TYPE, SEARCH_TERMS_FACET_INPUT, SEARCH_VECTOR_INPUT,
         },
+        register_engine_enum,
     },
     rules::{
         cache_directive::CacheDirective,
         model_directive::{METADATA_FIELD_CREATED_AT, METADATA_FIELD_UPDATED_AT},
-        search_directive::SEARCH_DIRECTIVE,
+        search_directive::{SearchDirective, SEARCH_DIRECTIVE},
         visitor::VisitorContext,
     },
     type_names::TypeNameExt,
 };
 
+enum FacetKind {
+    Terms,
+    Range { scalar: &'static str },
+}
+
+impl FacetKind {
+    fn from_field_type(ty: &search::FieldType) -> Option<Self> {
+        use search::FieldType::*;
+        Some(match ty {
+            URL(_) | Email(_) | PhoneNumber(_) | String(_) => FacetKind::Terms,
+            Int(_) => FacetKind::Range { scalar: "Int" },
+            Float(_) => FacetKind::Range { scalar: "Float" },
+            Date(_) => FacetKind::Range { scalar: "Date" },
+            DateTime(_) => FacetKind::Range { scalar: "DateTime" },
+            Timestamp(_) => FacetKind::Range { scalar: "Timestamp" },
+            Boolean(_) | IPAddress(_) | Vector(_) => return None,
+        })
+    }
+}
+
 enum FilterKind {
     Single { scalar: String, is_nullable: bool },
     List { scalar: String },
@@ -112,6 +144,47 @@ fn convert_to_search_field_type(
     }
 }
 
+/// Text fields use the analyzer of their own @search directive, otherwise the one of the model.
+/// [Float] fields with dimensions are vectors.
+fn build_field_entry(
+    field: &FieldDefinition,
+    field_type: &str,
+    ty: search::FieldType,
+    model_analyzer: Option<search::Analyzer>,
+) -> search::FieldEntry {
+    let ty = match (ty, SearchDirective::dimensions(&field.directives)) {
+        (search::FieldType::Float(_), Some(dimensions)) => search::FieldType::Vector(search::VectorOptions {
+            nullable: field.ty.node.nullable,
+            dimensions,
+        }),
+        (ty, _) => ty,
+    };
+    let analyzer = if ty.is_text() {
+        SearchDirective::analyzer(&field.directives)
+            .or(model_analyzer)
+            .unwrap_or_default()
+    } else {
+        search::Analyzer::default()
+    };
+    // Ordering by a list has no obvious meaning.
+    let sortable = !matches!(FilterKind::from(field_type), FilterKind::List { .. })
+        && match ty {
+            search::FieldType::Int(_)
+            | search::FieldType::Float(_)
+            | search::FieldType::Date(_)
+            | search::FieldType::DateTime(_)
+            | search::FieldType::Timestamp(_) => true,
+            search::FieldType::URL(_) | search::FieldType::Email(_) | search::FieldType::String(_) => {
+                analyzer == search::Analyzer::Keyword
+            }
+            search::FieldType::PhoneNumber(_)
+            | search::FieldType::Boolean(_)
+            | search::FieldType::IPAddress(_)
+            | search::FieldType::Vector(_) => false,
+        };
+    search::FieldEntry { ty, analyzer, sortable }
+}
+
 pub fn build_search_schema(
     ctx: &mut VisitorContext<'_>,
     model_type_definition: &TypeDefinition,
@@ -122,20 +195,27 @@ pub fn build_search_schema(
         .iter()
         .any(|directive| directive.is_search())
     {
+        let model_analyzer = SearchDirective::analyzer(&model_type_definition.directives);
         let mut search_fields: HashMap<String, search::FieldEntry> = fields
             .iter()
             .filter_map(|field| {
-                convert_to_search_field_type(&ctx.registry.borrow(), &field.node.ty.node.to_string(), None)
+                let field_type = field.node.ty.node.to_string();
+                convert_to_search_field_type(&ctx.registry.borrow(), &field_type, None)
                     .ok()
-                    .map(|ty| (field.node.name.node.to_string(), search::FieldEntry { ty }))
+                    .map(|ty| {
+                        (
+                            field.node.name.node.to_string(),
+                            build_field_entry(&field.node, &field_type, ty, model_analyzer),
+                        )
+                    })
             })
             .collect();
-        let ty = search::FieldType::DateTime(search::FieldOptions { nullable: false });
-        search_fields.insert(
-            METADATA_FIELD_CREATED_AT.to_string(),
-            search::FieldEntry { ty: ty.clone() },
-        );
-        search_fields.insert(METADATA_FIELD_UPDATED_AT.to_string(), search::FieldEntry { ty });
+        let entry = search::FieldEntry {
+            sortable: true,
+            ..search::FieldEntry::new(search::FieldType::DateTime(search::FieldOptions { nullable: false }))
+        };
+        search_fields.insert(METADATA_FIELD_CREATED_AT.to_string(), entry.clone());
+        search_fields.insert(METADATA_FIELD_UPDATED_AT.to_string(), entry);
         search_fields
     } else {
         let (search_fields, errors): (HashMap<_, _>, Vec<_>) = fields
@@ -147,10 +227,14 @@ pub fn build_search_schema(
                     .iter()
                     .find(|directive| directive.is_search())
                     .map(|directive| {
-                        let field_type =
-                            convert_to_search_field_type(&ctx.registry.borrow(), &field.node.ty.node.to_string(), None);
-                        field_type
-                            .map(|ty| (field.node.name.node.to_string(), search::FieldEntry { ty }))
+                        let field_type = field.node.ty.node.to_string();
+                        convert_to_search_field_type(&ctx.registry.borrow(), &field_type, None)
+                            .map(|ty| {
+                                (
+                                    field.node.name.node.to_string(),
+                                    build_field_entry(&field.node, &field_type, ty, None),
+                                )
+                            })
                             .map_err(|unsupported_type_name| {
                                 ctx.report_error(
                                     vec![directive.pos],
@@ -189,7 +273,12 @@ pub fn add_query_search(
             .iter()
             .filter_map(|field| {
                 let name = field.node.name.node.to_string();
-                if schema.fields.contains_key(&name) {
+                // Vectors are only used for nearest neighbours search.
+                if schema
+                    .fields
+                    .get(&name)
+                    .is_some_and(|entry| !matches!(entry.ty, search::FieldType::Vector(_)))
+                {
                     Some((name, FilterKind::from(field.node.ty.node.to_string().as_str())))
                 } else {
                     None
@@ -217,6 +306,30 @@ pub fn add_query_search(
 
         field_filters
     };
+    let field_facets = {
+        let mut field_facets = schema
+            .fields
+            .iter()
+            .filter_map(|(name, entry)| FacetKind::from_field_type(&entry.ty).map(|kind| (name.clone(), kind)))
+            .collect::<Vec<_>>();
+        // Stable schema
+        field_facets.sort_by(|(a, _), (b, _)| a.cmp(b));
+        field_facets
+    };
+    let sortable_fields = schema
+        .fields
+        .iter()
+        .filter(|(_, entry)| entry.sortable)
+        .map(|(name, _)| name.clone())
+        .sorted()
+        .collect::<Vec<_>>();
+    let vector_fields = schema
+        .fields
+        .iter()
+        .filter(|(_, entry)| matches!(entry.ty, search::FieldType::Vector(_)))
+        .map(|(name, _)| name.clone())
+        .sorted()
+        .collect::<Vec<_>>();
 
     ctx.registry
         .get_mut()
@@ -224,14 +337,28 @@ pub fn add_query_search(
         .indices
         .insert(entity_type.clone(), search::IndexConfig { schema });
 
-    let connection_type = register_connection_type(ctx.registry.get_mut(), model_type_definition, model_auth);
+    let facets_types = if field_facets.is_empty() {
+        None
+    } else {
+        Some(register_facets_types(
+            ctx.registry.get_mut(),
+            model_type_definition,
+            field_facets,
+        ))
+    };
+    let connection_type = register_connection_type(
+        ctx.registry.get_mut(),
+        model_type_definition,
+        model_auth,
+        facets_types.as_ref().map(|(_, output_type)| output_type.as_str()),
+    );
     ctx.queries.push(MetaField {
         name: MetaNames::query_search(model_type_definition),
         mapped_name: None,
         description: Some(format!("Search `{type_name}`")),
         args: {
             let mut pagination_args = generate_pagination_args(ctx.registry.get_mut(), model_type_definition);
-            let args = vec![
+            let mut args = vec![
                 MetaInputValue::new(INPUT_ARG_QUERY, "String").with_description("Text to search."),
                 MetaInputValue::new(INPUT_ARG_FIELDS, "[String!]").with_description(concat!(
                     "Fields used for searching. ",
@@ -254,7 +381,39 @@ pub fn add_query_search(
                 pagination_args
                     .remove(PAGINATION_INPUT_ARG_BEFORE)
                     .expect("Has to be present"),
+                MetaInputValue::new(INPUT_ARG_FUZZINESS, "Int").with_description(concat!(
+                    "Maximum number of typos (0, 1 or 2) tolerated per word of the query. ",
+                    "If not specified, words with at least 4 characters tolerate one typo ",
+                    "and words with at least 8 characters two."
+                )),
             ];
+            if let Some((input_type, _)) = facets_types {
+                args.push(
+                    MetaInputValue::new(INPUT_ARG_FACETS, input_type)
+                        .with_description("Fields for which the values of all matching documents are counted."),
+                );
+            }
+            if !sortable_fields.is_empty() {
+                args.push(
+                    MetaInputValue::new(
+                        PAGINATION_INPUT_ARG_ORDER_BY,
+                        register_order_by_input(ctx.registry.get_mut(), model_type_definition, sortable_fields),
+                    )
+                    .with_description("Field by which the results are ordered. Ordered by relevance if not specified."),
+                );
+            }
+            if !vector_fields.is_empty() {
+                args.push(
+                    MetaInputValue::new(
+                        INPUT_ARG_VECTOR,
+                        register_vector_input(ctx.registry.get_mut(), model_type_definition, vector_fields),
+                    )
+                    .with_description(concat!(
+                        "Nearest neighbours search on a vector field, restricted to the documents matching the filter. ",
+                        "Combined with a text query, documents matching either are ranked by a mix of both scores."
+                    )),
+                );
+            }
 
             args.into_iter().map(|input| (input.name.clone(), input)).collect()
         },
@@ -277,6 +436,10 @@ pub fn add_query_search(
             after: VariableResolveDefinition::input_type_name(PAGINATION_INPUT_ARG_AFTER),
             before: VariableResolveDefinition::input_type_name(PAGINATION_INPUT_ARG_BEFORE),
             last: VariableResolveDefinition::input_type_name(PAGINATION_INPUT_ARG_LAST),
+            fuzziness: VariableResolveDefinition::input_type_name(INPUT_ARG_FUZZINESS),
+            facets: VariableResolveDefinition::input_type_name(INPUT_ARG_FACETS),
+            order_by: VariableResolveDefinition::input_type_name(PAGINATION_INPUT_ARG_ORDER_BY),
+            vector: VariableResolveDefinition::input_type_name(INPUT_ARG_VECTOR),
             type_name: type_name.into(),
             entity_type,
         }),
@@ -285,6 +448,7 @@ pub fn add_query_search(
         shareable: false,
         inaccessible: false,
         tags: vec![],
+        cost: None,
     });
 }
 
@@ -292,6 +456,7 @@ fn register_connection_type(
     registry: &mut Registry,
     model_type_definition: &TypeDefinition,
     model_auth: Option<&AuthConfig>,
+    facets_type: Option<&str>,
 ) -> String {
     let type_name = MetaNames::search_connection_type(model_type_definition);
 
@@ -302,34 +467,262 @@ fn register_connection_type(
             let page_info_type = super::pagination::register_page_info_type(registry)
                 .as_non_null()
                 .into();
+            let mut fields = vec![
+                MetaField {
+                    name: PAGINATION_FIELD_PAGE_INFO.to_string(),
+                    ty: page_info_type,
+                    required_operation: Some(Operations::LIST),
+                    auth: model_auth.cloned(),
+                    ..Default::default()
+                },
+                MetaField {
+                    name: PAGINATION_FIELD_SEARCH_INFO.to_string(),
+                    ty: search_info_type.as_nullable().into(),
+                    required_operation: Some(Operations::LIST),
+                    auth: model_auth.cloned(),
+                    ..Default::default()
+                },
+                MetaField {
+                    name: PAGINATION_FIELD_EDGES.to_string(),
+                    ty: format!("[{edge_type}!]!").into(),
+                    required_operation: Some(Operations::LIST),
+                    auth: model_auth.cloned(),
+                    resolver: Transformer::select(SEARCH_RESOLVER_EDGES).into(),
+                    ..Default::default()
+                },
+            ];
+            if let Some(facets_type) = facets_type {
+                fields.push(MetaField {
+                    name: PAGINATION_FIELD_SEARCH_FACETS.to_string(),
+                    ty: facets_type.into(),
+                    required_operation: Some(Operations::LIST),
+                    auth: model_auth.cloned(),
+                    resolver: Transformer::select(SEARCH_RESOLVER_FACETS).into(),
+                    ..Default::default()
+                });
+            }
+            registry::ObjectType::new(type_name.clone(), fields)
+                .with_cache_control(CacheDirective::parse(&model_type_definition.directives))
+                .into()
+        },
+        &type_name,
+        &type_name,
+    );
+
+    type_name
+}
+
+fn register_search_info(registry: &mut Registry) -> NamedType<'static> {
+    let type_name = SEARCH_INFO_TYPE.to_string();
+    registry.create_type(
+        |_| {
+            registry::ObjectType::new(
+                type_name.clone(),
+                [MetaField {
+                    name: SEARCH_INFO_FIELD_TOTAL_HITS.to_string(),
+                    ty: "Int!".into(),
+                    resolver: Transformer::select(SEARCH_RESOLVER_TOTAL_HITS).into(),
+                    ..Default::default()
+                }],
+            )
+            .into()
+        },
+        &type_name,
+        &type_name,
+    );
+
+    type_name.into()
+}
+
+fn register_search_highlight(registry: &mut Registry) -> NamedType<'static> {
+    let type_name = SEARCH_HIGHLIGHT_TYPE.to_string();
+    registry.create_type(
+        |_| {
             registry::ObjectType::new(
                 type_name.clone(),
                 [
                     MetaField {
-                        name: PAGINATION_FIELD_PAGE_INFO.to_string(),
-                        ty: page_info_type,
-                        required_operation: Some(Operations::LIST),
-                        auth: model_auth.cloned(),
+                        name: SEARCH_HIGHLIGHT_FIELD_FIELD.to_string(),
+                        ty: "String!".into(),
+                        resolver: Transformer::select(SEARCH_HIGHLIGHT_FIELD_FIELD).into(),
                         ..Default::default()
                     },
                     MetaField {
-                        name: PAGINATION_FIELD_SEARCH_INFO.to_string(),
-                        ty: search_info_type.as_nullable().into(),
-                        required_operation: Some(Operations::LIST),
-                        auth: model_auth.cloned(),
+                        name: SEARCH_HIGHLIGHT_FIELD_SNIPPET.to_string(),
+                        ty: "String!".into(),
+                        resolver: Transformer::select(SEARCH_HIGHLIGHT_FIELD_SNIPPET).into(),
+                        ..Default::default()
+                    },
+                ],
+            )
+            .into()
+        },
+        &type_name,
+        &type_name,
+    );
+
+    type_name.into()
+}
+
+/// Registers the facets input and output types, returning their names.
+fn register_facets_types(
+    registry: &mut Registry,
+    model_type_definition: &TypeDefinition,
+    field_facets: Vec<(String, FacetKind)>,
+) -> (String, String) {
+    let input_type_name = MetaNames::search_facets_input(model_type_definition);
+    let output_type_name = MetaNames::search_facets_type(model_type_definition);
+    let (inputs, outputs): (Vec<_>, Vec<_>) = field_facets
+        .into_iter()
+        .map(|(name, kind)| {
+            let (input_type, bucket_type) = match kind {
+                FacetKind::Terms => (
+                    register_terms_facet_input(registry),
+                    register_terms_facet_bucket(registry),
+                ),
+                FacetKind::Range { scalar } => (
+                    register_range_facet_input(registry, scalar),
+                    register_range_facet_bucket(registry, scalar),
+                ),
+            };
+            (
+                MetaInputValue::new(name.clone(), input_type),
+                MetaField {
+                    name: name.clone(),
+                    ty: format!("[{bucket_type}!]").into(),
+                    resolver: Transformer::select(&name).into(),
+                    ..Default::default()
+                },
+            )
+        })
+        .unzip();
+
+    registry.create_type(
+        |_| InputObjectType::new(input_type_name.clone(), inputs).into(),
+        &input_type_name,
+        &input_type_name,
+    );
+    registry.create_type(
+        |_| registry::ObjectType::new(output_type_name.clone(), outputs).into(),
+        &output_type_name,
+        &output_type_name,
+    );
+
+    (input_type_name, output_type_name)
+}
+
+fn register_order_by_input(
+    registry: &mut Registry,
+    model_type_definition: &TypeDefinition,
+    sortable_fields: Vec<String>,
+) -> String {
+    let input_type_name = MetaNames::search_order_by_input(model_type_definition);
+    registry.create_type(
+        |registry| {
+            let order_by_direction_type = register_engine_enum::<OrderByDirection>(registry);
+            InputObjectType::new(
+                input_type_name.clone(),
+                sortable_fields
+                    .into_iter()
+                    .map(|name| MetaInputValue::new(name, order_by_direction_type.as_nullable())),
+            )
+            .with_oneof(true)
+            .into()
+        },
+        &input_type_name,
+        &input_type_name,
+    );
+    input_type_name
+}
+
+fn register_vector_input(
+    registry: &mut Registry,
+    model_type_definition: &TypeDefinition,
+    vector_fields: Vec<String>,
+) -> String {
+    let input_type_name = MetaNames::search_vector_input(model_type_definition);
+    registry.create_type(
+        |registry| {
+            let vector_search_input = register_vector_search_input(registry);
+            InputObjectType::new(
+                input_type_name.clone(),
+                vector_fields
+                    .into_iter()
+                    .map(|name| MetaInputValue::new(name, vector_search_input.as_str())),
+            )
+            .with_oneof(true)
+            .into()
+        },
+        &input_type_name,
+        &input_type_name,
+    );
+    input_type_name
+}
+
+fn register_vector_search_input(registry: &mut Registry) -> String {
+    let type_name = SEARCH_VECTOR_INPUT.to_string();
+    registry.create_type(
+        |_| {
+            InputObjectType::new(
+                type_name.clone(),
+                [
+                    MetaInputValue::new(INPUT_FIELD_VECTOR_VECTOR, "[Float!]!")
+                        .with_description("Embedding to compare to, with as many dimensions as the field."),
+                    MetaInputValue::new(INPUT_FIELD_VECTOR_K, "Int")
+                        .with_description("Number of nearest neighbours returned, between 1 and 1000. Defaults to 10."),
+                    MetaInputValue::new(INPUT_FIELD_VECTOR_WEIGHT, "Float").with_description(concat!(
+                        "Weight of the vector similarity against the text score when combined with a query, ",
+                        "between 0 and 1. Defaults to 0.5."
+                    )),
+                ],
+            )
+            .into()
+        },
+        &type_name,
+        &type_name,
+    );
+    type_name
+}
+
+fn register_terms_facet_input(registry: &mut Registry) -> String {
+    let type_name = SEARCH_TERMS_FACET_INPUT.to_string();
+    registry.create_type(
+        |_| {
+            InputObjectType::new(
+                type_name.clone(),
+                [MetaInputValue::new(INPUT_FIELD_FACET_LIMIT, "Int")
+                    .with_description("Maximum number of values returned, the most frequent first. Defaults to 10.")],
+            )
+            .into()
+        },
+        &type_name,
+        &type_name,
+    );
+
+    type_name
+}
+
+fn register_terms_facet_bucket(registry: &mut Registry) -> String {
+    let type_name = SEARCH_TERMS_FACET_BUCKET_TYPE.to_string();
+    registry.create_type(
+        |_| {
+            registry::ObjectType::new(
+                type_name.clone(),
+                [
+                    MetaField {
+                        name: OUTPUT_FIELD_FACET_BUCKET_VALUE.to_string(),
+                        ty: "String!".into(),
+                        resolver: Transformer::select(OUTPUT_FIELD_FACET_BUCKET_VALUE).into(),
                         ..Default::default()
                     },
                     MetaField {
-                        name: PAGINATION_FIELD_EDGES.to_string(),
-                        ty: format!("[{edge_type}!]!").into(),
-                        required_operation: Some(Operations::LIST),
-                        auth: model_auth.cloned(),
-                        resolver: Transformer::select(SEARCH_RESOLVER_EDGES).into(),
+                        name: OUTPUT_FIELD_FACET_BUCKET_COUNT.to_string(),
+                        ty: "Int!".into(),
+                        resolver: Transformer::select(OUTPUT_FIELD_FACET_BUCKET_COUNT).into(),
                         ..Default::default()
                     },
                 ],
             )
-            .with_cache_control(CacheDirective::parse(&model_type_definition.directives))
             .into()
         },
         &type_name,
@@ -339,18 +732,53 @@ fn register_connection_type(
     type_name
 }
 
-fn register_search_info(registry: &mut Registry) -> NamedType<'static> {
-    let type_name = SEARCH_INFO_TYPE.to_string();
+fn register_range_facet_input(registry: &mut Registry, scalar: &str) -> String {
+    let type_name = MetaNames::search_range_facet_input(scalar);
+    registry.create_type(
+        |_| {
+            InputObjectType::new(
+                type_name.clone(),
+                [
+                    MetaInputValue::new(INPUT_FIELD_FACET_BOUNDARIES, format!("[{scalar}!]!")).with_description(
+                        "Values delimiting the buckets, each bucket includes its lower bound and excludes its upper one.",
+                    ),
+                ],
+            )
+            .into()
+        },
+        &type_name,
+        &type_name,
+    );
+
+    type_name
+}
+
+fn register_range_facet_bucket(registry: &mut Registry, scalar: &str) -> String {
+    let type_name = MetaNames::search_range_facet_bucket_type(scalar);
     registry.create_type(
         |_| {
             registry::ObjectType::new(
                 type_name.clone(),
-                [MetaField {
-                    name: SEARCH_INFO_FIELD_TOTAL_HITS.to_string(),
-                    ty: "Int!".into(),
-                    resolver: Transformer::select(SEARCH_RESOLVER_TOTAL_HITS).into(),
-                    ..Default::default()
-                }],
+                [
+                    MetaField {
+                        name: OUTPUT_FIELD_FACET_BUCKET_GTE.to_string(),
+                        ty: scalar.into(),
+                        resolver: Transformer::select(OUTPUT_FIELD_FACET_BUCKET_GTE).into(),
+                        ..Default::default()
+                    },
+                    MetaField {
+                        name: OUTPUT_FIELD_FACET_BUCKET_LT.to_string(),
+                        ty: scalar.into(),
+                        resolver: Transformer::select(OUTPUT_FIELD_FACET_BUCKET_LT).into(),
+                        ..Default::default()
+                    },
+                    MetaField {
+                        name: OUTPUT_FIELD_FACET_BUCKET_COUNT.to_string(),
+                        ty: "Int!".into(),
+                        resolver: Transformer::select(OUTPUT_FIELD_FACET_BUCKET_COUNT).into(),
+                        ..Default::default()
+                    },
+                ],
             )
             .into()
         },
@@ -358,7 +786,7 @@ fn register_search_info(registry: &mut Registry) -> NamedType<'static> {
         &type_name,
     );
 
-    type_name.into()
+    type_name
 }
 
 fn register_edge_type(
@@ -369,7 +797,8 @@ fn register_edge_type(
     let type_name = MetaNames::search_edge_type(model_type_definition);
     let model_name = NamedType::from(MetaNames::model(model_type_definition));
     registry.create_type(
-        |_| {
+        |registry| {
+            let highlight_type = register_search_highlight(registry);
             registry::ObjectType::new(
                 type_name.clone(),
                 [
@@ -396,6 +825,21 @@ fn register_edge_type(
                         resolver: Transformer::select(SEARCH_RESOLVER_EDGE_SCORE).into(),
                         ..Default::default()
                     },
+                    MetaField {
+                        name: PAGINATION_FIELD_EDGE_SEARCH_HIGHLIGHTS.to_string(),
+                        description: Some(
+                            concat!(
+                                "Snippets of the text fields matching the query, with the matched words ",
+                                "wrapped in <b> tags."
+                            )
+                            .to_string(),
+                        ),
+                        ty: format!("[{highlight_type}!]!").into(),
+                        required_operation: Some(Operations::LIST),
+                        auth: model_auth.cloned(),
+                        resolver: Transformer::select(SEARCH_RESOLVER_EDGE_HIGHLIGHTS).into(),
+                        ..Default::default()
+                    },
                 ],
             )
             .with_cache_control(CacheDirective::parse(&model_type_definition.directives))
@@ -125,6 +125,15 @@ mod tests {
                         ),
                     ),
                 ],
+                operation_limits: OperationLimits {
+                    depth: None,
+                    aliases: None,
+                    root_fields: None,
+                    complexity: None,
+                    tokens: None,
+                    parser_recursion: None,
+                },
+                rate_limits: [],
             },
         )
         "###);
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use engine::ServerError;
+use engine::{ClaimsExpression, ServerError};
 use engine_parser::{types::ConstDirective, Positioned};
 use engine_value::ConstValue;
 
@@ -17,6 +17,8 @@ struct InternalAuthConfig {
 
     allowed_owner_ops: Operations,
 
+    claims_rules: Vec<(ClaimsExpression, Operations)>,
+
     provider: Option<AuthProvider>,
 }
 
@@ -114,19 +116,28 @@ pub fn parse_auth_config(
         })?;
 
     let allowed_owner_ops: Operations = rules
-        .into_iter()
+        .iter()
         .filter_map(|rule| match rule {
-            AuthRule::Owner { operations } => Some(operations.unwrap_or_default().into_inner()),
+            AuthRule::Owner { operations } => Some(operations.clone().unwrap_or_default().into_inner()),
             _ => None,
         })
         .flatten()
         .collect();
 
+    let claims_rules = rules
+        .into_iter()
+        .filter_map(|rule| match rule {
+            AuthRule::Claims { expr, operations } => Some((expr, operations.unwrap_or_default())),
+            _ => None,
+        })
+        .collect();
+
     Ok(engine::AuthConfig::from(InternalAuthConfig {
         allowed_private_ops,
         allowed_public_ops,
         allowed_group_ops,
         allowed_owner_ops,
+        claims_rules,
         provider,
     }))
 }
@@ -146,6 +157,15 @@ impl From<InternalAuthConfig> for engine::AuthConfig {
 
             allowed_owner_ops: internal_auth.allowed_owner_ops.into(),
 
+            claims_rules: internal_auth
+                .claims_rules
+                .into_iter()
+                .map(|(expression, operations)| engine::ClaimsRule {
+                    expression,
+                    operations: operations.into(),
+                })
+                .collect(),
+
             provider: internal_auth.provider.map(|provider| match provider {
                 AuthProvider::Oidc {
                     issuer,
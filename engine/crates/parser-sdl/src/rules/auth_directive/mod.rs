@@ -54,7 +54,7 @@ impl<'a> Visitor<'a> for AuthDirective {
     }
 
     // Visit types to check that the auth directive is used correctly. Actual
-    // processing happens in the model directive.
+    // processing happens in the model directive, or when extending connector types.
     fn enter_type_definition(
         &mut self,
         ctx: &mut VisitorContext<'a>,
@@ -62,17 +62,19 @@ impl<'a> Visitor<'a> for AuthDirective {
     ) {
         if let (Some(auth_directive), false) = (
             type_definition.node.directives.iter().find(|d| d.is_auth()),
-            type_definition.node.directives.iter().any(|d| d.is_model()),
+            type_definition.node.extend || type_definition.node.directives.iter().any(|d| d.is_model()),
         ) {
             ctx.report_error(
                 vec![auth_directive.pos],
-                format!("the @{AUTH_DIRECTIVE} directive can only be used on @{MODEL_DIRECTIVE} types"),
+                format!(
+                    "the @{AUTH_DIRECTIVE} directive can only be used on @{MODEL_DIRECTIVE} types and type extensions"
+                ),
             );
         }
     }
 
     // Visit fields to check that the auth directive is used correctly. Actual
-    // processing happens in the model directive.
+    // processing happens in the model directive, or when extending connector types.
     fn enter_field(
         &mut self,
         ctx: &mut VisitorContext<'a>,
@@ -81,11 +83,13 @@ impl<'a> Visitor<'a> for AuthDirective {
     ) {
         if let (Some(auth_directive), false) = (
             field.node.directives.iter().find(|d| d.is_auth()),
-            parent_type.node.directives.iter().any(|d| d.is_model()),
+            parent_type.node.extend || parent_type.node.directives.iter().any(|d| d.is_model()),
         ) {
             ctx.report_error(
                 vec![auth_directive.pos],
-                format!("the @{AUTH_DIRECTIVE} directive can only be used on fields of @{MODEL_DIRECTIVE} types"),
+                format!(
+                    "the @{AUTH_DIRECTIVE} directive can only be used on fields of @{MODEL_DIRECTIVE} types and type extensions"
+                ),
             );
         }
     }
@@ -160,7 +164,7 @@ pub mod tests {
           query: Query
         }
         ",
-        "auth rule: unknown variant `anonymous`, expected one of `private`, `public`, `groups`, `owner`"
+        "auth rule: unknown variant `anonymous`, expected one of `private`, `public`, `groups`, `owner`, `claims`"
     );
 
     parse_fail!(
@@ -172,7 +176,7 @@ pub mod tests {
           query: Query
         }
         ",
-        "auth rule: unknown variant `anonymous`, expected one of `private`, `public`, `groups`, `owner`"
+        "auth rule: unknown variant `anonymous`, expected one of `private`, `public`, `groups`, `owner`, `claims`"
     );
 
     parse_test!(
@@ -345,6 +349,49 @@ pub mod tests {
         }
     );
 
+    parse_test!(
+        claims_rule,
+        r#"
+        schema @auth(
+          rules: [ { allow: claims, expr: "org.role == 'admin'", operations: [read] } ],
+        ){
+          query: Query
+        }
+        "#,
+        engine::AuthConfig {
+            claims_rules: vec![engine::ClaimsRule {
+                expression: "org.role == 'admin'".parse().unwrap(),
+                operations: Operations::READ,
+            }],
+            allowed_public_ops: allowed_public_ops(Operations::empty()),
+            ..Default::default()
+        }
+    );
+
+    parse_fail!(
+        claims_rule_with_invalid_expression,
+        r#"
+        schema @auth(
+          rules: [ { allow: claims, expr: "tenant_id = 1" } ],
+        ){
+          query: Query
+        }
+        "#,
+        "auth rule: unexpected `=` in claims expression"
+    );
+
+    parse_fail!(
+        global_claims_rule_with_parent,
+        r#"
+        schema @auth(
+          rules: [ { allow: claims, expr: "tenant_id == $parent.tenantId" } ],
+        ){
+          query: Query
+        }
+        "#,
+        "claims rules using $parent or $args can only be configured on types and fields"
+    );
+
     parse_test!(
         oidc_provider,
         r#"
@@ -385,7 +432,7 @@ pub mod tests {
           id: ID!
         }
         ",
-        "the @auth directive can only be used on @model types"
+        "the @auth directive can only be used on @model types and type extensions"
     );
 
     parse_fail!(
@@ -406,7 +453,7 @@ pub mod tests {
           title: String @auth(rules: [])
         }
         ",
-        "the @auth directive can only be used on fields of @model types"
+        "the @auth directive can only be used on fields of @model types and type extensions"
     );
 
     parse_fail!(
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use engine::ServerError;
+use engine::{ClaimsExpression, ServerError};
 use engine_value::ConstValue;
 use serde::{Deserialize, Serialize};
 
@@ -50,6 +50,18 @@ pub enum AuthRule {
         #[serde(default)]
         operations: Option<Operations>,
     },
+
+    /// Attribute-based data access. Access is allowed when the expression comparing the token
+    /// claims with constants, arguments (`$args`) or fields of the object (`$parent`) holds.
+    // Ex: { allow: claims, expr: "org.role == 'admin'" }
+    //     { allow: claims, expr: "tenant_id == $parent.tenantId", operations: [read] }
+    #[serde(rename_all = "camelCase")]
+    Claims {
+        expr: ClaimsExpression,
+
+        #[serde(default)]
+        operations: Option<Operations>,
+    },
 }
 
 impl AuthRule {
@@ -66,6 +78,15 @@ impl AuthRule {
         let rule: AuthRule =
             serde_json::from_value(value).map_err(|err| ServerError::new(format!("auth rule: {err}"), None))?;
 
+        if let AuthRule::Claims { expr, .. } = &rule {
+            if is_global && (expr.references_parent() || expr.references_arguments()) {
+                return Err(ServerError::new(
+                    "claims rules using $parent or $args can only be configured on types and fields",
+                    None,
+                ));
+            }
+        }
+
         if !is_global
             && rule
                 .maybe_operations()
@@ -86,7 +107,8 @@ impl AuthRule {
             AuthRule::Private { operations }
             | AuthRule::Public { operations }
             | AuthRule::Groups { groups: _, operations }
-            | AuthRule::Owner { operations } => operations,
+            | AuthRule::Owner { operations }
+            | AuthRule::Claims { operations, .. } => operations,
         }
         .as_ref()
     }
@@ -7,16 +7,18 @@
 use engine::registry::{
     self,
     federation::FederationKey,
-    resolvers::{custom::CustomResolver, transformer::Transformer, Resolver},
-    MetaField, MetaType, ObjectType,
+    resolvers::{transformer::Transformer, Resolver},
+    MetaField, MetaInputValue, MetaType, ObjectType,
 };
 use engine_parser::{
     types::{FieldDefinition, TypeKind},
     Pos, Positioned,
 };
+use indexmap::IndexMap;
 use itertools::Itertools;
 
 use super::{
+    cost_directive::CostDirective,
     deprecated_directive::DeprecatedDirective,
     federation::{
         ExternalDirective, InaccessibleDirective, KeyDirective, OverrideDirective, ProvidesDirective,
@@ -74,6 +76,8 @@ impl<'a> Visitor<'a> for BasicType {
                 let deprecation = DeprecatedDirective::from_directives(&field.directives, ctx);
                 let inaccessible = InaccessibleDirective::from_directives(&field.directives, ctx);
                 let tags = TagDirective::from_directives(&field.directives, ctx);
+                let cost = CostDirective::from_directives(&field.directives, ctx);
+                let mut args = IndexMap::new();
 
                 if let Some(join_directive) = JoinDirective::from_directives(&field.node.directives, ctx) {
                     if resolver.is_custom() {
@@ -84,14 +88,25 @@ impl<'a> Visitor<'a> for BasicType {
                         // If someone asks we could do it
                         ctx.report_error(vec![field.pos], "A field can't have a join and a requires on it");
                     }
-                    requires = join_directive.select.required_fieldset();
-                    resolver = Resolver::Join(join_directive.select.to_join_resolver());
+                    requires = join_directive.required_fieldset(&field.node);
+                    resolver = Resolver::Join(join_directive.to_join_resolver());
+                    // Arguments of a joined field can be forwarded to the field it joins with.
+                    args = field
+                        .node
+                        .arguments
+                        .iter()
+                        .map(|argument| {
+                            MetaInputValue::new(argument.node.name.to_string(), argument.node.ty.to_string())
+                        })
+                        .map(|arg| (arg.name.clone(), arg))
+                        .collect();
                 }
 
                 MetaField {
                     name: name.clone(),
                     mapped_name,
                     description: field.node.description.clone().map(|x| x.node),
+                    args,
                     ty: field.node.ty.clone().node.to_string().into(),
                     cache_control: CacheDirective::parse(&field.node.directives),
                     resolver,
@@ -103,6 +118,7 @@ impl<'a> Visitor<'a> for BasicType {
                     deprecation,
                     inaccessible,
                     tags,
+                    cost,
                     ..Default::default()
                 }
             })
@@ -181,10 +197,8 @@ impl KeyDirective {
 }
 
 fn field_resolver(field: &Positioned<FieldDefinition>, mapped_name: Option<&str>) -> Resolver {
-    if let Some(resolver_name) = ResolverDirective::resolver_name(&field.node) {
-        return Resolver::CustomResolver(CustomResolver {
-            resolver_name: resolver_name.to_owned(),
-        });
+    if let Some(custom_resolver) = ResolverDirective::custom_resolver(&field.node) {
+        return Resolver::CustomResolver(custom_resolver);
     }
 
     Transformer::select(mapped_name.unwrap_or_else(|| field.name())).into()
@@ -0,0 +1,67 @@
+use engine::registry::{resolvers::graphql::UpstreamNames, Registry};
+
+use super::{field_lookup::FieldLookup, lookup_fields, SelectedField};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct Hoist {
+    field: FieldLookup,
+    /// Dot separated path of the nested field to expose instead, e.g. `data.items`.
+    path: String,
+}
+
+/// Replaces each selected field by the field nested at `path` within its type. The hoisted field
+/// keeps its name and arguments, its resolver goes through the nested fields.
+pub(super) fn run_hoist(
+    registry: &mut Registry,
+    Hoist { field: lookup, path }: &Hoist,
+    upstream_names: &mut UpstreamNames,
+) -> Result<(), String> {
+    for SelectedField { ty, field } in lookup_fields(registry, lookup) {
+        let Some(hoisted_field) = registry.types.get(&ty).and_then(|ty| ty.field_by_name(&field)) else {
+            return Err(format!(
+                "Cannot hoist {path} into {ty}.{field}, only fields of objects can be hoisted"
+            ));
+        };
+
+        let mut field_type = hoisted_field.ty.clone();
+        let mut resolver = hoisted_field.resolver.clone();
+        let mut upstream_path = Vec::new();
+        let mut is_nullable = field_type.is_nullable();
+        for name in path.split('.') {
+            if field_type.is_list() {
+                return Err(format!(
+                    "Cannot hoist {path} into {ty}.{field}, {name} is within a list"
+                ));
+            }
+            let Some(nested_field) = registry
+                .types
+                .get(field_type.base_type_name())
+                .and_then(|ty| ty.field_by_name(name))
+            else {
+                return Err(format!(
+                    "Cannot hoist {path} into {ty}.{field}, {} has no field {name}",
+                    field_type.base_type_name()
+                ));
+            };
+            upstream_path.push(nested_field.target_field_name().to_string());
+            resolver = resolver.and_then(nested_field.resolver.clone());
+            field_type = nested_field.ty.clone();
+            is_nullable |= field_type.is_nullable();
+        }
+        if is_nullable {
+            if let Some(nullable_type) = field_type.as_str().strip_suffix('!') {
+                field_type = nullable_type.into();
+            }
+        }
+
+        if let Some(hoisted_field) = registry.types.get_mut(&ty).and_then(|ty| ty.field_by_name_mut(&field)) {
+            hoisted_field.ty = field_type;
+            hoisted_field.resolver = resolver;
+        }
+        upstream_names
+            .hoisted_fields
+            .insert(format!("{ty}.{field}"), upstream_path);
+    }
+
+    Ok(())
+}
@@ -1,21 +1,38 @@
 use std::collections::HashSet;
 
 use engine::registry::{
+    resolvers::{graphql::UpstreamNames, Resolver},
     type_kinds::{InputType, OutputType},
     InputObjectType, InterfaceType, MetaType, ObjectType, Registry,
 };
 
-use self::field_lookup::FieldLookup;
+use self::{
+    field_lookup::FieldLookup,
+    hoist::{run_hoist, Hoist},
+    rename::{run_rename, Rename},
+};
 
 mod field_lookup;
+mod hoist;
+mod rename;
 
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct Transforms {
+    #[serde(default)]
     exclude: Vec<FieldLookup>,
     pub prefix_types: Option<String>,
+    /// Types renamed by their name and fields by a field lookup. Types of different connectors
+    /// renamed to the same name are merged.
+    #[serde(default)]
+    rename: Vec<Rename>,
+    /// Fields replaced by a field nested within their type, e.g. `data.items`.
+    #[serde(default)]
+    hoist: Vec<Hoist>,
 }
 
-pub fn run_transforms(registry: &mut Registry, transforms: &Transforms) {
+/// Fields are excluded first, then types and fields are renamed and finally fields are hoisted.
+/// Each transform looks up types and fields by the names the previous ones left.
+pub fn run_transforms(registry: &mut Registry, transforms: &Transforms) -> Result<(), String> {
     let fields_to_remove = transforms
         .exclude
         .iter()
@@ -34,7 +51,43 @@ pub fn run_transforms(registry: &mut Registry, transforms: &Transforms) {
         }
     }
 
-    registry.remove_unused_types();
+    let mut upstream_names = UpstreamNames::default();
+    for rename in &transforms.rename {
+        run_rename(registry, rename, &mut upstream_names)?;
+    }
+    for hoist in &transforms.hoist {
+        run_hoist(registry, hoist, &mut upstream_names)?;
+    }
+    if !upstream_names.is_empty() {
+        set_upstream_names(registry, &upstream_names);
+    }
+
+    if !transforms.exclude.is_empty() || !transforms.hoist.is_empty() {
+        registry.remove_unused_types();
+    }
+
+    Ok(())
+}
+
+/// GraphQL resolvers need to know what was renamed or hoisted to query the upstream server.
+fn set_upstream_names(registry: &mut Registry, upstream_names: &UpstreamNames) {
+    fn visit(resolver: &mut Resolver, upstream_names: &UpstreamNames) {
+        match resolver {
+            Resolver::Graphql(resolver) => resolver.upstream_names = upstream_names.clone(),
+            Resolver::Composition(resolvers) => {
+                for resolver in resolvers {
+                    visit(resolver, upstream_names);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    for ty in registry.types.values_mut() {
+        for field in ty.fields_mut().into_iter().flat_map(|fields| fields.values_mut()) {
+            visit(&mut field.resolver, upstream_names);
+        }
+    }
 }
 
 #[derive(PartialEq, Eq, Hash)]
@@ -115,10 +168,18 @@ mod tests {
         run_transforms(
             &mut registry,
             &Transforms::deserialize(json!({"exclude": [input]})).unwrap(),
-        );
+        )
+        .unwrap();
         registry.export_sdl(false)
     }
 
+    fn run_transforms_test(mut registry: Registry, transforms: serde_json::Value) -> Result<Registry, String> {
+        run_transforms(&mut registry, &Transforms::deserialize(transforms).unwrap())?;
+        // Only keep the types used by the connector, like for the other transforms.
+        registry.remove_unused_types();
+        Ok(registry)
+    }
+
     #[test]
     fn test_excluding_specific_field() {
         insta::assert_snapshot!(do_exclude_test("User.email"), @r###"
@@ -238,6 +299,147 @@ mod tests {
         "###);
     }
 
+    #[test]
+    fn test_renaming_fields() {
+        let registry = run_transforms_test(
+            registry(),
+            json!({"rename": [{"from": "Query.{user,users}.name", "to": "fullName"}]}),
+        )
+        .unwrap();
+
+        let field = registry.types["User"].field_by_name("fullName").unwrap();
+        assert_eq!(field.mapped_name.as_deref(), Some("name"));
+
+        insta::assert_snapshot!(registry.export_sdl(false), @r###"
+        type Account {
+        	id: ID!
+        	email: String!
+        }
+        type Other {
+        	id: ID!
+        }
+        type Query {
+        	user: User
+        	users: [User]
+        	other: Other
+        }
+        type User {
+        	id: ID!
+        	fullName: String!
+        	account: Account!
+        }
+        schema {
+        	query: Query
+        }
+        "###);
+    }
+
+    #[test]
+    fn test_renaming_types() {
+        let registry = run_transforms_test(registry(), json!({"rename": [{"from": "User", "to": "Person"}]})).unwrap();
+
+        insta::assert_snapshot!(registry.export_sdl(false), @r###"
+        type Account {
+        	id: ID!
+        	email: String!
+        }
+        type Other {
+        	id: ID!
+        }
+        type Person {
+        	id: ID!
+        	name: String!
+        	account: Account!
+        }
+        type Query {
+        	user: Person
+        	users: [Person]
+        	other: Other
+        }
+        schema {
+        	query: Query
+        }
+        "###);
+    }
+
+    #[test]
+    fn test_renaming_conflicts() {
+        let error =
+            run_transforms_test(registry(), json!({"rename": [{"from": "User", "to": "Account"}]})).unwrap_err();
+        assert_eq!(
+            error,
+            "Cannot rename User to Account, a type with that name already exists"
+        );
+
+        let error =
+            run_transforms_test(registry(), json!({"rename": [{"from": "User.name", "to": "id"}]})).unwrap_err();
+        assert_eq!(
+            error,
+            "Cannot rename User.name to id, the type already has a field with that name"
+        );
+    }
+
+    #[test]
+    fn test_hoisting_fields() {
+        let registry = crate::to_parse_result_with_variables(
+            r#"
+          type User {
+            id: ID!
+            name: String!
+          }
+
+          type UserPage {
+            data: UserPageData
+          }
+
+          type UserPageData {
+            items: [User!]!
+            total: Int
+          }
+
+          extend type Query {
+            userPage: UserPage! @resolver(name: "whatever")
+          }
+        "#,
+            &HashMap::default(),
+        )
+        .unwrap()
+        .registry;
+
+        let registry = run_transforms_test(
+            registry,
+            json!({"hoist": [{"field": "Query.userPage", "path": "data.items"}]}),
+        )
+        .unwrap();
+
+        let field = registry.types["Query"].field_by_name("userPage").unwrap();
+        assert!(matches!(field.resolver, Resolver::Composition(_)));
+
+        // data is nullable, so is the hoisted field.
+        insta::assert_snapshot!(registry.export_sdl(false), @r###"
+        type Query {
+        	userPage: [User!]
+        }
+        type User {
+        	id: ID!
+        	name: String!
+        }
+        schema {
+        	query: Query
+        }
+        "###);
+    }
+
+    #[test]
+    fn test_hoisting_out_of_lists() {
+        let error = run_transforms_test(
+            registry(),
+            json!({"hoist": [{"field": "Query.users", "path": "account"}]}),
+        )
+        .unwrap_err();
+        assert_eq!(error, "Cannot hoist account into Query.users, account is within a list");
+    }
+
     fn registry() -> Registry {
         crate::to_parse_result_with_variables(
             r#"
@@ -0,0 +1,216 @@
+use engine::registry::{
+    resolvers::graphql::UpstreamNames, InputObjectType, InterfaceType, MetaType, MetaTypeName, ObjectType, Registry,
+    UnionType,
+};
+
+use super::{field_lookup::FieldLookup, lookup_fields, SelectedField};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct Rename {
+    from: RenameTarget,
+    to: String,
+}
+
+/// Either a type name like `User` or a field lookup like `Query.{user,users}.name`.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(try_from = "String")]
+enum RenameTarget {
+    Type(String),
+    Fields(FieldLookup),
+}
+
+impl TryFrom<String> for RenameTarget {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.contains('.') {
+            Ok(RenameTarget::Fields(value.try_into()?))
+        } else {
+            Ok(RenameTarget::Type(value))
+        }
+    }
+}
+
+pub(super) fn run_rename(
+    registry: &mut Registry,
+    Rename { from, to }: &Rename,
+    upstream_names: &mut UpstreamNames,
+) -> Result<(), String> {
+    match from {
+        RenameTarget::Type(from) => rename_type(registry, from, to, upstream_names),
+        RenameTarget::Fields(lookup) => rename_fields(registry, lookup, to),
+    }
+}
+
+/// Renamed fields keep their previous name as the name resolvers use upstream.
+fn rename_fields(registry: &mut Registry, lookup: &FieldLookup, to: &str) -> Result<(), String> {
+    for SelectedField { ty, field } in lookup_fields(registry, lookup) {
+        if field == to {
+            continue;
+        }
+        match registry.types.get_mut(&ty) {
+            Some(MetaType::Object(ObjectType { fields, .. }) | MetaType::Interface(InterfaceType { fields, .. })) => {
+                if fields.contains_key(to) {
+                    return Err(field_exists_error(&ty, &field, to));
+                }
+                *fields = std::mem::take(fields)
+                    .into_iter()
+                    .map(|(name, mut meta_field)| {
+                        if name != field {
+                            return (name, meta_field);
+                        }
+                        let previous_name = std::mem::replace(&mut meta_field.name, to.to_string());
+                        meta_field.mapped_name.get_or_insert(previous_name);
+                        (to.to_string(), meta_field)
+                    })
+                    .collect();
+            }
+            Some(MetaType::InputObject(InputObjectType { input_fields, .. })) => {
+                if input_fields.contains_key(to) {
+                    return Err(field_exists_error(&ty, &field, to));
+                }
+                *input_fields = std::mem::take(input_fields)
+                    .into_iter()
+                    .map(|(name, mut input_value)| {
+                        if name != field {
+                            return (name, input_value);
+                        }
+                        let previous_name = std::mem::replace(&mut input_value.name, to.to_string());
+                        input_value.rename.get_or_insert(previous_name);
+                        (to.to_string(), input_value)
+                    })
+                    .collect();
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn field_exists_error(ty: &str, field: &str, to: &str) -> String {
+    format!("Cannot rename {ty}.{field} to {to}, the type already has a field with that name")
+}
+
+/// Renames a type and every reference to it. Types from different connectors which are renamed to
+/// the same name are merged if they have the same shape.
+fn rename_type(
+    registry: &mut Registry,
+    from: &str,
+    to: &str,
+    upstream_names: &mut UpstreamNames,
+) -> Result<(), String> {
+    if from == to {
+        return Ok(());
+    }
+    let is_root_type = from == registry.query_type
+        || Some(from) == registry.mutation_type.as_deref()
+        || Some(from) == registry.subscription_type.as_deref();
+    if is_root_type {
+        return Err(format!("Cannot rename the root type {from}"));
+    }
+    if registry.types.contains_key(to) {
+        return Err(format!(
+            "Cannot rename {from} to {to}, a type with that name already exists"
+        ));
+    }
+    let Some(mut ty) = registry.types.remove(from) else {
+        return Err(format!("Cannot rename {from}, there is no type with that name"));
+    };
+
+    match &mut ty {
+        MetaType::Scalar(scalar) => scalar.name = to.to_string(),
+        MetaType::Object(object) => {
+            object.name = to.to_string();
+            object.rust_typename = to.to_string();
+        }
+        MetaType::Interface(interface) => {
+            interface.name = to.to_string();
+            interface.rust_typename = to.to_string();
+        }
+        MetaType::Union(union) => {
+            union.name = to.to_string();
+            union.rust_typename = to.to_string();
+        }
+        MetaType::Enum(enum_type) => {
+            enum_type.name = to.to_string();
+            enum_type.rust_typename = to.to_string();
+        }
+        MetaType::InputObject(input_object) => {
+            input_object.name = to.to_string();
+            input_object.rust_typename = to.to_string();
+        }
+    }
+    registry.types.insert(to.to_string(), ty);
+
+    let rename = |name: &mut String| {
+        if name.as_str() == from {
+            *name = to.to_string();
+        }
+    };
+    let rename_reference = |ty: &str| (MetaTypeName::concrete_typename(ty) == from).then(|| ty.replacen(from, to, 1));
+
+    for ty in registry.types.values_mut() {
+        match ty {
+            MetaType::Object(ObjectType { fields, .. }) | MetaType::Interface(InterfaceType { fields, .. }) => {
+                for field in fields.values_mut() {
+                    if let Some(renamed) = rename_reference(field.ty.as_str()) {
+                        field.ty = renamed.into();
+                    }
+                    for argument in field.args.values_mut() {
+                        if let Some(renamed) = rename_reference(argument.ty.as_str()) {
+                            argument.ty = renamed.into();
+                        }
+                    }
+                }
+            }
+            MetaType::InputObject(InputObjectType { input_fields, .. }) => {
+                for input_field in input_fields.values_mut() {
+                    if let Some(renamed) = rename_reference(input_field.ty.as_str()) {
+                        input_field.ty = renamed.into();
+                    }
+                }
+            }
+            _ => {}
+        }
+        match ty {
+            MetaType::Interface(InterfaceType { possible_types, .. })
+            | MetaType::Union(UnionType { possible_types, .. }) => {
+                *possible_types = std::mem::take(possible_types)
+                    .into_iter()
+                    .map(|mut name| {
+                        rename(&mut name);
+                        name
+                    })
+                    .collect();
+            }
+            _ => {}
+        }
+        if let MetaType::Union(UnionType {
+            discriminators: Some(discriminators),
+            ..
+        }) = ty
+        {
+            for (name, _) in discriminators {
+                rename(name);
+            }
+        }
+    }
+
+    if let Some(implemented) = registry.implements.remove(from) {
+        registry.implements.insert(to.to_string(), implemented);
+    }
+    for implemented in registry.implements.values_mut() {
+        if implemented.remove(from) {
+            implemented.insert(to.to_string());
+        }
+    }
+    if let Some(entity) = registry.federation_entities.remove(from) {
+        registry.federation_entities.insert(to.to_string(), entity);
+    }
+
+    let previous_name = upstream_names.types.remove(from).unwrap_or_else(|| from.to_string());
+    upstream_names.types.insert(to.to_string(), previous_name);
+
+    Ok(())
+}
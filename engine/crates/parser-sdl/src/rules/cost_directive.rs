@@ -0,0 +1,81 @@
+use engine_parser::{types::ConstDirective, Positioned};
+
+use crate::{
+    directive_de::parse_directive,
+    rules::{directive::Directive, visitor::VisitorContext},
+};
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CostDirective {
+    weight: u16,
+}
+
+impl CostDirective {
+    pub fn from_directives(directives: &[Positioned<ConstDirective>], ctx: &mut VisitorContext<'_>) -> Option<u16> {
+        let directive = directives.iter().find(|directive| directive.name.node == "cost")?;
+        match parse_directive::<Self>(directive, ctx.variables) {
+            Ok(directive) => Some(directive.weight),
+            Err(error) => {
+                ctx.append_errors(vec![error]);
+                None
+            }
+        }
+    }
+}
+
+impl Directive for CostDirective {
+    fn definition() -> String {
+        r#"
+        directive @cost(
+          "What the field costs in the cost limits of @rateLimit, instead of 1"
+          weight: Int!
+        ) on FIELD_DEFINITION
+        "#
+        .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_registry;
+
+    #[test]
+    fn test_cost_directive() {
+        let registry = parse_registry(
+            r#"
+                extend type Query {
+                    products: [Product!]! @resolver(name: "products") @cost(weight: 10)
+                }
+
+                type Product {
+                    id: ID!
+                    reviews: [String!]! @cost(weight: 0)
+                }
+            "#,
+        )
+        .unwrap();
+
+        let cost = |type_name: &str, field_name: &str| {
+            registry.types[type_name]
+                .field_by_name(field_name)
+                .and_then(|field| field.cost)
+        };
+        assert_eq!(cost("Query", "products"), Some(10));
+        assert_eq!(cost("Product", "id"), None);
+        assert_eq!(cost("Product", "reviews"), Some(0));
+    }
+
+    #[test]
+    fn test_negative_weight() {
+        let result = parse_registry(
+            r#"
+                type Product {
+                    id: ID! @cost(weight: -1)
+                }
+            "#,
+        );
+
+        assert!(result.is_err());
+    }
+}
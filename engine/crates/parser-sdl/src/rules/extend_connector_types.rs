@@ -1,11 +1,12 @@
-use engine::registry::{
-    self,
-    resolvers::{custom::CustomResolver, Resolver},
-    MetaField, MetaInputValue, MetaType,
+use engine::{
+    registry::{self, resolvers::Resolver, MetaField, MetaInputValue, MetaType, Registry},
+    AuthConfig,
 };
 use engine_parser::types::TypeKind;
 
 use super::{
+    auth_directive::AuthDirective,
+    cost_directive::CostDirective,
     deprecated_directive::DeprecatedDirective,
     federation::{
         ExternalDirective, InaccessibleDirective, OverrideDirective, ProvidesDirective, ShareableDirective,
@@ -15,7 +16,7 @@ use super::{
     requires_directive::RequiresDirective,
     visitor::{Visitor, VisitorContext},
 };
-use crate::rules::resolver_directive::ResolverDirective;
+use crate::{registry::get_validators, rules::resolver_directive::ResolverDirective};
 
 pub struct ExtendConnectorTypes;
 
@@ -35,6 +36,14 @@ impl<'a> Visitor<'a> for ExtendConnectorTypes {
             return;
         }
 
+        let type_auth = match AuthDirective::parse(ctx, &type_definition.node.directives, false) {
+            Ok(auth) => auth,
+            Err(err) => {
+                ctx.report_error(err.locations, err.message);
+                None
+            }
+        };
+
         let extended_fields = object
             .fields
             .iter()
@@ -42,7 +51,7 @@ impl<'a> Visitor<'a> for ExtendConnectorTypes {
                 let name = field.node.name.node.to_string();
 
                 let join_directive = JoinDirective::from_directives(&field.node.directives, ctx);
-                let resolver_name = ResolverDirective::resolver_name(&field.node);
+                let custom_resolver = ResolverDirective::custom_resolver(&field.node);
 
                 let mut requires =
                     RequiresDirective::from_directives(&field.directives, ctx).map(RequiresDirective::into_fields);
@@ -56,8 +65,16 @@ impl<'a> Visitor<'a> for ExtendConnectorTypes {
                 let deprecation = DeprecatedDirective::from_directives(&field.directives, ctx);
                 let inaccessible = InaccessibleDirective::from_directives(&field.directives, ctx);
                 let tags = TagDirective::from_directives(&field.directives, ctx);
+                let cost = CostDirective::from_directives(&field.directives, ctx);
+                let auth = match AuthDirective::parse(ctx, &field.directives, false) {
+                    Ok(auth) => auth,
+                    Err(err) => {
+                        ctx.report_error(err.locations, err.message);
+                        None
+                    }
+                };
 
-                let resolver = match (join_directive, resolver_name) {
+                let resolver = match (join_directive, custom_resolver) {
                     (None, None) => {
                         ctx.report_error(
                             vec![field.pos],
@@ -65,17 +82,15 @@ impl<'a> Visitor<'a> for ExtendConnectorTypes {
                         );
                         return None;
                     }
-                    (None, Some(resolver_name)) => Resolver::CustomResolver(CustomResolver {
-                        resolver_name: resolver_name.to_owned(),
-                    }),
+                    (None, Some(custom_resolver)) => Resolver::CustomResolver(custom_resolver),
                     (Some(join_directive), None) => {
                         if requires.is_some() {
                             // We could support this by merging the requires, but I don't want to implement it right now.
                             // If someone asks we could do it
                             ctx.report_error(vec![field.pos], "A field can't have a join and a requires on it");
                         }
-                        requires = join_directive.select.required_fieldset();
-                        Resolver::Join(join_directive.select.to_join_resolver())
+                        requires = join_directive.required_fieldset(&field.node);
+                        Resolver::Join(join_directive.to_join_resolver())
                     }
                     (Some(_), Some(_)) => {
                         ctx.report_error(vec![field.pos], "A field can't have a join and a custom resolver on it");
@@ -91,8 +106,9 @@ impl<'a> Visitor<'a> for ExtendConnectorTypes {
                     args: field
                         .arguments
                         .iter()
-                        .map(|argument| {
-                            MetaInputValue::new(argument.node.name.to_string(), argument.node.ty.to_string())
+                        .map(|argument| MetaInputValue {
+                            validators: get_validators(&argument.node.directives, &argument.node.ty.node),
+                            ..MetaInputValue::new(argument.node.name.to_string(), argument.node.ty.to_string())
                         })
                         .map(|arg| (arg.name.clone(), arg))
                         .collect(),
@@ -106,6 +122,8 @@ impl<'a> Visitor<'a> for ExtendConnectorTypes {
                     deprecation,
                     inaccessible,
                     tags,
+                    cost,
+                    auth,
                     ..MetaField::default()
                 })
             })
@@ -120,6 +138,44 @@ impl<'a> Visitor<'a> for ExtendConnectorTypes {
         };
 
         fields.extend(extended_fields);
+
+        if let Some(auth) = type_auth {
+            // Fields without their own rules fall back to the rules of the type, like for models.
+            for field in fields.values_mut() {
+                field.auth.get_or_insert_with(|| auth.clone());
+            }
+            apply_auth_to_root_fields(&mut registry, type_name, &auth);
+        }
+    }
+}
+
+/// The root fields returning the type, or for Postgres those accessing the table of the type,
+/// get the rules of the type. Rules depending on the rows are applied by the resolver.
+fn apply_auth_to_root_fields(registry: &mut Registry, type_name: &str, auth: &AuthConfig) {
+    let root_types = [Some(registry.query_type.clone()), registry.mutation_type.clone()];
+
+    for root_type in root_types.into_iter().flatten() {
+        let Some(MetaType::Object(registry::ObjectType { fields, .. })) = registry.types.get_mut(&root_type) else {
+            continue;
+        };
+
+        for field in fields.values_mut() {
+            let is_returning_type = field.ty.base_type_name() == type_name
+                || match &field.resolver {
+                    Resolver::PostgresResolver(resolver) => registry
+                        .postgres_databases
+                        .get(resolver.directive_name())
+                        .is_some_and(|database| {
+                            let table = |name: &str| database.find_table_for_client_type(name).map(|table| table.id());
+                            table(type_name).is_some() && table(field.ty.base_type_name()) == table(type_name)
+                        }),
+                    _ => false,
+                };
+
+            if is_returning_type {
+                field.auth.get_or_insert_with(|| auth.clone());
+            }
+        }
     }
 }
 
@@ -6,11 +6,15 @@ use engine::registry::{
 use engine_parser::types::{ObjectType, TypeKind};
 
 use super::{
+    cost_directive::CostDirective,
     deprecated_directive::DeprecatedDirective,
     federation::{InaccessibleDirective, TagDirective},
     visitor::{Visitor, VisitorContext, MUTATION_TYPE, QUERY_TYPE},
 };
-use crate::rules::{cache_directive::CacheDirective, resolver_directive::ResolverDirective};
+use crate::{
+    registry::get_validators,
+    rules::{cache_directive::CacheDirective, resolver_directive::ResolverDirective},
+};
 
 pub struct ExtendQueryAndMutationTypes;
 
@@ -52,9 +56,16 @@ impl<'a> Visitor<'a> for ExtendQueryAndMutationTypes {
                     );
                     continue;
                 };
+                if ResolverDirective::is_batched(&field.node) {
+                    ctx.report_error(
+                        vec![field.pos],
+                        format!("Field '{name}' of '{type_name}' can't have a batched resolver, it has no parents."),
+                    );
+                }
                 let deprecation = DeprecatedDirective::from_directives(&field.directives, ctx);
                 let inaccessible = InaccessibleDirective::from_directives(&field.directives, ctx);
                 let tags = TagDirective::from_directives(&field.directives, ctx);
+                let cost = CostDirective::from_directives(&field.directives, ctx);
 
                 let (field_collection, cache_control) = match entry_point {
                     EntryPoint::Query => (&mut ctx.queries, CacheDirective::parse(&field.node.directives)),
@@ -72,7 +83,10 @@ impl<'a> Visitor<'a> for ExtendQueryAndMutationTypes {
                         .map(|argument| {
                             (
                                 argument.node.name.to_string(),
-                                MetaInputValue::new(argument.node.name.to_string(), argument.node.ty.to_string()),
+                                MetaInputValue {
+                                    validators: get_validators(&argument.node.directives, &argument.node.ty.node),
+                                    ..MetaInputValue::new(argument.node.name.to_string(), argument.node.ty.to_string())
+                                },
                             )
                         })
                         .collect(),
@@ -87,6 +101,7 @@ impl<'a> Visitor<'a> for ExtendQueryAndMutationTypes {
                     compute_complexity: None,
                     resolver: Resolver::CustomResolver(CustomResolver {
                         resolver_name: resolver_name.to_owned(),
+                        batched: false,
                     }),
                     edges: Vec::new(),
                     relation: None,
@@ -95,6 +110,7 @@ impl<'a> Visitor<'a> for ExtendQueryAndMutationTypes {
                     shareable: false,
                     inaccessible,
                     tags,
+                    cost,
                 });
             }
         }
@@ -123,6 +139,13 @@ mod tests {
     ", &[
         "Field 'foo' of 'Query' must have a resolver defined."
     ])]
+    #[case(r#"
+        extend type Query {
+            foo: [String!]! @resolver(name: "return-foo", batch: true)
+        }
+    "#, &[
+        "Field 'foo' of 'Query' can't have a batched resolver, it has no parents."
+    ])]
     #[case(r#"
         extend type Query {
             foo: String! @resolver(name: "return-foo")
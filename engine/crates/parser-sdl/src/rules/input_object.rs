@@ -1,8 +1,9 @@
 //! Pulls input objects into the registry
-use engine::registry::{InputObjectType, MetaInputValue};
+use engine::registry::{InputObjectType, MetaInputValue, MetaType};
 use engine_parser::types::TypeKind;
 
 use super::visitor::{Visitor, VisitorContext};
+use crate::registry::get_validators;
 
 pub struct InputObjectVisitor;
 
@@ -16,6 +17,39 @@ impl<'a> Visitor<'a> for InputObjectVisitor {
             return;
         };
         let type_name = type_definition.node.name.node.to_string();
+
+        if type_definition.node.extend {
+            // Extending an existing input, e.g. one generated by a connector, with validations.
+            let mut registry = ctx.registry.borrow_mut();
+            let Some(MetaType::InputObject(InputObjectType { input_fields, .. })) = registry.types.get_mut(&type_name)
+            else {
+                drop(registry);
+                ctx.report_error(vec![type_definition.pos], format!("Input '{type_name}' does not exist"));
+                return;
+            };
+
+            let mut unknown_fields = Vec::new();
+            for field in &input_object.fields {
+                match input_fields.get_mut(field.node.name.node.as_str()) {
+                    Some(input_field) => {
+                        if let Some(validators) = get_validators(&field.node.directives, &field.node.ty.node) {
+                            input_field.validators.get_or_insert_with(Vec::new).extend(validators);
+                        }
+                    }
+                    None => unknown_fields.push(field),
+                }
+            }
+
+            drop(registry);
+            for field in unknown_fields {
+                ctx.report_error(
+                    vec![field.pos],
+                    format!("Field '{}' does not exist on input '{type_name}'", field.node.name.node),
+                );
+            }
+            return;
+        }
+
         ctx.registry.get_mut().create_type(
             |_| {
                 InputObjectType::new(
@@ -23,6 +57,7 @@ impl<'a> Visitor<'a> for InputObjectVisitor {
                     input_object.fields.iter().map(|field| MetaInputValue {
                         description: field.node.description.clone().map(|description| description.node),
                         default_value: field.node.default_value.clone().map(|default| default.node),
+                        validators: get_validators(&field.node.directives, &field.node.ty.node),
                         ..MetaInputValue::new(field.node.name.node.to_string(), field.node.ty.node.to_string())
                     }),
                 )
@@ -73,4 +108,34 @@ mod tests {
         }
         "###);
     }
+
+    #[test]
+    fn test_input_validation_directives() {
+        let schema = r#"
+            extend type Mutation {
+                checkout(input: CheckoutInput!, coupons: [String!] @items(max: 3)): CheckoutSession! @resolver(name: "checkout")
+            }
+
+            input CheckoutInput {
+                price: String! @pattern(regex: "^[0-9]+$")
+                quantity: Int @range(min: 1, max: 10) @multipleOf(value: 2)
+            }
+
+            type CheckoutSession {
+                url: String!
+            }
+            "#;
+
+        let registry = crate::parse_registry(schema).unwrap();
+
+        let Some(engine::registry::MetaType::InputObject(input)) = registry.types.get("CheckoutInput") else {
+            unreachable!("the input must be registered")
+        };
+        let validators = |name: &str| input.input_fields[name].validators.as_ref().map(Vec::len);
+        assert_eq!(validators("price"), Some(1));
+        assert_eq!(validators("quantity"), Some(2));
+
+        let checkout = registry.mutation_root().field_by_name("checkout").unwrap();
+        assert_eq!(checkout.args["coupons"].validators.as_ref().map(Vec::len), Some(1));
+    }
 }
@@ -1,7 +1,11 @@
 use std::collections::{BTreeSet, HashMap};
 
 use engine::registry::{field_set::Selection, resolvers::join::JoinResolver, FieldSet};
-use engine_parser::{parse_field, types::ConstDirective, Positioned};
+use engine_parser::{
+    parse_field,
+    types::{ConstDirective, FieldDefinition},
+    Positioned,
+};
 use engine_value::{Name, Value};
 use serde::de::Error;
 
@@ -12,6 +16,7 @@ use crate::directive_de::parse_directive;
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct JoinDirective {
     pub select: FieldSelection,
+    pub batch_key: Option<String>,
 }
 
 #[derive(Debug)]
@@ -24,7 +29,7 @@ pub struct FieldSelection {
 impl Directive for JoinDirective {
     fn definition() -> String {
         "
-        directive @join(select: FieldSelection!) on FIELD_DEFINITION
+        directive @join(select: FieldSelection!, batchKey: String) on FIELD_DEFINITION
         "
         .into()
     }
@@ -45,15 +50,37 @@ impl JoinDirective {
             }
         }
     }
+
+    /// The fields of the parent required by the join, variables naming an argument of the field
+    /// are forwarded from that argument instead.
+    pub fn required_fieldset(&self, field: &FieldDefinition) -> Option<FieldSet> {
+        self.select
+            .required_fieldset_except(field.arguments.iter().map(|argument| argument.node.name.node.as_str()))
+    }
+
+    pub fn to_join_resolver(&self) -> JoinResolver {
+        self.select.to_join_resolver().with_batch_key(self.batch_key.clone())
+    }
 }
 
 impl FieldSelection {
     pub fn required_fieldset(&self) -> Option<FieldSet> {
-        if self.required_fields.is_empty() {
+        self.required_fieldset_except([])
+    }
+
+    fn required_fieldset_except<'a>(&self, excluded: impl IntoIterator<Item = &'a str>) -> Option<FieldSet> {
+        let excluded = excluded.into_iter().collect::<BTreeSet<_>>();
+        let required_fields = self
+            .required_fields
+            .iter()
+            .filter(|field| !excluded.contains(field.as_str()))
+            .collect::<Vec<_>>();
+
+        if required_fields.is_empty() {
             return None;
         }
 
-        Some(FieldSet::new(self.required_fields.iter().map(|field| Selection {
+        Some(FieldSet::new(required_fields.into_iter().map(|field| Selection {
             field: field.clone(),
             selections: vec![],
         })))
@@ -326,6 +353,155 @@ mod tests {
         );
     }
 
+    #[test]
+    fn batched_join() {
+        let schema = r#"
+            extend schema @federation(version: "2.3")
+
+            extend type Query {
+                users(ids: [ID!]!): [User!]! @resolver(name: "users")
+            }
+
+            type User @key(fields: "id", resolvable: false) {
+                id: ID!
+                name: String!
+            }
+
+            type Post @key(fields: "id", resolvable: false) {
+                id: ID!
+                authorId: ID!
+                author: User @join(select: "users(ids: $authorId)", batchKey: "id")
+            }
+        "#;
+
+        let registry = crate::to_parse_result_with_variables(schema, &HashMap::new())
+            .unwrap()
+            .registry;
+
+        let field = &registry.types["Post"].fields().as_ref().unwrap()["author"];
+
+        assert_json_snapshot!(field.resolver, @r###"
+        {
+          "J": {
+            "field_name": "users",
+            "arguments": [
+              [
+                "ids",
+                {
+                  "$var": "authorId"
+                }
+              ]
+            ],
+            "batch_key": "id"
+          }
+        }
+        "###);
+    }
+
+    #[test]
+    fn batched_join_on_single_item_field() {
+        assert_validation_error!(
+            r#"
+            extend schema @federation(version: "2.3")
+
+            extend type Query {
+                user(id: ID!): User @resolver(name: "user")
+            }
+
+            type User @key(fields: "id", resolvable: false) {
+                id: ID!
+            }
+
+            type Post @key(fields: "id", resolvable: false) {
+                id: ID!
+                authorId: ID!
+                author: User @join(select: "user(id: $authorId)", batchKey: "id")
+            }
+            "#,
+            "The field author on the type Post is a batched join with the field named user, but that field does not return a list"
+        );
+    }
+
+    #[test]
+    fn batched_join_with_unknown_key() {
+        assert_validation_error!(
+            r#"
+            extend schema @federation(version: "2.3")
+
+            extend type Query {
+                users(ids: [ID!]!): [User!]! @resolver(name: "users")
+            }
+
+            type User @key(fields: "id", resolvable: false) {
+                id: ID!
+            }
+
+            type Post @key(fields: "id", resolvable: false) {
+                id: ID!
+                authorId: ID!
+                author: User @join(select: "users(ids: $authorId)", batchKey: "userId")
+            }
+            "#,
+            "The field author on the type Post is a batched join with the field named users, but the items it returns have no field userId"
+        );
+    }
+
+    #[test]
+    fn batched_join_with_several_parent_fields() {
+        assert_validation_error!(
+            r#"
+            extend schema @federation(version: "2.3")
+
+            extend type Query {
+                users(ids: [ID!]!, names: [String!]): [User!]! @resolver(name: "users")
+            }
+
+            type User @key(fields: "id", resolvable: false) {
+                id: ID!
+            }
+
+            type Post @key(fields: "id", resolvable: false) {
+                id: ID!
+                authorId: ID!
+                authorName: String!
+                author: User @join(select: "users(ids: $authorId, names: $authorName)", batchKey: "id")
+            }
+            "#,
+            "The field author on the type Post is a batched join, which must use exactly one field of its parent"
+        );
+    }
+
+    #[test]
+    fn join_forwarding_arguments() {
+        let schema = r#"
+            extend schema @federation(version: "2.3")
+
+            extend type Query {
+                posts(authorId: ID!, first: Int): [String!]! @resolver(name: "posts")
+            }
+
+            type User @key(fields: "id", resolvable: false) {
+                id: ID!
+                posts(first: Int): [String!]! @join(select: "posts(authorId: $id, first: $first)")
+            }
+        "#;
+
+        let registry = crate::to_parse_result_with_variables(schema, &HashMap::new())
+            .unwrap()
+            .registry;
+
+        let field = &registry.types["User"].fields().as_ref().unwrap()["posts"];
+
+        assert_eq!(field.args.keys().collect::<Vec<_>>(), ["first"]);
+        assert_json_snapshot!(field.requires, @r###"
+        [
+          {
+            "field": "id"
+          }
+        ]
+        "###);
+    }
+
     #[test]
     fn join_directive_deser() {
         let directive = JoinDirective::deserialize(json!({"select": "findUser(name: $name, filters: {eq: $filters})"}));
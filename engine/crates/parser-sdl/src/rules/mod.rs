@@ -9,6 +9,7 @@ pub mod check_type_validity;
 pub mod check_types_underscore;
 mod connector_headers;
 pub mod connector_transforms;
+pub mod cost_directive;
 pub mod default_directive;
 pub mod default_directive_types;
 pub mod deprecated_directive;
@@ -29,13 +30,17 @@ pub mod model_directive;
 pub mod mongodb_directive;
 pub mod one_of_directive;
 pub mod openapi_directive;
+pub mod operation_limits_directive;
 pub mod postgres_directive;
+pub mod rate_limit_directive;
 pub mod relations;
 pub mod requires_directive;
 pub mod resolver_directive;
 pub mod scalar_hydratation;
 pub mod search_directive;
 pub mod subgraph_directive;
+pub mod telemetry_directive;
 pub mod unique_directive;
 pub mod unique_fields;
+pub mod validation_directives;
 pub mod visitor;
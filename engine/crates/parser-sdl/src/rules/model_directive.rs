@@ -29,7 +29,7 @@ use engine::{
         self,
         federation::{FederationEntity, FederationKey, FederationResolver},
         is_array_basic_type,
-        resolvers::{custom::CustomResolver, dynamo_querying::DynamoResolver, transformer::Transformer, Resolver},
+        resolvers::{dynamo_querying::DynamoResolver, transformer::Transformer, Resolver},
         scalars::{DateTimeScalar, IDScalar, SDLDefinitionScalar},
         variables::VariableResolveDefinition,
         MetaField, MetaInputValue, MetaType,
@@ -241,12 +241,10 @@ impl<'a> Visitor<'a> for ModelDirective {
                         }
 
                         let (resolver, relation, edges, args, ty, cache_control) =
-                            ResolverDirective::resolver_name(&field.node)
-                                .map(|resolver_name| {
+                            ResolverDirective::custom_resolver(&field.node)
+                                .map(|custom_resolver| {
                                     (
-                                        Resolver::CustomResolver(CustomResolver {
-                                            resolver_name: resolver_name.to_owned(),
-                                        }),
+                                        Resolver::CustomResolver(custom_resolver),
                                         None,
                                         vec![],
                                         field
@@ -0,0 +1,210 @@
+use engine::{
+    names::{MONGODB_OUTPUT_FIELD_ID, OUTPUT_FIELD_ID},
+    registry::MongoDBRelation,
+    Positioned,
+};
+use engine_parser::types::{FieldDefinition, ObjectType, TypeKind};
+
+use super::model_directive::{collection_name, connector_name};
+use crate::{
+    directive_de::parse_directive,
+    rules::{directive::Directive, visitor::VisitorContext},
+};
+
+const LOOKUP_DIRECTIVE_NAME: &str = "lookup";
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MongoDBLookupDirective {
+    #[serde(default = "default_to_id")]
+    local_field: String,
+    #[serde(default = "default_to_id")]
+    foreign_field: String,
+}
+
+fn default_to_id() -> String {
+    OUTPUT_FIELD_ID.to_string()
+}
+
+impl Directive for MongoDBLookupDirective {
+    fn definition() -> String {
+        r#"
+        directive @lookup(
+          """
+          The field of this model holding the value to match. Defaults to the id.
+          """
+          localField: String
+
+          """
+          The field of the related model to match against. Defaults to the id.
+          """
+          foreignField: String
+        ) on FIELD_DEFINITION
+        "#
+        .to_string()
+    }
+}
+
+impl MongoDBLookupDirective {
+    /// True, if the field is a relation to another MongoDB model.
+    pub(super) fn is_lookup(field: &FieldDefinition) -> bool {
+        field
+            .directives
+            .iter()
+            .any(|directive| directive.node.name.node == LOOKUP_DIRECTIVE_NAME)
+    }
+
+    /// Parses the relation defined with the `@lookup` directive of the field. Both sides of the
+    /// relation must be models of the same connector, and the matched fields must exist.
+    pub(super) fn relation(
+        ctx: &mut VisitorContext<'_>,
+        connector: &str,
+        model: &ObjectType,
+        field: &Positioned<FieldDefinition>,
+    ) -> Option<MongoDBRelation> {
+        let directive = field
+            .node
+            .directives
+            .iter()
+            .find(|directive| directive.node.name.node == LOOKUP_DIRECTIVE_NAME)?;
+
+        let lookup = match parse_directive::<Self>(&directive.node, ctx.variables) {
+            Ok(lookup) => lookup,
+            Err(error) => {
+                ctx.append_errors(vec![error]);
+                return None;
+            }
+        };
+
+        let field_name = field.node.name.node.as_str();
+        let target_name = field.node.ty.node.base.to_base_type_str();
+
+        let target = ctx.types.get(target_name).and_then(|target| {
+            let TypeKind::Object(ref object) = target.node.kind else {
+                return None;
+            };
+
+            if connector_name(&target.node) != Some(connector) {
+                return None;
+            }
+
+            let foreign_field = database_field_name(object, &lookup.foreign_field);
+
+            Some((collection_name(&target.node), foreign_field))
+        });
+
+        let Some((collection, foreign_field)) = target else {
+            ctx.report_error(
+                vec![field.pos],
+                format!(
+                    "The field '{field_name}' must refer to a model of the connector '{connector}' to use @lookup."
+                ),
+            );
+
+            return None;
+        };
+
+        let Some(foreign_field) = foreign_field else {
+            ctx.report_error(
+                vec![directive.pos],
+                format!(
+                    "The foreign field '{}' of the field '{field_name}' does not exist in the model {target_name}.",
+                    lookup.foreign_field
+                ),
+            );
+
+            return None;
+        };
+
+        let Some(local_field) = database_field_name(model, &lookup.local_field) else {
+            ctx.report_error(
+                vec![directive.pos],
+                format!(
+                    "The local field '{}' of the field '{field_name}' does not exist in the model.",
+                    lookup.local_field
+                ),
+            );
+
+            return None;
+        };
+
+        Some(MongoDBRelation {
+            collection,
+            local_field,
+            foreign_field,
+            is_list: field.node.ty.node.base.is_list(),
+        })
+    }
+}
+
+/// The name of a stored field in the collection, taking `@map` into account.
+fn database_field_name(model: &ObjectType, field_name: &str) -> Option<String> {
+    if field_name == OUTPUT_FIELD_ID {
+        return Some(MONGODB_OUTPUT_FIELD_ID.to_string());
+    }
+
+    model
+        .fields
+        .iter()
+        .map(|field| &field.node)
+        .filter(|field| !MongoDBLookupDirective::is_lookup(field))
+        .find(|field| field.name.node == field_name)
+        .map(|field| field.mapped_name().unwrap_or(field_name).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::assert_validation_error;
+
+    #[test]
+    fn lookup_to_a_non_model() {
+        assert_validation_error!(
+            r#"
+            extend schema
+              @mongodb(
+                name: "test"
+                apiKey: "TEST"
+                url: "https://example.com"
+                dataSource: "TEST"
+                database: "test"
+              )
+
+            type User {
+              name: String!
+            }
+
+            type Post @model(connector: "test", collection: "posts") {
+              title: String!
+              author: User @lookup
+            }
+            "#,
+            "The field 'author' must refer to a model of the connector 'test' to use @lookup."
+        );
+    }
+
+    #[test]
+    fn lookup_with_a_missing_foreign_field() {
+        assert_validation_error!(
+            r#"
+            extend schema
+              @mongodb(
+                name: "test"
+                apiKey: "TEST"
+                url: "https://example.com"
+                dataSource: "TEST"
+                database: "test"
+              )
+
+            type User @model(connector: "test", collection: "users") {
+              name: String!
+              posts: [Post] @lookup(foreignField: "userId")
+            }
+
+            type Post @model(connector: "test", collection: "posts") {
+              title: String!
+            }
+            "#,
+            "The foreign field 'userId' of the field 'posts' does not exist in the model Post."
+        );
+    }
+}
@@ -99,30 +99,37 @@ fn get_config<'a>(ctx: &'a VisitorContext<'_>, r#type: &'a Positioned<TypeDefini
         return None;
     }
 
-    for directive in &r#type.node.directives {
-        if !directive.is_model() {
-            continue;
-        }
-
-        for (key, argument) in &directive.node.arguments {
-            let Some(connector_name) = argument.node.as_str() else {
-                continue;
-            };
+    let connector_name = connector_name(&r#type.node)?;
 
-            if key.node.as_str() != CONNECTOR_KEY {
-                continue;
-            }
+    ctx.registry
+        .borrow()
+        .mongodb_configurations
+        .get(connector_name)
+        .cloned()
+}
 
-            return ctx
-                .registry
-                .borrow()
-                .mongodb_configurations
-                .get(connector_name)
-                .cloned();
-        }
-    }
+/// The name of the connector defined in the `@model` directive of the type.
+pub(super) fn connector_name(r#type: &TypeDefinition) -> Option<&str> {
+    r#type
+        .directives
+        .iter()
+        .filter(|directive| directive.is_model())
+        .flat_map(|directive| directive.node.arguments.iter())
+        .filter(|(key, _)| key.node.as_str() == CONNECTOR_KEY)
+        .find_map(|(_, argument)| argument.node.as_str())
+}
 
-    None
+/// The name of the collection defined in the `@model` directive of the type, defaulting
+/// to the name of the type.
+pub(super) fn collection_name(r#type: &TypeDefinition) -> String {
+    r#type
+        .directives
+        .iter()
+        .filter(|directive| directive.is_model())
+        .filter_map(|directive| directive.node.get_argument(COLLECTION_KEY))
+        .find_map(|argument| argument.node.as_str())
+        .unwrap_or_else(|| r#type.name.as_str())
+        .to_string()
 }
 
 #[cfg(test)]
@@ -5,12 +5,16 @@ use engine::{
 };
 use engine_parser::types::{FieldDefinition, ObjectType, TypeDefinition};
 
-use super::COLLECTION_KEY;
-use crate::rules::{cache_directive::CacheDirective, unique_directive::UniqueDirective, visitor::VisitorContext};
+use crate::rules::{
+    cache_directive::CacheDirective, mongodb_directive::MongoDBLookupDirective, unique_directive::UniqueDirective,
+    visitor::VisitorContext,
+};
 
 pub(crate) struct CreateTypeContext<'a> {
     pub(super) r#type: &'a Positioned<TypeDefinition>,
-    pub(super) object: &'a ObjectType,
+    /// The model without the relation fields, which are not stored in the collection.
+    pub(super) object: ObjectType,
+    lookups: Vec<&'a Positioned<FieldDefinition>>,
     model_name: &'a str,
     model_cache: CacheControl,
     model_auth: Option<AuthConfig>,
@@ -31,22 +35,24 @@ impl<'a> CreateTypeContext<'a> {
     ) -> Self {
         let model_cache = CacheDirective::parse(&r#type.node.directives);
 
-        let collection = r#type
-            .node
-            .directives
-            .iter()
-            .filter(|directive| directive.is_model())
-            .filter_map(|directive| directive.node.get_argument(COLLECTION_KEY))
-            .find_map(|argument| argument.node.as_str())
-            .unwrap_or_else(|| r#type.node.name.as_str())
-            .to_string();
+        let collection = super::collection_name(&r#type.node);
 
         let model_name = r#type.node.name.node.as_str();
 
+        let (lookups, fields): (Vec<_>, Vec<_>) = object
+            .fields
+            .iter()
+            .partition(|field| MongoDBLookupDirective::is_lookup(&field.node));
+
+        let object = ObjectType {
+            implements: object.implements.clone(),
+            fields: fields.into_iter().cloned().collect(),
+        };
+
         let unique_directives = object
             .fields
             .iter()
-            .filter_map(|field| UniqueDirective::parse(visitor_ctx, object, model_name, field))
+            .filter_map(|field| UniqueDirective::parse(visitor_ctx, &object, model_name, field))
             .collect();
 
         let query_type_name = config.namespace.then(|| format!("{}Query", config.name).to_camel());
@@ -55,6 +61,7 @@ impl<'a> CreateTypeContext<'a> {
         Self {
             r#type,
             object,
+            lookups,
             model_name,
             model_cache,
             model_auth,
@@ -86,6 +93,11 @@ impl<'a> CreateTypeContext<'a> {
         self.object.fields.iter().map(|field| &field.node)
     }
 
+    /// The relation fields of the model, defined with the `@lookup` directive.
+    pub(super) fn lookups(&self) -> impl ExactSizeIterator<Item = &'a Positioned<FieldDefinition>> + '_ {
+        self.lookups.iter().copied()
+    }
+
     pub(super) fn unique_directives(&self) -> impl ExactSizeIterator<Item = &UniqueDirective> + '_ {
         self.unique_directives.iter()
     }
@@ -9,8 +9,8 @@ use resolver_data::ResolverData;
 
 use super::CreateTypeContext;
 use crate::rules::{
-    auth_directive::AuthDirective, requires_directive::RequiresDirective, resolver_directive::ResolverDirective,
-    visitor::VisitorContext,
+    auth_directive::AuthDirective, cache_directive::CacheDirective, mongodb_directive::MongoDBLookupDirective,
+    requires_directive::RequiresDirective, resolver_directive::ResolverDirective, visitor::VisitorContext,
 };
 
 pub(super) fn create(visitor_ctx: &mut VisitorContext<'_>, create_ctx: &CreateTypeContext<'_>) {
@@ -44,8 +44,8 @@ pub(super) fn create(visitor_ctx: &mut VisitorContext<'_>, create_ctx: &CreateTy
         }
         .or_else(|| create_ctx.model_auth().clone());
 
-        let resolver_data = match ResolverDirective::resolver_name(field) {
-            Some(resolver_name) => ResolverData::resolver(resolver_name, field),
+        let resolver_data = match ResolverDirective::custom_resolver(field) {
+            Some(custom_resolver) => ResolverData::resolver(custom_resolver, field),
             None => ResolverData::projection(field),
         };
 
@@ -70,6 +70,54 @@ pub(super) fn create(visitor_ctx: &mut VisitorContext<'_>, create_ctx: &CreateTy
         fields.insert(name.clone(), meta_field);
     }
 
+    for field in create_ctx.lookups() {
+        let connector = create_ctx.config().name.as_str();
+
+        let Some(relation) = MongoDBLookupDirective::relation(visitor_ctx, connector, &create_ctx.object, field) else {
+            continue;
+        };
+
+        let name = field.node.name.node.to_string();
+
+        let auth = match AuthDirective::parse(visitor_ctx, &field.node.directives, false) {
+            Ok(auth) => auth,
+            Err(err) => {
+                visitor_ctx.report_error(err.locations, err.message);
+                None
+            }
+        }
+        .or_else(|| create_ctx.model_auth().clone());
+
+        // The related documents are joined to the document with the field name in the
+        // aggregation pipeline.
+        let meta_field = MetaField {
+            name: name.clone(),
+            description: field
+                .node
+                .description
+                .as_ref()
+                .map(|description| description.node.clone()),
+            ty: field.node.ty.node.to_string().into(),
+            cache_control: CacheDirective::parse(&field.node.directives),
+            resolver: Transformer::select(&name).into(),
+            auth,
+            ..Default::default()
+        };
+
+        fields.insert(name.clone(), meta_field);
+
+        visitor_ctx
+            .registry
+            .get_mut()
+            .mongodb_configurations
+            .get_mut(connector)
+            .expect("connector must exist")
+            .relations
+            .entry(type_name.clone())
+            .or_default()
+            .insert(name, relation);
+    }
+
     let description = create_ctx.type_description().map(ToString::to_string);
     let cache_control = create_ctx.model_cache().clone();
     let rust_typename = create_ctx.model_name().to_string();
@@ -19,10 +19,8 @@ pub(super) struct ResolverData {
 }
 
 impl ResolverData {
-    pub(super) fn resolver(resolver_name: &str, field: &FieldDefinition) -> Self {
-        let resolver = Resolver::CustomResolver(CustomResolver {
-            resolver_name: resolver_name.to_owned(),
-        });
+    pub(super) fn resolver(custom_resolver: CustomResolver, field: &FieldDefinition) -> Self {
+        let resolver = Resolver::CustomResolver(custom_resolver);
 
         let field_type = field.ty.node.to_string();
         let cache_control = CacheDirective::parse(&field.directives);
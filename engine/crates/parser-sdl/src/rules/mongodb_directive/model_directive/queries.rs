@@ -1,3 +1,4 @@
+mod aggregate;
 mod create_many;
 mod create_one;
 mod delete_many;
@@ -17,9 +18,11 @@ pub(super) fn create(visitor_ctx: &mut VisitorContext<'_>, create_ctx: &CreateTy
     let delete_output_type = types::delete::register_output(visitor_ctx, create_ctx);
     let update_input_type = types::input::register_input(visitor_ctx, create_ctx);
     let update_output_type = types::update::register_output(visitor_ctx, create_ctx);
+    let aggregate_types = types::aggregate::register_output(visitor_ctx, create_ctx);
 
     filter_one::create(visitor_ctx, create_ctx, &filter_oneof_type);
     filter_many::create(visitor_ctx, create_ctx, &filter_input_type);
+    aggregate::create(visitor_ctx, create_ctx, &filter_input_type, &aggregate_types);
     create_one::create(visitor_ctx, create_ctx, &create_input_type);
     delete_one::create(visitor_ctx, create_ctx, &filter_oneof_type, &delete_output_type);
     delete_many::create(visitor_ctx, create_ctx, &filter_input_type, &delete_output_type);
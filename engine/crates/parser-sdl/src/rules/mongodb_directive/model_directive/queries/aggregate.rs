@@ -0,0 +1,68 @@
+use common_types::auth::Operations;
+use engine::{
+    indexmap::IndexMap,
+    names::MONGODB_AGGREGATE_INPUT_GROUP_BY,
+    registry::{
+        resolvers::{
+            atlas_data_api::{AtlasDataApiResolver, OperationType},
+            Resolver,
+        },
+        MetaField, MetaInputValue,
+    },
+};
+
+use crate::{
+    registry::names::INPUT_ARG_FILTER,
+    rules::{
+        mongodb_directive::model_directive::{types::aggregate::AggregateTypes, CreateTypeContext},
+        visitor::VisitorContext,
+    },
+    utils::to_lower_camelcase,
+};
+
+pub(super) fn create(
+    visitor_ctx: &mut VisitorContext<'_>,
+    create_ctx: &CreateTypeContext<'_>,
+    filter_type: &str,
+    aggregate_types: &AggregateTypes,
+) {
+    let type_name = create_ctx.model_name();
+    let query_name = format!("{}Aggregate", to_lower_camelcase(type_name));
+
+    let mut args = IndexMap::new();
+
+    args.insert(
+        INPUT_ARG_FILTER.to_string(),
+        MetaInputValue::new(INPUT_ARG_FILTER, filter_type),
+    );
+
+    let group_by = MetaInputValue::new(
+        MONGODB_AGGREGATE_INPUT_GROUP_BY,
+        format!("[{}!]", aggregate_types.group_by_type),
+    )
+    .with_description(String::from(
+        "The fields to group the documents by. If not set, all documents are aggregated into one group",
+    ));
+
+    args.insert(MONGODB_AGGREGATE_INPUT_GROUP_BY.to_string(), group_by);
+
+    let resolver = Resolver::MongoResolver(AtlasDataApiResolver {
+        collection: create_ctx.collection().to_string(),
+        operation_type: OperationType::Aggregate,
+        directive_name: create_ctx.config().name.clone(),
+    });
+
+    let meta_field = MetaField {
+        name: query_name,
+        description: Some(format!("Aggregate the {type_name} documents matching the filter")),
+        args,
+        ty: format!("[{}!]!", aggregate_types.output_type).into(),
+        cache_control: create_ctx.model_cache().clone(),
+        resolver,
+        required_operation: Some(Operations::LIST),
+        auth: create_ctx.model_auth().clone(),
+        ..Default::default()
+    };
+
+    visitor_ctx.push_namespaced_query(create_ctx.query_type_name(), meta_field);
+}
@@ -52,7 +52,7 @@ pub(super) fn create(visitor_ctx: &mut VisitorContext<'_>, create_ctx: &CreateTy
     let mut args = input_args(filter_type);
 
     let extra_order_fields = std::iter::once((OUTPUT_FIELD_ID, MONGODB_OUTPUT_FIELD_ID));
-    let order_by_type_name = register_orderby_input(visitor_ctx, create_ctx.object, type_name, extra_order_fields);
+    let order_by_type_name = register_orderby_input(visitor_ctx, &create_ctx.object, type_name, extra_order_fields);
     let order_by_type_name = format!("[{order_by_type_name}]");
 
     args.insert(
@@ -0,0 +1,210 @@
+use common_types::auth::Operations;
+use engine::{
+    names::{
+        MONGODB_AGGREGATE_FIELD_AVG, MONGODB_AGGREGATE_FIELD_COUNT, MONGODB_AGGREGATE_FIELD_GROUP,
+        MONGODB_AGGREGATE_FIELD_MAX, MONGODB_AGGREGATE_FIELD_MIN, MONGODB_AGGREGATE_FIELD_SUM, MONGODB_OUTPUT_FIELD_ID,
+        OUTPUT_FIELD_ID,
+    },
+    registry::{
+        resolvers::{transformer::Transformer, Resolver},
+        EnumType, MetaEnumValue, MetaField, ObjectType,
+    },
+};
+use engine_parser::types::{FieldDefinition, TypeKind};
+
+use crate::rules::{
+    mongodb_directive::{
+        model_directive::create_type_context::CreateTypeContext, DATE_TIME_SCALARS, MONGODB_SCALARS, NUMERIC_SCALARS,
+    },
+    resolver_directive::ResolverDirective,
+    visitor::VisitorContext,
+};
+
+/// The types used in the aggregate query of a model.
+pub(crate) struct AggregateTypes {
+    /// The type of a single aggregated group.
+    pub(crate) output_type: String,
+    /// An enum of the fields the documents can be grouped by.
+    pub(crate) group_by_type: String,
+}
+
+pub(crate) fn register_output(
+    visitor_ctx: &mut VisitorContext<'_>,
+    create_ctx: &CreateTypeContext<'_>,
+) -> AggregateTypes {
+    let model_name = create_ctx.model_name();
+    let output_type_name = format!("{model_name}Aggregate");
+
+    let numeric_fields: Vec<_> = stored_fields(create_ctx)
+        .filter(|field| NUMERIC_SCALARS.contains(&base_type(field)))
+        .collect();
+
+    let sum_fields = numeric_fields
+        .iter()
+        .map(|field| aggregate_field(create_ctx, field, base_type(field)));
+
+    let avg_fields = numeric_fields.iter().map(|field| {
+        let r#type = match base_type(field) {
+            "Decimal" => "Decimal",
+            _ => "Float",
+        };
+
+        aggregate_field(create_ctx, field, r#type)
+    });
+
+    let min_max_fields: Vec<_> = stored_fields(create_ctx)
+        .filter(|field| {
+            let base = base_type(field);
+            NUMERIC_SCALARS.contains(&base) || DATE_TIME_SCALARS.contains(&base) || base == "String"
+        })
+        .collect();
+
+    let min_max_fields = || {
+        min_max_fields
+            .iter()
+            .map(|field| aggregate_field(create_ctx, field, base_type(field)))
+    };
+
+    let group_fields = std::iter::once({
+        let mut id = MetaField::new(OUTPUT_FIELD_ID, "ID");
+        id.mapped_name = Some(String::from(MONGODB_OUTPUT_FIELD_ID));
+        id.resolver = Transformer::select(MONGODB_OUTPUT_FIELD_ID).into();
+        id.required_operation = Some(Operations::LIST);
+        id.auth = create_ctx.model_auth().clone();
+
+        id
+    })
+    .chain(
+        stored_fields(create_ctx)
+            .filter(|field| {
+                let base = base_type(field);
+
+                let is_enum = visitor_ctx
+                    .types
+                    .get(base)
+                    .map(|r#type| matches!(r#type.node.kind, TypeKind::Enum(_)))
+                    .unwrap_or_default();
+
+                MONGODB_SCALARS.contains(&base) || is_enum
+            })
+            .map(|field| aggregate_field(create_ctx, field, base_type(field))),
+    )
+    .collect::<Vec<_>>();
+
+    let group_by_values = group_fields.iter().map(|field| MetaEnumValue::new(field.name.clone()));
+    let group_by_type_name = format!("{model_name}AggregateGroupBy");
+    let group_by_type = EnumType::new(group_by_type_name.clone(), group_by_values);
+
+    visitor_ctx
+        .registry
+        .get_mut()
+        .create_type(|_| group_by_type.into(), &group_by_type_name, &group_by_type_name);
+
+    let mut output_fields = vec![{
+        let mut count = MetaField::new(MONGODB_AGGREGATE_FIELD_COUNT, "Int!");
+        count.description = Some(String::from("The number of documents in the group"));
+        count.resolver = Transformer::select(MONGODB_AGGREGATE_FIELD_COUNT).into();
+
+        count
+    }];
+
+    let object_fields = [
+        (
+            MONGODB_AGGREGATE_FIELD_GROUP,
+            "Group",
+            "The values of the fields the documents are grouped by",
+            group_fields,
+        ),
+        (
+            MONGODB_AGGREGATE_FIELD_SUM,
+            "Sum",
+            "The sum of the numeric fields in the group",
+            sum_fields.collect(),
+        ),
+        (
+            MONGODB_AGGREGATE_FIELD_AVG,
+            "Avg",
+            "The average of the numeric fields in the group",
+            avg_fields.collect(),
+        ),
+        (
+            MONGODB_AGGREGATE_FIELD_MIN,
+            "MinMax",
+            "The smallest values of the fields in the group",
+            min_max_fields().collect(),
+        ),
+        (
+            MONGODB_AGGREGATE_FIELD_MAX,
+            "MinMax",
+            "The largest values of the fields in the group",
+            min_max_fields().collect(),
+        ),
+    ];
+
+    for (field_name, suffix, description, fields) in object_fields {
+        if fields.is_empty() {
+            continue;
+        }
+
+        let type_name = format!("{output_type_name}{suffix}");
+        let object = ObjectType::new(type_name.clone(), fields);
+
+        visitor_ctx
+            .registry
+            .get_mut()
+            .create_type(|_| object.into(), &type_name, &type_name);
+
+        let mut field = MetaField::new(field_name, type_name);
+        field.description = Some(String::from(description));
+        field.resolver = Transformer::select(field_name).into();
+
+        output_fields.push(field);
+    }
+
+    for field in &mut output_fields {
+        field.required_operation = Some(Operations::LIST);
+        field.auth = create_ctx.model_auth().clone();
+    }
+
+    let output_type = ObjectType::new(output_type_name.clone(), output_fields)
+        .with_description(format!("An aggregated group of {model_name} documents"));
+
+    visitor_ctx
+        .registry
+        .get_mut()
+        .create_type(|_| output_type.into(), &output_type_name, &output_type_name);
+
+    AggregateTypes {
+        output_type: output_type_name,
+        group_by_type: group_by_type_name,
+    }
+}
+
+/// The non-list fields stored in the collection.
+fn stored_fields<'a>(create_ctx: &'a CreateTypeContext<'_>) -> impl Iterator<Item = &'a FieldDefinition> + 'a {
+    create_ctx
+        .fields()
+        .filter(|field| !field.ty.node.base.is_list())
+        .filter(|field| ResolverDirective::resolver_name(field).is_none())
+}
+
+fn base_type(field: &FieldDefinition) -> &str {
+    field.ty.node.base.to_base_type_str()
+}
+
+fn aggregate_field(create_ctx: &CreateTypeContext<'_>, field: &FieldDefinition, r#type: &str) -> MetaField {
+    let key = field.mapped_name().unwrap_or_else(|| field.name()).to_string();
+    let mut resolver = Resolver::from(Transformer::Select { key });
+
+    if let "Timestamp" = r#type {
+        resolver = resolver.and_then(Transformer::MongoTimestamp);
+    }
+
+    let mut meta_field = MetaField::new(field.name(), r#type);
+    meta_field.mapped_name = field.mapped_name().map(ToString::to_string);
+    meta_field.resolver = resolver;
+    meta_field.required_operation = Some(Operations::LIST);
+    meta_field.auth = create_ctx.model_auth().clone();
+
+    meta_field
+}
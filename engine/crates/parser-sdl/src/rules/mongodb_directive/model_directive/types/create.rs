@@ -9,7 +9,7 @@ use engine::{
 };
 
 use crate::{
-    registry::{get_length_validator, names::MetaNames},
+    registry::{get_validators, names::MetaNames},
     rules::{
         default_directive::DefaultDirective,
         mongodb_directive::model_directive::create_type_context::CreateTypeContext, visitor::VisitorContext,
@@ -34,7 +34,7 @@ pub(crate) fn register_input(visitor_ctx: &mut VisitorContext<'_>, create_ctx: &
         input.description = field.description().map(ToString::to_string);
         input.rename = field.mapped_name().map(ToString::to_string);
         input.default_value = DefaultDirective::default_value_of(field);
-        input.validators = get_length_validator(field).map(|validator| vec![validator]);
+        input.validators = get_validators(&field.directives, &field.ty.node);
 
         input
     });
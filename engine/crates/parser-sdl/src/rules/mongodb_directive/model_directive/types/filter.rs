@@ -38,7 +38,7 @@ pub(crate) fn register_input(visitor_ctx: &mut VisitorContext<'_>, create_ctx: &
 
     register_type_input(
         visitor_ctx,
-        create_ctx.object,
+        &create_ctx.object,
         &input_type_name,
         implicit_fields.chain(logical_array_fields),
     );
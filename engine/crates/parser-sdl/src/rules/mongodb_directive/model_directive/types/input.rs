@@ -9,7 +9,7 @@ use crate::{
 };
 
 pub(crate) fn register_input(visitor_ctx: &mut VisitorContext<'_>, create_ctx: &CreateTypeContext<'_>) -> String {
-    register_type_input(visitor_ctx, create_ctx.object, create_ctx.r#type)
+    register_type_input(visitor_ctx, &create_ctx.object, create_ctx.r#type)
 }
 
 pub(crate) fn register_type_input(
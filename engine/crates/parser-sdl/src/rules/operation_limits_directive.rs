@@ -0,0 +1,140 @@
+use engine_parser::types::SchemaDefinition;
+
+use crate::{directive_de::parse_directive, federation::OperationLimits};
+
+use super::{
+    directive::Directive,
+    visitor::{Visitor, VisitorContext},
+};
+
+/// An `@operationLimits` directive that restricts the shape of the operations a federated
+/// graph accepts
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct OperationLimitsDirective {
+    depth: Option<u16>,
+    aliases: Option<u16>,
+    root_fields: Option<u16>,
+    complexity: Option<u16>,
+    tokens: Option<u32>,
+    parser_recursion: Option<u16>,
+}
+
+impl Directive for OperationLimitsDirective {
+    fn definition() -> String {
+        r#"
+        directive @operationLimits(
+          "The maximum nesting of fields in an operation"
+          depth: Int
+          "The maximum number of aliased fields in an operation"
+          aliases: Int
+          "The maximum number of root fields in an operation"
+          rootFields: Int
+          "The maximum complexity of an operation, every field costs 1 unless it has a @cost"
+          complexity: Int
+          "The maximum number of tokens in a query"
+          tokens: Int
+          "The maximum nesting of braces, brackets and parentheses in a query"
+          parserRecursion: Int
+        ) on SCHEMA
+        "#
+        .to_string()
+    }
+}
+
+pub struct OperationLimitsDirectiveVisitor;
+
+impl Visitor<'_> for OperationLimitsDirectiveVisitor {
+    fn enter_schema(&mut self, ctx: &mut VisitorContext<'_>, doc: &engine::Positioned<SchemaDefinition>) {
+        let directives = doc
+            .node
+            .directives
+            .iter()
+            .filter(|directive| directive.node.name.node == "operationLimits")
+            .collect::<Vec<_>>();
+
+        if !ctx.registry.borrow().is_federated {
+            if !directives.is_empty() {
+                ctx.report_error(
+                    directives.into_iter().map(|directive| directive.pos).collect(),
+                    "The @operationLimits directive is only valid in federated graphs",
+                );
+            }
+            return;
+        }
+
+        for directive in directives {
+            let directive = match parse_directive::<OperationLimitsDirective>(directive, ctx.variables) {
+                Ok(directive) => directive,
+                Err(error) => {
+                    ctx.append_errors(vec![error]);
+                    return;
+                }
+            };
+
+            // Later directives only override the limits they define.
+            let limits = &mut ctx.federated_graph_config.operation_limits;
+            *limits = OperationLimits {
+                depth: directive.depth.or(limits.depth),
+                aliases: directive.aliases.or(limits.aliases),
+                root_fields: directive.root_fields.or(limits.root_fields),
+                complexity: directive.complexity.or(limits.complexity),
+                tokens: directive.tokens.or(limits.tokens),
+                parser_recursion: directive.parser_recursion.or(limits.parser_recursion),
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{tests::assert_validation_error, to_parse_result_with_variables};
+
+    #[test]
+    fn test_happy_path() {
+        let schema = r#"
+            extend schema
+                @operationLimits(depth: 10, aliases: 5, complexity: 100)
+                @operationLimits(depth: 3, rootFields: 2, tokens: 1000, parserRecursion: 20)
+                @graph(type: federated)
+        "#;
+
+        let result = to_parse_result_with_variables(schema, &HashMap::new()).unwrap();
+
+        insta::assert_debug_snapshot!(result.federated_graph_config.unwrap().operation_limits, @r###"
+        OperationLimits {
+            depth: Some(
+                3,
+            ),
+            aliases: Some(
+                5,
+            ),
+            root_fields: Some(
+                2,
+            ),
+            complexity: Some(
+                100,
+            ),
+            tokens: Some(
+                1000,
+            ),
+            parser_recursion: Some(
+                20,
+            ),
+        }
+        "###);
+    }
+
+    #[test]
+    fn test_errors_if_not_federated_graph() {
+        assert_validation_error!(
+            r#"
+            extend schema
+              @operationLimits(depth: 3)
+            "#,
+            "The @operationLimits directive is only valid in federated graphs"
+        );
+    }
+}
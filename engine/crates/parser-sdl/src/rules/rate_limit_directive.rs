@@ -0,0 +1,416 @@
+use std::{collections::HashSet, time::Duration};
+
+use engine_parser::types::SchemaDefinition;
+use inflector::Inflector;
+use runtime::rate_limiting::{RateLimitKey, RateLimitRule};
+
+use crate::directive_de::parse_directive;
+
+use super::{
+    directive::Directive,
+    visitor::{Visitor, VisitorContext},
+};
+
+const RATE_LIMIT_DIRECTIVE_NAME: &str = "rateLimit";
+
+/// A `@rateLimit` directive limiting the requests, and optionally the cost of the operations,
+/// accepted within fixed windows
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RateLimitDirective {
+    rules: Vec<RateLimitRuleInput>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct RateLimitRuleInput {
+    name: String,
+    requests: u64,
+    cost: Option<u64>,
+    duration: u64,
+    key: Option<RateLimitKeyInput>,
+    subgraph: Option<String>,
+    connector: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct RateLimitKeyInput {
+    #[serde(default)]
+    client_ip: bool,
+    client_ip_header: Option<String>,
+    header: Option<String>,
+    jwt_claim: Option<String>,
+    #[serde(default)]
+    operation_name: bool,
+}
+
+impl RateLimitKeyInput {
+    fn into_key(self) -> Option<RateLimitKey> {
+        let mut keys = [
+            self.client_ip.then_some(RateLimitKey::ClientIp),
+            self.client_ip_header.map(RateLimitKey::ClientIpHeader),
+            self.header.map(RateLimitKey::Header),
+            self.jwt_claim.map(RateLimitKey::JwtClaim),
+            self.operation_name.then_some(RateLimitKey::OperationName),
+        ]
+        .into_iter()
+        .flatten();
+
+        match (keys.next(), keys.next()) {
+            (Some(key), None) => Some(key),
+            _ => None,
+        }
+    }
+}
+
+impl Directive for RateLimitDirective {
+    fn definition() -> String {
+        r#"
+        directive @rateLimit(
+          "Every rule is applied, a request exceeding any of them is rejected"
+          rules: [RateLimitRule!]!
+        ) on SCHEMA
+
+        input RateLimitRule {
+          name: String!
+          "The maximum number of requests within a window"
+          requests: Int!
+          "The maximum total cost of the operations within a window, every selected field costs 1 unless it has a @cost"
+          cost: Int
+          "The duration of a window, in seconds"
+          duration: Int!
+          "What the requests are counted by, all of them share a single counter by default"
+          key: RateLimitKey
+          "Only limits the requests sent to this subgraph"
+          subgraph: String
+          "Only limits the requests sent to this OpenAPI or GraphQL connector"
+          connector: String
+        }
+
+        "Exactly one of the fields must be set"
+        input RateLimitKey {
+          "The address the request came from"
+          clientIp: Boolean
+          "The client IP set in this header by a trusted proxy, like x-forwarded-for, or the address the request came from without it"
+          clientIpHeader: String
+          header: String
+          jwtClaim: String
+          operationName: Boolean
+        }
+        "#
+        .to_string()
+    }
+}
+
+/// Must run with the connector visitors, the rules refer to the connectors by their name.
+pub struct RateLimitDirectiveVisitor;
+
+impl Visitor<'_> for RateLimitDirectiveVisitor {
+    fn enter_schema(&mut self, ctx: &mut VisitorContext<'_>, doc: &engine::Positioned<SchemaDefinition>) {
+        let is_federated = ctx.registry.borrow().is_federated;
+        let mut names = HashSet::new();
+        let mut rules = Vec::new();
+
+        for directive in doc
+            .node
+            .directives
+            .iter()
+            .filter(|directive| directive.node.name.node == RATE_LIMIT_DIRECTIVE_NAME)
+        {
+            let parsed = match parse_directive::<RateLimitDirective>(directive, ctx.variables) {
+                Ok(parsed) => parsed,
+                Err(error) => {
+                    ctx.append_errors(vec![error]);
+                    continue;
+                }
+            };
+
+            for rule in parsed.rules {
+                let error = if !names.insert(rule.name.clone()) {
+                    Some(format!("The rate limit rule '{}' is defined more than once", rule.name))
+                } else if rule.requests == 0 || rule.duration == 0 || rule.cost == Some(0) {
+                    Some(format!(
+                        "The requests, cost and duration of the rate limit rule '{}' must be positive",
+                        rule.name
+                    ))
+                } else if rule.subgraph.is_some() && !is_federated {
+                    Some(format!(
+                        "The rate limit rule '{}' can only limit a subgraph in federated graphs",
+                        rule.name
+                    ))
+                } else if rule.connector.is_some() && is_federated {
+                    Some(format!(
+                        "The rate limit rule '{}' can't limit a connector in federated graphs",
+                        rule.name
+                    ))
+                } else if rule.subgraph.is_some() && rule.connector.is_some() {
+                    Some(format!(
+                        "The rate limit rule '{}' can't limit both a subgraph and a connector",
+                        rule.name
+                    ))
+                } else if rule.cost.is_some() && (rule.subgraph.is_some() || rule.connector.is_some()) {
+                    Some(format!(
+                        "The rate limit rule '{}' can only limit the cost of whole operations",
+                        rule.name
+                    ))
+                } else {
+                    None
+                };
+                if let Some(error) = error {
+                    ctx.report_error(vec![directive.pos], error);
+                    continue;
+                }
+
+                let key = match rule.key.map(RateLimitKeyInput::into_key) {
+                    None => RateLimitKey::Global,
+                    Some(Some(key)) => key,
+                    Some(None) => {
+                        ctx.report_error(
+                            vec![directive.pos],
+                            format!(
+                                "The key of the rate limit rule '{}' must have exactly one field",
+                                rule.name
+                            ),
+                        );
+                        continue;
+                    }
+                };
+                if matches!(key, RateLimitKey::JwtClaim(_)) && is_federated {
+                    ctx.report_error(
+                        vec![directive.pos],
+                        format!(
+                            "The rate limit rule '{}' can't be keyed by a JWT claim in federated graphs",
+                            rule.name
+                        ),
+                    );
+                    continue;
+                }
+
+                let source = match (rule.subgraph, rule.connector) {
+                    (Some(subgraph), _) => Some(subgraph),
+                    (None, Some(connector)) => match connector_source(ctx, &connector) {
+                        Some(source) => Some(source),
+                        None => {
+                            ctx.report_error(
+                                vec![directive.pos],
+                                format!(
+                                    "The rate limit rule '{}' limits '{connector}', which isn't an OpenAPI or GraphQL connector",
+                                    rule.name
+                                ),
+                            );
+                            continue;
+                        }
+                    },
+                    (None, None) => None,
+                };
+
+                rules.push(RateLimitRule {
+                    name: rule.name,
+                    key,
+                    requests: rule.requests,
+                    cost: rule.cost,
+                    duration: Duration::from_secs(rule.duration),
+                    source,
+                });
+            }
+        }
+
+        if is_federated {
+            ctx.federated_graph_config.rate_limits = rules;
+        } else {
+            ctx.registry.borrow_mut().rate_limits = rules;
+        }
+    }
+}
+
+/// The name the resolvers of the connector are identified by, OpenAPI ones use its camel case.
+fn connector_source(ctx: &VisitorContext<'_>, name: &str) -> Option<String> {
+    if ctx
+        .openapi_directives
+        .iter()
+        .any(|(directive, _)| directive.name == name)
+    {
+        Some(name.to_camel_case())
+    } else if ctx
+        .graphql_directives
+        .iter()
+        .any(|(directive, _)| directive.name == name)
+    {
+        Some(name.to_string())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, time::Duration};
+
+    use runtime::rate_limiting::{RateLimitKey, RateLimitRule};
+
+    use crate::{tests::assert_validation_error, to_parse_result_with_variables};
+
+    #[test]
+    fn test_happy_path() {
+        let schema = r#"
+            extend schema
+              @openapi(
+                name: "stripe-payments"
+                url: "https://api.stripe.com"
+                schema: "https://raw.githubusercontent.com/stripe/openapi/master/openapi/spec3.json"
+              )
+              @rateLimit(rules: [
+                { name: "global", requests: 1000, cost: 50000, duration: 60 }
+                { name: "per-ip", requests: 100, duration: 10, key: { clientIp: true } }
+                { name: "per-forwarded-ip", requests: 100, duration: 10, key: { clientIpHeader: "x-forwarded-for" } }
+              ])
+              @rateLimit(rules: [
+                { name: "per-user", requests: 10, duration: 1, key: { jwtClaim: "sub" }, connector: "stripe-payments" }
+              ])
+
+            type Product {
+                id: ID!
+            }
+        "#;
+
+        let registry = to_parse_result_with_variables(schema, &HashMap::new())
+            .unwrap()
+            .registry;
+
+        assert_eq!(
+            registry.rate_limits,
+            vec![
+                RateLimitRule {
+                    name: "global".to_string(),
+                    key: RateLimitKey::Global,
+                    requests: 1000,
+                    cost: Some(50000),
+                    duration: Duration::from_secs(60),
+                    source: None,
+                },
+                RateLimitRule {
+                    name: "per-ip".to_string(),
+                    key: RateLimitKey::ClientIp,
+                    requests: 100,
+                    cost: None,
+                    duration: Duration::from_secs(10),
+                    source: None,
+                },
+                RateLimitRule {
+                    name: "per-forwarded-ip".to_string(),
+                    key: RateLimitKey::ClientIpHeader("x-forwarded-for".to_string()),
+                    requests: 100,
+                    cost: None,
+                    duration: Duration::from_secs(10),
+                    source: None,
+                },
+                RateLimitRule {
+                    name: "per-user".to_string(),
+                    key: RateLimitKey::JwtClaim("sub".to_string()),
+                    requests: 10,
+                    cost: None,
+                    duration: Duration::from_secs(1),
+                    source: Some("stripePayments".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_federated_graph() {
+        let schema = r#"
+            extend schema
+              @graph(type: federated)
+              @rateLimit(rules: [
+                { name: "products", requests: 10, duration: 60, key: { header: "x-api-key" }, subgraph: "products" }
+              ])
+        "#;
+
+        let result = to_parse_result_with_variables(schema, &HashMap::new()).unwrap();
+
+        assert!(result.registry.rate_limits.is_empty());
+        assert_eq!(
+            result.federated_graph_config.unwrap().rate_limits,
+            vec![RateLimitRule {
+                name: "products".to_string(),
+                key: RateLimitKey::Header("x-api-key".to_string()),
+                requests: 10,
+                cost: None,
+                duration: Duration::from_secs(60),
+                source: Some("products".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_duplicate_names() {
+        assert_validation_error!(
+            r#"
+            extend schema
+              @rateLimit(rules: [
+                { name: "global", requests: 10, duration: 60 }
+                { name: "global", requests: 100, duration: 3600 }
+              ])
+            "#,
+            "The rate limit rule 'global' is defined more than once"
+        );
+    }
+
+    #[test]
+    fn test_zero_duration() {
+        assert_validation_error!(
+            r#"
+            extend schema
+              @rateLimit(rules: [{ name: "global", requests: 10, duration: 0 }])
+            "#,
+            "The requests, cost and duration of the rate limit rule 'global' must be positive"
+        );
+    }
+
+    #[test]
+    fn test_multiple_keys() {
+        assert_validation_error!(
+            r#"
+            extend schema
+              @rateLimit(rules: [
+                { name: "global", requests: 10, duration: 60, key: { clientIp: true, header: "x-api-key" } }
+              ])
+            "#,
+            "The key of the rate limit rule 'global' must have exactly one field"
+        );
+    }
+
+    #[test]
+    fn test_subgraph_in_standalone_graph() {
+        assert_validation_error!(
+            r#"
+            extend schema
+              @rateLimit(rules: [{ name: "products", requests: 10, duration: 60, subgraph: "products" }])
+            "#,
+            "The rate limit rule 'products' can only limit a subgraph in federated graphs"
+        );
+    }
+
+    #[test]
+    fn test_unknown_connector() {
+        assert_validation_error!(
+            r#"
+            extend schema
+              @rateLimit(rules: [{ name: "stripe", requests: 10, duration: 60, connector: "stripe" }])
+            "#,
+            "The rate limit rule 'stripe' limits 'stripe', which isn't an OpenAPI or GraphQL connector"
+        );
+    }
+
+    #[test]
+    fn test_connector_cost() {
+        assert_validation_error!(
+            r#"
+            extend schema
+              @rateLimit(rules: [{ name: "stripe", requests: 10, cost: 100, duration: 60, connector: "stripe" }])
+            "#,
+            "The rate limit rule 'stripe' can only limit the cost of whole operations"
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use engine::Positioned;
+use engine::{registry::resolvers::custom::CustomResolver, Positioned};
 use engine_parser::types::{FieldDefinition, TypeDefinition};
 use engine_value::ConstValue;
 
@@ -9,6 +9,7 @@ use super::{
 
 pub const RESOLVER_DIRECTIVE: &str = "resolver";
 pub const NAME_ARGUMENT: &str = "name";
+pub const BATCH_ARGUMENT: &str = "batch";
 
 pub struct ResolverDirective;
 
@@ -24,6 +25,23 @@ impl ResolverDirective {
                 _ => None,
             })
     }
+
+    /// Whether the resolver is invoked once for all the parents of a list.
+    pub fn is_batched(field: &FieldDefinition) -> bool {
+        field
+            .directives
+            .iter()
+            .find(|directive| directive.node.name.node == RESOLVER_DIRECTIVE)
+            .and_then(|directive| directive.node.get_argument(BATCH_ARGUMENT))
+            .is_some_and(|value| matches!(value.node, ConstValue::Boolean(true)))
+    }
+
+    pub fn custom_resolver(field: &FieldDefinition) -> Option<CustomResolver> {
+        Self::resolver_name(field).map(|resolver_name| CustomResolver {
+            resolver_name: resolver_name.to_owned(),
+            batched: Self::is_batched(field),
+        })
+    }
 }
 
 impl<'a> Visitor<'a> for ResolverDirective {
@@ -39,7 +57,12 @@ impl<'a> Visitor<'a> for ResolverDirective {
             .iter()
             .find(|d| d.node.name.node == RESOLVER_DIRECTIVE)
         {
-            if let Ok(arguments) = super::directive::extract_arguments(ctx, directive, &[&[NAME_ARGUMENT]], None) {
+            if let Ok(arguments) = super::directive::extract_arguments(
+                ctx,
+                directive,
+                &[&[NAME_ARGUMENT], &[BATCH_ARGUMENT, NAME_ARGUMENT]],
+                None,
+            ) {
                 if let ConstValue::String(resolver_name) = arguments.get(NAME_ARGUMENT).unwrap() {
                     // OK.
                     ctx.required_resolvers.insert(resolver_name.clone());
@@ -49,6 +72,12 @@ impl<'a> Visitor<'a> for ResolverDirective {
                         "The @{RESOLVER_DIRECTIVE} directive expects the `{name}` argument to be a string".to_string(),
                     );
                 }
+                if !matches!(arguments.get(BATCH_ARGUMENT), None | Some(ConstValue::Boolean(_))) {
+                    ctx.report_error(
+                        vec![directive.pos],
+                        format!("The @{RESOLVER_DIRECTIVE} directive expects the `{BATCH_ARGUMENT}` argument to be a boolean"),
+                    );
+                }
             }
         }
     }
@@ -57,7 +86,7 @@ impl<'a> Visitor<'a> for ResolverDirective {
 impl Directive for ResolverDirective {
     fn definition() -> String {
         r"
-        directive @resolver(name: String) on FIELD_DEFINITION
+        directive @resolver(name: String, batch: Boolean) on FIELD_DEFINITION
         "
         .to_string()
     }
@@ -78,7 +107,7 @@ mod tests {
             balance: Int! @resolver
         }
     ", &[
-        "The @resolver directive takes a single `name` argument"
+        "The @resolver directive expects at least one of the `name` or `batch` and `name` arguments"
     ])]
     #[case(r#"
         type Customer @model {
@@ -86,7 +115,7 @@ mod tests {
             balance: Int! @resolver(path: "resolvers/balance")
         }
     "#, &[
-        "The @resolver directive takes a single `name` argument"
+        "Unexpected argument path, @resolver directive only supports the following arguments: `batch` and `name`"
     ])]
     #[case(r#"
         type Customer @model {
@@ -94,6 +123,20 @@ mod tests {
             balance: Int! @resolver(name: "resolvers/balance")
         }
     "#, &[])]
+    #[case(r#"
+        type Customer @model {
+            id: ID!
+            balance: Int! @resolver(name: "balance", batch: "yes")
+        }
+    "#, &[
+        "The @resolver directive expects the `batch` argument to be a boolean"
+    ])]
+    #[case(r#"
+        type Customer @model {
+            id: ID!
+            balance: Int! @resolver(name: "balance", batch: true)
+        }
+    "#, &[])]
     fn test_parse_result(#[case] schema: &str, #[case] expected_messages: &[&str]) {
         let schema = parse_schema(schema).unwrap();
         let mut ctx = VisitorContext::new_for_tests(&schema);
@@ -1,5 +1,7 @@
 use engine::Positioned;
-use engine_parser::types::{TypeDefinition, TypeKind};
+use engine_parser::types::{BaseType, ConstDirective, TypeDefinition, TypeKind};
+use engine_value::ConstValue;
+use runtime::search::Analyzer;
 
 use super::{
     auth_directive::AuthDirective,
@@ -10,14 +12,62 @@ use super::{
 use crate::registry::add_query_search;
 
 pub const SEARCH_DIRECTIVE: &str = "search";
+pub const ANALYZER_ARGUMENT: &str = "analyzer";
+pub const DIMENSIONS_ARGUMENT: &str = "dimensions";
+const MAX_DIMENSIONS: u32 = 4096;
 
 pub struct SearchDirective;
 
+impl SearchDirective {
+    /// Analyzer set on the field or, for a model, on all of its text fields. Invalid values are
+    /// reported during validation.
+    pub fn analyzer(directives: &[Positioned<ConstDirective>]) -> Option<Analyzer> {
+        directives
+            .iter()
+            .find(|directive| directive.is_search())
+            .and_then(|directive| Self::parse_analyzer(&directive.node).ok().flatten())
+    }
+
+    fn parse_analyzer(directive: &ConstDirective) -> Result<Option<Analyzer>, String> {
+        match directive.get_argument(ANALYZER_ARGUMENT).map(|value| &value.node) {
+            None | Some(ConstValue::Null) => Ok(None),
+            Some(ConstValue::String(name)) => name.parse().map(Some).map_err(|_| {
+                format!(
+                    "Unknown analyzer '{name}', expected one of: {}.",
+                    Analyzer::names().join(", ")
+                )
+            }),
+            Some(_) => Err(format!("The {ANALYZER_ARGUMENT} must be a string.")),
+        }
+    }
+
+    /// Number of dimensions of a vector field. Invalid values are reported during validation.
+    pub fn dimensions(directives: &[Positioned<ConstDirective>]) -> Option<u32> {
+        directives
+            .iter()
+            .find(|directive| directive.is_search())
+            .and_then(|directive| Self::parse_dimensions(&directive.node).ok().flatten())
+    }
+
+    fn parse_dimensions(directive: &ConstDirective) -> Result<Option<u32>, String> {
+        match directive.get_argument(DIMENSIONS_ARGUMENT).map(|value| &value.node) {
+            None | Some(ConstValue::Null) => Ok(None),
+            Some(ConstValue::Number(number)) => number
+                .as_u64()
+                .and_then(|dimensions| u32::try_from(dimensions).ok())
+                .filter(|dimensions| (1..=MAX_DIMENSIONS).contains(dimensions))
+                .map(Some)
+                .ok_or_else(|| format!("The {DIMENSIONS_ARGUMENT} must be between 1 and {MAX_DIMENSIONS}.")),
+            Some(_) => Err(format!("The {DIMENSIONS_ARGUMENT} must be an integer.")),
+        }
+    }
+}
+
 impl Directive for SearchDirective {
     fn definition() -> String {
         format!(
             r#"
-            directive @{SEARCH_DIRECTIVE} on OBJECT | FIELD_DEFINITION
+            directive @{SEARCH_DIRECTIVE}({ANALYZER_ARGUMENT}: String, {DIMENSIONS_ARGUMENT}: Int) on OBJECT | FIELD_DEFINITION
             "#
         )
     }
@@ -42,6 +92,22 @@ impl<'a> Visitor<'a> for SearchDirective {
                 format!("The @{SEARCH_DIRECTIVE} directive can only be used on @{MODEL_DIRECTIVE} types."),
             );
         }
+        if let Some(directive) = type_definition
+            .node
+            .directives
+            .iter()
+            .find(|directive| directive.is_search())
+        {
+            if let Err(message) = Self::parse_analyzer(&directive.node) {
+                ctx.report_error(vec![directive.pos], message);
+            }
+            if directive.node.get_argument(DIMENSIONS_ARGUMENT).is_some() {
+                ctx.report_error(
+                    vec![directive.pos],
+                    format!("The {DIMENSIONS_ARGUMENT} can only be used on fields."),
+                );
+            }
+        }
         if let TypeKind::Object(object) = &type_definition.node.kind {
             for field in &object.fields {
                 if let Some(directive) = field.node.directives.iter().find(|directive| directive.is_search()) {
@@ -61,6 +127,32 @@ impl<'a> Visitor<'a> for SearchDirective {
                             format!("The @{SEARCH_DIRECTIVE} directive cannot be used with the {ty} type."),
                         ),
                     }
+
+                    match Self::parse_analyzer(&directive.node) {
+                        Ok(Some(_)) if !matches!(field_base_type, "String" | "URL" | "Email") => ctx.report_error(
+                            vec![directive.pos],
+                            format!("The {ANALYZER_ARGUMENT} can only be used on String, URL and Email fields."),
+                        ),
+                        Err(message) => ctx.report_error(vec![directive.pos], message),
+                        Ok(_) => (),
+                    }
+
+                    match Self::parse_dimensions(&directive.node) {
+                        Ok(Some(_)) => {
+                            let is_float_list = matches!(
+                                &field.node.ty.node.base,
+                                BaseType::List(item) if matches!(&item.base, BaseType::Named(name) if name.as_str() == "Float")
+                            );
+                            if !is_float_list {
+                                ctx.report_error(
+                                    vec![directive.pos],
+                                    format!("The {DIMENSIONS_ARGUMENT} can only be used on [Float] fields."),
+                                );
+                            }
+                        }
+                        Err(message) => ctx.report_error(vec![directive.pos], message),
+                        Ok(None) => (),
+                    }
                 }
             }
 
@@ -149,6 +149,15 @@ mod tests {
                     },
                 },
                 default_headers: [],
+                operation_limits: OperationLimits {
+                    depth: None,
+                    aliases: None,
+                    root_fields: None,
+                    complexity: None,
+                    tokens: None,
+                    parser_recursion: None,
+                },
+                rate_limits: [],
             },
         )
         "###);
@@ -0,0 +1,268 @@
+use std::time::Duration;
+
+use engine_parser::types::SchemaDefinition;
+use url::Url;
+
+use crate::directive_de::parse_directive;
+
+use super::{
+    directive::Directive,
+    visitor::{Visitor, VisitorContext},
+};
+
+const TELEMETRY_DIRECTIVE_NAME: &str = "telemetry";
+const DEFAULT_SERVICE_NAME: &str = "grafbase";
+const DEFAULT_EXPORT_TIMEOUT: Duration = Duration::from_secs(10);
+const DEFAULT_METRICS_INTERVAL: Duration = Duration::from_secs(60);
+
+/// A `@telemetry` directive exporting the traces and metrics of the gateway to an
+/// OpenTelemetry collector
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct TelemetryDirective {
+    service_name: Option<String>,
+    pub tracing: Option<TelemetryTracing>,
+    pub metrics: Option<TelemetryMetrics>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct TelemetryTracing {
+    pub exporter: TelemetryExporter,
+    sampling: Option<f64>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct TelemetryMetrics {
+    pub exporter: TelemetryExporter,
+    interval: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct TelemetryExporter {
+    pub endpoint: Url,
+    #[serde(default)]
+    pub protocol: TelemetryProtocol,
+    #[serde(default)]
+    pub headers: Vec<TelemetryHeader>,
+    timeout: Option<u64>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TelemetryProtocol {
+    #[default]
+    Grpc,
+    Http,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TelemetryHeader {
+    pub name: String,
+    pub value: String,
+}
+
+impl TelemetryDirective {
+    pub fn service_name(&self) -> &str {
+        self.service_name.as_deref().unwrap_or(DEFAULT_SERVICE_NAME)
+    }
+}
+
+impl TelemetryTracing {
+    /// The ratio of the traces that are sampled, all of them by default.
+    pub fn sampling(&self) -> f64 {
+        self.sampling.unwrap_or(1.0)
+    }
+}
+
+impl TelemetryMetrics {
+    pub fn interval(&self) -> Duration {
+        self.interval.map_or(DEFAULT_METRICS_INTERVAL, Duration::from_secs)
+    }
+}
+
+impl TelemetryExporter {
+    pub fn timeout(&self) -> Duration {
+        self.timeout.map_or(DEFAULT_EXPORT_TIMEOUT, Duration::from_secs)
+    }
+}
+
+impl Directive for TelemetryDirective {
+    fn definition() -> String {
+        r#"
+        directive @telemetry(
+          "The service name attached to the exported traces and metrics, grafbase by default"
+          serviceName: String
+          "Exports a trace for each operation, with spans for the resolvers and subgraph requests"
+          tracing: TelemetryTracing
+          "Exports the operation counts, latencies, errors and cache reads"
+          metrics: TelemetryMetrics
+        ) on SCHEMA
+
+        input TelemetryTracing {
+            exporter: TelemetryExporter!
+            "The ratio of traces to sample, between 0 and 1. Traces with a sampled parent are always sampled."
+            sampling: Float
+        }
+
+        input TelemetryMetrics {
+            exporter: TelemetryExporter!
+            "How often the metrics are exported, in seconds"
+            interval: Int
+        }
+
+        input TelemetryExporter {
+            "The URL of the OpenTelemetry collector"
+            endpoint: Url!
+            protocol: TelemetryProtocol
+            headers: [TelemetryHeader!]
+            "The timeout of an export, in seconds"
+            timeout: Int
+        }
+
+        enum TelemetryProtocol {
+            GRPC
+            HTTP
+        }
+
+        input TelemetryHeader {
+            name: String!
+            value: String!
+        }
+        "#
+        .to_string()
+    }
+}
+
+pub struct TelemetryDirectiveVisitor;
+
+impl Visitor<'_> for TelemetryDirectiveVisitor {
+    fn enter_schema(&mut self, ctx: &mut VisitorContext<'_>, doc: &engine::Positioned<SchemaDefinition>) {
+        for directive in doc
+            .node
+            .directives
+            .iter()
+            .filter(|directive| directive.node.name.node == TELEMETRY_DIRECTIVE_NAME)
+        {
+            if ctx.telemetry.is_some() {
+                ctx.report_error(vec![directive.pos], "The @telemetry directive can only be defined once");
+                continue;
+            }
+
+            let telemetry = match parse_directive::<TelemetryDirective>(directive, ctx.variables) {
+                Ok(telemetry) => telemetry,
+                Err(error) => {
+                    ctx.append_errors(vec![error]);
+                    continue;
+                }
+            };
+
+            if let Some(sampling) = telemetry.tracing.as_ref().and_then(|tracing| tracing.sampling) {
+                if !(0.0..=1.0).contains(&sampling) {
+                    ctx.report_error(
+                        vec![directive.pos],
+                        "The tracing sampling of the @telemetry directive must be between 0 and 1",
+                    );
+                    continue;
+                }
+            }
+
+            ctx.telemetry = Some(telemetry);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, time::Duration};
+
+    use super::TelemetryProtocol;
+    use crate::{tests::assert_validation_error, to_parse_result_with_variables};
+
+    #[test]
+    fn test_happy_path() {
+        let schema = r#"
+            extend schema
+              @telemetry(
+                serviceName: "products"
+                tracing: { exporter: { endpoint: "http://localhost:4317" }, sampling: 0.5 }
+                metrics: {
+                  exporter: {
+                    endpoint: "http://localhost:4318"
+                    protocol: HTTP
+                    headers: [{ name: "authorization", value: "{{ env.OTLP_TOKEN }}" }]
+                    timeout: 5
+                  }
+                  interval: 15
+                }
+              )
+
+            type Product {
+                id: ID!
+            }
+        "#;
+
+        let variables = HashMap::from([("OTLP_TOKEN".to_string(), "Bearer secret".to_string())]);
+        let telemetry = to_parse_result_with_variables(schema, &variables)
+            .unwrap()
+            .telemetry
+            .unwrap();
+
+        assert_eq!(telemetry.service_name(), "products");
+
+        let tracing = telemetry.tracing.unwrap();
+        assert_eq!(tracing.sampling(), 0.5);
+        assert_eq!(tracing.exporter.endpoint.as_str(), "http://localhost:4317/");
+        assert_eq!(tracing.exporter.protocol, TelemetryProtocol::Grpc);
+        assert_eq!(tracing.exporter.timeout(), Duration::from_secs(10));
+
+        let metrics = telemetry.metrics.unwrap();
+        assert_eq!(metrics.interval(), Duration::from_secs(15));
+        assert_eq!(metrics.exporter.protocol, TelemetryProtocol::Http);
+        assert_eq!(metrics.exporter.timeout(), Duration::from_secs(5));
+        assert_eq!(metrics.exporter.headers[0].value, "Bearer secret");
+    }
+
+    #[test]
+    fn test_federated_graph() {
+        let schema = r#"
+            extend schema
+              @graph(type: federated)
+              @telemetry(tracing: { exporter: { endpoint: "http://localhost:4317" } })
+        "#;
+
+        let result = to_parse_result_with_variables(schema, &HashMap::new()).unwrap();
+
+        assert!(result.federated_graph_config.is_some());
+        let telemetry = result.telemetry.unwrap();
+        assert_eq!(telemetry.service_name(), "grafbase");
+        assert_eq!(telemetry.tracing.unwrap().sampling(), 1.0);
+        assert!(telemetry.metrics.is_none());
+    }
+
+    #[test]
+    fn test_invalid_sampling() {
+        assert_validation_error!(
+            r#"
+            extend schema
+              @telemetry(tracing: { exporter: { endpoint: "http://localhost:4317" }, sampling: 2 })
+            "#,
+            "The tracing sampling of the @telemetry directive must be between 0 and 1"
+        );
+    }
+
+    #[test]
+    fn test_multiple_directives() {
+        assert_validation_error!(
+            r#"
+            extend schema
+              @telemetry(tracing: { exporter: { endpoint: "http://localhost:4317" } })
+              @telemetry(metrics: { exporter: { endpoint: "http://localhost:4317" } })
+            "#,
+            "The @telemetry directive can only be defined once"
+        );
+    }
+}
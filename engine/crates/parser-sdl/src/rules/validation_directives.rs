@@ -0,0 +1,249 @@
+//! Directives validating the input values of fields, arguments and input object fields:
+//!
+//! - `@range(min, max)` on numbers,
+//! - `@multipleOf(value)` on numbers,
+//! - `@pattern(regex)` on strings,
+//! - `@items(min, max)` on lists.
+//!
+//! Defined on a list of numbers or strings, the number and string validators apply to each item.
+use engine::{validation::dynamic_validators::DynValidator, Positioned};
+use engine_parser::types::{BaseType, ConstDirective, FieldDefinition, Type, TypeDefinition, TypeKind};
+use engine_value::ConstValue;
+
+use super::{
+    directive::Directive,
+    visitor::{Visitor, VisitorContext},
+};
+
+pub const RANGE_DIRECTIVE: &str = "range";
+pub const MULTIPLE_OF_DIRECTIVE: &str = "multipleOf";
+pub const PATTERN_DIRECTIVE: &str = "pattern";
+pub const ITEMS_DIRECTIVE: &str = "items";
+
+const MIN_ARGUMENT: &str = "min";
+const MAX_ARGUMENT: &str = "max";
+const VALUE_ARGUMENT: &str = "value";
+const REGEX_ARGUMENT: &str = "regex";
+
+pub struct ValidationDirectives;
+
+impl Directive for ValidationDirectives {
+    fn definition() -> String {
+        r"
+        directive @range(min: Float, max: Float) on FIELD_DEFINITION | ARGUMENT_DEFINITION | INPUT_FIELD_DEFINITION
+        directive @multipleOf(value: Float!) on FIELD_DEFINITION | ARGUMENT_DEFINITION | INPUT_FIELD_DEFINITION
+        directive @pattern(regex: String!) on FIELD_DEFINITION | ARGUMENT_DEFINITION | INPUT_FIELD_DEFINITION
+        directive @items(min: Int, max: Int) on FIELD_DEFINITION | ARGUMENT_DEFINITION | INPUT_FIELD_DEFINITION
+        "
+        .to_string()
+    }
+}
+
+impl ValidationDirectives {
+    /// The validator defined by the directive, `None` if it's not a validation directive.
+    pub fn validator(directive: &Positioned<ConstDirective>, ty: &Type) -> Result<Option<DynValidator>, String> {
+        let validator = match directive.node.name.node.as_str() {
+            RANGE_DIRECTIVE => {
+                check_type(directive, ty, is_number, "numbers")?;
+                let (min, max) = bounds(directive, number_argument)?;
+                DynValidator::range(min, max)
+            }
+            MULTIPLE_OF_DIRECTIVE => {
+                check_type(directive, ty, is_number, "numbers")?;
+                match number_argument(directive, VALUE_ARGUMENT) {
+                    Ok(Some(value)) if value > 0.0 => DynValidator::multiple_of(value),
+                    _ => return Err("The @multipleOf directive's value must be a positive number".to_string()),
+                }
+            }
+            PATTERN_DIRECTIVE => {
+                check_type(directive, ty, is_string, "strings")?;
+                match directive.node.get_argument(REGEX_ARGUMENT).map(|value| &value.node) {
+                    Some(ConstValue::String(regex)) if regex::Regex::new(regex).is_ok() => DynValidator::pattern(regex),
+                    _ => return Err("The @pattern directive's regex must be a valid regular expression".to_string()),
+                }
+            }
+            ITEMS_DIRECTIVE => {
+                if !matches!(ty.base, BaseType::List(_)) {
+                    return Err("The @items directive is only accepted on lists".to_string());
+                }
+                let (min, max) = bounds(directive, count_argument)?;
+                DynValidator::items(min, max)
+            }
+            _ => return Ok(None),
+        };
+
+        Ok(Some(validator))
+    }
+
+    fn check(ctx: &mut VisitorContext<'_>, directives: &[Positioned<ConstDirective>], ty: &Type) {
+        for directive in directives {
+            if let Err(message) = Self::validator(directive, ty) {
+                ctx.report_error(vec![directive.pos], message);
+            }
+        }
+    }
+}
+
+impl<'a> Visitor<'a> for ValidationDirectives {
+    fn enter_type_definition(&mut self, ctx: &mut VisitorContext<'a>, type_definition: &'a Positioned<TypeDefinition>) {
+        let TypeKind::InputObject(input_object) = &type_definition.node.kind else {
+            return;
+        };
+
+        for field in &input_object.fields {
+            Self::check(ctx, &field.node.directives, &field.node.ty.node);
+        }
+    }
+
+    fn enter_field(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        field: &'a Positioned<FieldDefinition>,
+        _parent_type: &'a Positioned<TypeDefinition>,
+    ) {
+        Self::check(ctx, &field.node.directives, &field.node.ty.node);
+
+        for argument in &field.node.arguments {
+            Self::check(ctx, &argument.node.directives, &argument.node.ty.node);
+        }
+    }
+}
+
+fn check_type(
+    directive: &Positioned<ConstDirective>,
+    ty: &Type,
+    accepts: fn(&str) -> bool,
+    description: &str,
+) -> Result<(), String> {
+    if accepts(item_type_name(ty)) {
+        Ok(())
+    } else {
+        let name = directive.node.name.node.as_str();
+        Err(format!(
+            "The @{name} directive is only accepted on {description} and lists of {description}"
+        ))
+    }
+}
+
+fn item_type_name(ty: &Type) -> &str {
+    match &ty.base {
+        BaseType::Named(name) => name.as_str(),
+        BaseType::List(ty) => item_type_name(ty),
+    }
+}
+
+fn is_number(name: &str) -> bool {
+    matches!(name, "Int" | "Float" | "BigInt" | "Decimal")
+}
+
+fn is_string(name: &str) -> bool {
+    matches!(name, "String" | "ID" | "Email" | "URL" | "PhoneNumber")
+}
+
+fn number_argument(directive: &Positioned<ConstDirective>, name: &str) -> Result<Option<f64>, ()> {
+    match directive.node.get_argument(name).map(|value| &value.node) {
+        None | Some(ConstValue::Null) => Ok(None),
+        Some(ConstValue::Number(number)) => number.as_f64().map(Some).ok_or(()),
+        Some(_) => Err(()),
+    }
+}
+
+fn count_argument(directive: &Positioned<ConstDirective>, name: &str) -> Result<Option<usize>, ()> {
+    match directive.node.get_argument(name).map(|value| &value.node) {
+        None | Some(ConstValue::Null) => Ok(None),
+        Some(ConstValue::Number(number)) => number
+            .as_u64()
+            .and_then(|count| usize::try_from(count).ok())
+            .map(Some)
+            .ok_or(()),
+        Some(_) => Err(()),
+    }
+}
+
+/// The `min` and `max` arguments, at least one of them must be defined.
+#[allow(clippy::type_complexity)]
+fn bounds<T: PartialOrd>(
+    directive: &Positioned<ConstDirective>,
+    argument: fn(&Positioned<ConstDirective>, &str) -> Result<Option<T>, ()>,
+) -> Result<(Option<T>, Option<T>), String> {
+    let name = directive.node.name.node.as_str();
+    let expected = if name == ITEMS_DIRECTIVE {
+        "a positive integer"
+    } else {
+        "a number"
+    };
+
+    let bound = |argument_name| {
+        argument(directive, argument_name)
+            .map_err(|()| format!("The @{name} directive's {argument_name} argument must be {expected}"))
+    };
+
+    match (bound(MIN_ARGUMENT)?, bound(MAX_ARGUMENT)?) {
+        (None, None) => Err(format!(
+            "The @{name} directive expects at least one of the `max` and `min` arguments"
+        )),
+        (Some(min), Some(max)) if min > max => Err(format!(
+            "The `{MAX_ARGUMENT}` must be greater than the `{MIN_ARGUMENT}`"
+        )),
+        bounds => Ok(bounds),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use engine_parser::parse_schema;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::rules::visitor::visit;
+
+    #[rstest::rstest]
+    #[case(r#"
+        input ProductInput {
+            price: Float @range(min: 0, max: 1000) @multipleOf(value: 0.01)
+            sku: String! @pattern(regex: "^[A-Z]{3}-[0-9]+$")
+            tags: [String!] @items(max: 10) @pattern(regex: "^[a-z]+$")
+        }
+
+        extend type Query {
+            products(first: Int @range(min: 1, max: 100)): [String!] @resolver(name: "products")
+        }
+        "#, &[]
+    )]
+    #[case(r#"
+        input ProductInput {
+            name: String @range(min: 0)
+            price: Float @pattern(regex: "[0-9]+")
+            tag: String @items(max: 1)
+        }
+        "#, &[
+        "The @range directive is only accepted on numbers and lists of numbers",
+        "The @pattern directive is only accepted on strings and lists of strings",
+        "The @items directive is only accepted on lists",
+    ])]
+    #[case(r#"
+        input ProductInput {
+            price: Float @range
+            quantity: Int @range(min: 10, max: 1)
+            discount: Float @range(min: "0")
+            step: Float @multipleOf(value: 0)
+            sku: String @pattern(regex: "[")
+            tags: [String] @items(min: -1)
+        }
+        "#, &[
+        "The @range directive expects at least one of the `max` and `min` arguments",
+        "The `max` must be greater than the `min`",
+        "The @range directive's min argument must be a number",
+        "The @multipleOf directive's value must be a positive number",
+        "The @pattern directive's regex must be a valid regular expression",
+        "The @items directive's min argument must be a positive integer",
+    ])]
+    fn test_parse_result(#[case] schema_string: &str, #[case] expected_messages: &[&str]) {
+        let schema = parse_schema(schema_string).unwrap();
+        let mut ctx = VisitorContext::new_for_tests(&schema);
+        visit(&mut ValidationDirectives, &mut ctx, &schema);
+
+        let actual_messages: Vec<_> = ctx.errors.iter().map(|error| error.message.as_str()).collect();
+        assert_eq!(actual_messages.as_slice(), expected_messages, "for {schema_string}");
+    }
+}
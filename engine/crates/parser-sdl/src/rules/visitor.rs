@@ -18,6 +18,7 @@ type TypeStackType<'a> = Vec<(Option<&'a Positioned<Type>>, Option<&'a Positione
 
 pub const QUERY_TYPE: &str = "Query";
 pub const MUTATION_TYPE: &str = "Mutation";
+pub const SUBSCRIPTION_TYPE: &str = "Subscription";
 
 pub fn visit<'a, V: Visitor<'a>>(v: &mut V, ctx: &mut VisitorContext<'a>, doc: &'a ServiceDocument) {
     v.enter_document(ctx, doc);
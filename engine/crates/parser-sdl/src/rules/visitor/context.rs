@@ -19,10 +19,10 @@ use engine_parser::{
 };
 use engine_value::{indexmap::IndexMap, Name};
 
-use super::{warnings::Warnings, RuleError, TypeStackType, Warning, MUTATION_TYPE, QUERY_TYPE};
+use super::{warnings::Warnings, RuleError, TypeStackType, Warning, MUTATION_TYPE, QUERY_TYPE, SUBSCRIPTION_TYPE};
 use crate::{
     federation::FederatedGraphConfig, rules::federation::FederationVersion, GlobalCacheRules, GlobalCacheTarget,
-    GraphqlDirective, MongoDBDirective, OpenApiDirective, ParseResult, PostgresDirective,
+    GraphqlDirective, MongoDBDirective, OpenApiDirective, ParseResult, PostgresDirective, TelemetryDirective,
 };
 
 /// The VisitorContext to visit every types from the Schema.
@@ -36,6 +36,8 @@ pub struct VisitorContext<'a> {
     pub(crate) type_stack: TypeStackType<'a>,
     pub(crate) queries: Vec<MetaField>,
     pub(crate) mutations: Vec<MetaField>,
+    /// Subscription fields, only generated by connectors.
+    pub(crate) subscriptions: Vec<MetaField>,
     /// Relations by name
     pub(crate) relations: IndexMap<String, MetaRelation>,
     pub schema_id_generator: SchemaIDGenerator,
@@ -64,6 +66,8 @@ pub struct VisitorContext<'a> {
     pub federation: Option<FederationVersion>,
 
     pub federated_graph_config: FederatedGraphConfig,
+
+    pub telemetry: Option<TelemetryDirective>,
 }
 
 impl<'a> VisitorContext<'a> {
@@ -133,6 +137,7 @@ impl<'a> VisitorContext<'a> {
             type_stack: Default::default(),
             registry: RefCell::new(Schema::create_registry()),
             mutations: Default::default(),
+            subscriptions: Default::default(),
             queries: Default::default(),
             relations: Default::default(),
             schema_to_build: Default::default(),
@@ -148,6 +153,7 @@ impl<'a> VisitorContext<'a> {
             database_models_enabled,
             federation: None,
             federated_graph_config: Default::default(),
+            telemetry: None,
         }
     }
 
@@ -165,6 +171,10 @@ impl<'a> VisitorContext<'a> {
             registry.mutation_type = Some(MUTATION_TYPE.to_string());
         }
 
+        if !self.subscriptions.is_empty() {
+            registry.subscription_type = Some(SUBSCRIPTION_TYPE.to_string());
+        }
+
         registry.create_type(
             |registry| {
                 let schema_type = __Schema::create_type_info(registry);
@@ -208,6 +218,14 @@ impl<'a> VisitorContext<'a> {
             );
         }
 
+        if !self.subscriptions.is_empty() {
+            registry.create_type(
+                |_| engine::registry::ObjectType::new(SUBSCRIPTION_TYPE.to_owned(), self.subscriptions).into(),
+                SUBSCRIPTION_TYPE,
+                SUBSCRIPTION_TYPE,
+            );
+        }
+
         registry.remove_unused_types();
 
         let mut required_udfs = self
@@ -224,6 +242,7 @@ impl<'a> VisitorContext<'a> {
             federated_graph_config: registry.is_federated.then_some(self.federated_graph_config),
             registry,
             required_udfs,
+            telemetry: self.telemetry,
         }
     }
 
@@ -650,6 +650,103 @@ fn should_support_search_directive() {
     );
 }
 
+#[test]
+fn test_search_analyzers() {
+    use runtime::search::Analyzer;
+
+    let registry = super::parse_registry(
+        r#"
+            type Product @model @search(analyzer: "english") {
+              title: String
+              sku: String @search(analyzer: "keyword")
+              tags: [String] @search(analyzer: "keyword")
+              price: Int
+            }
+            "#,
+    )
+    .unwrap();
+    let fields = &registry.search_config.indices["product"].schema.fields;
+    assert_eq!(fields["title"].analyzer, Analyzer::English);
+    assert!(!fields["title"].sortable);
+    assert_eq!(fields["sku"].analyzer, Analyzer::Keyword);
+    assert!(fields["sku"].sortable);
+    assert!(!fields["tags"].sortable);
+    assert_eq!(fields["price"].analyzer, Analyzer::Standard);
+    assert!(fields["price"].sortable);
+    assert!(fields["createdAt"].sortable);
+
+    assert_validation_error!(
+        r#"
+            type Product @model {
+                title: String @search(analyzer: "klingon")
+            }
+        "#,
+        "Unknown analyzer 'klingon', expected one of: standard, keyword, danish, dutch, english, finnish, french, german, hungarian, italian, norwegian, portuguese, russian, spanish, swedish."
+    );
+
+    assert_validation_error!(
+        r#"
+            type Product @model {
+                price: Int @search(analyzer: "english")
+            }
+        "#,
+        "The analyzer can only be used on String, URL and Email fields."
+    );
+}
+
+#[test]
+fn test_search_vectors() {
+    use runtime::search::{FieldType, VectorOptions};
+
+    let registry = super::parse_registry(
+        r#"
+            type Product @model @search {
+              title: String
+              embedding: [Float!] @search(dimensions: 3)
+            }
+            "#,
+    )
+    .unwrap();
+    let fields = &registry.search_config.indices["product"].schema.fields;
+    assert_eq!(
+        fields["embedding"].ty,
+        FieldType::Vector(VectorOptions {
+            nullable: true,
+            dimensions: 3
+        })
+    );
+    assert!(!fields["embedding"].sortable);
+    assert!(registry.types.contains_key("ProductSearchVectorInput"));
+    assert!(registry.types.contains_key("SearchVectorInput"));
+
+    assert_validation_error!(
+        r#"
+            type Product @model {
+                price: Int @search(dimensions: 3)
+            }
+        "#,
+        "The dimensions can only be used on [Float] fields."
+    );
+
+    assert_validation_error!(
+        r#"
+            type Product @model {
+                embedding: [Float!] @search(dimensions: 0)
+            }
+        "#,
+        "The dimensions must be between 1 and 4096."
+    );
+
+    assert_validation_error!(
+        r#"
+            type Product @model @search(dimensions: 3) {
+                embedding: [Float!]
+            }
+        "#,
+        "The dimensions can only be used on fields."
+    );
+}
+
 #[test]
 #[named]
 fn test_search_enums_placed_after_use() {
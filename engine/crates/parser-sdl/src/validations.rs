@@ -1,4 +1,4 @@
-use std::sync::OnceLock;
+use std::{collections::BTreeSet, sync::OnceLock};
 
 use engine::{
     registry::{
@@ -123,12 +123,11 @@ fn validate_joins(registry: &Registry) -> Vec<RuleError> {
 
         for field in fields.values() {
             if let Resolver::Join(join) = &field.resolver {
-                errors.extend(validate_join(
-                    join,
-                    registry,
-                    SchemaCoord::Field(ty.name(), &field.name),
-                    &field.ty,
-                ));
+                let coord = SchemaCoord::Field(ty.name(), &field.name);
+                errors.extend(validate_join(join, registry, coord, &field.ty));
+                if join.batch_key.is_some() {
+                    errors.extend(validate_join_batch(join, field, coord));
+                }
             }
         }
     }
@@ -157,9 +156,41 @@ fn validate_join(
         return errors;
     };
 
+    // A batched join gets a single item of the list returned by the destination field.
+    let destination_type = match &join.batch_key {
+        Some(batch_key) => {
+            let Some(item_type) = list_item_type(&destination_field.ty) else {
+                errors.push(RuleError::new(
+                    vec![],
+                    format!(
+                        "{coord} is a batched join with the field named {}, but that field does not return a list",
+                        join.field_name
+                    ),
+                ));
+                return errors;
+            };
+            let has_key = registry
+                .lookup(&item_type)
+                .ok()
+                .and_then(|ty| ty.field_map().map(|fields| fields.contains_key(batch_key)))
+                .unwrap_or_default();
+            if !has_key {
+                errors.push(RuleError::new(
+                    vec![],
+                    format!(
+                        "{coord} is a batched join with the field named {}, but the items it returns have no field {batch_key}",
+                        join.field_name
+                    ),
+                ));
+            }
+            item_type
+        }
+        None => destination_field.ty.clone(),
+    };
+
     // TODO: Make this a bit more forgiving.
     // If destination_field is non-null but expected_return is null that's fine...
-    if !types_are_compatible(&destination_field.ty, expected_return_type) {
+    if !types_are_compatible(&destination_type, expected_return_type) {
         errors.push(RuleError::new(
             vec![],
             format!(
@@ -184,6 +215,32 @@ fn validate_join(
     errors
 }
 
+/// Batched joins give every parent the item matching its value, so they must refer to exactly one
+/// field of the parent. Other variables are arguments of the field.
+fn validate_join_batch(join: &JoinResolver, field: &MetaField, coord: SchemaCoord<'_>) -> Option<RuleError> {
+    let parent_fields = join
+        .arguments
+        .clone()
+        .into_iter()
+        .flat_map(|(_, value)| value.variables_used().map(ToString::to_string).collect::<Vec<_>>())
+        .filter(|variable| !field.args.contains_key(variable))
+        .collect::<BTreeSet<_>>();
+
+    (parent_fields.len() != 1).then(|| {
+        RuleError::new(
+            vec![],
+            format!("{coord} is a batched join, which must use exactly one field of its parent"),
+        )
+    })
+}
+
+fn list_item_type(ty: &MetaFieldType) -> Option<MetaFieldType> {
+    match Type::new(ty.as_str())?.base {
+        BaseType::List(item_type) => Some(item_type.to_string().into()),
+        BaseType::Named(_) => None,
+    }
+}
+
 fn types_are_compatible(actual_type: &MetaFieldType, expected_type: &MetaFieldType) -> bool {
     let Some(actual) = Type::new(actual_type.as_str()) else {
         return false;
@@ -0,0 +1,173 @@
+//! The change feed behind subscriptions. A trigger publishes every change to a table with
+//! `pg_notify`, and the listeners fetch the changed rows with the key from the notification.
+//! The triggers are installed ahead of time with [`install_triggers`], not by the listeners.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+    database_definition::{DatabaseDefinition, TableWalker},
+    error::Error,
+    transport::Transport,
+};
+
+/// Notifications have a payload limit of 8000 bytes.
+const MAX_PAYLOAD_SIZE: usize = 8000;
+
+/// The name of the trigger, which is unique per table.
+const TRIGGER_NAME: &str = "grafbase_changes";
+
+/// The kind of a change to a row.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum ChangeKind {
+    Created,
+    Updated,
+    Deleted,
+}
+
+/// A change published by the trigger of a table.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChangeEvent {
+    kind: ChangeKind,
+    /// The values of the key columns of the changed row.
+    key: Map<String, Value>,
+    /// The deleted row, left out if it didn't fit into the notification.
+    #[serde(default)]
+    row: Option<Value>,
+}
+
+impl ChangeEvent {
+    /// Parses a notification payload.
+    pub fn parse(payload: &str) -> crate::Result<Self> {
+        Ok(serde_json::from_str(payload)?)
+    }
+
+    pub fn kind(&self) -> ChangeKind {
+        self.kind
+    }
+
+    /// The changed row as a JSON object. If the row is not in the notification, only the key
+    /// columns are defined.
+    pub fn row(&self) -> Value {
+        match self.row {
+            Some(ref row) => row.clone(),
+            None => Value::Object(self.key.clone()),
+        }
+    }
+}
+
+/// The channel the changes of the table are published to. Hashed, so the name fits into the
+/// identifier length limit.
+pub fn channel(table: TableWalker<'_>) -> String {
+    // FNV-1a, the name must be stable between processes.
+    let hash = table
+        .schema()
+        .bytes()
+        .chain(Some(b'.'))
+        .chain(table.database_name().bytes())
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+
+    format!("{TRIGGER_NAME}_{hash:016x}")
+}
+
+/// A query returning a row, if the trigger publishing the changes of the table exists.
+pub fn trigger_exists_query(table: TableWalker<'_>) -> String {
+    format!(
+        "SELECT 1 AS exists FROM pg_trigger WHERE tgname = '{TRIGGER_NAME}' AND tgrelid = '{}'::regclass",
+        qualified_name(table).replace('\'', "''")
+    )
+}
+
+/// Installs or replaces the triggers publishing the changes of every table in the client.
+pub async fn install_triggers<T>(transport: &T, definition: &DatabaseDefinition) -> crate::Result<()>
+where
+    T: Transport + ?Sized,
+{
+    for table in definition.tables().filter(|table| table.allowed_in_client()) {
+        for statement in create_trigger_statements(table)? {
+            transport.execute(&statement).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The statements creating the trigger publishing the changes of the table, and its function.
+/// The key columns are always in the notification. The deleted rows are included, if they fit.
+///
+/// Requires Postgres 14 or later.
+pub fn create_trigger_statements(table: TableWalker<'_>) -> crate::Result<[String; 2]> {
+    let channel = channel(table);
+
+    let Some(key) = table.implicit_ordering_key() else {
+        return Err(Error::Internal(format!(
+            "the changes of {} cannot be published without a unique constraint",
+            qualified_name(table)
+        )));
+    };
+
+    let key = key
+        .columns()
+        .map(|column| {
+            let name = column.table_column().database_name();
+            format!("'{}', changed.{}", name.replace('\'', "''"), quote(name))
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let function = format!("{}.{}", quote(table.schema()), quote(&channel));
+
+    let create_function = format!(
+        r#"CREATE OR REPLACE FUNCTION {function}() RETURNS trigger LANGUAGE plpgsql AS $grafbase$
+DECLARE
+  changed record;
+  kind text;
+  payload text;
+BEGIN
+  IF TG_OP = 'DELETE' THEN
+    changed := OLD;
+    kind := 'deleted';
+  ELSIF TG_OP = 'UPDATE' THEN
+    changed := NEW;
+    kind := 'updated';
+  ELSE
+    changed := NEW;
+    kind := 'created';
+  END IF;
+
+  payload := json_build_object(
+    'kind', kind,
+    'key', json_build_object({key}),
+    'row', CASE WHEN TG_OP = 'DELETE' THEN row_to_json(changed) END
+  )::text;
+
+  IF octet_length(payload) >= {MAX_PAYLOAD_SIZE} THEN
+    payload := json_build_object('kind', kind, 'key', json_build_object({key}))::text;
+  END IF;
+
+  PERFORM pg_notify('{channel}', payload);
+
+  RETURN NULL;
+END
+$grafbase$"#
+    );
+
+    let create_trigger = format!(
+        "CREATE OR REPLACE TRIGGER {} AFTER INSERT OR UPDATE OR DELETE ON {} FOR EACH ROW EXECUTE FUNCTION {function}()",
+        quote(TRIGGER_NAME),
+        qualified_name(table),
+    );
+
+    Ok([create_function, create_trigger])
+}
+
+fn qualified_name(table: TableWalker<'_>) -> String {
+    format!("{}.{}", quote(table.schema()), quote(table.database_name()))
+}
+
+fn quote(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
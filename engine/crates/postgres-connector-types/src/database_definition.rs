@@ -1,7 +1,11 @@
+mod composite_type;
+mod composite_type_attribute;
 mod r#enum;
 mod enum_variant;
 mod foreign_key;
 mod foreign_key_column;
+mod function;
+mod function_argument;
 mod ids;
 mod names;
 mod postgres_type;
@@ -15,12 +19,17 @@ mod walkers;
 
 use std::collections::HashMap;
 
+pub use composite_type::CompositeType;
+pub use composite_type_attribute::CompositeTypeAttribute;
 pub use enum_variant::EnumVariant;
 pub use foreign_key::ForeignKey;
 pub use foreign_key_column::ForeignKeyColumn;
+pub use function::{Function, FunctionReturnType, Volatility};
+pub use function_argument::FunctionArgument;
 pub use ids::{
-    BackRelationId, EnumId, EnumVariantId, ForeignKeyColumnId, ForeignKeyId, ForwardRelationId, RelationId, SchemaId,
-    TableColumnId, TableId, UniqueConstraintColumnId, UniqueConstraintId,
+    BackRelationId, CompositeTypeAttributeId, CompositeTypeId, EnumId, EnumVariantId, ForeignKeyColumnId, ForeignKeyId,
+    ForwardRelationId, FunctionArgumentId, FunctionId, RelationId, SchemaId, TableColumnId, TableId,
+    UniqueConstraintColumnId, UniqueConstraintId,
 };
 use inflector::Inflector;
 use names::{Names, StringId};
@@ -33,10 +42,13 @@ pub use table_column::TableColumn;
 pub use unique_constraint::{ConstraintType, UniqueConstraint};
 pub use unique_constraint_column::UniqueConstraintColumn;
 pub use walkers::{
-    EnumVariantWalker, EnumWalker, RelationWalker, TableColumnWalker, TableWalker, UniqueConstraintColumnWalker,
-    UniqueConstraintWalker, Walker,
+    CompositeTypeAttributeWalker, CompositeTypeWalker, EnumVariantWalker, EnumWalker, FunctionArgumentWalker,
+    FunctionReturnTypeWalker, FunctionWalker, RelationWalker, TableColumnWalker, TableWalker,
+    UniqueConstraintColumnWalker, UniqueConstraintWalker, Walker,
 };
 
+use crate::row_level_security::RowLevelSecurity;
+
 /// Definition of a PostgreSQL database. Contains all the
 /// tables, enums, columns, constraints etc. for us to render
 /// a GraphQL schema, and for us to allow querying the database
@@ -74,8 +86,26 @@ pub struct DatabaseDefinition {
     unique_constraints: Vec<UniqueConstraint<StringId>>,
     /// Ordered by schema id, table id, constraint id and the column position.
     unique_constraint_columns: Vec<UniqueConstraintColumn>,
+    /// Ordered by schema id, then function name.
+    #[serde(default)]
+    functions: Vec<Function<StringId>>,
+    /// Ordered by schema id, function id and the argument position.
+    #[serde(default)]
+    function_arguments: Vec<FunctionArgument<StringId>>,
+    /// Ordered by schema id, then type name.
+    #[serde(default)]
+    composite_types: Vec<CompositeType<StringId>>,
+    /// Ordered by schema id, type id and the attribute position.
+    #[serde(default)]
+    composite_type_attributes: Vec<CompositeTypeAttribute<StringId>>,
     names: Names,
     relations: Relations,
+    /// Passing token claims to the database session, if enabled.
+    #[serde(default)]
+    row_level_security: Option<RowLevelSecurity>,
+    /// Publishing the changes of the tables to subscriptions, if enabled.
+    #[serde(default)]
+    subscriptions: bool,
 }
 
 impl DatabaseDefinition {
@@ -91,8 +121,14 @@ impl DatabaseDefinition {
             foreign_key_columns: Vec::new(),
             unique_constraints: Vec::new(),
             unique_constraint_columns: Vec::new(),
+            functions: Vec::new(),
+            function_arguments: Vec::new(),
+            composite_types: Vec::new(),
+            composite_type_attributes: Vec::new(),
             names: Names::default(),
             relations: Relations::default(),
+            row_level_security: None,
+            subscriptions: false,
         }
     }
 
@@ -101,6 +137,26 @@ impl DatabaseDefinition {
         &self.connection_string
     }
 
+    /// Settings for passing token claims to the database session for row-level security.
+    pub fn row_level_security(&self) -> Option<&RowLevelSecurity> {
+        self.row_level_security.as_ref()
+    }
+
+    /// Enables row-level security support for the database.
+    pub fn set_row_level_security(&mut self, row_level_security: RowLevelSecurity) {
+        self.row_level_security = Some(row_level_security);
+    }
+
+    /// True, if the changes of the tables are published to subscriptions.
+    pub fn subscriptions(&self) -> bool {
+        self.subscriptions
+    }
+
+    /// Enables subscriptions to the changes of the tables.
+    pub fn enable_subscriptions(&mut self) {
+        self.subscriptions = true;
+    }
+
     /// Iterates over all tables of the introspected database.
     pub fn tables(&self) -> impl ExactSizeIterator<Item = TableWalker<'_>> + '_ {
         (0..self.tables.len()).map(move |id| self.walk(TableId(id as u32)))
@@ -111,6 +167,16 @@ impl DatabaseDefinition {
         (0..self.enums.len()).map(move |id| self.walk(EnumId(id as u32)))
     }
 
+    /// Iterates over all composite types of the introspected database.
+    pub fn composite_types(&self) -> impl ExactSizeIterator<Item = CompositeTypeWalker<'_>> + '_ {
+        (0..self.composite_types.len()).map(move |id| self.walk(CompositeTypeId(id as u32)))
+    }
+
+    /// Iterates over all functions of the introspected database.
+    pub fn functions(&self) -> impl ExactSizeIterator<Item = FunctionWalker<'_>> + '_ {
+        (0..self.functions.len()).map(move |id| self.walk(FunctionId(id as u32)))
+    }
+
     /// Find a table in a specified schema with the specified name.
     pub fn find_table(&self, schema_name: &str, table_name: &str) -> Option<TableWalker<'_>> {
         let schema_id = self.get_schema_id(schema_name)?;
@@ -146,6 +212,13 @@ impl DatabaseDefinition {
             .map(|relation_id| self.walk(relation_id))
     }
 
+    /// Find a function that represents the given client query or mutation field.
+    pub fn find_function_for_client_field(&self, client_field: &str) -> Option<FunctionWalker<'_>> {
+        self.names
+            .get_function_id_for_client_field(client_field)
+            .map(|function_id| self.walk(function_id))
+    }
+
     /// Find a unique constraint that represents the given client field.
     pub fn find_unique_constraint_for_client_field(
         &self,
@@ -228,6 +301,39 @@ impl DatabaseDefinition {
         id
     }
 
+    /// Adds a composite type to the definition.
+    pub fn push_composite_type(&mut self, composite_type: CompositeType<String>) -> CompositeTypeId {
+        let id = self.next_composite_type_id();
+
+        self.names.intern_composite_type(&composite_type, id);
+
+        self.composite_types.push(CompositeType {
+            schema_id: composite_type.schema_id(),
+            database_name: self.names.intern_string(composite_type.database_name()),
+            client_name: self.names.intern_string(composite_type.client_name()),
+        });
+
+        id
+    }
+
+    /// Adds an attribute of a composite type to the definition.
+    pub fn push_composite_type_attribute(
+        &mut self,
+        attribute: CompositeTypeAttribute<String>,
+    ) -> CompositeTypeAttributeId {
+        let id = self.next_composite_type_attribute_id();
+
+        self.composite_type_attributes.push(CompositeTypeAttribute {
+            composite_type_id: attribute.composite_type_id(),
+            database_name: self.names.intern_string(attribute.database_name()),
+            database_type: attribute.database_type(),
+            client_name: self.names.intern_string(attribute.client_name()),
+            is_array: attribute.is_array(),
+        });
+
+        id
+    }
+
     /// Adds a foreign key to the definition.
     pub fn push_foreign_key(&mut self, foreign_key: ForeignKey<String>) -> ForeignKeyId {
         let id = self.next_foreign_key_id();
@@ -278,6 +384,40 @@ impl DatabaseDefinition {
         id
     }
 
+    /// Adds a function to the definition.
+    pub fn push_function(&mut self, function: Function<String>) -> FunctionId {
+        let id = self.next_function_id();
+        self.names.intern_function(&function, id);
+
+        self.functions.push(Function {
+            schema_id: function.schema_id(),
+            database_name: self.names.intern_string(function.database_name()),
+            client_name: self.names.intern_string(function.client_name()),
+            return_type: function.return_type(),
+            returns_set: function.returns_set(),
+            returns_array: function.returns_array(),
+            volatility: function.volatility(),
+        });
+
+        id
+    }
+
+    /// Adds a function argument to the definition.
+    pub fn push_function_argument(&mut self, argument: FunctionArgument<String>) -> FunctionArgumentId {
+        let id = self.next_function_argument_id();
+
+        self.function_arguments.push(FunctionArgument {
+            function_id: argument.function_id(),
+            database_name: self.names.intern_string(argument.database_name()),
+            database_type: argument.database_type(),
+            client_name: self.names.intern_string(argument.client_name()),
+            has_default: argument.has_default(),
+            is_array: argument.is_array(),
+        });
+
+        id
+    }
+
     /// Adds an index from client type name to table id.
     pub fn push_client_type_mapping(&mut self, type_name: &str, table_id: TableId) {
         self.names.intern_client_type(type_name, table_id);
@@ -309,6 +449,11 @@ impl DatabaseDefinition {
         self.names.intern_client_relation(field_name, table_id, relation_id);
     }
 
+    /// Adds an index from client query or mutation field name to the corresponding function id.
+    pub fn push_client_function_mapping(&mut self, field_name: &str, function_id: FunctionId) {
+        self.names.intern_client_function(field_name, function_id);
+    }
+
     /// Finds the id of a schema with the given name, if existing.
     pub fn get_schema_id(&self, schema: &str) -> Option<SchemaId> {
         self.schemas
@@ -332,6 +477,16 @@ impl DatabaseDefinition {
         self.names.get_enum_id(schema_id, enum_name)
     }
 
+    /// Finds the id of a composite type with the given name, if existing.
+    pub fn get_composite_type_id(&self, schema_id: SchemaId, type_name: &str) -> Option<CompositeTypeId> {
+        self.names.get_composite_type_id(schema_id, type_name)
+    }
+
+    /// Finds the id of a function with the given name, if existing.
+    pub fn get_function_id(&self, schema_id: SchemaId, function_name: &str) -> Option<FunctionId> {
+        self.names.get_function_id(schema_id, function_name)
+    }
+
     /// Finds the id of an enum with the given name, if existing.
     pub fn get_foreign_key_id(&self, schema_id: SchemaId, constraint_name: &str) -> Option<ForeignKeyId> {
         self.names.get_foreign_key_id(schema_id, constraint_name)
@@ -359,9 +514,9 @@ impl DatabaseDefinition {
         }
     }
 
-    /// Tables and enums are namespaced per schema in PostgreSQL, but in GraphQL all schemas are in the same namespace.
+    /// Tables, enums, composite types and functions are namespaced per schema in PostgreSQL, but in GraphQL all schemas are in the same namespace.
     ///
-    /// If a table or enum has a duplicate name in different schemas, we'll prefix the name with the name of the schema.
+    /// If a table, enum, composite type or function has a duplicate name in different schemas, we'll prefix the name with the name of the schema.
     fn deduplicate_names(&mut self) {
         let mut names = HashMap::new();
 
@@ -406,6 +561,50 @@ impl DatabaseDefinition {
 
             r#enum.set_client_name(client_name);
         }
+
+        names.clear();
+
+        for composite_type in &self.composite_types {
+            let counter = names.entry(composite_type.client_name()).or_default();
+            *counter += 1;
+        }
+
+        for composite_type in &mut self.composite_types {
+            if names.get(&composite_type.client_name()).copied().unwrap_or(0) < 2 {
+                continue;
+            }
+
+            let schema_name = &self.schemas[composite_type.schema_id().0 as usize];
+            let client_name = self.names.get_name(composite_type.client_name());
+
+            let client_name = self
+                .names
+                .intern_string(&format!("{schema_name}_{client_name}").to_pascal_case());
+
+            composite_type.set_client_name(client_name);
+        }
+
+        names.clear();
+
+        for function in &self.functions {
+            let counter = names.entry(function.client_name()).or_default();
+            *counter += 1;
+        }
+
+        for function in &mut self.functions {
+            if names.get(&function.client_name()).copied().unwrap_or(0) < 2 {
+                continue;
+            }
+
+            let schema_name = &self.schemas[function.schema_id().0 as usize];
+            let client_name = self.names.get_name(function.client_name());
+
+            let client_name = self
+                .names
+                .intern_string(&format!("{schema_name}_{client_name}").to_camel_case());
+
+            function.set_client_name(client_name);
+        }
     }
 
     fn next_schema_id(&self) -> SchemaId {
@@ -428,6 +627,22 @@ impl DatabaseDefinition {
         EnumVariantId(self.enum_variants.len() as u32)
     }
 
+    fn next_composite_type_id(&self) -> CompositeTypeId {
+        CompositeTypeId(self.composite_types.len() as u32)
+    }
+
+    fn next_composite_type_attribute_id(&self) -> CompositeTypeAttributeId {
+        CompositeTypeAttributeId(self.composite_type_attributes.len() as u32)
+    }
+
+    fn next_function_id(&self) -> FunctionId {
+        FunctionId(self.functions.len() as u32)
+    }
+
+    fn next_function_argument_id(&self) -> FunctionArgumentId {
+        FunctionArgumentId(self.function_arguments.len() as u32)
+    }
+
     fn next_foreign_key_id(&self) -> ForeignKeyId {
         ForeignKeyId(self.foreign_keys.len() as u32)
     }
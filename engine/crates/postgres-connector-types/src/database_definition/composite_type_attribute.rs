@@ -0,0 +1,63 @@
+use inflector::Inflector;
+use serde::{Deserialize, Serialize};
+
+use super::{names::StringId, ColumnType, CompositeTypeId};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CompositeTypeAttribute<T> {
+    pub(super) composite_type_id: CompositeTypeId,
+    pub(super) database_name: T,
+    pub(super) database_type: ColumnType,
+    pub(super) client_name: T,
+    pub(super) is_array: bool,
+}
+
+impl<T> CompositeTypeAttribute<T> {
+    pub(crate) fn composite_type_id(&self) -> CompositeTypeId {
+        self.composite_type_id
+    }
+
+    pub(crate) fn database_type(&self) -> ColumnType {
+        self.database_type
+    }
+
+    pub(crate) fn is_array(&self) -> bool {
+        self.is_array
+    }
+
+    pub fn set_is_array(&mut self, value: bool) {
+        self.is_array = value;
+    }
+}
+
+impl CompositeTypeAttribute<String> {
+    pub fn new(composite_type_id: CompositeTypeId, name: String, database_type: ColumnType) -> Self {
+        let client_name = name.to_camel_case();
+
+        Self {
+            composite_type_id,
+            database_name: name,
+            database_type,
+            client_name,
+            is_array: false,
+        }
+    }
+
+    pub(crate) fn database_name(&self) -> &str {
+        &self.database_name
+    }
+
+    pub(crate) fn client_name(&self) -> &str {
+        &self.client_name
+    }
+}
+
+impl CompositeTypeAttribute<StringId> {
+    pub(crate) fn database_name(&self) -> StringId {
+        self.database_name
+    }
+
+    pub(crate) fn client_name(&self) -> StringId {
+        self.client_name
+    }
+}
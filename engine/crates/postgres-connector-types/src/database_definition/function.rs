@@ -0,0 +1,118 @@
+use inflector::Inflector;
+use serde::{Deserialize, Serialize};
+
+use super::{names::StringId, EnumId, ScalarType, SchemaId, TableId};
+
+/// The volatility category of a function, deciding if it's exposed as a query or a mutation.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum Volatility {
+    /// Cannot modify the database and always returns the same result given the same arguments.
+    Immutable,
+    /// Cannot modify the database, and returns the same result for the same arguments within a single statement.
+    Stable,
+    /// Can do anything, including modifying the database.
+    Volatile,
+}
+
+impl From<&str> for Volatility {
+    fn from(value: &str) -> Self {
+        match value {
+            "i" => Self::Immutable,
+            "s" => Self::Stable,
+            _ => Self::Volatile,
+        }
+    }
+}
+
+/// The type a function returns.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum FunctionReturnType {
+    Scalar(ScalarType),
+    Enum(EnumId),
+    /// The row type of a table.
+    Table(TableId),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Function<T> {
+    pub(super) schema_id: SchemaId,
+    pub(super) database_name: T,
+    pub(super) client_name: T,
+    pub(super) return_type: FunctionReturnType,
+    pub(super) returns_set: bool,
+    pub(super) returns_array: bool,
+    pub(super) volatility: Volatility,
+}
+
+impl<T> Function<T> {
+    pub(crate) fn schema_id(&self) -> SchemaId {
+        self.schema_id
+    }
+
+    pub(crate) fn return_type(&self) -> FunctionReturnType {
+        self.return_type
+    }
+
+    pub(crate) fn returns_set(&self) -> bool {
+        self.returns_set
+    }
+
+    pub(crate) fn returns_array(&self) -> bool {
+        self.returns_array
+    }
+
+    pub(crate) fn volatility(&self) -> Volatility {
+        self.volatility
+    }
+
+    pub fn set_returns_set(&mut self, value: bool) {
+        self.returns_set = value;
+    }
+
+    pub fn set_returns_array(&mut self, value: bool) {
+        self.returns_array = value;
+    }
+
+    pub fn set_volatility(&mut self, value: Volatility) {
+        self.volatility = value;
+    }
+}
+
+impl Function<String> {
+    pub fn new(schema_id: SchemaId, name: String, return_type: FunctionReturnType) -> Self {
+        let client_name = name.to_camel_case();
+
+        Self {
+            schema_id,
+            database_name: name,
+            client_name,
+            return_type,
+            returns_set: false,
+            returns_array: false,
+            volatility: Volatility::Volatile,
+        }
+    }
+
+    pub(crate) fn database_name(&self) -> &str {
+        &self.database_name
+    }
+
+    pub(crate) fn client_name(&self) -> &str {
+        &self.client_name
+    }
+}
+
+impl Function<StringId> {
+    pub(crate) fn database_name(&self) -> StringId {
+        self.database_name
+    }
+
+    pub(crate) fn client_name(&self) -> StringId {
+        self.client_name
+    }
+
+    pub(super) fn set_client_name(&mut self, client_name: StringId) {
+        self.client_name = client_name;
+    }
+}
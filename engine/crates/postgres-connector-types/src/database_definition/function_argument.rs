@@ -0,0 +1,79 @@
+use inflector::Inflector;
+use serde::{Deserialize, Serialize};
+
+use super::{names::StringId, ColumnType, FunctionId};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunctionArgument<T> {
+    pub(super) function_id: FunctionId,
+    pub(super) database_name: T,
+    pub(super) database_type: ColumnType,
+    pub(super) client_name: T,
+    pub(super) has_default: bool,
+    pub(super) is_array: bool,
+}
+
+impl<T> FunctionArgument<T> {
+    pub(crate) fn function_id(&self) -> FunctionId {
+        self.function_id
+    }
+
+    pub(crate) fn database_type(&self) -> ColumnType {
+        self.database_type
+    }
+
+    pub(crate) fn has_default(&self) -> bool {
+        self.has_default
+    }
+
+    pub(crate) fn is_array(&self) -> bool {
+        self.is_array
+    }
+
+    pub fn set_has_default(&mut self, value: bool) {
+        self.has_default = value;
+    }
+
+    pub fn set_is_array(&mut self, value: bool) {
+        self.is_array = value;
+    }
+}
+
+impl FunctionArgument<String> {
+    /// A new argument. Unnamed arguments have an empty database name, and are named by their
+    /// position in the client, starting from one.
+    pub fn new(function_id: FunctionId, name: String, position: usize, database_type: ColumnType) -> Self {
+        let client_name = if name.is_empty() {
+            format!("arg{position}")
+        } else {
+            name.to_camel_case()
+        };
+
+        Self {
+            function_id,
+            database_name: name,
+            database_type,
+            client_name,
+            has_default: false,
+            is_array: false,
+        }
+    }
+
+    pub(crate) fn database_name(&self) -> &str {
+        &self.database_name
+    }
+
+    pub(crate) fn client_name(&self) -> &str {
+        &self.client_name
+    }
+}
+
+impl FunctionArgument<StringId> {
+    pub(crate) fn database_name(&self) -> StringId {
+        self.database_name
+    }
+
+    pub(crate) fn client_name(&self) -> StringId {
+        self.client_name
+    }
+}
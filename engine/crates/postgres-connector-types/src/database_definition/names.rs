@@ -6,8 +6,8 @@ use serde::{Deserialize, Serialize};
 
 pub(super) use self::interner::{StringId, StringInterner};
 use super::{
-    Enum, EnumId, EnumVariant, EnumVariantId, ForeignKey, ForeignKeyId, RelationId, SchemaId, Table, TableColumn,
-    TableColumnId, TableId, UniqueConstraint, UniqueConstraintId,
+    CompositeType, CompositeTypeId, Enum, EnumId, EnumVariant, EnumVariantId, ForeignKey, ForeignKeyId, Function,
+    FunctionId, RelationId, SchemaId, Table, TableColumn, TableColumnId, TableId, UniqueConstraint, UniqueConstraintId,
 };
 
 #[derive(Default, Serialize, Deserialize, Debug, Clone)]
@@ -35,6 +35,12 @@ pub(super) struct Names {
     client_enums: HashMap<StringId, EnumId>,
     #[serde(with = "super::vectorize")]
     client_relations: HashMap<(TableId, StringId), RelationId>,
+    #[serde(with = "super::vectorize", default)]
+    functions: HashMap<(SchemaId, StringId), FunctionId>,
+    #[serde(with = "super::vectorize", default)]
+    client_functions: HashMap<StringId, FunctionId>,
+    #[serde(with = "super::vectorize", default)]
+    composite_types: HashMap<(SchemaId, StringId), CompositeTypeId>,
 }
 
 impl Names {
@@ -53,6 +59,16 @@ impl Names {
         self.enums.insert((r#enum.schema_id(), string_id), enum_id);
     }
 
+    pub(super) fn intern_composite_type(&mut self, composite_type: &CompositeType<String>, id: CompositeTypeId) {
+        let string_id = self.interner.intern(composite_type.database_name());
+        self.composite_types.insert((composite_type.schema_id(), string_id), id);
+    }
+
+    pub(super) fn intern_function(&mut self, function: &Function<String>, function_id: FunctionId) {
+        let string_id = self.interner.intern(function.database_name());
+        self.functions.insert((function.schema_id(), string_id), function_id);
+    }
+
     pub(super) fn intern_foreign_key(&mut self, foreign_key: &ForeignKey<String>, foreign_key_id: ForeignKeyId) {
         let string_id = self.interner.intern(foreign_key.constraint_name());
 
@@ -108,6 +124,11 @@ impl Names {
         self.client_relations.insert((table_id, string_id), relation_id);
     }
 
+    pub(super) fn intern_client_function(&mut self, field_name: &str, function_id: FunctionId) {
+        let string_id = self.interner.intern(field_name);
+        self.client_functions.insert(string_id, function_id);
+    }
+
     pub(super) fn intern_string(&mut self, string_value: &str) -> StringId {
         self.interner.intern(string_value)
     }
@@ -144,6 +165,13 @@ impl Names {
             .copied()
     }
 
+    pub(super) fn get_function_id_for_client_field(&self, field_name: &str) -> Option<FunctionId> {
+        self.interner
+            .lookup(field_name)
+            .and_then(|string_id| self.client_functions.get(&string_id))
+            .copied()
+    }
+
     pub(super) fn get_table_id(&self, schema_id: SchemaId, table_name: &str) -> Option<TableId> {
         self.lookup_name(table_name)
             .and_then(|string_id| self.tables.get(&(schema_id, string_id)))
@@ -162,6 +190,18 @@ impl Names {
             .copied()
     }
 
+    pub(super) fn get_composite_type_id(&self, schema_id: SchemaId, type_name: &str) -> Option<CompositeTypeId> {
+        self.lookup_name(type_name)
+            .and_then(|string_id| self.composite_types.get(&(schema_id, string_id)))
+            .copied()
+    }
+
+    pub(super) fn get_function_id(&self, schema_id: SchemaId, function_name: &str) -> Option<FunctionId> {
+        self.lookup_name(function_name)
+            .and_then(|string_id| self.functions.get(&(schema_id, string_id)))
+            .copied()
+    }
+
     pub(super) fn get_foreign_key_id(&self, schema_id: SchemaId, foreign_key_name: &str) -> Option<ForeignKeyId> {
         self.lookup_name(foreign_key_name)
             .and_then(|string_id| self.foreign_keys.get(&(schema_id, string_id)))
@@ -1,9 +1,10 @@
-use super::{EnumId, EnumWalker};
+use super::{CompositeTypeId, CompositeTypeWalker, EnumId, EnumWalker};
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum DatabaseType<'a> {
     Scalar(ScalarType),
     Enum(EnumWalker<'a>),
+    Composite(CompositeTypeWalker<'a>),
 }
 
 impl<'a> DatabaseType<'a> {
@@ -16,21 +17,40 @@ impl<'a> DatabaseType<'a> {
     }
 
     pub fn is_json(&self) -> bool {
-        matches!(self, DatabaseType::Scalar(ScalarType::Json | ScalarType::JsonArray))
+        // geometries are represented as GeoJSON, but have no JSONB operations
+        matches!(
+            self,
+            DatabaseType::Scalar(
+                ScalarType::Json | ScalarType::JsonArray | ScalarType::Geometry | ScalarType::GeometryArray
+            )
+        )
     }
 
     pub fn is_jsonb(&self) -> bool {
         matches!(self, DatabaseType::Scalar(ScalarType::Jsonb | ScalarType::JsonbArray))
     }
+
+    pub fn is_tsvector(&self) -> bool {
+        matches!(self, DatabaseType::Scalar(ScalarType::TsVector))
+    }
+
+    pub fn is_composite(&self) -> bool {
+        matches!(self, DatabaseType::Composite(_))
+    }
+
+    pub fn is_range(&self) -> bool {
+        matches!(self, DatabaseType::Scalar(scalar) if scalar.range_element_type().is_some())
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum ColumnType {
     Scalar(ScalarType),
     Enum(EnumId),
+    Composite(CompositeTypeId),
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum ScalarType {
     Bool,
     Bytea,
@@ -218,6 +238,13 @@ pub enum ScalarType {
     DatemultiRangeArray,
     Int8multiRangeArray,
     Other(u32),
+    // Types defined in extensions have no fixed oid, and are detected by their name.
+    Citext,
+    CitextArray,
+    Ltree,
+    LtreeArray,
+    Geometry,
+    GeometryArray,
 }
 
 impl ScalarType {
@@ -225,18 +252,20 @@ impl ScalarType {
         use ScalarType::*;
 
         let type_name = match self {
-            Char | Name | Text | Xml | Cidr | Macaddr8 | Macaddr | Bpchar | Varchar | Bit | Varbit | Cstring => {
-                "String"
-            }
+            Char | Name | Text | Xml | Cidr | Macaddr8 | Macaddr | Bpchar | Varchar | Bit | Varbit | Cstring
+            | TsVector | Interval | Citext | Ltree => "String",
 
             XmlArray | CidrArray | Macaddr8Array | CharArray | NameArray | TextArray | BpcharArray | VarcharArray
-            | MacaddrArray | CstringArray | BitArray | VarbitArray => "[String]",
+            | MacaddrArray | CstringArray | BitArray | VarbitArray | IntervalArray | CitextArray | LtreeArray => {
+                "[String]"
+            }
 
             Int8 => "BigInt",
             Oid => "UnsignedBigInt",
             Int2 | Int4 => "Int",
-            Json | Jsonb => "JSON",
-            JsonArray | JsonbArray => "[JSON]",
+            // PostGIS geometries are read and written as GeoJSON.
+            Json | Jsonb | Geometry => "JSON",
+            JsonArray | JsonbArray | GeometryArray => "[JSON]",
             Money | Numeric => "Decimal",
             MoneyArray | NumericArray => "[Decimal]",
             Int2Array | Int4Array => "[Int]",
@@ -265,6 +294,38 @@ impl ScalarType {
 
         Some(type_name)
     }
+
+    /// The GraphQL type of the bounds, if the type is a range.
+    pub fn range_element_type(self) -> Option<&'static str> {
+        use ScalarType::*;
+
+        let type_name = match self {
+            Int4Range => "Int",
+            Int8Range => "BigInt",
+            NumRange => "Decimal",
+            TsRange => "NaiveDateTime",
+            TstzRange => "DateTime",
+            DateRange => "Date",
+            _ => return None,
+        };
+
+        Some(type_name)
+    }
+
+    /// Maps a type defined in an extension by its name. Array types are prefixed with an underscore.
+    pub fn from_extension_type(type_name: &str) -> Option<Self> {
+        let scalar = match type_name {
+            "citext" => Self::Citext,
+            "_citext" => Self::CitextArray,
+            "ltree" => Self::Ltree,
+            "_ltree" => Self::LtreeArray,
+            "geometry" => Self::Geometry,
+            "_geometry" => Self::GeometryArray,
+            _ => return None,
+        };
+
+        Some(scalar)
+    }
 }
 
 impl From<u32> for ScalarType {
@@ -1,20 +1,29 @@
 mod back_relation;
+mod composite_type;
+mod composite_type_attribute;
 mod r#enum;
 mod enum_variant;
 mod foreign_key;
 mod foreign_key_column;
 mod forward_relation;
+mod function;
+mod function_argument;
 mod relation;
 mod table;
 mod table_column;
 mod unique_constraint;
 mod unique_constraint_column;
 
-use std::ops::Range;
+use std::{borrow::Cow, ops::Range};
 
+pub use composite_type::CompositeTypeWalker;
+pub use composite_type_attribute::CompositeTypeAttributeWalker;
 pub use enum_variant::EnumVariantWalker;
 pub(crate) use foreign_key::ForeignKeyWalker;
 pub(crate) use foreign_key_column::ForeignKeyColumnWalker;
+pub use function::{FunctionReturnTypeWalker, FunctionWalker};
+pub use function_argument::FunctionArgumentWalker;
+use inflector::Inflector;
 
 pub use r#enum::EnumWalker;
 pub use relation::RelationWalker;
@@ -23,7 +32,7 @@ pub use table_column::TableColumnWalker;
 pub use unique_constraint::UniqueConstraintWalker;
 pub use unique_constraint_column::UniqueConstraintColumnWalker;
 
-use super::names::StringId;
+use super::{names::StringId, DatabaseType};
 use crate::database_definition::DatabaseDefinition;
 
 /// An abstraction to iterate over an introspected PostgreSQL database.
@@ -83,3 +92,67 @@ where
     let end = seed + iter.position(|i| extract(i) != key).unwrap_or(slice.len() - seed);
     start..end
 }
+
+/// The GraphQL type of a value with the given database type, such as a column or a function argument.
+///
+/// Returns `None`, if we don't support the database type yet.
+fn graphql_type<'a>(database_type: DatabaseType<'a>, is_array: bool, prefix: Option<&str>) -> Option<Cow<'a, str>> {
+    match database_type {
+        // arrays of ranges have no GraphQL mapping yet
+        DatabaseType::Scalar(scalar) => match scalar.range_element_type() {
+            Some(_) if is_array => None,
+            Some(element) => Some(object_type_name(&format!("{element}Range"), false, prefix)),
+            None => scalar.client_type().map(Cow::from),
+        },
+        DatabaseType::Composite(composite_type) if composite_type.allowed_in_client() => {
+            Some(object_type_name(composite_type.client_name(), is_array, prefix))
+        }
+        DatabaseType::Composite(_) => None,
+        DatabaseType::Enum(r#enum) if is_array => Some(Cow::from(match prefix {
+            Some(prefix) => format!("[{prefix}_{}]", r#enum.client_name()).to_pascal_case(),
+            None => format!("[{}]", r#enum.client_name()),
+        })),
+        DatabaseType::Enum(r#enum) => Some(match prefix {
+            Some(prefix) => Cow::from(format!("{prefix}_{}", r#enum.client_name()).to_pascal_case()),
+            None => Cow::from(r#enum.client_name()),
+        }),
+    }
+}
+
+/// The GraphQL type of a value with the given database type in input objects and arguments. Composite
+/// and range values are objects in the output, and have a separate input object type.
+///
+/// Returns `None`, if we don't support the database type yet.
+fn graphql_input_type<'a>(
+    database_type: DatabaseType<'a>,
+    is_array: bool,
+    prefix: Option<&str>,
+) -> Option<Cow<'a, str>> {
+    match database_type {
+        DatabaseType::Scalar(scalar) => match scalar.range_element_type() {
+            Some(_) if is_array => None,
+            Some(element) => Some(object_type_name(&format!("{element}RangeInput"), false, prefix)),
+            None => graphql_type(database_type, is_array, prefix),
+        },
+        DatabaseType::Composite(composite_type) if composite_type.allowed_in_client() => Some(object_type_name(
+            &format!("{}Input", composite_type.client_name()),
+            is_array,
+            prefix,
+        )),
+        _ => graphql_type(database_type, is_array, prefix),
+    }
+}
+
+/// The name of an object type defined for a database type, such as a composite or a range type.
+fn object_type_name<'a>(name: &str, is_array: bool, prefix: Option<&str>) -> Cow<'a, str> {
+    let name = match prefix {
+        Some(prefix) => format!("{prefix}_{name}").to_pascal_case(),
+        None => name.to_string(),
+    };
+
+    if is_array {
+        Cow::from(format!("[{name}]"))
+    } else {
+        Cow::from(name)
+    }
+}
@@ -0,0 +1,49 @@
+use super::{CompositeTypeAttributeWalker, Walker};
+use crate::database_definition::{names::StringId, CompositeType, CompositeTypeAttributeId, CompositeTypeId};
+
+/// A composite type definition in the database, created with `CREATE TYPE name AS (...)`.
+pub type CompositeTypeWalker<'a> = Walker<'a, CompositeTypeId>;
+
+impl<'a> CompositeTypeWalker<'a> {
+    /// The schema this type belongs to.
+    pub fn schema(self) -> &'a str {
+        &self.database_definition.schemas[self.get().schema_id().0 as usize]
+    }
+
+    /// The name of the type in the database.
+    pub fn database_name(self) -> &'a str {
+        self.get_name(self.get().database_name())
+    }
+
+    /// The name of the type in the GraphQL APIs.
+    pub fn client_name(self) -> &'a str {
+        self.get_name(self.get().client_name())
+    }
+
+    /// The attributes of the type, in the order they are defined.
+    pub fn attributes(self) -> impl ExactSizeIterator<Item = CompositeTypeAttributeWalker<'a>> + 'a {
+        let range = super::range_for_key(
+            &self.database_definition.composite_type_attributes,
+            self.id,
+            |attribute| attribute.composite_type_id(),
+        );
+
+        range.map(move |id| self.walk(CompositeTypeAttributeId(id as u32)))
+    }
+
+    /// Find an attribute with a given client name.
+    pub fn find_attribute(self, client_name: &str) -> Option<CompositeTypeAttributeWalker<'a>> {
+        self.attributes()
+            .find(|attribute| attribute.client_name() == client_name)
+    }
+
+    /// A type can be used in the client, if all of its attributes have a supported type. Values are
+    /// always written as a whole, so we cannot leave out attributes we don't support.
+    pub fn allowed_in_client(self) -> bool {
+        self.attributes().next().is_some() && self.attributes().all(|attribute| attribute.has_supported_type())
+    }
+
+    fn get(self) -> &'a CompositeType<StringId> {
+        &self.database_definition.composite_types[self.id.0 as usize]
+    }
+}
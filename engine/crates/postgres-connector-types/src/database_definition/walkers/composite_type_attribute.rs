@@ -0,0 +1,60 @@
+use std::borrow::Cow;
+
+use super::{CompositeTypeWalker, Walker};
+use crate::database_definition::{
+    names::StringId, ColumnType, CompositeTypeAttribute, CompositeTypeAttributeId, DatabaseType,
+};
+
+/// Definition of an attribute in a composite type.
+pub type CompositeTypeAttributeWalker<'a> = Walker<'a, CompositeTypeAttributeId>;
+
+impl<'a> CompositeTypeAttributeWalker<'a> {
+    /// The composite type this attribute belongs to.
+    pub fn composite_type(self) -> CompositeTypeWalker<'a> {
+        self.walk(self.get().composite_type_id())
+    }
+
+    /// The name of the attribute in the database.
+    pub fn database_name(self) -> &'a str {
+        self.get_name(self.get().database_name())
+    }
+
+    /// The name of the attribute in the GraphQL APIs.
+    pub fn client_name(self) -> &'a str {
+        self.get_name(self.get().client_name())
+    }
+
+    /// The type of the attribute in the database.
+    pub fn database_type(self) -> DatabaseType<'a> {
+        match self.get().database_type() {
+            ColumnType::Scalar(scalar) => DatabaseType::Scalar(scalar),
+            ColumnType::Enum(enum_id) => DatabaseType::Enum(self.walk(enum_id)),
+            ColumnType::Composite(composite_id) => DatabaseType::Composite(self.walk(composite_id)),
+        }
+    }
+
+    /// The type of this attribute in the GraphQL APIs.
+    ///
+    /// Returns `None`, if we don't support the database type yet.
+    pub fn graphql_type(self, prefix: Option<&str>) -> Option<Cow<'a, str>> {
+        super::graphql_type(self.database_type(), self.is_array(), prefix)
+    }
+
+    /// The type of this attribute in GraphQL input objects.
+    pub fn graphql_input_type(self, prefix: Option<&str>) -> Option<Cow<'a, str>> {
+        super::graphql_input_type(self.database_type(), self.is_array(), prefix)
+    }
+
+    pub fn has_supported_type(self) -> bool {
+        self.graphql_type(None).is_some()
+    }
+
+    /// True, if the attribute is an array.
+    pub fn is_array(self) -> bool {
+        self.get().is_array()
+    }
+
+    fn get(self) -> &'a CompositeTypeAttribute<StringId> {
+        &self.database_definition.composite_type_attributes[self.id.0 as usize]
+    }
+}
@@ -0,0 +1,112 @@
+use std::borrow::Cow;
+
+use inflector::Inflector;
+
+use super::{EnumWalker, FunctionArgumentWalker, TableWalker, Walker};
+use crate::database_definition::{
+    names::StringId, DatabaseType, Function, FunctionArgumentId, FunctionId, FunctionReturnType, ScalarType, Volatility,
+};
+
+/// Definition of a function.
+pub type FunctionWalker<'a> = Walker<'a, FunctionId>;
+
+/// The type returned from a function.
+#[derive(Clone, Copy, PartialEq)]
+pub enum FunctionReturnTypeWalker<'a> {
+    Scalar(ScalarType),
+    Enum(EnumWalker<'a>),
+    Table(TableWalker<'a>),
+}
+
+impl<'a> FunctionWalker<'a> {
+    /// The name of the schema this function is located.
+    pub fn schema(self) -> &'a str {
+        &self.database_definition.schemas[self.get().schema_id().0 as usize]
+    }
+
+    /// The name of the function in the database.
+    pub fn database_name(self) -> &'a str {
+        self.get_name(self.get().database_name())
+    }
+
+    /// The name of the function in the GraphQL APIs.
+    pub fn client_name(self) -> &'a str {
+        self.get_name(self.get().client_name())
+    }
+
+    /// An iterator over the arguments of the function, in the order they are defined.
+    pub fn arguments(self) -> impl ExactSizeIterator<Item = FunctionArgumentWalker<'a>> + 'a {
+        let range = super::range_for_key(&self.database_definition.function_arguments, self.id, |argument| {
+            argument.function_id()
+        });
+
+        range.map(move |id| self.walk(FunctionArgumentId(id as u32)))
+    }
+
+    /// The type this function returns.
+    pub fn return_type(self) -> FunctionReturnTypeWalker<'a> {
+        match self.get().return_type() {
+            FunctionReturnType::Scalar(scalar) => FunctionReturnTypeWalker::Scalar(scalar),
+            FunctionReturnType::Enum(enum_id) => FunctionReturnTypeWalker::Enum(self.walk(enum_id)),
+            FunctionReturnType::Table(table_id) => FunctionReturnTypeWalker::Table(self.walk(table_id)),
+        }
+    }
+
+    /// The table this function returns rows from, if any.
+    pub fn returned_table(self) -> Option<TableWalker<'a>> {
+        match self.return_type() {
+            FunctionReturnTypeWalker::Table(table) => Some(table),
+            _ => None,
+        }
+    }
+
+    /// True, if the function is declared to return a `SETOF` values.
+    pub fn returns_set(self) -> bool {
+        self.get().returns_set()
+    }
+
+    /// True, if the function modifies the database and must be exposed as a mutation.
+    pub fn is_mutation(self) -> bool {
+        matches!(self.get().volatility(), Volatility::Volatile)
+    }
+
+    /// The GraphQL type of a function returning a scalar or an enum value.
+    ///
+    /// Returns `None` for functions returning table rows, or if we don't support the
+    /// database type yet.
+    pub fn graphql_type(self, prefix: Option<&str>) -> Option<Cow<'a, str>> {
+        let database_type = match self.return_type() {
+            FunctionReturnTypeWalker::Scalar(scalar) => DatabaseType::Scalar(scalar),
+            FunctionReturnTypeWalker::Enum(r#enum) => DatabaseType::Enum(r#enum),
+            FunctionReturnTypeWalker::Table(_) => return None,
+        };
+
+        let graphql_type = super::graphql_type(database_type, self.get().returns_array(), prefix)?;
+
+        if self.returns_set() {
+            Some(Cow::from(format!("[{graphql_type}]")))
+        } else {
+            Some(graphql_type)
+        }
+    }
+
+    /// A function can be used in the client, if all of its arguments and the return value
+    /// have a type we support.
+    pub fn allowed_in_client(self) -> bool {
+        let supported_return_type = match self.returned_table() {
+            Some(table) => table.allowed_in_client(),
+            None => self.graphql_type(None).is_some(),
+        };
+
+        supported_return_type && self.arguments().all(|argument| argument.has_supported_type())
+    }
+
+    /// The name of the result set of this function, used when selecting rows from it.
+    pub fn result_set_name(self) -> String {
+        format!("{}_{}_result", self.schema(), self.database_name()).to_snake_case()
+    }
+
+    fn get(self) -> &'a Function<StringId> {
+        &self.database_definition.functions[self.id.0 as usize]
+    }
+}
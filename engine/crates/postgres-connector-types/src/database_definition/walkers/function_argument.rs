@@ -0,0 +1,63 @@
+use std::borrow::Cow;
+
+use super::{FunctionWalker, Walker};
+use crate::database_definition::{names::StringId, ColumnType, DatabaseType, FunctionArgument, FunctionArgumentId};
+
+/// Definition of an argument of a function.
+pub type FunctionArgumentWalker<'a> = Walker<'a, FunctionArgumentId>;
+
+impl<'a> FunctionArgumentWalker<'a> {
+    /// The function this argument belongs to.
+    pub fn function(self) -> FunctionWalker<'a> {
+        self.walk(self.get().function_id())
+    }
+
+    /// The name of the argument in the database, `None` for unnamed arguments.
+    pub fn database_name(self) -> Option<&'a str> {
+        Some(self.get_name(self.get().database_name())).filter(|name| !name.is_empty())
+    }
+
+    /// The name of the argument in the GraphQL APIs.
+    pub fn client_name(self) -> &'a str {
+        self.get_name(self.get().client_name())
+    }
+
+    /// The type of the argument in the database.
+    pub fn database_type(self) -> DatabaseType<'a> {
+        match self.get().database_type() {
+            ColumnType::Scalar(scalar) => DatabaseType::Scalar(scalar),
+            ColumnType::Enum(enum_id) => DatabaseType::Enum(self.walk(enum_id)),
+            ColumnType::Composite(composite_id) => DatabaseType::Composite(self.walk(composite_id)),
+        }
+    }
+
+    /// The type of this argument in the GraphQL APIs.
+    ///
+    /// Returns `None`, if we don't support the database type yet.
+    pub fn graphql_type(self, prefix: Option<&str>) -> Option<Cow<'a, str>> {
+        super::graphql_type(self.database_type(), self.is_array(), prefix)
+    }
+
+    /// The type of this argument in GraphQL input objects and arguments.
+    pub fn graphql_input_type(self, prefix: Option<&str>) -> Option<Cow<'a, str>> {
+        super::graphql_input_type(self.database_type(), self.is_array(), prefix)
+    }
+
+    pub fn has_supported_type(self) -> bool {
+        self.graphql_type(None).is_some()
+    }
+
+    /// True, if the argument can be omitted from the call.
+    pub fn has_default(self) -> bool {
+        self.get().has_default()
+    }
+
+    /// True, if the argument is an array.
+    pub fn is_array(self) -> bool {
+        self.get().is_array()
+    }
+
+    fn get(self) -> &'a FunctionArgument<StringId> {
+        &self.database_definition.function_arguments[self.id.0 as usize]
+    }
+}
@@ -1,7 +1,5 @@
 use std::borrow::Cow;
 
-use inflector::Inflector;
-
 use super::{TableWalker, Walker};
 use crate::database_definition::{names::StringId, ColumnType, DatabaseType, TableColumn, TableColumnId};
 
@@ -29,6 +27,7 @@ impl<'a> TableColumnWalker<'a> {
         match self.get().database_type() {
             ColumnType::Scalar(scalar) => DatabaseType::Scalar(scalar),
             ColumnType::Enum(enum_id) => DatabaseType::Enum(self.walk(enum_id)),
+            ColumnType::Composite(composite_id) => DatabaseType::Composite(self.walk(composite_id)),
         }
     }
 
@@ -42,17 +41,12 @@ impl<'a> TableColumnWalker<'a> {
     ///
     /// Returns `None`, if we don't support the database type yet.
     pub fn graphql_type(self, prefix: Option<&str>) -> Option<Cow<'a, str>> {
-        match self.database_type() {
-            DatabaseType::Scalar(scalar) => scalar.client_type().map(Cow::from),
-            DatabaseType::Enum(r#enum) if self.is_array() => Some(Cow::from(match prefix {
-                Some(prefix) => format!("[{prefix}_{}]", r#enum.client_name()).to_pascal_case(),
-                None => format!("[{}]", r#enum.client_name()),
-            })),
-            DatabaseType::Enum(r#enum) => Some(match prefix {
-                Some(prefix) => Cow::from(format!("{prefix}_{}", r#enum.client_name()).to_pascal_case()),
-                None => Cow::from(r#enum.client_name()),
-            }),
-        }
+        super::graphql_type(self.database_type(), self.is_array(), prefix)
+    }
+
+    /// The type of this column in GraphQL input objects and arguments.
+    pub fn graphql_input_type(self, prefix: Option<&str>) -> Option<Cow<'a, str>> {
+        super::graphql_input_type(self.database_type(), self.is_array(), prefix)
     }
 
     pub fn has_supported_type(self) -> bool {
@@ -31,9 +31,15 @@ impl<'a> UniqueConstraintWalker<'a> {
         columns.all(|left| self.columns().any(|right| left == right.table_column()))
     }
 
-    /// True, if all columns in the constraint have a type we support.
+    /// True, if all columns in the constraint have a type we support. Composite and range values
+    /// are objects, which cannot be used to find a unique row.
     pub fn all_columns_use_supported_types(self) -> bool {
-        self.columns().all(|column| column.table_column().has_supported_type())
+        self.columns().all(|column| {
+            let column = column.table_column();
+            let database_type = column.database_type();
+
+            column.has_supported_type() && !database_type.is_composite() && !database_type.is_range()
+        })
     }
 
     /// True, if the constraint is the primary key of the table.
@@ -1,6 +1,9 @@
+pub mod change_feed;
 pub mod cursor;
 pub mod database_definition;
 pub mod error;
+pub mod range;
+pub mod row_level_security;
 pub mod transport;
 
 pub type Result<T> = std::result::Result<T, error::Error>;
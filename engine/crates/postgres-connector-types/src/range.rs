@@ -0,0 +1,135 @@
+//! Conversions between range values in the PostgreSQL text format, such as `[1,10)`, and
+//! the range objects of the GraphQL APIs.
+
+use serde_json::{Map, Value};
+
+use crate::{database_definition::ScalarType, error::Error};
+
+/// Parses a range from the PostgreSQL text format into a range object. The bounds are
+/// converted to the format of the GraphQL scalar of the range elements.
+pub fn to_object(scalar: ScalarType, range: &str) -> crate::Result<Value> {
+    if range == "empty" {
+        return Ok(object(Value::Null, Value::Null, false, false, true));
+    }
+
+    let invalid = || Error::Internal(format!("invalid range value: {range}"));
+    let mut chars = range.chars();
+
+    let start_inclusive = match chars.next() {
+        Some('[') => true,
+        Some('(') => false,
+        _ => return Err(invalid()),
+    };
+
+    let end_inclusive = match chars.next_back() {
+        Some(']') => true,
+        Some(')') => false,
+        _ => return Err(invalid()),
+    };
+
+    let (start, end) = split_bounds(chars.as_str()).ok_or_else(invalid)?;
+    let start = start.map(|value| element(scalar, value)).unwrap_or_default();
+    let end = end.map(|value| element(scalar, value)).unwrap_or_default();
+
+    Ok(object(start, end, start_inclusive, end_inclusive, false))
+}
+
+/// Renders a range object from the GraphQL APIs in the PostgreSQL text format. A missing bound
+/// is unbounded. The start is inclusive and the end exclusive, if not defined otherwise.
+pub fn to_literal(range: &Map<String, Value>) -> String {
+    let start_inclusive = range.get("startInclusive").and_then(Value::as_bool).unwrap_or(true);
+    let end_inclusive = range.get("endInclusive").and_then(Value::as_bool).unwrap_or(false);
+
+    format!(
+        "{}{},{}{}",
+        if start_inclusive { '[' } else { '(' },
+        literal_bound(range.get("start")),
+        literal_bound(range.get("end")),
+        if end_inclusive { ']' } else { ')' },
+    )
+}
+
+/// Renders a range containing only the given value, to check if a range contains the value.
+pub fn to_single_value_literal(value: &Value) -> String {
+    let bound = literal_bound(Some(value));
+    format!("[{bound},{bound}]")
+}
+
+fn object(start: Value, end: Value, start_inclusive: bool, end_inclusive: bool, is_empty: bool) -> Value {
+    let mut object = Map::new();
+
+    object.insert(String::from("start"), start);
+    object.insert(String::from("end"), end);
+    object.insert(String::from("startInclusive"), Value::Bool(start_inclusive));
+    object.insert(String::from("endInclusive"), Value::Bool(end_inclusive));
+    object.insert(String::from("isEmpty"), Value::Bool(is_empty));
+
+    Value::Object(object)
+}
+
+/// Splits the bounds between the brackets, unquoting them. An empty bound is unbounded.
+fn split_bounds(bounds: &str) -> Option<(Option<String>, Option<String>)> {
+    let mut result = Vec::with_capacity(2);
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut quoted = false;
+    let mut chars = bounds.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => current.push(chars.next()?),
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                chars.next();
+                current.push('"');
+            }
+            '"' => {
+                in_quotes = !in_quotes;
+                quoted = true;
+            }
+            ',' if !in_quotes => {
+                result.push((quoted || !current.is_empty()).then(|| std::mem::take(&mut current)));
+                quoted = false;
+            }
+            c => current.push(c),
+        }
+    }
+
+    result.push((quoted || !current.is_empty()).then_some(current));
+
+    match <[_; 2]>::try_from(result) {
+        Ok([start, end]) => Some((start, end)),
+        Err(_) => None,
+    }
+}
+
+fn element(scalar: ScalarType, value: String) -> Value {
+    match scalar {
+        ScalarType::Int4Range | ScalarType::Int8Range => match value.parse::<i64>() {
+            Ok(number) => Value::from(number),
+            Err(_) => Value::String(value),
+        },
+        // the text format separates the date and the time with a space
+        ScalarType::TsRange => Value::String(value.replacen(' ', "T", 1)),
+        ScalarType::TstzRange => {
+            let mut value = value.replacen(' ', "T", 1);
+
+            // the offset is rendered without minutes, if they are zero
+            if let [.., b'+' | b'-', hours, minutes] = value.as_bytes() {
+                if hours.is_ascii_digit() && minutes.is_ascii_digit() {
+                    value.push_str(":00");
+                }
+            }
+
+            Value::String(value)
+        }
+        _ => Value::String(value),
+    }
+}
+
+fn literal_bound(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(value)) => format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"")),
+        Some(value) => value.to_string(),
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{error::Error, transport::Session};
+
+/// Configures how the claims of a verified token are passed to the database, to be used
+/// in row-level security policies. If set, every operation runs in a transaction, where
+/// the role and the settings are set locally before the query.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RowLevelSecurity {
+    /// The claim holding the role to switch to.
+    #[serde(default)]
+    role_claim: Option<String>,
+    /// The role to switch to, if the token has no role claim or the request has no token.
+    #[serde(default)]
+    default_role: Option<String>,
+    /// The setting holding all claims of the token as a JSON object, such as `request.jwt.claims`.
+    #[serde(default)]
+    claims_setting: Option<String>,
+    /// Single claims mapped to settings.
+    #[serde(default)]
+    settings: Vec<ClaimSetting>,
+}
+
+/// Maps a claim to a setting, readable with `current_setting(name)` in the database.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ClaimSetting {
+    /// The name of the setting, such as `app.tenant_id`.
+    name: String,
+    /// The claim. A dot-separated path points to a claim in a nested object.
+    claim: String,
+}
+
+impl ClaimSetting {
+    pub fn new(name: impl Into<String>, claim: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            claim: claim.into(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn claim(&self) -> &str {
+        &self.claim
+    }
+}
+
+impl RowLevelSecurity {
+    pub fn set_role_claim(&mut self, claim: impl Into<String>) {
+        self.role_claim = Some(claim.into());
+    }
+
+    pub fn set_default_role(&mut self, role: impl Into<String>) {
+        self.default_role = Some(role.into());
+    }
+
+    pub fn set_claims_setting(&mut self, setting: impl Into<String>) {
+        self.claims_setting = Some(setting.into());
+    }
+
+    pub fn push_setting(&mut self, setting: ClaimSetting) {
+        self.settings.push(setting);
+    }
+
+    pub fn role_claim(&self) -> Option<&str> {
+        self.role_claim.as_deref()
+    }
+
+    pub fn default_role(&self) -> Option<&str> {
+        self.default_role.as_deref()
+    }
+
+    pub fn claims_setting(&self) -> Option<&str> {
+        self.claims_setting.as_deref()
+    }
+
+    pub fn settings(&self) -> &[ClaimSetting] {
+        &self.settings
+    }
+
+    /// Builds the session for a request with the given token claims, or with no token.
+    ///
+    /// Claims missing from the token are set as empty strings, so `current_setting` doesn't fail
+    /// in the policies. A role claim is required, unless a default role is defined.
+    pub fn session(&self, claims: Option<&BTreeMap<String, Value>>) -> crate::Result<Session> {
+        let mut session = Session::default();
+
+        if let Some(ref claim) = self.role_claim {
+            let role = match claims.and_then(|claims| find_claim(claims, claim)) {
+                Some(Value::String(role)) => role.clone(),
+                Some(_) => {
+                    return Err(Error::Unauthorized(format!("the {claim} claim must be a string")));
+                }
+                None => match self.default_role {
+                    Some(ref role) => role.clone(),
+                    None => {
+                        return Err(Error::Unauthorized(format!(
+                            "the token has no {claim} claim, and no default role is defined"
+                        )));
+                    }
+                },
+            };
+
+            session.set_role(role);
+        } else if let Some(ref role) = self.default_role {
+            session.set_role(role.clone());
+        }
+
+        if let Some(ref setting) = self.claims_setting {
+            let claims = claims.map(serde_json::to_string).transpose()?;
+            session.push_setting(setting.clone(), claims.unwrap_or_default());
+        }
+
+        for setting in &self.settings {
+            let value = match claims.and_then(|claims| find_claim(claims, &setting.claim)) {
+                Some(Value::String(value)) => value.clone(),
+                Some(value) => value.to_string(),
+                None => String::new(),
+            };
+
+            session.push_setting(setting.name.clone(), value);
+        }
+
+        Ok(session)
+    }
+}
+
+fn find_claim<'a>(claims: &'a BTreeMap<String, Value>, path: &str) -> Option<&'a Value> {
+    let mut segments = path.split('.');
+    let mut value = claims.get(segments.next()?)?;
+
+    for segment in segments {
+        value = value.as_object()?.get(segment)?;
+    }
+
+    Some(value)
+}
@@ -1,10 +1,12 @@
 mod ext;
+mod session;
 mod tcp;
 
 use async_trait::async_trait;
 pub use ext::TransportExt;
 use futures::stream::BoxStream;
 use serde_json::Value;
+pub use session::{Session, SessionTransport};
 pub use tcp::{TcpTransport, Transaction};
 
 use crate::{database_definition::ScalarType, error::Error};
@@ -39,8 +41,36 @@ pub trait Transport: Send + Sync {
 
     fn parameterized_query<'a>(&'a self, query: &'a str, params: Vec<Value>) -> BoxStream<'a, Result<Value, Error>>;
 
+    /// Runs the query in a transaction, with the session applied before the query.
+    fn parameterized_query_in_session<'a>(
+        &'a self,
+        session: &'a Session,
+        query: &'a str,
+        params: Vec<Value>,
+    ) -> BoxStream<'a, Result<Value, Error>>;
+
+    /// Runs the statement in a transaction, with the session applied before the statement.
+    async fn parameterized_execute_in_session(
+        &self,
+        session: &Session,
+        query: &str,
+        params: Vec<Value>,
+    ) -> crate::Result<i64>;
+
     fn connection_string(&self) -> &str;
 
+    /// Listens to the notifications sent to the channel on a dedicated connection, returning the
+    /// payloads.
+    fn listen<'a>(&'a self, channel: &'a str) -> BoxStream<'a, Result<String, Error>> {
+        let _ = channel;
+
+        Box::pin(futures::stream::once(async {
+            Err(Error::Internal(String::from(
+                "listening to notifications is not supported with this connection",
+            )))
+        }))
+    }
+
     fn query<'a>(&'a self, query: &'a str) -> BoxStream<'a, Result<Value, Error>> {
         self.parameterized_query(query, Vec::new())
     }
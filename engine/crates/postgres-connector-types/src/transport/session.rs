@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+use serde_json::Value;
+
+use super::Transport;
+use crate::error::Error;
+
+/// Settings applied to the transaction of a query before running it. All settings are local
+/// to the transaction, and are reset after it's done.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Session {
+    role: Option<String>,
+    settings: Vec<(String, String)>,
+}
+
+impl Session {
+    /// Switches to the given role, same as `SET LOCAL ROLE`.
+    pub fn set_role(&mut self, role: impl Into<String>) {
+        self.role = Some(role.into());
+    }
+
+    /// Sets a configuration parameter, readable with `current_setting(name)`.
+    pub fn push_setting(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.settings.push((name.into(), value.into()));
+    }
+
+    pub fn role(&self) -> Option<&str> {
+        self.role.as_deref()
+    }
+
+    pub fn settings(&self) -> impl ExactSizeIterator<Item = (&str, &str)> + '_ {
+        self.settings
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    /// The statement applying the session, if there is anything to apply. Uses `set_config`, so
+    /// the role and the values can be given as parameters.
+    pub fn statement(&self) -> Option<(String, Vec<Value>)> {
+        let settings = self
+            .role
+            .as_deref()
+            .map(|role| ("role", role))
+            .into_iter()
+            .chain(self.settings());
+
+        let mut calls = Vec::new();
+        let mut params = Vec::new();
+
+        for (name, value) in settings {
+            params.push(Value::from(name));
+            params.push(Value::from(value));
+
+            calls.push(format!("set_config(${}, ${}, true)", params.len() - 1, params.len()));
+        }
+
+        if calls.is_empty() {
+            return None;
+        }
+
+        Some((format!("SELECT {}", calls.join(", ")), params))
+    }
+}
+
+/// A transport running every query in its own transaction with the given session applied.
+pub struct SessionTransport {
+    inner: Arc<dyn Transport>,
+    session: Session,
+}
+
+impl SessionTransport {
+    pub fn new(inner: Arc<dyn Transport>, session: Session) -> Self {
+        Self { inner, session }
+    }
+
+    pub fn session(&self) -> &Session {
+        &self.session
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Transport for SessionTransport {
+    // the inner transport is shared, and closed by its owner
+    async fn close(self) -> crate::Result<()> {
+        Ok(())
+    }
+
+    fn parameterized_query<'a>(&'a self, query: &'a str, params: Vec<Value>) -> BoxStream<'a, Result<Value, Error>> {
+        self.inner.parameterized_query_in_session(&self.session, query, params)
+    }
+
+    async fn parameterized_execute(&self, query: &str, params: Vec<Value>) -> crate::Result<i64> {
+        self.inner
+            .parameterized_execute_in_session(&self.session, query, params)
+            .await
+    }
+
+    fn parameterized_query_in_session<'a>(
+        &'a self,
+        session: &'a Session,
+        query: &'a str,
+        params: Vec<Value>,
+    ) -> BoxStream<'a, Result<Value, Error>> {
+        self.inner.parameterized_query_in_session(session, query, params)
+    }
+
+    async fn parameterized_execute_in_session(
+        &self,
+        session: &Session,
+        query: &str,
+        params: Vec<Value>,
+    ) -> crate::Result<i64> {
+        self.inner
+            .parameterized_execute_in_session(session, query, params)
+            .await
+    }
+
+    fn connection_string(&self) -> &str {
+        self.inner.connection_string()
+    }
+
+    // notifications are not bound to a session
+    fn listen<'a>(&'a self, channel: &'a str) -> BoxStream<'a, Result<String, Error>> {
+        self.inner.listen(channel)
+    }
+}
@@ -2,119 +2,153 @@ mod conversion;
 mod executor;
 mod transaction;
 
+use async_lock::Mutex;
 use async_trait::async_trait;
-use futures::{channel::oneshot, stream::BoxStream};
+use futures::{channel::oneshot, pin_mut, stream::BoxStream, StreamExt};
 use serde_json::Value;
 pub use tokio_postgres::Transaction;
 
 use self::conversion::json_to_string;
-use super::Transport;
+use super::{Session, Transport};
 use crate::error::Error;
 
+/// How many idle connections are kept for the session transactions.
+const MAX_IDLE_SESSION_CONNECTIONS: usize = 8;
+
 pub struct TcpTransport {
     client: tokio_postgres::Client,
+    /// A session transaction needs a connection of its own, so the other queries don't wait for it to
+    /// end. The connections are reused once the transaction is over.
+    session_connections: Mutex<Vec<tokio_postgres::Client>>,
     connection_string: String,
     close_recv: oneshot::Receiver<()>,
 }
 
 impl TcpTransport {
-    #[cfg(not(target_arch = "wasm32"))]
     pub async fn new(connection_string: &str) -> crate::Result<Self> {
-        let mut roots = rustls::RootCertStore::empty();
+        let (client, close_recv) = connect(connection_string).await?;
+
+        let this = Self {
+            client,
+            session_connections: Mutex::new(Vec::new()),
+            connection_string: connection_string.to_string(),
+            close_recv,
+        };
 
-        for cert in rustls_native_certs::load_native_certs().expect("could not load platform certs") {
-            roots
-                .add(&rustls::Certificate(cert.0))
-                .expect("could not add platform cert");
+        Ok(this)
+    }
+
+    pub async fn transaction(&mut self) -> crate::Result<Transaction<'_>> {
+        Ok(self.client.transaction().await?)
+    }
+
+    /// An idle session connection, or a new one if they're all in use.
+    async fn session_connection(&self) -> crate::Result<tokio_postgres::Client> {
+        while let Some(client) = self.session_connections.lock().await.pop() {
+            if !client.is_closed() {
+                return Ok(client);
+            }
         }
 
-        let config = rustls::ClientConfig::builder()
-            .with_safe_defaults()
-            .with_root_certificates(roots)
-            .with_no_client_auth();
+        let (client, _) = connect(&self.connection_string).await?;
 
-        let tls = tokio_postgres_rustls::MakeRustlsConnect::new(config);
+        Ok(client)
+    }
 
-        let (client, connection) = tokio_postgres::connect(connection_string, tls)
-            .await
-            .map_err(|error| crate::error::Error::Connection(error.to_string()))?;
+    /// Must only be called once the transaction is committed, a failed connection is dropped instead.
+    async fn release_session_connection(&self, client: tokio_postgres::Client) {
+        let mut idle = self.session_connections.lock().await;
 
-        let (close_send, close_recv) = oneshot::channel();
+        if !client.is_closed() && idle.len() < MAX_IDLE_SESSION_CONNECTIONS {
+            idle.push(client);
+        }
+    }
+}
 
-        async_runtime::spawn(async move {
-            if let Err(e) = connection.await {
-                tracing::trace!("postgres connection error: {e}");
-            }
+#[cfg(not(target_arch = "wasm32"))]
+async fn connect(connection_string: &str) -> crate::Result<(tokio_postgres::Client, oneshot::Receiver<()>)> {
+    let (client, connection) = tokio_postgres::connect(connection_string, tls())
+        .await
+        .map_err(|error| crate::error::Error::Connection(error.to_string()))?;
 
-            if close_send.send(()).is_err() {
-                tracing::trace!("did not close the postgres connection properly (matters only in API)");
-            }
-        });
+    let (close_send, close_recv) = oneshot::channel();
 
-        let this = Self {
-            client,
-            connection_string: connection_string.to_string(),
-            close_recv,
-        };
+    async_runtime::spawn(async move {
+        if let Err(e) = connection.await {
+            tracing::trace!("postgres connection error: {e}");
+        }
 
-        Ok(this)
-    }
+        if close_send.send(()).is_err() {
+            tracing::trace!("did not close the postgres connection properly (matters only in API)");
+        }
+    });
 
-    #[cfg(target_arch = "wasm32")]
-    pub async fn new(connection_string: &str) -> crate::Result<Self> {
-        use std::str::FromStr;
+    Ok((client, close_recv))
+}
 
-        let url = url::Url::parse(connection_string)
-            .map_err(|error| crate::error::Error::InvalidConnectionString(error.to_string()))?;
+#[cfg(target_arch = "wasm32")]
+async fn connect(connection_string: &str) -> crate::Result<(tokio_postgres::Client, oneshot::Receiver<()>)> {
+    use std::str::FromStr;
 
-        let config = tokio_postgres::config::Config::from_str(connection_string)
-            .map_err(|error| crate::error::Error::Connection(error.to_string()))?;
+    let url = url::Url::parse(connection_string)
+        .map_err(|error| crate::error::Error::InvalidConnectionString(error.to_string()))?;
 
-        let hostname = url.host_str().ok_or_else(|| {
-            crate::error::Error::InvalidConnectionString(String::from(
-                "the connection string does not define a valid hostname",
-            ))
-        })?;
+    let config = tokio_postgres::config::Config::from_str(connection_string)
+        .map_err(|error| crate::error::Error::Connection(error.to_string()))?;
 
-        let socket = worker::Socket::builder()
-            .connect(hostname, url.port().unwrap_or(5432))
-            .map_err(|error| crate::error::Error::Connection(error.to_string()))?;
+    let hostname = url.host_str().ok_or_else(|| {
+        crate::error::Error::InvalidConnectionString(String::from(
+            "the connection string does not define a valid hostname",
+        ))
+    })?;
 
-        let (client, connection) = config
-            .connect_raw(socket, tokio_postgres::tls::NoTls)
-            .await
-            .map_err(|error| crate::error::Error::Connection(error.to_string()))?;
+    let socket = worker::Socket::builder()
+        .connect(hostname, url.port().unwrap_or(5432))
+        .map_err(|error| crate::error::Error::Connection(error.to_string()))?;
 
-        let (close_send, close_recv) = oneshot::channel();
+    let (client, connection) = config
+        .connect_raw(socket, tokio_postgres::tls::NoTls)
+        .await
+        .map_err(|error| crate::error::Error::Connection(error.to_string()))?;
 
-        async_runtime::spawn(async move {
-            if let Err(e) = connection.await {
-                tracing::trace!("postgres connection error: {e}");
-            }
+    let (close_send, close_recv) = oneshot::channel();
 
-            if close_send.send(()).is_err() {
-                tracing::trace!("did not close the postgres connection properly (matters only in API)");
-            }
-        });
+    async_runtime::spawn(async move {
+        if let Err(e) = connection.await {
+            tracing::trace!("postgres connection error: {e}");
+        }
 
-        let this = Self {
-            client,
-            connection_string: connection_string.to_string(),
-            close_recv,
-        };
+        if close_send.send(()).is_err() {
+            tracing::trace!("did not close the postgres connection properly (matters only in API)");
+        }
+    });
 
-        Ok(this)
-    }
+    Ok((client, close_recv))
+}
 
-    pub async fn transaction(&mut self) -> crate::Result<Transaction<'_>> {
-        Ok(self.client.transaction().await?)
+#[cfg(not(target_arch = "wasm32"))]
+fn tls() -> tokio_postgres_rustls::MakeRustlsConnect {
+    let mut roots = rustls::RootCertStore::empty();
+
+    for cert in rustls_native_certs::load_native_certs().expect("could not load platform certs") {
+        roots
+            .add(&rustls::Certificate(cert.0))
+            .expect("could not add platform cert");
     }
+
+    let config = rustls::ClientConfig::builder()
+        .with_safe_defaults()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+
+    tokio_postgres_rustls::MakeRustlsConnect::new(config)
 }
 
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl Transport for TcpTransport {
     async fn close(self) -> crate::Result<()> {
+        drop(self.session_connections);
         drop(self.client);
         self.close_recv.await.map_err(|e| Error::Internal(e.to_string()))
     }
@@ -127,7 +161,93 @@ impl Transport for TcpTransport {
         executor::execute(&self.client, query, params).await
     }
 
+    fn parameterized_query_in_session<'a>(
+        &'a self,
+        session: &'a Session,
+        query: &'a str,
+        params: Vec<Value>,
+    ) -> BoxStream<'a, Result<Value, Error>> {
+        Box::pin(async_stream::try_stream! {
+            let mut client = self.session_connection().await?;
+            let transaction = client.transaction().await?;
+
+            executor::apply_session(&transaction, session).await?;
+
+            {
+                let rows = executor::query(&transaction, query, params);
+
+                pin_mut!(rows);
+
+                while let Some(row) = rows.next().await {
+                    yield row?;
+                }
+            }
+
+            transaction.commit().await?;
+            self.release_session_connection(client).await;
+        })
+    }
+
+    async fn parameterized_execute_in_session(
+        &self,
+        session: &Session,
+        query: &str,
+        params: Vec<Value>,
+    ) -> crate::Result<i64> {
+        let mut client = self.session_connection().await?;
+        let transaction = client.transaction().await?;
+
+        executor::apply_session(&transaction, session).await?;
+        let row_count = executor::execute(&transaction, query, params).await?;
+
+        transaction.commit().await?;
+        self.release_session_connection(client).await;
+
+        Ok(row_count)
+    }
+
     fn connection_string(&self) -> &str {
         &self.connection_string
     }
+
+    // the notifications of the shared connection would go to every listener
+    #[cfg(not(target_arch = "wasm32"))]
+    fn listen<'a>(&'a self, channel: &'a str) -> BoxStream<'a, Result<String, Error>> {
+        use tokio_postgres::AsyncMessage;
+
+        Box::pin(async_stream::try_stream! {
+            let (client, mut connection) = tokio_postgres::connect(&self.connection_string, tls())
+                .await
+                .map_err(|error| Error::Connection(error.to_string()))?;
+
+            let (sender, mut receiver) = futures::channel::mpsc::unbounded();
+
+            async_runtime::spawn(async move {
+                let mut messages = futures::stream::poll_fn(move |cx| connection.poll_message(cx));
+
+                while let Some(message) = messages.next().await {
+                    let payload = match message {
+                        Ok(AsyncMessage::Notification(notification)) => Ok(notification.payload().to_string()),
+                        Ok(_) => continue,
+                        Err(error) => Err(Error::from(error)),
+                    };
+
+                    let failed = payload.is_err();
+
+                    // the listener is gone, or the connection is lost
+                    if sender.unbounded_send(payload).is_err() || failed {
+                        break;
+                    }
+                }
+            });
+
+            client
+                .batch_execute(&format!("LISTEN \"{}\"", channel.replace('"', "\"\"")))
+                .await?;
+
+            while let Some(payload) = receiver.next().await {
+                yield payload?;
+            }
+        })
+    }
 }
@@ -2,7 +2,7 @@ use futures::{pin_mut, stream::BoxStream, StreamExt};
 use serde_json::Value;
 use tokio_postgres::GenericClient;
 
-use crate::error::Error;
+use crate::{error::Error, transport::Session};
 
 pub(super) fn query<'a, T>(client: &'a T, query: &'a str, params: Vec<Value>) -> BoxStream<'a, Result<Value, Error>>
 where
@@ -46,3 +46,15 @@ where
 
     Ok(row_count.unwrap_or_default())
 }
+
+/// Applies the session settings. Must be called in a transaction.
+pub(super) async fn apply_session<T>(client: &T, session: &Session) -> crate::Result<()>
+where
+    T: GenericClient,
+{
+    if let Some((statement, params)) = session.statement() {
+        execute(client, &statement, params).await?;
+    }
+
+    Ok(())
+}
@@ -1,10 +1,13 @@
 use async_trait::async_trait;
-use futures::stream::BoxStream;
+use futures::{pin_mut, stream::BoxStream, StreamExt};
 use serde_json::Value;
 use tokio_postgres::Transaction;
 
 use super::executor;
-use crate::{error::Error, transport::Transport};
+use crate::{
+    error::Error,
+    transport::{Session, Transport},
+};
 
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
@@ -21,6 +24,36 @@ impl Transport for Transaction<'_> {
         executor::execute(self, query, params).await
     }
 
+    // we're already in a transaction, the session stays until the end of it
+    fn parameterized_query_in_session<'a>(
+        &'a self,
+        session: &'a Session,
+        query: &'a str,
+        params: Vec<Value>,
+    ) -> BoxStream<'a, Result<Value, Error>> {
+        Box::pin(async_stream::try_stream! {
+            executor::apply_session(self, session).await?;
+
+            let rows = executor::query(self, query, params);
+
+            pin_mut!(rows);
+
+            while let Some(row) = rows.next().await {
+                yield row?;
+            }
+        })
+    }
+
+    async fn parameterized_execute_in_session(
+        &self,
+        session: &Session,
+        query: &str,
+        params: Vec<Value>,
+    ) -> crate::Result<i64> {
+        executor::apply_session(self, session).await?;
+        executor::execute(self, query, params).await
+    }
+
     // we don't care about this in a tx
     fn connection_string(&self) -> &str {
         ""
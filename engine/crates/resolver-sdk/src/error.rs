@@ -0,0 +1,46 @@
+use serde_json::{Map, Value};
+
+/// Why a resolver failed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// Only logged, the client receives a generic error, like for a thrown JavaScript exception.
+    Message(String),
+    /// Added as is to the `errors` of the response.
+    Graphql {
+        message: String,
+        extensions: Option<Map<String, Value>>,
+    },
+}
+
+impl Error {
+    pub fn new(message: impl Into<String>) -> Self {
+        Error::Message(message.into())
+    }
+
+    /// An error returned to the client, like a `GraphQLError` thrown by a JavaScript resolver.
+    pub fn graphql(message: impl Into<String>) -> Self {
+        Error::Graphql {
+            message: message.into(),
+            extensions: None,
+        }
+    }
+
+    /// Adds an extension to a [`Error::Graphql`], does nothing for other errors.
+    #[must_use]
+    pub fn with_extension(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        if let Error::Graphql { extensions, .. } = &mut self {
+            extensions.get_or_insert_with(Map::new).insert(key.into(), value.into());
+        }
+        self
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Message(message) | Error::Graphql { message, .. } => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,120 @@
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::Error;
+
+/// An outgoing HTTP request, sent by the gateway with [`fetch`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+    method: String,
+    url: String,
+    headers: Vec<(String, String)>,
+    body: Option<Vec<u8>>,
+}
+
+impl Request {
+    pub fn new(method: impl Into<String>, url: impl Into<String>) -> Self {
+        Request {
+            method: method.into(),
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    pub fn get(url: impl Into<String>) -> Self {
+        Self::new("GET", url)
+    }
+
+    pub fn post(url: impl Into<String>) -> Self {
+        Self::new("POST", url)
+    }
+
+    #[must_use]
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    #[must_use]
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Sets a JSON body along with its `Content-Type`.
+    pub fn json<T: Serialize>(self, body: &T) -> Result<Self, Error> {
+        let body = serde_json::to_vec(body).map_err(|err| Error::new(format!("Invalid JSON body: {err}")))?;
+        Ok(self.header("content-type", "application/json").body(body))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl Response {
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// The first value of the header, by its case-insensitive name.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.body
+    }
+
+    pub fn text(&self) -> Result<&str, Error> {
+        std::str::from_utf8(&self.body).map_err(|err| Error::new(format!("Invalid UTF-8 response body: {err}")))
+    }
+
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        serde_json::from_slice(&self.body).map_err(|err| Error::new(format!("Invalid JSON response body: {err}")))
+    }
+}
+
+/// Sends the request through the gateway. Only fails if no response was received, error
+/// statuses must be checked with [`Response::status`].
+#[cfg(target_arch = "wasm32")]
+pub fn fetch(request: Request) -> Result<Response, Error> {
+    use crate::component::bindings::{self, Header, HttpRequest};
+
+    let response = bindings::fetch(&HttpRequest {
+        method: request.method,
+        url: request.url,
+        headers: request
+            .headers
+            .into_iter()
+            .map(|(name, value)| Header { name, value })
+            .collect(),
+        body: request.body,
+    })
+    .map_err(|err| Error::new(format!("Request failed: {err}")))?;
+
+    Ok(Response {
+        status: response.status,
+        headers: response
+            .headers
+            .into_iter()
+            .map(|Header { name, value }| (name, value))
+            .collect(),
+        body: response.body,
+    })
+}
+
+/// Requests can only be sent from within the gateway.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn fetch(request: Request) -> Result<Response, Error> {
+    Err(Error::new(format!(
+        "Could not send a request to {}, resolvers must be compiled to WebAssembly",
+        request.url
+    )))
+}
@@ -0,0 +1,159 @@
+//! Custom resolvers written in Rust, run by the gateway as WebAssembly components without
+//! Node.js.
+//!
+//! A resolver is a function taking the [`Input`] of the resolved field and returning its value,
+//! exported with the [`export!`] macro:
+//!
+//! ```no_run
+//! use grafbase_resolver::{Error, Input, Value};
+//!
+//! #[derive(serde::Deserialize)]
+//! struct Args {
+//!     name: String,
+//! }
+//!
+//! fn hello(input: Input) -> Result<Value, Error> {
+//!     let Args { name } = input.args()?;
+//!     Ok(Value::String(format!("Hello, {name}!")))
+//! }
+//!
+//! grafbase_resolver::export!(hello);
+//! ```
+//!
+//! The crate must be a `cdylib` built for `wasm32-wasi` and turned into a component with
+//! `wasm-tools component new` and the WASI reactor adapter. The component is picked up by the CLI
+//! when written to `grafbase/resolvers/<name>.wasm`, taking precedence over a JavaScript resolver
+//! of the same name.
+
+mod error;
+mod fetch;
+
+use std::collections::BTreeMap;
+
+use serde::de::DeserializeOwned;
+pub use serde_json::Value;
+
+pub use crate::{
+    error::Error,
+    fetch::{fetch, Request, Response},
+};
+
+/// Everything a resolver receives about the field being resolved.
+#[derive(Debug, Clone, Default)]
+pub struct Input {
+    args: Value,
+    parent: Option<Value>,
+    context: Value,
+    headers: BTreeMap<String, String>,
+    info: Option<Value>,
+}
+
+impl Input {
+    /// The arguments of the field.
+    pub fn args<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        serde_json::from_value(self.args.clone()).map_err(|err| Error::new(format!("Invalid arguments: {err}")))
+    }
+
+    /// The resolved parent object, `None` for root fields. Batched resolvers receive the list of
+    /// all the parents and return a list with a result for each of them.
+    pub fn parent<T: DeserializeOwned>(&self) -> Result<Option<T>, Error> {
+        self.parent
+            .clone()
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(|err| Error::new(format!("Invalid parent: {err}")))
+    }
+
+    /// The context of the request, the same object JavaScript resolvers receive.
+    pub fn context(&self) -> &Value {
+        &self.context
+    }
+
+    /// A header of the request sent to the gateway, by its lowercase name.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(String::as_str)
+    }
+
+    pub fn headers(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.headers.iter().map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    /// The field name, path and variables of the resolved field.
+    pub fn info(&self) -> Option<&Value> {
+        self.info.as_ref()
+    }
+}
+
+/// Exports a `fn(Input) -> Result<Value, Error>` as the resolver of the component. Must be used
+/// exactly once per component.
+#[macro_export]
+macro_rules! export {
+    ($resolver:path) => {
+        #[doc(hidden)]
+        #[no_mangle]
+        pub fn __grafbase_resolver(input: $crate::Input) -> ::core::result::Result<$crate::Value, $crate::Error> {
+            $resolver(input)
+        }
+    };
+}
+
+/// The glue between the component interface and the exported resolver, only generated when
+/// compiling to WebAssembly.
+#[cfg(target_arch = "wasm32")]
+mod component {
+    pub(crate) mod bindings {
+        wit_bindgen::generate!({
+            path: "wit",
+            world: "resolver",
+            exports: {
+                world: super::Component,
+            },
+        });
+    }
+
+    use bindings::{GraphqlError, ResolverError, ResolverInput};
+
+    use crate::{Error, Input, Value};
+
+    extern "Rust" {
+        // Defined by the `export!` macro.
+        fn __grafbase_resolver(input: Input) -> Result<Value, Error>;
+    }
+
+    pub struct Component;
+
+    impl bindings::Guest for Component {
+        fn resolve(input: ResolverInput) -> Result<String, ResolverError> {
+            let input = Input {
+                args: parse_json(&input.args)?,
+                parent: input.parent.as_deref().map(parse_json).transpose()?,
+                context: parse_json(&input.context)?,
+                headers: input
+                    .headers
+                    .into_iter()
+                    .map(|header| (header.name, header.value))
+                    .collect(),
+                info: input.info.as_deref().map(parse_json).transpose()?,
+            };
+            // SAFETY: the symbol is defined by `export!` with this exact signature.
+            let value = unsafe { __grafbase_resolver(input) }.map_err(ResolverError::from)?;
+            serde_json::to_string(&value).map_err(|err| ResolverError::Message(err.to_string()))
+        }
+    }
+
+    fn parse_json(json: &str) -> Result<Value, ResolverError> {
+        serde_json::from_str(json).map_err(|err| ResolverError::Message(format!("Invalid input: {err}")))
+    }
+
+    impl From<Error> for ResolverError {
+        fn from(error: Error) -> Self {
+            match error {
+                Error::Message(message) => ResolverError::Message(message),
+                Error::Graphql { message, extensions } => ResolverError::Graphql(GraphqlError {
+                    message,
+                    extensions: extensions.map(|extensions| Value::Object(extensions).to_string()),
+                }),
+            }
+        }
+    }
+}
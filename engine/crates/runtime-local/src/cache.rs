@@ -1,11 +1,29 @@
 use std::{
-    collections::{BinaryHeap, HashMap, HashSet},
+    collections::{BTreeMap, BinaryHeap, HashMap, HashSet},
     sync::Arc,
     time::Instant,
 };
 
 use futures_util::lock::Mutex;
-use runtime::cache::{Cacheable, Entry, EntryState, Result};
+use runtime::cache::{CacheStats, Cacheable, Entry, EntryState, Result};
+
+/// Bounds of an [`InMemoryCache`], the least recently used entries are evicted first when
+/// either is exceeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InMemoryCacheCapacity {
+    pub max_entries: usize,
+    /// Approximated with the size of the keys and serialized values.
+    pub max_bytes: usize,
+}
+
+impl Default for InMemoryCacheCapacity {
+    fn default() -> Self {
+        Self {
+            max_entries: 10_000,
+            max_bytes: 128 * 1024 * 1024,
+        }
+    }
+}
 
 pub struct InMemoryCache<T> {
     inner: Mutex<CacheInner<T>>,
@@ -16,14 +34,30 @@ impl<T> InMemoryCache<T> {
         Self::default()
     }
 
+    pub fn with_capacity(capacity: InMemoryCacheCapacity) -> Self {
+        Self::with_capacity_and_time(capacity, Instant::now)
+    }
+
     #[cfg(test)]
     pub fn new_with_time(now: impl Fn() -> Instant + Sync + Send + 'static) -> Self {
+        Self::with_capacity_and_time(InMemoryCacheCapacity::default(), now)
+    }
+
+    fn with_capacity_and_time(
+        capacity: InMemoryCacheCapacity,
+        now: impl Fn() -> Instant + Sync + Send + 'static,
+    ) -> Self {
         InMemoryCache {
             inner: Mutex::new(CacheInner {
                 now: Box::new(now),
+                capacity,
                 key_to_entry: HashMap::new(),
                 deletion_tasks: BinaryHeap::new(),
                 tag_to_keys: HashMap::new(),
+                recently_used: BTreeMap::new(),
+                clock: 0,
+                size_bytes: 0,
+                stats: CacheStats::default(),
             }),
         }
     }
@@ -31,29 +65,32 @@ impl<T> InMemoryCache<T> {
 
 impl<T> Default for InMemoryCache<T> {
     fn default() -> Self {
-        InMemoryCache {
-            inner: Mutex::new(CacheInner {
-                now: Box::new(Instant::now),
-                key_to_entry: HashMap::new(),
-                deletion_tasks: BinaryHeap::new(),
-                tag_to_keys: HashMap::new(),
-            }),
-        }
+        Self::with_capacity(InMemoryCacheCapacity::default())
     }
 }
 
 struct CacheInner<T> {
     // for testing
     now: Box<dyn Fn() -> Instant + Sync + Send>,
+    capacity: InMemoryCacheCapacity,
     key_to_entry: HashMap<String, CacheEntry<T>>,
     deletion_tasks: BinaryHeap<DeletionTask>,
     tag_to_keys: HashMap<String, HashSet<String>>,
+    /// Keys by their last access, the least recently used first.
+    recently_used: BTreeMap<u64, String>,
+    clock: u64,
+    size_bytes: usize,
+    stats: CacheStats,
 }
 
 struct CacheEntry<T> {
     state: EntryState,
     value: Arc<T>,
     max_age_at: Instant,
+    to_delete_at: Instant,
+    tags: Vec<String>,
+    size_bytes: usize,
+    last_access: u64,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -79,22 +116,95 @@ impl Ord for DeletionTask {
 
 impl<T> CacheInner<T> {
     fn purge(&mut self, now: Instant) {
-        let mut deleted = vec![];
-        while let Some(DeletionTask { key, to_delete_at }) = self.deletion_tasks.peek() {
-            if to_delete_at <= &now {
-                self.key_to_entry.remove(key);
-                let DeletionTask { key, .. } = self.deletion_tasks.pop().unwrap();
-                deleted.push(key);
-            } else {
+        while let Some(DeletionTask { to_delete_at, .. }) = self.deletion_tasks.peek() {
+            if to_delete_at > &now {
                 break;
             }
+            let DeletionTask { key, to_delete_at } = self.deletion_tasks.pop().unwrap();
+            // The key may have been put again since, with a later deletion.
+            if self
+                .key_to_entry
+                .get(&key)
+                .is_some_and(|entry| entry.to_delete_at == to_delete_at)
+            {
+                self.remove(&key);
+            }
+        }
+        // Tasks of removed or replaced entries are otherwise only dropped once due.
+        if self.deletion_tasks.len() > 2 * self.key_to_entry.len() + 1024 {
+            let key_to_entry = &self.key_to_entry;
+            self.deletion_tasks.retain(|task| {
+                key_to_entry
+                    .get(&task.key)
+                    .is_some_and(|entry| entry.to_delete_at == task.to_delete_at)
+            });
+        }
+    }
+
+    fn touch(&mut self, key: &str) {
+        self.clock += 1;
+        let clock = self.clock;
+        if let Some(entry) = self.key_to_entry.get_mut(key) {
+            let key = self
+                .recently_used
+                .remove(&entry.last_access)
+                .unwrap_or_else(|| key.to_string());
+            entry.last_access = clock;
+            self.recently_used.insert(clock, key);
+        }
+    }
+
+    fn insert(&mut self, key: String, mut entry: CacheEntry<T>) {
+        self.remove(&key);
+        self.clock += 1;
+        entry.last_access = self.clock;
+        self.size_bytes += entry.size_bytes;
+        for tag in &entry.tags {
+            self.tag_to_keys.entry(tag.clone()).or_default().insert(key.clone());
+        }
+        self.deletion_tasks.push(DeletionTask {
+            key: key.clone(),
+            to_delete_at: entry.to_delete_at,
+        });
+        self.recently_used.insert(entry.last_access, key.clone());
+        self.key_to_entry.insert(key, entry);
+        self.evict();
+    }
+
+    fn remove(&mut self, key: &str) -> Option<CacheEntry<T>> {
+        let entry = self.key_to_entry.remove(key)?;
+        self.recently_used.remove(&entry.last_access);
+        self.size_bytes -= entry.size_bytes;
+        for tag in &entry.tags {
+            if let Some(keys) = self.tag_to_keys.get_mut(tag) {
+                keys.remove(key);
+                if keys.is_empty() {
+                    self.tag_to_keys.remove(tag);
+                }
+            }
         }
-        for tagged in self.tag_to_keys.values_mut() {
-            for key in &deleted {
-                tagged.remove(key);
+        Some(entry)
+    }
+
+    /// Removes the least recently used entries until the cache fits within its capacity.
+    fn evict(&mut self) {
+        while self.key_to_entry.len() > self.capacity.max_entries || self.size_bytes > self.capacity.max_bytes {
+            let Some((_, key)) = self.recently_used.pop_first() else {
+                break;
+            };
+            if self.remove(&key).is_some() {
+                self.stats.evictions += 1;
             }
         }
     }
+
+    fn clear(&mut self) {
+        self.key_to_entry.clear();
+        self.deletion_tasks.clear();
+        self.tag_to_keys.clear();
+        self.recently_used.clear();
+        self.size_bytes = 0;
+    }
 }
 
 #[async_trait::async_trait]
@@ -105,7 +215,7 @@ impl<T: Clone + Cacheable + 'static> runtime::cache::Cache for InMemoryCache<T>
         let mut inner = self.inner.lock().await;
         let now = (inner.now)();
         inner.purge(now);
-        Ok(inner
+        let entry = inner
             .key_to_entry
             .get(key)
             .map(|entry| {
@@ -119,31 +229,43 @@ impl<T: Clone + Cacheable + 'static> runtime::cache::Cache for InMemoryCache<T>
                     }
                 }
             })
-            .unwrap_or(Entry::Miss))
+            .unwrap_or(Entry::Miss);
+        if matches!(entry, Entry::Miss) {
+            inner.stats.misses += 1;
+        } else {
+            inner.stats.hits += 1;
+            inner.touch(key);
+        }
+        Ok(entry)
     }
 
     async fn put(&self, key: &str, state: EntryState, value: Arc<Self::Value>, tags: Vec<String>) -> Result<()> {
+        let size_bytes = key.len()
+            + serde_json::to_vec(value.as_ref())
+                .map(|bytes| bytes.len())
+                .unwrap_or_default();
         let mut inner = self.inner.lock().await;
         let now = (inner.now)();
         inner.purge(now);
-        let key = key.to_string();
-        inner.key_to_entry.insert(
-            key.clone(),
+        if size_bytes > inner.capacity.max_bytes {
+            // Would evict everything else and itself.
+            inner.remove(key);
+            return Ok(());
+        }
+        inner.insert(
+            key.to_string(),
             CacheEntry {
                 state,
                 value: Arc::clone(&value),
                 max_age_at: now.checked_add(value.max_age()).unwrap(),
+                to_delete_at: now
+                    .checked_add(value.max_age() + value.stale_while_revalidate())
+                    .unwrap(),
+                tags,
+                size_bytes,
+                last_access: 0,
             },
         );
-        for tag in tags {
-            inner.tag_to_keys.entry(tag).or_default().insert(key.clone());
-        }
-        inner.deletion_tasks.push(DeletionTask {
-            key,
-            to_delete_at: now
-                .checked_add(value.max_age() + value.stale_while_revalidate())
-                .unwrap(),
-        });
         Ok(())
     }
 
@@ -151,10 +273,7 @@ impl<T: Clone + Cacheable + 'static> runtime::cache::Cache for InMemoryCache<T>
         let mut inner = self.inner.lock().await;
         let now = (inner.now)();
         inner.purge(now);
-        inner.key_to_entry.remove(key);
-        for tagged in inner.tag_to_keys.values_mut() {
-            tagged.remove(key);
-        }
+        inner.remove(key);
         Ok(())
     }
 
@@ -167,7 +286,7 @@ impl<T: Clone + Cacheable + 'static> runtime::cache::Cache for InMemoryCache<T>
             acc
         });
         for key in keys {
-            inner.key_to_entry.remove(&key);
+            inner.remove(&key);
         }
         Ok(())
     }
@@ -175,11 +294,18 @@ impl<T: Clone + Cacheable + 'static> runtime::cache::Cache for InMemoryCache<T>
     // in local there is only one host, the cli itself.
     async fn purge_by_hostname(&self, _hostname: String) -> Result<()> {
         let mut inner = self.inner.lock().await;
-        inner.key_to_entry.clear();
-        inner.deletion_tasks.clear();
-        inner.tag_to_keys.clear();
+        inner.clear();
         Ok(())
     }
+
+    async fn stats(&self) -> Result<Option<CacheStats>> {
+        let inner = self.inner.lock().await;
+        Ok(Some(CacheStats {
+            entries: inner.key_to_entry.len() as u64,
+            size_bytes: inner.size_bytes as u64,
+            ..inner.stats
+        }))
+    }
 }
 
 #[cfg(test)]
@@ -192,9 +318,9 @@ mod tests {
         time::{Duration, Instant},
     };
 
-    use runtime::cache::{Cache, Cacheable, Entry, EntryState};
+    use runtime::cache::{Cache, CacheStats, Cacheable, Entry, EntryState};
 
-    use super::InMemoryCache;
+    use super::{InMemoryCache, InMemoryCacheCapacity};
 
     #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
     struct Dummy {
@@ -328,4 +454,119 @@ mod tests {
         assert_eq!(cache.get("Great Dane").await.unwrap(), Entry::Miss);
         assert_eq!(cache.get("Saint Bernard").await.unwrap(), Entry::Miss);
     }
+
+    #[tokio::test]
+    async fn least_recently_used_eviction() {
+        let cache = InMemoryCache::<Dummy>::with_capacity(InMemoryCacheCapacity {
+            max_entries: 2,
+            ..Default::default()
+        });
+        let put = |key: &'static str, tags: &'static [&'static str]| async {
+            cache
+                .put(
+                    key,
+                    EntryState::Fresh,
+                    Arc::new(Dummy::new(key.to_string(), 10, 20)),
+                    tags.iter().map(ToString::to_string).collect(),
+                )
+                .await
+                .unwrap();
+        };
+        put("Great Dane", &["large"]).await;
+        put("Basset Hound", &["small"]).await;
+        // Great Dane becomes the most recently used.
+        assert_eq!(
+            cache.get("Great Dane").await.unwrap(),
+            Entry::Hit(Dummy::new("Great Dane", 10, 20))
+        );
+        put("Saint Bernard", &["large"]).await;
+
+        assert_eq!(cache.get("Basset Hound").await.unwrap(), Entry::Miss);
+        assert_eq!(
+            cache.get("Saint Bernard").await.unwrap(),
+            Entry::Hit(Dummy::new("Saint Bernard", 10, 20))
+        );
+        assert_eq!(
+            cache.get("Great Dane").await.unwrap(),
+            Entry::Hit(Dummy::new("Great Dane", 10, 20))
+        );
+
+        // Tags of evicted entries are dropped.
+        assert!(!cache.inner.lock().await.tag_to_keys.contains_key("small"));
+        cache.purge_by_tags(vec!["large".to_string()]).await.unwrap();
+        assert_eq!(cache.get("Great Dane").await.unwrap(), Entry::Miss);
+
+        let stats = cache.stats().await.unwrap().unwrap();
+        assert_eq!(
+            stats,
+            CacheStats {
+                hits: 3,
+                misses: 2,
+                evictions: 1,
+                entries: 0,
+                size_bytes: 0,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn size_eviction() {
+        let value_size = serde_json::to_vec(&Dummy::new("0", 10, 20)).unwrap().len() + 1;
+        let cache = InMemoryCache::<Dummy>::with_capacity(InMemoryCacheCapacity {
+            max_entries: 100,
+            max_bytes: value_size * 3,
+        });
+        for key in ["1", "2", "3", "4"] {
+            cache
+                .put(key, EntryState::Fresh, Arc::new(Dummy::new("0", 10, 20)), vec![])
+                .await
+                .unwrap();
+        }
+        assert_eq!(cache.get("1").await.unwrap(), Entry::Miss);
+        assert_eq!(cache.get("4").await.unwrap(), Entry::Hit(Dummy::new("0", 10, 20)));
+        let stats = cache.stats().await.unwrap().unwrap();
+        assert_eq!(
+            (stats.entries, stats.size_bytes, stats.evictions),
+            (3, value_size as u64 * 3, 1)
+        );
+
+        // Too big to be cached at all
+        cache
+            .put(
+                "big",
+                EntryState::Fresh,
+                Arc::new(Dummy::new("0".repeat(value_size * 3), 10, 20)),
+                vec![],
+            )
+            .await
+            .unwrap();
+        assert_eq!(cache.get("big").await.unwrap(), Entry::Miss);
+        assert_eq!(cache.stats().await.unwrap().unwrap().entries, 3);
+    }
+
+    #[tokio::test]
+    async fn put_again_postpones_deletion() {
+        let offset: &'static AtomicU64 = Box::leak(Box::new(AtomicU64::new(0)));
+        let cache = InMemoryCache::<Dummy>::new_with_time({
+            let start = Instant::now();
+            move || start.checked_add(Duration::from_secs(offset.load(Relaxed))).unwrap()
+        });
+        let put = || cache.put("test", EntryState::Fresh, Arc::new(Dummy::new("test", 10, 20)), vec![]);
+
+        put().await.unwrap();
+        offset.store(20, Relaxed);
+        put().await.unwrap();
+        // The first deletion is due, but the entry was replaced since.
+        offset.store(35, Relaxed);
+        assert_eq!(
+            cache.get("test").await.unwrap(),
+            Entry::Stale {
+                response: Dummy::new("test", 10, 20),
+                state: EntryState::Fresh,
+                is_early_stale: false
+            }
+        );
+        offset.store(51, Relaxed);
+        assert_eq!(cache.get("test").await.unwrap(), Entry::Miss);
+    }
 }
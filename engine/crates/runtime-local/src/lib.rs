@@ -3,13 +3,19 @@ mod cache;
 mod fetch;
 mod log;
 mod pg;
+mod rate_limiting;
+#[cfg(not(target_arch = "wasm32"))]
+mod redis;
 pub mod search;
 mod ufd_invoker;
 
 pub use bridge::Bridge;
-pub use cache::InMemoryCache;
+pub use cache::{InMemoryCache, InMemoryCacheCapacity};
 pub use fetch::NativeFetcher;
 pub use pg::LocalPgTransportFactory;
+pub use rate_limiting::InMemoryRateLimitStore;
+#[cfg(not(target_arch = "wasm32"))]
+pub use redis::{RedisCache, RedisKv};
 pub use search::LocalSearchEngine;
 pub use ufd_invoker::UdfInvokerImpl;
 
@@ -0,0 +1,373 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use futures_util::lock::Mutex;
+use runtime::rate_limiting::{RateLimitCounters, RateLimitStore, RateLimitStoreError};
+
+/// How often the expired counters are dropped.
+const PURGE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Rate limit counters of a single gateway instance.
+pub struct InMemoryRateLimitStore {
+    inner: Mutex<StoreInner>,
+}
+
+struct StoreInner {
+    counters: HashMap<String, (RateLimitCounters, Instant)>,
+    next_purge: Instant,
+}
+
+impl InMemoryRateLimitStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for InMemoryRateLimitStore {
+    fn default() -> Self {
+        InMemoryRateLimitStore {
+            inner: Mutex::new(StoreInner {
+                counters: HashMap::new(),
+                next_purge: Instant::now() + PURGE_INTERVAL,
+            }),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl RateLimitStore for InMemoryRateLimitStore {
+    async fn increment(
+        &self,
+        key: &str,
+        ttl: Duration,
+        increment: RateLimitCounters,
+    ) -> Result<RateLimitCounters, RateLimitStoreError> {
+        let now = Instant::now();
+        let mut inner = self.inner.lock().await;
+        if inner.next_purge <= now {
+            inner.counters.retain(|_, (_, expires_at)| *expires_at > now);
+            inner.next_purge = now + PURGE_INTERVAL;
+        }
+
+        let (counters, expires_at) = inner
+            .counters
+            .entry(key.to_string())
+            .or_insert_with(|| (RateLimitCounters::default(), now + ttl));
+        if *expires_at <= now {
+            *counters = RateLimitCounters::default();
+            *expires_at = now + ttl;
+        }
+        counters.requests = counters.requests.saturating_add(increment.requests);
+        counters.cost = counters.cost.saturating_add(increment.cost);
+        Ok(*counters)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        net::IpAddr,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use runtime::{
+        kv::{KvGet, KvPut, KvStore},
+        rate_limiting::{
+            KvRateLimitStore, RateLimitCounters, RateLimitKey, RateLimitRequest, RateLimitRule, RateLimitStore,
+            RateLimiter,
+        },
+    };
+    use serde::{de::DeserializeOwned, Serialize};
+
+    use super::InMemoryRateLimitStore;
+
+    #[derive(Default)]
+    struct Request {
+        headers: HashMap<&'static str, &'static str>,
+        operation_name: Option<&'static str>,
+        client_ip: Option<IpAddr>,
+    }
+
+    impl RateLimitRequest for Request {
+        fn header(&self, name: &str) -> Option<&str> {
+            self.headers.get(name).copied()
+        }
+
+        fn jwt_claim(&self, _name: &str) -> Option<String> {
+            None
+        }
+
+        fn operation_name(&self) -> Option<&str> {
+            self.operation_name
+        }
+
+        fn client_ip(&self) -> Option<IpAddr> {
+            self.client_ip
+        }
+    }
+
+    /// Values put in the store, kept as JSON along their expiration TTL.
+    type FakeKvValues = HashMap<String, (serde_json::Value, Option<Duration>)>;
+
+    #[derive(Clone, Default)]
+    struct FakeKv {
+        values: Arc<Mutex<FakeKvValues>>,
+    }
+
+    impl FakeKv {
+        fn ttl(&self, name: &str) -> Option<Duration> {
+            self.values.lock().unwrap().get(name).and_then(|(_, ttl)| *ttl)
+        }
+    }
+
+    struct FakeKvGet {
+        value: Option<serde_json::Value>,
+    }
+
+    struct FakeKvPut {
+        kv: FakeKv,
+        name: String,
+        value: serde_json::Value,
+        expiration_ttl: Option<Duration>,
+    }
+
+    impl KvStore for FakeKv {
+        type Error = serde_json::Error;
+        type Get = FakeKvGet;
+        type Put = FakeKvPut;
+
+        fn get(&self, name: &str) -> Self::Get {
+            FakeKvGet {
+                value: self.values.lock().unwrap().get(name).map(|(value, _)| value.clone()),
+            }
+        }
+
+        fn put<T: Serialize>(&self, name: &str, value: T) -> Result<Self::Put, Self::Error> {
+            Ok(FakeKvPut {
+                kv: self.clone(),
+                name: name.to_string(),
+                value: serde_json::to_value(value)?,
+                expiration_ttl: None,
+            })
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl KvGet for FakeKvGet {
+        type Error = serde_json::Error;
+
+        fn cache_ttl(self, _cache_ttl: Duration) -> Self {
+            self
+        }
+
+        async fn json<T: DeserializeOwned>(self) -> Result<Option<T>, Self::Error> {
+            self.value.map(serde_json::from_value).transpose()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl KvPut for FakeKvPut {
+        type Error = serde_json::Error;
+
+        fn expiration_ttl(self, expiration_ttl: Duration) -> Self {
+            FakeKvPut {
+                expiration_ttl: Some(expiration_ttl),
+                ..self
+            }
+        }
+
+        async fn execute(self) -> Result<(), Self::Error> {
+            self.kv
+                .values
+                .lock()
+                .unwrap()
+                .insert(self.name, (self.value, self.expiration_ttl));
+            Ok(())
+        }
+    }
+
+    fn rule(name: &str, key: RateLimitKey, requests: u64) -> RateLimitRule {
+        RateLimitRule {
+            name: name.to_string(),
+            key,
+            requests,
+            cost: None,
+            // Long enough for the tests not to cross a window.
+            duration: Duration::from_secs(3600),
+            source: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_store_increments_until_expiration() {
+        let store = InMemoryRateLimitStore::new();
+        let increment = RateLimitCounters { requests: 1, cost: 3 };
+
+        let counters = store.increment("a", Duration::from_secs(60), increment).await.unwrap();
+        assert_eq!(counters, RateLimitCounters { requests: 1, cost: 3 });
+        let counters = store.increment("a", Duration::from_secs(60), increment).await.unwrap();
+        assert_eq!(counters, RateLimitCounters { requests: 2, cost: 6 });
+
+        store.increment("b", Duration::ZERO, increment).await.unwrap();
+        let counters = store.increment("b", Duration::ZERO, increment).await.unwrap();
+        assert_eq!(counters, RateLimitCounters { requests: 1, cost: 3 });
+    }
+
+    #[tokio::test]
+    async fn test_requests_limit_by_header() {
+        let limiter = RateLimiter::new(
+            vec![rule("per-key", RateLimitKey::Header("x-api-key".to_string()), 2)],
+            Arc::new(InMemoryRateLimitStore::new()),
+        );
+        let alice = Request {
+            headers: HashMap::from([("x-api-key", "alice")]),
+            ..Default::default()
+        };
+        let bob = Request {
+            headers: HashMap::from([("x-api-key", "bob")]),
+            ..Default::default()
+        };
+
+        assert!(limiter.check(None, &alice, 0).await.is_ok());
+        assert!(limiter.check(None, &alice, 0).await.is_ok());
+        let rejection = limiter.check(None, &alice, 0).await.unwrap_err();
+        assert_eq!(rejection.rule, "per-key");
+        assert!(rejection.retry_after <= Duration::from_secs(3600));
+        assert!((1..=3600).contains(&rejection.retry_after_secs()));
+
+        assert!(limiter.check(None, &bob, 0).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_cost_limit() {
+        let limiter = RateLimiter::new(
+            vec![RateLimitRule {
+                cost: Some(10),
+                ..rule("cost", RateLimitKey::OperationName, 100)
+            }],
+            Arc::new(InMemoryRateLimitStore::new()),
+        );
+        let request = Request {
+            operation_name: Some("products"),
+            ..Default::default()
+        };
+
+        assert!(limiter.limits_cost());
+        assert!(limiter.check(None, &request, 6).await.is_ok());
+        assert!(limiter.check(None, &request, 4).await.is_ok());
+        assert!(limiter.check(None, &request, 1).await.is_err());
+        assert!(limiter.check(None, &Request::default(), 1).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_kv_store_increments() {
+        let kv = FakeKv::default();
+        let store = KvRateLimitStore::new(kv.clone());
+        let increment = RateLimitCounters { requests: 1, cost: 3 };
+
+        let counters = store
+            .increment("a", Duration::from_secs(3600), increment)
+            .await
+            .unwrap();
+        assert_eq!(counters, RateLimitCounters { requests: 1, cost: 3 });
+        let counters = store
+            .increment("a", Duration::from_secs(3600), increment)
+            .await
+            .unwrap();
+        assert_eq!(counters, RateLimitCounters { requests: 2, cost: 6 });
+        assert_eq!(kv.ttl("a"), Some(Duration::from_secs(3600)));
+
+        // Shorter windows are kept as long as the store allows.
+        store.increment("b", Duration::from_secs(1), increment).await.unwrap();
+        assert_eq!(kv.ttl("b"), Some(Duration::from_secs(60)));
+
+        let limiter = RateLimiter::new(
+            vec![rule("per-key", RateLimitKey::Header("x-api-key".to_string()), 1)],
+            Arc::new(store),
+        );
+        let alice = Request {
+            headers: HashMap::from([("x-api-key", "alice")]),
+            ..Default::default()
+        };
+        assert!(limiter.check(None, &alice, 0).await.is_ok());
+        assert!(limiter.check(None, &alice, 0).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_client_ip_and_sources() {
+        let limiter = RateLimiter::new(
+            vec![RateLimitRule {
+                source: Some("products".to_string()),
+                ..rule("products", RateLimitKey::ClientIp, 1)
+            }],
+            Arc::new(InMemoryRateLimitStore::new()),
+        );
+        // The forwarded header isn't trusted without a rule asking for it.
+        let first = Request {
+            headers: HashMap::from([("x-forwarded-for", "10.0.0.2")]),
+            client_ip: Some([10, 0, 0, 1].into()),
+            ..Default::default()
+        };
+        let second = Request {
+            headers: HashMap::from([("x-forwarded-for", "10.0.0.1")]),
+            client_ip: Some([10, 0, 0, 2].into()),
+            ..Default::default()
+        };
+
+        assert!(!limiter.limits_cost());
+        for _ in 0..3 {
+            assert!(limiter.check(None, &first, 0).await.is_ok());
+            assert!(limiter.check(Some("reviews"), &first, 0).await.is_ok());
+        }
+        assert!(limiter.check(Some("products"), &first, 0).await.is_ok());
+        assert!(limiter.check(Some("products"), &first, 0).await.is_err());
+        assert!(limiter.check(Some("products"), &second, 0).await.is_ok());
+
+        // Requests without a known address aren't limited by the rule.
+        for _ in 0..3 {
+            assert!(limiter.check(Some("products"), &Request::default(), 0).await.is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_client_ip_header() {
+        let limiter = RateLimiter::new(
+            vec![rule(
+                "per-forwarded-ip",
+                RateLimitKey::ClientIpHeader("x-forwarded-for".to_string()),
+                1,
+            )],
+            Arc::new(InMemoryRateLimitStore::new()),
+        );
+        // The last address is the one the trusted proxy saw, the others are set by the client.
+        let first = Request {
+            headers: HashMap::from([("x-forwarded-for", "10.0.0.9, 10.0.0.1")]),
+            client_ip: Some([192, 168, 0, 1].into()),
+            ..Default::default()
+        };
+        let spoofed = Request {
+            headers: HashMap::from([("x-forwarded-for", "10.0.0.8, 10.0.0.1")]),
+            client_ip: Some([192, 168, 0, 1].into()),
+            ..Default::default()
+        };
+        let second = Request {
+            headers: HashMap::from([("x-forwarded-for", "10.0.0.2")]),
+            client_ip: Some([192, 168, 0, 1].into()),
+            ..Default::default()
+        };
+        let direct = Request {
+            client_ip: Some([10, 0, 0, 3].into()),
+            ..Default::default()
+        };
+
+        assert!(limiter.check(None, &first, 0).await.is_ok());
+        assert!(limiter.check(None, &spoofed, 0).await.is_err());
+        assert!(limiter.check(None, &second, 0).await.is_ok());
+        assert!(limiter.check(None, &direct, 0).await.is_ok());
+        assert!(limiter.check(None, &direct, 0).await.is_err());
+    }
+}
@@ -22,6 +22,9 @@ impl SearchEngineInner for LocalSearchEngine {
             query,
             pagination,
             index,
+            facets,
+            highlight,
+            sort,
         } = request;
         let request = QueryRequest {
             query: Query::try_from(query)?,
@@ -32,6 +35,9 @@ impl SearchEngineInner for LocalSearchEngine {
             response_parameters: QueryResponseParameters {
                 version: QueryResponseDiscriminants::V1,
             },
+            facets,
+            highlight,
+            sort,
         };
         self.bridge
             .request::<QueryRequest, QueryResponse>("search", request)
@@ -0,0 +1,438 @@
+//! Custom resolvers compiled to WebAssembly components with the `grafbase-resolver` SDK, run
+//! in-process rather than in JavaScript workers. Every invocation gets a fresh instance of the
+//! component, with the environment variables of the project and its stderr inherited.
+
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use runtime::udf::{CustomResolverError, CustomResolverRequestPayload, CustomResolverResponse, UdfInvoker, UdfRequest};
+use wasmtime::{
+    component::{Component, Linker},
+    Config, Engine, Store,
+};
+use wasmtime_wasi::preview2::{Table, WasiCtx, WasiCtxBuilder, WasiView};
+
+/// How long a resolver may run, including the time spent waiting on its `fetch` calls.
+const RESOLVER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How often a running resolver yields to the other tasks: the epoch of the engine is incremented
+/// at this interval, and resolvers yield whenever it changes.
+const EPOCH_TICK: Duration = Duration::from_millis(10);
+
+/// How long a single `fetch` call of a resolver may take.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+wasmtime::component::bindgen!({
+    path: "../resolver-sdk/wit",
+    world: "resolver",
+    async: true,
+});
+
+#[derive(Debug, thiserror::Error)]
+pub enum WasmResolverError {
+    #[error("could not initialize the WebAssembly runtime: {0:#}")]
+    Runtime(wasmtime::Error),
+    #[error("could not load the resolver '{name}': {error:#}")]
+    Load { name: String, error: wasmtime::Error },
+    #[error("could not initialize the HTTP client of resolvers: {0}")]
+    HttpClient(reqwest::Error),
+}
+
+/// The resolvers compiled to WebAssembly, by name.
+pub struct WasmResolvers {
+    engine: Engine,
+    linker: Linker<ResolverState>,
+    components: HashMap<String, Component>,
+    environment_variables: Vec<(String, String)>,
+    http_client: reqwest::Client,
+    /// Kept until the resolvers are dropped.
+    _epoch_ticker: EpochTicker,
+    timeout: Duration,
+}
+
+impl WasmResolvers {
+    pub fn new(environment_variables: &HashMap<String, String>) -> Result<Self, WasmResolverError> {
+        let mut config = Config::new();
+        config
+            .wasm_component_model(true)
+            .async_support(true)
+            .epoch_interruption(true);
+        let engine = Engine::new(&config).map_err(WasmResolverError::Runtime)?;
+        let epoch_ticker = EpochTicker::start(engine.clone());
+
+        let mut linker = Linker::new(&engine);
+        wasmtime_wasi::preview2::command::add_to_linker(&mut linker).map_err(WasmResolverError::Runtime)?;
+        Resolver::add_to_linker(&mut linker, |state: &mut ResolverState| state).map_err(WasmResolverError::Runtime)?;
+
+        Ok(Self {
+            engine,
+            linker,
+            components: HashMap::new(),
+            environment_variables: environment_variables
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect(),
+            http_client: reqwest::Client::builder()
+                .timeout(FETCH_TIMEOUT)
+                .build()
+                .map_err(WasmResolverError::HttpClient)?,
+            _epoch_ticker: epoch_ticker,
+            timeout: RESOLVER_TIMEOUT,
+        })
+    }
+
+    /// Compiles the component at `path` as the resolver `name`.
+    pub fn load(&mut self, name: &str, path: &Path) -> Result<(), WasmResolverError> {
+        let component = Component::from_file(&self.engine, path).map_err(|error| WasmResolverError::Load {
+            name: name.to_string(),
+            error,
+        })?;
+        self.components.insert(name.to_string(), component);
+        Ok(())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.components.contains_key(name)
+    }
+
+    async fn resolve(
+        &self,
+        component: &Component,
+        payload: CustomResolverRequestPayload,
+    ) -> wasmtime::Result<CustomResolverResponse> {
+        let input = resolver_input(payload)?;
+        let state = ResolverState {
+            table: Table::new(),
+            wasi: WasiCtxBuilder::new()
+                .envs(self.environment_variables.as_slice())
+                .inherit_stderr()
+                .build(),
+            http_client: self.http_client.clone(),
+        };
+        let mut store = Store::new(&self.engine, state);
+        // Yields at every tick so a looping resolver doesn't hold the thread, and is dropped once
+        // the timeout is reached.
+        store.epoch_deadline_async_yield_and_update(1);
+        store.set_epoch_deadline(1);
+        let (resolver, _) = Resolver::instantiate_async(&mut store, component, &self.linker).await?;
+        let output = resolver.call_resolve(&mut store, &input).await?;
+        into_response(output)
+    }
+}
+
+/// Increments the epoch of the engine from a background thread, until dropped.
+struct EpochTicker {
+    stopped: Arc<AtomicBool>,
+}
+
+impl EpochTicker {
+    fn start(engine: Engine) -> Self {
+        let stopped = Arc::new(AtomicBool::new(false));
+        std::thread::spawn({
+            let stopped = Arc::clone(&stopped);
+            move || {
+                while !stopped.load(Ordering::Relaxed) {
+                    std::thread::sleep(EPOCH_TICK);
+                    engine.increment_epoch();
+                }
+            }
+        });
+        Self { stopped }
+    }
+}
+
+impl Drop for EpochTicker {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+}
+
+#[async_trait::async_trait]
+impl UdfInvoker<CustomResolverRequestPayload> for WasmResolvers {
+    async fn invoke(
+        &self,
+        ray_id: &str,
+        request: UdfRequest<'_, CustomResolverRequestPayload>,
+    ) -> Result<CustomResolverResponse, CustomResolverError>
+    where
+        CustomResolverRequestPayload: 'async_trait,
+    {
+        let Some(component) = self.components.get(request.name) else {
+            tracing::error!("{ray_id}: no WebAssembly resolver named '{}'", request.name);
+            return Err(CustomResolverError::InvocationError);
+        };
+        tokio::time::timeout(self.timeout, self.resolve(component, request.payload))
+            .await
+            .unwrap_or_else(|_| Err(wasmtime::Error::msg(format!("timed out after {:?}", self.timeout))))
+            .map_err(|error| {
+                tracing::error!("{ray_id}: resolver '{}' failed with: {error:#}", request.name);
+                CustomResolverError::InvocationError
+            })
+    }
+}
+
+fn resolver_input(payload: CustomResolverRequestPayload) -> serde_json::Result<ResolverInput> {
+    let CustomResolverRequestPayload {
+        arguments,
+        parent,
+        context,
+        info,
+    } = payload;
+    let headers = context
+        .request
+        .headers
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter_map(|(name, value)| {
+            Some(Header {
+                name: name.clone(),
+                value: value.as_str()?.to_string(),
+            })
+        })
+        .collect();
+
+    Ok(ResolverInput {
+        args: serde_json::to_string(&arguments)?,
+        parent: parent.map(|parent| parent.to_string()),
+        context: serde_json::to_string(&context)?,
+        headers,
+        info: info.map(|info| info.to_string()),
+    })
+}
+
+fn into_response(output: Result<String, ResolverError>) -> wasmtime::Result<CustomResolverResponse> {
+    Ok(match output {
+        Ok(value) => CustomResolverResponse::Success(serde_json::from_str(&value)?),
+        Err(ResolverError::Message(message)) => CustomResolverResponse::Error(message),
+        Err(ResolverError::Graphql(GraphqlError { message, extensions })) => CustomResolverResponse::GraphQLError {
+            message,
+            extensions: extensions.as_deref().map(serde_json::from_str).transpose()?,
+        },
+    })
+}
+
+struct ResolverState {
+    table: Table,
+    wasi: WasiCtx,
+    http_client: reqwest::Client,
+}
+
+impl WasiView for ResolverState {
+    fn table(&self) -> &Table {
+        &self.table
+    }
+
+    fn table_mut(&mut self) -> &mut Table {
+        &mut self.table
+    }
+
+    fn ctx(&self) -> &WasiCtx {
+        &self.wasi
+    }
+
+    fn ctx_mut(&mut self) -> &mut WasiCtx {
+        &mut self.wasi
+    }
+}
+
+#[async_trait::async_trait]
+impl ResolverImports for ResolverState {
+    async fn fetch(&mut self, request: HttpRequest) -> wasmtime::Result<Result<HttpResponse, String>> {
+        Ok(send(&self.http_client, request).await)
+    }
+}
+
+async fn send(client: &reqwest::Client, request: HttpRequest) -> Result<HttpResponse, String> {
+    let method = reqwest::Method::from_bytes(request.method.as_bytes()).map_err(|err| err.to_string())?;
+    let mut builder = client.request(method, request.url.as_str());
+    for Header { name, value } in request.headers {
+        builder = builder.header(name, value);
+    }
+    if let Some(body) = request.body {
+        builder = builder.body(body);
+    }
+
+    let response = builder.send().await.map_err(|err| err.to_string())?;
+    let status = response.status().as_u16();
+    let headers = response
+        .headers()
+        .iter()
+        .filter_map(|(name, value)| {
+            Some(Header {
+                name: name.to_string(),
+                value: value.to_str().ok()?.to_string(),
+            })
+        })
+        .collect();
+    let body = response.bytes().await.map_err(|err| err.to_string())?.to_vec();
+
+    Ok(HttpResponse { status, headers, body })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        time::{Duration, Instant},
+    };
+
+    use runtime::udf::{
+        CustomResolverError, CustomResolverRequestPayload, CustomResolverResponse, UdfInvoker, UdfKind, UdfRequest,
+        UdfRequestContext, UdfRequestContextRequest,
+    };
+    use serde_json::json;
+    use wasmtime::component::Component;
+
+    use super::{into_response, resolver_input, GraphqlError, Header, ResolverError, WasmResolvers};
+
+    /// A guest whose `resolve` runs `body`, which returns a pointer to the result written in its
+    /// memory. The string `"hello"` is at offset 16.
+    fn component(body: &str) -> String {
+        format!(
+            r#"
+            (component
+              (core module $m
+                (memory (export "memory") 1)
+                (global $heap (mut i32) (i32.const 1024))
+                (func (export "realloc") (param i32 i32 i32 i32) (result i32)
+                  (local $ptr i32)
+                  (local.set $ptr (global.get $heap))
+                  (global.set $heap (i32.add (global.get $heap) (i32.and (i32.add (local.get 3) (i32.const 7)) (i32.const -8))))
+                  (local.get $ptr))
+                (data (i32.const 16) "\"hello\"")
+                (func (export "resolve") (param i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32) (result i32)
+                  {body}))
+              (core instance $i (instantiate $m))
+              (type $header' (record (field "name" string) (field "value" string)))
+              (export $header "header" (type $header'))
+              (type $input' (record
+                (field "args" string)
+                (field "parent" (option string))
+                (field "context" string)
+                (field "headers" (list $header))
+                (field "info" (option string))))
+              (export $input "resolver-input" (type $input'))
+              (type $graphql-error' (record (field "message" string) (field "extensions" (option string))))
+              (export $graphql-error "graphql-error" (type $graphql-error'))
+              (type $resolver-error' (variant (case "message" string) (case "graphql" $graphql-error)))
+              (export $resolver-error "resolver-error" (type $resolver-error'))
+              (func (export "resolve") (param "input" $input) (result (result string (error $resolver-error)))
+                (canon lift (core func $i "resolve") (memory (core memory $i "memory")) (realloc (core func $i "realloc")))))
+            "#
+        )
+    }
+
+    async fn invoke(resolvers: &WasmResolvers, name: &str) -> Result<CustomResolverResponse, CustomResolverError> {
+        let payload = CustomResolverRequestPayload {
+            arguments: HashMap::new(),
+            parent: None,
+            context: UdfRequestContext {
+                request: UdfRequestContextRequest { headers: json!({}) },
+            },
+            info: None,
+        };
+        let request = UdfRequest {
+            request_id: "request",
+            name,
+            payload,
+            udf_kind: UdfKind::Resolver,
+        };
+        resolvers.invoke("ray", request).await
+    }
+
+    #[test]
+    fn test_resolver_input() {
+        let input = resolver_input(CustomResolverRequestPayload {
+            arguments: HashMap::from([("name".to_string(), json!("Grafbase"))]),
+            parent: Some(json!({ "id": 1 })),
+            context: UdfRequestContext {
+                request: UdfRequestContextRequest {
+                    headers: json!({ "authorization": "Bearer token" }),
+                },
+            },
+            info: None,
+        })
+        .unwrap();
+
+        assert_eq!(input.args, r#"{"name":"Grafbase"}"#);
+        assert_eq!(input.parent.as_deref(), Some(r#"{"id":1}"#));
+        assert_eq!(
+            input.context,
+            r#"{"request":{"headers":{"authorization":"Bearer token"}}}"#
+        );
+        assert_eq!(
+            input
+                .headers
+                .iter()
+                .map(|Header { name, value }| (name.as_str(), value.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("authorization", "Bearer token")]
+        );
+        assert_eq!(input.info, None);
+    }
+
+    #[test]
+    fn test_into_response() {
+        assert!(matches!(
+            into_response(Ok(r#"{"id":1}"#.to_string())).unwrap(),
+            CustomResolverResponse::Success(value) if value == json!({ "id": 1 })
+        ));
+        assert!(matches!(
+            into_response(Err(ResolverError::Message("oops".to_string()))).unwrap(),
+            CustomResolverResponse::Error(message) if message == "oops"
+        ));
+        assert!(matches!(
+            into_response(Err(ResolverError::Graphql(GraphqlError {
+                message: "Not found".to_string(),
+                extensions: Some(r#"{"code":"NOT_FOUND"}"#.to_string()),
+            })))
+            .unwrap(),
+            CustomResolverResponse::GraphQLError { message, extensions: Some(extensions) }
+                if message == "Not found" && extensions["code"] == json!("NOT_FOUND")
+        ));
+        assert!(into_response(Ok("not json".to_string())).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_invoke_component() {
+        let mut resolvers = WasmResolvers::new(&HashMap::new()).unwrap();
+        // Ok("\"hello\""), the discriminant of the result at 32 and the string at 36.
+        let hello = component(
+            "(i32.store8 (i32.const 32) (i32.const 0))
+             (i32.store (i32.const 36) (i32.const 16))
+             (i32.store (i32.const 40) (i32.const 7))
+             (i32.const 32)",
+        );
+        let component = Component::new(&resolvers.engine, hello).unwrap();
+        resolvers.components.insert("hello".to_string(), component);
+
+        assert!(matches!(
+            invoke(&resolvers, "hello").await.unwrap(),
+            CustomResolverResponse::Success(value) if value == json!("hello")
+        ));
+        assert!(invoke(&resolvers, "missing").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_invoke_timeout() {
+        let mut resolvers = WasmResolvers::new(&HashMap::new()).unwrap();
+        resolvers.timeout = Duration::from_millis(100);
+        let component = Component::new(&resolvers.engine, component("(loop $l (br $l)) (unreachable)")).unwrap();
+        resolvers.components.insert("loop".to_string(), component);
+
+        // The runtime of the test has a single thread, the timeout only fires if the resolver yields.
+        let start = Instant::now();
+        let result = tokio::time::timeout(Duration::from_secs(5), invoke(&resolvers, "loop")).await;
+        assert!(matches!(result, Ok(Err(CustomResolverError::InvocationError))));
+        // Stopped by the timeout rather than at the first tick of the epoch.
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+}
@@ -14,6 +14,8 @@ pub enum Error {
     CacheDelete(String),
     #[error("{0}")]
     CachePurgeByTags(String),
+    #[error("{0}")]
+    CachePurgeByHostname(String),
     #[error("Origin error: {0}")]
     Origin(String),
     #[error("Serialization error: {0}")]
@@ -40,6 +42,19 @@ pub enum Entry<T> {
     },
 }
 
+/// Usage of a cache since it was created.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct CacheStats {
+    /// Lookups returning an entry, fresh or stale.
+    pub hits: u64,
+    pub misses: u64,
+    /// Entries removed to stay within the capacity.
+    pub evictions: u64,
+    pub entries: u64,
+    /// Approximate size of the keys and values.
+    pub size_bytes: u64,
+}
+
 #[async_trait::async_trait]
 pub trait Cache: Send + Sync {
     type Value: Cacheable + 'static;
@@ -49,6 +64,11 @@ pub trait Cache: Send + Sync {
     async fn delete(&self, key: &str) -> Result<()>;
     async fn purge_by_tags(&self, tags: Vec<String>) -> Result<()>;
     async fn purge_by_hostname(&self, hostname: String) -> Result<()>;
+
+    /// Not all caches keep track of their usage.
+    async fn stats(&self) -> Result<Option<CacheStats>> {
+        Ok(None)
+    }
 }
 
 pub trait Cacheable: DeserializeOwned + Serialize + Send + Sync {
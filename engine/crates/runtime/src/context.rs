@@ -1,4 +1,4 @@
-use std::{collections::HashMap, ops::Deref, sync::Arc};
+use std::{collections::HashMap, net::IpAddr, ops::Deref, sync::Arc};
 
 use futures_util::future::BoxFuture;
 
@@ -37,6 +37,11 @@ pub trait RequestContext: Send + Sync {
     async fn wait_until(&self, fut: BoxFuture<'static, ()>);
     fn headers(&self) -> &http::HeaderMap;
 
+    /// The address of the peer the request came from, if the runtime knows it.
+    fn client_ip(&self) -> Option<IpAddr> {
+        None
+    }
+
     fn headers_as_map(&self) -> HashMap<String, String> {
         self.headers()
             .iter()
@@ -4,6 +4,7 @@ pub mod fetch;
 pub mod kv;
 pub mod log;
 pub mod pg;
+pub mod rate_limiting;
 pub mod search;
 pub mod udf;
 
@@ -0,0 +1,262 @@
+//! Fixed window rate limiting. Each rule counts the requests, and optionally their cost, of every
+//! key within windows of its duration. The counters are kept by a [`RateLimitStore`], either in
+//! memory or in a key-value store to share them between instances.
+
+use std::{net::IpAddr, sync::Arc, time::Duration};
+
+use crate::kv::{KvGet, KvPut, KvStore};
+
+/// The `extensions.code` of the GraphQL errors returned for rejected requests.
+pub const RATE_LIMITED_ERROR_CODE: &str = "RATE_LIMITED";
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RateLimitRule {
+    pub name: String,
+    pub key: RateLimitKey,
+    /// Maximum number of requests within a window.
+    pub requests: u64,
+    /// Maximum total cost of the operations within a window, as measured by the gateway. Only
+    /// operation-wide rules may limit the cost.
+    pub cost: Option<u64>,
+    pub duration: Duration,
+    /// The subgraph or connector whose requests are limited, every operation if `None`.
+    pub source: Option<String>,
+}
+
+/// What the requests are counted by. Requests without a value for the key, like a missing header,
+/// share a single counter, except for the client IP: requests whose address is unknown aren't
+/// limited by its rules, counting them together would throttle all of those clients at once.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RateLimitKey {
+    #[default]
+    Global,
+    /// The address of the peer the request came from.
+    ClientIp,
+    /// The client IP from the header, which must be set by a trusted proxy in front of the gateway
+    /// as clients could send any value. Only the last address of a list like `x-forwarded-for` is
+    /// used, the one added by the proxy. Requests without the header are counted by their peer
+    /// address.
+    ClientIpHeader(String),
+    Header(String),
+    JwtClaim(String),
+    OperationName,
+}
+
+/// The request being counted, providing the values the rules may be keyed by.
+pub trait RateLimitRequest: Sync {
+    /// The address of the peer, `None` if the runtime doesn't know it.
+    fn client_ip(&self) -> Option<IpAddr>;
+    fn header(&self, name: &str) -> Option<&str>;
+    /// `None` if the request isn't authenticated with a JWT.
+    fn jwt_claim(&self, name: &str) -> Option<String>;
+    fn operation_name(&self) -> Option<&str>;
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RateLimitCounters {
+    pub requests: u64,
+    pub cost: u64,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Rate limit store error: {0}")]
+pub struct RateLimitStoreError(pub String);
+
+#[async_trait::async_trait]
+pub trait RateLimitStore: Send + Sync {
+    /// Adds `increment` to the counters of the key, creating them if needed, and returns their
+    /// new values. New counters expire after `ttl`.
+    async fn increment(
+        &self,
+        key: &str,
+        ttl: Duration,
+        increment: RateLimitCounters,
+    ) -> Result<RateLimitCounters, RateLimitStoreError>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Too many requests, retry in {} seconds.", retry_after_secs(*.retry_after))]
+pub struct RateLimited {
+    pub rule: String,
+    pub retry_after: Duration,
+}
+
+impl RateLimited {
+    pub fn retry_after_secs(&self) -> u64 {
+        retry_after_secs(self.retry_after)
+    }
+}
+
+/// The value of the `Retry-After` header, rounded up to at least one second.
+pub fn retry_after_secs(retry_after: Duration) -> u64 {
+    let secs = retry_after.as_secs();
+    if retry_after.subsec_nanos() > 0 {
+        secs + 1
+    } else {
+        secs.max(1)
+    }
+}
+
+#[derive(Clone)]
+pub struct RateLimiter {
+    rules: Arc<[RateLimitRule]>,
+    store: Arc<dyn RateLimitStore>,
+}
+
+impl RateLimiter {
+    pub fn new(rules: Vec<RateLimitRule>, store: Arc<dyn RateLimitStore>) -> Self {
+        Self {
+            rules: rules.into(),
+            store,
+        }
+    }
+
+    /// Whether the cost of the operations must be computed before calling [`Self::check`].
+    pub fn limits_cost(&self) -> bool {
+        self.rules
+            .iter()
+            .any(|rule| rule.source.is_none() && rule.cost.is_some())
+    }
+
+    /// Counts the request against the rules of the source, or the operation-wide ones for `None`,
+    /// and rejects it if any of them is exceeded. Store errors are logged and the request let
+    /// through, an unavailable store must not take the gateway down.
+    pub async fn check(
+        &self,
+        source: Option<&str>,
+        request: &dyn RateLimitRequest,
+        cost: u64,
+    ) -> Result<(), RateLimited> {
+        let now = web_time::SystemTime::now()
+            .duration_since(web_time::SystemTime::UNIX_EPOCH)
+            .unwrap_or_default();
+
+        let mut rejection: Option<RateLimited> = None;
+        for rule in self.rules.iter().filter(|rule| rule.source.as_deref() == source) {
+            let window_secs = rule.duration.as_secs().max(1);
+            let window = now.as_secs() / window_secs;
+            let retry_after = Duration::from_secs((window + 1) * window_secs).saturating_sub(now);
+
+            let key = match key_value(&rule.key, request) {
+                KeyValue::Value(value) => format!("rate_limit:{}:{window}:{value}", rule.name),
+                KeyValue::Missing => format!("rate_limit:{}:{window}", rule.name),
+                KeyValue::UnknownClientIp => {
+                    tracing::debug!("Skipping the rate limit '{}', the client IP is unknown", rule.name);
+                    continue;
+                }
+            };
+            let increment = RateLimitCounters {
+                requests: 1,
+                cost: if rule.cost.is_some() { cost } else { 0 },
+            };
+            let counters = match self.store.increment(&key, retry_after, increment).await {
+                Ok(counters) => counters,
+                Err(err) => {
+                    tracing::warn!("Could not apply the rate limit '{}': {err}", rule.name);
+                    continue;
+                }
+            };
+
+            let exceeded =
+                counters.requests > rule.requests || rule.cost.is_some_and(|max_cost| counters.cost > max_cost);
+            // The longest wait is reported when several rules are exceeded.
+            if exceeded
+                && rejection
+                    .as_ref()
+                    .is_none_or(|rejection| rejection.retry_after < retry_after)
+            {
+                rejection = Some(RateLimited {
+                    rule: rule.name.clone(),
+                    retry_after,
+                });
+            }
+        }
+
+        match rejection {
+            Some(rejection) => Err(rejection),
+            None => Ok(()),
+        }
+    }
+}
+
+enum KeyValue {
+    Value(String),
+    Missing,
+    UnknownClientIp,
+}
+
+fn key_value(key: &RateLimitKey, request: &dyn RateLimitRequest) -> KeyValue {
+    let value = match key {
+        RateLimitKey::Global => None,
+        RateLimitKey::ClientIp => return client_ip(request, None),
+        RateLimitKey::ClientIpHeader(name) => return client_ip(request, Some(name)),
+        RateLimitKey::Header(name) => request.header(name).map(str::to_string),
+        RateLimitKey::JwtClaim(name) => request.jwt_claim(name),
+        RateLimitKey::OperationName => request.operation_name().map(str::to_string),
+    };
+    value.map_or(KeyValue::Missing, KeyValue::Value)
+}
+
+fn client_ip(request: &dyn RateLimitRequest, trusted_header: Option<&str>) -> KeyValue {
+    trusted_header
+        .and_then(|name| request.header(name))
+        .and_then(|value| value.rsplit(',').next())
+        .and_then(|ip| ip.trim().parse::<IpAddr>().ok())
+        .or_else(|| request.client_ip())
+        .map_or(KeyValue::UnknownClientIp, |ip| KeyValue::Value(ip.to_string()))
+}
+
+/// Counters shared through a key-value store. Reads and writes aren't atomic, so concurrent
+/// requests may be under-counted, and counters of eventually consistent stores may lag behind
+/// between locations.
+pub struct KvRateLimitStore<K> {
+    kv: K,
+}
+
+/// Smallest expiration accepted by Cloudflare KV. Keeping the counters longer is harmless as the
+/// window is part of their key.
+const MIN_KV_TTL: Duration = Duration::from_secs(60);
+
+impl<K> KvRateLimitStore<K> {
+    pub fn new(kv: K) -> Self {
+        Self { kv }
+    }
+}
+
+#[async_trait::async_trait]
+impl<K> RateLimitStore for KvRateLimitStore<K>
+where
+    K: KvStore + Send + Sync,
+    K::Get: Send,
+    K::Put: Send,
+    K::Error: Send,
+{
+    async fn increment(
+        &self,
+        key: &str,
+        ttl: Duration,
+        increment: RateLimitCounters,
+    ) -> Result<RateLimitCounters, RateLimitStoreError> {
+        let store_error = |err: K::Error| RateLimitStoreError(format!("{err:?}"));
+        let current = self
+            .kv
+            .get(key)
+            .json::<RateLimitCounters>()
+            .await
+            .map_err(store_error)?
+            .unwrap_or_default();
+        let counters = RateLimitCounters {
+            requests: current.requests.saturating_add(increment.requests),
+            cost: current.cost.saturating_add(increment.cost),
+        };
+        self.kv
+            .put(key, counters)
+            .map_err(store_error)?
+            .expiration_ttl(ttl.max(MIN_KV_TTL))
+            .execute()
+            .await
+            .map_err(store_error)?;
+        Ok(counters)
+    }
+}
@@ -3,7 +3,10 @@ use std::{ops::Deref, sync::Arc};
 pub use search_protocol::{
     config,
     config::*,
-    query::{graphql::*, GraphqlCursor, Hit, Info, PaginatedHits, Pagination, QueryError, ScalarValue},
+    query::{
+        graphql::*, range_buckets, Facet, FacetBucket, FacetCounts, GraphqlCursor, Highlight, Hit, Info, PaginatedHits,
+        Pagination, QueryError, Range, ScalarValue, Sort, SortDirection,
+    },
 };
 use serde::{Deserialize, Serialize};
 
@@ -12,6 +15,9 @@ pub struct Request {
     pub query: GraphqlQuery,
     pub pagination: Pagination,
     pub index: String,
+    pub facets: Vec<Facet>,
+    pub highlight: bool,
+    pub sort: Option<Sort>,
 }
 
 pub type Response = Result<PaginatedHits<String>, QueryError>;
@@ -32,6 +32,19 @@ pub enum CustomResolverResponse {
     },
 }
 
+/// The result for one of the parents of a batched resolver, which returns a list of them in the
+/// order of the parents: `{ "data": value }` or `{ "error": { "message": "...", "extensions": {} } }`.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CustomResolverBatchItem {
+    Data(serde_json::Value),
+    Error {
+        message: String,
+        #[serde(default)]
+        extensions: Option<BTreeMap<String, serde_json::Value>>,
+    },
+}
+
 #[async_trait::async_trait]
 pub trait UdfInvoker<Payload: Serialize> {
     async fn invoke(
@@ -61,6 +74,7 @@ pub struct CustomResolverRequestInfo {}
 pub struct CustomResolverRequestPayload {
     #[serde(rename = "args")]
     pub arguments: std::collections::HashMap<String, serde_json::Value>,
+    /// The list of all the parents for batched resolvers, which share the same arguments.
     pub parent: Option<serde_json::Value>,
     pub context: UdfRequestContext,
     pub info: Option<serde_json::Value>,
@@ -18,10 +18,92 @@ pub struct Schema {
     pub fields: HashMap<String, FieldEntry>,
 }
 
-// Can be used to store anything relevant to the field that doesn't impact the index directly
+impl Schema {
+    /// Stable digest of the schema, an index must be rebuilt whenever it changes.
+    pub fn digest(&self) -> String {
+        // FNV-1a, we only need something stable across releases, not a cryptographic hash.
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        let mut fields = self.fields.iter().collect::<Vec<_>>();
+        fields.sort_unstable_by_key(|(name, _)| *name);
+        for (name, entry) in fields {
+            for byte in format!("{name}:{entry:?};").bytes() {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+        format!("{hash:016x}")
+    }
+}
+
 #[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct FieldEntry {
     pub ty: FieldType,
+    /// Only relevant for String, URL and Email fields.
+    #[serde(default)]
+    pub analyzer: Analyzer,
+    /// Whether search results can be ordered by this field.
+    #[serde(default)]
+    pub sortable: bool,
+}
+
+impl FieldEntry {
+    pub fn new(ty: FieldType) -> Self {
+        Self {
+            ty,
+            analyzer: Analyzer::default(),
+            sortable: false,
+        }
+    }
+}
+
+/// How text is split into the terms that are indexed and searched.
+// variant names are used in the @search directive, MUST NOT be renamed.
+#[derive(
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    Default,
+    Serialize,
+    Deserialize,
+    strum::EnumString,
+    strum::EnumIter,
+    strum::EnumVariantNames,
+    strum::IntoStaticStr,
+)]
+#[strum(serialize_all = "lowercase")]
+pub enum Analyzer {
+    /// Words are lowercased and stripped of their accents.
+    #[default]
+    Standard,
+    /// The whole value is a single term, only exact matches are found.
+    Keyword,
+    // Words are also stemmed and stop words are removed.
+    Danish,
+    Dutch,
+    English,
+    Finnish,
+    French,
+    German,
+    Hungarian,
+    Italian,
+    Norwegian,
+    Portuguese,
+    Russian,
+    Spanish,
+    Swedish,
+}
+
+impl Analyzer {
+    pub fn name(&self) -> &'static str {
+        From::from(self)
+    }
+
+    pub fn names() -> &'static [&'static str] {
+        <Self as strum::VariantNames>::VARIANTS
+    }
 }
 
 // enum names MUST match their GraphQL scalar counterpart
@@ -38,6 +120,8 @@ pub enum FieldType {
     Float(FieldOptions),
     Boolean(FieldOptions),
     IPAddress(FieldOptions),
+    /// Embedding supplied by the client, only searchable with vector queries.
+    Vector(VectorOptions),
 }
 
 #[derive(Clone, Eq, PartialEq, Default, Hash, Debug, Serialize, Deserialize)]
@@ -45,6 +129,12 @@ pub struct FieldOptions {
     pub nullable: bool,
 }
 
+#[derive(Clone, Eq, PartialEq, Default, Hash, Debug, Serialize, Deserialize)]
+pub struct VectorOptions {
+    pub nullable: bool,
+    pub dimensions: u32,
+}
+
 // Utility functions, essentially for more readable tests
 impl FieldType {
     pub fn scalar_name(&self) -> &'static str {
@@ -64,9 +154,15 @@ impl FieldType {
             | FieldType::Float(opts)
             | FieldType::Boolean(opts)
             | FieldType::IPAddress(opts) => opts.nullable,
+            FieldType::Vector(opts) => opts.nullable,
         }
     }
 
+    /// Whether the field has a tokenized text representation, the only ones supporting analyzers.
+    pub fn is_text(&self) -> bool {
+        matches!(self, FieldType::URL(_) | FieldType::Email(_) | FieldType::String(_))
+    }
+
     pub fn url() -> Self {
         Self::URL(FieldOptions::default())
     }
@@ -110,6 +206,13 @@ impl FieldType {
     pub fn ip() -> Self {
         Self::IPAddress(FieldOptions::default())
     }
+
+    pub fn vector(dimensions: u32) -> Self {
+        Self::Vector(VectorOptions {
+            nullable: false,
+            dimensions,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -215,36 +318,19 @@ mod tests {
                     IndexConfig {
                         schema: Schema {
                             fields: HashMap::from([
-                                ("url".to_string(), FieldEntry { ty: FieldType::url() }),
-                                ("email".to_string(), FieldEntry { ty: FieldType::email() }),
-                                ("phone".to_string(), FieldEntry { ty: FieldType::phone() }),
-                                (
-                                    "text".to_string(),
-                                    FieldEntry {
-                                        ty: FieldType::string()
-                                    }
-                                ),
-                                ("date".to_string(), FieldEntry { ty: FieldType::date() }),
-                                (
-                                    "datetime".to_string(),
-                                    FieldEntry {
-                                        ty: FieldType::datetime()
-                                    }
-                                ),
-                                (
-                                    "timestamp".to_string(),
-                                    FieldEntry {
-                                        ty: FieldType::timestamp()
-                                    }
-                                ),
-                                ("int".to_string(), FieldEntry { ty: FieldType::int() }),
-                                ("float".to_string(), FieldEntry { ty: FieldType::float() }),
-                                ("bool".to_string(), FieldEntry { ty: FieldType::bool() }),
+                                ("url".to_string(), FieldEntry::new(FieldType::url())),
+                                ("email".to_string(), FieldEntry::new(FieldType::email())),
+                                ("phone".to_string(), FieldEntry::new(FieldType::phone())),
+                                ("text".to_string(), FieldEntry::new(FieldType::string())),
+                                ("date".to_string(), FieldEntry::new(FieldType::date())),
+                                ("datetime".to_string(), FieldEntry::new(FieldType::datetime())),
+                                ("timestamp".to_string(), FieldEntry::new(FieldType::timestamp())),
+                                ("int".to_string(), FieldEntry::new(FieldType::int())),
+                                ("float".to_string(), FieldEntry::new(FieldType::float())),
+                                ("bool".to_string(), FieldEntry::new(FieldType::bool())),
                                 (
                                     "ip".to_string(),
-                                    FieldEntry {
-                                        ty: FieldType::IPAddress(FieldOptions { nullable: true })
-                                    }
+                                    FieldEntry::new(FieldType::IPAddress(FieldOptions { nullable: true }))
                                 ),
                             ])
                         }
@@ -253,4 +339,37 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn schema_digest() {
+        let schema = Schema {
+            fields: HashMap::from([
+                ("text".to_string(), FieldEntry::new(FieldType::string())),
+                ("int".to_string(), FieldEntry::new(FieldType::int())),
+            ]),
+        };
+        let digest = schema.digest();
+        assert_eq!(digest.len(), 16);
+        assert_eq!(
+            digest,
+            Schema {
+                fields: HashMap::from([
+                    ("int".to_string(), FieldEntry::new(FieldType::int())),
+                    ("text".to_string(), FieldEntry::new(FieldType::string())),
+                ]),
+            }
+            .digest()
+        );
+
+        let mut english = schema.clone();
+        english.fields.get_mut("text").unwrap().analyzer = Analyzer::English;
+        assert_ne!(digest, english.digest());
+    }
+
+    #[test]
+    fn analyzer_names() {
+        assert_eq!("english".parse::<Analyzer>().unwrap(), Analyzer::English);
+        assert_eq!(Analyzer::Keyword.name(), "keyword");
+        assert!("klingon".parse::<Analyzer>().is_err());
+    }
 }
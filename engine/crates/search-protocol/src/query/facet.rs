@@ -0,0 +1,93 @@
+use std::ops::Bound;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Range, ScalarValue};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Facet {
+    /// Counts the most frequent values of a String field.
+    Terms { field: String, limit: u64 },
+    /// Counts the values falling between consecutive boundaries, see [range_buckets].
+    Range {
+        field: String,
+        boundaries: Vec<ScalarValue>,
+    },
+}
+
+impl Facet {
+    pub fn field(&self) -> &str {
+        match self {
+            Facet::Terms { field, .. } | Facet::Range { field, .. } => field,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FacetCounts {
+    pub field: String,
+    pub buckets: Vec<FacetBucket>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FacetBucket {
+    Term { value: String, count: u64 },
+    Range { range: Range<ScalarValue>, count: u64 },
+}
+
+/// Splits the value space at each boundary: `[a, b]` gives the buckets `]..,a[`, `[a,b[` and
+/// `[b,..[`. Boundaries are sorted and deduplicated first.
+pub fn range_buckets(boundaries: &[ScalarValue]) -> Vec<Range<ScalarValue>> {
+    let mut boundaries = boundaries.to_vec();
+    boundaries.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    boundaries.dedup();
+
+    let mut start = Bound::Unbounded;
+    let mut buckets = Vec::with_capacity(boundaries.len() + 1);
+    for boundary in boundaries {
+        buckets.push(Range {
+            start,
+            end: Bound::Excluded(boundary.clone()),
+        });
+        start = Bound::Included(boundary);
+    }
+    buckets.push(Range {
+        start,
+        end: Bound::Unbounded,
+    });
+    buckets
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Highlight {
+    pub field: String,
+    /// Matching fragment of the field with the matched words wrapped in `<b>` tags.
+    pub snippet: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_buckets() {
+        assert_eq!(
+            range_buckets(&[ScalarValue::Int(10), ScalarValue::Int(0), ScalarValue::Int(10)]),
+            vec![
+                Range {
+                    start: Bound::Unbounded,
+                    end: Bound::Excluded(ScalarValue::Int(0))
+                },
+                Range {
+                    start: Bound::Included(ScalarValue::Int(0)),
+                    end: Bound::Excluded(ScalarValue::Int(10))
+                },
+                Range {
+                    start: Bound::Included(ScalarValue::Int(10)),
+                    end: Bound::Unbounded
+                },
+            ]
+        );
+        assert_eq!(range_buckets(&[]), vec![Range::unbounded()]);
+    }
+}
@@ -12,6 +12,19 @@ pub struct GraphqlQuery {
     pub text: Option<String>,
     pub fields: Option<Vec<String>>,
     pub filter: Option<Filter>,
+    #[serde(default)]
+    pub fuzziness: Option<u8>,
+    #[serde(default)]
+    pub vector: Option<VectorSearch>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VectorSearch {
+    pub field: String,
+    pub vector: Vec<f32>,
+    pub k: u32,
+    /// Weight of the vector similarity when combined with a text query.
+    pub weight: f32,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -57,15 +70,35 @@ impl TryFrom<GraphqlQuery> for Query {
     //        to copy-paste all of that code into the cli repository... With OS gateway, Query
     //        shouldn't be exposed anymore.
     fn try_from(value: GraphqlQuery) -> Result<Self, Self::Error> {
-        let GraphqlQuery { text, fields, filter } = value;
-        IntersectionQueryBuilder::build_from(
-            vec![
-                text.map(|text| Ok(Query::Text { value: text, fields })),
-                filter.map(Query::try_from),
-            ]
-            .into_iter()
-            .flatten(),
-        )
+        let GraphqlQuery {
+            text,
+            fields,
+            filter,
+            fuzziness,
+            vector,
+        } = value;
+        let text = text.map(|text| Query::Text {
+            value: text,
+            fields,
+            fuzziness,
+        });
+        let vector = vector.map(
+            |VectorSearch {
+                 field,
+                 vector,
+                 k,
+                 weight,
+             }| { (Query::Vector { field, vector, k }, weight) },
+        );
+        let ranking = match (text, vector) {
+            (Some(text), Some((vector, vector_weight))) => Some(Query::Hybrid {
+                text: Box::new(text),
+                vector: Box::new(vector),
+                vector_weight,
+            }),
+            (text, vector) => text.or(vector.map(|(vector, _)| vector)),
+        };
+        IntersectionQueryBuilder::build_from(vec![ranking.map(Ok), filter.map(Query::try_from)].into_iter().flatten())
     }
 }
 
@@ -507,4 +540,48 @@ mod tests {
             query
         );
     }
+
+    #[test]
+    fn test_vector() {
+        let vector = || VectorSearch {
+            field: "embedding".to_string(),
+            vector: vec![1.0, 0.0],
+            k: 3,
+            weight: 0.25,
+        };
+        let vector_query = || Query::Vector {
+            field: "embedding".to_string(),
+            vector: vec![1.0, 0.0],
+            k: 3,
+        };
+
+        assert_eq!(
+            Query::try_from(GraphqlQuery {
+                vector: Some(vector()),
+                filter: Some(filter("x", lt(10))),
+                ..Default::default()
+            })
+            .unwrap(),
+            Query::Intersection(vec![
+                vector_query(),
+                Query::Range {
+                    field: "x".to_string(),
+                    range: Range::of(..10)
+                }
+            ])
+        );
+        assert_eq!(
+            Query::try_from(GraphqlQuery {
+                text: Some("dogs".to_string()),
+                vector: Some(vector()),
+                ..Default::default()
+            })
+            .unwrap(),
+            Query::Hybrid {
+                text: Box::new(Query::text("dogs")),
+                vector: Box::new(vector_query()),
+                vector_weight: 0.25
+            }
+        );
+    }
 }
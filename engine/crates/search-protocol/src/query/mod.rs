@@ -1,17 +1,21 @@
 mod builder;
 mod error;
+mod facet;
 pub mod graphql;
 mod pagination;
 mod range;
 mod scalar;
+mod sort;
 
 use std::ops::{Not, RangeBounds};
 
 pub use error::{BadRequestError, QueryError};
+pub use facet::{range_buckets, Facet, FacetBucket, FacetCounts, Highlight};
 pub use pagination::{GraphqlCursor, Hit, Info, PaginatedHits, Pagination};
 pub use range::Range;
 pub use scalar::ScalarValue;
 use serde::{Deserialize, Serialize};
+pub use sort::{Sort, SortDirection};
 use ulid::Ulid;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -24,6 +28,14 @@ pub struct QueryRequest {
     pub ray_id: String,
     #[serde(default)]
     pub response_parameters: QueryResponseParameters,
+    #[serde(default)]
+    pub facets: Vec<Facet>,
+    /// Whether hits should include highlighted snippets of the matching text fields.
+    #[serde(default)]
+    pub highlight: bool,
+    /// Hits are ordered by relevance if not specified.
+    #[serde(default)]
+    pub sort: Option<Sort>,
 }
 
 #[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
@@ -62,24 +74,8 @@ pub enum QueryResponse {
 impl QueryResponse {
     pub fn normalized(self, transform_ulid: impl Fn(Ulid) -> String) -> Result<PaginatedHits<String>, QueryError> {
         match self {
-            Self::V0(PaginatedHits { hits, info }) => Ok(PaginatedHits {
-                hits: hits
-                    .into_iter()
-                    .map(|Hit { id, cursor, score }| Hit { id, cursor, score })
-                    .collect(),
-                info,
-            }),
-            Self::V1(resp) => resp.map(|PaginatedHits { hits, info }| PaginatedHits {
-                hits: hits
-                    .into_iter()
-                    .map(|Hit { id, cursor, score }| Hit {
-                        id: transform_ulid(id),
-                        cursor,
-                        score,
-                    })
-                    .collect(),
-                info,
-            }),
+            Self::V0(hits) => Ok(hits),
+            Self::V1(resp) => resp.map(|hits| hits.map_id(transform_ulid)),
         }
     }
 }
@@ -89,13 +85,45 @@ pub enum Query {
     Intersection(Vec<Query>),
     Union(Vec<Query>),
     Not(Box<Query>),
-    Range { field: String, range: Range<ScalarValue> },
-    In { field: String, values: Vec<ScalarValue> },
-    Regex { field: String, pattern: String },
+    Range {
+        field: String,
+        range: Range<ScalarValue>,
+    },
+    In {
+        field: String,
+        values: Vec<ScalarValue>,
+    },
+    Regex {
+        field: String,
+        pattern: String,
+    },
     All,
     Empty,
-    IsNull { field: String },
-    Text { value: String, fields: Option<Vec<String>> },
+    IsNull {
+        field: String,
+    },
+    Text {
+        value: String,
+        fields: Option<Vec<String>>,
+        /// Maximum number of typos per word, chosen from the word length if not specified.
+        #[serde(default)]
+        fuzziness: Option<u8>,
+    },
+    /// The `k` documents nearest to the vector by cosine similarity. Combined with filters in an
+    /// intersection, the nearest documents are searched among the matching ones.
+    Vector {
+        field: String,
+        vector: Vec<f32>,
+        k: u32,
+    },
+    /// Documents matching either the text or the vector query, ranked by the weighted sum of
+    /// their normalized text and vector scores.
+    Hybrid {
+        text: Box<Query>,
+        vector: Box<Query>,
+        /// Between 0 (text score only) and 1 (vector similarity only).
+        vector_weight: f32,
+    },
 }
 
 impl Not for Query {
@@ -135,6 +163,7 @@ impl Query {
         Query::Text {
             value: value.to_string(),
             fields: None,
+            fuzziness: None,
         }
     }
 
@@ -185,7 +214,10 @@ mod tests {
             ray_id: "7ea5c926ba983fdd".into(),
             response_parameters: QueryResponseParameters {
                 version: QueryResponseDiscriminants::V0
-            }
+            },
+            facets: vec![],
+            highlight: false,
+            sort: None
         }
     )]
     #[case( // 2
@@ -248,7 +280,10 @@ mod tests {
             ray_id: "7ea5c926ba983fdd".into(),
             response_parameters: QueryResponseParameters {
                 version: QueryResponseDiscriminants::V0
-            }
+            },
+            facets: vec![],
+            highlight: false,
+            sort: None
         }
     )]
     #[case( // 3
@@ -269,7 +304,8 @@ mod tests {
                   "value": "e",
                   "fields": [
                     "x"
-                  ]
+                  ],
+                  "fuzziness": 1
                 }
               },
               {
@@ -291,6 +327,29 @@ mod tests {
           "ray_id": "7ea5c926ba983fdd",
           "response_parameters": {
             "version": "V1"
+          },
+          "facets": [
+            {
+              "Terms": {
+                "field": "x",
+                "limit": 5
+              }
+            },
+            {
+              "Range": {
+                "field": "a",
+                "boundaries": [
+                  {
+                    "Int": 1
+                  }
+                ]
+              }
+            }
+          ],
+          "highlight": true,
+          "sort": {
+            "field": "a",
+            "direction": "Descending"
           }
         }
         "#,
@@ -299,7 +358,8 @@ mod tests {
                 !Query::text("c"),
                 Query::Text {
                     value: "e".into(),
-                    fields: Some(vec!["x".to_string()])
+                    fields: Some(vec!["x".to_string()]),
+                    fuzziness: Some(1)
                 },
                 Query::regex("d", ".*")
             ]),
@@ -312,7 +372,22 @@ mod tests {
             ray_id: "7ea5c926ba983fdd".into(),
             response_parameters: QueryResponseParameters {
                 version: QueryResponseDiscriminants::V1
-            }
+            },
+            facets: vec![
+                Facet::Terms {
+                    field: "x".into(),
+                    limit: 5
+                },
+                Facet::Range {
+                    field: "a".into(),
+                    boundaries: vec![ScalarValue::Int(1)]
+                }
+            ],
+            highlight: true,
+            sort: Some(Sort {
+                field: "a".into(),
+                direction: SortDirection::Descending
+            })
         }
     )]
     #[case( // 4
@@ -469,7 +544,10 @@ mod tests {
             ray_id: "7ea5c926ba983fdd".into(),
             response_parameters: QueryResponseParameters {
                 version: QueryResponseDiscriminants::V0
-            }
+            },
+            facets: vec![],
+            highlight: false,
+            sort: None
         }
     )]
     fn request_backwards_compatbility(#[case] request: &str, #[case] expected: QueryRequest) {
@@ -495,12 +573,14 @@ mod tests {
                 id: "animal_01H5YEYMPBEMAPWDG75VK5MBBP".to_string(),
                 cursor: GraphqlCursor::from([0x6f, 0x73, 0x36, 0x2c, 0x52, 0x41, 0x5a, 0x29, 0x62]),
                 score: 7.32,
+                highlights: vec![],
             }],
             info: Info {
                 has_previous_page: false,
                 has_next_page: false,
                 total_hits: 0,
             },
+            facets: vec![],
         })
     )]
     #[case( // 2
@@ -525,12 +605,14 @@ mod tests {
                 id: Ulid::from_str("01H5YEYMPBEMAPWDG75VK5MBBP").unwrap(),
                 cursor: GraphqlCursor::from([0x6f, 0x73, 0x36, 0x2c, 0x52, 0x41, 0x5a, 0x29, 0x62]),
                 score: 7.32,
+                highlights: vec![],
             }],
             info: Info {
                 has_previous_page: false,
                 has_next_page: false,
                 total_hits: 0,
             },
+            facets: vec![],
         })))
     )]
     #[case( // 3
@@ -583,6 +665,78 @@ mod tests {
             })),
         ))
     )]
+    #[case( // 6
+        r#"
+        {
+            "V1": {
+                "Ok": {
+                    "hits":[
+                        {
+                            "id": "01H5YEYMPBEMAPWDG75VK5MBBP",
+                            "cursor": "b3M2LFJBWili",
+                            "score": 7.32,
+                            "highlights": [{"field": "name", "snippet": "<b>Rex</b>"}]
+                        }
+                    ],
+                    "info":{
+                        "has_previous_page":false,
+                        "has_next_page":false,
+                        "total_hits":1
+                    },
+                    "facets": [
+                        {
+                            "field": "kind",
+                            "buckets": [{"Term": {"value": "Dog", "count": 1}}]
+                        },
+                        {
+                            "field": "age",
+                            "buckets": [
+                                {
+                                    "Range": {
+                                        "range": {"start": "Unbounded", "end": {"Excluded": {"Int": 3}}},
+                                        "count": 1
+                                    }
+                                }
+                            ]
+                        }
+                    ]
+                }
+            }
+        }
+        "#,
+        BackwardsCompatibleQueryResponse::New(QueryResponse::V1(Ok(PaginatedHits {
+            hits: vec![Hit {
+                id: Ulid::from_str("01H5YEYMPBEMAPWDG75VK5MBBP").unwrap(),
+                cursor: GraphqlCursor::from([0x6f, 0x73, 0x36, 0x2c, 0x52, 0x41, 0x5a, 0x29, 0x62]),
+                score: 7.32,
+                highlights: vec![Highlight {
+                    field: "name".into(),
+                    snippet: "<b>Rex</b>".into(),
+                }],
+            }],
+            info: Info {
+                has_previous_page: false,
+                has_next_page: false,
+                total_hits: 1,
+            },
+            facets: vec![
+                FacetCounts {
+                    field: "kind".into(),
+                    buckets: vec![FacetBucket::Term {
+                        value: "Dog".into(),
+                        count: 1,
+                    }],
+                },
+                FacetCounts {
+                    field: "age".into(),
+                    buckets: vec![FacetBucket::Range {
+                        range: Range::of(..3),
+                        count: 1,
+                    }],
+                },
+            ],
+        })))
+    )]
     fn response_backwards_compatbility(#[case] response: &str, #[case] expected: BackwardsCompatibleQueryResponse) {
         assert_eq!(
             serde_json::from_str::<BackwardsCompatibleQueryResponse>(response).unwrap(),
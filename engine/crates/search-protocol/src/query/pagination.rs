@@ -5,6 +5,8 @@ use serde_with::{
     serde_as,
 };
 
+use super::{FacetCounts, Highlight};
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Pagination {
     Forward { first: u64, after: Option<GraphqlCursor> },
@@ -40,6 +42,34 @@ impl<T: AsRef<[u8]>> From<T> for GraphqlCursor {
 pub struct PaginatedHits<Id> {
     pub hits: Vec<Hit<Id>>,
     pub info: Info,
+    #[serde(default)]
+    pub facets: Vec<FacetCounts>,
+}
+
+impl<T> PaginatedHits<T> {
+    pub fn map_id<U, F: Fn(T) -> U>(self, f: F) -> PaginatedHits<U> {
+        let PaginatedHits { hits, info, facets } = self;
+        PaginatedHits {
+            hits: hits
+                .into_iter()
+                .map(
+                    |Hit {
+                         id,
+                         cursor,
+                         score,
+                         highlights,
+                     }| Hit {
+                        id: f(id),
+                        cursor,
+                        score,
+                        highlights,
+                    },
+                )
+                .collect(),
+            info,
+            facets,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -54,4 +84,6 @@ pub struct Hit<Id> {
     pub id: Id,
     pub cursor: GraphqlCursor,
     pub score: f32,
+    #[serde(default)]
+    pub highlights: Vec<Highlight>,
 }
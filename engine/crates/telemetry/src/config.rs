@@ -0,0 +1,38 @@
+use std::time::Duration;
+
+/// What is exported, and where, with the name of the service attached to everything.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TelemetryConfig {
+    pub service_name: String,
+    pub tracing: Option<TracingConfig>,
+    pub metrics: Option<MetricsConfig>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TracingConfig {
+    pub exporter: ExporterConfig,
+    /// Ratio of the traces without a sampled parent that are exported, between 0 and 1.
+    pub sampling: f64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetricsConfig {
+    pub exporter: ExporterConfig,
+    pub interval: Duration,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExporterConfig {
+    /// For the HTTP protocol, `/v1/traces` and `/v1/metrics` are appended to it.
+    pub endpoint: String,
+    pub protocol: OtlpProtocol,
+    pub headers: Vec<(String, String)>,
+    pub timeout: Duration,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OtlpProtocol {
+    #[default]
+    Grpc,
+    Http,
+}
@@ -0,0 +1,16 @@
+//! OpenTelemetry instrumentation of the gateway.
+//!
+//! Spans are created with `tracing` everywhere and only exported when the [`layer`] is part of
+//! the global subscriber and [`init`] was called with a tracing exporter. Metrics and trace
+//! context propagation only rely on the OpenTelemetry API, so they're no-ops until then and can be
+//! used from any runtime.
+
+pub mod config;
+pub mod metrics;
+#[cfg(feature = "otlp")]
+mod otlp;
+pub mod propagation;
+
+pub use config::{ExporterConfig, MetricsConfig, OtlpProtocol, TelemetryConfig, TracingConfig};
+#[cfg(feature = "otlp")]
+pub use otlp::{init, layer, TelemetryError, TelemetryGuard};
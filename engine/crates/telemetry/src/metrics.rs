@@ -0,0 +1,106 @@
+//! Metrics of the GraphQL operations, exported with the meter provider installed by
+//! [`init`](crate::init). The cache hit ratio is the share of cache reads with the `hit` status.
+
+use std::time::Duration;
+
+use opentelemetry::{
+    global,
+    metrics::{Counter, Histogram, Meter, Unit},
+    KeyValue,
+};
+
+const METER_NAME: &str = "grafbase";
+
+/// The instruments are bound to the meter provider installed when they're built, so they must
+/// be built after the telemetry is initialized.
+#[derive(Clone)]
+pub struct GraphqlOperationMetrics {
+    operations: Counter<u64>,
+    duration: Histogram<f64>,
+    errors: Counter<u64>,
+    cache_reads: Counter<u64>,
+}
+
+pub struct OperationAttributes<'a> {
+    /// `query`, `mutation` or `subscription`, `None` if the operation couldn't be determined.
+    pub ty: Option<&'static str>,
+    pub name: Option<&'a str>,
+    pub has_errors: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CacheReadStatus {
+    Hit,
+    Miss,
+    Stale,
+    Bypass,
+}
+
+impl CacheReadStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            CacheReadStatus::Hit => "hit",
+            CacheReadStatus::Miss => "miss",
+            CacheReadStatus::Stale => "stale",
+            CacheReadStatus::Bypass => "bypass",
+        }
+    }
+}
+
+impl GraphqlOperationMetrics {
+    /// Builds the instruments from the global meter provider.
+    pub fn build() -> Self {
+        Self::new(&global::meter(METER_NAME))
+    }
+
+    pub fn new(meter: &Meter) -> Self {
+        Self {
+            operations: meter
+                .u64_counter("graphql.operations")
+                .with_description("Number of executed GraphQL operations")
+                .init(),
+            duration: meter
+                .f64_histogram("graphql.operation.duration")
+                .with_description("Duration of the GraphQL operations")
+                .with_unit(Unit::new("s"))
+                .init(),
+            errors: meter
+                .u64_counter("graphql.errors")
+                .with_description("Number of GraphQL errors returned, by error code")
+                .init(),
+            cache_reads: meter
+                .u64_counter("graphql.cache.reads")
+                .with_description("Number of response cache reads, by status")
+                .init(),
+        }
+    }
+
+    pub fn record_operation(&self, attributes: OperationAttributes<'_>, duration: Duration) {
+        let mut key_values = vec![KeyValue::new("graphql.response.has_errors", attributes.has_errors)];
+        if let Some(ty) = attributes.ty {
+            key_values.push(KeyValue::new("graphql.operation.type", ty));
+        }
+        if let Some(name) = attributes.name {
+            key_values.push(KeyValue::new("graphql.operation.name", name.to_string()));
+        }
+
+        self.operations.add(1, &key_values);
+        self.duration.record(duration.as_secs_f64(), &key_values);
+    }
+
+    /// Errors without a code are recorded with the `UNKNOWN` one.
+    pub fn record_error(&self, code: Option<&str>) {
+        self.errors.add(
+            1,
+            &[KeyValue::new(
+                "graphql.error.code",
+                code.unwrap_or("UNKNOWN").to_string(),
+            )],
+        );
+    }
+
+    pub fn record_cache_read(&self, status: CacheReadStatus) {
+        self.cache_reads
+            .add(1, &[KeyValue::new("graphql.cache.status", status.as_str())]);
+    }
+}
@@ -0,0 +1,157 @@
+use std::sync::OnceLock;
+
+use opentelemetry::{global, metrics::noop::NoopMeterProvider, KeyValue};
+use opentelemetry_otlp::{MetricsExporterBuilder, SpanExporterBuilder, WithExportConfig};
+use opentelemetry_sdk::{
+    metrics::MeterProvider,
+    runtime,
+    trace::{self, Sampler},
+    Resource,
+};
+use tonic::metadata::{MetadataKey, MetadataMap, MetadataValue};
+use tracing_subscriber::{filter::LevelFilter, registry::Registry, reload, Layer};
+
+use crate::{ExporterConfig, OtlpProtocol, TelemetryConfig};
+
+type BoxedLayer = Box<dyn Layer<Registry> + Send + Sync>;
+
+static RELOAD_HANDLE: OnceLock<reload::Handle<Option<BoxedLayer>, Registry>> = OnceLock::new();
+
+#[derive(Debug, thiserror::Error)]
+pub enum TelemetryError {
+    #[error("the telemetry layer isn't part of the global subscriber")]
+    LayerNotInstalled,
+    #[error("invalid exporter header '{0}'")]
+    InvalidHeader(String),
+    #[error(transparent)]
+    Trace(#[from] opentelemetry::trace::TraceError),
+    #[error(transparent)]
+    Metrics(#[from] opentelemetry::metrics::MetricsError),
+    #[error("could not enable the trace export: {0}")]
+    Reload(#[from] reload::Error),
+}
+
+/// The layer exporting the spans once [`init`] is called, it must be installed with the global
+/// subscriber and only once. Spans below the info level are never exported.
+pub fn layer() -> impl Layer<Registry> {
+    let (layer, handle) = reload::Layer::<Option<BoxedLayer>, Registry>::new(None);
+    RELOAD_HANDLE.set(handle).ok();
+    layer.with_filter(LevelFilter::INFO)
+}
+
+/// Starts exporting the traces and metrics until the guard is dropped. Calling it again replaces
+/// the previous exporters, which is needed whenever the configuration is reloaded.
+pub fn init(config: &TelemetryConfig) -> Result<TelemetryGuard, TelemetryError> {
+    let handle = RELOAD_HANDLE.get().ok_or(TelemetryError::LayerNotInstalled)?;
+    let resource = Resource::new([KeyValue::new("service.name", config.service_name.clone())]);
+    let mut guard = TelemetryGuard {
+        tracing: false,
+        meter_provider: None,
+    };
+
+    if let Some(tracing) = &config.tracing {
+        let tracer = opentelemetry_otlp::new_pipeline()
+            .tracing()
+            .with_exporter(span_exporter(&tracing.exporter)?)
+            .with_trace_config(
+                trace::config()
+                    .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+                        tracing.sampling,
+                    ))))
+                    .with_resource(resource.clone()),
+            )
+            .install_batch(runtime::Tokio)?;
+
+        let layer: BoxedLayer = Box::new(tracing_opentelemetry::layer().with_tracer(tracer));
+        handle.reload(Some(layer))?;
+        guard.tracing = true;
+    }
+
+    if let Some(metrics) = &config.metrics {
+        let meter_provider = opentelemetry_otlp::new_pipeline()
+            .metrics(runtime::Tokio)
+            .with_exporter(metrics_exporter(&metrics.exporter)?)
+            .with_period(metrics.interval)
+            .with_timeout(metrics.exporter.timeout)
+            .with_resource(resource)
+            .build()?;
+
+        global::set_meter_provider(meter_provider.clone());
+        guard.meter_provider = Some(meter_provider);
+    }
+
+    Ok(guard)
+}
+
+/// Flushes and stops the export when dropped.
+#[must_use]
+pub struct TelemetryGuard {
+    tracing: bool,
+    meter_provider: Option<MeterProvider>,
+}
+
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        if self.tracing {
+            if let Some(handle) = RELOAD_HANDLE.get() {
+                handle.reload(None).ok();
+            }
+            global::shutdown_tracer_provider();
+        }
+
+        if let Some(meter_provider) = self.meter_provider.take() {
+            global::set_meter_provider(NoopMeterProvider::new());
+            meter_provider.shutdown().ok();
+        }
+    }
+}
+
+fn span_exporter(config: &ExporterConfig) -> Result<SpanExporterBuilder, TelemetryError> {
+    Ok(match config.protocol {
+        OtlpProtocol::Grpc => opentelemetry_otlp::new_exporter()
+            .tonic()
+            .with_endpoint(&config.endpoint)
+            .with_timeout(config.timeout)
+            .with_metadata(metadata(config)?)
+            .into(),
+        OtlpProtocol::Http => opentelemetry_otlp::new_exporter()
+            .http()
+            .with_endpoint(http_endpoint(config, "traces"))
+            .with_timeout(config.timeout)
+            .with_headers(config.headers.iter().cloned().collect())
+            .into(),
+    })
+}
+
+fn metrics_exporter(config: &ExporterConfig) -> Result<MetricsExporterBuilder, TelemetryError> {
+    Ok(match config.protocol {
+        OtlpProtocol::Grpc => opentelemetry_otlp::new_exporter()
+            .tonic()
+            .with_endpoint(&config.endpoint)
+            .with_timeout(config.timeout)
+            .with_metadata(metadata(config)?)
+            .into(),
+        OtlpProtocol::Http => opentelemetry_otlp::new_exporter()
+            .http()
+            .with_endpoint(http_endpoint(config, "metrics"))
+            .with_timeout(config.timeout)
+            .with_headers(config.headers.iter().cloned().collect())
+            .into(),
+    })
+}
+
+/// The HTTP exporters post to the endpoint as is, without the path of the signal.
+fn http_endpoint(config: &ExporterConfig, signal: &str) -> String {
+    format!("{}/v1/{signal}", config.endpoint.trim_end_matches('/'))
+}
+
+fn metadata(config: &ExporterConfig) -> Result<MetadataMap, TelemetryError> {
+    let mut metadata = MetadataMap::with_capacity(config.headers.len());
+    for (name, value) in &config.headers {
+        let key = MetadataKey::from_bytes(name.to_ascii_lowercase().as_bytes())
+            .map_err(|_| TelemetryError::InvalidHeader(name.clone()))?;
+        let value = MetadataValue::try_from(value.as_str()).map_err(|_| TelemetryError::InvalidHeader(name.clone()))?;
+        metadata.insert(key, value);
+    }
+    Ok(metadata)
+}
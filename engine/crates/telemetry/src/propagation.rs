@@ -0,0 +1,184 @@
+//! [W3C trace context](https://www.w3.org/TR/trace-context/) propagation, continuing the trace of
+//! the client and passing it on to the subgraphs and connectors.
+
+use std::str::FromStr;
+
+use opentelemetry::{
+    trace::{SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState},
+    Context,
+};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+pub const TRACESTATE_HEADER: &str = "tracestate";
+
+const SUPPORTED_VERSION: u8 = 0;
+
+/// The trace context headers of the current span, empty if the span isn't exported.
+pub fn current_headers() -> Vec<(&'static str, String)> {
+    let mut headers = Vec::new();
+    inject(&tracing::Span::current().context(), |name, value| {
+        headers.push((name, value));
+    });
+    headers
+}
+
+/// Calls `set_header` with the trace context headers of the context's span, if it has a valid
+/// one.
+pub fn inject(context: &Context, mut set_header: impl FnMut(&'static str, String)) {
+    let span = context.span();
+    let span_context = span.span_context();
+    if !span_context.is_valid() {
+        return;
+    }
+
+    set_header(
+        TRACEPARENT_HEADER,
+        format!(
+            "{SUPPORTED_VERSION:02x}-{}-{}-{:02x}",
+            span_context.trace_id(),
+            span_context.span_id(),
+            u8::from(span_context.is_sampled())
+        ),
+    );
+
+    let trace_state = span_context.trace_state().header();
+    if !trace_state.is_empty() {
+        set_header(TRACESTATE_HEADER, trace_state);
+    }
+}
+
+/// The context of the remote span described by the trace context headers, `None` if they're
+/// missing or invalid.
+pub fn extract<'a>(get_header: impl Fn(&str) -> Option<&'a str>) -> Option<Context> {
+    let (trace_id, span_id, trace_flags) = parse_traceparent(get_header(TRACEPARENT_HEADER)?)?;
+    let trace_state = get_header(TRACESTATE_HEADER)
+        .and_then(|header| TraceState::from_str(header).ok())
+        .unwrap_or_default();
+
+    Some(Context::new().with_remote_span_context(SpanContext::new(trace_id, span_id, trace_flags, true, trace_state)))
+}
+
+/// Makes the span a child of the remote span described by the trace context headers, if any.
+pub fn set_parent<'a>(span: &tracing::Span, get_header: impl Fn(&str) -> Option<&'a str>) {
+    if let Some(context) = extract(get_header) {
+        span.set_parent(context);
+    }
+}
+
+fn parse_traceparent(header: &str) -> Option<(TraceId, SpanId, TraceFlags)> {
+    let mut parts = header.trim().split('-');
+    let (version, trace_id, span_id, flags) = (parts.next()?, parts.next()?, parts.next()?, parts.next()?);
+
+    // Later versions may add fields after the flags, but must keep the existing ones.
+    let version_number = u8::from_str_radix(version, 16).ok()?;
+    if version.len() != 2 || version_number == 0xff || (version_number == SUPPORTED_VERSION && parts.next().is_some()) {
+        return None;
+    }
+
+    let is_lowercase_hex = |part: &str, len: usize| {
+        part.len() == len && part.bytes().all(|byte| matches!(byte, b'0'..=b'9' | b'a'..=b'f'))
+    };
+    if !is_lowercase_hex(trace_id, 32) || !is_lowercase_hex(span_id, 16) || !is_lowercase_hex(flags, 2) {
+        return None;
+    }
+
+    let trace_id = TraceId::from_hex(trace_id).ok()?;
+    let span_id = SpanId::from_hex(span_id).ok()?;
+    if trace_id == TraceId::INVALID || span_id == SpanId::INVALID {
+        return None;
+    }
+
+    let flags = u8::from_str_radix(flags, 16).ok()?;
+    Some((trace_id, span_id, TraceFlags::new(flags) & TraceFlags::SAMPLED))
+}
+
+#[cfg(test)]
+mod tests {
+    use opentelemetry::trace::TracerProvider as _;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::*;
+
+    const TRACEPARENT: &str = "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01";
+
+    fn headers(context: &Context) -> Vec<(&'static str, String)> {
+        let mut headers = Vec::new();
+        inject(context, |name, value| headers.push((name, value)));
+        headers
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let context = extract(|name| match name {
+            TRACEPARENT_HEADER => Some(TRACEPARENT),
+            TRACESTATE_HEADER => Some("vendor=value"),
+            _ => None,
+        })
+        .unwrap();
+
+        assert!(context.span().span_context().is_remote());
+        assert_eq!(
+            headers(&context),
+            vec![
+                (TRACEPARENT_HEADER, TRACEPARENT.to_string()),
+                (TRACESTATE_HEADER, "vendor=value".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_traceparent() {
+        for traceparent in [
+            "",
+            "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331",
+            "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01-extra",
+            "ff-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01",
+            "00-0AF7651916CD43DD8448EB211C80319C-b7ad6b7169203331-01",
+            "00-00000000000000000000000000000000-b7ad6b7169203331-01",
+            "00-0af7651916cd43dd8448eb211c80319c-0000000000000000-01",
+            "00-0af7651916cd43dd8448eb211c8031-b7ad6b7169203331-01",
+        ] {
+            assert!(
+                extract(|name| (name == TRACEPARENT_HEADER).then_some(traceparent)).is_none(),
+                "for {traceparent}"
+            );
+        }
+
+        // Fields added by later versions are ignored.
+        let context = extract(|name| {
+            (name == TRACEPARENT_HEADER).then_some("cc-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01-extra")
+        })
+        .unwrap();
+        assert_eq!(headers(&context)[0].1, TRACEPARENT);
+    }
+
+    #[test]
+    fn test_no_headers_without_exported_span() {
+        let _span = tracing::info_span!("operation").entered();
+
+        assert!(current_headers().is_empty());
+    }
+
+    #[test]
+    fn test_current_span_continues_remote_trace() {
+        let provider = opentelemetry_sdk::trace::TracerProvider::builder().build();
+        let subscriber =
+            tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("operation");
+            set_parent(&span, |name| (name == TRACEPARENT_HEADER).then_some(TRACEPARENT));
+            let _span = span.entered();
+
+            let headers = current_headers();
+            assert_eq!(headers.len(), 1);
+
+            let (name, traceparent) = &headers[0];
+            assert_eq!(*name, TRACEPARENT_HEADER);
+            assert!(traceparent.starts_with("00-0af7651916cd43dd8448eb211c80319c-"));
+            assert!(traceparent.ends_with("-01"));
+            assert_ne!(traceparent, TRACEPARENT);
+        });
+    }
+}
@@ -0,0 +1,97 @@
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use axum::{body::Bytes, extract::State, http::Uri, routing::post, Router};
+use telemetry::{
+    metrics::{CacheReadStatus, GraphqlOperationMetrics, OperationAttributes},
+    ExporterConfig, MetricsConfig, OtlpProtocol, TelemetryConfig, TracingConfig,
+};
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+
+type Requests = Arc<Mutex<Vec<(String, Bytes)>>>;
+
+/// Stands in for an OpenTelemetry collector, keeping every export request it receives.
+async fn start_collector() -> (String, Requests) {
+    let requests = Requests::default();
+    let app = Router::new()
+        .route("/v1/traces", post(collect))
+        .route("/v1/metrics", post(collect))
+        .with_state(Arc::clone(&requests));
+
+    let server = axum::Server::bind(&SocketAddr::from(([127, 0, 0, 1], 0))).serve(app.into_make_service());
+    let endpoint = format!("http://{}", server.local_addr());
+    tokio::spawn(server);
+
+    (endpoint, requests)
+}
+
+async fn collect(State(requests): State<Requests>, uri: Uri, body: Bytes) {
+    requests.lock().unwrap().push((uri.path().to_string(), body));
+}
+
+fn exported(requests: &Requests, path: &str, name: &str) -> bool {
+    requests
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|(request_path, _)| request_path == path)
+        .any(|(_, body)| body.windows(name.len()).any(|window| window == name.as_bytes()))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn exports_to_collector() {
+    tracing_subscriber::registry().with(telemetry::layer()).init();
+
+    let (endpoint, requests) = start_collector().await;
+    let exporter = ExporterConfig {
+        endpoint,
+        protocol: OtlpProtocol::Http,
+        headers: vec![("authorization".to_string(), "Bearer secret".to_string())],
+        timeout: Duration::from_secs(5),
+    };
+    let guard = telemetry::init(&TelemetryConfig {
+        service_name: "test-gateway".to_string(),
+        tracing: Some(TracingConfig {
+            exporter: exporter.clone(),
+            sampling: 1.0,
+        }),
+        metrics: Some(MetricsConfig {
+            exporter,
+            interval: Duration::from_secs(60),
+        }),
+    })
+    .unwrap();
+
+    tracing::info_span!("graphql_operation").in_scope(|| {
+        tracing::info_span!("subgraph_request").in_scope(|| {});
+        tracing::debug_span!("debug_span").in_scope(|| {});
+    });
+
+    let metrics = GraphqlOperationMetrics::build();
+    metrics.record_operation(
+        OperationAttributes {
+            ty: Some("query"),
+            name: Some("products"),
+            has_errors: true,
+        },
+        Duration::from_millis(12),
+    );
+    metrics.record_error(Some("BAD_REQUEST"));
+    metrics.record_cache_read(CacheReadStatus::Hit);
+
+    // Shutting down flushes the pending spans and metrics.
+    tokio::task::spawn_blocking(move || drop(guard)).await.unwrap();
+
+    assert!(exported(&requests, "/v1/traces", "test-gateway"));
+    assert!(exported(&requests, "/v1/traces", "graphql_operation"));
+    assert!(exported(&requests, "/v1/traces", "subgraph_request"));
+    assert!(!exported(&requests, "/v1/traces", "debug_span"));
+
+    assert!(exported(&requests, "/v1/metrics", "graphql.operations"));
+    assert!(exported(&requests, "/v1/metrics", "graphql.operation.duration"));
+    assert!(exported(&requests, "/v1/metrics", "BAD_REQUEST"));
+    assert!(exported(&requests, "/v1/metrics", "graphql.cache.reads"));
+}
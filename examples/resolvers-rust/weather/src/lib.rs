@@ -0,0 +1,51 @@
+use grafbase_resolver::{fetch, Error, Input, Request, Value};
+use serde::Deserialize;
+use serde_json::json;
+
+#[derive(Deserialize)]
+struct Args {
+    city: String,
+}
+
+#[derive(Deserialize)]
+struct Forecast {
+    current_condition: Vec<Condition>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Condition {
+    #[serde(rename = "temp_C")]
+    temperature: String,
+    weather_desc: Vec<Description>,
+}
+
+#[derive(Deserialize)]
+struct Description {
+    value: String,
+}
+
+fn weather(input: Input) -> Result<Value, Error> {
+    let Args { city } = input.args()?;
+
+    let response = fetch(Request::get(format!("https://wttr.in/{city}?format=j1")))?;
+    if response.status() == 404 {
+        return Ok(Value::Null);
+    }
+    if response.status() != 200 {
+        return Err(Error::graphql("The weather service is unavailable").with_extension("code", "UNAVAILABLE"));
+    }
+
+    let Forecast { current_condition } = response.json()?;
+    let Some(condition) = current_condition.into_iter().next() else {
+        return Ok(Value::Null);
+    };
+
+    Ok(json!({
+        "city": city,
+        "temperature": condition.temperature.parse::<f64>().map_err(|err| Error::new(err.to_string()))?,
+        "description": condition.weather_desc.into_iter().map(|desc| desc.value).collect::<Vec<_>>().join(", "),
+    }))
+}
+
+grafbase_resolver::export!(weather);
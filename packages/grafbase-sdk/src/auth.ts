@@ -29,7 +29,12 @@ export type AuthOperation =
 /**
  * A list of supported authentication strategies.
  */
-export type AuthStrategy = 'public' | 'private' | 'owner' | AuthGroups
+export type AuthStrategy =
+  | 'public'
+  | 'private'
+  | 'owner'
+  | AuthGroups
+  | AuthClaims
 
 /**
  * A builder to greate auth groups.
@@ -47,6 +52,21 @@ export class AuthGroups {
   }
 }
 
+/**
+ * A builder to create a claims expression rule.
+ */
+export class AuthClaims {
+  private expr: string
+
+  constructor(expr: string) {
+    this.expr = expr
+  }
+
+  public toString(): string {
+    return `claims, expr: ${JSON.stringify(this.expr)}`
+  }
+}
+
 /**
  * A builder to create a rule to the auth attribute.
  */
@@ -166,6 +186,20 @@ export class AuthRules {
     return rule
   }
 
+  /**
+   * Allow access if the expression over the token claims holds, e.g.
+   * `role == "admin" || $parent.authorId == sub`.
+   *
+   * @param expr - The claims expression.
+   */
+  public claims(expr: string): AuthRule {
+    const rule = new AuthRule(new AuthClaims(expr))
+
+    this.rules.push(rule)
+
+    return rule
+  }
+
   public toString(): string {
     let rules = this.rules.map((rule) => `      ${rule}`).join('\n')
 
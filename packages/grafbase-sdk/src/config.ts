@@ -2,6 +2,7 @@ import { AuthParams, Authentication } from './auth'
 import { CacheParams, GlobalCache } from './cache'
 import { FederatedGraph, Graph } from './grafbase-schema'
 import { Experimental, ExperimentalParams } from './experimental'
+import { Telemetry, TelemetryParams } from './telemetry'
 
 /**
  * An interface to create the complete config definition.
@@ -11,6 +12,7 @@ export interface GraphConfigInput {
   auth?: AuthParams
   cache?: CacheParams
   experimental?: ExperimentalParams
+  telemetry?: TelemetryParams
 }
 
 /**
@@ -23,6 +25,7 @@ export interface DeprecatedGraphConfigInput {
   auth?: AuthParams
   cache?: CacheParams
   experimental?: ExperimentalParams
+  telemetry?: TelemetryParams
 }
 
 /**
@@ -30,6 +33,7 @@ export interface DeprecatedGraphConfigInput {
  */
 export interface FederatedGraphConfigInput {
   graph: FederatedGraph
+  telemetry?: TelemetryParams
 }
 
 /**
@@ -40,6 +44,7 @@ export class GraphConfig {
   private readonly auth?: Authentication
   private readonly cache?: GlobalCache
   private readonly experimental?: Experimental
+  private readonly telemetry?: Telemetry
 
   /** @deprecated use `graph` instead of `schema` */
   constructor(input: GraphConfigInput | DeprecatedGraphConfigInput) {
@@ -56,6 +61,10 @@ export class GraphConfig {
     if (input.experimental) {
       this.experimental = new Experimental(input.experimental)
     }
+
+    if (input.telemetry) {
+      this.telemetry = new Telemetry(input.telemetry)
+    }
   }
 
   public toString(): string {
@@ -63,19 +72,27 @@ export class GraphConfig {
     const auth = this.auth ? this.auth.toString() : ''
     const cache = this.cache ? this.cache.toString() : ''
     const experimental = this.experimental ? this.experimental.toString() : ''
+    const telemetry = this.telemetry ? this.telemetry.toString() : ''
 
-    return `${experimental}${auth}${cache}${graph}`
+    return `${experimental}${telemetry}${auth}${cache}${graph}`
   }
 }
 
 export class FederatedGraphConfig {
   private graph: FederatedGraph
+  private readonly telemetry?: Telemetry
 
   constructor(input: FederatedGraphConfigInput) {
     this.graph = input.graph
+
+    if (input.telemetry) {
+      this.telemetry = new Telemetry(input.telemetry)
+    }
   }
 
   public toString(): string {
-    return this.graph.toString()
+    const telemetry = this.telemetry ? this.telemetry.toString() : ''
+
+    return `${telemetry}${this.graph.toString()}`
   }
 }
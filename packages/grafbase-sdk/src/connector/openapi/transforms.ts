@@ -53,6 +53,29 @@ export class OpenApiTransforms {
   public prefixTypes(prefix: string) {
     this._schemaTransforms.prefixTypes(prefix)
   }
+
+  /**
+   * Renames a type, or one or more fields, of the connectors schema. Types of
+   * different connectors renamed to the same name are merged if they have the
+   * same fields.
+   *
+   * @param from - The name of a type, or fields in dot notation
+   *               e.g. `User`, `Query.{user,users}.name`
+   * @param to - The new name
+   */
+  public rename(from: string, to: string) {
+    this._schemaTransforms.rename(from, to)
+  }
+
+  /**
+   * Exposes a field nested within the type of one or more fields in their place
+   *
+   * @param field - The fields to replace in dot notation e.g. `Query.users`
+   * @param path - The path of the nested field in dot notation e.g. `data.items`
+   */
+  public hoist(field: string, path: string) {
+    this._schemaTransforms.hoist(field, path)
+  }
 }
 
 /**
@@ -1,18 +1,58 @@
+export interface PostgresClaimSetting {
+  /** The name of the setting, e.g. `app.tenant_id` */
+  name: string
+  /** The claim, a dot-separated path for nested claims */
+  claim: string
+}
+
+export interface PostgresRowLevelSecurity {
+  /** The claim holding the role to switch to with `SET LOCAL ROLE` */
+  roleClaim?: string
+  /** The role to switch to, if the token has no role claim or the request has no token */
+  defaultRole?: string
+  /** The setting holding all the claims as a JSON object, e.g. `request.jwt.claims` */
+  claimsSetting?: string
+  /** Single claims mapped to settings */
+  settings?: PostgresClaimSetting[]
+}
+
 export interface PostgresParams {
   url: string
+  /**
+   * If set, every operation runs in a transaction, which passes the claims
+   * of the verified token to the database session to be used in row-level
+   * security policies.
+   */
+  rowLevelSecurity?: PostgresRowLevelSecurity
+  /**
+   * If true, generates subscriptions streaming the created, updated and
+   * deleted rows of every table. The triggers publishing the changes are
+   * installed with `grafbase setup-subscriptions`.
+   */
+  subscriptions?: boolean
 }
 
 export class PartialPostgresAPI {
   private name: string
   private url: string
+  private rowLevelSecurity?: PostgresRowLevelSecurity
+  private subscriptions?: boolean
 
   constructor(name: string, params: PostgresParams) {
     this.name = name
     this.url = params.url
+    this.rowLevelSecurity = params.rowLevelSecurity
+    this.subscriptions = params.subscriptions
   }
 
   finalize(namespace?: boolean): PostgresAPI {
-    return new PostgresAPI(this.name, this.url, namespace)
+    return new PostgresAPI(
+      this.name,
+      this.url,
+      namespace,
+      this.rowLevelSecurity,
+      this.subscriptions
+    )
   }
 }
 
@@ -20,11 +60,21 @@ export class PostgresAPI {
   private name: string
   private url: string
   private namespace?: boolean
+  private rowLevelSecurity?: PostgresRowLevelSecurity
+  private subscriptions?: boolean
 
-  constructor(name: string, url: string, namespace?: boolean) {
+  constructor(
+    name: string,
+    url: string,
+    namespace?: boolean,
+    rowLevelSecurity?: PostgresRowLevelSecurity,
+    subscriptions?: boolean
+  ) {
     this.name = name
     this.url = url
     this.namespace = namespace
+    this.rowLevelSecurity = rowLevelSecurity
+    this.subscriptions = subscriptions
   }
 
   public toString(): string {
@@ -39,8 +89,43 @@ export class PostgresAPI {
       namespace = '    namespace: false\n'
     }
 
+    const rowLevelSecurity = this.rowLevelSecurity
+      ? renderRowLevelSecurity(this.rowLevelSecurity)
+      : ''
+
+    const subscriptions = this.subscriptions ? '    subscriptions: true\n' : ''
+
     const footer = '  )'
 
-    return `${header}${name}${url}${namespace}${footer}`
+    return `${header}${name}${url}${namespace}${rowLevelSecurity}${subscriptions}${footer}`
+  }
+}
+
+function renderRowLevelSecurity(config: PostgresRowLevelSecurity): string {
+  const fields = []
+
+  if (config.roleClaim) {
+    fields.push(`      roleClaim: "${config.roleClaim}"`)
+  }
+
+  if (config.defaultRole) {
+    fields.push(`      defaultRole: "${config.defaultRole}"`)
+  }
+
+  if (config.claimsSetting) {
+    fields.push(`      claimsSetting: "${config.claimsSetting}"`)
   }
+
+  if (config.settings && config.settings.length > 0) {
+    const settings = config.settings
+      .map(
+        (setting) =>
+          `        { name: "${setting.name}", claim: "${setting.claim}" }`
+      )
+      .join('\n')
+
+    fields.push(`      settings: [\n${settings}\n      ]`)
+  }
+
+  return `    rowLevelSecurity: {\n${fields.join('\n')}\n    }\n`
 }
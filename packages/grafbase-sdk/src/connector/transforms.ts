@@ -1,5 +1,9 @@
 export type TransformsGenerator = (schema: SchemaTransforms) => void
-export type SchemaTransform = ExcludeTransform | TypePrefixTransform
+export type SchemaTransform =
+  | ExcludeTransform
+  | TypePrefixTransform
+  | RenameTransform
+  | HoistTransform
 
 /**
  * An accumulator class to gather transforms for a connector that introspects
@@ -8,10 +12,14 @@ export type SchemaTransform = ExcludeTransform | TypePrefixTransform
 export class SchemaTransforms {
   private _excludes: string[]
   private _prefixTypes: TypePrefixTransform | null
+  private _renames: Rename[]
+  private _hoists: Hoist[]
 
   constructor() {
     this._excludes = []
     this._prefixTypes = null
+    this._renames = []
+    this._hoists = []
   }
 
   public get transforms(): SchemaTransform[] {
@@ -22,6 +30,12 @@ export class SchemaTransforms {
     if (this._prefixTypes !== null) {
       transforms.push(this._prefixTypes)
     }
+    if (this._renames.length != 0) {
+      transforms.push(new RenameTransform(this._renames))
+    }
+    if (this._hoists.length != 0) {
+      transforms.push(new HoistTransform(this._hoists))
+    }
     return transforms
   }
 
@@ -46,6 +60,39 @@ export class SchemaTransforms {
   public prefixTypes(prefix: string) {
     this._prefixTypes = new TypePrefixTransform(prefix)
   }
+
+  /**
+   * Renames a type, or one or more fields, of the connectors schema. Types of
+   * different connectors renamed to the same name are merged if they have the
+   * same fields.
+   *
+   * @param from - The name of a type, or fields in dot notation
+   *               e.g. `User`, `Query.{user,users}.name`
+   * @param to - The new name
+   */
+  public rename(from: string, to: string) {
+    this._renames.push({ from, to })
+  }
+
+  /**
+   * Exposes a field nested within the type of one or more fields in their place
+   *
+   * @param field - The fields to replace in dot notation e.g. `Query.users`
+   * @param path - The path of the nested field in dot notation e.g. `data.items`
+   */
+  public hoist(field: string, path: string) {
+    this._hoists.push({ field, path })
+  }
+}
+
+interface Rename {
+  from: string
+  to: string
+}
+
+interface Hoist {
+  field: string
+  path: string
 }
 
 /**
@@ -85,3 +132,42 @@ export class TypePrefixTransform {
     return `typePrefix: "${this.prefix}"`
   }
 }
+
+/**
+ * A transform that renames types or fields of a connectors output
+ */
+export class RenameTransform {
+  private values: Rename[]
+
+  constructor(values: Rename[]) {
+    this.values = values
+  }
+
+  public toString(): string {
+    const renames = this.values
+      .map(({ from, to }) => `        { from: "${from}", to: "${to}" }`)
+      .join('\n')
+
+    return `rename: [\n${renames}\n      ]`
+  }
+}
+
+/**
+ * A transform that replaces fields of a connectors output by fields nested
+ * within their type
+ */
+export class HoistTransform {
+  private values: Hoist[]
+
+  constructor(values: Hoist[]) {
+    this.values = values
+  }
+
+  public toString(): string {
+    const hoists = this.values
+      .map(({ field, path }) => `        { field: "${field}", path: "${path}" }`)
+      .join('\n')
+
+    return `hoist: [\n${hoists}\n      ]`
+  }
+}
@@ -0,0 +1,93 @@
+/**
+ * The protocol used to send the telemetry to the OpenTelemetry collector.
+ */
+export type TelemetryProtocol = 'grpc' | 'http'
+
+/**
+ * Where the traces or metrics are exported to.
+ */
+export interface TelemetryExporterParams {
+  /** The URL of the OpenTelemetry collector */
+  endpoint: string
+  /** gRPC by default */
+  protocol?: TelemetryProtocol
+  headers?: Record<string, string>
+  /** The timeout of an export, in seconds */
+  timeout?: number
+}
+
+export interface TelemetryTracingParams {
+  exporter: TelemetryExporterParams
+  /** The ratio of traces to sample, between 0 and 1 */
+  sampling?: number
+}
+
+export interface TelemetryMetricsParams {
+  exporter: TelemetryExporterParams
+  /** How often the metrics are exported, in seconds */
+  interval?: number
+}
+
+/**
+ * Defines the export of the traces and metrics to an OpenTelemetry collector.
+ */
+export interface TelemetryParams {
+  serviceName?: string
+  tracing?: TelemetryTracingParams
+  metrics?: TelemetryMetricsParams
+}
+
+export class Telemetry {
+  private params: TelemetryParams
+
+  constructor(params: TelemetryParams) {
+    this.params = params
+  }
+
+  public toString(): string {
+    const { serviceName, tracing, metrics } = this.params
+
+    const args = [
+      serviceName ? `serviceName: "${serviceName}"` : '',
+      tracing
+        ? `tracing: {\n      exporter: ${renderExporter(tracing.exporter)}${
+            tracing.sampling !== undefined
+              ? `,\n      sampling: ${tracing.sampling}`
+              : ''
+          }\n    }`
+        : '',
+      metrics
+        ? `metrics: {\n      exporter: ${renderExporter(metrics.exporter)}${
+            metrics.interval !== undefined
+              ? `,\n      interval: ${metrics.interval}`
+              : ''
+          }\n    }`
+        : ''
+    ].filter(Boolean)
+
+    return args.length > 0
+      ? `extend schema\n  @telemetry(\n    ${args.join(',\n    ')}\n  )\n\n`
+      : ''
+  }
+}
+
+function renderExporter(exporter: TelemetryExporterParams): string {
+  const fields = [`endpoint: "${exporter.endpoint}"`]
+
+  if (exporter.protocol) {
+    fields.push(`protocol: ${exporter.protocol.toUpperCase()}`)
+  }
+
+  if (exporter.headers) {
+    const headers = Object.entries(exporter.headers)
+      .map(([name, value]) => `{ name: "${name}", value: "${value}" }`)
+      .join(', ')
+    fields.push(`headers: [${headers}]`)
+  }
+
+  if (exporter.timeout !== undefined) {
+    fields.push(`timeout: ${exporter.timeout}`)
+  }
+
+  return `{ ${fields.join(', ')} }`
+}
@@ -433,6 +433,34 @@ describe('OpenID auth provider', () => {
     `)
   })
 
+  it('renders a provider with claims access', () => {
+    const clerk = auth.OpenIDConnect({
+      issuer: '{{ env.ISSUER_URL }}'
+    })
+
+    const cfg = config({
+      schema: g,
+      auth: {
+        providers: [clerk],
+        rules: (rules) => {
+          rules.claims('role == "admin"').read()
+        }
+      }
+    })
+
+    expect(renderGraphQL(cfg)).toMatchInlineSnapshot(`
+      "extend schema
+        @auth(
+          providers: [
+            { type: oidc, issuer: "{{ env.ISSUER_URL }}" }
+          ]
+          rules: [
+            { allow: claims, expr: "role == \\"admin\\"", operations: [read] }
+          ]
+        )"
+    `)
+  })
+
   it('renders a provider with groups access', () => {
     const clerk = auth.OpenIDConnect({
       issuer: '{{ env.ISSUER_URL }}'
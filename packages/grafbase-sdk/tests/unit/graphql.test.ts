@@ -89,6 +89,37 @@ describe('GraphQL connector', () => {
 `)
   })
 
+  it('generates rename and hoist transforms', () => {
+    const github = connector.GraphQL('GitHub', {
+      url: 'https://api.github.com/graphql',
+      transforms: (schema) => {
+        schema.rename('PullRequest', 'Change')
+        schema.rename('{PullRequest,Issue}.title', 'name')
+        schema.hoist('Query.issues', 'data.items')
+      }
+    })
+
+    g.datasource(github, { namespace: false })
+
+    expect(renderGraphQL(config({ schema: g }))).toMatchInlineSnapshot(`
+"extend schema
+  @graphql(
+    name: "GitHub"
+    namespace: false
+    url: "https://api.github.com/graphql"
+    transforms: {
+      rename: [
+        { from: "PullRequest", to: "Change" }
+        { from: "{PullRequest,Issue}.title", to: "name" }
+      ]
+      hoist: [
+        { field: "Query.issues", path: "data.items" }
+      ]
+    }
+  )"
+`)
+  })
+
   it('combines multiple apis into one extension', () => {
     const contentful = connector.GraphQL('Contentful', {
       url: 'https://graphql.contentful.com/content/v1/spaces/{{ env.CONTENTFUL_SPACE_ID }}/environments/{{ env.CONTENTFUL_ENVIRONMENT }}'
@@ -0,0 +1,104 @@
+import { config, graph } from '../../src/index'
+import { describe, expect, it, beforeEach } from '@jest/globals'
+import { renderGraphQL } from '../utils'
+
+const g = graph.Standalone()
+
+describe('Telemetry generator', () => {
+  beforeEach(() => g.clear())
+
+  it('renders telemetry with tracing', async () => {
+    const cfg = config({
+      graph: g,
+      telemetry: {
+        tracing: {
+          exporter: { endpoint: 'http://localhost:4317' }
+        }
+      }
+    })
+
+    expect(renderGraphQL(cfg)).toMatchInlineSnapshot(`
+      "extend schema
+        @telemetry(
+          tracing: {
+            exporter: { endpoint: "http://localhost:4317" }
+          }
+        )
+      
+      "
+    `)
+  })
+
+  it('renders telemetry with all the options', async () => {
+    const cfg = config({
+      graph: g,
+      telemetry: {
+        serviceName: 'my-gateway',
+        tracing: {
+          exporter: { endpoint: 'http://localhost:4317' },
+          sampling: 0.5
+        },
+        metrics: {
+          exporter: {
+            endpoint: 'http://localhost:4318',
+            protocol: 'http',
+            headers: { authorization: 'Bearer {{ env.OTLP_TOKEN }}' },
+            timeout: 5
+          },
+          interval: 30
+        }
+      }
+    })
+
+    expect(renderGraphQL(cfg)).toMatchInlineSnapshot(`
+      "extend schema
+        @telemetry(
+          serviceName: "my-gateway",
+          tracing: {
+            exporter: { endpoint: "http://localhost:4317" },
+            sampling: 0.5
+          },
+          metrics: {
+            exporter: { endpoint: "http://localhost:4318", protocol: HTTP, headers: [{ name: "authorization", value: "Bearer {{ env.OTLP_TOKEN }}" }], timeout: 5 },
+            interval: 30
+          }
+        )
+      
+      "
+    `)
+  })
+
+  it('renders telemetry for a federated graph', async () => {
+    const cfg = config({
+      graph: graph.Federated(),
+      telemetry: {
+        metrics: {
+          exporter: { endpoint: 'http://localhost:4317' }
+        }
+      }
+    })
+
+    expect(renderGraphQL(cfg)).toMatchInlineSnapshot(`
+      "extend schema
+        @telemetry(
+          metrics: {
+            exporter: { endpoint: "http://localhost:4317" }
+          }
+        )
+      
+      
+      extend schema
+        @graph(type: federated)
+      "
+    `)
+  })
+
+  it('renders nothing without options', async () => {
+    const cfg = config({
+      graph: g,
+      telemetry: {}
+    })
+
+    expect(renderGraphQL(cfg)).toMatchInlineSnapshot(`""`)
+  })
+})